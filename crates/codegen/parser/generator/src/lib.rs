@@ -6,6 +6,7 @@ mod rust_generator;
 mod scanner_definition;
 mod trie;
 mod typescript_generator;
+mod version_model;
 
 pub use rust_generator::RustGenerator;
 pub use typescript_generator::TypeScriptGenerator;
@@ -25,6 +25,7 @@ use crate::parser_definition::ParserDefinitionExtensions;
 use crate::precedence_parser_definition::PrecedenceParserDefinitionExtensions;
 use crate::scanner_definition::ScannerDefinitionExtensions;
 use crate::trie::Trie;
+use crate::version_model::VersionModel;
 
 #[derive(Default, Serialize)]
 pub struct RustGenerator {
@@ -32,6 +33,10 @@ pub struct RustGenerator {
 
     rule_kinds: BTreeSet<&'static str>,
     token_kinds: BTreeSet<&'static str>,
+    keyword_kinds: BTreeSet<&'static str>,
+    open_delimiter_kinds: BTreeSet<&'static str>,
+    close_delimiter_kinds: BTreeSet<&'static str>,
+    terminator_kinds: BTreeSet<&'static str>,
     trivia_scanner_names: BTreeSet<&'static str>,
     labels: BTreeSet<String>,
 
@@ -90,18 +95,48 @@ impl RustGenerator {
             )?;
         }
 
+        {
+            #[derive(Serialize)]
+            struct Context {
+                ast_model: AstModel,
+            }
+            codegen.render(
+                Context {
+                    ast_model: AstModel::create(language),
+                },
+                runtime_dir.join("templates/ast.rs.jinja2"),
+                output_dir.join("ast.rs"),
+            )?;
+        }
+
         {
             #[derive(Serialize)]
             struct Context<'a> {
                 generator: &'a RustGenerator,
+                ast_model: AstModel,
             }
             codegen.render(
-                Context { generator },
+                Context {
+                    generator,
+                    ast_model: AstModel::create(language),
+                },
                 runtime_dir.join("templates/kinds.rs.jinja2"),
                 output_dir.join("kinds.rs"),
             )?;
         }
 
+        {
+            #[derive(Serialize)]
+            struct Context<'a> {
+                generator: &'a RustGenerator,
+            }
+            codegen.render(
+                Context { generator },
+                runtime_dir.join("templates/visitor.rs.jinja2"),
+                output_dir.join("visitor.rs"),
+            )?;
+        }
+
         {
             #[derive(Serialize)]
             struct Context {
@@ -134,12 +169,18 @@ impl RustGenerator {
                 generator: &'a RustGenerator,
                 language_name: String,
                 versions: BTreeSet<Version>,
+                version_model: VersionModel,
             }
             codegen.render(
                 Context {
                     generator,
                     language_name: grammar.name.clone(),
                     versions: grammar.versions.clone(),
+                    version_model: VersionModel::create(
+                        language,
+                        &generator.rule_kinds,
+                        &generator.token_kinds,
+                    ),
                 },
                 runtime_dir.join("templates/language.rs.jinja2"),
                 output_dir.join("language.rs"),
@@ -156,9 +197,11 @@ impl RustGenerator {
         }
 
         for file in &[
+            "compatibility.rs",
             "cst.rs",
             "cursor.rs",
             "diagnostic.rs",
+            "encoding.rs",
             "lexer.rs",
             "napi_interface/cst.rs",
             "napi_interface/cursor.rs",
@@ -169,9 +212,11 @@ impl RustGenerator {
             "napi_interface/query.rs",
             "napi_interface/text_index.rs",
             "parse_error.rs",
+            "parse_options.rs",
             "parse_output.rs",
             "parser_support/choice_helper.rs",
             "parser_support/context.rs",
+            "parser_support/incremental.rs",
             "parser_support/mod.rs",
             "parser_support/optional_helper.rs",
             "parser_support/parser_function.rs",
@@ -186,7 +231,11 @@ impl RustGenerator {
             "query/mod.rs",
             "query/model.rs",
             "query/parser.rs",
+            "rewriter.rs",
+            "syntax_tree.rs",
             "text_index.rs",
+            "tokenizer.rs",
+            "trivia.rs",
         ] {
             codegen.copy_file(runtime_dir.join(file), output_dir.join(file))?;
         }
@@ -399,6 +448,7 @@ impl GrammarVisitor for RustGenerator {
             }
             ParserDefinitionNode::KeywordScannerDefinition(scanner) => {
                 self.token_kinds.insert(scanner.name());
+                self.keyword_kinds.insert(scanner.name());
 
                 self.current_context()
                     .keyword_scanner_defs
@@ -420,6 +470,13 @@ impl GrammarVisitor for RustGenerator {
             }
             ParserDefinitionNode::TerminatedBy(_, terminator) => {
                 self.labels.insert(terminator.label.clone());
+
+                match terminator.as_ref() {
+                    ParserDefinitionNode::ScannerDefinition(terminator, ..) => {
+                        self.terminator_kinds.insert(terminator.name());
+                    }
+                    _ => panic!("TerminatedBy must be terminated by a scanner"),
+                }
             }
 
             // Collect delimiters for each context
@@ -435,6 +492,9 @@ impl GrammarVisitor for RustGenerator {
                     _ => panic!("DelimitedBy must be delimited by scanners"),
                 };
 
+                self.open_delimiter_kinds.insert(open);
+                self.close_delimiter_kinds.insert(close);
+
                 let delimiters = &mut self.current_context().delimiters;
 
                 assert!(
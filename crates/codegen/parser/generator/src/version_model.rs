@@ -0,0 +1,394 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+use codegen_language_definition::model::{self, VersionSpecifier};
+use semver::Version;
+use serde::Serialize;
+
+/// The versions in which each construct of the language is enabled, for constructs that are not enabled in all of them.
+/// Ranges are rendered as Rust expressions, to be used as `&[VersionRange]` slices in the generated code.
+#[derive(Default, Serialize)]
+pub struct VersionModel {
+    /// Rule kinds, and the ranges they are enabled in.
+    pub rule_kinds: BTreeMap<String, String>,
+    /// Token kinds, and the ranges they are enabled (or reserved) in.
+    pub token_kinds: BTreeMap<String, String>,
+    /// Optional fields of rules, keyed by the rule kind, then the field label.
+    pub fields: BTreeMap<String, BTreeMap<String, String>>,
+    /// Variants of enums and precedence items, keyed by the rule kind, then the variant kind.
+    pub variants: BTreeMap<String, BTreeMap<String, String>>,
+    /// Keywords that are only reserved in some versions, keyed by the identifier token kind they can otherwise be used
+    /// as, as pairs of a `KeywordPattern` and the ranges the matching words can be used as identifiers in.
+    pub unreserved_keywords: BTreeMap<String, Vec<(String, String)>>,
+    /// Keywords that are only enabled in some versions (or start rules that are), and can be used as identifiers in the
+    /// others.
+    pub versioned_keywords: Vec<VersionedKeyword>,
+}
+
+#[derive(Serialize)]
+pub struct VersionedKeyword {
+    pub kind: String,
+    pub identifier: String,
+    pub pattern: String,
+    pub versions: String,
+    /// A rule that starts with this keyword, along with its ranges, if it is also only enabled in some versions.
+    pub rule: Option<(String, String)>,
+}
+
+/// A union of ranges, where `None` means that the construct is enabled in all versions.
+type Ranges = Option<Vec<VersionSpecifier>>;
+
+impl VersionModel {
+    pub fn create(
+        language: &model::Language,
+        rule_kinds: &BTreeSet<&'static str>,
+        token_kinds: &BTreeSet<&'static str>,
+    ) -> Self {
+        let mut rules = BTreeMap::<String, Ranges>::new();
+        let mut tokens = BTreeMap::<String, Ranges>::new();
+        let mut fields = BTreeMap::<(String, String), Ranges>::new();
+        let mut variants = BTreeMap::<(String, String), Ranges>::new();
+
+        for item in language.items() {
+            match item {
+                model::Item::Struct { item } => {
+                    union(
+                        &mut rules,
+                        item.name.to_string(),
+                        ranges(item.enabled.as_ref()),
+                    );
+                    collect_fields(&mut fields, &item.name, &item.fields);
+                }
+                model::Item::Enum { item } => {
+                    union(
+                        &mut rules,
+                        item.name.to_string(),
+                        ranges(item.enabled.as_ref()),
+                    );
+                    for variant in &item.variants {
+                        let key = (item.name.to_string(), variant.reference.to_string());
+                        union(&mut variants, key, ranges(variant.enabled.as_ref()));
+                    }
+                }
+                model::Item::Repeated { item } => {
+                    union(
+                        &mut rules,
+                        item.name.to_string(),
+                        ranges(item.enabled.as_ref()),
+                    );
+                }
+                model::Item::Separated { item } => {
+                    union(
+                        &mut rules,
+                        item.name.to_string(),
+                        ranges(item.enabled.as_ref()),
+                    );
+                }
+                model::Item::Precedence { item } => {
+                    union(
+                        &mut rules,
+                        item.name.to_string(),
+                        ranges(item.enabled.as_ref()),
+                    );
+
+                    // An expression is enabled if any of its operators is:
+                    for expression in &item.precedence_expressions {
+                        for operator in &expression.operators {
+                            let enabled = ranges(operator.enabled.as_ref());
+                            union(&mut rules, expression.name.to_string(), enabled);
+                            collect_fields(&mut fields, &expression.name, &operator.fields);
+                        }
+                    }
+
+                    for primary in &item.primary_expressions {
+                        let key = (item.name.to_string(), primary.reference.to_string());
+                        union(&mut variants, key, ranges(primary.enabled.as_ref()));
+                    }
+                }
+                model::Item::Keyword { item } => {
+                    for definition in &item.definitions {
+                        // The keyword is scanned if it is either enabled or reserved:
+                        let scanned = ranges(definition.enabled.as_ref())
+                            .zip(ranges(definition.reserved.as_ref()))
+                            .map(|(enabled, reserved)| [enabled, reserved].concat());
+                        union(&mut tokens, item.name.to_string(), scanned);
+                    }
+                }
+                model::Item::Token { item } => {
+                    for definition in &item.definitions {
+                        union(
+                            &mut tokens,
+                            item.name.to_string(),
+                            ranges(definition.enabled.as_ref()),
+                        );
+                    }
+                }
+                model::Item::Trivia { .. } | model::Item::Fragment { .. } => {}
+            }
+        }
+
+        Self {
+            rule_kinds: render_all(rules, |name| rule_kinds.contains(name.as_str())),
+            token_kinds: render_all(tokens, |name| token_kinds.contains(name.as_str())),
+            fields: render_nested(fields, |(rule, _)| rule_kinds.contains(rule.as_str())),
+            variants: render_nested(variants, |(rule, _)| rule_kinds.contains(rule.as_str())),
+            unreserved_keywords: collect_unreserved_keywords(language, token_kinds),
+            versioned_keywords: collect_versioned_keywords(language, rule_kinds, token_kinds),
+        }
+    }
+}
+
+fn collect_unreserved_keywords(
+    language: &model::Language,
+    token_kinds: &BTreeSet<&'static str>,
+) -> BTreeMap<String, Vec<(String, String)>> {
+    let mut result = BTreeMap::<String, Vec<(String, String)>>::new();
+
+    for item in language.items() {
+        let model::Item::Keyword { item } = item else {
+            continue;
+        };
+
+        if !token_kinds.contains(item.identifier.as_str()) {
+            continue;
+        }
+
+        for definition in &item.definitions {
+            // Words that are never reserved, or always reserved, are not ambiguous:
+            let reserved = match &definition.reserved {
+                None | Some(VersionSpecifier::Never) => continue,
+                Some(reserved) => reserved,
+            };
+
+            result
+                .entry(item.identifier.to_string())
+                .or_default()
+                .push((
+                    render_pattern(&definition.value),
+                    render(&complement(reserved)),
+                ));
+        }
+    }
+
+    result
+}
+
+fn collect_versioned_keywords(
+    language: &model::Language,
+    rule_kinds: &BTreeSet<&'static str>,
+    token_kinds: &BTreeSet<&'static str>,
+) -> Vec<VersionedKeyword> {
+    // Rules that are only enabled in some versions, keyed by the keyword they start with:
+    let mut rules = BTreeMap::<String, (String, String)>::new();
+
+    for item in language.items() {
+        let model::Item::Struct { item } = item else {
+            continue;
+        };
+
+        if let (Some(enabled), Some((_, model::Field::Required { reference }))) =
+            (&item.enabled, item.fields.first())
+        {
+            if rule_kinds.contains(item.name.as_str()) {
+                rules.entry(reference.to_string()).or_insert_with(|| {
+                    (item.name.to_string(), render(std::slice::from_ref(enabled)))
+                });
+            }
+        }
+    }
+
+    let mut result = vec![];
+
+    for item in language.items() {
+        let model::Item::Keyword { item } = item else {
+            continue;
+        };
+
+        if !token_kinds.contains(item.name.as_str())
+            || !token_kinds.contains(item.identifier.as_str())
+        {
+            continue;
+        }
+
+        let rule = rules.get(item.name.as_str());
+
+        for definition in &item.definitions {
+            // Keywords that are always enabled are only included if the rule they start is not:
+            let versions = match (&definition.enabled, rule) {
+                (Some(VersionSpecifier::Never), _) | (None, None) => continue,
+                (Some(enabled), _) => render(std::slice::from_ref(enabled)),
+                (None, Some(_)) => "&[VersionRange { from: None, till: None }]".to_owned(),
+            };
+
+            result.push(VersionedKeyword {
+                kind: item.name.to_string(),
+                identifier: item.identifier.to_string(),
+                pattern: render_pattern(&definition.value),
+                versions,
+                rule: rule.cloned(),
+            });
+        }
+    }
+
+    result
+}
+
+fn collect_fields(
+    fields: &mut BTreeMap<(String, String), Ranges>,
+    rule: &model::Identifier,
+    item_fields: &indexmap::IndexMap<model::Identifier, model::Field>,
+) {
+    for (label, field) in item_fields {
+        let enabled = match field {
+            model::Field::Required { .. } => None,
+            model::Field::Optional { enabled, .. } => enabled.as_ref(),
+        };
+
+        union(
+            fields,
+            (rule.to_string(), label.to_string()),
+            ranges(enabled),
+        );
+    }
+}
+
+/// Returns the ranges of a single specifier, where a missing specifier means all versions.
+fn ranges(enabled: Option<&VersionSpecifier>) -> Ranges {
+    enabled.map(|enabled| vec![enabled.clone()])
+}
+
+/// Adds the ranges of another definition of the same construct.
+fn union<K: Ord>(map: &mut BTreeMap<K, Ranges>, key: K, ranges: Ranges) {
+    match map.entry(key) {
+        Entry::Vacant(entry) => {
+            entry.insert(ranges);
+        }
+        Entry::Occupied(mut entry) => match (entry.get_mut(), ranges) {
+            (Some(existing), Some(ranges)) => existing.extend(ranges),
+            (existing, _) => *existing = None,
+        },
+    }
+}
+
+/// Returns the ranges of versions that are not in the given one.
+fn complement(range: &VersionSpecifier) -> Vec<VersionSpecifier> {
+    match range {
+        VersionSpecifier::Never => vec![],
+        VersionSpecifier::From { from } => vec![VersionSpecifier::Till { till: from.clone() }],
+        VersionSpecifier::Till { till } => vec![VersionSpecifier::From { from: till.clone() }],
+        VersionSpecifier::Range { from, till } => vec![
+            VersionSpecifier::Till { till: from.clone() },
+            VersionSpecifier::From { from: till.clone() },
+        ],
+    }
+}
+
+fn render_all(
+    map: BTreeMap<String, Ranges>,
+    filter: impl Fn(&String) -> bool,
+) -> BTreeMap<String, String> {
+    map.into_iter()
+        .filter(|(key, _)| filter(key))
+        .filter_map(|(key, ranges)| Some((key, render(&ranges?))))
+        .collect()
+}
+
+fn render_nested(
+    map: BTreeMap<(String, String), Ranges>,
+    filter: impl Fn(&(String, String)) -> bool,
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut result = BTreeMap::<String, BTreeMap<String, String>>::new();
+
+    for (key, ranges) in map {
+        if !filter(&key) {
+            continue;
+        }
+
+        if let Some(ranges) = ranges {
+            let (rule, child) = key;
+            result
+                .entry(rule)
+                .or_default()
+                .insert(child, render(&ranges));
+        }
+    }
+
+    result
+}
+
+fn render_pattern(value: &model::KeywordValue) -> String {
+    let render_all = |values: &[model::KeywordValue]| {
+        values
+            .iter()
+            .map(render_pattern)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match value {
+        model::KeywordValue::Sequence { values } => {
+            format!("KeywordPattern::Sequence(&[{}])", render_all(values))
+        }
+        model::KeywordValue::Optional { value } => {
+            format!("KeywordPattern::Optional(&{})", render_pattern(value))
+        }
+        model::KeywordValue::Choice { values } => {
+            format!("KeywordPattern::Choice(&[{}])", render_all(values))
+        }
+        model::KeywordValue::Atom { atom } => format!("KeywordPattern::Atom({atom:?})"),
+    }
+}
+
+/// Renders the union of the ranges, merging the ones that overlap.
+fn render(ranges: &[VersionSpecifier]) -> String {
+    let mut bounds = ranges
+        .iter()
+        .filter_map(|range| match range {
+            VersionSpecifier::Never => None,
+            VersionSpecifier::From { from } => Some((Some(from), None)),
+            VersionSpecifier::Till { till } => Some((None, Some(till))),
+            VersionSpecifier::Range { from, till } => Some((Some(from), Some(till))),
+        })
+        .collect::<Vec<_>>();
+
+    // Ranges without a lower bound come first:
+    bounds.sort_by_key(|(from, _)| *from);
+
+    let mut merged = Vec::<(Option<&Version>, Option<&Version>)>::new();
+    for (from, till) in bounds {
+        if let Some((_, last_till)) = merged.last_mut() {
+            match *last_till {
+                // Ranges without an upper bound include all the following ones:
+                None => continue,
+                Some(end) if from.map_or(true, |from| from <= end) => {
+                    *last_till = till.map(|till| till.max(end));
+                    continue;
+                }
+                Some(_) => {}
+            }
+        }
+
+        merged.push((from, till));
+    }
+
+    let version = |version: Option<&Version>| match version {
+        Some(version) => format!(
+            "Some(({}, {}, {}))",
+            version.major, version.minor, version.patch
+        ),
+        None => "None".to_owned(),
+    };
+
+    let ranges = merged
+        .into_iter()
+        .map(|(from, till)| {
+            format!(
+                "VersionRange {{ from: {}, till: {} }}",
+                version(from),
+                version(till)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("&[{}]", ranges.join(", "))
+}
@@ -8,15 +8,19 @@ use napi_derive::napi;
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr( feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds") )]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
 pub enum TokenKind {
     SKIPPED,
+    /// A zero-width token inserted by error recovery, in place of an expected token that was missing.
+    MISSING,
     // Used for testing this crate, this is generated in the client code
     Identifier,
     Token1,
@@ -28,6 +32,22 @@ impl TokenKind {
     pub fn is_trivia(&self) -> bool {
         unreachable!("Expanded by the template")
     }
+
+    pub fn is_keyword(&self) -> bool {
+        unreachable!("Expanded by the template")
+    }
+
+    pub fn is_open_delimiter(&self) -> bool {
+        unreachable!("Expanded by the template")
+    }
+
+    pub fn is_close_delimiter(&self) -> bool {
+        unreachable!("Expanded by the template")
+    }
+
+    pub fn is_terminator(&self) -> bool {
+        unreachable!("Expanded by the template")
+    }
 }
 
 #[derive(
@@ -37,10 +57,12 @@ impl TokenKind {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr( feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds") )]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
@@ -53,6 +75,16 @@ pub enum RuleKind {
     Rule3,
 }
 
+impl RuleKind {
+    pub fn is_list(&self) -> bool {
+        unreachable!("Expanded by the template")
+    }
+
+    pub fn token_variants(&self) -> &'static [TokenKind] {
+        unreachable!("Expanded by the template")
+    }
+}
+
 #[derive(
     Debug,
     Eq,
@@ -60,10 +92,12 @@ pub enum RuleKind {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
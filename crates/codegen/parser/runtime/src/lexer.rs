@@ -126,6 +126,16 @@ pub(crate) trait Lexer {
         input: &mut ParserContext<'_>,
         kind: TokenKind,
     ) -> ParserResult {
+        if input.take_missing(kind) {
+            return ParserResult::r#match(
+                vec![LabeledNode::anonymous(cst::Node::token(
+                    TokenKind::MISSING,
+                    String::new(),
+                ))],
+                vec![],
+            );
+        }
+
         let mut children = vec![];
 
         let restore = input.position();
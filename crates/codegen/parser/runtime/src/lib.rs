@@ -3,21 +3,36 @@
 #[macro_use]
 mod parser_support;
 
+pub mod ast;
 pub mod cst;
 pub mod cursor;
 pub mod diagnostic;
+pub mod encoding;
 pub mod kinds;
 pub(crate) mod lexer;
 pub mod parse_error;
+pub mod parse_options;
 pub mod parse_output;
 pub mod query;
+pub mod rewriter;
+pub mod syntax_tree;
 pub mod text_index;
+pub mod trivia;
+pub mod visitor;
 
 #[cfg(feature = "slang_napi_interfaces")]
 pub mod napi_interface;
 
 // TODO(#863): replace with the same hierarchy as the product crate:
 mod user_defined {
+    pub mod parse_error {
+        use crate::parse_error::TokenCategory;
+
+        pub(crate) static TOKEN_CATEGORIES: &[TokenCategory] = &[
+            // Empty Stub
+        ];
+    }
+
     pub mod query {
         pub struct UserDefinedQueriesImpl;
 
@@ -1,8 +1,7 @@
-use std::rc::Rc;
-
 use napi::Either;
 use napi_derive::napi;
 
+use crate::cst::Rc;
 use crate::napi_interface::cursor::Cursor;
 use crate::napi_interface::text_index::TextIndex;
 use crate::napi_interface::{
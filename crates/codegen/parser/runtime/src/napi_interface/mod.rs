@@ -16,8 +16,11 @@ type RustQuery = crate::query::Query;
 type RustQueryResult = crate::query::QueryResult;
 type RustQueryResultIterator = crate::query::QueryResultIterator;
 type RustRuleNode = crate::cst::RuleNode;
+type RustLineColumn = crate::text_index::LineColumn;
+type RustLineIndex = crate::text_index::LineIndex;
 type RustTextIndex = crate::text_index::TextIndex;
 type RustTextRange = crate::text_index::TextRange;
+type RustTextUnit = crate::text_index::TextUnit;
 type RustTokenNode = crate::cst::TokenNode;
 
 type RuleKind = crate::kinds::RuleKind;
@@ -5,7 +5,7 @@ use napi_derive::napi;
 use text_index::TextRange;
 
 use crate::napi_interface::diagnostic::Diagnostic;
-use crate::napi_interface::{text_index, RustParseError};
+use crate::napi_interface::{text_index, RuleKind, RustParseError};
 
 #[napi(namespace = "parse_error")]
 #[derive(PartialEq, Clone)]
@@ -24,6 +24,21 @@ impl ParseError {
         self.0.text_range().clone().into()
     }
 
+    #[napi(getter, catch_unwind)]
+    pub fn code(&self) -> String {
+        self.0.kind().code().to_owned()
+    }
+
+    #[napi(getter, ts_return_type = "kinds.RuleKind | undefined", catch_unwind)]
+    pub fn rule_kind(&self) -> Option<RuleKind> {
+        self.0.rule_kind()
+    }
+
+    #[napi(getter, catch_unwind)]
+    pub fn note(&self) -> Option<String> {
+        self.0.note().map(ToOwned::to_owned)
+    }
+
     #[napi(ts_return_type = "diagnostic.Diagnostic", catch_unwind)]
     pub fn to_diagnostic(&self) -> Diagnostic {
         // TODO: Figure out if we can auto-gen Diagnostics methods
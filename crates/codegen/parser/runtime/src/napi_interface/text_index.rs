@@ -1,6 +1,8 @@
 use napi_derive::napi;
 
-use crate::napi_interface::{RustTextIndex, RustTextRange};
+use crate::napi_interface::{
+    RustLineColumn, RustLineIndex, RustTextIndex, RustTextRange, RustTextUnit,
+};
 
 #[napi(object, namespace = "text_index")]
 #[derive(Copy, Clone)]
@@ -47,3 +49,78 @@ impl From<RustTextRange> for TextRange {
         }
     }
 }
+
+impl From<TextRange> for RustTextRange {
+    fn from(value: TextRange) -> Self {
+        (value.start).into()..(value.end).into()
+    }
+}
+
+#[napi(namespace = "text_index", string_enum)]
+pub enum TextUnit {
+    Utf8,
+    Utf16,
+    Char,
+}
+
+impl From<TextUnit> for RustTextUnit {
+    fn from(value: TextUnit) -> Self {
+        match value {
+            TextUnit::Utf8 => Self::Utf8,
+            TextUnit::Utf16 => Self::Utf16,
+            TextUnit::Char => Self::Char,
+        }
+    }
+}
+
+#[napi(object, namespace = "text_index")]
+#[derive(Copy, Clone)]
+pub struct LineColumn {
+    pub line: u32,
+    pub column: TextIndex,
+}
+
+impl From<RustLineColumn> for LineColumn {
+    fn from(value: RustLineColumn) -> Self {
+        // We only support 32-byte indices on TS side.
+        #[allow(clippy::cast_possible_truncation)]
+        Self {
+            line: value.line as u32,
+            column: value.column.into(),
+        }
+    }
+}
+
+#[napi(namespace = "text_index")]
+pub struct LineIndex(RustLineIndex);
+
+impl From<RustLineIndex> for LineIndex {
+    fn from(value: RustLineIndex) -> Self {
+        Self(value)
+    }
+}
+
+#[napi(namespace = "text_index")]
+impl LineIndex {
+    #[napi(getter, catch_unwind)]
+    pub fn line_count(&self) -> u32 {
+        // We only support 32-byte indices on TS side.
+        #[allow(clippy::cast_possible_truncation)]
+        let count = self.0.line_count() as u32;
+        count
+    }
+
+    /// Returns the position of the given offset.
+    #[napi(catch_unwind)]
+    pub fn line_column(&self, offset: TextIndex) -> LineColumn {
+        self.0.line_column(offset.into()).into()
+    }
+
+    /// Returns the offset of a `column` (measured in the given `unit`) within a zero-based `line`.
+    #[napi(catch_unwind)]
+    pub fn offset(&self, line: u32, column: u32, unit: TextUnit) -> Option<TextIndex> {
+        self.0
+            .offset(line as usize, column as usize, unit.into())
+            .map(Into::into)
+    }
+}
@@ -2,20 +2,166 @@ use std::collections::BTreeSet;
 use std::error::Error;
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+use crate::cst::{Node, RuleNode};
 use crate::diagnostic::{self, Diagnostic};
-use crate::kinds::TokenKind;
-use crate::text_index::TextRange;
+use crate::kinds::{RuleKind, TokenKind};
+use crate::text_index::{TextIndex, TextRange, TextRangeExtensions as _};
+
+/// The category of a [`ParseError`], which determines its stable [`ParseErrorKind::code`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum_macros::AsRefStr,
+    strum_macros::FromRepr,
+)]
+pub enum ParseErrorKind {
+    /// Tokens that could not be parsed were skipped, and parsing continued after them.
+    UnexpectedTokens,
+    /// A token was missing, and parsing continued as if it was there.
+    MissingTokens,
+    /// The input ended before the construct being parsed was complete.
+    UnexpectedEndOfInput,
+    /// The input was parsed completely, but it is followed by more tokens.
+    ExpectedEndOfInput,
+    /// Rules were nested deeper than [`ParseOptions::max_depth`](crate::parse_options::ParseOptions::max_depth).
+    NestingTooDeep,
+    /// The input is larger than [`ParseOptions::max_input_size`](crate::parse_options::ParseOptions::max_input_size).
+    InputTooLarge,
+    /// More tokens were scanned than [`ParseOptions::token_budget`](crate::parse_options::ParseOptions::token_budget).
+    TokenBudgetExceeded,
+    /// Parsing was stopped using [`ParseOptions::cancellation_flag`](crate::parse_options::ParseOptions::cancellation_flag).
+    Cancelled,
+}
+
+impl ParseErrorKind {
+    /// A code that identifies this kind of error. It never changes between versions, so it can be used to
+    /// filter or suppress errors, or link to their documentation.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::UnexpectedTokens => "P0001",
+            Self::MissingTokens => "P0002",
+            Self::UnexpectedEndOfInput => "P0003",
+            Self::ExpectedEndOfInput => "P0004",
+            Self::NestingTooDeep => "P0005",
+            Self::InputTooLarge => "P0006",
+            Self::TokenBudgetExceeded => "P0007",
+            Self::Cancelled => "P0008",
+        }
+    }
+
+    /// Whether parsing was stopped by one of the limits in [`ParseOptions`](crate::parse_options::ParseOptions),
+    /// in which case no tree was produced.
+    pub fn is_limit_exceeded(self) -> bool {
+        matches!(
+            self,
+            Self::NestingTooDeep
+                | Self::InputTooLarge
+                | Self::TokenBudgetExceeded
+                | Self::Cancelled
+        )
+    }
+}
+
+/// A friendly name for a group of tokens (e.g. "an expression"), used to shorten the list of expected tokens.
+pub(crate) struct TokenCategory {
+    pub name: &'static str,
+    /// The category is only used if all of these tokens are expected.
+    pub required: &'static [TokenKind],
+    /// Whether a token belongs to the category, and should be replaced by it when the category is used.
+    pub contains: fn(TokenKind) -> bool,
+}
+
+/// An item in the list of things that would have allowed the parser to make more progress.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Token(TokenKind),
+    /// A group of tokens, named after the construct they start (e.g. "an expression").
+    Category(&'static str),
+}
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Token(kind) => write!(f, "{kind}"),
+            Self::Category(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct ParseError {
     pub(crate) text_range: TextRange,
     pub(crate) tokens_that_would_have_allowed_more_progress: Vec<TokenKind>,
+    pub(crate) kind: ParseErrorKind,
+    /// The innermost rule that was being parsed when the error occurred.
+    pub(crate) rule_kind: Option<RuleKind>,
+    /// Explains why the input might not parse with this language version, if it would parse with others.
+    pub(crate) note: Option<String>,
 }
 
 impl ParseError {
     pub fn text_range(&self) -> &TextRange {
         &self.text_range
     }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The innermost rule that was being parsed when the error occurred, if any.
+    pub fn rule_kind(&self) -> Option<RuleKind> {
+        self.rule_kind
+    }
+
+    /// An explanation of why the input might not parse with this language version (e.g. using a construct that was
+    /// only added in a later version, or a word that is reserved as a keyword in this one), if any.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// The tokens that would have allowed the parser to make more progress, sorted and deduplicated.
+    pub fn expected_tokens(&self) -> Vec<TokenKind> {
+        self.tokens_that_would_have_allowed_more_progress
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Same as [`ParseError::expected_tokens`], but with groups of tokens replaced by their category
+    /// (e.g. "an expression"), followed by any remaining tokens.
+    pub fn expected(&self) -> Vec<Expected> {
+        let mut remaining = self
+            .tokens_that_would_have_allowed_more_progress
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut expected = vec![];
+
+        for category in crate::user_defined::parse_error::TOKEN_CATEGORIES {
+            if category
+                .required
+                .iter()
+                .all(|required| remaining.contains(required))
+            {
+                remaining.retain(|kind| !(category.contains)(*kind));
+                expected.push(Expected::Category(category.name));
+            }
+        }
+
+        expected.extend(remaining.into_iter().map(Expected::Token));
+        expected
+    }
 }
 
 impl ParseError {
@@ -23,34 +169,197 @@ impl ParseError {
         text_range: TextRange,
         tokens_that_would_have_allowed_more_progress: Vec<TokenKind>,
     ) -> Self {
+        let kind = if tokens_that_would_have_allowed_more_progress.is_empty() {
+            ParseErrorKind::ExpectedEndOfInput
+        } else if text_range.is_empty() {
+            ParseErrorKind::MissingTokens
+        } else {
+            ParseErrorKind::UnexpectedTokens
+        };
+
         Self {
             text_range,
             tokens_that_would_have_allowed_more_progress,
+            kind,
+            rule_kind: None,
+            note: None,
+        }
+    }
+
+    pub(crate) fn limit_exceeded(kind: ParseErrorKind, text_range: TextRange) -> Self {
+        debug_assert!(kind.is_limit_exceeded());
+
+        Self {
+            text_range,
+            tokens_that_would_have_allowed_more_progress: vec![],
+            kind,
+            rule_kind: None,
+            note: None,
+        }
+    }
+}
+
+/// Adds the context of each error, now that the whole tree is available, and removes errors that cascade from
+/// an earlier error: either reported for the same range, or within the range of tokens it already skipped.
+pub(crate) fn contextualize(errors: Vec<ParseError>, tree: &Node, input: &str) -> Vec<ParseError> {
+    let end_of_input = TextIndex::from(input);
+
+    let mut result: Vec<ParseError> = vec![];
+
+    for mut error in errors {
+        if let Some(previous) = result
+            .iter_mut()
+            .find(|previous| previous.text_range == error.text_range)
+        {
+            previous
+                .tokens_that_would_have_allowed_more_progress
+                .extend(error.tokens_that_would_have_allowed_more_progress);
+            continue;
         }
+
+        if result.iter().any(|previous| {
+            !previous.text_range.is_empty()
+                && previous.text_range.start <= error.text_range.start
+                && error.text_range.end <= previous.text_range.end
+        }) {
+            continue;
+        }
+
+        // Only trivia (if anything) was left when the input ended:
+        if error.kind != ParseErrorKind::ExpectedEndOfInput
+            && error.text_range.end == end_of_input
+            && input[error.text_range.utf8()].trim().is_empty()
+        {
+            error.kind = ParseErrorKind::UnexpectedEndOfInput;
+        }
+
+        if error.kind != ParseErrorKind::ExpectedEndOfInput {
+            error.rule_kind = enclosing_rule(tree, &error.text_range);
+        }
+
+        result.push(error);
+    }
+
+    result
+}
+
+/// Returns the innermost rule containing the range. Empty ranges (i.e. missing tokens) are also contained in rules
+/// that end at their position, since these are the incomplete rules that were being parsed, unless they end with a
+/// closing token (e.g. `}`).
+fn enclosing_rule(tree: &Node, range: &TextRange) -> Option<RuleKind> {
+    let root = tree.as_rule()?;
+
+    let innermost = innermost_rule(root, range);
+
+    // Remaining input that was skipped at the top level follows the incomplete rule that was being parsed:
+    if innermost.kind == root.kind && !range.is_empty() {
+        let position = range.start..range.start;
+        return Some(innermost_rule(root, &position).kind);
+    }
+
+    Some(innermost.kind)
+}
+
+fn innermost_rule<'a>(root: &'a RuleNode, range: &TextRange) -> &'a RuleNode {
+    let mut rule = root;
+    let mut offset = TextIndex::ZERO;
+
+    'outer: loop {
+        // Missing tokens inserted by error recovery belong to the rule that expected them:
+        if range.is_empty() && has_missing_token_at(rule, offset, range.start) {
+            return rule;
+        }
+
+        let mut child_offset = offset;
+
+        for child in &rule.children {
+            let start = child_offset;
+            let end = start + child.text_len();
+            child_offset = end;
+
+            let Node::Rule(child_rule) = &child.node else {
+                continue;
+            };
+
+            let contains = if range.is_empty() {
+                start < range.start
+                    && (range.start < end || (range.start == end && !is_closed(child_rule)))
+            } else {
+                start <= range.start && range.end <= end
+            };
+
+            if contains {
+                rule = child_rule;
+                offset = start;
+                continue 'outer;
+            }
+        }
+
+        return rule;
+    }
+}
+
+fn has_missing_token_at(rule: &RuleNode, offset: TextIndex, position: TextIndex) -> bool {
+    let mut offset = offset;
+
+    rule.children.iter().any(|child| {
+        let start = offset;
+        offset += child.text_len();
+
+        start == position && child.is_token_with_kind(TokenKind::MISSING)
+    })
+}
+
+/// Whether the rule ends with a closing token, either its own (e.g. the `}` of a block), or the one of its last child,
+/// as long as it has no opening token (e.g. `{`) of its own that was left unclosed.
+fn is_closed(rule: &RuleNode) -> bool {
+    let mut children = rule.children.iter().filter(|child| !child.is_trivia());
+
+    match children.next_back().map(|child| &child.node) {
+        Some(Node::Token(token)) => token.kind.is_close_delimiter() || token.kind.is_terminator(),
+        Some(Node::Rule(last)) => is_closed(last)
+            && !children.any(
+                |child| matches!(&child.node, Node::Token(token) if token.kind.is_open_delimiter()),
+            ),
+        None => false,
     }
 }
 
 impl Error for ParseError {}
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.tokens_that_would_have_allowed_more_progress.is_empty() {
-            write!(f, "Expected end of file.")
-        } else {
-            let deduped = self
-                .tokens_that_would_have_allowed_more_progress
-                .iter()
-                .collect::<BTreeSet<_>>();
+        match self.kind {
+            ParseErrorKind::NestingTooDeep => {
+                return write!(f, "Parsing stopped: the input is nested too deeply.")
+            }
+            ParseErrorKind::InputTooLarge => {
+                return write!(f, "Parsing stopped: the input is too large.")
+            }
+            ParseErrorKind::TokenBudgetExceeded => {
+                return write!(f, "Parsing stopped: the token budget was exceeded.")
+            }
+            ParseErrorKind::Cancelled => return write!(f, "Parsing was cancelled."),
+            _ => {}
+        }
 
-            write!(f, "Expected ")?;
+        let expected = self.expected();
 
-            for kind in deduped.iter().take(deduped.len() - 1) {
-                write!(f, "{kind} or ")?;
-            }
-            let last = deduped.last().expect("we just checked that it's not empty");
-            write!(f, "{last}.")?;
+        let Some((last, rest)) = expected.split_last() else {
+            return write!(f, "Expected end of file.");
+        };
 
-            Ok(())
+        write!(f, "Expected ")?;
+
+        for item in rest {
+            write!(f, "{item} or ")?;
+        }
+        write!(f, "{last}")?;
+
+        if let Some(rule_kind) = self.rule_kind {
+            write!(f, " while parsing {rule_kind}")?;
         }
+
+        write!(f, ".")
     }
 }
 
@@ -60,7 +369,7 @@ impl Diagnostic for ParseError {
     }
 
     fn code(&self) -> Box<dyn std::fmt::Display> {
-        Box::new("ParseError")
+        Box::new(self.kind.code())
     }
 
     fn severity(&self) -> diagnostic::Severity {
@@ -70,4 +379,8 @@ impl Diagnostic for ParseError {
     fn message(&self) -> String {
         ToString::to_string(&self)
     }
+
+    fn note(&self) -> Option<String> {
+        self.note.clone()
+    }
 }
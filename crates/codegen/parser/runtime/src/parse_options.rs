@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Limits on the work done by [`Language::parse_with_options`](crate::language::Language::parse_with_options),
+/// for parsing untrusted input.
+///
+/// When a limit is hit, parsing stops, and the output has a single error of the matching
+/// [`ParseErrorKind`](crate::parse_error::ParseErrorKind), with the entire input as a `SKIPPED` token.
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// The maximum number of rules that can be nested inside each other while parsing, which bounds the
+    /// size of the native stack. Defaults to `None` (unlimited).
+    pub max_depth: Option<usize>,
+    /// The maximum size of the input, in UTF-8 bytes. Defaults to `None` (unlimited).
+    pub max_input_size: Option<usize>,
+    /// The maximum number of tokens scanned, including the ones scanned again when the parser backtracks,
+    /// which bounds the time spent parsing. Defaults to `None` (unlimited).
+    pub token_budget: Option<usize>,
+    /// A flag that can be set from another thread to stop parsing as soon as possible. Defaults to `None`.
+    pub cancellation_flag: Option<Arc<AtomicBool>>,
+}
+
+impl ParseOptions {
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+}
@@ -1,9 +1,13 @@
+use serde::{Deserialize, Serialize};
+
 use crate::cst;
 use crate::cursor::Cursor;
+use crate::encoding::{self, DecodeError};
 use crate::parse_error::ParseError;
-use crate::text_index::TextIndex;
+use crate::syntax_tree::SyntaxNode;
+use crate::text_index::{LineIndex, TextIndex, TextRange};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ParseOutput {
     pub(crate) parse_tree: cst::Node,
     pub(crate) errors: Vec<ParseError>,
@@ -26,4 +30,39 @@ impl ParseOutput {
     pub fn create_tree_cursor(&self) -> Cursor {
         self.parse_tree.cursor_with_offset(TextIndex::ZERO)
     }
+
+    /// Creates a cursor that points to the deepest node (i.e. a token) containing the given offset,
+    /// or `None` if the offset is outside of the parse tree. See [`Cursor::go_to_offset`].
+    pub fn node_at_offset(&self, offset: TextIndex) -> Option<Cursor> {
+        let mut cursor = self.create_tree_cursor();
+        cursor.go_to_offset(offset).then_some(cursor)
+    }
+
+    /// Creates a cursor that points to the deepest node fully containing the given range,
+    /// or `None` if the range is outside of the parse tree.
+    pub fn covering_node(&self, range: &TextRange) -> Option<Cursor> {
+        let mut cursor = self.create_tree_cursor();
+        cursor.go_to_covering_node(range).then_some(cursor)
+    }
+
+    /// Creates a [`LineIndex`] of the parsed source, to convert text offsets to (line, column) positions and back.
+    pub fn create_line_index(&self) -> LineIndex {
+        LineIndex::new(&self.parse_tree.clone().unparse())
+    }
+
+    /// Returns the root of the parse tree as a [`SyntaxNode`], that can be used to navigate to parents and children.
+    pub fn syntax_tree(&self) -> SyntaxNode {
+        SyntaxNode::new_root(self.parse_tree.clone())
+    }
+
+    /// Encodes this parse output using a compact binary encoding, that can be decoded again using [`ParseOutput::from_bytes`].
+    /// See the [`encoding`] module for more details.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encoding::encode(self)
+    }
+
+    /// Decodes a parse output that was previously encoded using [`ParseOutput::to_bytes`], by the same version of this crate.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        encoding::decode(bytes)
+    }
 }
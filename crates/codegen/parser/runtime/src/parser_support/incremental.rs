@@ -0,0 +1,204 @@
+use crate::cst::{LabeledNode, Node, Rc, RuleNode};
+use crate::kinds::{RuleKind, TokenKind};
+use crate::parse_output::ParseOutput;
+use crate::text_index::{TextEdit, TextIndex, TextRange};
+
+/// Re-parses the `input` after applying the `edit` to the source of a `previous` parse output.
+///
+/// Looks for the innermost rule node that encloses the edit within its delimiters (e.g. `{` and `}`),
+/// since these are the same boundaries used by the error recovery, and re-parses only that node.
+/// The remaining subtrees are shared with the previous tree. If the re-parsed node is not valid,
+/// or does not span the edited text exactly, it falls back to re-parsing the next enclosing node,
+/// and ultimately to parsing the entire `input` from scratch.
+pub(crate) fn reparse(
+    kind: RuleKind,
+    previous: &ParseOutput,
+    edit: &TextEdit,
+    input: &str,
+    delimiters: &[(TokenKind, TokenKind)],
+    parse: impl Fn(RuleKind, &str) -> ParseOutput,
+) -> ParseOutput {
+    try_reparse(kind, previous, edit, input, delimiters, &parse)
+        .unwrap_or_else(|| parse(kind, input))
+}
+
+fn try_reparse(
+    kind: RuleKind,
+    previous: &ParseOutput,
+    edit: &TextEdit,
+    input: &str,
+    delimiters: &[(TokenKind, TokenKind)],
+    parse: &impl Fn(RuleKind, &str) -> ParseOutput,
+) -> Option<ParseOutput> {
+    // Errors from the previous parse can cascade arbitrarily far, so only reuse valid trees:
+    if !previous.is_valid() {
+        return None;
+    }
+
+    let root = previous.parse_tree.as_rule_with_kind(kind)?;
+
+    let inserted_len = TextIndex::from(edit.new_text.as_str());
+    let removed_len = edit.range.end - edit.range.start;
+
+    // Make sure the edit is consistent with both the previous and the new source:
+    if edit.range.start > edit.range.end
+        || edit.range.end > root.text_len
+        || root.text_len - removed_len + inserted_len != TextIndex::from(input)
+    {
+        return None;
+    }
+
+    // Collect the path from the root to the innermost rule node that fully contains the edit:
+    let mut path = vec![];
+    let mut node = Rc::clone(root);
+    let mut offset = TextIndex::ZERO;
+    loop {
+        let mut child_offset = offset;
+        let child = node.children.iter().enumerate().find_map(|(index, child)| {
+            let start = child_offset;
+            child_offset += child.text_len();
+
+            (start <= edit.range.start && edit.range.end <= child_offset)
+                .then(|| child.as_rule().map(|rule| (index, Rc::clone(rule), start)))
+                .flatten()
+        });
+
+        let Some((index, child, child_start)) = child else {
+            path.push((node, offset, None));
+            break;
+        };
+
+        path.push((node, offset, Some(index)));
+        node = child;
+        offset = child_start;
+    }
+
+    // Start from the innermost node, and try to re-parse the enclosing delimited groups:
+    for depth in (0..path.len()).rev() {
+        let (old_node, old_start, _) = &path[depth];
+
+        if !encloses_within_delimiters(old_node, *old_start, &edit.range, delimiters) {
+            continue;
+        }
+
+        let new_len = old_node.text_len - removed_len + inserted_len;
+        let new_text = &input[old_start.utf8..old_start.utf8 + new_len.utf8];
+
+        let output = parse(old_node.kind, new_text);
+        if !output.is_valid() || output.parse_tree.text_len() != new_len {
+            continue;
+        }
+        let Node::Rule(new_node) = output.parse_tree else {
+            continue;
+        };
+        if new_node.kind != old_node.kind {
+            continue;
+        }
+
+        // Share any unchanged subtrees of the re-parsed node:
+        let mut new_tree = share_unchanged(&Node::Rule(Rc::clone(old_node)), Node::Rule(new_node));
+
+        // Then rebuild its ancestors, sharing all of their other children:
+        for (ancestor, _, index) in path[..depth].iter().rev() {
+            let index = index.expect("ancestors always have a child on the path");
+
+            let mut children = ancestor.children.clone();
+            children[index].node = new_tree;
+            new_tree = Node::rule(ancestor.kind, children);
+        }
+
+        return Some(ParseOutput {
+            parse_tree: new_tree,
+            errors: vec![],
+        });
+    }
+
+    None
+}
+
+/// Whether the edit range is fully enclosed between a pair of matching delimiter tokens,
+/// that are direct children of the given node.
+fn encloses_within_delimiters(
+    node: &RuleNode,
+    node_start: TextIndex,
+    edit: &TextRange,
+    delimiters: &[(TokenKind, TokenKind)],
+) -> bool {
+    let mut open_end = None;
+    let mut offset = node_start;
+
+    for child in &node.children {
+        let start = offset;
+        offset += child.text_len();
+
+        let Some(token) = child.as_token() else {
+            continue;
+        };
+
+        match open_end {
+            None if delimiters.iter().any(|(open, _)| *open == token.kind) => {
+                open_end = Some((token.kind, offset));
+            }
+            Some((open_kind, open_end)) if delimiters.contains(&(open_kind, token.kind)) => {
+                return open_end <= edit.start && edit.end <= start;
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Returns the `new` node, replacing any of its descendants that are equal to the ones in the `old` node
+/// (at the same position from either the start or the end) with the old ones, so that they are shared.
+fn share_unchanged(old: &Node, new: Node) -> Node {
+    if *old == new {
+        return old.clone();
+    }
+
+    let (Node::Rule(old_rule), Node::Rule(new_rule)) = (old, &new) else {
+        return new;
+    };
+    if old_rule.kind != new_rule.kind {
+        return new;
+    }
+
+    let old_children = &old_rule.children;
+    let mut new_children = new_rule.children.clone();
+
+    let prefix_len = old_children
+        .iter()
+        .zip(&new_children)
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let suffix_len = old_children[prefix_len..]
+        .iter()
+        .rev()
+        .zip(new_children[prefix_len..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let shared = |old: &LabeledNode, new: &mut LabeledNode| new.node = old.node.clone();
+
+    for (old, new) in old_children.iter().zip(&mut new_children).take(prefix_len) {
+        shared(old, new);
+    }
+    for (old, new) in old_children
+        .iter()
+        .rev()
+        .zip(new_children.iter_mut().rev())
+        .take(suffix_len)
+    {
+        shared(old, new);
+    }
+
+    // If exactly one child was changed in place, descend into it to share its own children:
+    let new_len = new_children.len();
+    if old_children.len() == new_len && prefix_len + suffix_len + 1 == new_len {
+        let changed = &mut new_children[prefix_len];
+        changed.node = share_unchanged(&old_children[prefix_len].node, changed.node.clone());
+    }
+
+    Node::rule(new_rule.kind, new_children)
+}
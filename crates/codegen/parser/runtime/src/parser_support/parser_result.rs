@@ -210,7 +210,10 @@ impl IncompleteMatch {
             .flat_map(|node| node.cursor_with_offset(TextIndex::ZERO))
             .try_fold(0u8, |mut acc, node| {
                 match node {
-                    Node::Token(tok) if tok.kind != TokenKind::SKIPPED && !tok.kind.is_trivia() => {
+                    Node::Token(tok)
+                        if !matches!(tok.kind, TokenKind::SKIPPED | TokenKind::MISSING)
+                            && !tok.kind.is_trivia() =>
+                    {
                         acc += 1;
                     }
                     _ => {}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::collections::HashSet;
 
 use nom::branch::alt;
 use nom::bytes::complete::{is_not, tag, take_while, take_while1, take_while_m_n};
@@ -8,43 +8,73 @@ use nom::error::VerboseError;
 use nom::multi::{fold_many0, many0, many1};
 use nom::sequence::{delimited, pair, preceded, terminated};
 use nom::{Finish, IResult, Parser};
+use regex::Regex;
 
 // This crate is copied to another crate, so all imports should be relative
+use super::super::cst::Rc;
 use super::super::kinds::{NodeLabel, RuleKind, TokenKind};
 use super::model::{
-    AlternativesMatcher, BindingMatcher, Kind, Matcher, NodeMatcher, NodeSelector,
-    OneOrMoreMatcher, OptionalMatcher, SequenceMatcher,
+    AlternativesMatcher, BindingMatcher, Kind, Matcher, NegationMatcher, NodeMatcher, NodeSelector,
+    OneOrMoreMatcher, OptionalMatcher, Predicate, PredicateOperator, PredicateValue, Query,
+    SequenceMatcher,
 };
 
-pub(super) fn parse_query(input: &str) -> Result<Matcher, String> {
-    all_consuming(preceded(
+pub(super) fn parse_query(input: &str) -> Result<Query, String> {
+    let (matcher, predicates) = all_consuming(preceded(
         multispace0,
-        opt(binding_name_token)
-            .and(alt((
-                parse_node,
-                delimited(
-                    token('('),
-                    pair(parse_node, many1(preceded(token('|'), parse_node))),
-                    token(')'),
-                )
-                .map(|(first, rest)| {
-                    let mut children = vec![first];
-                    children.extend(rest);
-                    Matcher::Alternatives(Rc::new(AlternativesMatcher { children }))
-                }),
-            )))
-            .map(|(binding_name, child)| {
-                if let Some(name) = binding_name {
-                    Matcher::Binding(Rc::new(BindingMatcher { name, child }))
-                } else {
-                    child
-                }
-            }),
+        parse_root.and(many0(parse_predicate)),
     ))
     .parse(input)
     .finish()
     .map(|(_, query)| query)
-    .map_err(|e| e.to_string())
+    .map_err(|e| e.to_string())?;
+
+    let mut binding_names = HashSet::new();
+    let mut negated_binding_names = HashSet::new();
+    matcher.visit_binding_names(&mut |name, negated| {
+        if negated {
+            negated_binding_names.insert(name.to_owned());
+        } else {
+            binding_names.insert(name.to_owned());
+        }
+    });
+
+    let predicates = predicates
+        .into_iter()
+        .map(|(name, arguments)| {
+            build_predicate(&name, &arguments, &binding_names, &negated_binding_names)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Query {
+        matcher,
+        predicates,
+    })
+}
+
+fn parse_root(input: &str) -> IResult<&str, Matcher, VerboseError<&str>> {
+    opt(binding_name_token)
+        .and(alt((
+            parse_node,
+            delimited(
+                token('('),
+                pair(parse_node, many1(preceded(token('|'), parse_node))),
+                token(')'),
+            )
+            .map(|(first, rest)| {
+                let mut children = vec![first];
+                children.extend(rest);
+                Matcher::Alternatives(Rc::new(AlternativesMatcher { children }))
+            }),
+        )))
+        .map(|(binding_name, child)| {
+            if let Some(name) = binding_name {
+                Matcher::Binding(Rc::new(BindingMatcher { name, child }))
+            } else {
+                child
+            }
+        })
+        .parse(input)
 }
 
 fn parse_node(i: &str) -> IResult<&str, Matcher, VerboseError<&str>> {
@@ -101,6 +131,29 @@ enum Quantifier {
 }
 
 fn parse_match(input: &str) -> IResult<&str, Matcher, VerboseError<&str>> {
+    alt((parse_negation, parse_binding_or_match)).parse(input)
+}
+
+/// A pattern prefixed with `!`, which matches (without consuming any nodes) only if the pattern doesn't match.
+fn parse_negation(input: &str) -> IResult<&str, Matcher, VerboseError<&str>> {
+    preceded(
+        token('!'),
+        alt((
+            parse_node,
+            delimited(token('('), many1(parse_match), token(')')).map(|mut children| {
+                if children.len() == 1 {
+                    children.pop().unwrap()
+                } else {
+                    Matcher::Sequence(Rc::new(SequenceMatcher { children }))
+                }
+            }),
+        )),
+    )
+    .map(|child| Matcher::Negation(Rc::new(NegationMatcher { child })))
+    .parse(input)
+}
+
+fn parse_binding_or_match(input: &str) -> IResult<&str, Matcher, VerboseError<&str>> {
     opt(binding_name_token)
         .and(alt((
             parse_node,
@@ -155,6 +208,96 @@ fn parse_trailing_quantifier(i: &str) -> IResult<&str, Quantifier, VerboseError<
     .parse(i)
 }
 
+enum PredicateArgument {
+    Binding(String),
+    Text(String),
+}
+
+fn parse_predicate(
+    input: &str,
+) -> IResult<&str, (String, Vec<PredicateArgument>), VerboseError<&str>> {
+    delimited(
+        token('('),
+        predicate_name_token.and(many0(alt((
+            binding_name_token.map(PredicateArgument::Binding),
+            terminated(text_token, multispace0).map(PredicateArgument::Text),
+        )))),
+        token(')'),
+    )
+    .parse(input)
+}
+
+fn build_predicate(
+    name: &str,
+    arguments: &[PredicateArgument],
+    binding_names: &HashSet<String>,
+    negated_binding_names: &HashSet<String>,
+) -> Result<Predicate, String> {
+    for argument in arguments {
+        if let PredicateArgument::Binding(binding) = argument {
+            if binding_names.contains(binding) {
+                continue;
+            }
+
+            // Negated patterns only match when their bindings don't capture anything:
+            if negated_binding_names.contains(binding) {
+                return Err(format!(
+                    "Predicate '#{name}?' refers to a binding '@{binding}' that is only inside a negation."
+                ));
+            }
+
+            return Err(format!(
+                "Predicate '#{name}?' refers to an unknown binding '@{binding}'."
+            ));
+        }
+    }
+
+    let (negated, operator) = match name.strip_prefix("not-") {
+        Some(operator) => (true, operator),
+        None => (false, name),
+    };
+
+    if !matches!(operator, "eq" | "match" | "any-of") {
+        return Err(format!("Unknown predicate '#{name}?'."));
+    }
+
+    let invalid_arguments = || format!("Invalid arguments to predicate '#{name}?'.");
+
+    let Some((PredicateArgument::Binding(binding), values)) = arguments.split_first() else {
+        return Err(invalid_arguments());
+    };
+
+    let operator = match (operator, values) {
+        ("eq", [PredicateArgument::Binding(other)]) => {
+            PredicateOperator::Eq(PredicateValue::Binding(other.clone()))
+        }
+        ("eq", [PredicateArgument::Text(text)]) => {
+            PredicateOperator::Eq(PredicateValue::Text(text.clone()))
+        }
+        ("match", [PredicateArgument::Text(pattern)]) => {
+            PredicateOperator::Match(Regex::new(pattern).map_err(|error| {
+                format!("Invalid regular expression in predicate '#{name}?': {error}")
+            })?)
+        }
+        ("any-of", [_, ..]) => PredicateOperator::AnyOf(
+            values
+                .iter()
+                .map(|value| match value {
+                    PredicateArgument::Text(text) => Ok(text.clone()),
+                    PredicateArgument::Binding(_) => Err(invalid_arguments()),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => return Err(invalid_arguments()),
+    };
+
+    Ok(Predicate {
+        operator,
+        negated,
+        binding: binding.clone(),
+    })
+}
+
 fn raw_identifier(i: &str) -> IResult<&str, String, VerboseError<&str>> {
     let identifier_head = satisfy(|c| c.is_alphabetic());
     let is_identifier_tail = |c: char| c == '_' || c.is_alphanumeric();
@@ -172,6 +315,19 @@ fn binding_name_token(i: &str) -> IResult<&str, String, VerboseError<&str>> {
     terminated(preceded(char('@'), raw_identifier), multispace0).parse(i)
 }
 
+fn predicate_name_token(i: &str) -> IResult<&str, String, VerboseError<&str>> {
+    terminated(
+        delimited(
+            char('#'),
+            take_while1(|c: char| c == '-' || c.is_alphanumeric()),
+            char('?'),
+        ),
+        multispace0,
+    )
+    .map(|name: &str| name.to_string())
+    .parse(i)
+}
+
 fn kind_token(i: &str) -> IResult<&str, Kind, VerboseError<&str>> {
     terminated(raw_identifier, multispace0)
         .map(|id| {
@@ -0,0 +1,269 @@
+//! A parent-aware view over the CST, that can be used to navigate up and down the tree without a cursor.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+#[cfg(not(feature = "sync"))]
+use std::rc::Weak;
+#[cfg(feature = "sync")]
+use std::sync::Weak;
+
+use crate::cst::{LabeledNode, Node, Rc, RuleNode, TokenNode};
+use crate::cursor::Cursor;
+use crate::kinds::NodeLabel;
+use crate::text_index::{TextIndex, TextRange};
+
+/// A stable identity of a [`SyntaxNode`] within its tree.
+///
+/// Creating the same node again (for example, by navigating to it from a different node)
+/// results in the same identity, so it can be used to key side tables (e.g. a `HashMap<NodeId, _>`).
+/// Subtrees that are shared with a previous tree (see `Language::reparse`) keep their identities,
+/// as long as they are still at the same text offset.
+///
+/// An identity holds a weak reference to its node, so it never compares equal to a node of another tree,
+/// even after the original tree is dropped and its memory is reused.
+#[derive(Clone)]
+pub struct NodeId {
+    node: WeakNode,
+    text_offset: usize,
+}
+
+#[derive(Clone)]
+enum WeakNode {
+    Rule(Weak<RuleNode>),
+    Token(Weak<TokenNode>),
+}
+
+impl NodeId {
+    /// Whether the node this identity was created from is still alive.
+    pub fn is_alive(&self) -> bool {
+        match &self.node {
+            WeakNode::Rule(rule) => rule.strong_count() > 0,
+            WeakNode::Token(token) => token.strong_count() > 0,
+        }
+    }
+
+    /// The address of the node's allocation, which is kept (and can't be reused) while the weak reference exists.
+    fn address(&self) -> usize {
+        match &self.node {
+            WeakNode::Rule(rule) => Weak::as_ptr(rule) as usize,
+            WeakNode::Token(token) => Weak::as_ptr(token) as usize,
+        }
+    }
+}
+
+impl PartialEq for NodeId {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address() && self.text_offset == other.text_offset
+    }
+}
+
+impl Eq for NodeId {}
+
+impl Hash for NodeId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+        self.text_offset.hash(state);
+    }
+}
+
+impl fmt::Debug for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeId")
+            .field("address", &format_args!("{:#x}", self.address()))
+            .field("text_offset", &self.text_offset)
+            .field("is_alive", &self.is_alive())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A CST node along with its position in the tree: its parent, label, and absolute text offset.
+///
+/// Unlike [`Node`], equality is based on the node's identity rather than its contents,
+/// so two structurally equal subtrees at different positions are not equal.
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<SyntaxNodeData>);
+
+struct SyntaxNodeData {
+    parent: Option<SyntaxNode>,
+    child_number: usize,
+    label: Option<NodeLabel>,
+    text_offset: TextIndex,
+    node: Node,
+}
+
+impl SyntaxNode {
+    /// Creates the root of a new tree, starting at the beginning of the source text.
+    pub fn new_root(node: Node) -> Self {
+        Self::new_root_with_offset(node, TextIndex::ZERO)
+    }
+
+    pub(crate) fn new_root_with_offset(node: Node, text_offset: TextIndex) -> Self {
+        Self(Rc::new(SyntaxNodeData {
+            parent: None,
+            child_number: 0,
+            label: None,
+            text_offset,
+            node,
+        }))
+    }
+
+    pub(crate) fn new_child(
+        parent: SyntaxNode,
+        child_number: usize,
+        text_offset: TextIndex,
+    ) -> Self {
+        let LabeledNode { label, node } = parent.0.node.children()[child_number].clone();
+
+        Self(Rc::new(SyntaxNodeData {
+            parent: Some(parent),
+            child_number,
+            label,
+            text_offset,
+            node,
+        }))
+    }
+
+    /// Returns the identity of this node within its tree.
+    pub fn id(&self) -> NodeId {
+        let node = match &self.0.node {
+            Node::Rule(rule) => WeakNode::Rule(Rc::downgrade(rule)),
+            Node::Token(token) => WeakNode::Token(Rc::downgrade(token)),
+        };
+
+        NodeId {
+            node,
+            text_offset: self.0.text_offset.utf8,
+        }
+    }
+
+    fn address(&self) -> usize {
+        match &self.0.node {
+            Node::Rule(rule) => Rc::as_ptr(rule) as usize,
+            Node::Token(token) => Rc::as_ptr(token) as usize,
+        }
+    }
+
+    /// Returns the underlying CST [`Node`].
+    pub fn node(&self) -> &Node {
+        &self.0.node
+    }
+
+    /// Returns the label of this node in its parent, if any.
+    pub fn label(&self) -> Option<NodeLabel> {
+        self.0.label
+    }
+
+    /// Returns the parent of this node, or `None` if it is the root.
+    pub fn parent(&self) -> Option<&SyntaxNode> {
+        self.0.parent.as_ref()
+    }
+
+    /// Returns an iterator over the node's ancestors, starting from its parent.
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        std::iter::successors(self.parent().cloned(), |node| node.parent().cloned())
+    }
+
+    /// Returns the index of this node in its parent's children.
+    pub fn child_number(&self) -> usize {
+        self.0.child_number
+    }
+
+    /// Returns the text offset that corresponds to the beginning of this node.
+    pub fn text_offset(&self) -> TextIndex {
+        self.0.text_offset
+    }
+
+    /// Returns the text range that corresponds to this node.
+    pub fn text_range(&self) -> TextRange {
+        let start = self.0.text_offset;
+        let end = start + self.0.node.text_len();
+        start..end
+    }
+
+    /// Returns an iterator over the children (not all descendants) of this node.
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        let mut text_offset = self.0.text_offset;
+
+        self.0
+            .node
+            .children()
+            .iter()
+            .enumerate()
+            .map(move |(child_number, child)| {
+                let child_offset = text_offset;
+                text_offset += child.text_len();
+                Self::new_child(self.clone(), child_number, child_offset)
+            })
+    }
+
+    /// Returns the nth child of this node, if any.
+    pub fn nth_child(&self, child_number: usize) -> Option<SyntaxNode> {
+        let children = self.0.node.children();
+        if child_number >= children.len() {
+            return None;
+        }
+
+        let text_offset = self.0.text_offset
+            + children[..child_number]
+                .iter()
+                .map(|child| child.text_len())
+                .sum();
+
+        Some(Self::new_child(self.clone(), child_number, text_offset))
+    }
+
+    /// Returns the next sibling of this node, if any.
+    pub fn next_sibling(&self) -> Option<SyntaxNode> {
+        let parent = self.parent()?;
+        if self.0.child_number + 1 >= parent.0.node.children().len() {
+            return None;
+        }
+
+        let text_offset = self.0.text_offset + self.0.node.text_len();
+        Some(Self::new_child(
+            parent.clone(),
+            self.0.child_number + 1,
+            text_offset,
+        ))
+    }
+
+    /// Returns the previous sibling of this node, if any.
+    pub fn previous_sibling(&self) -> Option<SyntaxNode> {
+        let parent = self.parent()?;
+        let child_number = self.0.child_number.checked_sub(1)?;
+
+        let text_offset = self.0.text_offset - parent.0.node.children()[child_number].text_len();
+        Some(Self::new_child(parent.clone(), child_number, text_offset))
+    }
+
+    /// Creates a [`Cursor`] that starts at this node as the root, with its absolute text offset.
+    pub fn create_cursor(&self) -> Cursor {
+        self.0.node.cursor_with_offset(self.0.text_offset)
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &Self) -> bool {
+        // Both nodes are alive, so comparing their addresses is enough:
+        self.address() == other.address() && self.0.text_offset.utf8 == other.0.text_offset.utf8
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl Hash for SyntaxNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+        self.0.text_offset.utf8.hash(state);
+    }
+}
+
+impl fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxNode")
+            .field("label", &self.0.label)
+            .field("text_range", &self.text_range())
+            .field("node", &self.0.node)
+            .finish_non_exhaustive()
+    }
+}
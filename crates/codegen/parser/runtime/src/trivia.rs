@@ -0,0 +1,153 @@
+//! Attaching trivia (comments and blank lines) to the nodes they describe.
+
+use crate::cst::{Node, Rc, TokenNode};
+use crate::cursor::Cursor;
+use crate::kinds::NodeLabel;
+use crate::text_index::TextRange;
+
+/// A comment token, along with its position in the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub token: Rc<TokenNode>,
+    pub text_range: TextRange,
+}
+
+/// The trivia attached to a node, returned by [`Cursor::attached_trivia`].
+///
+/// Ownership follows the trivia already collected by the parser around each token:
+///
+/// - Leading trivia before the first (non-trivia) token of a node belongs to that node. Of its comments,
+///   the ones directly preceding the node (without a blank line in between) are its `leading_comments`,
+///   and any earlier ones (e.g. a file header) are its `detached_comments`.
+/// - Trailing trivia after the last (non-trivia) token of a node belongs to that node,
+///   which is the rest of the line the node ends on.
+///
+/// All nodes that start (or end) at the same token share the same trivia, so it is usually
+/// queried on the outermost node of interest (e.g. a contract member, rather than its type name).
+/// Comments that precede a closing delimiter (e.g. `}`) are attached to the delimiter itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AttachedTrivia {
+    /// Comments before the node, separated from it by at least one blank line.
+    pub detached_comments: Vec<Comment>,
+    /// Comments directly before the node, without any blank lines between them and the node.
+    pub leading_comments: Vec<Comment>,
+    /// Comments after the node, on the same line.
+    pub trailing_comments: Vec<Comment>,
+    /// Number of blank lines before the node, or before its first leading comment if any.
+    pub blank_lines_before: usize,
+}
+
+pub(crate) fn attached_trivia(cursor: &Cursor) -> AttachedTrivia {
+    let Some((first, last)) = non_trivia_bounds(cursor) else {
+        return AttachedTrivia::default();
+    };
+
+    let mut trivia = AttachedTrivia::default();
+
+    // Collect the leading trivia backwards, up to the previous token:
+    let mut leading = vec![];
+    let mut previous = first;
+    let mut is_line_start = true;
+    while previous.go_to_previous_token() {
+        let Node::Token(token) = previous.node() else {
+            unreachable!("the cursor only stops at tokens");
+        };
+
+        if previous.label() != Some(NodeLabel::LeadingTrivia) {
+            is_line_start = token.text.ends_with(['\n', '\r']);
+            break;
+        }
+
+        leading.push(Comment {
+            token,
+            text_range: previous.text_range(),
+        });
+    }
+
+    let mut blank_lines = 0;
+    for trivia_token in leading.into_iter().rev() {
+        if is_comment(&trivia_token.token) {
+            if blank_lines > 0 {
+                trivia
+                    .detached_comments
+                    .append(&mut trivia.leading_comments);
+                trivia.blank_lines_before = blank_lines;
+            } else if trivia.leading_comments.is_empty() {
+                trivia.blank_lines_before = 0;
+            }
+
+            trivia.leading_comments.push(trivia_token);
+            blank_lines = 0;
+            is_line_start = false;
+            continue;
+        }
+
+        for _ in 0..line_breaks(&trivia_token.token.text) {
+            if is_line_start {
+                blank_lines += 1;
+            }
+            is_line_start = true;
+        }
+    }
+
+    // Comments separated from the node itself by a blank line are detached as well:
+    if blank_lines > 0 || trivia.leading_comments.is_empty() {
+        trivia
+            .detached_comments
+            .append(&mut trivia.leading_comments);
+        trivia.blank_lines_before = blank_lines;
+    }
+
+    // Then the trailing trivia forwards, up to the next token:
+    let mut next = last;
+    while next.go_to_next_token() && next.label() == Some(NodeLabel::TrailingTrivia) {
+        let Node::Token(token) = next.node() else {
+            unreachable!("the cursor only stops at tokens");
+        };
+
+        if is_comment(&token) {
+            trivia.trailing_comments.push(Comment {
+                token,
+                text_range: next.text_range(),
+            });
+        }
+    }
+
+    trivia
+}
+
+/// Returns cursors pointing to the first and last non-trivia tokens of the current node, if any.
+fn non_trivia_bounds(cursor: &Cursor) -> Option<(Cursor, Cursor)> {
+    let range = cursor.text_range();
+    let is_within = |other: &Cursor| {
+        let other_range = other.text_range();
+        range.start <= other_range.start && other_range.end <= range.end
+    };
+
+    match cursor.node() {
+        Node::Token(token) if token.kind.is_trivia() => None,
+        Node::Token(_) => Some((cursor.clone(), cursor.clone())),
+        Node::Rule(_) => {
+            let mut first = cursor.clone();
+            if !first.go_to_next_non_trivia_token() || !is_within(&first) {
+                return None;
+            }
+
+            let mut last = cursor.clone();
+            while last.go_to_last_child() {}
+            if last.node().is_rule() || last.node().is_trivia() {
+                last.go_to_previous_non_trivia_token();
+            }
+
+            Some((first, last))
+        }
+    }
+}
+
+fn is_comment(token: &TokenNode) -> bool {
+    !token.text.chars().all(char::is_whitespace)
+}
+
+fn line_breaks(text: &str) -> usize {
+    text.matches('\n').count() + text.matches('\r').count() - text.matches("\r\n").count()
+}
@@ -0,0 +1 @@
+//! Generated by the templating engine.
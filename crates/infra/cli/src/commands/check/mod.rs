@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::{Parser, ValueEnum};
 use infra_utils::cargo::CargoWorkspace;
+use infra_utils::commands::Command;
 use infra_utils::terminal::Terminal;
 
 use crate::toolchains::mkdocs::Mkdocs;
@@ -48,7 +49,30 @@ fn check_cargo() -> Result<()> {
     // 'cargo clippy' will run both 'cargo check', and 'clippy' lints:
     CargoWorkspace::get_command("clippy")?
         .flag("--all-targets")
-        .run()
+        .run()?;
+
+    // Features are conflated across the workspace above, so also check that the 'sync' feature
+    // (backing the CST with 'Arc') builds on its own for the published crates, and their tests:
+    for (package, tests) in [
+        ("slang_solidity", "solidity_cargo_tests"),
+        ("slang_testlang", "testlang_cargo_tests"),
+    ] {
+        Command::new("cargo")
+            .arg("check")
+            .property("--package", package)
+            .property("--features", "sync")
+            .flag("--all-targets")
+            .run()?;
+
+        Command::new("cargo")
+            .arg("check")
+            .property("--package", tests)
+            .property("--features", format!("{package}/sync"))
+            .flag("--all-targets")
+            .run()?;
+    }
+
+    Ok(())
 }
 
 fn check_rustdoc() -> Result<()> {
@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+
+use crate::cst::{LabeledNode, Node, Rc, RuleNode, TokenNode};
+use crate::formatter::doc::Doc;
+use crate::formatter::spacing::{needs_space, TokenContext};
+use crate::formatter::FormatterOptions;
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+
+/// Declarations with a body in braces, where each member is printed on its own line.
+const BLOCK_KINDS: &[RuleKind] = &[
+    RuleKind::ContractDefinition,
+    RuleKind::InterfaceDefinition,
+    RuleKind::LibraryDefinition,
+    RuleKind::StructDefinition,
+    RuleKind::EnumDefinition,
+    RuleKind::Block,
+];
+
+/// Lists where each item is printed on its own line.
+const LINE_LIST_KINDS: &[RuleKind] = &[
+    RuleKind::SourceUnitMembers,
+    RuleKind::ContractMembers,
+    RuleKind::InterfaceMembers,
+    RuleKind::LibraryMembers,
+    RuleKind::StructMembers,
+    RuleKind::EnumMembers,
+    RuleKind::Statements,
+    RuleKind::YulStatements,
+    RuleKind::YulSwitchCases,
+];
+
+/// Comma-separated lists in delimiters (parentheses, brackets, or braces),
+/// that are broken into one item per line if they don't fit on a single line.
+const DELIMITED_LIST_KINDS: &[RuleKind] = &[
+    RuleKind::Parameters,
+    RuleKind::PositionalArguments,
+    RuleKind::NamedArguments,
+    RuleKind::CallOptions,
+    RuleKind::TupleValues,
+    RuleKind::ArrayValues,
+    RuleKind::TupleDeconstructionElements,
+    RuleKind::ImportDeconstructionSymbols,
+    RuleKind::UsingDeconstructionSymbols,
+    RuleKind::OverridePaths,
+    RuleKind::EventParameters,
+    RuleKind::ErrorParameters,
+    RuleKind::AssemblyFlags,
+    RuleKind::YulParameters,
+    RuleKind::YulArguments,
+];
+
+/// Where comments and empty lines appeared in the original source.
+#[derive(Clone, Copy, Debug, Default)]
+struct Placement {
+    /// Preceded by an empty line.
+    blank_line_before: bool,
+    /// Preceded by a newline (or at the start of the file).
+    own_line: bool,
+    /// Followed by a newline.
+    newline_after: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Previous<'a> {
+    Token(&'a TokenNode, RuleKind, Option<NodeLabel>),
+    Comment,
+}
+
+/// Converts a CST into a [`Doc`], deciding how tokens are separated, and where lines can be broken.
+pub(super) struct Builder<'a> {
+    options: &'a FormatterOptions,
+    placements: HashMap<*const TokenNode, Placement>,
+    docs: Vec<Doc>,
+    previous: Option<Previous<'a>>,
+    /// Whether a separator (space or line) was already emitted since the previous token.
+    separated: bool,
+    /// Replaces the empty line before the next token, if set.
+    blank_line_override: Option<bool>,
+}
+
+impl<'a> Builder<'a> {
+    pub(super) fn new(options: &'a FormatterOptions, root: &'a Node) -> Self {
+        let mut builder = Self {
+            options,
+            placements: HashMap::new(),
+            docs: vec![],
+            previous: None,
+            separated: true,
+            blank_line_override: None,
+        };
+
+        let mut state = PlacementState::default();
+        builder.collect_placements(root, &mut state);
+
+        builder
+    }
+
+    pub(super) fn build(mut self, root: &'a Node) -> Doc {
+        self.node(root, None, RuleKind::SourceUnit);
+        Doc::Concat(self.docs)
+    }
+
+    fn collect_placements(&mut self, node: &'a Node, state: &mut PlacementState) {
+        match node {
+            Node::Rule(rule) => {
+                for child in &rule.children {
+                    self.collect_placements(&child.node, state);
+                }
+            }
+            Node::Token(token) => match token.kind {
+                TokenKind::Whitespace => {}
+                TokenKind::EndOfLine => {
+                    state.newlines += 1;
+
+                    if let Some(comment) = state.last_comment.take() {
+                        if let Some(placement) = self.placements.get_mut(&comment) {
+                            placement.newline_after = true;
+                        }
+                    }
+                }
+                kind => {
+                    self.placements.insert(
+                        Rc::as_ptr(token),
+                        Placement {
+                            blank_line_before: state.newlines >= 2,
+                            own_line: state.newlines > 0 || !state.started,
+                            newline_after: false,
+                        },
+                    );
+
+                    state.started = true;
+                    state.newlines = 0;
+                    state.last_comment = kind.is_trivia().then_some(Rc::as_ptr(token));
+                }
+            },
+        }
+    }
+
+    fn placement(&self, token: &Rc<TokenNode>) -> Placement {
+        self.placements
+            .get(&Rc::as_ptr(token))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    //
+    // Emitting documents:
+    //
+
+    fn push(&mut self, doc: Doc) {
+        if matches!(doc, Doc::Line | Doc::SoftLine | Doc::HardLine) {
+            self.separated = true;
+        }
+
+        self.docs.push(doc);
+    }
+
+    /// Collects the documents emitted by `build` into a separate list, to be wrapped in an indent or group.
+    fn nested(&mut self, build: impl FnOnce(&mut Self)) -> Vec<Doc> {
+        let outer = std::mem::take(&mut self.docs);
+        build(self);
+        std::mem::replace(&mut self.docs, outer)
+    }
+
+    fn push_text(&mut self, token: &Rc<TokenNode>) {
+        let blank_line_before = self
+            .blank_line_override
+            .take()
+            .unwrap_or_else(|| self.placement(token).blank_line_before);
+
+        self.docs.push(Doc::Text {
+            text: token.text.clone(),
+            blank_line_before,
+        });
+        self.separated = false;
+    }
+
+    fn node(&mut self, node: &'a Node, label: Option<NodeLabel>, parent: RuleKind) {
+        match node {
+            Node::Rule(rule) => self.rule(rule),
+            Node::Token(token) if token.kind.is_trivia() => self.trivia(token),
+            Node::Token(token) => self.token(token, label, parent),
+        }
+    }
+
+    fn children(&mut self, rule: &'a RuleNode, children: &'a [LabeledNode]) {
+        for child in children {
+            self.node(&child.node, child.label, rule.kind);
+        }
+    }
+
+    fn token(&mut self, token: &'a Rc<TokenNode>, label: Option<NodeLabel>, parent: RuleKind) {
+        let space = match self.previous {
+            _ if self.separated => false,
+            None => false,
+            Some(Previous::Comment) => true,
+            Some(Previous::Token(previous, previous_parent, previous_label)) => needs_space(
+                &TokenContext {
+                    kind: previous.kind,
+                    text: &previous.text,
+                    parent: previous_parent,
+                    label: previous_label,
+                },
+                &TokenContext {
+                    kind: token.kind,
+                    text: &token.text,
+                    parent,
+                    label,
+                },
+            ),
+        };
+
+        if space {
+            self.docs.push(Doc::text(" "));
+        }
+
+        self.push_text(token);
+        self.previous = Some(Previous::Token(token, parent, label));
+    }
+
+    fn trivia(&mut self, token: &'a Rc<TokenNode>) {
+        let placement = self.placement(token);
+
+        match token.kind {
+            TokenKind::SingleLineComment
+            | TokenKind::SingleLineNatSpecComment
+            | TokenKind::MultiLineComment
+            | TokenKind::MultiLineNatSpecComment => {}
+            _ => return,
+        }
+
+        if placement.own_line {
+            self.push(Doc::HardLine);
+        } else if !self.separated {
+            self.docs.push(Doc::text(" "));
+        }
+
+        self.push_text(token);
+        self.previous = Some(Previous::Comment);
+
+        let is_single_line = matches!(
+            token.kind,
+            TokenKind::SingleLineComment | TokenKind::SingleLineNatSpecComment
+        );
+        if is_single_line || placement.newline_after {
+            self.push(Doc::HardLine);
+        }
+    }
+
+    //
+    // Layout of rules:
+    //
+
+    fn rule(&mut self, rule: &'a RuleNode) {
+        if BLOCK_KINDS.contains(&rule.kind) {
+            self.block(rule);
+        } else if rule.kind == RuleKind::YulBlock {
+            self.yul_block(rule);
+        } else if LINE_LIST_KINDS.contains(&rule.kind) {
+            self.line_list(rule);
+        } else if DELIMITED_LIST_KINDS.contains(&rule.kind) {
+            self.delimited_list(rule);
+        } else if rule
+            .children
+            .iter()
+            .any(|child| matches!(&child.node, Node::Rule(list) if DELIMITED_LIST_KINDS.contains(&list.kind)))
+        {
+            self.delimited(rule);
+        } else {
+            self.children(rule, &rule.children);
+        }
+    }
+
+    /// Splits the children of a rule around its delimiters, returning the indices of the opening and closing ones.
+    fn find_delimiters(
+        rule: &RuleNode,
+        open: TokenKind,
+        close: TokenKind,
+    ) -> Option<(usize, usize)> {
+        let open = rule
+            .children
+            .iter()
+            .position(|child| child.node.is_token_with_kind(open))?;
+        let close = rule
+            .children
+            .iter()
+            .rposition(|child| child.node.is_token_with_kind(close))?;
+
+        (open < close).then_some((open, close))
+    }
+
+    /// Bodies in braces, with each member on its own line:
+    ///
+    /// ```text
+    /// contract Foo {
+    ///     uint x;
+    /// }
+    /// ```
+    fn block(&mut self, rule: &'a RuleNode) {
+        let Some((open, close)) =
+            Self::find_delimiters(rule, TokenKind::OpenBrace, TokenKind::CloseBrace)
+        else {
+            self.children(rule, &rule.children);
+            return;
+        };
+
+        self.children(rule, &rule.children[..=open]);
+
+        // Comments on the same line as the opening brace stay there:
+        let mut inner = &rule.children[open + 1..close];
+        while let Some((first, rest)) = inner.split_first() {
+            match &first.node {
+                Node::Token(token) if token.kind.is_trivia() && !self.placement(token).own_line => {
+                    self.trivia(token);
+                    inner = rest;
+                }
+                _ => break,
+            }
+        }
+
+        if !is_empty(inner) {
+            let docs = self.nested(|builder| {
+                builder.push(Doc::HardLine);
+                builder.children(rule, inner);
+            });
+
+            self.push(Doc::indent(docs));
+            self.push(Doc::HardLine);
+        }
+
+        self.children(rule, &rule.children[close..]);
+    }
+
+    /// Yul blocks are kept on a single line if they only contain a single short statement: `if x { y := 1 }`
+    fn yul_block(&mut self, rule: &'a RuleNode) {
+        let Some((open, close)) =
+            Self::find_delimiters(rule, TokenKind::OpenBrace, TokenKind::CloseBrace)
+        else {
+            self.children(rule, &rule.children);
+            return;
+        };
+
+        let docs = self.nested(|builder| {
+            builder.children(rule, &rule.children[..=open]);
+
+            let inner = &rule.children[open + 1..close];
+            if !is_empty(inner) {
+                let docs = builder.nested(|builder| {
+                    builder.push(Doc::Line);
+                    builder.children(rule, inner);
+                });
+
+                builder.push(Doc::indent(docs));
+                builder.push(Doc::Line);
+            }
+
+            builder.children(rule, &rule.children[close..]);
+        });
+
+        self.docs.push(Doc::group(docs));
+    }
+
+    /// Rules containing a delimited list (e.g. parameters or arguments), which is either printed on a single line:
+    ///
+    /// ```text
+    /// foo(a, b)
+    /// ```
+    ///
+    /// Or broken into one item per line:
+    ///
+    /// ```text
+    /// foo(
+    ///     a,
+    ///     b
+    /// )
+    /// ```
+    fn delimited(&mut self, rule: &'a RuleNode) {
+        let delimiters = [
+            (TokenKind::OpenParen, TokenKind::CloseParen),
+            (TokenKind::OpenBracket, TokenKind::CloseBracket),
+            (TokenKind::OpenBrace, TokenKind::CloseBrace),
+        ];
+
+        let Some((open, close)) = delimiters
+            .into_iter()
+            .find_map(|(open, close)| Self::find_delimiters(rule, open, close))
+        else {
+            self.children(rule, &rule.children);
+            return;
+        };
+
+        let docs = self.nested(|builder| {
+            builder.children(rule, &rule.children[..=open]);
+
+            let inner = &rule.children[open + 1..close];
+            if !is_empty(inner) {
+                let docs = builder.nested(|builder| {
+                    builder.push(Doc::SoftLine);
+                    builder.children(rule, inner);
+                });
+
+                builder.push(Doc::indent(docs));
+                builder.push(Doc::SoftLine);
+            }
+
+            builder.children(rule, &rule.children[close..]);
+        });
+
+        self.docs.push(Doc::group(docs));
+    }
+
+    /// Items of a delimited list, separated by a comma, and a line that is broken along with the enclosing group.
+    fn delimited_list(&mut self, rule: &'a RuleNode) {
+        let mut children = rule.children.iter().peekable();
+
+        while let Some(child) = children.next() {
+            self.node(&child.node, child.label, rule.kind);
+
+            if child.label == Some(NodeLabel::Separator) {
+                // Keep any trailing comments on the same line as the separator:
+                while let Some(trivia) =
+                    children.next_if(|next| next.label == Some(NodeLabel::TrailingTrivia))
+                {
+                    self.node(&trivia.node, trivia.label, rule.kind);
+                }
+
+                self.push(Doc::Line);
+            }
+        }
+    }
+
+    fn line_list(&mut self, rule: &'a RuleNode) {
+        let mut items: Vec<&LabeledNode> = rule.children.iter().collect();
+
+        let blank_line_overrides =
+            if rule.kind == RuleKind::SourceUnitMembers && self.options.sort_imports {
+                self.sort_imports(&mut items)
+            } else {
+                HashMap::new()
+            };
+
+        let mut is_first = true;
+        for (index, child) in items.into_iter().enumerate() {
+            if child.label == Some(NodeLabel::Item) {
+                // Each switch case starts on a new line, including the first one:
+                if !is_first || rule.kind == RuleKind::YulSwitchCases {
+                    self.push(Doc::HardLine);
+                }
+
+                is_first = false;
+            }
+
+            self.blank_line_override = blank_line_overrides.get(&index).copied();
+            self.node(&child.node, child.label, rule.kind);
+        }
+    }
+
+    /// Sorts each run of consecutive import directives by their path, returning the empty lines to use
+    /// before the moved items: the run keeps the empty line before its original first item, if any.
+    /// Imports with leading comments are kept in place, as these can be file headers, or describe a specific import.
+    fn sort_imports(&self, items: &mut [&LabeledNode]) -> HashMap<usize, bool> {
+        let mut overrides = HashMap::new();
+
+        if items.iter().any(|item| item.label != Some(NodeLabel::Item)) {
+            return overrides;
+        }
+
+        let mut start = 0;
+        while start < items.len() {
+            let length = items[start..]
+                .iter()
+                .take_while(|item| {
+                    import_path(&item.node).is_some()
+                        && !first_significant_token(&item.node)
+                            .is_some_and(|token| token.kind.is_trivia())
+                })
+                .count();
+
+            if length < 2 {
+                start += length.max(1);
+                continue;
+            }
+
+            let run = &mut items[start..start + length];
+            let original: Vec<_> = run.iter().map(|item| std::ptr::from_ref(*item)).collect();
+            let blank_line_before = first_significant_token(&run[0].node)
+                .is_some_and(|token| self.placement(token).blank_line_before);
+
+            run.sort_by_cached_key(|item| import_path(&item.node));
+
+            if run
+                .iter()
+                .map(|item| std::ptr::from_ref(*item))
+                .ne(original)
+            {
+                overrides.insert(start, blank_line_before);
+                overrides.extend((start + 1..start + length).map(|index| (index, false)));
+            }
+
+            start += length;
+        }
+
+        overrides
+    }
+}
+
+#[derive(Default)]
+struct PlacementState {
+    started: bool,
+    newlines: usize,
+    last_comment: Option<*const TokenNode>,
+}
+
+/// Whether the children don't contain any tokens or comments.
+fn is_empty(children: &[LabeledNode]) -> bool {
+    children.iter().all(|child| match &child.node {
+        Node::Rule(rule) => is_empty(&rule.children),
+        Node::Token(token) => matches!(token.kind, TokenKind::Whitespace | TokenKind::EndOfLine),
+    })
+}
+
+/// Returns the path of an import directive, if the item is one.
+fn import_path(item: &Node) -> Option<String> {
+    let member = item.as_rule()?;
+    let directive = member.children.iter().find_map(|child| {
+        child
+            .node
+            .as_rule()
+            .filter(|rule| rule.kind == RuleKind::ImportDirective)
+    })?;
+
+    let mut stack = vec![directive];
+    while let Some(rule) = stack.pop() {
+        for child in rule.children.iter().rev() {
+            match &child.node {
+                Node::Rule(rule) => stack.push(rule),
+                Node::Token(token)
+                    if matches!(
+                        token.kind,
+                        TokenKind::DoubleQuotedStringLiteral | TokenKind::SingleQuotedStringLiteral
+                    ) =>
+                {
+                    return Some(token.text[1..token.text.len() - 1].to_owned());
+                }
+                Node::Token(_) => {}
+            }
+        }
+    }
+
+    None
+}
+
+fn first_significant_token(node: &Node) -> Option<&Rc<TokenNode>> {
+    match node {
+        Node::Rule(rule) => rule
+            .children
+            .iter()
+            .find_map(|child| first_significant_token(&child.node)),
+        Node::Token(token) => {
+            (!matches!(token.kind, TokenKind::Whitespace | TokenKind::EndOfLine)).then_some(token)
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! A small document algebra for pretty printing, based on Wadler's "A prettier printer":
+//! the builder describes the layout of the source as a tree of [`Doc`]s, and the printer chooses
+//! where to break lines, so that each group fits within the line width if possible.
+
+#[derive(Clone, Debug)]
+pub(super) enum Doc {
+    /// Literal text. Only multi-line comments can contain newlines.
+    Text {
+        text: String,
+        /// Whether this was preceded by an empty line in the original source.
+        /// Preserved (as a single empty line) if the text starts a new line.
+        blank_line_before: bool,
+    },
+    /// A space, or a newline if the enclosing group is broken.
+    Line,
+    /// Nothing, or a newline if the enclosing group is broken.
+    SoftLine,
+    /// Always a newline. Forces all enclosing groups to break.
+    HardLine,
+    /// Increases the indentation of any newlines inside it.
+    Indent(Box<Doc>),
+    /// Printed on a single line if it fits within the line width, or broken otherwise.
+    Group {
+        doc: Box<Doc>,
+        should_break: bool,
+    },
+    Concat(Vec<Doc>),
+}
+
+impl Doc {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            blank_line_before: false,
+        }
+    }
+
+    pub fn indent(docs: Vec<Doc>) -> Self {
+        Self::Indent(Box::new(Self::Concat(docs)))
+    }
+
+    pub fn group(docs: Vec<Doc>) -> Self {
+        let doc = Self::Concat(docs);
+        let should_break = doc.has_forced_break();
+
+        Self::Group {
+            doc: Box::new(doc),
+            should_break,
+        }
+    }
+
+    fn has_forced_break(&self) -> bool {
+        match self {
+            Self::Text { text, .. } => text.contains('\n'),
+            Self::Line | Self::SoftLine => false,
+            Self::HardLine => true,
+            Self::Indent(doc) => doc.has_forced_break(),
+            Self::Group { should_break, .. } => *should_break,
+            Self::Concat(docs) => docs.iter().any(Self::has_forced_break),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+pub(super) fn print(doc: &Doc, line_width: usize, indent_width: usize) -> String {
+    let mut printer = Printer {
+        output: String::new(),
+        column: 0,
+        pending_newline: None,
+    };
+
+    let mut stack = vec![(0, Mode::Break, doc)];
+
+    while let Some((indent, mode, doc)) = stack.pop() {
+        match doc {
+            Doc::Text {
+                text,
+                blank_line_before,
+            } => printer.write(text, *blank_line_before),
+            Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                if matches!(doc, Doc::Line) {
+                    printer.write(" ", false);
+                }
+            }
+            Doc::Line | Doc::SoftLine | Doc::HardLine => printer.pending_newline = Some(indent),
+            Doc::Indent(doc) => stack.push((indent + indent_width, mode, doc)),
+            Doc::Group { doc, should_break } => {
+                let mode = if *should_break {
+                    Mode::Break
+                } else if fits(
+                    (indent, Mode::Flat, doc),
+                    &stack,
+                    line_width.saturating_sub(printer.column),
+                ) {
+                    Mode::Flat
+                } else {
+                    Mode::Break
+                };
+
+                stack.push((indent, mode, doc));
+            }
+            Doc::Concat(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+        }
+    }
+
+    if !printer.output.is_empty() {
+        printer.output.push('\n');
+    }
+
+    printer.output
+}
+
+struct Printer {
+    output: String,
+    column: usize,
+    /// Newlines are only written before the next text, so that consecutive ones are merged,
+    /// and trailing whitespace is never produced. Holds the indentation of the next line.
+    pending_newline: Option<usize>,
+}
+
+impl Printer {
+    fn write(&mut self, text: &str, blank_line_before: bool) {
+        if let Some(indent) = self.pending_newline {
+            if text.trim().is_empty() {
+                return;
+            }
+
+            self.pending_newline = None;
+
+            if !self.output.is_empty() {
+                self.output
+                    .truncate(self.output.trim_end_matches(' ').len());
+                self.output.push('\n');
+
+                // Empty lines are not preserved at the start or end of a block:
+                let after_open = self.output.trim_end().ends_with(['{', '(', '[']);
+                let before_close = text.starts_with(['}', ')', ']']);
+                if blank_line_before && !after_open && !before_close {
+                    self.output.push('\n');
+                }
+
+                self.output.extend(std::iter::repeat(' ').take(indent));
+                self.column = indent;
+            }
+        }
+
+        if self.output.is_empty() && text.trim().is_empty() {
+            return;
+        }
+
+        self.output.push_str(text);
+
+        match text.rfind('\n') {
+            Some(index) => self.column = text[index + 1..].chars().count(),
+            None => self.column += text.chars().count(),
+        }
+    }
+}
+
+/// Checks whether the next document fits in the remaining width when printed flat,
+/// along with the rest of the current line (until the next possible line break).
+fn fits(next: (usize, Mode, &Doc), rest: &[(usize, Mode, &Doc)], width: usize) -> bool {
+    let mut remaining = isize::try_from(width).unwrap_or(isize::MAX);
+    let mut rest = rest.iter().rev();
+    let mut stack = vec![next];
+
+    loop {
+        let Some((indent, mode, doc)) = stack.pop().or_else(|| rest.next().copied()) else {
+            return true;
+        };
+
+        match doc {
+            Doc::Text { text, .. } => {
+                let first_line = text.split('\n').next().unwrap_or_default();
+                remaining -= isize::try_from(first_line.chars().count()).unwrap_or(isize::MAX);
+
+                if text.contains('\n') {
+                    return remaining >= 0;
+                }
+            }
+            Doc::Line if mode == Mode::Flat => remaining -= 1,
+            Doc::SoftLine if mode == Mode::Flat => {}
+            Doc::Line | Doc::SoftLine | Doc::HardLine => return true,
+            Doc::Indent(doc) => stack.push((indent, mode, doc)),
+            Doc::Group { doc, should_break } => {
+                let mode = if *should_break { Mode::Break } else { mode };
+                stack.push((indent, mode, doc));
+            }
+            Doc::Concat(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+        }
+
+        if remaining < 0 {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Formatting Solidity source files into a canonical layout.
+//!
+//! The formatter walks the CST of a source unit, and only changes its trivia: tokens are never added, removed,
+//! or reordered, so the output parses to the same tree (ignoring trivia). The only exception is sorting
+//! consecutive import directives, which is opt-in through [`FormatterOptions::sort_imports`].
+//! All comments are preserved, while whitespace is normalized:
+//!
+//! - Members of contracts, structs, enums, and blocks are printed on their own lines, with opening braces
+//!   on the same line as their declaration. At most one empty line is kept between them.
+//! - Tokens are separated by single spaces, except around delimiters, member accesses, and unary operators.
+//! - Lists of parameters, arguments, and other comma-separated items are broken into one item per line
+//!   if they don't fit within the line width.
+
+mod builder;
+mod doc;
+mod spacing;
+
+use crate::parse_output::ParseOutput;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatterOptions {
+    /// The maximum width of a line, which lists are broken to fit in. Defaults to `80`.
+    pub line_width: usize,
+    /// The number of spaces used for each level of indentation. Defaults to `4`.
+    pub indent_width: usize,
+    /// Whether to sort consecutive import directives by their path. Defaults to `false`, since it reorders
+    /// the directives, so the output no longer parses to the same tree.
+    pub sort_imports: bool,
+}
+
+impl Default for FormatterOptions {
+    fn default() -> Self {
+        Self {
+            line_width: 80,
+            indent_width: 4,
+            sort_imports: false,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FormatError {
+    #[error("Cannot format a parse tree with {0} syntax error(s).")]
+    SyntaxErrors(usize),
+}
+
+/// Formats the source code of the parse output. Only parse outputs without any syntax errors can be formatted.
+pub fn format(output: &ParseOutput, options: &FormatterOptions) -> Result<String, FormatError> {
+    if !output.is_valid() {
+        return Err(FormatError::SyntaxErrors(output.errors().len()));
+    }
+
+    let root = output.tree();
+    let doc = builder::Builder::new(options, &root).build(&root);
+
+    Ok(doc::print(&doc, options.line_width, options.indent_width))
+}
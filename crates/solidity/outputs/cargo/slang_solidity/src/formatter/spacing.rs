@@ -0,0 +1,85 @@
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+
+/// A non-trivia token, along with where it appears in the tree.
+#[derive(Clone, Debug)]
+pub(super) struct TokenContext<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub parent: RuleKind,
+    pub label: Option<NodeLabel>,
+}
+
+impl TokenContext<'_> {
+    fn is_operator_of(&self, parent: RuleKind) -> bool {
+        self.parent == parent && self.label == Some(NodeLabel::Operator)
+    }
+}
+
+/// Whether a space should separate two adjacent tokens printed on the same line.
+pub(super) fn needs_space(previous: &TokenContext<'_>, next: &TokenContext<'_>) -> bool {
+    use TokenKind::{
+        CloseBrace, CloseBracket, CloseParen, Colon, Comma, OpenBrace, OpenBracket, OpenParen,
+        Period, Semicolon,
+    };
+
+    match (previous.kind, next.kind) {
+        (_, CloseParen | CloseBracket | Comma | Semicolon | Period) => false,
+        (OpenParen | OpenBracket | Period, _) => false,
+        (OpenBrace, CloseBrace) => false,
+
+        // Calls, parameter lists, and other keywords that are directly followed by parentheses.
+        // Control flow statements (`if (...)`), `returns (...)`, and tuples are separated by a space:
+        (_, OpenParen) => match next.parent {
+            RuleKind::PositionalArgumentsDeclaration
+            | RuleKind::NamedArgumentsDeclaration
+            | RuleKind::TypeExpression
+            | RuleKind::MappingType
+            | RuleKind::OverridePathsDeclaration
+            | RuleKind::EventParametersDeclaration
+            | RuleKind::ErrorParametersDeclaration
+            | RuleKind::YulParametersDeclaration
+            | RuleKind::YulFunctionCallExpression => false,
+            RuleKind::ParametersDeclaration => matches!(
+                previous.kind,
+                TokenKind::ReturnsKeyword | TokenKind::CatchKeyword
+            ),
+            _ => true,
+        },
+
+        // Index accesses and array types (`a[0]`, `uint[]`), but not array literals (`= [1, 2]`):
+        (_, OpenBracket) => !matches!(
+            next.parent,
+            RuleKind::IndexAccessExpression | RuleKind::ArrayTypeName
+        ),
+
+        // Call options: `foo{value: 1}`
+        (_, OpenBrace) => next.parent != RuleKind::CallOptionsExpression,
+
+        // Named arguments (`a: 1`), index ranges (`a[1:2]`), and Yul labels:
+        (_, Colon) => !matches!(
+            next.parent,
+            RuleKind::NamedArgument | RuleKind::IndexAccessEnd | RuleKind::YulLabel
+        ),
+        (Colon, _) => !matches!(
+            previous.parent,
+            RuleKind::IndexAccessEnd | RuleKind::YulColonAndEqual
+        ),
+
+        _ if next.is_operator_of(RuleKind::PostfixExpression) => false,
+
+        // Prefix operators, unless they would be merged with the next token (`- -a` vs `--a`):
+        _ if previous.is_operator_of(RuleKind::PrefixExpression) => {
+            previous.kind == TokenKind::DeleteKeyword
+                || previous
+                    .text
+                    .chars()
+                    .last()
+                    .is_some_and(|last| matches!(last, '+' | '-') && next.text.starts_with(last))
+        }
+
+        // Version comparators: `^0.8.0`
+        _ if previous.is_operator_of(RuleKind::VersionComparator) => false,
+
+        _ => true,
+    }
+}
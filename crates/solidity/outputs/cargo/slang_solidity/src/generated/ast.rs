@@ -0,0 +1,8103 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! Strongly-typed wrappers over the CST nodes, lazily selecting their children on access.
+//!
+//! Every wrapper holds the underlying [`RuleNode`], and can be created from one with the matching
+//! [`RuleKind`] using the `cast` function. Accessing a required child of a node that was recovered
+//! from a syntax error (i.e. the child is missing) will panic.
+
+#![allow(clippy::too_many_lines)]
+
+use crate::cst::{Node, Rc, RuleNode, TokenNode};
+use crate::kinds::{NodeLabel, RuleKind};
+
+//
+// Sequences:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnit(Rc<RuleNode>);
+
+impl SourceUnit {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnit`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnit).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn members(&self) -> SourceUnitMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(SourceUnitMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PragmaDirective(Rc<RuleNode>);
+
+impl PragmaDirective {
+    /// Wraps the given CST node, if it has the [`RuleKind::PragmaDirective`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PragmaDirective).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn pragma_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::PragmaKeyword),
+            &self.0,
+            NodeLabel::PragmaKeyword,
+        )
+    }
+
+    pub fn pragma(&self) -> Pragma {
+        required(
+            select_rule(&self.0, NodeLabel::Pragma).and_then(Pragma::cast),
+            &self.0,
+            NodeLabel::Pragma,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ABICoderPragma(Rc<RuleNode>);
+
+impl ABICoderPragma {
+    /// Wraps the given CST node, if it has the [`RuleKind::ABICoderPragma`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ABICoderPragma).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn abicoder_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::AbicoderKeyword),
+            &self.0,
+            NodeLabel::AbicoderKeyword,
+        )
+    }
+
+    pub fn version(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Version),
+            &self.0,
+            NodeLabel::Version,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExperimentalPragma(Rc<RuleNode>);
+
+impl ExperimentalPragma {
+    /// Wraps the given CST node, if it has the [`RuleKind::ExperimentalPragma`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ExperimentalPragma).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn experimental_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ExperimentalKeyword),
+            &self.0,
+            NodeLabel::ExperimentalKeyword,
+        )
+    }
+
+    pub fn feature(&self) -> ExperimentalFeature {
+        required(
+            select_rule(&self.0, NodeLabel::Feature).and_then(ExperimentalFeature::cast),
+            &self.0,
+            NodeLabel::Feature,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionPragma(Rc<RuleNode>);
+
+impl VersionPragma {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionPragma`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionPragma).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn solidity_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::SolidityKeyword),
+            &self.0,
+            NodeLabel::SolidityKeyword,
+        )
+    }
+
+    pub fn sets(&self) -> VersionExpressionSets {
+        required(
+            select_rule(&self.0, NodeLabel::Sets).and_then(VersionExpressionSets::cast),
+            &self.0,
+            NodeLabel::Sets,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionRange(Rc<RuleNode>);
+
+impl VersionRange {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionRange`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionRange).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> VersionExpression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(VersionExpression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> VersionExpression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(VersionExpression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionComparator(Rc<RuleNode>);
+
+impl VersionComparator {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionComparator`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionComparator).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn operand(&self) -> VersionExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(VersionExpression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDirective(Rc<RuleNode>);
+
+impl ImportDirective {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportDirective`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportDirective).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn import_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ImportKeyword),
+            &self.0,
+            NodeLabel::ImportKeyword,
+        )
+    }
+
+    pub fn clause(&self) -> ImportClause {
+        required(
+            select_rule(&self.0, NodeLabel::Clause).and_then(ImportClause::cast),
+            &self.0,
+            NodeLabel::Clause,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathImport(Rc<RuleNode>);
+
+impl PathImport {
+    /// Wraps the given CST node, if it has the [`RuleKind::PathImport`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PathImport).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn path(&self) -> StringLiteral {
+        required(
+            select_rule(&self.0, NodeLabel::Path).and_then(StringLiteral::cast),
+            &self.0,
+            NodeLabel::Path,
+        )
+    }
+
+    pub fn alias(&self) -> Option<ImportAlias> {
+        select_rule(&self.0, NodeLabel::Alias).and_then(ImportAlias::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedImport(Rc<RuleNode>);
+
+impl NamedImport {
+    /// Wraps the given CST node, if it has the [`RuleKind::NamedImport`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NamedImport).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn asterisk(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Asterisk),
+            &self.0,
+            NodeLabel::Asterisk,
+        )
+    }
+
+    pub fn alias(&self) -> ImportAlias {
+        required(
+            select_rule(&self.0, NodeLabel::Alias).and_then(ImportAlias::cast),
+            &self.0,
+            NodeLabel::Alias,
+        )
+    }
+
+    pub fn from_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FromKeyword),
+            &self.0,
+            NodeLabel::FromKeyword,
+        )
+    }
+
+    pub fn path(&self) -> StringLiteral {
+        required(
+            select_rule(&self.0, NodeLabel::Path).and_then(StringLiteral::cast),
+            &self.0,
+            NodeLabel::Path,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDeconstruction(Rc<RuleNode>);
+
+impl ImportDeconstruction {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportDeconstruction`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportDeconstruction).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn symbols(&self) -> ImportDeconstructionSymbols {
+        required(
+            select_rule(&self.0, NodeLabel::Symbols).and_then(ImportDeconstructionSymbols::cast),
+            &self.0,
+            NodeLabel::Symbols,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+
+    pub fn from_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FromKeyword),
+            &self.0,
+            NodeLabel::FromKeyword,
+        )
+    }
+
+    pub fn path(&self) -> StringLiteral {
+        required(
+            select_rule(&self.0, NodeLabel::Path).and_then(StringLiteral::cast),
+            &self.0,
+            NodeLabel::Path,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDeconstructionSymbol(Rc<RuleNode>);
+
+impl ImportDeconstructionSymbol {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportDeconstructionSymbol`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportDeconstructionSymbol).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn alias(&self) -> Option<ImportAlias> {
+        select_rule(&self.0, NodeLabel::Alias).and_then(ImportAlias::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportAlias(Rc<RuleNode>);
+
+impl ImportAlias {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportAlias`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportAlias).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn as_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::AsKeyword),
+            &self.0,
+            NodeLabel::AsKeyword,
+        )
+    }
+
+    pub fn identifier(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Identifier),
+            &self.0,
+            NodeLabel::Identifier,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingDirective(Rc<RuleNode>);
+
+impl UsingDirective {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingDirective`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingDirective).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn using_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::UsingKeyword),
+            &self.0,
+            NodeLabel::UsingKeyword,
+        )
+    }
+
+    pub fn clause(&self) -> UsingClause {
+        required(
+            select_rule(&self.0, NodeLabel::Clause).and_then(UsingClause::cast),
+            &self.0,
+            NodeLabel::Clause,
+        )
+    }
+
+    pub fn for_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ForKeyword),
+            &self.0,
+            NodeLabel::ForKeyword,
+        )
+    }
+
+    pub fn target(&self) -> UsingTarget {
+        required(
+            select_rule(&self.0, NodeLabel::Target).and_then(UsingTarget::cast),
+            &self.0,
+            NodeLabel::Target,
+        )
+    }
+
+    pub fn global_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::GlobalKeyword)
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingDeconstruction(Rc<RuleNode>);
+
+impl UsingDeconstruction {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingDeconstruction`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingDeconstruction).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn symbols(&self) -> UsingDeconstructionSymbols {
+        required(
+            select_rule(&self.0, NodeLabel::Symbols).and_then(UsingDeconstructionSymbols::cast),
+            &self.0,
+            NodeLabel::Symbols,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingDeconstructionSymbol(Rc<RuleNode>);
+
+impl UsingDeconstructionSymbol {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingDeconstructionSymbol`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingDeconstructionSymbol).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn name(&self) -> IdentifierPath {
+        required(
+            select_rule(&self.0, NodeLabel::Name).and_then(IdentifierPath::cast),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn alias(&self) -> Option<UsingAlias> {
+        select_rule(&self.0, NodeLabel::Alias).and_then(UsingAlias::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingAlias(Rc<RuleNode>);
+
+impl UsingAlias {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingAlias`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingAlias).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn as_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::AsKeyword),
+            &self.0,
+            NodeLabel::AsKeyword,
+        )
+    }
+
+    pub fn operator(&self) -> UsingOperator {
+        required(
+            select_rule(&self.0, NodeLabel::Operator).and_then(UsingOperator::cast),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractDefinition(Rc<RuleNode>);
+
+impl ContractDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ContractDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ContractDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn abstract_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::AbstractKeyword)
+    }
+
+    pub fn contract_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ContractKeyword),
+            &self.0,
+            NodeLabel::ContractKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn inheritence(&self) -> Option<InheritanceSpecifier> {
+        select_rule(&self.0, NodeLabel::Inheritence).and_then(InheritanceSpecifier::cast)
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn members(&self) -> ContractMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(ContractMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InheritanceSpecifier(Rc<RuleNode>);
+
+impl InheritanceSpecifier {
+    /// Wraps the given CST node, if it has the [`RuleKind::InheritanceSpecifier`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::InheritanceSpecifier).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn is_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::IsKeyword),
+            &self.0,
+            NodeLabel::IsKeyword,
+        )
+    }
+
+    pub fn types(&self) -> InheritanceTypes {
+        required(
+            select_rule(&self.0, NodeLabel::Types).and_then(InheritanceTypes::cast),
+            &self.0,
+            NodeLabel::Types,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InheritanceType(Rc<RuleNode>);
+
+impl InheritanceType {
+    /// Wraps the given CST node, if it has the [`RuleKind::InheritanceType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::InheritanceType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> IdentifierPath {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(IdentifierPath::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn arguments(&self) -> Option<ArgumentsDeclaration> {
+        select_rule(&self.0, NodeLabel::Arguments).and_then(ArgumentsDeclaration::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceDefinition(Rc<RuleNode>);
+
+impl InterfaceDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::InterfaceDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::InterfaceDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn interface_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::InterfaceKeyword),
+            &self.0,
+            NodeLabel::InterfaceKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn inheritence(&self) -> Option<InheritanceSpecifier> {
+        select_rule(&self.0, NodeLabel::Inheritence).and_then(InheritanceSpecifier::cast)
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn members(&self) -> InterfaceMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(InterfaceMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryDefinition(Rc<RuleNode>);
+
+impl LibraryDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::LibraryDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::LibraryDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn library_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::LibraryKeyword),
+            &self.0,
+            NodeLabel::LibraryKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn members(&self) -> LibraryMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(LibraryMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructDefinition(Rc<RuleNode>);
+
+impl StructDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::StructDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StructDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn struct_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::StructKeyword),
+            &self.0,
+            NodeLabel::StructKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn members(&self) -> StructMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(StructMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructMember(Rc<RuleNode>);
+
+impl StructMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::StructMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StructMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumDefinition(Rc<RuleNode>);
+
+impl EnumDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::EnumDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EnumDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn enum_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::EnumKeyword),
+            &self.0,
+            NodeLabel::EnumKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn members(&self) -> EnumMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(EnumMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstantDefinition(Rc<RuleNode>);
+
+impl ConstantDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ConstantDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ConstantDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn constant_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ConstantKeyword),
+            &self.0,
+            NodeLabel::ConstantKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn equal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Equal),
+            &self.0,
+            NodeLabel::Equal,
+        )
+    }
+
+    pub fn value(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Value).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Value,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateVariableDefinition(Rc<RuleNode>);
+
+impl StateVariableDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::StateVariableDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StateVariableDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn attributes(&self) -> StateVariableAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(StateVariableAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn value(&self) -> Option<StateVariableDefinitionValue> {
+        select_rule(&self.0, NodeLabel::Value).and_then(StateVariableDefinitionValue::cast)
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateVariableDefinitionValue(Rc<RuleNode>);
+
+impl StateVariableDefinitionValue {
+    /// Wraps the given CST node, if it has the [`RuleKind::StateVariableDefinitionValue`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StateVariableDefinitionValue).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn equal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Equal),
+            &self.0,
+            NodeLabel::Equal,
+        )
+    }
+
+    pub fn value(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Value).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Value,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionDefinition(Rc<RuleNode>);
+
+impl FunctionDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn function_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FunctionKeyword),
+            &self.0,
+            NodeLabel::FunctionKeyword,
+        )
+    }
+
+    pub fn name(&self) -> FunctionName {
+        required(
+            select_rule(&self.0, NodeLabel::Name).and_then(FunctionName::cast),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> FunctionAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(FunctionAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn returns(&self) -> Option<ReturnsDeclaration> {
+        select_rule(&self.0, NodeLabel::Returns).and_then(ReturnsDeclaration::cast)
+    }
+
+    pub fn body(&self) -> FunctionBody {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(FunctionBody::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParametersDeclaration(Rc<RuleNode>);
+
+impl ParametersDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::ParametersDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ParametersDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(Parameters::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameter(Rc<RuleNode>);
+
+impl Parameter {
+    /// Wraps the given CST node, if it has the [`RuleKind::Parameter`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Parameter).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn storage_location(&self) -> Option<StorageLocation> {
+        select_rule(&self.0, NodeLabel::StorageLocation).and_then(StorageLocation::cast)
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverrideSpecifier(Rc<RuleNode>);
+
+impl OverrideSpecifier {
+    /// Wraps the given CST node, if it has the [`RuleKind::OverrideSpecifier`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::OverrideSpecifier).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn override_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OverrideKeyword),
+            &self.0,
+            NodeLabel::OverrideKeyword,
+        )
+    }
+
+    pub fn overridden(&self) -> Option<OverridePathsDeclaration> {
+        select_rule(&self.0, NodeLabel::Overridden).and_then(OverridePathsDeclaration::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverridePathsDeclaration(Rc<RuleNode>);
+
+impl OverridePathsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::OverridePathsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::OverridePathsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn paths(&self) -> OverridePaths {
+        required(
+            select_rule(&self.0, NodeLabel::Paths).and_then(OverridePaths::cast),
+            &self.0,
+            NodeLabel::Paths,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReturnsDeclaration(Rc<RuleNode>);
+
+impl ReturnsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::ReturnsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ReturnsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn returns_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ReturnsKeyword),
+            &self.0,
+            NodeLabel::ReturnsKeyword,
+        )
+    }
+
+    pub fn variables(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Variables).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Variables,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstructorDefinition(Rc<RuleNode>);
+
+impl ConstructorDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ConstructorDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ConstructorDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn constructor_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ConstructorKeyword),
+            &self.0,
+            NodeLabel::ConstructorKeyword,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> ConstructorAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(ConstructorAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn body(&self) -> Block {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Block::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnnamedFunctionDefinition(Rc<RuleNode>);
+
+impl UnnamedFunctionDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::UnnamedFunctionDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UnnamedFunctionDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn function_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FunctionKeyword),
+            &self.0,
+            NodeLabel::FunctionKeyword,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> UnnamedFunctionAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(UnnamedFunctionAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn body(&self) -> FunctionBody {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(FunctionBody::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FallbackFunctionDefinition(Rc<RuleNode>);
+
+impl FallbackFunctionDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::FallbackFunctionDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FallbackFunctionDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn fallback_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FallbackKeyword),
+            &self.0,
+            NodeLabel::FallbackKeyword,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> FallbackFunctionAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(FallbackFunctionAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn returns(&self) -> Option<ReturnsDeclaration> {
+        select_rule(&self.0, NodeLabel::Returns).and_then(ReturnsDeclaration::cast)
+    }
+
+    pub fn body(&self) -> FunctionBody {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(FunctionBody::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveFunctionDefinition(Rc<RuleNode>);
+
+impl ReceiveFunctionDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ReceiveFunctionDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ReceiveFunctionDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn receive_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ReceiveKeyword),
+            &self.0,
+            NodeLabel::ReceiveKeyword,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> ReceiveFunctionAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(ReceiveFunctionAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn body(&self) -> FunctionBody {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(FunctionBody::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifierDefinition(Rc<RuleNode>);
+
+impl ModifierDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ModifierDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ModifierDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn modifier_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ModifierKeyword),
+            &self.0,
+            NodeLabel::ModifierKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn parameters(&self) -> Option<ParametersDeclaration> {
+        select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast)
+    }
+
+    pub fn attributes(&self) -> ModifierAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(ModifierAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn body(&self) -> FunctionBody {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(FunctionBody::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifierInvocation(Rc<RuleNode>);
+
+impl ModifierInvocation {
+    /// Wraps the given CST node, if it has the [`RuleKind::ModifierInvocation`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ModifierInvocation).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn name(&self) -> IdentifierPath {
+        required(
+            select_rule(&self.0, NodeLabel::Name).and_then(IdentifierPath::cast),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn arguments(&self) -> Option<ArgumentsDeclaration> {
+        select_rule(&self.0, NodeLabel::Arguments).and_then(ArgumentsDeclaration::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventDefinition(Rc<RuleNode>);
+
+impl EventDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::EventDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EventDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn event_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::EventKeyword),
+            &self.0,
+            NodeLabel::EventKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn parameters(&self) -> EventParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(EventParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn anonymous_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::AnonymousKeyword)
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventParametersDeclaration(Rc<RuleNode>);
+
+impl EventParametersDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::EventParametersDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EventParametersDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn parameters(&self) -> EventParameters {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(EventParameters::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventParameter(Rc<RuleNode>);
+
+impl EventParameter {
+    /// Wraps the given CST node, if it has the [`RuleKind::EventParameter`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EventParameter).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn indexed_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::IndexedKeyword)
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserDefinedValueTypeDefinition(Rc<RuleNode>);
+
+impl UserDefinedValueTypeDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::UserDefinedValueTypeDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UserDefinedValueTypeDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::TypeKeyword),
+            &self.0,
+            NodeLabel::TypeKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn is_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::IsKeyword),
+            &self.0,
+            NodeLabel::IsKeyword,
+        )
+    }
+
+    pub fn value_type(&self) -> ElementaryType {
+        required(
+            select_rule(&self.0, NodeLabel::ValueType).and_then(ElementaryType::cast),
+            &self.0,
+            NodeLabel::ValueType,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorDefinition(Rc<RuleNode>);
+
+impl ErrorDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ErrorDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ErrorDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn error_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ErrorKeyword),
+            &self.0,
+            NodeLabel::ErrorKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn members(&self) -> ErrorParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(ErrorParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorParametersDeclaration(Rc<RuleNode>);
+
+impl ErrorParametersDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::ErrorParametersDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ErrorParametersDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn parameters(&self) -> ErrorParameters {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ErrorParameters::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorParameter(Rc<RuleNode>);
+
+impl ErrorParameter {
+    /// Wraps the given CST node, if it has the [`RuleKind::ErrorParameter`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ErrorParameter).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrayTypeName(Rc<RuleNode>);
+
+impl ArrayTypeName {
+    /// Wraps the given CST node, if it has the [`RuleKind::ArrayTypeName`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ArrayTypeName).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn open_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBracket),
+            &self.0,
+            NodeLabel::OpenBracket,
+        )
+    }
+
+    pub fn index(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::Index).and_then(Expression::cast)
+    }
+
+    pub fn close_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBracket),
+            &self.0,
+            NodeLabel::CloseBracket,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionType(Rc<RuleNode>);
+
+impl FunctionType {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn function_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FunctionKeyword),
+            &self.0,
+            NodeLabel::FunctionKeyword,
+        )
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn attributes(&self) -> FunctionTypeAttributes {
+        required(
+            select_rule(&self.0, NodeLabel::Attributes).and_then(FunctionTypeAttributes::cast),
+            &self.0,
+            NodeLabel::Attributes,
+        )
+    }
+
+    pub fn returns(&self) -> Option<ReturnsDeclaration> {
+        select_rule(&self.0, NodeLabel::Returns).and_then(ReturnsDeclaration::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingType(Rc<RuleNode>);
+
+impl MappingType {
+    /// Wraps the given CST node, if it has the [`RuleKind::MappingType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MappingType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn mapping_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::MappingKeyword),
+            &self.0,
+            NodeLabel::MappingKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn key_type(&self) -> MappingKey {
+        required(
+            select_rule(&self.0, NodeLabel::KeyType).and_then(MappingKey::cast),
+            &self.0,
+            NodeLabel::KeyType,
+        )
+    }
+
+    pub fn equal_greater_than(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::EqualGreaterThan),
+            &self.0,
+            NodeLabel::EqualGreaterThan,
+        )
+    }
+
+    pub fn value_type(&self) -> MappingValue {
+        required(
+            select_rule(&self.0, NodeLabel::ValueType).and_then(MappingValue::cast),
+            &self.0,
+            NodeLabel::ValueType,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingKey(Rc<RuleNode>);
+
+impl MappingKey {
+    /// Wraps the given CST node, if it has the [`RuleKind::MappingKey`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MappingKey).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn key_type(&self) -> MappingKeyType {
+        required(
+            select_rule(&self.0, NodeLabel::KeyType).and_then(MappingKeyType::cast),
+            &self.0,
+            NodeLabel::KeyType,
+        )
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingValue(Rc<RuleNode>);
+
+impl MappingValue {
+    /// Wraps the given CST node, if it has the [`RuleKind::MappingValue`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MappingValue).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressType(Rc<RuleNode>);
+
+impl AddressType {
+    /// Wraps the given CST node, if it has the [`RuleKind::AddressType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AddressType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn address_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::AddressKeyword),
+            &self.0,
+            NodeLabel::AddressKeyword,
+        )
+    }
+
+    pub fn payable_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::PayableKeyword)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block(Rc<RuleNode>);
+
+impl Block {
+    /// Wraps the given CST node, if it has the [`RuleKind::Block`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Block).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn statements(&self) -> Statements {
+        required(
+            select_rule(&self.0, NodeLabel::Statements).and_then(Statements::cast),
+            &self.0,
+            NodeLabel::Statements,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UncheckedBlock(Rc<RuleNode>);
+
+impl UncheckedBlock {
+    /// Wraps the given CST node, if it has the [`RuleKind::UncheckedBlock`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UncheckedBlock).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn unchecked_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::UncheckedKeyword),
+            &self.0,
+            NodeLabel::UncheckedKeyword,
+        )
+    }
+
+    pub fn block(&self) -> Block {
+        required(
+            select_rule(&self.0, NodeLabel::Block).and_then(Block::cast),
+            &self.0,
+            NodeLabel::Block,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpressionStatement(Rc<RuleNode>);
+
+impl ExpressionStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::ExpressionStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ExpressionStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssemblyStatement(Rc<RuleNode>);
+
+impl AssemblyStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::AssemblyStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AssemblyStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn assembly_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::AssemblyKeyword),
+            &self.0,
+            NodeLabel::AssemblyKeyword,
+        )
+    }
+
+    pub fn label(&self) -> Option<StringLiteral> {
+        select_rule(&self.0, NodeLabel::Label).and_then(StringLiteral::cast)
+    }
+
+    pub fn flags(&self) -> Option<AssemblyFlagsDeclaration> {
+        select_rule(&self.0, NodeLabel::Flags).and_then(AssemblyFlagsDeclaration::cast)
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssemblyFlagsDeclaration(Rc<RuleNode>);
+
+impl AssemblyFlagsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::AssemblyFlagsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AssemblyFlagsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn flags(&self) -> AssemblyFlags {
+        required(
+            select_rule(&self.0, NodeLabel::Flags).and_then(AssemblyFlags::cast),
+            &self.0,
+            NodeLabel::Flags,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleDeconstructionStatement(Rc<RuleNode>);
+
+impl TupleDeconstructionStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleDeconstructionStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleDeconstructionStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn var_keyword(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::VarKeyword)
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn elements(&self) -> TupleDeconstructionElements {
+        required(
+            select_rule(&self.0, NodeLabel::Elements).and_then(TupleDeconstructionElements::cast),
+            &self.0,
+            NodeLabel::Elements,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+
+    pub fn equal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Equal),
+            &self.0,
+            NodeLabel::Equal,
+        )
+    }
+
+    pub fn expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleDeconstructionElement(Rc<RuleNode>);
+
+impl TupleDeconstructionElement {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleDeconstructionElement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleDeconstructionElement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn member(&self) -> Option<TupleMember> {
+        select_rule(&self.0, NodeLabel::Member).and_then(TupleMember::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedTupleMember(Rc<RuleNode>);
+
+impl TypedTupleMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::TypedTupleMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TypedTupleMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn storage_location(&self) -> Option<StorageLocation> {
+        select_rule(&self.0, NodeLabel::StorageLocation).and_then(StorageLocation::cast)
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UntypedTupleMember(Rc<RuleNode>);
+
+impl UntypedTupleMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::UntypedTupleMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UntypedTupleMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn storage_location(&self) -> Option<StorageLocation> {
+        select_rule(&self.0, NodeLabel::StorageLocation).and_then(StorageLocation::cast)
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableDeclarationStatement(Rc<RuleNode>);
+
+impl VariableDeclarationStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::VariableDeclarationStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VariableDeclarationStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variable_type(&self) -> VariableDeclarationType {
+        required(
+            select_rule(&self.0, NodeLabel::VariableType).and_then(VariableDeclarationType::cast),
+            &self.0,
+            NodeLabel::VariableType,
+        )
+    }
+
+    pub fn storage_location(&self) -> Option<StorageLocation> {
+        select_rule(&self.0, NodeLabel::StorageLocation).and_then(StorageLocation::cast)
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn value(&self) -> Option<VariableDeclarationValue> {
+        select_rule(&self.0, NodeLabel::Value).and_then(VariableDeclarationValue::cast)
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableDeclarationValue(Rc<RuleNode>);
+
+impl VariableDeclarationValue {
+    /// Wraps the given CST node, if it has the [`RuleKind::VariableDeclarationValue`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VariableDeclarationValue).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn equal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Equal),
+            &self.0,
+            NodeLabel::Equal,
+        )
+    }
+
+    pub fn expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IfStatement(Rc<RuleNode>);
+
+impl IfStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::IfStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::IfStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn if_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::IfKeyword),
+            &self.0,
+            NodeLabel::IfKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn condition(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+
+    pub fn body(&self) -> Statement {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Statement::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+
+    pub fn else_branch(&self) -> Option<ElseBranch> {
+        select_rule(&self.0, NodeLabel::ElseBranch).and_then(ElseBranch::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElseBranch(Rc<RuleNode>);
+
+impl ElseBranch {
+    /// Wraps the given CST node, if it has the [`RuleKind::ElseBranch`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ElseBranch).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn else_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ElseKeyword),
+            &self.0,
+            NodeLabel::ElseKeyword,
+        )
+    }
+
+    pub fn body(&self) -> Statement {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Statement::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForStatement(Rc<RuleNode>);
+
+impl ForStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::ForStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ForStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn for_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ForKeyword),
+            &self.0,
+            NodeLabel::ForKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn initialization(&self) -> ForStatementInitialization {
+        required(
+            select_rule(&self.0, NodeLabel::Initialization)
+                .and_then(ForStatementInitialization::cast),
+            &self.0,
+            NodeLabel::Initialization,
+        )
+    }
+
+    pub fn condition(&self) -> ForStatementCondition {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(ForStatementCondition::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn iterator(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::Iterator).and_then(Expression::cast)
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+
+    pub fn body(&self) -> Statement {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Statement::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WhileStatement(Rc<RuleNode>);
+
+impl WhileStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::WhileStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::WhileStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn while_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::WhileKeyword),
+            &self.0,
+            NodeLabel::WhileKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn condition(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+
+    pub fn body(&self) -> Statement {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Statement::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DoWhileStatement(Rc<RuleNode>);
+
+impl DoWhileStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::DoWhileStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::DoWhileStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn do_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::DoKeyword),
+            &self.0,
+            NodeLabel::DoKeyword,
+        )
+    }
+
+    pub fn body(&self) -> Statement {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Statement::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+
+    pub fn while_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::WhileKeyword),
+            &self.0,
+            NodeLabel::WhileKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn condition(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContinueStatement(Rc<RuleNode>);
+
+impl ContinueStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::ContinueStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ContinueStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn continue_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ContinueKeyword),
+            &self.0,
+            NodeLabel::ContinueKeyword,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreakStatement(Rc<RuleNode>);
+
+impl BreakStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::BreakStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::BreakStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn break_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::BreakKeyword),
+            &self.0,
+            NodeLabel::BreakKeyword,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReturnStatement(Rc<RuleNode>);
+
+impl ReturnStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::ReturnStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ReturnStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn return_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ReturnKeyword),
+            &self.0,
+            NodeLabel::ReturnKeyword,
+        )
+    }
+
+    pub fn expression(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast)
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmitStatement(Rc<RuleNode>);
+
+impl EmitStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::EmitStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EmitStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn emit_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::EmitKeyword),
+            &self.0,
+            NodeLabel::EmitKeyword,
+        )
+    }
+
+    pub fn event(&self) -> IdentifierPath {
+        required(
+            select_rule(&self.0, NodeLabel::Event).and_then(IdentifierPath::cast),
+            &self.0,
+            NodeLabel::Event,
+        )
+    }
+
+    pub fn arguments(&self) -> ArgumentsDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(ArgumentsDeclaration::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryStatement(Rc<RuleNode>);
+
+impl TryStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::TryStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TryStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn try_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::TryKeyword),
+            &self.0,
+            NodeLabel::TryKeyword,
+        )
+    }
+
+    pub fn expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+
+    pub fn returns(&self) -> Option<ReturnsDeclaration> {
+        select_rule(&self.0, NodeLabel::Returns).and_then(ReturnsDeclaration::cast)
+    }
+
+    pub fn body(&self) -> Block {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Block::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+
+    pub fn catch_clauses(&self) -> CatchClauses {
+        required(
+            select_rule(&self.0, NodeLabel::CatchClauses).and_then(CatchClauses::cast),
+            &self.0,
+            NodeLabel::CatchClauses,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatchClause(Rc<RuleNode>);
+
+impl CatchClause {
+    /// Wraps the given CST node, if it has the [`RuleKind::CatchClause`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::CatchClause).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn catch_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CatchKeyword),
+            &self.0,
+            NodeLabel::CatchKeyword,
+        )
+    }
+
+    pub fn error(&self) -> Option<CatchClauseError> {
+        select_rule(&self.0, NodeLabel::Error).and_then(CatchClauseError::cast)
+    }
+
+    pub fn body(&self) -> Block {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(Block::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatchClauseError(Rc<RuleNode>);
+
+impl CatchClauseError {
+    /// Wraps the given CST node, if it has the [`RuleKind::CatchClauseError`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::CatchClauseError).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+
+    pub fn parameters(&self) -> ParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(ParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevertStatement(Rc<RuleNode>);
+
+impl RevertStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::RevertStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::RevertStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn revert_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::RevertKeyword),
+            &self.0,
+            NodeLabel::RevertKeyword,
+        )
+    }
+
+    pub fn error(&self) -> Option<IdentifierPath> {
+        select_rule(&self.0, NodeLabel::Error).and_then(IdentifierPath::cast)
+    }
+
+    pub fn arguments(&self) -> ArgumentsDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(ArgumentsDeclaration::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThrowStatement(Rc<RuleNode>);
+
+impl ThrowStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::ThrowStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ThrowStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn throw_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ThrowKeyword),
+            &self.0,
+            NodeLabel::ThrowKeyword,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssignmentExpression(Rc<RuleNode>);
+
+impl AssignmentExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::AssignmentExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AssignmentExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConditionalExpression(Rc<RuleNode>);
+
+impl ConditionalExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::ConditionalExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ConditionalExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn question_mark(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::QuestionMark),
+            &self.0,
+            NodeLabel::QuestionMark,
+        )
+    }
+
+    pub fn true_expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::TrueExpression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::TrueExpression,
+        )
+    }
+
+    pub fn colon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Colon),
+            &self.0,
+            NodeLabel::Colon,
+        )
+    }
+
+    pub fn false_expression(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::FalseExpression).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::FalseExpression,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrExpression(Rc<RuleNode>);
+
+impl OrExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::OrExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::OrExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AndExpression(Rc<RuleNode>);
+
+impl AndExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::AndExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AndExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EqualityExpression(Rc<RuleNode>);
+
+impl EqualityExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::EqualityExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EqualityExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComparisonExpression(Rc<RuleNode>);
+
+impl ComparisonExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::ComparisonExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ComparisonExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitwiseOrExpression(Rc<RuleNode>);
+
+impl BitwiseOrExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::BitwiseOrExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::BitwiseOrExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitwiseXorExpression(Rc<RuleNode>);
+
+impl BitwiseXorExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::BitwiseXorExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::BitwiseXorExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitwiseAndExpression(Rc<RuleNode>);
+
+impl BitwiseAndExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::BitwiseAndExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::BitwiseAndExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShiftExpression(Rc<RuleNode>);
+
+impl ShiftExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::ShiftExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ShiftExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdditiveExpression(Rc<RuleNode>);
+
+impl AdditiveExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::AdditiveExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AdditiveExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiplicativeExpression(Rc<RuleNode>);
+
+impl MultiplicativeExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::MultiplicativeExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MultiplicativeExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExponentiationExpression(Rc<RuleNode>);
+
+impl ExponentiationExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::ExponentiationExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ExponentiationExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostfixExpression(Rc<RuleNode>);
+
+impl PostfixExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::PostfixExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PostfixExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefixExpression(Rc<RuleNode>);
+
+impl PrefixExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::PrefixExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PrefixExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCallExpression(Rc<RuleNode>);
+
+impl FunctionCallExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionCallExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionCallExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn arguments(&self) -> ArgumentsDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(ArgumentsDeclaration::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallOptionsExpression(Rc<RuleNode>);
+
+impl CallOptionsExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::CallOptionsExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::CallOptionsExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn options(&self) -> CallOptions {
+        required(
+            select_rule(&self.0, NodeLabel::Options).and_then(CallOptions::cast),
+            &self.0,
+            NodeLabel::Options,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberAccessExpression(Rc<RuleNode>);
+
+impl MemberAccessExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::MemberAccessExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MemberAccessExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn period(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Period),
+            &self.0,
+            NodeLabel::Period,
+        )
+    }
+
+    pub fn member(&self) -> MemberAccess {
+        required(
+            select_rule(&self.0, NodeLabel::Member).and_then(MemberAccess::cast),
+            &self.0,
+            NodeLabel::Member,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexAccessExpression(Rc<RuleNode>);
+
+impl IndexAccessExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::IndexAccessExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::IndexAccessExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn open_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBracket),
+            &self.0,
+            NodeLabel::OpenBracket,
+        )
+    }
+
+    pub fn start(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::Start).and_then(Expression::cast)
+    }
+
+    pub fn end(&self) -> Option<IndexAccessEnd> {
+        select_rule(&self.0, NodeLabel::End).and_then(IndexAccessEnd::cast)
+    }
+
+    pub fn close_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBracket),
+            &self.0,
+            NodeLabel::CloseBracket,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexAccessEnd(Rc<RuleNode>);
+
+impl IndexAccessEnd {
+    /// Wraps the given CST node, if it has the [`RuleKind::IndexAccessEnd`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::IndexAccessEnd).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn colon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Colon),
+            &self.0,
+            NodeLabel::Colon,
+        )
+    }
+
+    pub fn end(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::End).and_then(Expression::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PositionalArgumentsDeclaration(Rc<RuleNode>);
+
+impl PositionalArgumentsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::PositionalArgumentsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PositionalArgumentsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn arguments(&self) -> PositionalArguments {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(PositionalArguments::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedArgumentsDeclaration(Rc<RuleNode>);
+
+impl NamedArgumentsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::NamedArgumentsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NamedArgumentsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn arguments(&self) -> Option<NamedArgumentGroup> {
+        select_rule(&self.0, NodeLabel::Arguments).and_then(NamedArgumentGroup::cast)
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedArgumentGroup(Rc<RuleNode>);
+
+impl NamedArgumentGroup {
+    /// Wraps the given CST node, if it has the [`RuleKind::NamedArgumentGroup`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NamedArgumentGroup).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn arguments(&self) -> NamedArguments {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(NamedArguments::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedArgument(Rc<RuleNode>);
+
+impl NamedArgument {
+    /// Wraps the given CST node, if it has the [`RuleKind::NamedArgument`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NamedArgument).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn colon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Colon),
+            &self.0,
+            NodeLabel::Colon,
+        )
+    }
+
+    pub fn value(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Value).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Value,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeExpression(Rc<RuleNode>);
+
+impl TypeExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::TypeExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TypeExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn type_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::TypeKeyword),
+            &self.0,
+            NodeLabel::TypeKeyword,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewExpression(Rc<RuleNode>);
+
+impl NewExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::NewExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NewExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn new_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::NewKeyword),
+            &self.0,
+            NodeLabel::NewKeyword,
+        )
+    }
+
+    pub fn type_name(&self) -> TypeName {
+        required(
+            select_rule(&self.0, NodeLabel::TypeName).and_then(TypeName::cast),
+            &self.0,
+            NodeLabel::TypeName,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleExpression(Rc<RuleNode>);
+
+impl TupleExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn items(&self) -> TupleValues {
+        required(
+            select_rule(&self.0, NodeLabel::Items).and_then(TupleValues::cast),
+            &self.0,
+            NodeLabel::Items,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleValue(Rc<RuleNode>);
+
+impl TupleValue {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleValue`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleValue).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn expression(&self) -> Option<Expression> {
+        select_rule(&self.0, NodeLabel::Expression).and_then(Expression::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrayExpression(Rc<RuleNode>);
+
+impl ArrayExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::ArrayExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ArrayExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBracket),
+            &self.0,
+            NodeLabel::OpenBracket,
+        )
+    }
+
+    pub fn items(&self) -> ArrayValues {
+        required(
+            select_rule(&self.0, NodeLabel::Items).and_then(ArrayValues::cast),
+            &self.0,
+            NodeLabel::Items,
+        )
+    }
+
+    pub fn close_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBracket),
+            &self.0,
+            NodeLabel::CloseBracket,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexNumberExpression(Rc<RuleNode>);
+
+impl HexNumberExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::HexNumberExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::HexNumberExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn literal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Literal),
+            &self.0,
+            NodeLabel::Literal,
+        )
+    }
+
+    pub fn unit(&self) -> Option<NumberUnit> {
+        select_rule(&self.0, NodeLabel::Unit).and_then(NumberUnit::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalNumberExpression(Rc<RuleNode>);
+
+impl DecimalNumberExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::DecimalNumberExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::DecimalNumberExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn literal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Literal),
+            &self.0,
+            NodeLabel::Literal,
+        )
+    }
+
+    pub fn unit(&self) -> Option<NumberUnit> {
+        select_rule(&self.0, NodeLabel::Unit).and_then(NumberUnit::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulBlock(Rc<RuleNode>);
+
+impl YulBlock {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulBlock`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulBlock).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBrace),
+            &self.0,
+            NodeLabel::OpenBrace,
+        )
+    }
+
+    pub fn statements(&self) -> YulStatements {
+        required(
+            select_rule(&self.0, NodeLabel::Statements).and_then(YulStatements::cast),
+            &self.0,
+            NodeLabel::Statements,
+        )
+    }
+
+    pub fn close_brace(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBrace),
+            &self.0,
+            NodeLabel::CloseBrace,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulFunctionDefinition(Rc<RuleNode>);
+
+impl YulFunctionDefinition {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulFunctionDefinition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulFunctionDefinition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn function_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::FunctionKeyword),
+            &self.0,
+            NodeLabel::FunctionKeyword,
+        )
+    }
+
+    pub fn name(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Name),
+            &self.0,
+            NodeLabel::Name,
+        )
+    }
+
+    pub fn parameters(&self) -> YulParametersDeclaration {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(YulParametersDeclaration::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn returns(&self) -> Option<YulReturnsDeclaration> {
+        select_rule(&self.0, NodeLabel::Returns).and_then(YulReturnsDeclaration::cast)
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulParametersDeclaration(Rc<RuleNode>);
+
+impl YulParametersDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulParametersDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulParametersDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn parameters(&self) -> YulParameters {
+        required(
+            select_rule(&self.0, NodeLabel::Parameters).and_then(YulParameters::cast),
+            &self.0,
+            NodeLabel::Parameters,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulReturnsDeclaration(Rc<RuleNode>);
+
+impl YulReturnsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulReturnsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulReturnsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn minus_greater_than(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::MinusGreaterThan),
+            &self.0,
+            NodeLabel::MinusGreaterThan,
+        )
+    }
+
+    pub fn variables(&self) -> YulReturnVariables {
+        required(
+            select_rule(&self.0, NodeLabel::Variables).and_then(YulReturnVariables::cast),
+            &self.0,
+            NodeLabel::Variables,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulVariableDeclarationStatement(Rc<RuleNode>);
+
+impl YulVariableDeclarationStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulVariableDeclarationStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulVariableDeclarationStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn let_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::LetKeyword),
+            &self.0,
+            NodeLabel::LetKeyword,
+        )
+    }
+
+    pub fn names(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Names),
+            &self.0,
+            NodeLabel::Names,
+        )
+    }
+
+    pub fn value(&self) -> Option<YulVariableDeclarationValue> {
+        select_rule(&self.0, NodeLabel::Value).and_then(YulVariableDeclarationValue::cast)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulVariableDeclarationValue(Rc<RuleNode>);
+
+impl YulVariableDeclarationValue {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulVariableDeclarationValue`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulVariableDeclarationValue).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn assignment(&self) -> YulAssignmentOperator {
+        required(
+            select_rule(&self.0, NodeLabel::Assignment).and_then(YulAssignmentOperator::cast),
+            &self.0,
+            NodeLabel::Assignment,
+        )
+    }
+
+    pub fn expression(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulAssignmentStatement(Rc<RuleNode>);
+
+impl YulAssignmentStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulAssignmentStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulAssignmentStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn names(&self) -> YulPaths {
+        required(
+            select_rule(&self.0, NodeLabel::Names).and_then(YulPaths::cast),
+            &self.0,
+            NodeLabel::Names,
+        )
+    }
+
+    pub fn assignment(&self) -> YulAssignmentOperator {
+        required(
+            select_rule(&self.0, NodeLabel::Assignment).and_then(YulAssignmentOperator::cast),
+            &self.0,
+            NodeLabel::Assignment,
+        )
+    }
+
+    pub fn expression(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulColonAndEqual(Rc<RuleNode>);
+
+impl YulColonAndEqual {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulColonAndEqual`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulColonAndEqual).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn colon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Colon),
+            &self.0,
+            NodeLabel::Colon,
+        )
+    }
+
+    pub fn equal(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Equal),
+            &self.0,
+            NodeLabel::Equal,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulIfStatement(Rc<RuleNode>);
+
+impl YulIfStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulIfStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulIfStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn if_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::IfKeyword),
+            &self.0,
+            NodeLabel::IfKeyword,
+        )
+    }
+
+    pub fn condition(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulForStatement(Rc<RuleNode>);
+
+impl YulForStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulForStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulForStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn for_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ForKeyword),
+            &self.0,
+            NodeLabel::ForKeyword,
+        )
+    }
+
+    pub fn initialization(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Initialization).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Initialization,
+        )
+    }
+
+    pub fn condition(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Condition).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Condition,
+        )
+    }
+
+    pub fn iterator(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Iterator).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Iterator,
+        )
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulSwitchStatement(Rc<RuleNode>);
+
+impl YulSwitchStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulSwitchStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulSwitchStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn switch_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::SwitchKeyword),
+            &self.0,
+            NodeLabel::SwitchKeyword,
+        )
+    }
+
+    pub fn expression(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Expression).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Expression,
+        )
+    }
+
+    pub fn cases(&self) -> YulSwitchCases {
+        required(
+            select_rule(&self.0, NodeLabel::Cases).and_then(YulSwitchCases::cast),
+            &self.0,
+            NodeLabel::Cases,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulDefaultCase(Rc<RuleNode>);
+
+impl YulDefaultCase {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulDefaultCase`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulDefaultCase).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn default_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::DefaultKeyword),
+            &self.0,
+            NodeLabel::DefaultKeyword,
+        )
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulValueCase(Rc<RuleNode>);
+
+impl YulValueCase {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulValueCase`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulValueCase).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn case_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CaseKeyword),
+            &self.0,
+            NodeLabel::CaseKeyword,
+        )
+    }
+
+    pub fn value(&self) -> YulLiteral {
+        required(
+            select_rule(&self.0, NodeLabel::Value).and_then(YulLiteral::cast),
+            &self.0,
+            NodeLabel::Value,
+        )
+    }
+
+    pub fn body(&self) -> YulBlock {
+        required(
+            select_rule(&self.0, NodeLabel::Body).and_then(YulBlock::cast),
+            &self.0,
+            NodeLabel::Body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulLeaveStatement(Rc<RuleNode>);
+
+impl YulLeaveStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulLeaveStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulLeaveStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn leave_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::LeaveKeyword),
+            &self.0,
+            NodeLabel::LeaveKeyword,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulBreakStatement(Rc<RuleNode>);
+
+impl YulBreakStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulBreakStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulBreakStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn break_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::BreakKeyword),
+            &self.0,
+            NodeLabel::BreakKeyword,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulContinueStatement(Rc<RuleNode>);
+
+impl YulContinueStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulContinueStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulContinueStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn continue_keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::ContinueKeyword),
+            &self.0,
+            NodeLabel::ContinueKeyword,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulLabel(Rc<RuleNode>);
+
+impl YulLabel {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulLabel`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulLabel).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn label(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Label),
+            &self.0,
+            NodeLabel::Label,
+        )
+    }
+
+    pub fn colon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Colon),
+            &self.0,
+            NodeLabel::Colon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulFunctionCallExpression(Rc<RuleNode>);
+
+impl YulFunctionCallExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulFunctionCallExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulFunctionCallExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> YulExpression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(YulExpression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn open_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenParen),
+            &self.0,
+            NodeLabel::OpenParen,
+        )
+    }
+
+    pub fn arguments(&self) -> YulArguments {
+        required(
+            select_rule(&self.0, NodeLabel::Arguments).and_then(YulArguments::cast),
+            &self.0,
+            NodeLabel::Arguments,
+        )
+    }
+
+    pub fn close_paren(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseParen),
+            &self.0,
+            NodeLabel::CloseParen,
+        )
+    }
+}
+
+//
+// Choices:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnitMember(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SourceUnitMemberVariant {
+    PragmaDirective(PragmaDirective),
+    ImportDirective(ImportDirective),
+    ContractDefinition(ContractDefinition),
+    InterfaceDefinition(InterfaceDefinition),
+    LibraryDefinition(LibraryDefinition),
+    StructDefinition(StructDefinition),
+    EnumDefinition(EnumDefinition),
+    FunctionDefinition(FunctionDefinition),
+    ConstantDefinition(ConstantDefinition),
+    ErrorDefinition(ErrorDefinition),
+    UserDefinedValueTypeDefinition(UserDefinedValueTypeDefinition),
+    UsingDirective(UsingDirective),
+    EventDefinition(EventDefinition),
+}
+
+impl SourceUnitMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnitMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnitMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> SourceUnitMemberVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::PragmaDirective => {
+                    SourceUnitMemberVariant::PragmaDirective(PragmaDirective(Rc::clone(rule)))
+                }
+                RuleKind::ImportDirective => {
+                    SourceUnitMemberVariant::ImportDirective(ImportDirective(Rc::clone(rule)))
+                }
+                RuleKind::ContractDefinition => {
+                    SourceUnitMemberVariant::ContractDefinition(ContractDefinition(Rc::clone(rule)))
+                }
+                RuleKind::InterfaceDefinition => SourceUnitMemberVariant::InterfaceDefinition(
+                    InterfaceDefinition(Rc::clone(rule)),
+                ),
+                RuleKind::LibraryDefinition => {
+                    SourceUnitMemberVariant::LibraryDefinition(LibraryDefinition(Rc::clone(rule)))
+                }
+                RuleKind::StructDefinition => {
+                    SourceUnitMemberVariant::StructDefinition(StructDefinition(Rc::clone(rule)))
+                }
+                RuleKind::EnumDefinition => {
+                    SourceUnitMemberVariant::EnumDefinition(EnumDefinition(Rc::clone(rule)))
+                }
+                RuleKind::FunctionDefinition => {
+                    SourceUnitMemberVariant::FunctionDefinition(FunctionDefinition(Rc::clone(rule)))
+                }
+                RuleKind::ConstantDefinition => {
+                    SourceUnitMemberVariant::ConstantDefinition(ConstantDefinition(Rc::clone(rule)))
+                }
+                RuleKind::ErrorDefinition => {
+                    SourceUnitMemberVariant::ErrorDefinition(ErrorDefinition(Rc::clone(rule)))
+                }
+                RuleKind::UserDefinedValueTypeDefinition => {
+                    SourceUnitMemberVariant::UserDefinedValueTypeDefinition(
+                        UserDefinedValueTypeDefinition(Rc::clone(rule)),
+                    )
+                }
+                RuleKind::UsingDirective => {
+                    SourceUnitMemberVariant::UsingDirective(UsingDirective(Rc::clone(rule)))
+                }
+                RuleKind::EventDefinition => {
+                    SourceUnitMemberVariant::EventDefinition(EventDefinition(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pragma(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PragmaVariant {
+    ABICoderPragma(ABICoderPragma),
+    ExperimentalPragma(ExperimentalPragma),
+    VersionPragma(VersionPragma),
+}
+
+impl Pragma {
+    /// Wraps the given CST node, if it has the [`RuleKind::Pragma`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Pragma).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> PragmaVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ABICoderPragma => {
+                    PragmaVariant::ABICoderPragma(ABICoderPragma(Rc::clone(rule)))
+                }
+                RuleKind::ExperimentalPragma => {
+                    PragmaVariant::ExperimentalPragma(ExperimentalPragma(Rc::clone(rule)))
+                }
+                RuleKind::VersionPragma => {
+                    PragmaVariant::VersionPragma(VersionPragma(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExperimentalFeature(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExperimentalFeatureVariant {
+    StringLiteral(StringLiteral),
+    Token(Rc<TokenNode>),
+}
+
+impl ExperimentalFeature {
+    /// Wraps the given CST node, if it has the [`RuleKind::ExperimentalFeature`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ExperimentalFeature).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ExperimentalFeatureVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::StringLiteral => {
+                    ExperimentalFeatureVariant::StringLiteral(StringLiteral(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ExperimentalFeatureVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionExpression(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionExpressionVariant {
+    VersionRange(VersionRange),
+    VersionComparator(VersionComparator),
+    VersionSpecifiers(VersionSpecifiers),
+    Token(Rc<TokenNode>),
+}
+
+impl VersionExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> VersionExpressionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::VersionRange => {
+                    VersionExpressionVariant::VersionRange(VersionRange(Rc::clone(rule)))
+                }
+                RuleKind::VersionComparator => {
+                    VersionExpressionVariant::VersionComparator(VersionComparator(Rc::clone(rule)))
+                }
+                RuleKind::VersionSpecifiers => {
+                    VersionExpressionVariant::VersionSpecifiers(VersionSpecifiers(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => VersionExpressionVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportClause(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportClauseVariant {
+    PathImport(PathImport),
+    NamedImport(NamedImport),
+    ImportDeconstruction(ImportDeconstruction),
+}
+
+impl ImportClause {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportClause`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportClause).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ImportClauseVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::PathImport => {
+                    ImportClauseVariant::PathImport(PathImport(Rc::clone(rule)))
+                }
+                RuleKind::NamedImport => {
+                    ImportClauseVariant::NamedImport(NamedImport(Rc::clone(rule)))
+                }
+                RuleKind::ImportDeconstruction => {
+                    ImportClauseVariant::ImportDeconstruction(ImportDeconstruction(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingClause(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UsingClauseVariant {
+    IdentifierPath(IdentifierPath),
+    UsingDeconstruction(UsingDeconstruction),
+}
+
+impl UsingClause {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingClause`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingClause).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> UsingClauseVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::IdentifierPath => {
+                    UsingClauseVariant::IdentifierPath(IdentifierPath(Rc::clone(rule)))
+                }
+                RuleKind::UsingDeconstruction => {
+                    UsingClauseVariant::UsingDeconstruction(UsingDeconstruction(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingOperator(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UsingOperatorVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl UsingOperator {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingOperator`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingOperator).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> UsingOperatorVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => UsingOperatorVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingTarget(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UsingTargetVariant {
+    TypeName(TypeName),
+    Token(Rc<TokenNode>),
+}
+
+impl UsingTarget {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingTarget`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingTarget).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> UsingTargetVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::TypeName => UsingTargetVariant::TypeName(TypeName(Rc::clone(rule))),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => UsingTargetVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractMember(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractMemberVariant {
+    UsingDirective(UsingDirective),
+    FunctionDefinition(FunctionDefinition),
+    ConstructorDefinition(ConstructorDefinition),
+    ReceiveFunctionDefinition(ReceiveFunctionDefinition),
+    FallbackFunctionDefinition(FallbackFunctionDefinition),
+    UnnamedFunctionDefinition(UnnamedFunctionDefinition),
+    ModifierDefinition(ModifierDefinition),
+    StructDefinition(StructDefinition),
+    EnumDefinition(EnumDefinition),
+    EventDefinition(EventDefinition),
+    StateVariableDefinition(StateVariableDefinition),
+    ErrorDefinition(ErrorDefinition),
+    UserDefinedValueTypeDefinition(UserDefinedValueTypeDefinition),
+}
+
+impl ContractMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::ContractMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ContractMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ContractMemberVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::UsingDirective => {
+                    ContractMemberVariant::UsingDirective(UsingDirective(Rc::clone(rule)))
+                }
+                RuleKind::FunctionDefinition => {
+                    ContractMemberVariant::FunctionDefinition(FunctionDefinition(Rc::clone(rule)))
+                }
+                RuleKind::ConstructorDefinition => ContractMemberVariant::ConstructorDefinition(
+                    ConstructorDefinition(Rc::clone(rule)),
+                ),
+                RuleKind::ReceiveFunctionDefinition => {
+                    ContractMemberVariant::ReceiveFunctionDefinition(ReceiveFunctionDefinition(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::FallbackFunctionDefinition => {
+                    ContractMemberVariant::FallbackFunctionDefinition(FallbackFunctionDefinition(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::UnnamedFunctionDefinition => {
+                    ContractMemberVariant::UnnamedFunctionDefinition(UnnamedFunctionDefinition(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::ModifierDefinition => {
+                    ContractMemberVariant::ModifierDefinition(ModifierDefinition(Rc::clone(rule)))
+                }
+                RuleKind::StructDefinition => {
+                    ContractMemberVariant::StructDefinition(StructDefinition(Rc::clone(rule)))
+                }
+                RuleKind::EnumDefinition => {
+                    ContractMemberVariant::EnumDefinition(EnumDefinition(Rc::clone(rule)))
+                }
+                RuleKind::EventDefinition => {
+                    ContractMemberVariant::EventDefinition(EventDefinition(Rc::clone(rule)))
+                }
+                RuleKind::StateVariableDefinition => {
+                    ContractMemberVariant::StateVariableDefinition(StateVariableDefinition(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::ErrorDefinition => {
+                    ContractMemberVariant::ErrorDefinition(ErrorDefinition(Rc::clone(rule)))
+                }
+                RuleKind::UserDefinedValueTypeDefinition => {
+                    ContractMemberVariant::UserDefinedValueTypeDefinition(
+                        UserDefinedValueTypeDefinition(Rc::clone(rule)),
+                    )
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateVariableAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateVariableAttributeVariant {
+    OverrideSpecifier(OverrideSpecifier),
+    Token(Rc<TokenNode>),
+}
+
+impl StateVariableAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::StateVariableAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StateVariableAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> StateVariableAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::OverrideSpecifier => StateVariableAttributeVariant::OverrideSpecifier(
+                    OverrideSpecifier(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => StateVariableAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionName(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionNameVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl FunctionName {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionName`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionName).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> FunctionNameVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => FunctionNameVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionAttributeVariant {
+    ModifierInvocation(ModifierInvocation),
+    OverrideSpecifier(OverrideSpecifier),
+    Token(Rc<TokenNode>),
+}
+
+impl FunctionAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> FunctionAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ModifierInvocation => FunctionAttributeVariant::ModifierInvocation(
+                    ModifierInvocation(Rc::clone(rule)),
+                ),
+                RuleKind::OverrideSpecifier => {
+                    FunctionAttributeVariant::OverrideSpecifier(OverrideSpecifier(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => FunctionAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionBody(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionBodyVariant {
+    Block(Block),
+    Token(Rc<TokenNode>),
+}
+
+impl FunctionBody {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionBody`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionBody).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> FunctionBodyVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::Block => FunctionBodyVariant::Block(Block(Rc::clone(rule))),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => FunctionBodyVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstructorAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConstructorAttributeVariant {
+    ModifierInvocation(ModifierInvocation),
+    Token(Rc<TokenNode>),
+}
+
+impl ConstructorAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::ConstructorAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ConstructorAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ConstructorAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ModifierInvocation => ConstructorAttributeVariant::ModifierInvocation(
+                    ModifierInvocation(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ConstructorAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnnamedFunctionAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnnamedFunctionAttributeVariant {
+    ModifierInvocation(ModifierInvocation),
+    Token(Rc<TokenNode>),
+}
+
+impl UnnamedFunctionAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::UnnamedFunctionAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UnnamedFunctionAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> UnnamedFunctionAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ModifierInvocation => {
+                    UnnamedFunctionAttributeVariant::ModifierInvocation(ModifierInvocation(
+                        Rc::clone(rule),
+                    ))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => UnnamedFunctionAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FallbackFunctionAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FallbackFunctionAttributeVariant {
+    ModifierInvocation(ModifierInvocation),
+    OverrideSpecifier(OverrideSpecifier),
+    Token(Rc<TokenNode>),
+}
+
+impl FallbackFunctionAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::FallbackFunctionAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FallbackFunctionAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> FallbackFunctionAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ModifierInvocation => {
+                    FallbackFunctionAttributeVariant::ModifierInvocation(ModifierInvocation(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::OverrideSpecifier => FallbackFunctionAttributeVariant::OverrideSpecifier(
+                    OverrideSpecifier(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => FallbackFunctionAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveFunctionAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReceiveFunctionAttributeVariant {
+    ModifierInvocation(ModifierInvocation),
+    OverrideSpecifier(OverrideSpecifier),
+    Token(Rc<TokenNode>),
+}
+
+impl ReceiveFunctionAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::ReceiveFunctionAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ReceiveFunctionAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ReceiveFunctionAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ModifierInvocation => {
+                    ReceiveFunctionAttributeVariant::ModifierInvocation(ModifierInvocation(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::OverrideSpecifier => ReceiveFunctionAttributeVariant::OverrideSpecifier(
+                    OverrideSpecifier(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ReceiveFunctionAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifierAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModifierAttributeVariant {
+    OverrideSpecifier(OverrideSpecifier),
+    Token(Rc<TokenNode>),
+}
+
+impl ModifierAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::ModifierAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ModifierAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ModifierAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::OverrideSpecifier => {
+                    ModifierAttributeVariant::OverrideSpecifier(OverrideSpecifier(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ModifierAttributeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeName(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeNameVariant {
+    ArrayTypeName(ArrayTypeName),
+    FunctionType(FunctionType),
+    MappingType(MappingType),
+    ElementaryType(ElementaryType),
+    IdentifierPath(IdentifierPath),
+}
+
+impl TypeName {
+    /// Wraps the given CST node, if it has the [`RuleKind::TypeName`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TypeName).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> TypeNameVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ArrayTypeName => {
+                    TypeNameVariant::ArrayTypeName(ArrayTypeName(Rc::clone(rule)))
+                }
+                RuleKind::FunctionType => {
+                    TypeNameVariant::FunctionType(FunctionType(Rc::clone(rule)))
+                }
+                RuleKind::MappingType => TypeNameVariant::MappingType(MappingType(Rc::clone(rule))),
+                RuleKind::ElementaryType => {
+                    TypeNameVariant::ElementaryType(ElementaryType(Rc::clone(rule)))
+                }
+                RuleKind::IdentifierPath => {
+                    TypeNameVariant::IdentifierPath(IdentifierPath(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionTypeAttribute(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionTypeAttributeVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl FunctionTypeAttribute {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionTypeAttribute`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionTypeAttribute).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> FunctionTypeAttributeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => FunctionTypeAttributeVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingKeyType(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingKeyTypeVariant {
+    ElementaryType(ElementaryType),
+    IdentifierPath(IdentifierPath),
+}
+
+impl MappingKeyType {
+    /// Wraps the given CST node, if it has the [`RuleKind::MappingKeyType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MappingKeyType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> MappingKeyTypeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ElementaryType => {
+                    MappingKeyTypeVariant::ElementaryType(ElementaryType(Rc::clone(rule)))
+                }
+                RuleKind::IdentifierPath => {
+                    MappingKeyTypeVariant::IdentifierPath(IdentifierPath(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElementaryType(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ElementaryTypeVariant {
+    AddressType(AddressType),
+    Token(Rc<TokenNode>),
+}
+
+impl ElementaryType {
+    /// Wraps the given CST node, if it has the [`RuleKind::ElementaryType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ElementaryType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ElementaryTypeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::AddressType => {
+                    ElementaryTypeVariant::AddressType(AddressType(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ElementaryTypeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatementVariant {
+    ExpressionStatement(ExpressionStatement),
+    VariableDeclarationStatement(VariableDeclarationStatement),
+    TupleDeconstructionStatement(TupleDeconstructionStatement),
+    IfStatement(IfStatement),
+    ForStatement(ForStatement),
+    WhileStatement(WhileStatement),
+    DoWhileStatement(DoWhileStatement),
+    ContinueStatement(ContinueStatement),
+    BreakStatement(BreakStatement),
+    ReturnStatement(ReturnStatement),
+    ThrowStatement(ThrowStatement),
+    EmitStatement(EmitStatement),
+    TryStatement(TryStatement),
+    RevertStatement(RevertStatement),
+    AssemblyStatement(AssemblyStatement),
+    Block(Block),
+    UncheckedBlock(UncheckedBlock),
+}
+
+impl Statement {
+    /// Wraps the given CST node, if it has the [`RuleKind::Statement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Statement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> StatementVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ExpressionStatement => {
+                    StatementVariant::ExpressionStatement(ExpressionStatement(Rc::clone(rule)))
+                }
+                RuleKind::VariableDeclarationStatement => {
+                    StatementVariant::VariableDeclarationStatement(VariableDeclarationStatement(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::TupleDeconstructionStatement => {
+                    StatementVariant::TupleDeconstructionStatement(TupleDeconstructionStatement(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::IfStatement => {
+                    StatementVariant::IfStatement(IfStatement(Rc::clone(rule)))
+                }
+                RuleKind::ForStatement => {
+                    StatementVariant::ForStatement(ForStatement(Rc::clone(rule)))
+                }
+                RuleKind::WhileStatement => {
+                    StatementVariant::WhileStatement(WhileStatement(Rc::clone(rule)))
+                }
+                RuleKind::DoWhileStatement => {
+                    StatementVariant::DoWhileStatement(DoWhileStatement(Rc::clone(rule)))
+                }
+                RuleKind::ContinueStatement => {
+                    StatementVariant::ContinueStatement(ContinueStatement(Rc::clone(rule)))
+                }
+                RuleKind::BreakStatement => {
+                    StatementVariant::BreakStatement(BreakStatement(Rc::clone(rule)))
+                }
+                RuleKind::ReturnStatement => {
+                    StatementVariant::ReturnStatement(ReturnStatement(Rc::clone(rule)))
+                }
+                RuleKind::ThrowStatement => {
+                    StatementVariant::ThrowStatement(ThrowStatement(Rc::clone(rule)))
+                }
+                RuleKind::EmitStatement => {
+                    StatementVariant::EmitStatement(EmitStatement(Rc::clone(rule)))
+                }
+                RuleKind::TryStatement => {
+                    StatementVariant::TryStatement(TryStatement(Rc::clone(rule)))
+                }
+                RuleKind::RevertStatement => {
+                    StatementVariant::RevertStatement(RevertStatement(Rc::clone(rule)))
+                }
+                RuleKind::AssemblyStatement => {
+                    StatementVariant::AssemblyStatement(AssemblyStatement(Rc::clone(rule)))
+                }
+                RuleKind::Block => StatementVariant::Block(Block(Rc::clone(rule))),
+                RuleKind::UncheckedBlock => {
+                    StatementVariant::UncheckedBlock(UncheckedBlock(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleMember(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TupleMemberVariant {
+    TypedTupleMember(TypedTupleMember),
+    UntypedTupleMember(UntypedTupleMember),
+}
+
+impl TupleMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> TupleMemberVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::TypedTupleMember => {
+                    TupleMemberVariant::TypedTupleMember(TypedTupleMember(Rc::clone(rule)))
+                }
+                RuleKind::UntypedTupleMember => {
+                    TupleMemberVariant::UntypedTupleMember(UntypedTupleMember(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableDeclarationType(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VariableDeclarationTypeVariant {
+    TypeName(TypeName),
+    Token(Rc<TokenNode>),
+}
+
+impl VariableDeclarationType {
+    /// Wraps the given CST node, if it has the [`RuleKind::VariableDeclarationType`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VariableDeclarationType).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> VariableDeclarationTypeVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::TypeName => {
+                    VariableDeclarationTypeVariant::TypeName(TypeName(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => VariableDeclarationTypeVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageLocation(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageLocationVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl StorageLocation {
+    /// Wraps the given CST node, if it has the [`RuleKind::StorageLocation`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StorageLocation).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> StorageLocationVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => StorageLocationVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForStatementInitialization(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForStatementInitializationVariant {
+    ExpressionStatement(ExpressionStatement),
+    VariableDeclarationStatement(VariableDeclarationStatement),
+    TupleDeconstructionStatement(TupleDeconstructionStatement),
+    Token(Rc<TokenNode>),
+}
+
+impl ForStatementInitialization {
+    /// Wraps the given CST node, if it has the [`RuleKind::ForStatementInitialization`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ForStatementInitialization).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ForStatementInitializationVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ExpressionStatement => {
+                    ForStatementInitializationVariant::ExpressionStatement(ExpressionStatement(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::VariableDeclarationStatement => {
+                    ForStatementInitializationVariant::VariableDeclarationStatement(
+                        VariableDeclarationStatement(Rc::clone(rule)),
+                    )
+                }
+                RuleKind::TupleDeconstructionStatement => {
+                    ForStatementInitializationVariant::TupleDeconstructionStatement(
+                        TupleDeconstructionStatement(Rc::clone(rule)),
+                    )
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ForStatementInitializationVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForStatementCondition(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForStatementConditionVariant {
+    ExpressionStatement(ExpressionStatement),
+    Token(Rc<TokenNode>),
+}
+
+impl ForStatementCondition {
+    /// Wraps the given CST node, if it has the [`RuleKind::ForStatementCondition`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ForStatementCondition).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ForStatementConditionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::ExpressionStatement => ForStatementConditionVariant::ExpressionStatement(
+                    ExpressionStatement(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ForStatementConditionVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expression(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionVariant {
+    AssignmentExpression(AssignmentExpression),
+    ConditionalExpression(ConditionalExpression),
+    OrExpression(OrExpression),
+    AndExpression(AndExpression),
+    EqualityExpression(EqualityExpression),
+    ComparisonExpression(ComparisonExpression),
+    BitwiseOrExpression(BitwiseOrExpression),
+    BitwiseXorExpression(BitwiseXorExpression),
+    BitwiseAndExpression(BitwiseAndExpression),
+    ShiftExpression(ShiftExpression),
+    AdditiveExpression(AdditiveExpression),
+    MultiplicativeExpression(MultiplicativeExpression),
+    ExponentiationExpression(ExponentiationExpression),
+    PostfixExpression(PostfixExpression),
+    PrefixExpression(PrefixExpression),
+    FunctionCallExpression(FunctionCallExpression),
+    CallOptionsExpression(CallOptionsExpression),
+    MemberAccessExpression(MemberAccessExpression),
+    IndexAccessExpression(IndexAccessExpression),
+    NewExpression(NewExpression),
+    TupleExpression(TupleExpression),
+    TypeExpression(TypeExpression),
+    ArrayExpression(ArrayExpression),
+    HexNumberExpression(HexNumberExpression),
+    DecimalNumberExpression(DecimalNumberExpression),
+    StringExpression(StringExpression),
+    ElementaryType(ElementaryType),
+    Token(Rc<TokenNode>),
+}
+
+impl Expression {
+    /// Wraps the given CST node, if it has the [`RuleKind::Expression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Expression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ExpressionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::AssignmentExpression => {
+                    ExpressionVariant::AssignmentExpression(AssignmentExpression(Rc::clone(rule)))
+                }
+                RuleKind::ConditionalExpression => {
+                    ExpressionVariant::ConditionalExpression(ConditionalExpression(Rc::clone(rule)))
+                }
+                RuleKind::OrExpression => {
+                    ExpressionVariant::OrExpression(OrExpression(Rc::clone(rule)))
+                }
+                RuleKind::AndExpression => {
+                    ExpressionVariant::AndExpression(AndExpression(Rc::clone(rule)))
+                }
+                RuleKind::EqualityExpression => {
+                    ExpressionVariant::EqualityExpression(EqualityExpression(Rc::clone(rule)))
+                }
+                RuleKind::ComparisonExpression => {
+                    ExpressionVariant::ComparisonExpression(ComparisonExpression(Rc::clone(rule)))
+                }
+                RuleKind::BitwiseOrExpression => {
+                    ExpressionVariant::BitwiseOrExpression(BitwiseOrExpression(Rc::clone(rule)))
+                }
+                RuleKind::BitwiseXorExpression => {
+                    ExpressionVariant::BitwiseXorExpression(BitwiseXorExpression(Rc::clone(rule)))
+                }
+                RuleKind::BitwiseAndExpression => {
+                    ExpressionVariant::BitwiseAndExpression(BitwiseAndExpression(Rc::clone(rule)))
+                }
+                RuleKind::ShiftExpression => {
+                    ExpressionVariant::ShiftExpression(ShiftExpression(Rc::clone(rule)))
+                }
+                RuleKind::AdditiveExpression => {
+                    ExpressionVariant::AdditiveExpression(AdditiveExpression(Rc::clone(rule)))
+                }
+                RuleKind::MultiplicativeExpression => ExpressionVariant::MultiplicativeExpression(
+                    MultiplicativeExpression(Rc::clone(rule)),
+                ),
+                RuleKind::ExponentiationExpression => ExpressionVariant::ExponentiationExpression(
+                    ExponentiationExpression(Rc::clone(rule)),
+                ),
+                RuleKind::PostfixExpression => {
+                    ExpressionVariant::PostfixExpression(PostfixExpression(Rc::clone(rule)))
+                }
+                RuleKind::PrefixExpression => {
+                    ExpressionVariant::PrefixExpression(PrefixExpression(Rc::clone(rule)))
+                }
+                RuleKind::FunctionCallExpression => ExpressionVariant::FunctionCallExpression(
+                    FunctionCallExpression(Rc::clone(rule)),
+                ),
+                RuleKind::CallOptionsExpression => {
+                    ExpressionVariant::CallOptionsExpression(CallOptionsExpression(Rc::clone(rule)))
+                }
+                RuleKind::MemberAccessExpression => ExpressionVariant::MemberAccessExpression(
+                    MemberAccessExpression(Rc::clone(rule)),
+                ),
+                RuleKind::IndexAccessExpression => {
+                    ExpressionVariant::IndexAccessExpression(IndexAccessExpression(Rc::clone(rule)))
+                }
+                RuleKind::NewExpression => {
+                    ExpressionVariant::NewExpression(NewExpression(Rc::clone(rule)))
+                }
+                RuleKind::TupleExpression => {
+                    ExpressionVariant::TupleExpression(TupleExpression(Rc::clone(rule)))
+                }
+                RuleKind::TypeExpression => {
+                    ExpressionVariant::TypeExpression(TypeExpression(Rc::clone(rule)))
+                }
+                RuleKind::ArrayExpression => {
+                    ExpressionVariant::ArrayExpression(ArrayExpression(Rc::clone(rule)))
+                }
+                RuleKind::HexNumberExpression => {
+                    ExpressionVariant::HexNumberExpression(HexNumberExpression(Rc::clone(rule)))
+                }
+                RuleKind::DecimalNumberExpression => ExpressionVariant::DecimalNumberExpression(
+                    DecimalNumberExpression(Rc::clone(rule)),
+                ),
+                RuleKind::StringExpression => {
+                    ExpressionVariant::StringExpression(StringExpression(Rc::clone(rule)))
+                }
+                RuleKind::ElementaryType => {
+                    ExpressionVariant::ElementaryType(ElementaryType(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ExpressionVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberAccess(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemberAccessVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl MemberAccess {
+    /// Wraps the given CST node, if it has the [`RuleKind::MemberAccess`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MemberAccess).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> MemberAccessVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => MemberAccessVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArgumentsDeclaration(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArgumentsDeclarationVariant {
+    PositionalArgumentsDeclaration(PositionalArgumentsDeclaration),
+    NamedArgumentsDeclaration(NamedArgumentsDeclaration),
+}
+
+impl ArgumentsDeclaration {
+    /// Wraps the given CST node, if it has the [`RuleKind::ArgumentsDeclaration`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ArgumentsDeclaration).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ArgumentsDeclarationVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::PositionalArgumentsDeclaration => {
+                    ArgumentsDeclarationVariant::PositionalArgumentsDeclaration(
+                        PositionalArgumentsDeclaration(Rc::clone(rule)),
+                    )
+                }
+                RuleKind::NamedArgumentsDeclaration => {
+                    ArgumentsDeclarationVariant::NamedArgumentsDeclaration(
+                        NamedArgumentsDeclaration(Rc::clone(rule)),
+                    )
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberUnit(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberUnitVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl NumberUnit {
+    /// Wraps the given CST node, if it has the [`RuleKind::NumberUnit`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NumberUnit).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> NumberUnitVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => NumberUnitVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringExpression(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StringExpressionVariant {
+    StringLiteral(StringLiteral),
+    StringLiterals(StringLiterals),
+    HexStringLiteral(HexStringLiteral),
+    HexStringLiterals(HexStringLiterals),
+    UnicodeStringLiterals(UnicodeStringLiterals),
+}
+
+impl StringExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::StringExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StringExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> StringExpressionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::StringLiteral => {
+                    StringExpressionVariant::StringLiteral(StringLiteral(Rc::clone(rule)))
+                }
+                RuleKind::StringLiterals => {
+                    StringExpressionVariant::StringLiterals(StringLiterals(Rc::clone(rule)))
+                }
+                RuleKind::HexStringLiteral => {
+                    StringExpressionVariant::HexStringLiteral(HexStringLiteral(Rc::clone(rule)))
+                }
+                RuleKind::HexStringLiterals => {
+                    StringExpressionVariant::HexStringLiterals(HexStringLiterals(Rc::clone(rule)))
+                }
+                RuleKind::UnicodeStringLiterals => StringExpressionVariant::UnicodeStringLiterals(
+                    UnicodeStringLiterals(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringLiteral(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StringLiteralVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl StringLiteral {
+    /// Wraps the given CST node, if it has the [`RuleKind::StringLiteral`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StringLiteral).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> StringLiteralVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => StringLiteralVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexStringLiteral(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HexStringLiteralVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl HexStringLiteral {
+    /// Wraps the given CST node, if it has the [`RuleKind::HexStringLiteral`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::HexStringLiteral).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> HexStringLiteralVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => HexStringLiteralVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnicodeStringLiteral(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnicodeStringLiteralVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl UnicodeStringLiteral {
+    /// Wraps the given CST node, if it has the [`RuleKind::UnicodeStringLiteral`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UnicodeStringLiteral).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> UnicodeStringLiteralVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => UnicodeStringLiteralVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulStatement(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulStatementVariant {
+    YulBlock(YulBlock),
+    YulFunctionDefinition(YulFunctionDefinition),
+    YulVariableDeclarationStatement(YulVariableDeclarationStatement),
+    YulAssignmentStatement(YulAssignmentStatement),
+    YulIfStatement(YulIfStatement),
+    YulForStatement(YulForStatement),
+    YulSwitchStatement(YulSwitchStatement),
+    YulLeaveStatement(YulLeaveStatement),
+    YulBreakStatement(YulBreakStatement),
+    YulContinueStatement(YulContinueStatement),
+    YulLabel(YulLabel),
+    YulExpression(YulExpression),
+}
+
+impl YulStatement {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulStatement`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulStatement).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulStatementVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::YulBlock => YulStatementVariant::YulBlock(YulBlock(Rc::clone(rule))),
+                RuleKind::YulFunctionDefinition => YulStatementVariant::YulFunctionDefinition(
+                    YulFunctionDefinition(Rc::clone(rule)),
+                ),
+                RuleKind::YulVariableDeclarationStatement => {
+                    YulStatementVariant::YulVariableDeclarationStatement(
+                        YulVariableDeclarationStatement(Rc::clone(rule)),
+                    )
+                }
+                RuleKind::YulAssignmentStatement => YulStatementVariant::YulAssignmentStatement(
+                    YulAssignmentStatement(Rc::clone(rule)),
+                ),
+                RuleKind::YulIfStatement => {
+                    YulStatementVariant::YulIfStatement(YulIfStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulForStatement => {
+                    YulStatementVariant::YulForStatement(YulForStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulSwitchStatement => {
+                    YulStatementVariant::YulSwitchStatement(YulSwitchStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulLeaveStatement => {
+                    YulStatementVariant::YulLeaveStatement(YulLeaveStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulBreakStatement => {
+                    YulStatementVariant::YulBreakStatement(YulBreakStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulContinueStatement => {
+                    YulStatementVariant::YulContinueStatement(YulContinueStatement(Rc::clone(rule)))
+                }
+                RuleKind::YulLabel => YulStatementVariant::YulLabel(YulLabel(Rc::clone(rule))),
+                RuleKind::YulExpression => {
+                    YulStatementVariant::YulExpression(YulExpression(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulAssignmentOperator(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulAssignmentOperatorVariant {
+    YulColonAndEqual(YulColonAndEqual),
+    Token(Rc<TokenNode>),
+}
+
+impl YulAssignmentOperator {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulAssignmentOperator`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulAssignmentOperator).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulAssignmentOperatorVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::YulColonAndEqual => YulAssignmentOperatorVariant::YulColonAndEqual(
+                    YulColonAndEqual(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => YulAssignmentOperatorVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulSwitchCase(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulSwitchCaseVariant {
+    YulDefaultCase(YulDefaultCase),
+    YulValueCase(YulValueCase),
+}
+
+impl YulSwitchCase {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulSwitchCase`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulSwitchCase).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulSwitchCaseVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::YulDefaultCase => {
+                    YulSwitchCaseVariant::YulDefaultCase(YulDefaultCase(Rc::clone(rule)))
+                }
+                RuleKind::YulValueCase => {
+                    YulSwitchCaseVariant::YulValueCase(YulValueCase(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulExpression(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulExpressionVariant {
+    YulFunctionCallExpression(YulFunctionCallExpression),
+    YulLiteral(YulLiteral),
+    YulBuiltInFunction(YulBuiltInFunction),
+    YulPath(YulPath),
+}
+
+impl YulExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulExpressionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::YulFunctionCallExpression => {
+                    YulExpressionVariant::YulFunctionCallExpression(YulFunctionCallExpression(
+                        Rc::clone(rule),
+                    ))
+                }
+                RuleKind::YulLiteral => {
+                    YulExpressionVariant::YulLiteral(YulLiteral(Rc::clone(rule)))
+                }
+                RuleKind::YulBuiltInFunction => {
+                    YulExpressionVariant::YulBuiltInFunction(YulBuiltInFunction(Rc::clone(rule)))
+                }
+                RuleKind::YulPath => YulExpressionVariant::YulPath(YulPath(Rc::clone(rule))),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulPathComponent(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulPathComponentVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl YulPathComponent {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulPathComponent`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulPathComponent).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulPathComponentVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => YulPathComponentVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulBuiltInFunction(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulBuiltInFunctionVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl YulBuiltInFunction {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulBuiltInFunction`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulBuiltInFunction).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulBuiltInFunctionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => YulBuiltInFunctionVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulLiteral(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YulLiteralVariant {
+    HexStringLiteral(HexStringLiteral),
+    StringLiteral(StringLiteral),
+    Token(Rc<TokenNode>),
+}
+
+impl YulLiteral {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulLiteral`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulLiteral).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> YulLiteralVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::HexStringLiteral => {
+                    YulLiteralVariant::HexStringLiteral(HexStringLiteral(Rc::clone(rule)))
+                }
+                RuleKind::StringLiteral => {
+                    YulLiteralVariant::StringLiteral(StringLiteral(Rc::clone(rule)))
+                }
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => YulLiteralVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+//
+// Repeated:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnitMembers(Rc<RuleNode>);
+
+impl SourceUnitMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnitMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnitMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<SourceUnitMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(SourceUnitMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionExpressionSet(Rc<RuleNode>);
+
+impl VersionExpressionSet {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionExpressionSet`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionExpressionSet).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<VersionExpression> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(VersionExpression::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractMembers(Rc<RuleNode>);
+
+impl ContractMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::ContractMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ContractMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ContractMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ContractMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceMembers(Rc<RuleNode>);
+
+impl InterfaceMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::InterfaceMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::InterfaceMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ContractMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ContractMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryMembers(Rc<RuleNode>);
+
+impl LibraryMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::LibraryMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::LibraryMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ContractMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ContractMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructMembers(Rc<RuleNode>);
+
+impl StructMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::StructMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StructMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<StructMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(StructMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateVariableAttributes(Rc<RuleNode>);
+
+impl StateVariableAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::StateVariableAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StateVariableAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<StateVariableAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(StateVariableAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionAttributes(Rc<RuleNode>);
+
+impl FunctionAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<FunctionAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(FunctionAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstructorAttributes(Rc<RuleNode>);
+
+impl ConstructorAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::ConstructorAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ConstructorAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ConstructorAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ConstructorAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnnamedFunctionAttributes(Rc<RuleNode>);
+
+impl UnnamedFunctionAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::UnnamedFunctionAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UnnamedFunctionAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<UnnamedFunctionAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(UnnamedFunctionAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FallbackFunctionAttributes(Rc<RuleNode>);
+
+impl FallbackFunctionAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::FallbackFunctionAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FallbackFunctionAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<FallbackFunctionAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(FallbackFunctionAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveFunctionAttributes(Rc<RuleNode>);
+
+impl ReceiveFunctionAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::ReceiveFunctionAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ReceiveFunctionAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ReceiveFunctionAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ReceiveFunctionAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModifierAttributes(Rc<RuleNode>);
+
+impl ModifierAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::ModifierAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ModifierAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ModifierAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ModifierAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionTypeAttributes(Rc<RuleNode>);
+
+impl FunctionTypeAttributes {
+    /// Wraps the given CST node, if it has the [`RuleKind::FunctionTypeAttributes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::FunctionTypeAttributes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<FunctionTypeAttribute> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(FunctionTypeAttribute::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statements(Rc<RuleNode>);
+
+impl Statements {
+    /// Wraps the given CST node, if it has the [`RuleKind::Statements`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Statements).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Statement> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(Statement::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatchClauses(Rc<RuleNode>);
+
+impl CatchClauses {
+    /// Wraps the given CST node, if it has the [`RuleKind::CatchClauses`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::CatchClauses).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<CatchClause> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(CatchClause::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringLiterals(Rc<RuleNode>);
+
+impl StringLiterals {
+    /// Wraps the given CST node, if it has the [`RuleKind::StringLiterals`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::StringLiterals).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<StringLiteral> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(StringLiteral::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexStringLiterals(Rc<RuleNode>);
+
+impl HexStringLiterals {
+    /// Wraps the given CST node, if it has the [`RuleKind::HexStringLiterals`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::HexStringLiterals).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<HexStringLiteral> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(HexStringLiteral::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnicodeStringLiterals(Rc<RuleNode>);
+
+impl UnicodeStringLiterals {
+    /// Wraps the given CST node, if it has the [`RuleKind::UnicodeStringLiterals`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UnicodeStringLiterals).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<UnicodeStringLiteral> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(UnicodeStringLiteral::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulStatements(Rc<RuleNode>);
+
+impl YulStatements {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulStatements`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulStatements).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<YulStatement> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(YulStatement::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulSwitchCases(Rc<RuleNode>);
+
+impl YulSwitchCases {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulSwitchCases`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulSwitchCases).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<YulSwitchCase> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(YulSwitchCase::cast)
+            .collect()
+    }
+}
+
+//
+// Separated:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionExpressionSets(Rc<RuleNode>);
+
+impl VersionExpressionSets {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionExpressionSets`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionExpressionSets).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<VersionExpressionSet> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(VersionExpressionSet::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionSpecifiers(Rc<RuleNode>);
+
+impl VersionSpecifiers {
+    /// Wraps the given CST node, if it has the [`RuleKind::VersionSpecifiers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::VersionSpecifiers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDeconstructionSymbols(Rc<RuleNode>);
+
+impl ImportDeconstructionSymbols {
+    /// Wraps the given CST node, if it has the [`RuleKind::ImportDeconstructionSymbols`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ImportDeconstructionSymbols).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ImportDeconstructionSymbol> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ImportDeconstructionSymbol::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsingDeconstructionSymbols(Rc<RuleNode>);
+
+impl UsingDeconstructionSymbols {
+    /// Wraps the given CST node, if it has the [`RuleKind::UsingDeconstructionSymbols`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::UsingDeconstructionSymbols).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<UsingDeconstructionSymbol> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(UsingDeconstructionSymbol::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InheritanceTypes(Rc<RuleNode>);
+
+impl InheritanceTypes {
+    /// Wraps the given CST node, if it has the [`RuleKind::InheritanceTypes`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::InheritanceTypes).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<InheritanceType> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(InheritanceType::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumMembers(Rc<RuleNode>);
+
+impl EnumMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::EnumMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EnumMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters(Rc<RuleNode>);
+
+impl Parameters {
+    /// Wraps the given CST node, if it has the [`RuleKind::Parameters`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Parameters).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Parameter> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(Parameter::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverridePaths(Rc<RuleNode>);
+
+impl OverridePaths {
+    /// Wraps the given CST node, if it has the [`RuleKind::OverridePaths`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::OverridePaths).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<IdentifierPath> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(IdentifierPath::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventParameters(Rc<RuleNode>);
+
+impl EventParameters {
+    /// Wraps the given CST node, if it has the [`RuleKind::EventParameters`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::EventParameters).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<EventParameter> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(EventParameter::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorParameters(Rc<RuleNode>);
+
+impl ErrorParameters {
+    /// Wraps the given CST node, if it has the [`RuleKind::ErrorParameters`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ErrorParameters).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<ErrorParameter> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(ErrorParameter::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssemblyFlags(Rc<RuleNode>);
+
+impl AssemblyFlags {
+    /// Wraps the given CST node, if it has the [`RuleKind::AssemblyFlags`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AssemblyFlags).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<StringLiteral> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(StringLiteral::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleDeconstructionElements(Rc<RuleNode>);
+
+impl TupleDeconstructionElements {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleDeconstructionElements`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleDeconstructionElements).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<TupleDeconstructionElement> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(TupleDeconstructionElement::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PositionalArguments(Rc<RuleNode>);
+
+impl PositionalArguments {
+    /// Wraps the given CST node, if it has the [`RuleKind::PositionalArguments`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::PositionalArguments).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Expression> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(Expression::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedArguments(Rc<RuleNode>);
+
+impl NamedArguments {
+    /// Wraps the given CST node, if it has the [`RuleKind::NamedArguments`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NamedArguments).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<NamedArgument> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(NamedArgument::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallOptions(Rc<RuleNode>);
+
+impl CallOptions {
+    /// Wraps the given CST node, if it has the [`RuleKind::CallOptions`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::CallOptions).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<NamedArgument> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(NamedArgument::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleValues(Rc<RuleNode>);
+
+impl TupleValues {
+    /// Wraps the given CST node, if it has the [`RuleKind::TupleValues`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TupleValues).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<TupleValue> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(TupleValue::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrayValues(Rc<RuleNode>);
+
+impl ArrayValues {
+    /// Wraps the given CST node, if it has the [`RuleKind::ArrayValues`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::ArrayValues).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Expression> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(Expression::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierPath(Rc<RuleNode>);
+
+impl IdentifierPath {
+    /// Wraps the given CST node, if it has the [`RuleKind::IdentifierPath`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::IdentifierPath).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulParameters(Rc<RuleNode>);
+
+impl YulParameters {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulParameters`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulParameters).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulReturnVariables(Rc<RuleNode>);
+
+impl YulReturnVariables {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulReturnVariables`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulReturnVariables).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulArguments(Rc<RuleNode>);
+
+impl YulArguments {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulArguments`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulArguments).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<YulExpression> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(YulExpression::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulPaths(Rc<RuleNode>);
+
+impl YulPaths {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulPaths`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulPaths).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<YulPath> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(YulPath::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YulPath(Rc<RuleNode>);
+
+impl YulPath {
+    /// Wraps the given CST node, if it has the [`RuleKind::YulPath`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::YulPath).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<YulPathComponent> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(YulPathComponent::cast)
+            .collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+//
+// Common:
+//
+
+fn select(node: &RuleNode, label: NodeLabel) -> Option<&Node> {
+    node.children
+        .iter()
+        .find(|child| child.label == Some(label))
+        .map(|child| &child.node)
+}
+
+fn select_rule(node: &RuleNode, label: NodeLabel) -> Option<Rc<RuleNode>> {
+    select(node, label).and_then(Node::as_rule).cloned()
+}
+
+fn select_token(node: &RuleNode, label: NodeLabel) -> Option<Rc<TokenNode>> {
+    select(node, label).and_then(Node::as_token).cloned()
+}
+
+fn select_all_rules(node: &RuleNode, label: NodeLabel) -> impl Iterator<Item = Rc<RuleNode>> + '_ {
+    node.children
+        .iter()
+        .filter(move |child| child.label == Some(label))
+        .filter_map(|child| child.node.as_rule().cloned())
+}
+
+fn select_all_tokens(
+    node: &RuleNode,
+    label: NodeLabel,
+) -> impl Iterator<Item = Rc<TokenNode>> + '_ {
+    node.children
+        .iter()
+        .filter(move |child| child.label == Some(label))
+        .filter_map(|child| child.node.as_token().cloned())
+}
+
+fn required<T>(child: Option<T>, parent: &RuleNode, label: NodeLabel) -> T {
+    // Can happen if the user decided to use an incorrect/incomplete CST node.
+    child.unwrap_or_else(|| panic!("Missing child '{label}' in a '{}' node. Creating AST types from incorrect/incomplete CST nodes is not supported yet.", parent.kind))
+}
+
+#[allow(dead_code)]
+fn unexpected_variant(parent: &RuleNode, variant: &Node) -> ! {
+    // Should not theoretically happen, unless AST error recovery was changed.
+    match variant {
+        Node::Rule(rule) => panic!(
+            "Unexpected variant '{}' in a '{}' node.",
+            rule.kind, parent.kind
+        ),
+        Node::Token(token) => panic!(
+            "Unexpected variant '{}' in a '{}' node.",
+            token.kind, parent.kind
+        ),
+    }
+}
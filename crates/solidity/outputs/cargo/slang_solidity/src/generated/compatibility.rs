@@ -0,0 +1,514 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! Finding the language versions a source can be parsed with, and the constructs of its tree that are only enabled in
+//! some of them, as specified by the language definition.
+//!
+//! The input is parsed once per range of versions in which the language doesn't change, rather than once per
+//! supported version. See [`check_compatibility`].
+//!
+//! The same specifiers are used to add notes to parse errors that could be caused by constructs (or reserved words)
+//! of other versions. See [`ParseError::note`].
+
+use std::fmt;
+
+use semver::Version;
+
+use crate::cst::Node;
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::language::Language;
+use crate::parse_error::ParseError;
+use crate::parse_output::ParseOutput;
+use crate::text_index::{TextIndex, TextRange};
+
+/// A range of versions, from `from` (inclusive) until `till` (exclusive), where a missing bound is unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionRange {
+    pub(crate) from: Option<(u64, u64, u64)>,
+    pub(crate) till: Option<(u64, u64, u64)>,
+}
+
+impl VersionRange {
+    pub fn from(&self) -> Option<Version> {
+        self.from
+            .map(|(major, minor, patch)| Version::new(major, minor, patch))
+    }
+
+    pub fn till(&self) -> Option<Version> {
+        self.till
+            .map(|(major, minor, patch)| Version::new(major, minor, patch))
+    }
+
+    pub fn contains(&self, version: &Version) -> bool {
+        let version = (version.major, version.minor, version.patch);
+
+        self.from.map_or(true, |from| from <= version)
+            && self.till.map_or(true, |till| version < till)
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.from(), self.till()) {
+            (Some(from), Some(till)) => write!(f, ">={from} <{till}"),
+            (Some(from), None) => write!(f, ">={from}"),
+            (None, Some(till)) => write!(f, "<{till}"),
+            (None, None) => write!(f, "*"),
+        }
+    }
+}
+
+/// The words a keyword can be written as.
+#[derive(Debug)]
+#[allow(dead_code)] // Not constructed by languages without keywords that are only reserved in some versions.
+pub(crate) enum KeywordPattern {
+    Sequence(&'static [KeywordPattern]),
+    Optional(&'static KeywordPattern),
+    Choice(&'static [KeywordPattern]),
+    Atom(&'static str),
+}
+
+impl KeywordPattern {
+    pub(crate) fn matches(&self, word: &str) -> bool {
+        self.ends(word, 0).contains(&word.len())
+    }
+
+    /// Returns all offsets in the `word` where the pattern can end, if it starts at `start`.
+    fn ends(&self, word: &str, start: usize) -> Vec<usize> {
+        match self {
+            Self::Sequence(patterns) => patterns.iter().fold(vec![start], |starts, pattern| {
+                starts
+                    .into_iter()
+                    .flat_map(|start| pattern.ends(word, start))
+                    .collect()
+            }),
+            Self::Optional(pattern) => {
+                let mut ends = pattern.ends(word, start);
+                ends.push(start);
+                ends
+            }
+            Self::Choice(patterns) => patterns
+                .iter()
+                .flat_map(|pattern| pattern.ends(word, start))
+                .collect(),
+            Self::Atom(atom) if word[start..].starts_with(atom) => vec![start + atom.len()],
+            Self::Atom(_) => vec![],
+        }
+    }
+}
+
+/// A keyword that is only enabled in some versions (or starts a rule that is), and can be used as an identifier in
+/// the others.
+pub(crate) struct VersionedKeyword {
+    pub kind: TokenKind,
+    /// The kind of identifiers the keyword's words can be used as, when it is not enabled.
+    pub identifier: TokenKind,
+    pub pattern: KeywordPattern,
+    pub versions: &'static [VersionRange],
+    /// A rule that starts with this keyword, if it is also only enabled in some versions.
+    pub rule: Option<(RuleKind, &'static [VersionRange])>,
+}
+
+/// A construct of the language that is not enabled in all versions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Construct {
+    Rule(RuleKind),
+    Token(TokenKind),
+    /// An optional child with this label, in a rule of this kind.
+    Field(RuleKind, NodeLabel),
+    /// A variant with this (rule or token) kind, in a rule of this kind.
+    Variant(RuleKind, String),
+    /// An identifier with this text, which is reserved as a keyword in some versions.
+    Identifier(TokenKind, String),
+}
+
+impl fmt::Display for Construct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rule(kind) => write!(f, "{kind}"),
+            Self::Token(kind) => write!(f, "{kind}"),
+            Self::Field(kind, label) => write!(f, "'{label}' in {kind}"),
+            Self::Variant(kind, variant) => write!(f, "{variant} in {kind}"),
+            Self::Identifier(kind, text) => write!(f, "'{text}' as {kind}"),
+        }
+    }
+}
+
+/// A construct found in the input, and the versions it is enabled in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub construct: Construct,
+    /// Range of the construct in the input, excluding trivia.
+    pub text_range: TextRange,
+    pub versions: &'static [VersionRange],
+}
+
+impl Constraint {
+    pub fn allows(&self, version: &Version) -> bool {
+        allows(self.versions, version)
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} requires {}", self.construct, display(self.versions))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compatibility {
+    /// The supported versions that allow all constraints, in ascending order.
+    /// Empty if the input has errors in all supported versions.
+    pub versions: Vec<Version>,
+    /// The constructs found in the input that are not enabled in all versions, in source order.
+    pub constraints: Vec<Constraint>,
+}
+
+impl Compatibility {
+    /// Finds the constraints of a tree that was parsed without errors, and the versions its text can be parsed with.
+    pub fn from_tree(tree: &Node) -> Self {
+        let kind = tree.as_rule().expect("Parse trees start with a rule.").kind;
+
+        let (versions, _) = parse_all(kind, &tree.clone().unparse());
+
+        Self {
+            versions,
+            constraints: constraints(tree),
+        }
+    }
+
+    pub fn minimum_version(&self) -> Option<&Version> {
+        self.versions.first()
+    }
+
+    pub fn maximum_version(&self) -> Option<&Version> {
+        self.versions.last()
+    }
+
+    /// Returns the constraints that the `version` doesn't allow, i.e. the reasons the input can't be parsed with it.
+    /// This is empty if the input can't be parsed with it for other reasons, like literals that are written differently.
+    pub fn constraints_excluding<'a>(
+        &'a self,
+        version: &'a Version,
+    ) -> impl Iterator<Item = &'a Constraint> + 'a {
+        self.constraints
+            .iter()
+            .filter(|constraint| !constraint.allows(version))
+    }
+}
+
+/// Parses the `input`, and returns the supported versions it can be parsed with without errors, along with the
+/// constructs of its tree (in the latest of these versions) that narrow them.
+///
+/// Since the parser only changes in the versions referenced by the language definition, the input is parsed once
+/// with each range of versions between them, instead of once with each supported version. The constraints of the tree
+/// alone are not enough, as keywords that are identifiers in other versions, or literals that are written differently,
+/// change which versions can parse the input as well.
+pub fn check_compatibility(kind: RuleKind, input: &str) -> Compatibility {
+    let (versions, tree) = parse_all(kind, input);
+
+    Compatibility {
+        versions,
+        constraints: tree.as_ref().map(constraints).unwrap_or_default(),
+    }
+}
+
+/// Parses the `input` with each range of versions in which the language doesn't change, and returns the supported
+/// versions it can be parsed with without errors, along with the tree it was parsed into by the latest of them.
+fn parse_all(kind: RuleKind, input: &str) -> (Vec<Version>, Option<Node>) {
+    // The number of changes to the language up to a version, which is the same for all versions in a range:
+    let changes = |version: &Version| {
+        Language::BREAKING_VERSIONS.partition_point(|breaking| breaking <= version)
+    };
+
+    let mut versions = vec![];
+    let mut tree = None;
+
+    let mut remaining = Language::SUPPORTED_VERSIONS;
+    while let Some(first) = remaining.first() {
+        let len = remaining
+            .iter()
+            .take_while(|version| changes(version) == changes(first))
+            .count();
+        let (range, rest) = remaining.split_at(len);
+        remaining = rest;
+
+        let language = Language::new(first.clone()).expect("Supported versions are supported.");
+        let output = language.parse(kind, input);
+
+        if output.is_valid() {
+            versions.extend_from_slice(range);
+            tree = Some(output.tree());
+        }
+    }
+
+    (versions, tree)
+}
+
+/// Finds the constructs of a tree that are not enabled in all versions, in source order.
+fn constraints(tree: &Node) -> Vec<Constraint> {
+    let mut constraints = vec![];
+    collect(tree, None, None, TextIndex::ZERO, &mut constraints);
+    constraints
+}
+
+fn collect(
+    node: &Node,
+    parent: Option<RuleKind>,
+    label: Option<NodeLabel>,
+    offset: TextIndex,
+    constraints: &mut Vec<Constraint>,
+) {
+    let mut add = |construct, versions| {
+        if let (Some(versions), Some(text_range)) = (versions, significant_range(node, offset)) {
+            constraints.push(Constraint {
+                construct,
+                text_range,
+                versions,
+            });
+        }
+    };
+
+    let kind_name = match node {
+        Node::Rule(rule) => rule.kind.as_ref(),
+        Node::Token(token) => token.kind.as_ref(),
+    };
+
+    if let Some(parent) = parent {
+        match label {
+            Some(NodeLabel::Variant) => add(
+                Construct::Variant(parent, kind_name.to_owned()),
+                Language::variant_versions(parent, kind_name),
+            ),
+            Some(label) => add(
+                Construct::Field(parent, label),
+                Language::field_versions(parent, label),
+            ),
+            None => {}
+        }
+    }
+
+    match node {
+        Node::Rule(rule) => {
+            add(
+                Construct::Rule(rule.kind),
+                Language::rule_kind_versions(rule.kind),
+            );
+
+            let mut child_offset = offset;
+            for child in &rule.children {
+                collect(
+                    &child.node,
+                    Some(rule.kind),
+                    child.label,
+                    child_offset,
+                    constraints,
+                );
+                child_offset += child.text_len();
+            }
+        }
+        Node::Token(token) => {
+            for (pattern, versions) in Language::unreserved_keywords(token.kind) {
+                if pattern.matches(&token.text) {
+                    add(
+                        Construct::Identifier(token.kind, token.text.clone()),
+                        Some(versions),
+                    );
+                }
+            }
+
+            add(
+                Construct::Token(token.kind),
+                Language::token_kind_versions(token.kind),
+            );
+        }
+    }
+}
+
+/// Returns the range of the node, excluding its leading and trailing trivia, or `None` if it only contains trivia.
+fn significant_range(node: &Node, offset: TextIndex) -> Option<TextRange> {
+    let rule = match node {
+        Node::Token(token) if token.kind.is_trivia() => return None,
+        Node::Token(_) => return Some(offset..offset + node.text_len()),
+        Node::Rule(rule) => rule,
+    };
+
+    let mut starts = Vec::with_capacity(rule.children.len());
+    let mut child_offset = offset;
+    for child in &rule.children {
+        starts.push(child_offset);
+        child_offset += child.text_len();
+    }
+
+    let children = || rule.children.iter().zip(starts.iter().copied());
+
+    let start = children().find_map(|(child, start)| significant_range(child, start))?;
+    let end = children()
+        .rev()
+        .find_map(|(child, start)| significant_range(child, start))?;
+
+    Some(start.start..end.end)
+}
+
+/// Adds a note to each error that could be caused by a word before or at its start, that is a keyword (or starts a
+/// rule) that is only enabled in other versions, or that is reserved in this version but can be used as an identifier
+/// in others.
+pub(crate) fn add_version_notes(language: &Language, output: &mut ParseOutput) {
+    if output.errors.is_empty() {
+        return;
+    }
+
+    let mut tokens = vec![];
+    collect_tokens(&output.parse_tree, TextIndex::ZERO, &mut tokens);
+
+    for error in &mut output.errors {
+        let notes = error_notes(language, &tokens, error);
+
+        if !notes.is_empty() {
+            error.note = Some(notes.join(" "));
+        }
+    }
+}
+
+/// Returns the notes of an error, caused by the last words before it or the first one in it.
+fn error_notes(
+    language: &Language,
+    tokens: &[(TextIndex, TokenKind, String)],
+    error: &ParseError,
+) -> Vec<String> {
+    if error.kind.is_limit_exceeded() {
+        return vec![];
+    }
+
+    // The last two tokens before the error, and the first one in it:
+    let index = tokens.partition_point(|(start, _, _)| *start < error.text_range.start);
+    let words = tokens[index.saturating_sub(2)..(index + 1).min(tokens.len())]
+        .iter()
+        .map(|(_, kind, text)| match kind {
+            // Only the first word of skipped input is used, and it can be of any kind:
+            TokenKind::SKIPPED => (None, first_word(text)),
+            kind => (Some(*kind), text.as_str()),
+        });
+
+    let mut notes: Vec<String> = vec![];
+    for (kind, word) in words {
+        for note in word_notes(language, error, kind, word) {
+            if !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+    }
+
+    notes
+}
+
+fn word_notes(
+    language: &Language,
+    error: &ParseError,
+    kind: Option<TokenKind>,
+    word: &str,
+) -> Vec<String> {
+    let version = language.version();
+    let name = Language::NAME;
+    let mut notes = vec![];
+
+    for keyword in Language::VERSIONED_KEYWORDS {
+        if kind.is_some_and(|kind| kind != keyword.kind && kind != keyword.identifier)
+            || !keyword.pattern.matches(word)
+        {
+            continue;
+        }
+
+        match keyword.rule {
+            Some((rule, versions)) if !allows(versions, version) => {
+                notes.push(format!(
+                    "{rule} (starting with '{word}') requires {name} {}.",
+                    display(versions)
+                ));
+            }
+            _ if !allows(keyword.versions, version) => {
+                notes.push(format!(
+                    "'{word}' is only a keyword in {name} {}.",
+                    display(keyword.versions)
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // Words that are reserved in this version can't be used where identifiers are expected,
+    // unless they are keywords of other versions, which is a more likely explanation:
+    if !notes.is_empty() {
+        return notes;
+    }
+
+    for identifier in &error.tokens_that_would_have_allowed_more_progress {
+        if kind == Some(*identifier) {
+            continue;
+        }
+
+        for (pattern, unreserved) in Language::unreserved_keywords(*identifier) {
+            if allows(unreserved, version) || !pattern.matches(word) {
+                continue;
+            }
+
+            let since = unreserved
+                .iter()
+                .filter_map(VersionRange::till)
+                .filter(|till| till <= version)
+                .max();
+
+            let before = unreserved
+                .iter()
+                .filter_map(VersionRange::from)
+                .filter(|from| from > version)
+                .min();
+
+            notes.push(match (since, before) {
+                (Some(since), _) => format!("'{word}' is reserved since {name} {since}."),
+                (None, Some(before)) => format!("'{word}' is reserved before {name} {before}."),
+                (None, None) => format!("'{word}' is reserved in {name} {version}."),
+            });
+        }
+    }
+
+    notes
+}
+
+/// Collects the start, kind, and text of all tokens in the tree, except trivia and missing tokens.
+fn collect_tokens(
+    node: &Node,
+    offset: TextIndex,
+    tokens: &mut Vec<(TextIndex, TokenKind, String)>,
+) {
+    match node {
+        Node::Rule(rule) => {
+            let mut child_offset = offset;
+            for child in &rule.children {
+                collect_tokens(&child.node, child_offset, tokens);
+                child_offset += child.text_len();
+            }
+        }
+        Node::Token(token) if token.kind.is_trivia() || token.kind == TokenKind::MISSING => {}
+        Node::Token(token) => tokens.push((offset, token.kind, token.text.clone())),
+    }
+}
+
+fn first_word(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(text.len());
+
+    &text[..end]
+}
+
+fn allows(ranges: &[VersionRange], version: &Version) -> bool {
+    ranges.iter().any(|range| range.contains(version))
+}
+
+fn display(ranges: &[VersionRange]) -> String {
+    ranges
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
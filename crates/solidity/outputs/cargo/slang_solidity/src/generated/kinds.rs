@@ -10,10 +10,12 @@ use napi_derive::napi;
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
@@ -234,6 +236,296 @@ pub enum RuleKind {
     YulVariableDeclarationValue,
 }
 
+impl RuleKind {
+    /// Whether nodes of this kind are lists, whose children are labeled as items (and separators, if any).
+    pub fn is_list(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::SourceUnitMembers => true,
+            Self::VersionExpressionSet => true,
+            Self::ContractMembers => true,
+            Self::InterfaceMembers => true,
+            Self::LibraryMembers => true,
+            Self::StructMembers => true,
+            Self::StateVariableAttributes => true,
+            Self::FunctionAttributes => true,
+            Self::ConstructorAttributes => true,
+            Self::UnnamedFunctionAttributes => true,
+            Self::FallbackFunctionAttributes => true,
+            Self::ReceiveFunctionAttributes => true,
+            Self::ModifierAttributes => true,
+            Self::FunctionTypeAttributes => true,
+            Self::Statements => true,
+            Self::CatchClauses => true,
+            Self::StringLiterals => true,
+            Self::HexStringLiterals => true,
+            Self::UnicodeStringLiterals => true,
+            Self::YulStatements => true,
+            Self::YulSwitchCases => true,
+            Self::VersionExpressionSets => true,
+            Self::VersionSpecifiers => true,
+            Self::ImportDeconstructionSymbols => true,
+            Self::UsingDeconstructionSymbols => true,
+            Self::InheritanceTypes => true,
+            Self::EnumMembers => true,
+            Self::Parameters => true,
+            Self::OverridePaths => true,
+            Self::EventParameters => true,
+            Self::ErrorParameters => true,
+            Self::AssemblyFlags => true,
+            Self::TupleDeconstructionElements => true,
+            Self::PositionalArguments => true,
+            Self::NamedArguments => true,
+            Self::CallOptions => true,
+            Self::TupleValues => true,
+            Self::ArrayValues => true,
+            Self::IdentifierPath => true,
+            Self::YulParameters => true,
+            Self::YulReturnVariables => true,
+            Self::YulArguments => true,
+            Self::YulPaths => true,
+            Self::YulPath => true,
+            _ => false,
+        }
+    }
+
+    /// The kinds of the tokens that are variants of this rule, if it is a choice (e.g. the units of a number).
+    #[allow(clippy::too_many_lines)]
+    pub fn token_variants(&self) -> &'static [TokenKind] {
+        match self {
+            Self::ExperimentalFeature => &[TokenKind::Identifier],
+            Self::VersionExpression => &[
+                TokenKind::SingleQuotedVersionLiteral,
+                TokenKind::DoubleQuotedVersionLiteral,
+            ],
+            Self::UsingOperator => &[
+                TokenKind::Ampersand,
+                TokenKind::Asterisk,
+                TokenKind::BangEqual,
+                TokenKind::Bar,
+                TokenKind::Caret,
+                TokenKind::EqualEqual,
+                TokenKind::GreaterThan,
+                TokenKind::GreaterThanEqual,
+                TokenKind::LessThan,
+                TokenKind::LessThanEqual,
+                TokenKind::Minus,
+                TokenKind::Percent,
+                TokenKind::Plus,
+                TokenKind::Slash,
+                TokenKind::Tilde,
+            ],
+            Self::UsingTarget => &[TokenKind::Asterisk],
+            Self::StateVariableAttribute => &[
+                TokenKind::ConstantKeyword,
+                TokenKind::InternalKeyword,
+                TokenKind::PrivateKeyword,
+                TokenKind::PublicKeyword,
+                TokenKind::ImmutableKeyword,
+            ],
+            Self::FunctionName => &[
+                TokenKind::Identifier,
+                TokenKind::FallbackKeyword,
+                TokenKind::ReceiveKeyword,
+            ],
+            Self::FunctionAttribute => &[
+                TokenKind::ConstantKeyword,
+                TokenKind::ExternalKeyword,
+                TokenKind::InternalKeyword,
+                TokenKind::PayableKeyword,
+                TokenKind::PrivateKeyword,
+                TokenKind::PublicKeyword,
+                TokenKind::PureKeyword,
+                TokenKind::ViewKeyword,
+                TokenKind::VirtualKeyword,
+            ],
+            Self::FunctionBody => &[TokenKind::Semicolon],
+            Self::ConstructorAttribute => &[
+                TokenKind::InternalKeyword,
+                TokenKind::OverrideKeyword,
+                TokenKind::PayableKeyword,
+                TokenKind::PublicKeyword,
+                TokenKind::VirtualKeyword,
+            ],
+            Self::UnnamedFunctionAttribute => &[
+                TokenKind::ConstantKeyword,
+                TokenKind::ExternalKeyword,
+                TokenKind::InternalKeyword,
+                TokenKind::PayableKeyword,
+                TokenKind::PrivateKeyword,
+                TokenKind::PublicKeyword,
+                TokenKind::PureKeyword,
+                TokenKind::ViewKeyword,
+            ],
+            Self::FallbackFunctionAttribute => &[
+                TokenKind::ExternalKeyword,
+                TokenKind::PayableKeyword,
+                TokenKind::PureKeyword,
+                TokenKind::ViewKeyword,
+                TokenKind::VirtualKeyword,
+            ],
+            Self::ReceiveFunctionAttribute => &[
+                TokenKind::ExternalKeyword,
+                TokenKind::PayableKeyword,
+                TokenKind::VirtualKeyword,
+            ],
+            Self::ModifierAttribute => &[TokenKind::VirtualKeyword],
+            Self::FunctionTypeAttribute => &[
+                TokenKind::InternalKeyword,
+                TokenKind::ExternalKeyword,
+                TokenKind::PrivateKeyword,
+                TokenKind::PublicKeyword,
+                TokenKind::ConstantKeyword,
+                TokenKind::PureKeyword,
+                TokenKind::ViewKeyword,
+                TokenKind::PayableKeyword,
+            ],
+            Self::ElementaryType => &[
+                TokenKind::BoolKeyword,
+                TokenKind::ByteKeyword,
+                TokenKind::StringKeyword,
+                TokenKind::BytesKeyword,
+                TokenKind::IntKeyword,
+                TokenKind::UintKeyword,
+                TokenKind::FixedKeyword,
+                TokenKind::UfixedKeyword,
+            ],
+            Self::VariableDeclarationType => &[TokenKind::VarKeyword],
+            Self::StorageLocation => &[
+                TokenKind::MemoryKeyword,
+                TokenKind::StorageKeyword,
+                TokenKind::CallDataKeyword,
+            ],
+            Self::ForStatementInitialization => &[TokenKind::Semicolon],
+            Self::ForStatementCondition => &[TokenKind::Semicolon],
+            Self::Expression => &[
+                TokenKind::PayableKeyword,
+                TokenKind::TrueKeyword,
+                TokenKind::FalseKeyword,
+                TokenKind::Identifier,
+            ],
+            Self::MemberAccess => &[TokenKind::Identifier, TokenKind::AddressKeyword],
+            Self::NumberUnit => &[
+                TokenKind::WeiKeyword,
+                TokenKind::GweiKeyword,
+                TokenKind::SzaboKeyword,
+                TokenKind::FinneyKeyword,
+                TokenKind::EtherKeyword,
+                TokenKind::SecondsKeyword,
+                TokenKind::MinutesKeyword,
+                TokenKind::HoursKeyword,
+                TokenKind::DaysKeyword,
+                TokenKind::WeeksKeyword,
+                TokenKind::YearsKeyword,
+            ],
+            Self::StringLiteral => &[
+                TokenKind::SingleQuotedStringLiteral,
+                TokenKind::DoubleQuotedStringLiteral,
+            ],
+            Self::HexStringLiteral => &[
+                TokenKind::SingleQuotedHexStringLiteral,
+                TokenKind::DoubleQuotedHexStringLiteral,
+            ],
+            Self::UnicodeStringLiteral => &[
+                TokenKind::SingleQuotedUnicodeStringLiteral,
+                TokenKind::DoubleQuotedUnicodeStringLiteral,
+            ],
+            Self::YulAssignmentOperator => &[TokenKind::ColonEqual],
+            Self::YulPathComponent => &[TokenKind::YulIdentifier, TokenKind::YulAddressKeyword],
+            Self::YulBuiltInFunction => &[
+                TokenKind::YulAddKeyword,
+                TokenKind::YulAddModKeyword,
+                TokenKind::YulAddressKeyword,
+                TokenKind::YulAndKeyword,
+                TokenKind::YulBalanceKeyword,
+                TokenKind::YulBlockHashKeyword,
+                TokenKind::YulByteKeyword,
+                TokenKind::YulCallCodeKeyword,
+                TokenKind::YulCallDataCopyKeyword,
+                TokenKind::YulCallDataLoadKeyword,
+                TokenKind::YulCallDataSizeKeyword,
+                TokenKind::YulCallerKeyword,
+                TokenKind::YulCallKeyword,
+                TokenKind::YulCallValueKeyword,
+                TokenKind::YulCoinBaseKeyword,
+                TokenKind::YulCreateKeyword,
+                TokenKind::YulDelegateCallKeyword,
+                TokenKind::YulDivKeyword,
+                TokenKind::YulEqKeyword,
+                TokenKind::YulExpKeyword,
+                TokenKind::YulExtCodeCopyKeyword,
+                TokenKind::YulExtCodeSizeKeyword,
+                TokenKind::YulGasKeyword,
+                TokenKind::YulGasLimitKeyword,
+                TokenKind::YulGasPriceKeyword,
+                TokenKind::YulGtKeyword,
+                TokenKind::YulInvalidKeyword,
+                TokenKind::YulIsZeroKeyword,
+                TokenKind::YulLog0Keyword,
+                TokenKind::YulLog1Keyword,
+                TokenKind::YulLog2Keyword,
+                TokenKind::YulLog3Keyword,
+                TokenKind::YulLog4Keyword,
+                TokenKind::YulLtKeyword,
+                TokenKind::YulMLoadKeyword,
+                TokenKind::YulModKeyword,
+                TokenKind::YulMSizeKeyword,
+                TokenKind::YulMStore8Keyword,
+                TokenKind::YulMStoreKeyword,
+                TokenKind::YulMulKeyword,
+                TokenKind::YulMulModKeyword,
+                TokenKind::YulNotKeyword,
+                TokenKind::YulNumberKeyword,
+                TokenKind::YulOriginKeyword,
+                TokenKind::YulOrKeyword,
+                TokenKind::YulPopKeyword,
+                TokenKind::YulReturnKeyword,
+                TokenKind::YulRevertKeyword,
+                TokenKind::YulSDivKeyword,
+                TokenKind::YulSelfDestructKeyword,
+                TokenKind::YulSgtKeyword,
+                TokenKind::YulSignExtendKeyword,
+                TokenKind::YulSLoadKeyword,
+                TokenKind::YulSltKeyword,
+                TokenKind::YulSModKeyword,
+                TokenKind::YulSStoreKeyword,
+                TokenKind::YulStopKeyword,
+                TokenKind::YulSubKeyword,
+                TokenKind::YulTimestampKeyword,
+                TokenKind::YulXorKeyword,
+                TokenKind::YulKeccak256Keyword,
+                TokenKind::YulSha3Keyword,
+                TokenKind::YulSuicideKeyword,
+                TokenKind::YulReturnDataCopyKeyword,
+                TokenKind::YulReturnDataSizeKeyword,
+                TokenKind::YulStaticCallKeyword,
+                TokenKind::YulCreate2Keyword,
+                TokenKind::YulExtCodeHashKeyword,
+                TokenKind::YulSarKeyword,
+                TokenKind::YulShlKeyword,
+                TokenKind::YulShrKeyword,
+                TokenKind::YulChainIdKeyword,
+                TokenKind::YulSelfBalanceKeyword,
+                TokenKind::YulBaseFeeKeyword,
+                TokenKind::YulDifficultyKeyword,
+                TokenKind::YulPrevRandaoKeyword,
+                TokenKind::YulBlobBaseFeeKeyword,
+                TokenKind::YulBlobHashKeyword,
+                TokenKind::YulTLoadKeyword,
+                TokenKind::YulTStoreKeyword,
+                TokenKind::YulMCopyKeyword,
+            ],
+            Self::YulLiteral => &[
+                TokenKind::YulTrueKeyword,
+                TokenKind::YulFalseKeyword,
+                TokenKind::YulDecimalLiteral,
+                TokenKind::YulHexLiteral,
+            ],
+            _ => &[],
+        }
+    }
+}
+
 #[derive(
     Debug,
     Eq,
@@ -241,10 +533,12 @@ pub enum RuleKind {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[strum(serialize_all = "snake_case")]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
@@ -392,15 +686,19 @@ pub enum NodeLabel {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
 pub enum TokenKind {
     SKIPPED,
+    /// A zero-width token inserted by error recovery, in place of an expected token that was missing.
+    MISSING,
     AbicoderKeyword,
     AbstractKeyword,
     AddressKeyword,
@@ -783,11 +1081,358 @@ impl TokenKind {
             _ => false,
         }
     }
+
+    /// Whether this token is a keyword of the language, even if it is only reserved in some versions.
+    #[allow(clippy::too_many_lines)]
+    pub fn is_keyword(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::AbicoderKeyword => true,
+            Self::AbstractKeyword => true,
+            Self::AddressKeyword => true,
+            Self::AfterKeyword => true,
+            Self::AliasKeyword => true,
+            Self::AnonymousKeyword => true,
+            Self::ApplyKeyword => true,
+            Self::AsKeyword => true,
+            Self::AssemblyKeyword => true,
+            Self::AutoKeyword => true,
+            Self::BoolKeyword => true,
+            Self::BreakKeyword => true,
+            Self::ByteKeyword => true,
+            Self::BytesKeyword => true,
+            Self::CallDataKeyword => true,
+            Self::CaseKeyword => true,
+            Self::CatchKeyword => true,
+            Self::ConstantKeyword => true,
+            Self::ConstructorKeyword => true,
+            Self::ContinueKeyword => true,
+            Self::ContractKeyword => true,
+            Self::CopyOfKeyword => true,
+            Self::DaysKeyword => true,
+            Self::DefaultKeyword => true,
+            Self::DefineKeyword => true,
+            Self::DeleteKeyword => true,
+            Self::DoKeyword => true,
+            Self::ElseKeyword => true,
+            Self::EmitKeyword => true,
+            Self::EnumKeyword => true,
+            Self::ErrorKeyword => true,
+            Self::EtherKeyword => true,
+            Self::EventKeyword => true,
+            Self::ExperimentalKeyword => true,
+            Self::ExternalKeyword => true,
+            Self::FallbackKeyword => true,
+            Self::FalseKeyword => true,
+            Self::FinalKeyword => true,
+            Self::FinneyKeyword => true,
+            Self::FixedKeyword => true,
+            Self::ForKeyword => true,
+            Self::FromKeyword => true,
+            Self::FunctionKeyword => true,
+            Self::GlobalKeyword => true,
+            Self::GweiKeyword => true,
+            Self::HexKeyword => true,
+            Self::HoursKeyword => true,
+            Self::IfKeyword => true,
+            Self::ImmutableKeyword => true,
+            Self::ImplementsKeyword => true,
+            Self::ImportKeyword => true,
+            Self::InKeyword => true,
+            Self::IndexedKeyword => true,
+            Self::InlineKeyword => true,
+            Self::IntKeyword => true,
+            Self::InterfaceKeyword => true,
+            Self::InternalKeyword => true,
+            Self::IsKeyword => true,
+            Self::LetKeyword => true,
+            Self::LibraryKeyword => true,
+            Self::MacroKeyword => true,
+            Self::MappingKeyword => true,
+            Self::MatchKeyword => true,
+            Self::MemoryKeyword => true,
+            Self::MinutesKeyword => true,
+            Self::ModifierKeyword => true,
+            Self::MutableKeyword => true,
+            Self::NewKeyword => true,
+            Self::NullKeyword => true,
+            Self::OfKeyword => true,
+            Self::OverrideKeyword => true,
+            Self::PartialKeyword => true,
+            Self::PayableKeyword => true,
+            Self::PragmaKeyword => true,
+            Self::PrivateKeyword => true,
+            Self::PromiseKeyword => true,
+            Self::PublicKeyword => true,
+            Self::PureKeyword => true,
+            Self::ReceiveKeyword => true,
+            Self::ReferenceKeyword => true,
+            Self::RelocatableKeyword => true,
+            Self::ReturnKeyword => true,
+            Self::ReturnsKeyword => true,
+            Self::RevertKeyword => true,
+            Self::SealedKeyword => true,
+            Self::SecondsKeyword => true,
+            Self::SizeOfKeyword => true,
+            Self::SolidityKeyword => true,
+            Self::StaticKeyword => true,
+            Self::StorageKeyword => true,
+            Self::StringKeyword => true,
+            Self::StructKeyword => true,
+            Self::SupportsKeyword => true,
+            Self::SwitchKeyword => true,
+            Self::SzaboKeyword => true,
+            Self::ThrowKeyword => true,
+            Self::TrueKeyword => true,
+            Self::TryKeyword => true,
+            Self::TypeDefKeyword => true,
+            Self::TypeKeyword => true,
+            Self::TypeOfKeyword => true,
+            Self::UfixedKeyword => true,
+            Self::UintKeyword => true,
+            Self::UncheckedKeyword => true,
+            Self::UsingKeyword => true,
+            Self::VarKeyword => true,
+            Self::ViewKeyword => true,
+            Self::VirtualKeyword => true,
+            Self::WeeksKeyword => true,
+            Self::WeiKeyword => true,
+            Self::WhileKeyword => true,
+            Self::YearsKeyword => true,
+            Self::YulAbstractKeyword => true,
+            Self::YulAddKeyword => true,
+            Self::YulAddModKeyword => true,
+            Self::YulAddressKeyword => true,
+            Self::YulAfterKeyword => true,
+            Self::YulAliasKeyword => true,
+            Self::YulAndKeyword => true,
+            Self::YulAnonymousKeyword => true,
+            Self::YulApplyKeyword => true,
+            Self::YulAsKeyword => true,
+            Self::YulAssemblyKeyword => true,
+            Self::YulAutoKeyword => true,
+            Self::YulBalanceKeyword => true,
+            Self::YulBaseFeeKeyword => true,
+            Self::YulBlobBaseFeeKeyword => true,
+            Self::YulBlobHashKeyword => true,
+            Self::YulBlockHashKeyword => true,
+            Self::YulBoolKeyword => true,
+            Self::YulBreakKeyword => true,
+            Self::YulByteKeyword => true,
+            Self::YulBytesKeyword => true,
+            Self::YulCallCodeKeyword => true,
+            Self::YulCallDataCopyKeyword => true,
+            Self::YulCallDataKeyword => true,
+            Self::YulCallDataLoadKeyword => true,
+            Self::YulCallDataSizeKeyword => true,
+            Self::YulCallKeyword => true,
+            Self::YulCallValueKeyword => true,
+            Self::YulCallerKeyword => true,
+            Self::YulCaseKeyword => true,
+            Self::YulCatchKeyword => true,
+            Self::YulChainIdKeyword => true,
+            Self::YulCoinBaseKeyword => true,
+            Self::YulConstantKeyword => true,
+            Self::YulConstructorKeyword => true,
+            Self::YulContinueKeyword => true,
+            Self::YulContractKeyword => true,
+            Self::YulCopyOfKeyword => true,
+            Self::YulCreate2Keyword => true,
+            Self::YulCreateKeyword => true,
+            Self::YulDaysKeyword => true,
+            Self::YulDefaultKeyword => true,
+            Self::YulDefineKeyword => true,
+            Self::YulDelegateCallKeyword => true,
+            Self::YulDeleteKeyword => true,
+            Self::YulDifficultyKeyword => true,
+            Self::YulDivKeyword => true,
+            Self::YulDoKeyword => true,
+            Self::YulElseKeyword => true,
+            Self::YulEmitKeyword => true,
+            Self::YulEnumKeyword => true,
+            Self::YulEqKeyword => true,
+            Self::YulEtherKeyword => true,
+            Self::YulEventKeyword => true,
+            Self::YulExpKeyword => true,
+            Self::YulExtCodeCopyKeyword => true,
+            Self::YulExtCodeHashKeyword => true,
+            Self::YulExtCodeSizeKeyword => true,
+            Self::YulExternalKeyword => true,
+            Self::YulFallbackKeyword => true,
+            Self::YulFalseKeyword => true,
+            Self::YulFinalKeyword => true,
+            Self::YulFinneyKeyword => true,
+            Self::YulFixedKeyword => true,
+            Self::YulForKeyword => true,
+            Self::YulFunctionKeyword => true,
+            Self::YulGasKeyword => true,
+            Self::YulGasLimitKeyword => true,
+            Self::YulGasPriceKeyword => true,
+            Self::YulGtKeyword => true,
+            Self::YulGweiKeyword => true,
+            Self::YulHexKeyword => true,
+            Self::YulHoursKeyword => true,
+            Self::YulIfKeyword => true,
+            Self::YulImmutableKeyword => true,
+            Self::YulImplementsKeyword => true,
+            Self::YulImportKeyword => true,
+            Self::YulInKeyword => true,
+            Self::YulIndexedKeyword => true,
+            Self::YulInlineKeyword => true,
+            Self::YulIntKeyword => true,
+            Self::YulInterfaceKeyword => true,
+            Self::YulInternalKeyword => true,
+            Self::YulInvalidKeyword => true,
+            Self::YulIsKeyword => true,
+            Self::YulIsZeroKeyword => true,
+            Self::YulKeccak256Keyword => true,
+            Self::YulLeaveKeyword => true,
+            Self::YulLetKeyword => true,
+            Self::YulLibraryKeyword => true,
+            Self::YulLog0Keyword => true,
+            Self::YulLog1Keyword => true,
+            Self::YulLog2Keyword => true,
+            Self::YulLog3Keyword => true,
+            Self::YulLog4Keyword => true,
+            Self::YulLtKeyword => true,
+            Self::YulMCopyKeyword => true,
+            Self::YulMLoadKeyword => true,
+            Self::YulMSizeKeyword => true,
+            Self::YulMStore8Keyword => true,
+            Self::YulMStoreKeyword => true,
+            Self::YulMacroKeyword => true,
+            Self::YulMappingKeyword => true,
+            Self::YulMatchKeyword => true,
+            Self::YulMemoryKeyword => true,
+            Self::YulMinutesKeyword => true,
+            Self::YulModKeyword => true,
+            Self::YulModifierKeyword => true,
+            Self::YulMulKeyword => true,
+            Self::YulMulModKeyword => true,
+            Self::YulMutableKeyword => true,
+            Self::YulNewKeyword => true,
+            Self::YulNotKeyword => true,
+            Self::YulNullKeyword => true,
+            Self::YulNumberKeyword => true,
+            Self::YulOfKeyword => true,
+            Self::YulOrKeyword => true,
+            Self::YulOriginKeyword => true,
+            Self::YulOverrideKeyword => true,
+            Self::YulPartialKeyword => true,
+            Self::YulPayableKeyword => true,
+            Self::YulPopKeyword => true,
+            Self::YulPragmaKeyword => true,
+            Self::YulPrevRandaoKeyword => true,
+            Self::YulPrivateKeyword => true,
+            Self::YulPromiseKeyword => true,
+            Self::YulPublicKeyword => true,
+            Self::YulPureKeyword => true,
+            Self::YulReceiveKeyword => true,
+            Self::YulReferenceKeyword => true,
+            Self::YulRelocatableKeyword => true,
+            Self::YulReturnDataCopyKeyword => true,
+            Self::YulReturnDataSizeKeyword => true,
+            Self::YulReturnKeyword => true,
+            Self::YulReturnsKeyword => true,
+            Self::YulRevertKeyword => true,
+            Self::YulSDivKeyword => true,
+            Self::YulSLoadKeyword => true,
+            Self::YulSModKeyword => true,
+            Self::YulSStoreKeyword => true,
+            Self::YulSarKeyword => true,
+            Self::YulSealedKeyword => true,
+            Self::YulSecondsKeyword => true,
+            Self::YulSelfBalanceKeyword => true,
+            Self::YulSelfDestructKeyword => true,
+            Self::YulSgtKeyword => true,
+            Self::YulSha3Keyword => true,
+            Self::YulShlKeyword => true,
+            Self::YulShrKeyword => true,
+            Self::YulSignExtendKeyword => true,
+            Self::YulSizeOfKeyword => true,
+            Self::YulSltKeyword => true,
+            Self::YulStaticCallKeyword => true,
+            Self::YulStaticKeyword => true,
+            Self::YulStopKeyword => true,
+            Self::YulStorageKeyword => true,
+            Self::YulStringKeyword => true,
+            Self::YulStructKeyword => true,
+            Self::YulSubKeyword => true,
+            Self::YulSuicideKeyword => true,
+            Self::YulSupportsKeyword => true,
+            Self::YulSwitchKeyword => true,
+            Self::YulSzaboKeyword => true,
+            Self::YulTLoadKeyword => true,
+            Self::YulTStoreKeyword => true,
+            Self::YulThrowKeyword => true,
+            Self::YulTimestampKeyword => true,
+            Self::YulTrueKeyword => true,
+            Self::YulTryKeyword => true,
+            Self::YulTypeDefKeyword => true,
+            Self::YulTypeKeyword => true,
+            Self::YulTypeOfKeyword => true,
+            Self::YulUfixedKeyword => true,
+            Self::YulUintKeyword => true,
+            Self::YulUncheckedKeyword => true,
+            Self::YulUsingKeyword => true,
+            Self::YulVarKeyword => true,
+            Self::YulViewKeyword => true,
+            Self::YulVirtualKeyword => true,
+            Self::YulWeeksKeyword => true,
+            Self::YulWeiKeyword => true,
+            Self::YulWhileKeyword => true,
+            Self::YulXorKeyword => true,
+            Self::YulYearsKeyword => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token opens a delimited group (e.g. `(`), which is incomplete until it is closed.
+    pub fn is_open_delimiter(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::OpenBrace => true,
+            Self::OpenBracket => true,
+            Self::OpenParen => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token closes a delimited group (e.g. `)`).
+    pub fn is_close_delimiter(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::CloseBrace => true,
+            Self::CloseBracket => true,
+            Self::CloseParen => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token terminates a construct (e.g. `;`).
+    pub fn is_terminator(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::Semicolon => true,
+            _ => false,
+        }
+    }
 }
 
-/// The lexical context of the scanner.
-#[derive(strum_macros::FromRepr, Clone, Copy)]
-pub(crate) enum LexicalContext {
+/// The lexical context of the scanner, which determines the set of tokens that can be recognized.
+#[derive(
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum_macros::AsRefStr,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::FromRepr,
+    Clone,
+    Copy,
+)]
+pub enum LexicalContext {
     Default,
     Pragma,
     Yul,
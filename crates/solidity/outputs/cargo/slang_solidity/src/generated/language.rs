@@ -7,6 +7,7 @@
     clippy::unused_self,
     clippy::struct_excessive_bools,
     clippy::similar_names,
+    clippy::match_single_binding,
     unused_imports
 )]
 
@@ -14,6 +15,7 @@
 use napi_derive::napi;
 use semver::Version;
 
+use crate::compatibility::{self, KeywordPattern, VersionRange, VersionedKeyword};
 use crate::cst;
 use crate::kinds::{
     IsLexicalContext, LexicalContext, LexicalContextType, NodeLabel, RuleKind, TokenKind,
@@ -21,11 +23,15 @@ use crate::kinds::{
 use crate::lexer::{KeywordScan, Lexer, ScannedToken};
 #[cfg(feature = "slang_napi_interfaces")]
 use crate::napi_interface::parse_output::ParseOutput as NAPIParseOutput;
+use crate::parse_options::ParseOptions;
 use crate::parse_output::ParseOutput;
 use crate::parser_support::{
-    ChoiceHelper, OneOrMoreHelper, OptionalHelper, ParserContext, ParserFunction, ParserResult,
-    PrecedenceHelper, SeparatedHelper, SequenceHelper, TokenAcceptanceThreshold, ZeroOrMoreHelper,
+    reparse, ChoiceHelper, OneOrMoreHelper, OptionalHelper, ParserContext, ParserFunction,
+    ParserResult, PrecedenceHelper, SeparatedHelper, SequenceHelper, TokenAcceptanceThreshold,
+    ZeroOrMoreHelper,
 };
+use crate::text_index::TextEdit;
+use crate::tokenizer::Tokenizer;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "slang_napi_interfaces", napi(namespace = "language"))]
@@ -212,1132 +218,5139 @@ impl Language {
         &self.version
     }
 
+    /********************************************
+     *         Version Compatibility
+     ********************************************/
+
+    pub(crate) const NAME: &'static str = "Solidity";
+
+    /// The versions in which constructs are added to or removed from the language, in ascending order.
+    pub(crate) const BREAKING_VERSIONS: &'static [Version] = &[
+        Version::new(0, 4, 12),
+        Version::new(0, 4, 14),
+        Version::new(0, 4, 16),
+        Version::new(0, 4, 21),
+        Version::new(0, 4, 22),
+        Version::new(0, 4, 25),
+        Version::new(0, 5, 0),
+        Version::new(0, 5, 3),
+        Version::new(0, 5, 5),
+        Version::new(0, 5, 8),
+        Version::new(0, 5, 10),
+        Version::new(0, 5, 12),
+        Version::new(0, 5, 14),
+        Version::new(0, 6, 0),
+        Version::new(0, 6, 2),
+        Version::new(0, 6, 5),
+        Version::new(0, 6, 7),
+        Version::new(0, 6, 8),
+        Version::new(0, 6, 11),
+        Version::new(0, 7, 0),
+        Version::new(0, 7, 1),
+        Version::new(0, 7, 4),
+        Version::new(0, 8, 0),
+        Version::new(0, 8, 4),
+        Version::new(0, 8, 7),
+        Version::new(0, 8, 8),
+        Version::new(0, 8, 10),
+        Version::new(0, 8, 13),
+        Version::new(0, 8, 18),
+        Version::new(0, 8, 19),
+        Version::new(0, 8, 22),
+        Version::new(0, 8, 24),
+    ];
+
+    /// Returns the versions rules of this kind are enabled in, or `None` if they are enabled in all versions.
+    pub(crate) fn rule_kind_versions(kind: RuleKind) -> Option<&'static [VersionRange]> {
+        match kind {
+            RuleKind::CallOptions => Some(&[VersionRange {
+                from: Some((0, 6, 2)),
+                till: None,
+            }]),
+            RuleKind::CallOptionsExpression => Some(&[VersionRange {
+                from: Some((0, 6, 2)),
+                till: None,
+            }]),
+            RuleKind::CatchClause => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::CatchClauseError => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::CatchClauses => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::ConstantDefinition => Some(&[VersionRange {
+                from: Some((0, 7, 4)),
+                till: None,
+            }]),
+            RuleKind::ConstructorAttribute => Some(&[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }]),
+            RuleKind::ConstructorAttributes => Some(&[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }]),
+            RuleKind::ConstructorDefinition => Some(&[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }]),
+            RuleKind::EmitStatement => Some(&[VersionRange {
+                from: Some((0, 4, 21)),
+                till: None,
+            }]),
+            RuleKind::ErrorDefinition => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            RuleKind::ErrorParameter => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            RuleKind::ErrorParameters => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            RuleKind::ErrorParametersDeclaration => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            RuleKind::ExponentiationExpression => Some(&[VersionRange {
+                from: None,
+                till: None,
+            }]),
+            RuleKind::FallbackFunctionAttribute => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::FallbackFunctionAttributes => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::FallbackFunctionDefinition => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::HexStringLiterals => Some(&[VersionRange {
+                from: Some((0, 5, 14)),
+                till: None,
+            }]),
+            RuleKind::OverridePaths => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::OverridePathsDeclaration => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::OverrideSpecifier => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::ReceiveFunctionAttribute => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::ReceiveFunctionAttributes => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::ReceiveFunctionDefinition => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::RevertStatement => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            RuleKind::StringLiterals => Some(&[VersionRange {
+                from: Some((0, 5, 14)),
+                till: None,
+            }]),
+            RuleKind::ThrowStatement => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            RuleKind::TryStatement => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            RuleKind::TypeExpression => Some(&[VersionRange {
+                from: Some((0, 5, 3)),
+                till: None,
+            }]),
+            RuleKind::UncheckedBlock => Some(&[VersionRange {
+                from: Some((0, 8, 0)),
+                till: None,
+            }]),
+            RuleKind::UnicodeStringLiteral => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: None,
+            }]),
+            RuleKind::UnicodeStringLiterals => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: None,
+            }]),
+            RuleKind::UnnamedFunctionAttribute => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 0)),
+            }]),
+            RuleKind::UnnamedFunctionAttributes => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 0)),
+            }]),
+            RuleKind::UnnamedFunctionDefinition => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 0)),
+            }]),
+            RuleKind::UserDefinedValueTypeDefinition => Some(&[VersionRange {
+                from: Some((0, 8, 8)),
+                till: None,
+            }]),
+            RuleKind::UsingAlias => Some(&[VersionRange {
+                from: Some((0, 8, 19)),
+                till: None,
+            }]),
+            RuleKind::UsingDeconstruction => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            RuleKind::UsingDeconstructionSymbol => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            RuleKind::UsingDeconstructionSymbols => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            RuleKind::UsingOperator => Some(&[VersionRange {
+                from: Some((0, 8, 19)),
+                till: None,
+            }]),
+            RuleKind::YulColonAndEqual => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 5)),
+            }]),
+            RuleKind::YulLabel => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            RuleKind::YulLeaveStatement => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            _ => None,
+        }
+    }
+
+    /// Returns the versions tokens of this kind are scanned in, or `None` if they are scanned in all versions.
+    pub(crate) fn token_kind_versions(kind: TokenKind) -> Option<&'static [VersionRange]> {
+        match kind {
+            TokenKind::AliasKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::ApplyKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::AutoKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::CallDataKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::ConstructorKeyword => Some(&[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }]),
+            TokenKind::CopyOfKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::DefineKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::DoubleQuotedUnicodeStringLiteral => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: None,
+            }]),
+            TokenKind::EmitKeyword => Some(&[VersionRange {
+                from: Some((0, 4, 21)),
+                till: None,
+            }]),
+            TokenKind::ErrorKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            TokenKind::FinneyKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            TokenKind::GlobalKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            TokenKind::GweiKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 11)),
+                till: None,
+            }]),
+            TokenKind::ImmutableKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::ImplementsKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::MacroKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::MutableKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::OverrideKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::PartialKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::PromiseKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::ReferenceKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::RevertKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            TokenKind::SealedKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::SingleQuotedUnicodeStringLiteral => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: None,
+            }]),
+            TokenKind::SizeOfKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::SupportsKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::SzaboKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            TokenKind::TypeDefKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::UncheckedKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::VirtualKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            TokenKind::YulAbstractKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAfterKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAliasKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAnonymousKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulApplyKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAsKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAssemblyKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulAutoKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulBaseFeeKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 7)),
+                till: None,
+            }]),
+            TokenKind::YulBlobBaseFeeKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            TokenKind::YulBlobHashKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            TokenKind::YulBoolKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 10)),
+            }]),
+            TokenKind::YulBytesKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulCallDataKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulCatchKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulConstantKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulConstructorKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulContractKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulCopyOfKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulCreate2Keyword => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            TokenKind::YulDaysKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulDefineKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulDeleteKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulDoKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulElseKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulEmitKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulEnumKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulEtherKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulEventKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulExtCodeHashKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            TokenKind::YulExternalKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulFallbackKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulFinalKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulFinneyKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            TokenKind::YulFixedKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulGweiKeyword => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulHoursKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulImmutableKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulImplementsKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulImportKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulInKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 8)),
+            }]),
+            TokenKind::YulIndexedKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulInlineKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulIntKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulInterfaceKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulInternalKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulIsKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulKeccak256Keyword => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            TokenKind::YulLeaveKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            TokenKind::YulLibraryKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMCopyKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            TokenKind::YulMacroKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMappingKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMatchKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMemoryKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMinutesKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulModifierKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulMutableKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulNewKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulNullKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulOfKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulOverrideKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPartialKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPayableKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPragmaKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPrevRandaoKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 18)),
+                till: None,
+            }]),
+            TokenKind::YulPrivateKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPromiseKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPublicKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulPureKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulReceiveKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulReferenceKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulRelocatableKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulReturnDataCopyKeyword => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            TokenKind::YulReturnDataSizeKeyword => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            TokenKind::YulReturnsKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulSealedKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulSecondsKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulSha3Keyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            TokenKind::YulSizeOfKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulStaticCallKeyword => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            TokenKind::YulStaticKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulStorageKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulStringKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulStructKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulSuicideKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            TokenKind::YulSupportsKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulSzaboKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            TokenKind::YulTLoadKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            TokenKind::YulTStoreKeyword => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            TokenKind::YulThrowKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulTryKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulTypeDefKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulTypeKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulTypeOfKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulUfixedKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulUintKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulUncheckedKeyword => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulUsingKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulVarKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 5)),
+            }]),
+            TokenKind::YulViewKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulVirtualKeyword => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulWeeksKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulWeiKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulWhileKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            TokenKind::YulYearsKeyword => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 1)),
+            }]),
+            _ => None,
+        }
+    }
+
+    /// Returns the versions an optional field of a rule is enabled in, or `None` if it is enabled in all versions.
+    pub(crate) fn field_versions(
+        kind: RuleKind,
+        label: NodeLabel,
+    ) -> Option<&'static [VersionRange]> {
+        match (kind, label) {
+            (RuleKind::ContractDefinition, NodeLabel::AbstractKeyword) => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::HexNumberExpression, NodeLabel::Unit) => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::MappingKey, NodeLabel::Name) => Some(&[VersionRange {
+                from: Some((0, 8, 18)),
+                till: None,
+            }]),
+            (RuleKind::MappingValue, NodeLabel::Name) => Some(&[VersionRange {
+                from: Some((0, 8, 18)),
+                till: None,
+            }]),
+            (RuleKind::TupleDeconstructionStatement, NodeLabel::VarKeyword) => {
+                Some(&[VersionRange {
+                    from: None,
+                    till: Some((0, 5, 0)),
+                }])
+            }
+            (RuleKind::UsingDeconstructionSymbol, NodeLabel::Alias) => Some(&[VersionRange {
+                from: Some((0, 8, 19)),
+                till: None,
+            }]),
+            (RuleKind::UsingDirective, NodeLabel::GlobalKeyword) => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            _ => None,
+        }
+    }
+
+    /// Returns the versions a variant of a rule is enabled in, or `None` if it is enabled in all versions.
+    pub(crate) fn variant_versions(
+        kind: RuleKind,
+        variant: &str,
+    ) -> Option<&'static [VersionRange]> {
+        match (kind, variant) {
+            (RuleKind::ConstructorAttribute, "OverrideKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: Some((0, 6, 7)),
+            }]),
+            (RuleKind::ConstructorAttribute, "VirtualKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: Some((0, 6, 7)),
+            }]),
+            (RuleKind::ContractMember, "ConstructorDefinition") => Some(&[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }]),
+            (RuleKind::ContractMember, "ErrorDefinition") => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            (RuleKind::ContractMember, "FallbackFunctionDefinition") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::ContractMember, "ReceiveFunctionDefinition") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::ContractMember, "UnnamedFunctionDefinition") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 6, 0)),
+            }]),
+            (RuleKind::ContractMember, "UserDefinedValueTypeDefinition") => Some(&[VersionRange {
+                from: Some((0, 8, 8)),
+                till: None,
+            }]),
+            (RuleKind::ElementaryType, "ByteKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 8, 0)),
+            }]),
+            (RuleKind::Expression, "PayableKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::Expression, "TypeExpression") => Some(&[VersionRange {
+                from: Some((0, 5, 3)),
+                till: None,
+            }]),
+            (RuleKind::FunctionAttribute, "ConstantKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::FunctionAttribute, "OverrideSpecifier") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::FunctionAttribute, "PureKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }]),
+            (RuleKind::FunctionAttribute, "ViewKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }]),
+            (RuleKind::FunctionAttribute, "VirtualKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::FunctionTypeAttribute, "ConstantKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::FunctionTypeAttribute, "PureKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }]),
+            (RuleKind::FunctionTypeAttribute, "ViewKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }]),
+            (RuleKind::ModifierAttribute, "OverrideSpecifier") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::ModifierAttribute, "VirtualKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::NumberUnit, "FinneyKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            (RuleKind::NumberUnit, "GweiKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 11)),
+                till: None,
+            }]),
+            (RuleKind::NumberUnit, "SzaboKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }]),
+            (RuleKind::NumberUnit, "YearsKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::SourceUnitMember, "ConstantDefinition") => Some(&[VersionRange {
+                from: Some((0, 7, 4)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "EnumDefinition") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "ErrorDefinition") => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "EventDefinition") => Some(&[VersionRange {
+                from: Some((0, 8, 22)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "FunctionDefinition") => Some(&[VersionRange {
+                from: Some((0, 7, 1)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "StructDefinition") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::SourceUnitMember, "UserDefinedValueTypeDefinition") => {
+                Some(&[VersionRange {
+                    from: Some((0, 8, 8)),
+                    till: None,
+                }])
+            }
+            (RuleKind::SourceUnitMember, "UsingDirective") => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            (RuleKind::StateVariableAttribute, "ImmutableKeyword") => Some(&[VersionRange {
+                from: Some((0, 6, 5)),
+                till: None,
+            }]),
+            (RuleKind::StateVariableAttribute, "OverrideSpecifier") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::Statement, "EmitStatement") => Some(&[VersionRange {
+                from: Some((0, 4, 21)),
+                till: None,
+            }]),
+            (RuleKind::Statement, "RevertStatement") => Some(&[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }]),
+            (RuleKind::Statement, "ThrowStatement") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::Statement, "TryStatement") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            (RuleKind::Statement, "UncheckedBlock") => Some(&[VersionRange {
+                from: Some((0, 8, 0)),
+                till: None,
+            }]),
+            (RuleKind::StorageLocation, "CallDataKeyword") => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            (RuleKind::StringExpression, "HexStringLiteral") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 14)),
+            }]),
+            (RuleKind::StringExpression, "HexStringLiterals") => Some(&[VersionRange {
+                from: Some((0, 5, 14)),
+                till: None,
+            }]),
+            (RuleKind::StringExpression, "StringLiteral") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 14)),
+            }]),
+            (RuleKind::StringExpression, "StringLiterals") => Some(&[VersionRange {
+                from: Some((0, 5, 14)),
+                till: None,
+            }]),
+            (RuleKind::StringExpression, "UnicodeStringLiterals") => Some(&[VersionRange {
+                from: Some((0, 7, 0)),
+                till: None,
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "ConstantKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "InternalKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "PrivateKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "PublicKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "PureKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: Some((0, 6, 0)),
+            }]),
+            (RuleKind::UnnamedFunctionAttribute, "ViewKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 16)),
+                till: Some((0, 6, 0)),
+            }]),
+            (RuleKind::UsingClause, "UsingDeconstruction") => Some(&[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }]),
+            (RuleKind::VariableDeclarationType, "VarKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::YulAssignmentOperator, "YulColonAndEqual") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 5)),
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulBaseFeeKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 7)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulBlobBaseFeeKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulBlobHashKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulCreate2Keyword") => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulDifficultyKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 8, 18)),
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulExtCodeHashKeyword") => Some(&[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulKeccak256Keyword") => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulMCopyKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulPrevRandaoKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 18)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulReturnDataCopyKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulReturnDataSizeKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulSha3Keyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulStaticCallKeyword") => Some(&[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulSuicideKeyword") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulTLoadKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            (RuleKind::YulBuiltInFunction, "YulTStoreKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }]),
+            (RuleKind::YulPathComponent, "YulAddressKeyword") => Some(&[VersionRange {
+                from: Some((0, 8, 10)),
+                till: None,
+            }]),
+            (RuleKind::YulStatement, "YulLabel") => Some(&[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }]),
+            (RuleKind::YulStatement, "YulLeaveStatement") => Some(&[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }]),
+            _ => None,
+        }
+    }
+
+    /// Returns the keywords that can be used as identifiers of this kind in some versions,
+    /// and the versions in which the words matching them can be.
+    pub(crate) fn unreserved_keywords(
+        kind: TokenKind,
+    ) -> &'static [(KeywordPattern, &'static [VersionRange])] {
+        match kind {
+            TokenKind::Identifier => &[
+                (
+                    KeywordPattern::Atom("alias"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("apply"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("auto"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("calldata"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("constructor"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("copyof"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("define"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("emit"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("fallback"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 6, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("finney"),
+                    &[VersionRange {
+                        from: Some((0, 7, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x80"),
+                            KeywordPattern::Atom("192x72"),
+                            KeywordPattern::Atom("192x80"),
+                            KeywordPattern::Atom("200x64"),
+                            KeywordPattern::Atom("200x72"),
+                            KeywordPattern::Atom("200x80"),
+                            KeywordPattern::Atom("208x56"),
+                            KeywordPattern::Atom("208x64"),
+                            KeywordPattern::Atom("208x72"),
+                            KeywordPattern::Atom("208x80"),
+                            KeywordPattern::Atom("216x48"),
+                            KeywordPattern::Atom("216x56"),
+                            KeywordPattern::Atom("216x64"),
+                            KeywordPattern::Atom("216x72"),
+                            KeywordPattern::Atom("216x80"),
+                            KeywordPattern::Atom("224x40"),
+                            KeywordPattern::Atom("224x48"),
+                            KeywordPattern::Atom("224x56"),
+                            KeywordPattern::Atom("224x64"),
+                            KeywordPattern::Atom("224x72"),
+                            KeywordPattern::Atom("224x80"),
+                            KeywordPattern::Atom("232x32"),
+                            KeywordPattern::Atom("232x40"),
+                            KeywordPattern::Atom("232x48"),
+                            KeywordPattern::Atom("232x56"),
+                            KeywordPattern::Atom("232x64"),
+                            KeywordPattern::Atom("232x72"),
+                            KeywordPattern::Atom("232x80"),
+                            KeywordPattern::Atom("240x24"),
+                            KeywordPattern::Atom("240x32"),
+                            KeywordPattern::Atom("240x40"),
+                            KeywordPattern::Atom("240x48"),
+                            KeywordPattern::Atom("240x56"),
+                            KeywordPattern::Atom("240x64"),
+                            KeywordPattern::Atom("240x72"),
+                            KeywordPattern::Atom("240x80"),
+                            KeywordPattern::Atom("248x16"),
+                            KeywordPattern::Atom("248x24"),
+                            KeywordPattern::Atom("248x32"),
+                            KeywordPattern::Atom("248x40"),
+                            KeywordPattern::Atom("248x48"),
+                            KeywordPattern::Atom("248x56"),
+                            KeywordPattern::Atom("248x64"),
+                            KeywordPattern::Atom("248x72"),
+                            KeywordPattern::Atom("248x80"),
+                            KeywordPattern::Atom("256x8"),
+                            KeywordPattern::Atom("256x16"),
+                            KeywordPattern::Atom("256x24"),
+                            KeywordPattern::Atom("256x32"),
+                            KeywordPattern::Atom("256x40"),
+                            KeywordPattern::Atom("256x48"),
+                            KeywordPattern::Atom("256x56"),
+                            KeywordPattern::Atom("256x64"),
+                            KeywordPattern::Atom("256x72"),
+                            KeywordPattern::Atom("256x80"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 14)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("0"),
+                            KeywordPattern::Atom("1"),
+                            KeywordPattern::Atom("2"),
+                            KeywordPattern::Atom("3"),
+                            KeywordPattern::Atom("4"),
+                            KeywordPattern::Atom("5"),
+                            KeywordPattern::Atom("6"),
+                            KeywordPattern::Atom("7"),
+                            KeywordPattern::Atom("9"),
+                            KeywordPattern::Atom("10"),
+                            KeywordPattern::Atom("11"),
+                            KeywordPattern::Atom("12"),
+                            KeywordPattern::Atom("13"),
+                            KeywordPattern::Atom("14"),
+                            KeywordPattern::Atom("15"),
+                            KeywordPattern::Atom("17"),
+                            KeywordPattern::Atom("18"),
+                            KeywordPattern::Atom("19"),
+                            KeywordPattern::Atom("20"),
+                            KeywordPattern::Atom("21"),
+                            KeywordPattern::Atom("22"),
+                            KeywordPattern::Atom("23"),
+                            KeywordPattern::Atom("25"),
+                            KeywordPattern::Atom("26"),
+                            KeywordPattern::Atom("27"),
+                            KeywordPattern::Atom("28"),
+                            KeywordPattern::Atom("29"),
+                            KeywordPattern::Atom("30"),
+                            KeywordPattern::Atom("31"),
+                            KeywordPattern::Atom("33"),
+                            KeywordPattern::Atom("34"),
+                            KeywordPattern::Atom("35"),
+                            KeywordPattern::Atom("36"),
+                            KeywordPattern::Atom("37"),
+                            KeywordPattern::Atom("38"),
+                            KeywordPattern::Atom("39"),
+                            KeywordPattern::Atom("41"),
+                            KeywordPattern::Atom("42"),
+                            KeywordPattern::Atom("43"),
+                            KeywordPattern::Atom("44"),
+                            KeywordPattern::Atom("45"),
+                            KeywordPattern::Atom("46"),
+                            KeywordPattern::Atom("47"),
+                            KeywordPattern::Atom("49"),
+                            KeywordPattern::Atom("50"),
+                            KeywordPattern::Atom("51"),
+                            KeywordPattern::Atom("52"),
+                            KeywordPattern::Atom("53"),
+                            KeywordPattern::Atom("54"),
+                            KeywordPattern::Atom("55"),
+                            KeywordPattern::Atom("57"),
+                            KeywordPattern::Atom("58"),
+                            KeywordPattern::Atom("59"),
+                            KeywordPattern::Atom("60"),
+                            KeywordPattern::Atom("61"),
+                            KeywordPattern::Atom("62"),
+                            KeywordPattern::Atom("63"),
+                            KeywordPattern::Atom("65"),
+                            KeywordPattern::Atom("66"),
+                            KeywordPattern::Atom("67"),
+                            KeywordPattern::Atom("68"),
+                            KeywordPattern::Atom("69"),
+                            KeywordPattern::Atom("70"),
+                            KeywordPattern::Atom("71"),
+                            KeywordPattern::Atom("73"),
+                            KeywordPattern::Atom("74"),
+                            KeywordPattern::Atom("75"),
+                            KeywordPattern::Atom("76"),
+                            KeywordPattern::Atom("77"),
+                            KeywordPattern::Atom("78"),
+                            KeywordPattern::Atom("79"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 14)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("gwei"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 7, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("immutable"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("implements"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("macro"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("mutable"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("override"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("partial"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("promise"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("receive"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 6, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("reference"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sealed"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sizeof"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("supports"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("szabo"),
+                    &[VersionRange {
+                        from: Some((0, 7, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("typedef"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x80"),
+                            KeywordPattern::Atom("192x72"),
+                            KeywordPattern::Atom("192x80"),
+                            KeywordPattern::Atom("200x64"),
+                            KeywordPattern::Atom("200x72"),
+                            KeywordPattern::Atom("200x80"),
+                            KeywordPattern::Atom("208x56"),
+                            KeywordPattern::Atom("208x64"),
+                            KeywordPattern::Atom("208x72"),
+                            KeywordPattern::Atom("208x80"),
+                            KeywordPattern::Atom("216x48"),
+                            KeywordPattern::Atom("216x56"),
+                            KeywordPattern::Atom("216x64"),
+                            KeywordPattern::Atom("216x72"),
+                            KeywordPattern::Atom("216x80"),
+                            KeywordPattern::Atom("224x40"),
+                            KeywordPattern::Atom("224x48"),
+                            KeywordPattern::Atom("224x56"),
+                            KeywordPattern::Atom("224x64"),
+                            KeywordPattern::Atom("224x72"),
+                            KeywordPattern::Atom("224x80"),
+                            KeywordPattern::Atom("232x32"),
+                            KeywordPattern::Atom("232x40"),
+                            KeywordPattern::Atom("232x48"),
+                            KeywordPattern::Atom("232x56"),
+                            KeywordPattern::Atom("232x64"),
+                            KeywordPattern::Atom("232x72"),
+                            KeywordPattern::Atom("232x80"),
+                            KeywordPattern::Atom("240x24"),
+                            KeywordPattern::Atom("240x32"),
+                            KeywordPattern::Atom("240x40"),
+                            KeywordPattern::Atom("240x48"),
+                            KeywordPattern::Atom("240x56"),
+                            KeywordPattern::Atom("240x64"),
+                            KeywordPattern::Atom("240x72"),
+                            KeywordPattern::Atom("240x80"),
+                            KeywordPattern::Atom("248x16"),
+                            KeywordPattern::Atom("248x24"),
+                            KeywordPattern::Atom("248x32"),
+                            KeywordPattern::Atom("248x40"),
+                            KeywordPattern::Atom("248x48"),
+                            KeywordPattern::Atom("248x56"),
+                            KeywordPattern::Atom("248x64"),
+                            KeywordPattern::Atom("248x72"),
+                            KeywordPattern::Atom("248x80"),
+                            KeywordPattern::Atom("256x8"),
+                            KeywordPattern::Atom("256x16"),
+                            KeywordPattern::Atom("256x24"),
+                            KeywordPattern::Atom("256x32"),
+                            KeywordPattern::Atom("256x40"),
+                            KeywordPattern::Atom("256x48"),
+                            KeywordPattern::Atom("256x56"),
+                            KeywordPattern::Atom("256x64"),
+                            KeywordPattern::Atom("256x72"),
+                            KeywordPattern::Atom("256x80"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 14)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("0"),
+                            KeywordPattern::Atom("1"),
+                            KeywordPattern::Atom("2"),
+                            KeywordPattern::Atom("3"),
+                            KeywordPattern::Atom("4"),
+                            KeywordPattern::Atom("5"),
+                            KeywordPattern::Atom("6"),
+                            KeywordPattern::Atom("7"),
+                            KeywordPattern::Atom("9"),
+                            KeywordPattern::Atom("10"),
+                            KeywordPattern::Atom("11"),
+                            KeywordPattern::Atom("12"),
+                            KeywordPattern::Atom("13"),
+                            KeywordPattern::Atom("14"),
+                            KeywordPattern::Atom("15"),
+                            KeywordPattern::Atom("17"),
+                            KeywordPattern::Atom("18"),
+                            KeywordPattern::Atom("19"),
+                            KeywordPattern::Atom("20"),
+                            KeywordPattern::Atom("21"),
+                            KeywordPattern::Atom("22"),
+                            KeywordPattern::Atom("23"),
+                            KeywordPattern::Atom("25"),
+                            KeywordPattern::Atom("26"),
+                            KeywordPattern::Atom("27"),
+                            KeywordPattern::Atom("28"),
+                            KeywordPattern::Atom("29"),
+                            KeywordPattern::Atom("30"),
+                            KeywordPattern::Atom("31"),
+                            KeywordPattern::Atom("33"),
+                            KeywordPattern::Atom("34"),
+                            KeywordPattern::Atom("35"),
+                            KeywordPattern::Atom("36"),
+                            KeywordPattern::Atom("37"),
+                            KeywordPattern::Atom("38"),
+                            KeywordPattern::Atom("39"),
+                            KeywordPattern::Atom("41"),
+                            KeywordPattern::Atom("42"),
+                            KeywordPattern::Atom("43"),
+                            KeywordPattern::Atom("44"),
+                            KeywordPattern::Atom("45"),
+                            KeywordPattern::Atom("46"),
+                            KeywordPattern::Atom("47"),
+                            KeywordPattern::Atom("49"),
+                            KeywordPattern::Atom("50"),
+                            KeywordPattern::Atom("51"),
+                            KeywordPattern::Atom("52"),
+                            KeywordPattern::Atom("53"),
+                            KeywordPattern::Atom("54"),
+                            KeywordPattern::Atom("55"),
+                            KeywordPattern::Atom("57"),
+                            KeywordPattern::Atom("58"),
+                            KeywordPattern::Atom("59"),
+                            KeywordPattern::Atom("60"),
+                            KeywordPattern::Atom("61"),
+                            KeywordPattern::Atom("62"),
+                            KeywordPattern::Atom("63"),
+                            KeywordPattern::Atom("65"),
+                            KeywordPattern::Atom("66"),
+                            KeywordPattern::Atom("67"),
+                            KeywordPattern::Atom("68"),
+                            KeywordPattern::Atom("69"),
+                            KeywordPattern::Atom("70"),
+                            KeywordPattern::Atom("71"),
+                            KeywordPattern::Atom("73"),
+                            KeywordPattern::Atom("74"),
+                            KeywordPattern::Atom("75"),
+                            KeywordPattern::Atom("76"),
+                            KeywordPattern::Atom("77"),
+                            KeywordPattern::Atom("78"),
+                            KeywordPattern::Atom("79"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 14)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("unchecked"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("virtual"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 6, 0)),
+                    }],
+                ),
+            ],
+            TokenKind::YulIdentifier => &[
+                (
+                    KeywordPattern::Atom("abstract"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("after"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("alias"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("anonymous"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("apply"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("as"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("assembly"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("auto"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("basefee"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 7)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("blobbasefee"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 24)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("blobhash"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 24)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("bool"),
+                    &[VersionRange {
+                        from: Some((0, 5, 10)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("bytes"),
+                        KeywordPattern::Optional(&KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("1"),
+                            KeywordPattern::Atom("2"),
+                            KeywordPattern::Atom("3"),
+                            KeywordPattern::Atom("4"),
+                            KeywordPattern::Atom("5"),
+                            KeywordPattern::Atom("6"),
+                            KeywordPattern::Atom("7"),
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("9"),
+                            KeywordPattern::Atom("10"),
+                            KeywordPattern::Atom("11"),
+                            KeywordPattern::Atom("12"),
+                            KeywordPattern::Atom("13"),
+                            KeywordPattern::Atom("14"),
+                            KeywordPattern::Atom("15"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("17"),
+                            KeywordPattern::Atom("18"),
+                            KeywordPattern::Atom("19"),
+                            KeywordPattern::Atom("20"),
+                            KeywordPattern::Atom("21"),
+                            KeywordPattern::Atom("22"),
+                            KeywordPattern::Atom("23"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("25"),
+                            KeywordPattern::Atom("26"),
+                            KeywordPattern::Atom("27"),
+                            KeywordPattern::Atom("28"),
+                            KeywordPattern::Atom("29"),
+                            KeywordPattern::Atom("30"),
+                            KeywordPattern::Atom("31"),
+                            KeywordPattern::Atom("32"),
+                        ])),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("calldata"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("catch"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("chainid"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("constant"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("constructor"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("contract"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("copyof"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("create2"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("days"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("define"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("delete"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("do"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("else"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("emit"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("enum"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("ether"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("event"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("extcodehash"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 0)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("external"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("fallback"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 6, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("final"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("finney"),
+                    &[VersionRange {
+                        from: Some((0, 7, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("fixed"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x8"),
+                            KeywordPattern::Atom("184x16"),
+                            KeywordPattern::Atom("184x24"),
+                            KeywordPattern::Atom("184x32"),
+                            KeywordPattern::Atom("184x40"),
+                            KeywordPattern::Atom("184x48"),
+                            KeywordPattern::Atom("184x56"),
+                            KeywordPattern::Atom("184x64"),
+                            KeywordPattern::Atom("184x72"),
+                            KeywordPattern::Atom("192x8"),
+                            KeywordPattern::Atom("192x16"),
+                            KeywordPattern::Atom("192x24"),
+                            KeywordPattern::Atom("192x32"),
+                            KeywordPattern::Atom("192x40"),
+                            KeywordPattern::Atom("192x48"),
+                            KeywordPattern::Atom("192x56"),
+                            KeywordPattern::Atom("192x64"),
+                            KeywordPattern::Atom("200x8"),
+                            KeywordPattern::Atom("200x16"),
+                            KeywordPattern::Atom("200x24"),
+                            KeywordPattern::Atom("200x32"),
+                            KeywordPattern::Atom("200x40"),
+                            KeywordPattern::Atom("200x48"),
+                            KeywordPattern::Atom("200x56"),
+                            KeywordPattern::Atom("208x8"),
+                            KeywordPattern::Atom("208x16"),
+                            KeywordPattern::Atom("208x24"),
+                            KeywordPattern::Atom("208x32"),
+                            KeywordPattern::Atom("208x40"),
+                            KeywordPattern::Atom("208x48"),
+                            KeywordPattern::Atom("216x8"),
+                            KeywordPattern::Atom("216x16"),
+                            KeywordPattern::Atom("216x24"),
+                            KeywordPattern::Atom("216x32"),
+                            KeywordPattern::Atom("216x40"),
+                            KeywordPattern::Atom("224x8"),
+                            KeywordPattern::Atom("224x16"),
+                            KeywordPattern::Atom("224x24"),
+                            KeywordPattern::Atom("224x32"),
+                            KeywordPattern::Atom("232x8"),
+                            KeywordPattern::Atom("232x16"),
+                            KeywordPattern::Atom("232x24"),
+                            KeywordPattern::Atom("240x8"),
+                            KeywordPattern::Atom("240x16"),
+                            KeywordPattern::Atom("248x8"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x80"),
+                            KeywordPattern::Atom("192x72"),
+                            KeywordPattern::Atom("192x80"),
+                            KeywordPattern::Atom("200x64"),
+                            KeywordPattern::Atom("200x72"),
+                            KeywordPattern::Atom("200x80"),
+                            KeywordPattern::Atom("208x56"),
+                            KeywordPattern::Atom("208x64"),
+                            KeywordPattern::Atom("208x72"),
+                            KeywordPattern::Atom("208x80"),
+                            KeywordPattern::Atom("216x48"),
+                            KeywordPattern::Atom("216x56"),
+                            KeywordPattern::Atom("216x64"),
+                            KeywordPattern::Atom("216x72"),
+                            KeywordPattern::Atom("216x80"),
+                            KeywordPattern::Atom("224x40"),
+                            KeywordPattern::Atom("224x48"),
+                            KeywordPattern::Atom("224x56"),
+                            KeywordPattern::Atom("224x64"),
+                            KeywordPattern::Atom("224x72"),
+                            KeywordPattern::Atom("224x80"),
+                            KeywordPattern::Atom("232x32"),
+                            KeywordPattern::Atom("232x40"),
+                            KeywordPattern::Atom("232x48"),
+                            KeywordPattern::Atom("232x56"),
+                            KeywordPattern::Atom("232x64"),
+                            KeywordPattern::Atom("232x72"),
+                            KeywordPattern::Atom("232x80"),
+                            KeywordPattern::Atom("240x24"),
+                            KeywordPattern::Atom("240x32"),
+                            KeywordPattern::Atom("240x40"),
+                            KeywordPattern::Atom("240x48"),
+                            KeywordPattern::Atom("240x56"),
+                            KeywordPattern::Atom("240x64"),
+                            KeywordPattern::Atom("240x72"),
+                            KeywordPattern::Atom("240x80"),
+                            KeywordPattern::Atom("248x16"),
+                            KeywordPattern::Atom("248x24"),
+                            KeywordPattern::Atom("248x32"),
+                            KeywordPattern::Atom("248x40"),
+                            KeywordPattern::Atom("248x48"),
+                            KeywordPattern::Atom("248x56"),
+                            KeywordPattern::Atom("248x64"),
+                            KeywordPattern::Atom("248x72"),
+                            KeywordPattern::Atom("248x80"),
+                            KeywordPattern::Atom("256x8"),
+                            KeywordPattern::Atom("256x16"),
+                            KeywordPattern::Atom("256x24"),
+                            KeywordPattern::Atom("256x32"),
+                            KeywordPattern::Atom("256x40"),
+                            KeywordPattern::Atom("256x48"),
+                            KeywordPattern::Atom("256x56"),
+                            KeywordPattern::Atom("256x64"),
+                            KeywordPattern::Atom("256x72"),
+                            KeywordPattern::Atom("256x80"),
+                        ]),
+                    ]),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 4, 14)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("fixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("0"),
+                            KeywordPattern::Atom("1"),
+                            KeywordPattern::Atom("2"),
+                            KeywordPattern::Atom("3"),
+                            KeywordPattern::Atom("4"),
+                            KeywordPattern::Atom("5"),
+                            KeywordPattern::Atom("6"),
+                            KeywordPattern::Atom("7"),
+                            KeywordPattern::Atom("9"),
+                            KeywordPattern::Atom("10"),
+                            KeywordPattern::Atom("11"),
+                            KeywordPattern::Atom("12"),
+                            KeywordPattern::Atom("13"),
+                            KeywordPattern::Atom("14"),
+                            KeywordPattern::Atom("15"),
+                            KeywordPattern::Atom("17"),
+                            KeywordPattern::Atom("18"),
+                            KeywordPattern::Atom("19"),
+                            KeywordPattern::Atom("20"),
+                            KeywordPattern::Atom("21"),
+                            KeywordPattern::Atom("22"),
+                            KeywordPattern::Atom("23"),
+                            KeywordPattern::Atom("25"),
+                            KeywordPattern::Atom("26"),
+                            KeywordPattern::Atom("27"),
+                            KeywordPattern::Atom("28"),
+                            KeywordPattern::Atom("29"),
+                            KeywordPattern::Atom("30"),
+                            KeywordPattern::Atom("31"),
+                            KeywordPattern::Atom("33"),
+                            KeywordPattern::Atom("34"),
+                            KeywordPattern::Atom("35"),
+                            KeywordPattern::Atom("36"),
+                            KeywordPattern::Atom("37"),
+                            KeywordPattern::Atom("38"),
+                            KeywordPattern::Atom("39"),
+                            KeywordPattern::Atom("41"),
+                            KeywordPattern::Atom("42"),
+                            KeywordPattern::Atom("43"),
+                            KeywordPattern::Atom("44"),
+                            KeywordPattern::Atom("45"),
+                            KeywordPattern::Atom("46"),
+                            KeywordPattern::Atom("47"),
+                            KeywordPattern::Atom("49"),
+                            KeywordPattern::Atom("50"),
+                            KeywordPattern::Atom("51"),
+                            KeywordPattern::Atom("52"),
+                            KeywordPattern::Atom("53"),
+                            KeywordPattern::Atom("54"),
+                            KeywordPattern::Atom("55"),
+                            KeywordPattern::Atom("57"),
+                            KeywordPattern::Atom("58"),
+                            KeywordPattern::Atom("59"),
+                            KeywordPattern::Atom("60"),
+                            KeywordPattern::Atom("61"),
+                            KeywordPattern::Atom("62"),
+                            KeywordPattern::Atom("63"),
+                            KeywordPattern::Atom("65"),
+                            KeywordPattern::Atom("66"),
+                            KeywordPattern::Atom("67"),
+                            KeywordPattern::Atom("68"),
+                            KeywordPattern::Atom("69"),
+                            KeywordPattern::Atom("70"),
+                            KeywordPattern::Atom("71"),
+                            KeywordPattern::Atom("73"),
+                            KeywordPattern::Atom("74"),
+                            KeywordPattern::Atom("75"),
+                            KeywordPattern::Atom("76"),
+                            KeywordPattern::Atom("77"),
+                            KeywordPattern::Atom("78"),
+                            KeywordPattern::Atom("79"),
+                        ]),
+                    ]),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 4, 14)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("gwei"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 7, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("hours"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("immutable"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("implements"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("import"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("indexed"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("in"),
+                    &[VersionRange {
+                        from: Some((0, 6, 8)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("inline"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("interface"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("internal"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("int"),
+                        KeywordPattern::Optional(&KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ])),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("is"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("keccak256"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("leave"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 7, 1)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("library"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("macro"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("mapping"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("match"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("memory"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("minutes"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("mcopy"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 24)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("modifier"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("mutable"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("new"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("null"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("of"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("override"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("partial"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("payable"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("pragma"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("prevrandao"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 18)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("private"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("promise"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("public"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("pure"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("receive"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 6, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("reference"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("relocatable"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("returndatacopy"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("returndatasize"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("returns"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sar"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 21)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sealed"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("seconds"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("selfbalance"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 5, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sha3"),
+                    &[VersionRange {
+                        from: Some((0, 5, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("shl"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 21)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("shr"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 21)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("sizeof"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("staticcall"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 4, 12)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("static"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("storage"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("string"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("struct"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("suicide"),
+                    &[VersionRange {
+                        from: Some((0, 5, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("supports"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("szabo"),
+                    &[VersionRange {
+                        from: Some((0, 7, 0)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("throw"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("tload"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 24)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("tstore"),
+                    &[VersionRange {
+                        from: None,
+                        till: Some((0, 8, 24)),
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("try"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("typedef"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("type"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("typeof"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("ufixed"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x8"),
+                            KeywordPattern::Atom("184x16"),
+                            KeywordPattern::Atom("184x24"),
+                            KeywordPattern::Atom("184x32"),
+                            KeywordPattern::Atom("184x40"),
+                            KeywordPattern::Atom("184x48"),
+                            KeywordPattern::Atom("184x56"),
+                            KeywordPattern::Atom("184x64"),
+                            KeywordPattern::Atom("184x72"),
+                            KeywordPattern::Atom("192x8"),
+                            KeywordPattern::Atom("192x16"),
+                            KeywordPattern::Atom("192x24"),
+                            KeywordPattern::Atom("192x32"),
+                            KeywordPattern::Atom("192x40"),
+                            KeywordPattern::Atom("192x48"),
+                            KeywordPattern::Atom("192x56"),
+                            KeywordPattern::Atom("192x64"),
+                            KeywordPattern::Atom("200x8"),
+                            KeywordPattern::Atom("200x16"),
+                            KeywordPattern::Atom("200x24"),
+                            KeywordPattern::Atom("200x32"),
+                            KeywordPattern::Atom("200x40"),
+                            KeywordPattern::Atom("200x48"),
+                            KeywordPattern::Atom("200x56"),
+                            KeywordPattern::Atom("208x8"),
+                            KeywordPattern::Atom("208x16"),
+                            KeywordPattern::Atom("208x24"),
+                            KeywordPattern::Atom("208x32"),
+                            KeywordPattern::Atom("208x40"),
+                            KeywordPattern::Atom("208x48"),
+                            KeywordPattern::Atom("216x8"),
+                            KeywordPattern::Atom("216x16"),
+                            KeywordPattern::Atom("216x24"),
+                            KeywordPattern::Atom("216x32"),
+                            KeywordPattern::Atom("216x40"),
+                            KeywordPattern::Atom("224x8"),
+                            KeywordPattern::Atom("224x16"),
+                            KeywordPattern::Atom("224x24"),
+                            KeywordPattern::Atom("224x32"),
+                            KeywordPattern::Atom("232x8"),
+                            KeywordPattern::Atom("232x16"),
+                            KeywordPattern::Atom("232x24"),
+                            KeywordPattern::Atom("240x8"),
+                            KeywordPattern::Atom("240x16"),
+                            KeywordPattern::Atom("248x8"),
+                        ]),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("184x80"),
+                            KeywordPattern::Atom("192x72"),
+                            KeywordPattern::Atom("192x80"),
+                            KeywordPattern::Atom("200x64"),
+                            KeywordPattern::Atom("200x72"),
+                            KeywordPattern::Atom("200x80"),
+                            KeywordPattern::Atom("208x56"),
+                            KeywordPattern::Atom("208x64"),
+                            KeywordPattern::Atom("208x72"),
+                            KeywordPattern::Atom("208x80"),
+                            KeywordPattern::Atom("216x48"),
+                            KeywordPattern::Atom("216x56"),
+                            KeywordPattern::Atom("216x64"),
+                            KeywordPattern::Atom("216x72"),
+                            KeywordPattern::Atom("216x80"),
+                            KeywordPattern::Atom("224x40"),
+                            KeywordPattern::Atom("224x48"),
+                            KeywordPattern::Atom("224x56"),
+                            KeywordPattern::Atom("224x64"),
+                            KeywordPattern::Atom("224x72"),
+                            KeywordPattern::Atom("224x80"),
+                            KeywordPattern::Atom("232x32"),
+                            KeywordPattern::Atom("232x40"),
+                            KeywordPattern::Atom("232x48"),
+                            KeywordPattern::Atom("232x56"),
+                            KeywordPattern::Atom("232x64"),
+                            KeywordPattern::Atom("232x72"),
+                            KeywordPattern::Atom("232x80"),
+                            KeywordPattern::Atom("240x24"),
+                            KeywordPattern::Atom("240x32"),
+                            KeywordPattern::Atom("240x40"),
+                            KeywordPattern::Atom("240x48"),
+                            KeywordPattern::Atom("240x56"),
+                            KeywordPattern::Atom("240x64"),
+                            KeywordPattern::Atom("240x72"),
+                            KeywordPattern::Atom("240x80"),
+                            KeywordPattern::Atom("248x16"),
+                            KeywordPattern::Atom("248x24"),
+                            KeywordPattern::Atom("248x32"),
+                            KeywordPattern::Atom("248x40"),
+                            KeywordPattern::Atom("248x48"),
+                            KeywordPattern::Atom("248x56"),
+                            KeywordPattern::Atom("248x64"),
+                            KeywordPattern::Atom("248x72"),
+                            KeywordPattern::Atom("248x80"),
+                            KeywordPattern::Atom("256x8"),
+                            KeywordPattern::Atom("256x16"),
+                            KeywordPattern::Atom("256x24"),
+                            KeywordPattern::Atom("256x32"),
+                            KeywordPattern::Atom("256x40"),
+                            KeywordPattern::Atom("256x48"),
+                            KeywordPattern::Atom("256x56"),
+                            KeywordPattern::Atom("256x64"),
+                            KeywordPattern::Atom("256x72"),
+                            KeywordPattern::Atom("256x80"),
+                        ]),
+                    ]),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 4, 14)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("ufixed"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ]),
+                        KeywordPattern::Atom("x"),
+                        KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("0"),
+                            KeywordPattern::Atom("1"),
+                            KeywordPattern::Atom("2"),
+                            KeywordPattern::Atom("3"),
+                            KeywordPattern::Atom("4"),
+                            KeywordPattern::Atom("5"),
+                            KeywordPattern::Atom("6"),
+                            KeywordPattern::Atom("7"),
+                            KeywordPattern::Atom("9"),
+                            KeywordPattern::Atom("10"),
+                            KeywordPattern::Atom("11"),
+                            KeywordPattern::Atom("12"),
+                            KeywordPattern::Atom("13"),
+                            KeywordPattern::Atom("14"),
+                            KeywordPattern::Atom("15"),
+                            KeywordPattern::Atom("17"),
+                            KeywordPattern::Atom("18"),
+                            KeywordPattern::Atom("19"),
+                            KeywordPattern::Atom("20"),
+                            KeywordPattern::Atom("21"),
+                            KeywordPattern::Atom("22"),
+                            KeywordPattern::Atom("23"),
+                            KeywordPattern::Atom("25"),
+                            KeywordPattern::Atom("26"),
+                            KeywordPattern::Atom("27"),
+                            KeywordPattern::Atom("28"),
+                            KeywordPattern::Atom("29"),
+                            KeywordPattern::Atom("30"),
+                            KeywordPattern::Atom("31"),
+                            KeywordPattern::Atom("33"),
+                            KeywordPattern::Atom("34"),
+                            KeywordPattern::Atom("35"),
+                            KeywordPattern::Atom("36"),
+                            KeywordPattern::Atom("37"),
+                            KeywordPattern::Atom("38"),
+                            KeywordPattern::Atom("39"),
+                            KeywordPattern::Atom("41"),
+                            KeywordPattern::Atom("42"),
+                            KeywordPattern::Atom("43"),
+                            KeywordPattern::Atom("44"),
+                            KeywordPattern::Atom("45"),
+                            KeywordPattern::Atom("46"),
+                            KeywordPattern::Atom("47"),
+                            KeywordPattern::Atom("49"),
+                            KeywordPattern::Atom("50"),
+                            KeywordPattern::Atom("51"),
+                            KeywordPattern::Atom("52"),
+                            KeywordPattern::Atom("53"),
+                            KeywordPattern::Atom("54"),
+                            KeywordPattern::Atom("55"),
+                            KeywordPattern::Atom("57"),
+                            KeywordPattern::Atom("58"),
+                            KeywordPattern::Atom("59"),
+                            KeywordPattern::Atom("60"),
+                            KeywordPattern::Atom("61"),
+                            KeywordPattern::Atom("62"),
+                            KeywordPattern::Atom("63"),
+                            KeywordPattern::Atom("65"),
+                            KeywordPattern::Atom("66"),
+                            KeywordPattern::Atom("67"),
+                            KeywordPattern::Atom("68"),
+                            KeywordPattern::Atom("69"),
+                            KeywordPattern::Atom("70"),
+                            KeywordPattern::Atom("71"),
+                            KeywordPattern::Atom("73"),
+                            KeywordPattern::Atom("74"),
+                            KeywordPattern::Atom("75"),
+                            KeywordPattern::Atom("76"),
+                            KeywordPattern::Atom("77"),
+                            KeywordPattern::Atom("78"),
+                            KeywordPattern::Atom("79"),
+                        ]),
+                    ]),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 4, 14)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Sequence(&[
+                        KeywordPattern::Atom("uint"),
+                        KeywordPattern::Optional(&KeywordPattern::Choice(&[
+                            KeywordPattern::Atom("8"),
+                            KeywordPattern::Atom("16"),
+                            KeywordPattern::Atom("24"),
+                            KeywordPattern::Atom("32"),
+                            KeywordPattern::Atom("40"),
+                            KeywordPattern::Atom("48"),
+                            KeywordPattern::Atom("56"),
+                            KeywordPattern::Atom("64"),
+                            KeywordPattern::Atom("72"),
+                            KeywordPattern::Atom("80"),
+                            KeywordPattern::Atom("88"),
+                            KeywordPattern::Atom("96"),
+                            KeywordPattern::Atom("104"),
+                            KeywordPattern::Atom("112"),
+                            KeywordPattern::Atom("120"),
+                            KeywordPattern::Atom("128"),
+                            KeywordPattern::Atom("136"),
+                            KeywordPattern::Atom("144"),
+                            KeywordPattern::Atom("152"),
+                            KeywordPattern::Atom("160"),
+                            KeywordPattern::Atom("168"),
+                            KeywordPattern::Atom("176"),
+                            KeywordPattern::Atom("184"),
+                            KeywordPattern::Atom("192"),
+                            KeywordPattern::Atom("200"),
+                            KeywordPattern::Atom("208"),
+                            KeywordPattern::Atom("216"),
+                            KeywordPattern::Atom("224"),
+                            KeywordPattern::Atom("232"),
+                            KeywordPattern::Atom("240"),
+                            KeywordPattern::Atom("248"),
+                            KeywordPattern::Atom("256"),
+                        ])),
+                    ]),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("unchecked"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 5, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("using"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("var"),
+                    &[VersionRange {
+                        from: Some((0, 6, 5)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("view"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("virtual"),
+                    &[
+                        VersionRange {
+                            from: None,
+                            till: Some((0, 6, 0)),
+                        },
+                        VersionRange {
+                            from: Some((0, 7, 1)),
+                            till: None,
+                        },
+                    ],
+                ),
+                (
+                    KeywordPattern::Atom("weeks"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("wei"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("while"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+                (
+                    KeywordPattern::Atom("years"),
+                    &[VersionRange {
+                        from: Some((0, 7, 1)),
+                        till: None,
+                    }],
+                ),
+            ],
+            _ => &[],
+        }
+    }
+
+    pub(crate) const VERSIONED_KEYWORDS: &'static [VersionedKeyword] = &[
+        VersionedKeyword {
+            kind: TokenKind::AbstractKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("abstract"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::AsKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("as"),
+            versions: &[VersionRange {
+                from: None,
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::UsingAlias,
+                &[VersionRange {
+                    from: Some((0, 8, 19)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::ByteKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("byte"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 8, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::CallDataKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("calldata"),
+            versions: &[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::CatchKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("catch"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::CatchClause,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::ConstructorKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("constructor"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 22)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::ConstructorDefinition,
+                &[VersionRange {
+                    from: Some((0, 4, 22)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::EmitKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("emit"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 21)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::EmitStatement,
+                &[VersionRange {
+                    from: Some((0, 4, 21)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::ErrorKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("error"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::ErrorDefinition,
+                &[VersionRange {
+                    from: Some((0, 8, 4)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::FallbackKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("fallback"),
+            versions: &[VersionRange {
+                from: None,
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::FallbackFunctionDefinition,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::FinneyKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("finney"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::FunctionKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("function"),
+            versions: &[VersionRange {
+                from: None,
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::UnnamedFunctionDefinition,
+                &[VersionRange {
+                    from: None,
+                    till: Some((0, 6, 0)),
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::GlobalKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("global"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 13)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::GweiKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("gwei"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 11)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::ImmutableKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("immutable"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 5)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::OverrideKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("override"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::OverrideSpecifier,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::PureKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("pure"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::ReceiveKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("receive"),
+            versions: &[VersionRange {
+                from: None,
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::ReceiveFunctionDefinition,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::RevertKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("revert"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 4)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::RevertStatement,
+                &[VersionRange {
+                    from: Some((0, 8, 4)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::SzaboKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("szabo"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 7, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::ThrowKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("throw"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }],
+            rule: Some((
+                RuleKind::ThrowStatement,
+                &[VersionRange {
+                    from: None,
+                    till: Some((0, 5, 0)),
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::TryKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("try"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::TryStatement,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::TypeKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("type"),
+            versions: &[VersionRange {
+                from: Some((0, 5, 3)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::UserDefinedValueTypeDefinition,
+                &[VersionRange {
+                    from: Some((0, 8, 8)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::UncheckedKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("unchecked"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 0)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::UncheckedBlock,
+                &[VersionRange {
+                    from: Some((0, 8, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::VarKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("var"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::ViewKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("view"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 16)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::VirtualKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("virtual"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YearsKeyword,
+            identifier: TokenKind::Identifier,
+            pattern: KeywordPattern::Atom("years"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulBaseFeeKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("basefee"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 7)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulBlobBaseFeeKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("blobbasefee"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulBlobHashKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("blobhash"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulCreate2Keyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("create2"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulDifficultyKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("difficulty"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 8, 18)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulExtCodeHashKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("extcodehash"),
+            versions: &[VersionRange {
+                from: Some((0, 5, 0)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulKeccak256Keyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("keccak256"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulLeaveKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("leave"),
+            versions: &[VersionRange {
+                from: Some((0, 6, 0)),
+                till: None,
+            }],
+            rule: Some((
+                RuleKind::YulLeaveStatement,
+                &[VersionRange {
+                    from: Some((0, 6, 0)),
+                    till: None,
+                }],
+            )),
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulMCopyKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("mcopy"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulPrevRandaoKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("prevrandao"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 18)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulReturnDataCopyKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("returndatacopy"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulReturnDataSizeKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("returndatasize"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulSha3Keyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("sha3"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulStaticCallKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("staticcall"),
+            versions: &[VersionRange {
+                from: Some((0, 4, 12)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulSuicideKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("suicide"),
+            versions: &[VersionRange {
+                from: None,
+                till: Some((0, 5, 0)),
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulTLoadKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("tload"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }],
+            rule: None,
+        },
+        VersionedKeyword {
+            kind: TokenKind::YulTStoreKeyword,
+            identifier: TokenKind::YulIdentifier,
+            pattern: KeywordPattern::Atom("tstore"),
+            versions: &[VersionRange {
+                from: Some((0, 8, 24)),
+                till: None,
+            }],
+            rule: None,
+        },
+    ];
+
     /********************************************
      *         Parser Functions
      ********************************************/
 
     #[allow(unused_assignments, unused_parens)]
     fn abi_coder_pragma(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::AbicoderKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::AbicoderKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Version,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::AbicoderKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::AbicoderKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Version,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ABICoderPragma)
         })
-        .with_kind(RuleKind::ABICoderPragma)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn additive_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::AdditiveExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::AdditiveExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn address_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::AddressKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::AddressKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::PayableKeyword,
-                OptionalHelper::transform(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::AddressKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::PayableKeyword,
+                        TokenKind::AddressKeyword,
                     ),
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::PayableKeyword,
+                    OptionalHelper::transform(
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::PayableKeyword,
+                        ),
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::AddressType)
         })
-        .with_kind(RuleKind::AddressType)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn and_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::AndExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::AndExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn arguments_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.positional_arguments_declaration(input);
-            choice.consider(input, result)?;
-            let result = self.named_arguments_declaration(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.positional_arguments_declaration(input);
+                choice.consider(input, result)?;
+                let result = self.named_arguments_declaration(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ArgumentsDeclaration)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ArgumentsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn array_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenBracket,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenBracket,
-                ),
-            )?;
-            seq.elem(
-                self.array_values(input)
-                    .with_label(NodeLabel::Items)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenBracket,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenBracket,
+                    ),
+                )?;
+                seq.elem(
+                    self.array_values(input)
+                        .with_label(NodeLabel::Items)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                         input,
                         self,
                         TokenKind::CloseBracket,
                         TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseBracket,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseBracket,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseBracket,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CloseBracket,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ArrayExpression)
         })
-        .with_kind(RuleKind::ArrayExpression)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn array_type_name(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.type_name(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::TypeName => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.type_name(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::ArrayTypeName => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::TypeName => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::ArrayTypeName => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn array_values(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.expression(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::ArrayValues)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn assembly_flags(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.string_literal(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::AssemblyFlags)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn assembly_flags_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.assembly_flags(input)
+                        .with_label(NodeLabel::Flags)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::CloseParen,
+                        TokenAcceptanceThreshold(0u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CloseParen,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::AssemblyFlagsDeclaration)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn assembly_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::AssemblyKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::AssemblyKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Label,
+                    OptionalHelper::transform(self.string_literal(input)),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Flags,
+                    OptionalHelper::transform(self.assembly_flags_declaration(input)),
+                )?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::AssemblyStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn assignment_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::AssignmentExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn bitwise_and_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::BitwiseAndExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn bitwise_or_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::BitwiseOrExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn array_values(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.expression(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::ArrayValues)
+    fn bitwise_xor_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::BitwiseXorExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn assembly_flags(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.string_literal(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::AssemblyFlags)
+    fn block(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenBrace,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenBrace,
+                    ),
+                )?;
+                seq.elem(
+                    self.statements(input)
+                        .with_label(NodeLabel::Statements)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::CloseBrace,
+                        TokenAcceptanceThreshold(0u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseBrace,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CloseBrace,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::Block)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn assembly_flags_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.assembly_flags(input)
-                    .with_label(NodeLabel::Flags)
+    fn break_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::BreakKeyword,
+                    )
+                    .with_label(NodeLabel::BreakKeyword)
                     .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                         input,
                         self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::BreakStatement)
         })
-        .with_kind(RuleKind::AssemblyFlagsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn assembly_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::AssemblyKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+    fn call_options(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_2 {
+                SeparatedHelper::run::<_, LexicalContextType::Default>(
                     input,
-                    TokenKind::AssemblyKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Label,
-                OptionalHelper::transform(self.string_literal(input)),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Flags,
-                OptionalHelper::transform(self.assembly_flags_declaration(input)),
-            )?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+                    self,
+                    |input| self.named_argument(input).with_label(NodeLabel::Item),
+                    TokenKind::Comma,
+                    NodeLabel::Separator,
+                )
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::CallOptions)
         })
-        .with_kind(RuleKind::AssemblyStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn assignment_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+    fn call_options_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::AssignmentExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::CallOptionsExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn bitwise_and_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::BitwiseAndExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+    fn catch_clause(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::CatchKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CatchKeyword,
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Error,
+                        OptionalHelper::transform(self.catch_clause_error(input)),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Body, self.block(input))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::CatchClause)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn bitwise_or_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+    fn catch_clause_error(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::Name,
+                        OptionalHelper::transform(
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Identifier,
+                            ),
+                        ),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::CatchClauseError)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn catch_clauses(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                OneOrMoreHelper::run(input, |input| {
+                    self.catch_clause(input).with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::CatchClauses)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn comparison_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::BitwiseOrExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::ComparisonExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn bitwise_xor_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+    fn conditional_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::BitwiseXorExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::ConditionalExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn block(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenBrace,
-                ),
-            )?;
-            seq.elem(
-                self.statements(input)
-                    .with_label(NodeLabel::Statements)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseBrace,
-                ),
-            )?;
-            seq.finish()
+    fn constant_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_7_4 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                            seq.elem_labeled(
+                                NodeLabel::ConstantKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::ConstantKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Name,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::Identifier,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Equal,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::Equal,
+                                ),
+                            )?;
+                            seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Semicolon,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ConstantDefinition)
         })
-        .with_kind(RuleKind::Block)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn break_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::BreakKeyword,
-                )
-                .with_label(NodeLabel::BreakKeyword)
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+    fn constructor_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_4_22 {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.modifier_invocation(input);
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::InternalKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    if self.version_is_at_least_0_6_0 && !self.version_is_at_least_0_6_7 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OverrideKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PayableKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PublicKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    if self.version_is_at_least_0_6_0 && !self.version_is_at_least_0_6_7 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::VirtualKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ConstructorAttribute)
         })
-        .with_kind(RuleKind::BreakStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn call_options(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_2 {
-            SeparatedHelper::run::<_, LexicalContextType::Default>(
-                input,
-                self,
-                |input| self.named_argument(input).with_label(NodeLabel::Item),
-                TokenKind::Comma,
-                NodeLabel::Separator,
-            )
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::CallOptions)
+    fn constructor_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_4_22 {
+                ZeroOrMoreHelper::run(input, |input| {
+                    self.constructor_attribute(input)
+                        .with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ConstructorAttributes)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn call_options_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::CallOptionsExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+    fn constructor_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_4_22 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::ConstructorKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::ConstructorKeyword,
+                        ),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                    seq.elem_labeled(NodeLabel::Attributes, self.constructor_attributes(input))?;
+                    seq.elem_labeled(NodeLabel::Body, self.block(input))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ConstructorDefinition)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn catch_clause(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+    fn continue_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(
-                    NodeLabel::CatchKeyword,
+                seq.elem(
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CatchKeyword,
+                        TokenKind::ContinueKeyword,
+                    )
+                    .with_label(NodeLabel::ContinueKeyword)
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::Error,
-                    OptionalHelper::transform(self.catch_clause_error(input)),
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
                 )?;
-                seq.elem_labeled(NodeLabel::Body, self.block(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::CatchClause)
+            .with_kind(RuleKind::ContinueStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn catch_clause_error(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+    fn contract_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
+                if self.version_is_at_least_0_6_0 {
+                    seq.elem_labeled(
+                        NodeLabel::AbstractKeyword,
+                        OptionalHelper::transform(
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::AbstractKeyword,
+                            ),
+                        ),
+                    )?;
+                }
+                seq.elem_labeled(
+                    NodeLabel::ContractKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ContractKeyword,
+                    ),
+                )?;
                 seq.elem_labeled(
                     NodeLabel::Name,
-                    OptionalHelper::transform(
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Inheritence,
+                    OptionalHelper::transform(self.inheritance_specifier(input)),
+                )?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::Identifier,
+                            TokenKind::OpenBrace,
                         ),
-                    ),
-                )?;
-                seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                    )?;
+                    seq.elem(
+                        self.contract_members(input)
+                            .with_label(NodeLabel::Members)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseBrace,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseBrace,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::CatchClauseError)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn catch_clauses(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            OneOrMoreHelper::run(input, |input| {
-                self.catch_clause(input).with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::CatchClauses)
+            .with_kind(RuleKind::ContractDefinition)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn comparison_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::ComparisonExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+    fn contract_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.using_directive(input);
+                choice.consider(input, result)?;
+                let result = self.function_definition(input);
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_4_22 {
+                    let result = self.constructor_definition(input);
+                    choice.consider(input, result)?;
                 }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.receive_function_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.fallback_function_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_6_0 {
+                    let result = self.unnamed_function_definition(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.modifier_definition(input);
+                choice.consider(input, result)?;
+                let result = self.struct_definition(input);
+                choice.consider(input, result)?;
+                let result = self.enum_definition(input);
+                choice.consider(input, result)?;
+                let result = self.event_definition(input);
+                choice.consider(input, result)?;
+                let result = self.state_variable_definition(input);
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_8_4 {
+                    let result = self.error_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_8 {
+                    let result = self.user_defined_value_type_definition(input);
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ContractMember)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn conditional_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::ConditionalExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+    fn contract_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.contract_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::ContractMembers)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn constant_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_7_4 {
+    fn decimal_number_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
-                seq.elem(
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-                        seq.elem_labeled(
-                            NodeLabel::ConstantKeyword,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::ConstantKeyword,
-                            ),
-                        )?;
-                        seq.elem_labeled(
-                            NodeLabel::Name,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::Identifier,
-                            ),
-                        )?;
-                        seq.elem_labeled(
-                            NodeLabel::Equal,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::Equal,
-                            ),
-                        )?;
-                        seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
-                        seq.finish()
-                    })
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::Semicolon,
-                        TokenAcceptanceThreshold(1u8),
-                    ),
-                )?;
                 seq.elem_labeled(
-                    NodeLabel::Semicolon,
+                    NodeLabel::Literal,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::Semicolon,
+                        TokenKind::DecimalLiteral,
                     ),
                 )?;
+                seq.elem_labeled(
+                    NodeLabel::Unit,
+                    OptionalHelper::transform(self.number_unit(input)),
+                )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ConstantDefinition)
+            .with_kind(RuleKind::DecimalNumberExpression)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn constructor_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_4_22 {
+    fn do_while_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| { SequenceHelper :: run (| mut seq | { seq . elem (SequenceHelper :: run (| mut seq | { seq . elem_labeled (NodeLabel :: DoKeyword , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: DoKeyword)) ? ; seq . elem_labeled (NodeLabel :: Body , self . statement (input)) ? ; seq . elem_labeled (NodeLabel :: WhileKeyword , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: WhileKeyword)) ? ; seq . elem (SequenceHelper :: run (| mut seq | { let mut delim_guard = input . open_delim (TokenKind :: CloseParen) ; let input = delim_guard . ctx () ; seq . elem_labeled (NodeLabel :: OpenParen , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: OpenParen)) ? ; seq . elem (self . expression (input) . with_label (NodeLabel :: Condition) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: CloseParen , TokenAcceptanceThreshold (0u8) ,)) ? ; seq . elem_labeled (NodeLabel :: CloseParen , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: CloseParen)) ? ; seq . finish () })) ? ; seq . finish () }) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: Semicolon , TokenAcceptanceThreshold (1u8) ,)) ? ; seq . elem_labeled (NodeLabel :: Semicolon , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Semicolon)) ? ; seq . finish () }) . with_kind (RuleKind :: DoWhileStatement) })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn elementary_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.modifier_invocation(input);
-                choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::InternalKeyword,
+                    TokenKind::BoolKeyword,
                 );
                 choice.consider(input, result)?;
-                if self.version_is_at_least_0_6_0 && !self.version_is_at_least_0_6_7 {
+                if !self.version_is_at_least_0_8_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OverrideKeyword,
+                        TokenKind::ByteKeyword,
                     );
                     choice.consider(input, result)?;
                 }
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PayableKeyword,
+                    TokenKind::StringKeyword,
                 );
                 choice.consider(input, result)?;
+                let result = self.address_type(input);
+                choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PublicKeyword,
+                    TokenKind::BytesKeyword,
                 );
                 choice.consider(input, result)?;
-                if self.version_is_at_least_0_6_0 && !self.version_is_at_least_0_6_7 {
-                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::VirtualKeyword,
-                    );
-                    choice.consider(input, result)?;
-                }
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ConstructorAttribute)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn constructor_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_4_22 {
-            ZeroOrMoreHelper::run(input, |input| {
-                self.constructor_attribute(input)
-                    .with_label(NodeLabel::Item)
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::IntKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::UintKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::FixedKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::UfixedKeyword,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ConstructorAttributes)
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ElementaryType)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn constructor_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_4_22 {
+    fn else_branch(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::ConstructorKeyword,
+                    NodeLabel::ElseKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::ConstructorKeyword,
+                        TokenKind::ElseKeyword,
                     ),
                 )?;
-                seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-                seq.elem_labeled(NodeLabel::Attributes, self.constructor_attributes(input))?;
-                seq.elem_labeled(NodeLabel::Body, self.block(input))?;
+                seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ConstructorDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn continue_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ContinueKeyword,
-                )
-                .with_label(NodeLabel::ContinueKeyword)
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+            .with_kind(RuleKind::ElseBranch)
         })
-        .with_kind(RuleKind::ContinueStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn contract_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            if self.version_is_at_least_0_6_0 {
-                seq.elem_labeled(
-                    NodeLabel::AbstractKeyword,
-                    OptionalHelper::transform(
+    fn emit_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_4_21 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(
+                                NodeLabel::EmitKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::EmitKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(NodeLabel::Event, self.identifier_path(input))?;
+                            seq.elem_labeled(
+                                NodeLabel::Arguments,
+                                self.arguments_declaration(input),
+                            )?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::AbstractKeyword,
+                            TokenKind::Semicolon,
                         ),
-                    ),
-                )?;
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
             }
-            seq.elem_labeled(
-                NodeLabel::ContractKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ContractKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Inheritence,
-                OptionalHelper::transform(self.inheritance_specifier(input)),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
+            .with_kind(RuleKind::EmitStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn enum_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::OpenBrace,
+                    NodeLabel::EnumKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OpenBrace,
-                    ),
-                )?;
-                seq.elem(
-                    self.contract_members(input)
-                        .with_label(NodeLabel::Members)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
+                        TokenKind::EnumKeyword,
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::CloseBrace,
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseBrace,
+                        TokenKind::Identifier,
                     ),
                 )?;
-                seq.finish()
-            }))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::ContractDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn contract_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.using_directive(input);
-            choice.consider(input, result)?;
-            let result = self.function_definition(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_4_22 {
-                let result = self.constructor_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
-                let result = self.receive_function_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
-                let result = self.fallback_function_definition(input);
-                choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_6_0 {
-                let result = self.unnamed_function_definition(input);
-                choice.consider(input, result)?;
-            }
-            let result = self.modifier_definition(input);
-            choice.consider(input, result)?;
-            let result = self.struct_definition(input);
-            choice.consider(input, result)?;
-            let result = self.enum_definition(input);
-            choice.consider(input, result)?;
-            let result = self.event_definition(input);
-            choice.consider(input, result)?;
-            let result = self.state_variable_definition(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_8_4 {
-                let result = self.error_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_8 {
-                let result = self.user_defined_value_type_definition(input);
-                choice.consider(input, result)?;
-            }
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ContractMember)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn contract_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.contract_member(input).with_label(NodeLabel::Item)
-        })
-        .with_kind(RuleKind::ContractMembers)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn decimal_number_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Literal,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::DecimalLiteral,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Unit,
-                OptionalHelper::transform(self.number_unit(input)),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::DecimalNumberExpression)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn do_while_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
                     seq.elem_labeled(
-                        NodeLabel::DoKeyword,
+                        NodeLabel::OpenBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::DoKeyword,
+                            TokenKind::OpenBrace,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.enum_members(input)
+                            .with_label(NodeLabel::Members)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseBrace,
+                            TokenAcceptanceThreshold(0u8),
                         ),
                     )?;
-                    seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
                     seq.elem_labeled(
-                        NodeLabel::WhileKeyword,
+                        NodeLabel::CloseBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::WhileKeyword,
+                            TokenKind::CloseBrace,
                         ),
                     )?;
-                    seq.elem(SequenceHelper::run(|mut seq| {
-                        let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                        let input = delim_guard.ctx();
-                        seq.elem_labeled(
-                            NodeLabel::OpenParen,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::OpenParen,
-                            ),
-                        )?;
-                        seq.elem(
-                            self.expression(input)
-                                .with_label(NodeLabel::Condition)
-                                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                                input,
-                                self,
-                                TokenKind::CloseParen,
-                                TokenAcceptanceThreshold(0u8),
-                            ),
-                        )?;
-                        seq.elem_labeled(
-                            NodeLabel::CloseParen,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::CloseParen,
-                            ),
-                        )?;
-                        seq.finish()
-                    }))?;
                     seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+                }))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::EnumDefinition)
         })
-        .with_kind(RuleKind::DoWhileStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn elementary_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+    fn enum_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
                 input,
-                TokenKind::BoolKeyword,
-            );
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_8_0 {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ByteKeyword,
-                );
-                choice.consider(input, result)?;
+                self,
+                |input| {
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    )
+                    .with_label(NodeLabel::Item)
+                },
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::EnumMembers)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn equality_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::EqualityExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
             }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::StringKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.address_type(input);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::BytesKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::IntKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::UintKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::FixedKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::UfixedKeyword,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ElementaryType)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn else_branch(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ElseKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ElseKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
-            seq.finish()
+    fn error_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_4 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(
+                                NodeLabel::ErrorKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::ErrorKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Name,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::Identifier,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Members,
+                                self.error_parameters_declaration(input),
+                            )?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Semicolon,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ErrorDefinition)
         })
-        .with_kind(RuleKind::ElseBranch)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn emit_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_4_21 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem_labeled(
-                            NodeLabel::EmitKeyword,
+    fn error_parameter(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_4 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                    seq.elem_labeled(
+                        NodeLabel::Name,
+                        OptionalHelper::transform(
                             self.parse_token_with_trivia::<LexicalContextType::Default>(
                                 input,
-                                TokenKind::EmitKeyword,
+                                TokenKind::Identifier,
                             ),
-                        )?;
-                        seq.elem_labeled(NodeLabel::Event, self.identifier_path(input))?;
-                        seq.elem_labeled(NodeLabel::Arguments, self.arguments_declaration(input))?;
-                        seq.finish()
-                    })
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::Semicolon,
-                        TokenAcceptanceThreshold(1u8),
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::Semicolon,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::Semicolon,
-                    ),
-                )?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::EmitStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn enum_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::EnumKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::EnumKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenBrace,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenBrace,
-                    ),
-                )?;
-                seq.elem(
-                    self.enum_members(input)
-                        .with_label(NodeLabel::Members)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::CloseBrace,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::CloseBrace,
-                    ),
-                )?;
-                seq.finish()
-            }))?;
-            seq.finish()
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ErrorParameter)
         })
-        .with_kind(RuleKind::EnumDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn enum_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| {
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+    fn error_parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_4 {
+                OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
                     input,
-                    TokenKind::Identifier,
-                )
-                .with_label(NodeLabel::Item)
-            },
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::EnumMembers)
+                    self,
+                    |input| self.error_parameter(input).with_label(NodeLabel::Item),
+                    TokenKind::Comma,
+                    NodeLabel::Separator,
+                ))
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ErrorParameters)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn equality_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::EqualityExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+    fn error_parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_4 {
+                SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenParen,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.error_parameters(input)
+                            .with_label(NodeLabel::Parameters)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                                input,
+                                self,
+                                TokenKind::CloseParen,
+                                TokenAcceptanceThreshold(0u8),
+                            ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseParen,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ErrorParametersDeclaration)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn error_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_4 {
+    fn event_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem(
                     SequenceHelper::run(|mut seq| {
                         seq.elem_labeled(
-                            NodeLabel::ErrorKeyword,
+                            NodeLabel::EventKeyword,
                             self.parse_token_with_trivia::<LexicalContextType::Default>(
                                 input,
-                                TokenKind::ErrorKeyword,
+                                TokenKind::EventKeyword,
                             ),
                         )?;
                         seq.elem_labeled(
@@ -1348,8 +5361,17 @@ impl Language {
                             ),
                         )?;
                         seq.elem_labeled(
-                            NodeLabel::Members,
-                            self.error_parameters_declaration(input),
+                            NodeLabel::Parameters,
+                            self.event_parameters_declaration(input),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::AnonymousKeyword,
+                            OptionalHelper::transform(
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::AnonymousKeyword,
+                                ),
+                            ),
                         )?;
                         seq.finish()
                     })
@@ -1369,979 +5391,389 @@ impl Language {
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ErrorDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn error_parameter(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_4 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-                seq.elem_labeled(
-                    NodeLabel::Name,
-                    OptionalHelper::transform(
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
-                        ),
-                    ),
-                )?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ErrorParameter)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn error_parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_4 {
-            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-                input,
-                self,
-                |input| self.error_parameter(input).with_label(NodeLabel::Item),
-                TokenKind::Comma,
-                NodeLabel::Separator,
-            ))
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ErrorParameters)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn error_parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_4 {
-            SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenParen,
-                    ),
-                )?;
-                seq.elem(
-                    self.error_parameters(input)
-                        .with_label(NodeLabel::Parameters)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                            input,
-                            self,
-                            TokenKind::CloseParen,
-                            TokenAcceptanceThreshold(0u8),
-                        ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::CloseParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::CloseParen,
-                    ),
-                )?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ErrorParametersDeclaration)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn event_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::EventKeyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::EventKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Name,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Parameters,
-                        self.event_parameters_declaration(input),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::AnonymousKeyword,
-                        OptionalHelper::transform(
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::AnonymousKeyword,
-                            ),
-                        ),
-                    )?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+            .with_kind(RuleKind::EventDefinition)
         })
-        .with_kind(RuleKind::EventDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn event_parameter(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-            seq.elem_labeled(
-                NodeLabel::IndexedKeyword,
-                OptionalHelper::transform(
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::IndexedKeyword,
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                seq.elem_labeled(
+                    NodeLabel::IndexedKeyword,
+                    OptionalHelper::transform(
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::IndexedKeyword,
+                        ),
                     ),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                OptionalHelper::transform(
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::Identifier,
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Name,
+                    OptionalHelper::transform(
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Identifier,
+                        ),
                     ),
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::EventParameter)
         })
-        .with_kind(RuleKind::EventParameter)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn event_parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.event_parameter(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::EventParameters)
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.event_parameter(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::EventParameters)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn event_parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.event_parameters(input)
-                    .with_label(NodeLabel::Parameters)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.event_parameters(input)
+                        .with_label(NodeLabel::Parameters)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
                         TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::EventParametersDeclaration)
         })
-        .with_kind(RuleKind::EventParametersDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn experimental_feature(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                input,
-                TokenKind::Identifier,
-            );
-            choice.consider(input, result)?;
-            let result = self.string_literal(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                    input,
+                    TokenKind::Identifier,
+                );
+                choice.consider(input, result)?;
+                let result = self.string_literal(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ExperimentalFeature)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ExperimentalFeature)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn experimental_pragma(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ExperimentalKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::ExperimentalKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Feature, self.experimental_feature(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::ExperimentalKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::ExperimentalKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Feature, self.experimental_feature(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ExperimentalPragma)
         })
-        .with_kind(RuleKind::ExperimentalPragma)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn exponentiation_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::ExponentiationExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let parse_left_assignment_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::AssignmentExpression,
-                1u8,
-                1u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Equal,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::BarEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::PlusEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::MinusEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::CaretEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::SlashEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::PercentEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::AsteriskEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::AmpersandEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::LessThanLessThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThanGreaterThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThanGreaterThanGreaterThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_postfix_conditional_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::ConditionalExpression,
-                3u8,
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::QuestionMark,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::QuestionMark,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::TrueExpression, self.expression(input))?;
-                    seq.elem_labeled(
-                        NodeLabel::Colon,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Colon,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::FalseExpression, self.expression(input))?;
-                    seq.finish()
-                }),
-            )
-        };
-        let parse_left_or_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::OrExpression,
-                5u8,
-                5u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::BarBar,
-                )
-                .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_left_and_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::AndExpression,
-                7u8,
-                7u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::AmpersandAmpersand,
-                )
-                .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_left_equality_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::EqualityExpression,
-                9u8,
-                9u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::EqualEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::BangEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_left_comparison_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::ComparisonExpression,
-                11u8,
-                11u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::LessThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::LessThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_left_bitwise_or_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::BitwiseOrExpression,
-                13u8,
-                13u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Bar)
-                    .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_left_bitwise_xor_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::BitwiseXorExpression,
-                15u8,
-                15u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Caret,
-                )
-                .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_left_bitwise_and_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::BitwiseAndExpression,
-                17u8,
-                17u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Ampersand,
-                )
-                .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_left_shift_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::ShiftExpression,
-                19u8,
-                19u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::LessThanLessThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThanGreaterThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::GreaterThanGreaterThanGreaterThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_left_additive_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::AdditiveExpression,
-                21u8,
-                21u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Plus,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Minus,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_left_multiplicative_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::MultiplicativeExpression,
-                23u8,
-                23u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Asterisk,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Slash,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Percent,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_left_exponentiation_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::ExponentiationExpression,
-                25u8,
-                25u8 + 1,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    if !self.version_is_at_least_0_6_0 {
-                        let result = self
-                            .parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::AsteriskAsterisk,
-                            )
-                            .with_label(NodeLabel::Operator);
-                        choice.consider(input, result)?;
-                    }
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_right_exponentiation_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::ExponentiationExpression,
-                27u8 + 1,
-                27u8,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    if self.version_is_at_least_0_6_0 {
-                        let result = self
-                            .parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::AsteriskAsterisk,
-                            )
-                            .with_label(NodeLabel::Operator);
-                        choice.consider(input, result)?;
+    fn exponentiation_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::ExponentiationExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
                     }
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_postfix_postfix_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::PostfixExpression,
-                29u8,
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| { let parse_left_assignment_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: AssignmentExpression , 1u8 , 1u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Equal) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: BarEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: PlusEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: MinusEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: CaretEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: SlashEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: PercentEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: AsteriskEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: AmpersandEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: LessThanLessThanEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThanGreaterThanEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThanGreaterThanGreaterThanEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_postfix_conditional_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: ConditionalExpression , 3u8 , SequenceHelper :: run (| mut seq | { seq . elem_labeled (NodeLabel :: QuestionMark , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: QuestionMark)) ? ; seq . elem_labeled (NodeLabel :: TrueExpression , self . expression (input)) ? ; seq . elem_labeled (NodeLabel :: Colon , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Colon)) ? ; seq . elem_labeled (NodeLabel :: FalseExpression , self . expression (input)) ? ; seq . finish () })) ; let parse_left_or_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: OrExpression , 5u8 , 5u8 + 1 , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: BarBar) . with_label (NodeLabel :: Operator)) ; let parse_left_and_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: AndExpression , 7u8 , 7u8 + 1 , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: AmpersandAmpersand) . with_label (NodeLabel :: Operator)) ; let parse_left_equality_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: EqualityExpression , 9u8 , 9u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: EqualEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: BangEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_left_comparison_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: ComparisonExpression , 11u8 , 11u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: LessThan) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThan) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: LessThanEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThanEqual) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_left_bitwise_or_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: BitwiseOrExpression , 13u8 , 13u8 + 1 , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Bar) . with_label (NodeLabel :: Operator)) ; let parse_left_bitwise_xor_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: BitwiseXorExpression , 15u8 , 15u8 + 1 , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Caret) . with_label (NodeLabel :: Operator)) ; let parse_left_bitwise_and_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: BitwiseAndExpression , 17u8 , 17u8 + 1 , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Ampersand) . with_label (NodeLabel :: Operator)) ; let parse_left_shift_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: ShiftExpression , 19u8 , 19u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: LessThanLessThan) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThanGreaterThan) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: GreaterThanGreaterThanGreaterThan) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_left_additive_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: AdditiveExpression , 21u8 , 21u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Plus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Minus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_left_multiplicative_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: MultiplicativeExpression , 23u8 , 23u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Asterisk) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Slash) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Percent) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_left_exponentiation_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: ExponentiationExpression , 25u8 , 25u8 + 1 , ChoiceHelper :: run (input , | mut choice , input | { if ! self . version_is_at_least_0_6_0 { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: AsteriskAsterisk) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; } choice . finish (input) })) ; let parse_right_exponentiation_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_binary_operator (RuleKind :: ExponentiationExpression , 27u8 + 1 , 27u8 , ChoiceHelper :: run (input , | mut choice , input | { if self . version_is_at_least_0_6_0 { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: AsteriskAsterisk) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; } choice . finish (input) })) ; let parse_postfix_postfix_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: PostfixExpression , 29u8 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: PlusPlus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: MinusMinus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_prefix_prefix_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_prefix_operator (RuleKind :: PrefixExpression , 31u8 , ChoiceHelper :: run (input , | mut choice , input | { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: PlusPlus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: MinusMinus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Tilde) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Bang) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Minus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; if ! self . version_is_at_least_0_5_0 { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Plus) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; } let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: DeleteKeyword) . with_label (NodeLabel :: Operator) ; choice . consider (input , result) ? ; choice . finish (input) })) ; let parse_postfix_function_call_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: FunctionCallExpression , 33u8 , self . arguments_declaration (input) . with_label (NodeLabel :: Arguments)) ; let parse_postfix_call_options_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: CallOptionsExpression , 35u8 , ChoiceHelper :: run (input , | mut choice , input | { if self . version_is_at_least_0_6_2 { let result = SequenceHelper :: run (| mut seq | { let mut delim_guard = input . open_delim (TokenKind :: CloseBrace) ; let input = delim_guard . ctx () ; seq . elem_labeled (NodeLabel :: OpenBrace , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: OpenBrace)) ? ; seq . elem (self . call_options (input) . with_label (NodeLabel :: Options) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: CloseBrace , TokenAcceptanceThreshold (2u8) ,)) ? ; seq . elem_labeled (NodeLabel :: CloseBrace , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: CloseBrace)) ? ; seq . finish () }) ; choice . consider (input , result) ? ; } choice . finish (input) })) ; let parse_postfix_member_access_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: MemberAccessExpression , 37u8 , SequenceHelper :: run (| mut seq | { seq . elem_labeled (NodeLabel :: Period , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Period)) ? ; seq . elem_labeled (NodeLabel :: Member , self . member_access (input)) ? ; seq . finish () })) ; let parse_postfix_index_access_expression = | input : & mut ParserContext < '_ > | PrecedenceHelper :: to_postfix_operator (RuleKind :: IndexAccessExpression , 39u8 , SequenceHelper :: run (| mut seq | { let mut delim_guard = input . open_delim (TokenKind :: CloseBracket) ; let input = delim_guard . ctx () ; seq . elem_labeled (NodeLabel :: OpenBracket , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: OpenBracket)) ? ; seq . elem (SequenceHelper :: run (| mut seq | { seq . elem_labeled (NodeLabel :: Start , OptionalHelper :: transform (self . expression (input))) ? ; seq . elem_labeled (NodeLabel :: End , OptionalHelper :: transform (self . index_access_end (input))) ? ; seq . finish () }) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: CloseBracket , TokenAcceptanceThreshold (0u8) ,)) ? ; seq . elem_labeled (NodeLabel :: CloseBracket , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: CloseBracket)) ? ; seq . finish () })) ; let prefix_operator_parser = | input : & mut ParserContext < '_ > | ChoiceHelper :: run (input , | mut choice , input | { let result = parse_prefix_prefix_expression (input) ; choice . consider (input , result) ? ; choice . finish (input) }) ; let primary_expression_parser = | input : & mut ParserContext < '_ > | ChoiceHelper :: run (input , | mut choice , input | { let result = self . new_expression (input) ; choice . consider (input , result) ? ; let result = self . tuple_expression (input) ; choice . consider (input , result) ? ; if self . version_is_at_least_0_5_3 { let result = self . type_expression (input) ; choice . consider (input , result) ? ; } let result = self . array_expression (input) ; choice . consider (input , result) ? ; let result = self . hex_number_expression (input) ; choice . consider (input , result) ? ; let result = self . decimal_number_expression (input) ; choice . consider (input , result) ? ; let result = self . string_expression (input) ; choice . consider (input , result) ? ; let result = self . elementary_type (input) ; choice . consider (input , result) ? ; if self . version_is_at_least_0_6_0 { let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: PayableKeyword) ; choice . consider (input , result) ? ; } let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: TrueKeyword) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: FalseKeyword) ; choice . consider (input , result) ? ; let result = self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Identifier) ; choice . consider (input , result) ? ; choice . finish (input) }) . with_label (NodeLabel :: Variant) ; let postfix_operator_parser = | input : & mut ParserContext < '_ > | ChoiceHelper :: run (input , | mut choice , input | { let result = parse_postfix_conditional_expression (input) ; choice . consider (input , result) ? ; let result = parse_postfix_postfix_expression (input) ; choice . consider (input , result) ? ; let result = parse_postfix_function_call_expression (input) ; choice . consider (input , result) ? ; let result = parse_postfix_call_options_expression (input) ; choice . consider (input , result) ? ; let result = parse_postfix_member_access_expression (input) ; choice . consider (input , result) ? ; let result = parse_postfix_index_access_expression (input) ; choice . consider (input , result) ? ; choice . finish (input) }) ; let binary_operand_parser = | input : & mut ParserContext < '_ > | SequenceHelper :: run (| mut seq | { seq . elem (ZeroOrMoreHelper :: run (input , prefix_operator_parser)) ? ; seq . elem (primary_expression_parser (input)) ? ; seq . elem (ZeroOrMoreHelper :: run (input , postfix_operator_parser)) ? ; seq . finish () }) ; let binary_operator_parser = | input : & mut ParserContext < '_ > | ChoiceHelper :: run (input , | mut choice , input | { let result = parse_left_assignment_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_or_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_and_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_equality_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_comparison_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_bitwise_or_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_bitwise_xor_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_bitwise_and_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_shift_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_additive_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_multiplicative_expression (input) ; choice . consider (input , result) ? ; let result = parse_left_exponentiation_expression (input) ; choice . consider (input , result) ? ; let result = parse_right_exponentiation_expression (input) ; choice . consider (input , result) ? ; choice . finish (input) }) ; let linear_expression_parser = | input : & mut ParserContext < '_ > | SequenceHelper :: run (| mut seq | { seq . elem (binary_operand_parser (input)) ? ; seq . elem (ZeroOrMoreHelper :: run (input , | input | SequenceHelper :: run (| mut seq | { seq . elem (binary_operator_parser (input)) ? ; seq . elem (binary_operand_parser (input)) ? ; seq . finish () }))) ? ; seq . finish () }) ; PrecedenceHelper :: reduce_precedence_result (RuleKind :: Expression , linear_expression_parser (input)) . with_kind (RuleKind :: Expression) })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn expression_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    self.expression(input)
+                        .with_label(NodeLabel::Expression)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ExpressionStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn fallback_function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
                 ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::PlusPlus,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::MinusMinus,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.modifier_invocation(input);
                     choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_prefix_prefix_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_prefix_operator(
-                RuleKind::PrefixExpression,
-                31u8,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::PlusPlus,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.override_specifier(input);
                     choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::MinusMinus,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ExternalKeyword,
+                    );
                     choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Tilde,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PayableKeyword,
+                    );
                     choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Bang,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PureKeyword,
+                    );
                     choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Minus,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ViewKeyword,
+                    );
                     choice.consider(input, result)?;
-                    if !self.version_is_at_least_0_5_0 {
-                        let result = self
-                            .parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::Plus,
-                            )
-                            .with_label(NodeLabel::Operator);
-                        choice.consider(input, result)?;
-                    }
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::DeleteKeyword,
-                        )
-                        .with_label(NodeLabel::Operator);
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::VirtualKeyword,
+                    );
                     choice.consider(input, result)?;
                     choice.finish(input)
-                }),
-            )
-        };
-        let parse_postfix_function_call_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::FunctionCallExpression,
-                33u8,
-                self.arguments_declaration(input)
-                    .with_label(NodeLabel::Arguments),
-            )
-        };
-        let parse_postfix_call_options_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::CallOptionsExpression,
-                35u8,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    if self.version_is_at_least_0_6_2 {
-                        let result = SequenceHelper::run(|mut seq| {
-                            let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                            let input = delim_guard.ctx();
-                            seq.elem_labeled(
-                                NodeLabel::OpenBrace,
-                                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                    input,
-                                    TokenKind::OpenBrace,
-                                ),
-                            )?;
-                            seq . elem (self . call_options (input) . with_label (NodeLabel :: Options) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: CloseBrace , TokenAcceptanceThreshold (2u8) ,)) ? ;
-                            seq.elem_labeled(
-                                NodeLabel::CloseBrace,
-                                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                    input,
-                                    TokenKind::CloseBrace,
-                                ),
-                            )?;
-                            seq.finish()
-                        });
-                        choice.consider(input, result)?;
-                    }
-                    choice.finish(input)
-                }),
-            )
-        };
-        let parse_postfix_member_access_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::MemberAccessExpression,
-                37u8,
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::FallbackFunctionAttribute)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn fallback_function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                ZeroOrMoreHelper::run(input, |input| {
+                    self.fallback_function_attribute(input)
+                        .with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::FallbackFunctionAttributes)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn fallback_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
                 SequenceHelper::run(|mut seq| {
                     seq.elem_labeled(
-                        NodeLabel::Period,
+                        NodeLabel::FallbackKeyword,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::Period,
+                            TokenKind::FallbackKeyword,
                         ),
                     )?;
-                    seq.elem_labeled(NodeLabel::Member, self.member_access(input))?;
+                    seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                    seq.elem_labeled(
+                        NodeLabel::Attributes,
+                        self.fallback_function_attributes(input),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Returns,
+                        OptionalHelper::transform(self.returns_declaration(input)),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
                     seq.finish()
-                }),
-            )
-        };
-        let parse_postfix_index_access_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::IndexAccessExpression,
-                39u8,
-                SequenceHelper::run(|mut seq| {
-                    let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::FallbackFunctionDefinition)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn for_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::ForKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ForKeyword,
+                    ),
+                )?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
                     let input = delim_guard.ctx();
                     seq.elem_labeled(
-                        NodeLabel::OpenBracket,
+                        NodeLabel::OpenParen,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::OpenBracket,
+                            TokenKind::OpenParen,
                         ),
                     )?;
                     seq.elem(
                         SequenceHelper::run(|mut seq| {
                             seq.elem_labeled(
-                                NodeLabel::Start,
-                                OptionalHelper::transform(self.expression(input)),
+                                NodeLabel::Initialization,
+                                self.for_statement_initialization(input),
                             )?;
                             seq.elem_labeled(
-                                NodeLabel::End,
-                                OptionalHelper::transform(self.index_access_end(input)),
+                                NodeLabel::Condition,
+                                self.for_statement_condition(input),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Iterator,
+                                OptionalHelper::transform(self.expression(input)),
                             )?;
                             seq.finish()
                         })
                         .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                             input,
                             self,
-                            TokenKind::CloseBracket,
+                            TokenKind::CloseParen,
                             TokenAcceptanceThreshold(0u8),
                         ),
                     )?;
                     seq.elem_labeled(
-                        NodeLabel::CloseBracket,
+                        NodeLabel::CloseParen,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::CloseBracket,
+                            TokenKind::CloseParen,
                         ),
                     )?;
                     seq.finish()
-                }),
-            )
-        };
-        let prefix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_prefix_prefix_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
+                }))?;
+                seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
+                seq.finish()
             })
-        };
-        let primary_expression_parser = |input: &mut ParserContext<'_>| {
+            .with_kind(RuleKind::ForStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn for_statement_condition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.new_expression(input);
-                choice.consider(input, result)?;
-                let result = self.tuple_expression(input);
-                choice.consider(input, result)?;
-                if self.version_is_at_least_0_5_3 {
-                    let result = self.type_expression(input);
-                    choice.consider(input, result)?;
-                }
-                let result = self.array_expression(input);
-                choice.consider(input, result)?;
-                let result = self.hex_number_expression(input);
-                choice.consider(input, result)?;
-                let result = self.decimal_number_expression(input);
-                choice.consider(input, result)?;
-                let result = self.string_expression(input);
-                choice.consider(input, result)?;
-                let result = self.elementary_type(input);
-                choice.consider(input, result)?;
-                if self.version_is_at_least_0_6_0 {
-                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::PayableKeyword,
-                    );
-                    choice.consider(input, result)?;
-                }
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::TrueKeyword,
-                );
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::FalseKeyword,
-                );
+                let result = self.expression_statement(input);
                 choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::Identifier,
+                    TokenKind::Semicolon,
                 );
                 choice.consider(input, result)?;
                 choice.finish(input)
             })
             .with_label(NodeLabel::Variant)
-        };
-        let postfix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_postfix_conditional_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_postfix_postfix_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_postfix_function_call_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_postfix_call_options_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_postfix_member_access_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_postfix_index_access_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let binary_operand_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(ZeroOrMoreHelper::run(input, prefix_operator_parser))?;
-                seq.elem(primary_expression_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
-                seq.finish()
-            })
-        };
-        let binary_operator_parser = |input: &mut ParserContext<'_>| {
+            .with_kind(RuleKind::ForStatementCondition)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn for_statement_initialization(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_left_assignment_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_or_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_and_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_equality_expression(input);
+                let result = self.expression_statement(input);
                 choice.consider(input, result)?;
-                let result = parse_left_comparison_expression(input);
+                let result = self.variable_declaration_statement(input);
                 choice.consider(input, result)?;
-                let result = parse_left_bitwise_or_expression(input);
+                let result = self.tuple_deconstruction_statement(input);
                 choice.consider(input, result)?;
-                let result = parse_left_bitwise_xor_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_bitwise_and_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_shift_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_additive_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_multiplicative_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_left_exponentiation_expression(input);
-                choice.consider(input, result)?;
-                let result = parse_right_exponentiation_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let linear_expression_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(binary_operand_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, |input| {
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem(binary_operator_parser(input))?;
-                        seq.elem(binary_operand_parser(input))?;
-                        seq.finish()
-                    })
-                }))?;
-                seq.finish()
-            })
-        };
-        PrecedenceHelper::reduce_precedence_result(
-            RuleKind::Expression,
-            linear_expression_parser(input),
-        )
-        .with_kind(RuleKind::Expression)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn expression_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                self.expression(input)
-                    .with_label(NodeLabel::Expression)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::Semicolon,
-                        TokenAcceptanceThreshold(1u8),
-                    ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
                     TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ForStatementInitialization)
         })
-        .with_kind(RuleKind::ExpressionStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn fallback_function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+    fn function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.modifier_invocation(input);
                 choice.consider(input, result)?;
-                let result = self.override_specifier(input);
-                choice.consider(input, result)?;
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.override_specifier(input);
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ConstantKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
                     TokenKind::ExternalKeyword,
@@ -2349,62 +5781,119 @@ impl Language {
                 choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PayableKeyword,
+                    TokenKind::InternalKeyword,
                 );
                 choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PureKeyword,
+                    TokenKind::PayableKeyword,
                 );
                 choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::ViewKeyword,
+                    TokenKind::PrivateKeyword,
                 );
                 choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::VirtualKeyword,
+                    TokenKind::PublicKeyword,
                 );
                 choice.consider(input, result)?;
+                if self.version_is_at_least_0_4_16 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PureKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_16 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ViewKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::VirtualKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
                 choice.finish(input)
             })
             .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::FallbackFunctionAttribute)
+            .with_kind(RuleKind::FunctionAttribute)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn fallback_function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+    fn function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ZeroOrMoreHelper::run(input, |input| {
-                self.fallback_function_attribute(input)
-                    .with_label(NodeLabel::Item)
+                self.function_attribute(input).with_label(NodeLabel::Item)
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::FallbackFunctionAttributes)
+            .with_kind(RuleKind::FunctionAttributes)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn fallback_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+    fn function_body(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.block(input);
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::Semicolon,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::FunctionBody)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn function_call_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::FunctionCallExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::FallbackKeyword,
+                    NodeLabel::FunctionKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::FallbackKeyword,
+                        TokenKind::FunctionKeyword,
                     ),
                 )?;
+                seq.elem_labeled(NodeLabel::Name, self.function_name(input))?;
                 seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-                seq.elem_labeled(
-                    NodeLabel::Attributes,
-                    self.fallback_function_attributes(input),
-                )?;
+                seq.elem_labeled(NodeLabel::Attributes, self.function_attributes(input))?;
                 seq.elem_labeled(
                     NodeLabel::Returns,
                     OptionalHelper::transform(self.returns_declaration(input)),
@@ -2412,1746 +5901,1576 @@ impl Language {
                 seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::FallbackFunctionDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn for_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ForKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ForKeyword,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenParen,
-                    ),
-                )?;
-                seq.elem(
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem_labeled(
-                            NodeLabel::Initialization,
-                            self.for_statement_initialization(input),
-                        )?;
-                        seq.elem_labeled(
-                            NodeLabel::Condition,
-                            self.for_statement_condition(input),
-                        )?;
-                        seq.elem_labeled(
-                            NodeLabel::Iterator,
-                            OptionalHelper::transform(self.expression(input)),
-                        )?;
-                        seq.finish()
-                    })
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::CloseParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::CloseParen,
-                    ),
-                )?;
-                seq.finish()
-            }))?;
-            seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::ForStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn for_statement_condition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.expression_statement(input);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::Semicolon,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ForStatementCondition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn for_statement_initialization(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.expression_statement(input);
-            choice.consider(input, result)?;
-            let result = self.variable_declaration_statement(input);
-            choice.consider(input, result)?;
-            let result = self.tuple_deconstruction_statement(input);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::Semicolon,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+            .with_kind(RuleKind::FunctionDefinition)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ForStatementInitialization)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.modifier_invocation(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_6_0 {
-                let result = self.override_specifier(input);
-                choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_5_0 {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ConstantKeyword,
-                );
-                choice.consider(input, result)?;
-            }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::ExternalKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::InternalKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PayableKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PrivateKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PublicKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_4_16 {
+    fn function_name(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PureKeyword,
+                    TokenKind::Identifier,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_16 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::ViewKeyword,
+                    TokenKind::FallbackKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::VirtualKeyword,
+                    TokenKind::ReceiveKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::FunctionAttribute)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.function_attribute(input).with_label(NodeLabel::Item)
-        })
-        .with_kind(RuleKind::FunctionAttributes)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn function_body(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.block(input);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::Semicolon,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::FunctionBody)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn function_call_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::FunctionCallExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::FunctionKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::FunctionKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Name, self.function_name(input))?;
-            seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-            seq.elem_labeled(NodeLabel::Attributes, self.function_attributes(input))?;
-            seq.elem_labeled(
-                NodeLabel::Returns,
-                OptionalHelper::transform(self.returns_declaration(input)),
-            )?;
-            seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::FunctionDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn function_name(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::Identifier,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::FallbackKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::ReceiveKeyword,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::FunctionName)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::FunctionName)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn function_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::FunctionKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::FunctionKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-            seq.elem_labeled(NodeLabel::Attributes, self.function_type_attributes(input))?;
-            seq.elem_labeled(
-                NodeLabel::Returns,
-                OptionalHelper::transform(self.returns_declaration(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::FunctionKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::FunctionKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                seq.elem_labeled(NodeLabel::Attributes, self.function_type_attributes(input))?;
+                seq.elem_labeled(
+                    NodeLabel::Returns,
+                    OptionalHelper::transform(self.returns_declaration(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::FunctionType)
         })
-        .with_kind(RuleKind::FunctionType)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn function_type_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::InternalKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::ExternalKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PrivateKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PublicKeyword,
-            );
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_5_0 {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::ConstantKeyword,
+                    TokenKind::InternalKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_16 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::PureKeyword,
+                    TokenKind::ExternalKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_16 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::ViewKeyword,
+                    TokenKind::PrivateKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::PublicKeyword,
+                );
+                choice.consider(input, result)?;
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ConstantKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_16 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PureKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_16 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ViewKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::PayableKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PayableKeyword,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::FunctionTypeAttribute)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::FunctionTypeAttribute)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn function_type_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.function_type_attribute(input)
-                .with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.function_type_attribute(input)
+                    .with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::FunctionTypeAttributes)
         })
-        .with_kind(RuleKind::FunctionTypeAttributes)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn hex_number_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Literal,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::HexLiteral,
-                ),
-            )?;
-            if !self.version_is_at_least_0_5_0 {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::Unit,
-                    OptionalHelper::transform(self.number_unit(input)),
+                    NodeLabel::Literal,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::HexLiteral,
+                    ),
                 )?;
-            }
-            seq.finish()
+                if !self.version_is_at_least_0_5_0 {
+                    seq.elem_labeled(
+                        NodeLabel::Unit,
+                        OptionalHelper::transform(self.number_unit(input)),
+                    )?;
+                }
+                seq.finish()
+            })
+            .with_kind(RuleKind::HexNumberExpression)
         })
-        .with_kind(RuleKind::HexNumberExpression)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn hex_string_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::SingleQuotedHexStringLiteral,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::DoubleQuotedHexStringLiteral,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::SingleQuotedHexStringLiteral,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::DoubleQuotedHexStringLiteral,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::HexStringLiteral)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::HexStringLiteral)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn hex_string_literals(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_5_14 {
-            OneOrMoreHelper::run(input, |input| {
-                self.hex_string_literal(input).with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::HexStringLiterals)
+        input.nested(|input| {
+            if self.version_is_at_least_0_5_14 {
+                OneOrMoreHelper::run(input, |input| {
+                    self.hex_string_literal(input).with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::HexStringLiterals)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn identifier_path(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| {
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                )
-                .with_label(NodeLabel::Item)
-            },
-            TokenKind::Period,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::IdentifierPath)
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| {
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    )
+                    .with_label(NodeLabel::Item)
+                },
+                TokenKind::Period,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::IdentifierPath)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn if_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::IfKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::IfKeyword,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::OpenParen,
+                    NodeLabel::IfKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OpenParen,
+                        TokenKind::IfKeyword,
                     ),
                 )?;
-                seq.elem(
-                    self.expression(input)
-                        .with_label(NodeLabel::Condition)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenParen,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.expression(input)
+                            .with_label(NodeLabel::Condition)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseParen,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
+                seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
+                seq.elem_labeled(
+                    NodeLabel::ElseBranch,
+                    OptionalHelper::transform(self.else_branch(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::IfStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn import_alias(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::AsKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
+                        TokenKind::AsKeyword,
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::CloseParen,
+                    NodeLabel::Identifier,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseParen,
+                        TokenKind::Identifier,
                     ),
                 )?;
                 seq.finish()
-            }))?;
-            seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
-            seq.elem_labeled(
-                NodeLabel::ElseBranch,
-                OptionalHelper::transform(self.else_branch(input)),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::IfStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn import_alias(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::AsKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::AsKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Identifier,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.finish()
+            })
+            .with_kind(RuleKind::ImportAlias)
         })
-        .with_kind(RuleKind::ImportAlias)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn import_clause(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.path_import(input);
-            choice.consider(input, result)?;
-            let result = self.named_import(input);
-            choice.consider(input, result)?;
-            let result = self.import_deconstruction(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.path_import(input);
+                choice.consider(input, result)?;
+                let result = self.named_import(input);
+                choice.consider(input, result)?;
+                let result = self.import_deconstruction(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ImportClause)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ImportClause)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn import_deconstruction(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenBrace,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenBrace,
-                    ),
-                )?;
-                seq.elem(
-                    self.import_deconstruction_symbols(input)
-                        .with_label(NodeLabel::Symbols)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenBrace,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.import_deconstruction_symbols(input)
+                            .with_label(NodeLabel::Symbols)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                                input,
+                                self,
+                                TokenKind::CloseBrace,
+                                TokenAcceptanceThreshold(0u8),
+                            ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            self,
                             TokenKind::CloseBrace,
-                            TokenAcceptanceThreshold(0u8),
                         ),
-                )?;
+                    )?;
+                    seq.finish()
+                }))?;
                 seq.elem_labeled(
-                    NodeLabel::CloseBrace,
+                    NodeLabel::FromKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseBrace,
+                        TokenKind::FromKeyword,
                     ),
                 )?;
+                seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
                 seq.finish()
-            }))?;
-            seq.elem_labeled(
-                NodeLabel::FromKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::FromKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
-            seq.finish()
+            })
+            .with_kind(RuleKind::ImportDeconstruction)
         })
-        .with_kind(RuleKind::ImportDeconstruction)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn import_deconstruction_symbol(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Alias,
-                OptionalHelper::transform(self.import_alias(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Name,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Alias,
+                    OptionalHelper::transform(self.import_alias(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ImportDeconstructionSymbol)
         })
-        .with_kind(RuleKind::ImportDeconstructionSymbol)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn import_deconstruction_symbols(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| {
-                self.import_deconstruction_symbol(input)
-                    .with_label(NodeLabel::Item)
-            },
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::ImportDeconstructionSymbols)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn import_directive(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::ImportKeyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::ImportKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::Clause, self.import_clause(input))?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| {
+                    self.import_deconstruction_symbol(input)
+                        .with_label(NodeLabel::Item)
+                },
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::ImportDeconstructionSymbols)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn import_directive(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(
+                            NodeLabel::ImportKeyword,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::ImportKeyword,
+                            ),
+                        )?;
+                        seq.elem_labeled(NodeLabel::Clause, self.import_clause(input))?;
+                        seq.finish()
+                    })
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ImportDirective)
         })
-        .with_kind(RuleKind::ImportDirective)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn index_access_end(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Colon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Colon,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::End,
-                OptionalHelper::transform(self.expression(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Colon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Colon,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::End,
+                    OptionalHelper::transform(self.expression(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::IndexAccessEnd)
         })
-        .with_kind(RuleKind::IndexAccessEnd)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn index_access_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::IndexAccessExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::IndexAccessExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn inheritance_specifier(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::IsKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::IsKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Types, self.inheritance_types(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::IsKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::IsKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Types, self.inheritance_types(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::InheritanceSpecifier)
         })
-        .with_kind(RuleKind::InheritanceSpecifier)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn inheritance_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::TypeName, self.identifier_path(input))?;
-            seq.elem_labeled(
-                NodeLabel::Arguments,
-                OptionalHelper::transform(self.arguments_declaration(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::TypeName, self.identifier_path(input))?;
+                seq.elem_labeled(
+                    NodeLabel::Arguments,
+                    OptionalHelper::transform(self.arguments_declaration(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::InheritanceType)
         })
-        .with_kind(RuleKind::InheritanceType)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn inheritance_types(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.inheritance_type(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::InheritanceTypes)
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.inheritance_type(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::InheritanceTypes)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn interface_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::InterfaceKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::InterfaceKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Inheritence,
-                OptionalHelper::transform(self.inheritance_specifier(input)),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::OpenBrace,
+                    NodeLabel::InterfaceKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OpenBrace,
+                        TokenKind::InterfaceKeyword,
                     ),
                 )?;
-                seq.elem(
-                    self.interface_members(input)
-                        .with_label(NodeLabel::Members)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                            input,
-                            self,
-                            TokenKind::CloseBrace,
-                            TokenAcceptanceThreshold(0u8),
-                        ),
-                )?;
                 seq.elem_labeled(
-                    NodeLabel::CloseBrace,
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseBrace,
+                        TokenKind::Identifier,
                     ),
                 )?;
+                seq.elem_labeled(
+                    NodeLabel::Inheritence,
+                    OptionalHelper::transform(self.inheritance_specifier(input)),
+                )?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenBrace,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.interface_members(input)
+                            .with_label(NodeLabel::Members)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                                input,
+                                self,
+                                TokenKind::CloseBrace,
+                                TokenAcceptanceThreshold(0u8),
+                            ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseBrace,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
                 seq.finish()
-            }))?;
-            seq.finish()
+            })
+            .with_kind(RuleKind::InterfaceDefinition)
         })
-        .with_kind(RuleKind::InterfaceDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn interface_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.contract_member(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.contract_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::InterfaceMembers)
         })
-        .with_kind(RuleKind::InterfaceMembers)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn library_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::LibraryKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::LibraryKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::OpenBrace,
+                    NodeLabel::LibraryKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OpenBrace,
-                    ),
-                )?;
-                seq.elem(
-                    self.library_members(input)
-                        .with_label(NodeLabel::Members)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
+                        TokenKind::LibraryKeyword,
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::CloseBrace,
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseBrace,
+                        TokenKind::Identifier,
                     ),
                 )?;
-                seq.finish()
-            }))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::LibraryDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn library_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.contract_member(input).with_label(NodeLabel::Item)
-        })
-        .with_kind(RuleKind::LibraryMembers)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn mapping_key(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::KeyType, self.mapping_key_type(input))?;
-            if self.version_is_at_least_0_8_18 {
-                seq.elem_labeled(
-                    NodeLabel::Name,
-                    OptionalHelper::transform(
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::Identifier,
+                            TokenKind::OpenBrace,
                         ),
-                    ),
-                )?;
-            }
-            seq.finish()
+                    )?;
+                    seq.elem(
+                        self.library_members(input)
+                            .with_label(NodeLabel::Members)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseBrace,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseBrace,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::LibraryDefinition)
         })
-        .with_kind(RuleKind::MappingKey)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn mapping_key_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.elementary_type(input);
-            choice.consider(input, result)?;
-            let result = self.identifier_path(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+    fn library_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.contract_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::LibraryMembers)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::MappingKeyType)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn mapping_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::MappingKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::MappingKeyword,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenParen,
-                    ),
-                )?;
-                seq.elem(
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem_labeled(NodeLabel::KeyType, self.mapping_key(input))?;
-                        seq.elem_labeled(
-                            NodeLabel::EqualGreaterThan,
+    fn mapping_key(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::KeyType, self.mapping_key_type(input))?;
+                if self.version_is_at_least_0_8_18 {
+                    seq.elem_labeled(
+                        NodeLabel::Name,
+                        OptionalHelper::transform(
                             self.parse_token_with_trivia::<LexicalContextType::Default>(
                                 input,
-                                TokenKind::EqualGreaterThan,
-                            ),
-                        )?;
-                        seq.elem_labeled(NodeLabel::ValueType, self.mapping_value(input))?;
-                        seq.finish()
-                    })
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-                )?;
+                                TokenKind::Identifier,
+                            ),
+                        ),
+                    )?;
+                }
+                seq.finish()
+            })
+            .with_kind(RuleKind::MappingKey)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn mapping_key_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.elementary_type(input);
+                choice.consider(input, result)?;
+                let result = self.identifier_path(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::MappingKeyType)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn mapping_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::CloseParen,
+                    NodeLabel::MappingKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseParen,
+                        TokenKind::MappingKeyword,
                     ),
                 )?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenParen,
+                        ),
+                    )?;
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(NodeLabel::KeyType, self.mapping_key(input))?;
+                            seq.elem_labeled(
+                                NodeLabel::EqualGreaterThan,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::EqualGreaterThan,
+                                ),
+                            )?;
+                            seq.elem_labeled(NodeLabel::ValueType, self.mapping_value(input))?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseParen,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
                 seq.finish()
-            }))?;
-            seq.finish()
+            })
+            .with_kind(RuleKind::MappingType)
         })
-        .with_kind(RuleKind::MappingType)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn mapping_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-            if self.version_is_at_least_0_8_18 {
-                seq.elem_labeled(
-                    NodeLabel::Name,
-                    OptionalHelper::transform(
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                if self.version_is_at_least_0_8_18 {
+                    seq.elem_labeled(
+                        NodeLabel::Name,
+                        OptionalHelper::transform(
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Identifier,
+                            ),
                         ),
-                    ),
-                )?;
-            }
-            seq.finish()
+                    )?;
+                }
+                seq.finish()
+            })
+            .with_kind(RuleKind::MappingValue)
         })
-        .with_kind(RuleKind::MappingValue)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn member_access(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::Identifier,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::AddressKeyword,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::Identifier,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::AddressKeyword,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::MemberAccess)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::MemberAccess)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn member_access_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::MemberAccessExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::MemberAccessExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn modifier_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            if self.version_is_at_least_0_6_0 {
-                let result = self.override_specifier(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::VirtualKeyword,
-                );
-                choice.consider(input, result)?;
-            }
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.override_specifier(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::VirtualKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::ModifierAttribute)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::ModifierAttribute)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn modifier_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.modifier_attribute(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.modifier_attribute(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::ModifierAttributes)
         })
-        .with_kind(RuleKind::ModifierAttributes)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn modifier_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ModifierKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ModifierKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Parameters,
-                OptionalHelper::transform(self.parameters_declaration(input)),
-            )?;
-            seq.elem_labeled(NodeLabel::Attributes, self.modifier_attributes(input))?;
-            seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::ModifierKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ModifierKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Name,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Parameters,
+                    OptionalHelper::transform(self.parameters_declaration(input)),
+                )?;
+                seq.elem_labeled(NodeLabel::Attributes, self.modifier_attributes(input))?;
+                seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ModifierDefinition)
         })
-        .with_kind(RuleKind::ModifierDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn modifier_invocation(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::Name, self.identifier_path(input))?;
-            seq.elem_labeled(
-                NodeLabel::Arguments,
-                OptionalHelper::transform(self.arguments_declaration(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::Name, self.identifier_path(input))?;
+                seq.elem_labeled(
+                    NodeLabel::Arguments,
+                    OptionalHelper::transform(self.arguments_declaration(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ModifierInvocation)
         })
-        .with_kind(RuleKind::ModifierInvocation)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn multiplicative_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::MultiplicativeExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::MultiplicativeExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn named_argument(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Colon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Colon,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Name,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Colon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Colon,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::NamedArgument)
         })
-        .with_kind(RuleKind::NamedArgument)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn named_argument_group(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenBrace,
-                ),
-            )?;
-            seq.elem(
-                self.named_arguments(input)
-                    .with_label(NodeLabel::Arguments)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenBrace,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenBrace,
+                    ),
+                )?;
+                seq.elem(
+                    self.named_arguments(input)
+                        .with_label(NodeLabel::Arguments)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseBrace,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseBrace,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
                         TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseBrace,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::NamedArgumentGroup)
         })
-        .with_kind(RuleKind::NamedArgumentGroup)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn named_arguments(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.named_argument(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::NamedArguments)
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.named_argument(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::NamedArguments)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn named_arguments_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                OptionalHelper::transform(self.named_argument_group(input))
-                    .with_label(NodeLabel::Arguments)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    OptionalHelper::transform(self.named_argument_group(input))
+                        .with_label(NodeLabel::Arguments)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
                         TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::NamedArgumentsDeclaration)
         })
-        .with_kind(RuleKind::NamedArgumentsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn named_import(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Asterisk,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Asterisk,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Alias, self.import_alias(input))?;
-            seq.elem_labeled(
-                NodeLabel::FromKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::FromKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Asterisk,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Asterisk,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Alias, self.import_alias(input))?;
+                seq.elem_labeled(
+                    NodeLabel::FromKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::FromKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::NamedImport)
         })
-        .with_kind(RuleKind::NamedImport)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn new_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::NewKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::NewKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::NewKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::NewKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::NewExpression)
         })
-        .with_kind(RuleKind::NewExpression)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn number_unit(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::WeiKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_6_11 {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::GweiKeyword,
+                    TokenKind::WeiKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_7_0 {
+                if self.version_is_at_least_0_6_11 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::GweiKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_7_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::SzaboKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_7_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::FinneyKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::SzaboKeyword,
+                    TokenKind::EtherKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_7_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::FinneyKeyword,
+                    TokenKind::SecondsKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::EtherKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::SecondsKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::MinutesKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::HoursKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::DaysKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::WeeksKeyword,
-            );
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_5_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::YearsKeyword,
+                    TokenKind::MinutesKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::HoursKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::DaysKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::WeeksKeyword,
+                );
+                choice.consider(input, result)?;
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::YearsKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::NumberUnit)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::NumberUnit)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn or_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::OrExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::OrExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn override_paths(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            SeparatedHelper::run::<_, LexicalContextType::Default>(
-                input,
-                self,
-                |input| self.identifier_path(input).with_label(NodeLabel::Item),
-                TokenKind::Comma,
-                NodeLabel::Separator,
-            )
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::OverridePaths)
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SeparatedHelper::run::<_, LexicalContextType::Default>(
+                    input,
+                    self,
+                    |input| self.identifier_path(input).with_label(NodeLabel::Item),
+                    TokenKind::Comma,
+                    NodeLabel::Separator,
+                )
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::OverridePaths)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn override_paths_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenParen,
-                    ),
-                )?;
-                seq.elem(
-                    self.override_paths(input)
-                        .with_label(NodeLabel::Paths)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::CloseParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::CloseParen,
-                    ),
-                )?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::OverridePathsDeclaration)
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenParen,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.override_paths(input)
+                            .with_label(NodeLabel::Paths)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseParen,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::OverridePathsDeclaration)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn override_specifier(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::OverrideKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OverrideKeyword,
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Overridden,
+                        OptionalHelper::transform(self.override_paths_declaration(input)),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::OverrideSpecifier)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn parameter(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
                 seq.elem_labeled(
-                    NodeLabel::OverrideKeyword,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OverrideKeyword,
-                    ),
+                    NodeLabel::StorageLocation,
+                    OptionalHelper::transform(self.storage_location(input)),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::Overridden,
-                    OptionalHelper::transform(self.override_paths_declaration(input)),
+                    NodeLabel::Name,
+                    OptionalHelper::transform(
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Identifier,
+                        ),
+                    ),
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::OverrideSpecifier)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn parameter(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-            seq.elem_labeled(
-                NodeLabel::StorageLocation,
-                OptionalHelper::transform(self.storage_location(input)),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                OptionalHelper::transform(
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::Identifier,
-                    ),
-                ),
-            )?;
-            seq.finish()
+            .with_kind(RuleKind::Parameter)
         })
-        .with_kind(RuleKind::Parameter)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.parameter(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::Parameters)
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.parameter(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::Parameters)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.parameters(input)
-                    .with_label(NodeLabel::Parameters)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.parameters(input)
+                        .with_label(NodeLabel::Parameters)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                         input,
                         self,
                         TokenKind::CloseParen,
                         TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CloseParen,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ParametersDeclaration)
         })
-        .with_kind(RuleKind::ParametersDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn path_import(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
-            seq.elem_labeled(
-                NodeLabel::Alias,
-                OptionalHelper::transform(self.import_alias(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::Path, self.string_literal(input))?;
+                seq.elem_labeled(
+                    NodeLabel::Alias,
+                    OptionalHelper::transform(self.import_alias(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::PathImport)
         })
-        .with_kind(RuleKind::PathImport)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn positional_arguments(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.expression(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::PositionalArguments)
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.expression(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::PositionalArguments)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn positional_arguments_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.positional_arguments(input)
-                    .with_label(NodeLabel::Arguments)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.positional_arguments(input)
+                        .with_label(NodeLabel::Arguments)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
                         TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::PositionalArgumentsDeclaration)
         })
-        .with_kind(RuleKind::PositionalArgumentsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn postfix_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::PostfixExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::PostfixExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn pragma(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.abi_coder_pragma(input);
-            choice.consider(input, result)?;
-            let result = self.experimental_pragma(input);
-            choice.consider(input, result)?;
-            let result = self.version_pragma(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.abi_coder_pragma(input);
+                choice.consider(input, result)?;
+                let result = self.experimental_pragma(input);
+                choice.consider(input, result)?;
+                let result = self.version_pragma(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::Pragma)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::Pragma)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn pragma_directive(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::PragmaKeyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::PragmaKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::Pragma, self.pragma(input))?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Pragma>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(
+                            NodeLabel::PragmaKeyword,
+                            self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::PragmaKeyword,
+                            ),
+                        )?;
+                        seq.elem_labeled(NodeLabel::Pragma, self.pragma(input))?;
+                        seq.finish()
+                    })
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Pragma>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::PragmaDirective)
         })
-        .with_kind(RuleKind::PragmaDirective)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn prefix_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::PrefixExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::PrefixExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn receive_function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.modifier_invocation(input);
-                choice.consider(input, result)?;
-                let result = self.override_specifier(input);
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ExternalKeyword,
-                );
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::PayableKeyword,
-                );
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::VirtualKeyword,
-                );
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ReceiveFunctionAttribute)
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.modifier_invocation(input);
+                    choice.consider(input, result)?;
+                    let result = self.override_specifier(input);
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ExternalKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PayableKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::VirtualKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ReceiveFunctionAttribute)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn receive_function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            ZeroOrMoreHelper::run(input, |input| {
-                self.receive_function_attribute(input)
-                    .with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ReceiveFunctionAttributes)
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                ZeroOrMoreHelper::run(input, |input| {
+                    self.receive_function_attribute(input)
+                        .with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ReceiveFunctionAttributes)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn receive_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(
-                    NodeLabel::ReceiveKeyword,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::ReceiveKeyword,
-                    ),
-                )?;
-                seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-                seq.elem_labeled(
-                    NodeLabel::Attributes,
-                    self.receive_function_attributes(input),
-                )?;
-                seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ReceiveFunctionDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn return_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
                 SequenceHelper::run(|mut seq| {
                     seq.elem_labeled(
-                        NodeLabel::ReturnKeyword,
+                        NodeLabel::ReceiveKeyword,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::ReturnKeyword,
+                            TokenKind::ReceiveKeyword,
                         ),
                     )?;
+                    seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
                     seq.elem_labeled(
-                        NodeLabel::Expression,
-                        OptionalHelper::transform(self.expression(input)),
+                        NodeLabel::Attributes,
+                        self.receive_function_attributes(input),
                     )?;
+                    seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
                     seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::ReturnStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn returns_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ReturnsKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ReturnsKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Variables, self.parameters_declaration(input))?;
-            seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ReceiveFunctionDefinition)
         })
-        .with_kind(RuleKind::ReturnsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn revert_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_4 {
+    fn return_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem(
                     SequenceHelper::run(|mut seq| {
                         seq.elem_labeled(
-                            NodeLabel::RevertKeyword,
+                            NodeLabel::ReturnKeyword,
                             self.parse_token_with_trivia::<LexicalContextType::Default>(
                                 input,
-                                TokenKind::RevertKeyword,
+                                TokenKind::ReturnKeyword,
                             ),
                         )?;
                         seq.elem_labeled(
-                            NodeLabel::Error,
-                            OptionalHelper::transform(self.identifier_path(input)),
+                            NodeLabel::Expression,
+                            OptionalHelper::transform(self.expression(input)),
                         )?;
-                        seq.elem_labeled(NodeLabel::Arguments, self.arguments_declaration(input))?;
                         seq.finish()
                     })
                     .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
@@ -4170,455 +7489,515 @@ impl Language {
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::RevertStatement)
+            .with_kind(RuleKind::ReturnStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn returns_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::ReturnsKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ReturnsKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Variables, self.parameters_declaration(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::ReturnsDeclaration)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn revert_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_4 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(
+                                NodeLabel::RevertKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::RevertKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Error,
+                                OptionalHelper::transform(self.identifier_path(input)),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Arguments,
+                                self.arguments_declaration(input),
+                            )?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Semicolon,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::RevertStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn shift_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::ShiftExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::ShiftExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        self.source_unit_members(input)
-            .with_label(NodeLabel::Members)
-            .with_kind(RuleKind::SourceUnit)
+        input.nested(|input| {
+            self.source_unit_members(input)
+                .with_label(NodeLabel::Members)
+                .with_kind(RuleKind::SourceUnit)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.pragma_directive(input);
-            choice.consider(input, result)?;
-            let result = self.import_directive(input);
-            choice.consider(input, result)?;
-            let result = self.contract_definition(input);
-            choice.consider(input, result)?;
-            let result = self.interface_definition(input);
-            choice.consider(input, result)?;
-            let result = self.library_definition(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_6_0 {
-                let result = self.struct_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
-                let result = self.enum_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_7_1 {
-                let result = self.function_definition(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_7_4 {
-                let result = self.constant_definition(input);
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.pragma_directive(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_4 {
-                let result = self.error_definition(input);
+                let result = self.import_directive(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_8 {
-                let result = self.user_defined_value_type_definition(input);
+                let result = self.contract_definition(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_13 {
-                let result = self.using_directive(input);
+                let result = self.interface_definition(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_22 {
-                let result = self.event_definition(input);
+                let result = self.library_definition(input);
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.struct_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.enum_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_7_1 {
+                    let result = self.function_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_7_4 {
+                    let result = self.constant_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_4 {
+                    let result = self.error_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_8 {
+                    let result = self.user_defined_value_type_definition(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_13 {
+                    let result = self.using_directive(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_22 {
+                    let result = self.event_definition(input);
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::SourceUnitMember)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::SourceUnitMember)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.source_unit_member(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.source_unit_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::SourceUnitMembers)
         })
-        .with_kind(RuleKind::SourceUnitMembers)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn state_variable_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            if self.version_is_at_least_0_6_0 {
-                let result = self.override_specifier(input);
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.override_specifier(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::ConstantKeyword,
+                );
                 choice.consider(input, result)?;
-            }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::ConstantKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::InternalKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PrivateKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::PublicKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_6_5 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::ImmutableKeyword,
+                    TokenKind::InternalKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::PrivateKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::PublicKeyword,
+                );
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_6_5 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ImmutableKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::StateVariableAttribute)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::StateVariableAttribute)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn state_variable_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.state_variable_attribute(input)
-                .with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.state_variable_attribute(input)
+                    .with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::StateVariableAttributes)
         })
-        .with_kind(RuleKind::StateVariableAttributes)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn state_variable_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-                    seq.elem_labeled(NodeLabel::Attributes, self.state_variable_attributes(input))?;
-                    seq.elem_labeled(
-                        NodeLabel::Name,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Value,
-                        OptionalHelper::transform(self.state_variable_definition_value(input)),
-                    )?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                        seq.elem_labeled(
+                            NodeLabel::Attributes,
+                            self.state_variable_attributes(input),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::Name,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Identifier,
+                            ),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::Value,
+                            OptionalHelper::transform(self.state_variable_definition_value(input)),
+                        )?;
+                        seq.finish()
+                    })
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::StateVariableDefinition)
         })
-        .with_kind(RuleKind::StateVariableDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn state_variable_definition_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Equal,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Equal,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Equal,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Equal,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Value, self.expression(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::StateVariableDefinitionValue)
         })
-        .with_kind(RuleKind::StateVariableDefinitionValue)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.expression_statement(input);
-            choice.consider(input, result)?;
-            let result = self.variable_declaration_statement(input);
-            choice.consider(input, result)?;
-            let result = self.tuple_deconstruction_statement(input);
-            choice.consider(input, result)?;
-            let result = self.if_statement(input);
-            choice.consider(input, result)?;
-            let result = self.for_statement(input);
-            choice.consider(input, result)?;
-            let result = self.while_statement(input);
-            choice.consider(input, result)?;
-            let result = self.do_while_statement(input);
-            choice.consider(input, result)?;
-            let result = self.continue_statement(input);
-            choice.consider(input, result)?;
-            let result = self.break_statement(input);
-            choice.consider(input, result)?;
-            let result = self.return_statement(input);
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_5_0 {
-                let result = self.throw_statement(input);
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.expression_statement(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_21 {
-                let result = self.emit_statement(input);
+                let result = self.variable_declaration_statement(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_6_0 {
-                let result = self.try_statement(input);
+                let result = self.tuple_deconstruction_statement(input);
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_4 {
-                let result = self.revert_statement(input);
+                let result = self.if_statement(input);
                 choice.consider(input, result)?;
-            }
-            let result = self.assembly_statement(input);
-            choice.consider(input, result)?;
-            let result = self.block(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_8_0 {
-                let result = self.unchecked_block(input);
+                let result = self.for_statement(input);
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                let result = self.while_statement(input);
+                choice.consider(input, result)?;
+                let result = self.do_while_statement(input);
+                choice.consider(input, result)?;
+                let result = self.continue_statement(input);
+                choice.consider(input, result)?;
+                let result = self.break_statement(input);
+                choice.consider(input, result)?;
+                let result = self.return_statement(input);
+                choice.consider(input, result)?;
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.throw_statement(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_21 {
+                    let result = self.emit_statement(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.try_statement(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_4 {
+                    let result = self.revert_statement(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.assembly_statement(input);
+                choice.consider(input, result)?;
+                let result = self.block(input);
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_8_0 {
+                    let result = self.unchecked_block(input);
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::Statement)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::Statement)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn statements(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.statement(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.statement(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::Statements)
         })
-        .with_kind(RuleKind::Statements)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn storage_location(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::MemoryKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::StorageKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_5_0 {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::CallDataKeyword,
+                    TokenKind::MemoryKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::StorageKeyword,
+                );
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CallDataKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::StorageLocation)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::StorageLocation)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn string_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            if !self.version_is_at_least_0_5_14 {
-                let result = self.string_literal(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_5_14 {
-                let result = self.string_literals(input);
-                choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_5_14 {
-                let result = self.hex_string_literal(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_5_14 {
-                let result = self.hex_string_literals(input);
-                choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_7_0 {
-                let result = self.unicode_string_literals(input);
-                choice.consider(input, result)?;
-            }
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                if !self.version_is_at_least_0_5_14 {
+                    let result = self.string_literal(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_5_14 {
+                    let result = self.string_literals(input);
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_5_14 {
+                    let result = self.hex_string_literal(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_5_14 {
+                    let result = self.hex_string_literals(input);
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_7_0 {
+                    let result = self.unicode_string_literals(input);
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::StringExpression)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::StringExpression)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn string_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::SingleQuotedStringLiteral,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::DoubleQuotedStringLiteral,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::SingleQuotedStringLiteral,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    input,
+                    TokenKind::DoubleQuotedStringLiteral,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::StringLiteral)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::StringLiteral)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn string_literals(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_5_14 {
-            OneOrMoreHelper::run(input, |input| {
-                self.string_literal(input).with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::StringLiterals)
+        input.nested(|input| {
+            if self.version_is_at_least_0_5_14 {
+                OneOrMoreHelper::run(input, |input| {
+                    self.string_literal(input).with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::StringLiterals)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn struct_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::StructKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::StructKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-                let input = delim_guard.ctx();
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::OpenBrace,
+                    NodeLabel::StructKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::OpenBrace,
-                    ),
-                )?;
-                seq.elem(
-                    self.struct_members(input)
-                        .with_label(NodeLabel::Members)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
+                        TokenKind::StructKeyword,
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::CloseBrace,
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::CloseBrace,
+                        TokenKind::Identifier,
                     ),
                 )?;
-                seq.finish()
-            }))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::StructDefinition)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn struct_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
+                    let input = delim_guard.ctx();
                     seq.elem_labeled(
-                        NodeLabel::Name,
+                        NodeLabel::OpenBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::Identifier,
+                            TokenKind::OpenBrace,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.struct_members(input)
+                            .with_label(NodeLabel::Members)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseBrace,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseBrace,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseBrace,
                         ),
                     )?;
                     seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::StructMember)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn struct_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.struct_member(input).with_label(NodeLabel::Item)
+                }))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::StructDefinition)
         })
-        .with_kind(RuleKind::StructMembers)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn throw_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_5_0 {
+    fn struct_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem(
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::ThrowKeyword,
-                    )
-                    .with_label(NodeLabel::ThrowKeyword)
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                        seq.elem_labeled(
+                            NodeLabel::Name,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Identifier,
+                            ),
+                        )?;
+                        seq.finish()
+                    })
                     .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                         input,
                         self,
@@ -4635,76 +8014,199 @@ impl Language {
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::ThrowStatement)
+            .with_kind(RuleKind::StructMember)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn struct_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.struct_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::StructMembers)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn throw_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_5_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::ThrowKeyword,
+                        )
+                        .with_label(NodeLabel::ThrowKeyword)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Semicolon,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::ThrowStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn try_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::TryKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::TryKeyword,
+                        ),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Expression, self.expression(input))?;
+                    seq.elem_labeled(
+                        NodeLabel::Returns,
+                        OptionalHelper::transform(self.returns_declaration(input)),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Body, self.block(input))?;
+                    seq.elem_labeled(NodeLabel::CatchClauses, self.catch_clauses(input))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::TryStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn tuple_deconstruction_element(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OptionalHelper::transform(self.tuple_member(input))
+                .with_label(NodeLabel::Member)
+                .with_kind(RuleKind::TupleDeconstructionElement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn tuple_deconstruction_elements(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| {
+                    self.tuple_deconstruction_element(input)
+                        .with_label(NodeLabel::Item)
+                },
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::TupleDeconstructionElements)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn tuple_deconstruction_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| { SequenceHelper :: run (| mut seq | { seq . elem (SequenceHelper :: run (| mut seq | { if ! self . version_is_at_least_0_5_0 { seq . elem_labeled (NodeLabel :: VarKeyword , OptionalHelper :: transform (self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: VarKeyword))) ? ; } seq . elem (SequenceHelper :: run (| mut seq | { let mut delim_guard = input . open_delim (TokenKind :: CloseParen) ; let input = delim_guard . ctx () ; seq . elem_labeled (NodeLabel :: OpenParen , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: OpenParen)) ? ; seq . elem (self . tuple_deconstruction_elements (input) . with_label (NodeLabel :: Elements) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: CloseParen , TokenAcceptanceThreshold (0u8) ,)) ? ; seq . elem_labeled (NodeLabel :: CloseParen , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: CloseParen)) ? ; seq . finish () })) ? ; seq . elem_labeled (NodeLabel :: Equal , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Equal)) ? ; seq . elem_labeled (NodeLabel :: Expression , self . expression (input)) ? ; seq . finish () }) . recover_until_with_nested_delims :: < _ , LexicalContextType :: Default > (input , self , TokenKind :: Semicolon , TokenAcceptanceThreshold (1u8) ,)) ? ; seq . elem_labeled (NodeLabel :: Semicolon , self . parse_token_with_trivia :: < LexicalContextType :: Default > (input , TokenKind :: Semicolon)) ? ; seq . finish () }) . with_kind (RuleKind :: TupleDeconstructionStatement) })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn tuple_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
                 seq.elem_labeled(
-                    NodeLabel::TryKeyword,
+                    NodeLabel::OpenParen,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::TryKeyword,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.tuple_values(input)
+                        .with_label(NodeLabel::Items)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::CloseParen,
+                        TokenAcceptanceThreshold(0u8),
                     ),
                 )?;
-                seq.elem_labeled(NodeLabel::Expression, self.expression(input))?;
                 seq.elem_labeled(
-                    NodeLabel::Returns,
-                    OptionalHelper::transform(self.returns_declaration(input)),
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::CloseParen,
+                    ),
                 )?;
-                seq.elem_labeled(NodeLabel::Body, self.block(input))?;
-                seq.elem_labeled(NodeLabel::CatchClauses, self.catch_clauses(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::TryStatement)
+            .with_kind(RuleKind::TupleExpression)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.typed_tuple_member(input);
+                choice.consider(input, result)?;
+                let result = self.untyped_tuple_member(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::TupleMember)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_deconstruction_element(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(self.tuple_member(input))
-            .with_label(NodeLabel::Member)
-            .with_kind(RuleKind::TupleDeconstructionElement)
+    fn tuple_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OptionalHelper::transform(self.expression(input))
+                .with_label(NodeLabel::Expression)
+                .with_kind(RuleKind::TupleValue)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_deconstruction_elements(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| {
-                self.tuple_deconstruction_element(input)
-                    .with_label(NodeLabel::Item)
-            },
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::TupleDeconstructionElements)
+    fn tuple_values(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Default>(
+                input,
+                self,
+                |input| self.tuple_value(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::TupleValues)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_deconstruction_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
+    fn type_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_5_3 {
                 SequenceHelper::run(|mut seq| {
-                    if !self.version_is_at_least_0_5_0 {
-                        seq.elem_labeled(
-                            NodeLabel::VarKeyword,
-                            OptionalHelper::transform(
-                                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                    input,
-                                    TokenKind::VarKeyword,
-                                ),
-                            ),
-                        )?;
-                    }
+                    seq.elem_labeled(
+                        NodeLabel::TypeKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::TypeKeyword,
+                        ),
+                    )?;
                     seq.elem(SequenceHelper::run(|mut seq| {
                         let mut delim_guard = input.open_delim(TokenKind::CloseParen);
                         let input = delim_guard.ctx();
@@ -4716,451 +8218,674 @@ impl Language {
                             ),
                         )?;
                         seq.elem(
-                            self.tuple_deconstruction_elements(input)
-                                .with_label(NodeLabel::Elements)
+                            self.type_name(input)
+                                .with_label(NodeLabel::TypeName)
+                                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                                input,
+                                self,
+                                TokenKind::CloseParen,
+                                TokenAcceptanceThreshold(0u8),
+                            ),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::CloseParen,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::CloseParen,
+                            ),
+                        )?;
+                        seq.finish()
+                    }))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::TypeExpression)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn type_name(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let parse_postfix_array_type_name = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_postfix_operator(
+                    RuleKind::ArrayTypeName,
+                    1u8,
+                    SequenceHelper::run(|mut seq| {
+                        let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
+                        let input = delim_guard.ctx();
+                        seq.elem_labeled(
+                            NodeLabel::OpenBracket,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::OpenBracket,
+                            ),
+                        )?;
+                        seq.elem(
+                            OptionalHelper::transform(self.expression(input))
+                                .with_label(NodeLabel::Index)
                                 .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                                     input,
                                     self,
-                                    TokenKind::CloseParen,
+                                    TokenKind::CloseBracket,
                                     TokenAcceptanceThreshold(0u8),
                                 ),
                         )?;
                         seq.elem_labeled(
-                            NodeLabel::CloseParen,
+                            NodeLabel::CloseBracket,
                             self.parse_token_with_trivia::<LexicalContextType::Default>(
                                 input,
-                                TokenKind::CloseParen,
+                                TokenKind::CloseBracket,
                             ),
                         )?;
                         seq.finish()
-                    }))?;
+                    }),
+                )
+            };
+            let primary_expression_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.function_type(input);
+                    choice.consider(input, result)?;
+                    let result = self.mapping_type(input);
+                    choice.consider(input, result)?;
+                    let result = self.elementary_type(input);
+                    choice.consider(input, result)?;
+                    let result = self.identifier_path(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            };
+            let postfix_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_postfix_array_type_name(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let linear_expression_parser = |input: &mut ParserContext<'_>| {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(primary_expression_parser(input))?;
+                    seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
+                    seq.finish()
+                })
+            };
+            PrecedenceHelper::reduce_precedence_result(
+                RuleKind::TypeName,
+                linear_expression_parser(input),
+            )
+            .with_kind(RuleKind::TypeName)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn typed_tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
+                seq.elem_labeled(
+                    NodeLabel::StorageLocation,
+                    OptionalHelper::transform(self.storage_location(input)),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Name,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::TypedTupleMember)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn unchecked_block(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_0 {
+                SequenceHelper::run(|mut seq| {
                     seq.elem_labeled(
-                        NodeLabel::Equal,
+                        NodeLabel::UncheckedKeyword,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::Equal,
+                            TokenKind::UncheckedKeyword,
                         ),
                     )?;
-                    seq.elem_labeled(NodeLabel::Expression, self.expression(input))?;
+                    seq.elem_labeled(NodeLabel::Block, self.block(input))?;
                     seq.finish()
                 })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UncheckedBlock)
         })
-        .with_kind(RuleKind::TupleDeconstructionStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.tuple_values(input)
-                    .with_label(NodeLabel::Items)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+    fn unicode_string_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_7_0 {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                        TokenKind::SingleQuotedUnicodeStringLiteral,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::DoubleQuotedUnicodeStringLiteral,
+                    );
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UnicodeStringLiteral)
         })
-        .with_kind(RuleKind::TupleExpression)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.typed_tuple_member(input);
-            choice.consider(input, result)?;
-            let result = self.untyped_tuple_member(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+    fn unicode_string_literals(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_7_0 {
+                OneOrMoreHelper::run(input, |input| {
+                    self.unicode_string_literal(input)
+                        .with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UnicodeStringLiterals)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::TupleMember)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(self.expression(input))
-            .with_label(NodeLabel::Expression)
-            .with_kind(RuleKind::TupleValue)
+    fn unnamed_function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_6_0 {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.modifier_invocation(input);
+                    choice.consider(input, result)?;
+                    if !self.version_is_at_least_0_5_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::ConstantKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::ExternalKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    if !self.version_is_at_least_0_5_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::InternalKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::PayableKeyword,
+                    );
+                    choice.consider(input, result)?;
+                    if !self.version_is_at_least_0_5_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::PrivateKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    if !self.version_is_at_least_0_5_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::PublicKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    if self.version_is_at_least_0_4_16 && !self.version_is_at_least_0_6_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::PureKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    if self.version_is_at_least_0_4_16 && !self.version_is_at_least_0_6_0 {
+                        let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::ViewKeyword,
+                        );
+                        choice.consider(input, result)?;
+                    }
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UnnamedFunctionAttribute)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn tuple_values(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Default>(
-            input,
-            self,
-            |input| self.tuple_value(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::TupleValues)
+    fn unnamed_function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_6_0 {
+                ZeroOrMoreHelper::run(input, |input| {
+                    self.unnamed_function_attribute(input)
+                        .with_label(NodeLabel::Item)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UnnamedFunctionAttributes)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn type_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_5_3 {
+    fn unnamed_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_6_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::FunctionKeyword,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::FunctionKeyword,
+                        ),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
+                    seq.elem_labeled(
+                        NodeLabel::Attributes,
+                        self.unnamed_function_attributes(input),
+                    )?;
+                    seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UnnamedFunctionDefinition)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn untyped_tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::TypeKeyword,
+                    NodeLabel::StorageLocation,
+                    OptionalHelper::transform(self.storage_location(input)),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::TypeKeyword,
+                        TokenKind::Identifier,
                     ),
                 )?;
-                seq.elem(SequenceHelper::run(|mut seq| {
-                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                    let input = delim_guard.ctx();
-                    seq.elem_labeled(
-                        NodeLabel::OpenParen,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                seq.finish()
+            })
+            .with_kind(RuleKind::UntypedTupleMember)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn user_defined_value_type_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_8 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem_labeled(
+                                NodeLabel::TypeKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::TypeKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::Name,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::Identifier,
+                                ),
+                            )?;
+                            seq.elem_labeled(
+                                NodeLabel::IsKeyword,
+                                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                    input,
+                                    TokenKind::IsKeyword,
+                                ),
+                            )?;
+                            seq.elem_labeled(NodeLabel::ValueType, self.elementary_type(input))?;
+                            seq.finish()
+                        })
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                             input,
-                            TokenKind::OpenParen,
+                            self,
+                            TokenKind::Semicolon,
+                            TokenAcceptanceThreshold(1u8),
                         ),
                     )?;
-                    seq.elem(
-                        self.type_name(input)
-                            .with_label(NodeLabel::TypeName)
-                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                    seq.elem_labeled(
+                        NodeLabel::Semicolon,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            self,
-                            TokenKind::CloseParen,
-                            TokenAcceptanceThreshold(0u8),
+                            TokenKind::Semicolon,
                         ),
                     )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UserDefinedValueTypeDefinition)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn using_alias(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_19 {
+                SequenceHelper::run(|mut seq| {
                     seq.elem_labeled(
-                        NodeLabel::CloseParen,
+                        NodeLabel::AsKeyword,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::CloseParen,
+                            TokenKind::AsKeyword,
                         ),
                     )?;
+                    seq.elem_labeled(NodeLabel::Operator, self.using_operator(input))?;
                     seq.finish()
-                }))?;
-                seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UsingAlias)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn using_clause(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.identifier_path(input);
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_8_13 {
+                    let result = self.using_deconstruction(input);
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::TypeExpression)
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::UsingClause)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn type_name(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let parse_postfix_array_type_name = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::ArrayTypeName,
-                1u8,
+    fn using_deconstruction(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_13 {
                 SequenceHelper::run(|mut seq| {
-                    let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
+                    let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
                     let input = delim_guard.ctx();
                     seq.elem_labeled(
-                        NodeLabel::OpenBracket,
+                        NodeLabel::OpenBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::OpenBracket,
+                            TokenKind::OpenBrace,
                         ),
                     )?;
                     seq.elem(
-                        OptionalHelper::transform(self.expression(input))
-                            .with_label(NodeLabel::Index)
+                        self.using_deconstruction_symbols(input)
+                            .with_label(NodeLabel::Symbols)
                             .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
                                 input,
                                 self,
-                                TokenKind::CloseBracket,
+                                TokenKind::CloseBrace,
                                 TokenAcceptanceThreshold(0u8),
                             ),
                     )?;
                     seq.elem_labeled(
-                        NodeLabel::CloseBracket,
+                        NodeLabel::CloseBrace,
                         self.parse_token_with_trivia::<LexicalContextType::Default>(
                             input,
-                            TokenKind::CloseBracket,
+                            TokenKind::CloseBrace,
                         ),
                     )?;
                     seq.finish()
-                }),
-            )
-        };
-        let primary_expression_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.function_type(input);
-                choice.consider(input, result)?;
-                let result = self.mapping_type(input);
-                choice.consider(input, result)?;
-                let result = self.elementary_type(input);
-                choice.consider(input, result)?;
-                let result = self.identifier_path(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        };
-        let postfix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_postfix_array_type_name(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let linear_expression_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(primary_expression_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
-                seq.finish()
-            })
-        };
-        PrecedenceHelper::reduce_precedence_result(
-            RuleKind::TypeName,
-            linear_expression_parser(input),
-        )
-        .with_kind(RuleKind::TypeName)
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UsingDeconstruction)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn typed_tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::TypeName, self.type_name(input))?;
-            seq.elem_labeled(
-                NodeLabel::StorageLocation,
-                OptionalHelper::transform(self.storage_location(input)),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+    fn using_deconstruction_symbol(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_13 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(NodeLabel::Name, self.identifier_path(input))?;
+                    if self.version_is_at_least_0_8_19 {
+                        seq.elem_labeled(
+                            NodeLabel::Alias,
+                            OptionalHelper::transform(self.using_alias(input)),
+                        )?;
+                    }
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UsingDeconstructionSymbol)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn using_deconstruction_symbols(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_13 {
+                SeparatedHelper::run::<_, LexicalContextType::Default>(
                     input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.finish()
+                    self,
+                    |input| {
+                        self.using_deconstruction_symbol(input)
+                            .with_label(NodeLabel::Item)
+                    },
+                    TokenKind::Comma,
+                    NodeLabel::Separator,
+                )
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UsingDeconstructionSymbols)
         })
-        .with_kind(RuleKind::TypedTupleMember)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn unchecked_block(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_0 {
+    fn using_directive(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(
+                            NodeLabel::UsingKeyword,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::UsingKeyword,
+                            ),
+                        )?;
+                        seq.elem_labeled(NodeLabel::Clause, self.using_clause(input))?;
+                        seq.elem_labeled(
+                            NodeLabel::ForKeyword,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::ForKeyword,
+                            ),
+                        )?;
+                        seq.elem_labeled(NodeLabel::Target, self.using_target(input))?;
+                        if self.version_is_at_least_0_8_13 {
+                            seq.elem_labeled(
+                                NodeLabel::GlobalKeyword,
+                                OptionalHelper::transform(
+                                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                        input,
+                                        TokenKind::GlobalKeyword,
+                                    ),
+                                ),
+                            )?;
+                        }
+                        seq.finish()
+                    })
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
                 seq.elem_labeled(
-                    NodeLabel::UncheckedKeyword,
+                    NodeLabel::Semicolon,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::UncheckedKeyword,
+                        TokenKind::Semicolon,
                     ),
                 )?;
-                seq.elem_labeled(NodeLabel::Block, self.block(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UncheckedBlock)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn unicode_string_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_7_0 {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::SingleQuotedUnicodeStringLiteral,
-                );
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::DoubleQuotedUnicodeStringLiteral,
-                );
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UnicodeStringLiteral)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn unicode_string_literals(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_7_0 {
-            OneOrMoreHelper::run(input, |input| {
-                self.unicode_string_literal(input)
-                    .with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UnicodeStringLiterals)
+            .with_kind(RuleKind::UsingDirective)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn unnamed_function_attribute(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_6_0 {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.modifier_invocation(input);
-                choice.consider(input, result)?;
-                if !self.version_is_at_least_0_5_0 {
+    fn using_operator(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_8_19 {
+                ChoiceHelper::run(input, |mut choice, input| {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::ConstantKeyword,
+                        TokenKind::Ampersand,
                     );
                     choice.consider(input, result)?;
-                }
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::ExternalKeyword,
-                );
-                choice.consider(input, result)?;
-                if !self.version_is_at_least_0_5_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::InternalKeyword,
+                        TokenKind::Asterisk,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::BangEqual,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Bar,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Caret,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::EqualEqual,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::GreaterThan,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::GreaterThanEqual,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::LessThan,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::LessThanEqual,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Minus,
                     );
                     choice.consider(input, result)?;
-                }
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::PayableKeyword,
-                );
-                choice.consider(input, result)?;
-                if !self.version_is_at_least_0_5_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::PrivateKeyword,
+                        TokenKind::Percent,
                     );
                     choice.consider(input, result)?;
-                }
-                if !self.version_is_at_least_0_5_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::PublicKeyword,
+                        TokenKind::Plus,
                     );
                     choice.consider(input, result)?;
-                }
-                if self.version_is_at_least_0_4_16 && !self.version_is_at_least_0_6_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::PureKeyword,
+                        TokenKind::Slash,
                     );
                     choice.consider(input, result)?;
-                }
-                if self.version_is_at_least_0_4_16 && !self.version_is_at_least_0_6_0 {
                     let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::ViewKeyword,
+                        TokenKind::Tilde,
                     );
                     choice.consider(input, result)?;
-                }
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UnnamedFunctionAttribute)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn unnamed_function_attributes(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_6_0 {
-            ZeroOrMoreHelper::run(input, |input| {
-                self.unnamed_function_attribute(input)
-                    .with_label(NodeLabel::Item)
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UnnamedFunctionAttributes)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn unnamed_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_6_0 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(
-                    NodeLabel::FunctionKeyword,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::FunctionKeyword,
-                    ),
-                )?;
-                seq.elem_labeled(NodeLabel::Parameters, self.parameters_declaration(input))?;
-                seq.elem_labeled(
-                    NodeLabel::Attributes,
-                    self.unnamed_function_attributes(input),
-                )?;
-                seq.elem_labeled(NodeLabel::Body, self.function_body(input))?;
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UnnamedFunctionDefinition)
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::UsingOperator)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn untyped_tuple_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::StorageLocation,
-                OptionalHelper::transform(self.storage_location(input)),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+    fn using_target(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.type_name(input);
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
-                    TokenKind::Identifier,
-                ),
-            )?;
-            seq.finish()
+                    TokenKind::Asterisk,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::UsingTarget)
         })
-        .with_kind(RuleKind::UntypedTupleMember)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn user_defined_value_type_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_8 {
+    fn variable_declaration_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem(
                     SequenceHelper::run(|mut seq| {
                         seq.elem_labeled(
-                            NodeLabel::TypeKeyword,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::TypeKeyword,
-                            ),
+                            NodeLabel::VariableType,
+                            self.variable_declaration_type(input),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::StorageLocation,
+                            OptionalHelper::transform(self.storage_location(input)),
                         )?;
                         seq.elem_labeled(
                             NodeLabel::Name,
@@ -5170,13 +8895,9 @@ impl Language {
                             ),
                         )?;
                         seq.elem_labeled(
-                            NodeLabel::IsKeyword,
-                            self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                input,
-                                TokenKind::IsKeyword,
-                            ),
+                            NodeLabel::Value,
+                            OptionalHelper::transform(self.variable_declaration_value(input)),
                         )?;
-                        seq.elem_labeled(NodeLabel::ValueType, self.elementary_type(input))?;
                         seq.finish()
                     })
                     .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
@@ -5195,64 +8916,407 @@ impl Language {
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UserDefinedValueTypeDefinition)
+            .with_kind(RuleKind::VariableDeclarationStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn variable_declaration_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.type_name(input);
+                choice.consider(input, result)?;
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::VarKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::VariableDeclarationType)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn variable_declaration_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::Equal,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Equal,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Expression, self.expression(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::VariableDeclarationValue)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_comparator(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.version_expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::VersionExpression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::VersionComparator => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let parse_left_version_range = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_binary_operator(
+                    RuleKind::VersionRange,
+                    1u8,
+                    1u8 + 1,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::Minus,
+                    )
+                    .with_label(NodeLabel::Operator),
+                )
+            };
+            let parse_prefix_version_comparator = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_prefix_operator(
+                    RuleKind::VersionComparator,
+                    3u8,
+                    ChoiceHelper::run(input, |mut choice, input| {
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::Caret,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::Tilde,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::Equal,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::LessThan,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::GreaterThan,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::LessThanEqual,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        let result = self
+                            .parse_token_with_trivia::<LexicalContextType::Pragma>(
+                                input,
+                                TokenKind::GreaterThanEqual,
+                            )
+                            .with_label(NodeLabel::Operator);
+                        choice.consider(input, result)?;
+                        choice.finish(input)
+                    }),
+                )
+            };
+            let prefix_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_prefix_version_comparator(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let primary_expression_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.version_specifiers(input);
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::SingleQuotedVersionLiteral,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::DoubleQuotedVersionLiteral,
+                    );
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            };
+            let binary_operand_parser = |input: &mut ParserContext<'_>| {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(ZeroOrMoreHelper::run(input, prefix_operator_parser))?;
+                    seq.elem(primary_expression_parser(input))?;
+                    seq.finish()
+                })
+            };
+            let binary_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_left_version_range(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let linear_expression_parser = |input: &mut ParserContext<'_>| {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(binary_operand_parser(input))?;
+                    seq.elem(ZeroOrMoreHelper::run(input, |input| {
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem(binary_operator_parser(input))?;
+                            seq.elem(binary_operand_parser(input))?;
+                            seq.finish()
+                        })
+                    }))?;
+                    seq.finish()
+                })
+            };
+            PrecedenceHelper::reduce_precedence_result(
+                RuleKind::VersionExpression,
+                linear_expression_parser(input),
+            )
+            .with_kind(RuleKind::VersionExpression)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_expression_set(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OneOrMoreHelper::run(input, |input| {
+                self.version_expression(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::VersionExpressionSet)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_expression_sets(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Pragma>(
+                input,
+                self,
+                |input| {
+                    self.version_expression_set(input)
+                        .with_label(NodeLabel::Item)
+                },
+                TokenKind::BarBar,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::VersionExpressionSets)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_pragma(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::SolidityKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::SolidityKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Sets, self.version_expression_sets(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::VersionPragma)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_range(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.version_expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::VersionExpression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::VersionRange => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn version_specifiers(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Pragma>(
+                input,
+                self,
+                |input| {
+                    self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                        input,
+                        TokenKind::VersionSpecifier,
+                    )
+                    .with_label(NodeLabel::Item)
+                },
+                TokenKind::Period,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::VersionSpecifiers)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn using_alias(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_19 {
+    fn while_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::AsKeyword,
+                    NodeLabel::WhileKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Default>(
                         input,
-                        TokenKind::AsKeyword,
+                        TokenKind::WhileKeyword,
                     ),
                 )?;
-                seq.elem_labeled(NodeLabel::Operator, self.using_operator(input))?;
+                seq.elem(SequenceHelper::run(|mut seq| {
+                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                    let input = delim_guard.ctx();
+                    seq.elem_labeled(
+                        NodeLabel::OpenParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::OpenParen,
+                        ),
+                    )?;
+                    seq.elem(
+                        self.expression(input)
+                            .with_label(NodeLabel::Condition)
+                            .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::CloseParen,
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::CloseParen,
+                        ),
+                    )?;
+                    seq.finish()
+                }))?;
+                seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UsingAlias)
+            .with_kind(RuleKind::WhileStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn using_clause(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.identifier_path(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_8_13 {
-                let result = self.using_deconstruction(input);
+    fn yul_arguments(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Yul>(
+                input,
+                self,
+                |input| self.yul_expression(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::YulArguments)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn yul_assignment_operator(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                if !self.version_is_at_least_0_5_5 {
+                    let result = self.yul_colon_and_equal(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::ColonEqual,
+                );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulAssignmentOperator)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::UsingClause)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn using_deconstruction(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_13 {
+    fn yul_assignment_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::Names, self.yul_paths(input))?;
+                seq.elem_labeled(NodeLabel::Assignment, self.yul_assignment_operator(input))?;
+                seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulAssignmentStatement)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn yul_block(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
                 let input = delim_guard.ctx();
                 seq.elem_labeled(
                     NodeLabel::OpenBrace,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
                         TokenKind::OpenBrace,
                     ),
                 )?;
                 seq.elem(
-                    self.using_deconstruction_symbols(input)
-                        .with_label(NodeLabel::Symbols)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
+                    self.yul_statements(input)
+                        .with_label(NodeLabel::Statements)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
                             input,
                             self,
                             TokenKind::CloseBrace,
@@ -5261,1631 +9325,1079 @@ impl Language {
                 )?;
                 seq.elem_labeled(
                     NodeLabel::CloseBrace,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
                         TokenKind::CloseBrace,
                     ),
                 )?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UsingDeconstruction)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn using_deconstruction_symbol(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_13 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(NodeLabel::Name, self.identifier_path(input))?;
-                if self.version_is_at_least_0_8_19 {
-                    seq.elem_labeled(
-                        NodeLabel::Alias,
-                        OptionalHelper::transform(self.using_alias(input)),
-                    )?;
-                }
-                seq.finish()
-            })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UsingDeconstructionSymbol)
+            .with_kind(RuleKind::YulBlock)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn using_deconstruction_symbols(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_13 {
-            SeparatedHelper::run::<_, LexicalContextType::Default>(
+    fn yul_break_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            self.parse_token_with_trivia::<LexicalContextType::Yul>(
                 input,
-                self,
-                |input| {
-                    self.using_deconstruction_symbol(input)
-                        .with_label(NodeLabel::Item)
-                },
-                TokenKind::Comma,
-                NodeLabel::Separator,
+                TokenKind::YulBreakKeyword,
             )
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UsingDeconstructionSymbols)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn using_directive(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::UsingKeyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::UsingKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::Clause, self.using_clause(input))?;
-                    seq.elem_labeled(
-                        NodeLabel::ForKeyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::ForKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::Target, self.using_target(input))?;
-                    if self.version_is_at_least_0_8_13 {
-                        seq.elem_labeled(
-                            NodeLabel::GlobalKeyword,
-                            OptionalHelper::transform(
-                                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                                    input,
-                                    TokenKind::GlobalKeyword,
-                                ),
-                            ),
-                        )?;
-                    }
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+            .with_label(NodeLabel::BreakKeyword)
+            .with_kind(RuleKind::YulBreakStatement)
         })
-        .with_kind(RuleKind::UsingDirective)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn using_operator(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_8_19 {
+    fn yul_built_in_function(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Ampersand,
+                    TokenKind::YulAddKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulAddModKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulAddressKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulAndKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulBalanceKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulBlockHashKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulByteKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallCodeKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallDataCopyKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallDataLoadKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallDataSizeKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallerKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCallValueKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCoinBaseKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulCreateKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulDelegateCallKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulDivKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulEqKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulExpKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulExtCodeCopyKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulExtCodeSizeKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulGasKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulGasLimitKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulGasPriceKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulGtKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulInvalidKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulIsZeroKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Asterisk,
+                    TokenKind::YulLog0Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::BangEqual,
+                    TokenKind::YulLog1Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self
-                    .parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Bar);
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Caret,
+                    TokenKind::YulLog2Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::EqualEqual,
+                    TokenKind::YulLog3Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::GreaterThan,
+                    TokenKind::YulLog4Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::GreaterThanEqual,
+                    TokenKind::YulLtKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::LessThan,
+                    TokenKind::YulMLoadKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::LessThanEqual,
+                    TokenKind::YulModKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Minus,
+                    TokenKind::YulMSizeKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Percent,
+                    TokenKind::YulMStore8Keyword,
                 );
                 choice.consider(input, result)?;
-                let result = self
-                    .parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Plus);
-                choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Slash,
+                    TokenKind::YulMStoreKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Tilde,
+                    TokenKind::YulMulKeyword,
                 );
                 choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::UsingOperator)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn using_target(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.type_name(input);
-            choice.consider(input, result)?;
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Asterisk);
-            choice.consider(input, result)?;
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::UsingTarget)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn variable_declaration_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::VariableType,
-                        self.variable_declaration_type(input),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::StorageLocation,
-                        OptionalHelper::transform(self.storage_location(input)),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Name,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Value,
-                        OptionalHelper::transform(self.variable_declaration_value(input)),
-                    )?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::VariableDeclarationStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn variable_declaration_type(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.type_name(input);
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_5_0 {
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::VarKeyword,
+                    TokenKind::YulMulModKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::VariableDeclarationType)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn variable_declaration_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::Equal,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::Equal,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Expression, self.expression(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::VariableDeclarationValue)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_comparator(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.version_expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::VersionExpression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::VersionComparator => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let parse_left_version_range = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::VersionRange,
-                1u8,
-                1u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(input, TokenKind::Minus)
-                    .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_prefix_version_comparator = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_prefix_operator(
-                RuleKind::VersionComparator,
-                3u8,
-                ChoiceHelper::run(input, |mut choice, input| {
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::Caret,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::Tilde,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::Equal,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::LessThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::GreaterThan,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::LessThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    let result = self
-                        .parse_token_with_trivia::<LexicalContextType::Pragma>(
-                            input,
-                            TokenKind::GreaterThanEqual,
-                        )
-                        .with_label(NodeLabel::Operator);
-                    choice.consider(input, result)?;
-                    choice.finish(input)
-                }),
-            )
-        };
-        let prefix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_prefix_version_comparator(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let primary_expression_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.version_specifiers(input);
+                    TokenKind::YulNotKeyword,
+                );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::SingleQuotedVersionLiteral,
+                    TokenKind::YulNumberKeyword,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Pragma>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::DoubleQuotedVersionLiteral,
+                    TokenKind::YulOriginKeyword,
                 );
                 choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        };
-        let binary_operand_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(ZeroOrMoreHelper::run(input, prefix_operator_parser))?;
-                seq.elem(primary_expression_parser(input))?;
-                seq.finish()
-            })
-        };
-        let binary_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_left_version_range(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let linear_expression_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(binary_operand_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, |input| {
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem(binary_operator_parser(input))?;
-                        seq.elem(binary_operand_parser(input))?;
-                        seq.finish()
-                    })
-                }))?;
-                seq.finish()
-            })
-        };
-        PrecedenceHelper::reduce_precedence_result(
-            RuleKind::VersionExpression,
-            linear_expression_parser(input),
-        )
-        .with_kind(RuleKind::VersionExpression)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_expression_set(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OneOrMoreHelper::run(input, |input| {
-            self.version_expression(input).with_label(NodeLabel::Item)
-        })
-        .with_kind(RuleKind::VersionExpressionSet)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_expression_sets(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Pragma>(
-            input,
-            self,
-            |input| {
-                self.version_expression_set(input)
-                    .with_label(NodeLabel::Item)
-            },
-            TokenKind::BarBar,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::VersionExpressionSets)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_pragma(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::SolidityKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::SolidityKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Sets, self.version_expression_sets(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::VersionPragma)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_range(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.version_expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::VersionExpression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::VersionRange => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn version_specifiers(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Pragma>(
-            input,
-            self,
-            |input| {
-                self.parse_token_with_trivia::<LexicalContextType::Pragma>(
-                    input,
-                    TokenKind::VersionSpecifier,
-                )
-                .with_label(NodeLabel::Item)
-            },
-            TokenKind::Period,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::VersionSpecifiers)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn while_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::WhileKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::WhileKeyword,
-                ),
-            )?;
-            seq.elem(SequenceHelper::run(|mut seq| {
-                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                let input = delim_guard.ctx();
-                seq.elem_labeled(
-                    NodeLabel::OpenParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::OpenParen,
-                    ),
-                )?;
-                seq.elem(
-                    self.expression(input)
-                        .with_label(NodeLabel::Condition)
-                        .recover_until_with_nested_delims::<_, LexicalContextType::Default>(
-                        input,
-                        self,
-                        TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::CloseParen,
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::CloseParen,
-                    ),
-                )?;
-                seq.finish()
-            }))?;
-            seq.elem_labeled(NodeLabel::Body, self.statement(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::WhileStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_arguments(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Yul>(
-            input,
-            self,
-            |input| self.yul_expression(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::YulArguments)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_assignment_operator(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            if !self.version_is_at_least_0_5_5 {
-                let result = self.yul_colon_and_equal(input);
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulOrKeyword,
+                );
                 choice.consider(input, result)?;
-            }
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::ColonEqual);
-            choice.consider(input, result)?;
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulAssignmentOperator)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_assignment_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::Names, self.yul_paths(input))?;
-            seq.elem_labeled(NodeLabel::Assignment, self.yul_assignment_operator(input))?;
-            seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::YulAssignmentStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_block(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseBrace);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::OpenBrace,
-                ),
-            )?;
-            seq.elem(
-                self.yul_statements(input)
-                    .with_label(NodeLabel::Statements)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
-                        input,
-                        self,
-                        TokenKind::CloseBrace,
-                        TokenAcceptanceThreshold(0u8),
-                    ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseBrace,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    TokenKind::YulPopKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::CloseBrace,
-                ),
-            )?;
-            seq.finish()
-        })
-        .with_kind(RuleKind::YulBlock)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_break_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        self.parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::YulBreakKeyword)
-            .with_label(NodeLabel::BreakKeyword)
-            .with_kind(RuleKind::YulBreakStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_built_in_function(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulAddKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulAddModKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulAddressKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulAndKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulBalanceKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulBlockHashKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulByteKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallCodeKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallDataCopyKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallDataLoadKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallDataSizeKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallerKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCallValueKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCoinBaseKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulCreateKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulDelegateCallKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulDivKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::YulEqKeyword);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulExpKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulExtCodeCopyKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulExtCodeSizeKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulGasKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulGasLimitKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulGasPriceKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::YulGtKeyword);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulInvalidKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulIsZeroKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLog0Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLog1Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLog2Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLog3Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLog4Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::YulLtKeyword);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMLoadKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulModKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMSizeKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMStore8Keyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMStoreKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMulKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulMulModKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulNotKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulNumberKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulOriginKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self
-                .parse_token_with_trivia::<LexicalContextType::Yul>(input, TokenKind::YulOrKeyword);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulPopKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulReturnKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulRevertKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSDivKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSelfDestructKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSgtKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSignExtendKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSLoadKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSltKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSModKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSStoreKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulStopKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSubKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulTimestampKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulXorKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_4_12 {
+                    TokenKind::YulReturnKeyword,
+                );
+                choice.consider(input, result)?;
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulKeccak256Keyword,
+                    TokenKind::YulRevertKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_5_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulSha3Keyword,
+                    TokenKind::YulSDivKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_5_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulSuicideKeyword,
+                    TokenKind::YulSelfDestructKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_12 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulReturnDataCopyKeyword,
+                    TokenKind::YulSgtKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_12 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulReturnDataSizeKeyword,
+                    TokenKind::YulSignExtendKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_12 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulStaticCallKeyword,
+                    TokenKind::YulSLoadKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_4_12 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulCreate2Keyword,
+                    TokenKind::YulSltKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_5_0 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulExtCodeHashKeyword,
+                    TokenKind::YulSModKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSarKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulShlKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulShrKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulChainIdKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulSelfBalanceKeyword,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_8_7 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulBaseFeeKeyword,
+                    TokenKind::YulSStoreKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if !self.version_is_at_least_0_8_18 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulDifficultyKeyword,
+                    TokenKind::YulStopKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_18 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulPrevRandaoKeyword,
+                    TokenKind::YulSubKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_24 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulBlobBaseFeeKeyword,
+                    TokenKind::YulTimestampKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_24 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulBlobHashKeyword,
+                    TokenKind::YulXorKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_24 {
+                if self.version_is_at_least_0_4_12 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulKeccak256Keyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulSha3Keyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulSuicideKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_12 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulReturnDataCopyKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_12 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulReturnDataSizeKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_12 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulStaticCallKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_4_12 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulCreate2Keyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_5_0 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulExtCodeHashKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulTLoadKeyword,
+                    TokenKind::YulSarKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_24 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulTStoreKeyword,
+                    TokenKind::YulShlKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            if self.version_is_at_least_0_8_24 {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulMCopyKeyword,
+                    TokenKind::YulShrKeyword,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
-        })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulBuiltInFunction)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_colon_and_equal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_5_5 {
-            SequenceHelper::run(|mut seq| {
-                seq.elem_labeled(
-                    NodeLabel::Colon,
-                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulChainIdKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulSelfBalanceKeyword,
+                );
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_8_7 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
-                        TokenKind::Colon,
-                    ),
-                )?;
-                seq.elem_labeled(
-                    NodeLabel::Equal,
-                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        TokenKind::YulBaseFeeKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if !self.version_is_at_least_0_8_18 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
-                        TokenKind::Equal,
-                    ),
-                )?;
-                seq.finish()
+                        TokenKind::YulDifficultyKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_18 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulPrevRandaoKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_24 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulBlobBaseFeeKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_24 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulBlobHashKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_24 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulTLoadKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_24 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulTStoreKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                if self.version_is_at_least_0_8_24 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulMCopyKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::YulColonAndEqual)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_continue_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        self.parse_token_with_trivia::<LexicalContextType::Yul>(
-            input,
-            TokenKind::YulContinueKeyword,
-        )
-        .with_label(NodeLabel::ContinueKeyword)
-        .with_kind(RuleKind::YulContinueStatement)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn yul_default_case(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::DefaultKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulDefaultKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulBuiltInFunction)
         })
-        .with_kind(RuleKind::YulDefaultCase)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let parse_postfix_yul_function_call_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::YulFunctionCallExpression,
-                1u8,
+    fn yul_colon_and_equal(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_5_5 {
                 SequenceHelper::run(|mut seq| {
-                    let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-                    let input = delim_guard.ctx();
                     seq.elem_labeled(
-                        NodeLabel::OpenParen,
-                        self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                            input,
-                            TokenKind::OpenParen,
-                        ),
-                    )?;
-                    seq.elem(
-                        self.yul_arguments(input)
-                            .with_label(NodeLabel::Arguments)
-                            .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
-                            input,
-                            self,
-                            TokenKind::CloseParen,
-                            TokenAcceptanceThreshold(0u8),
+                        NodeLabel::Colon,
+                        self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                            input,
+                            TokenKind::Colon,
                         ),
                     )?;
                     seq.elem_labeled(
-                        NodeLabel::CloseParen,
+                        NodeLabel::Equal,
                         self.parse_token_with_trivia::<LexicalContextType::Yul>(
                             input,
-                            TokenKind::CloseParen,
+                            TokenKind::Equal,
                         ),
                     )?;
                     seq.finish()
-                }),
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::YulColonAndEqual)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn yul_continue_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                input,
+                TokenKind::YulContinueKeyword,
             )
-        };
-        let primary_expression_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = self.yul_literal(input);
-                choice.consider(input, result)?;
-                let result = self.yul_built_in_function(input);
-                choice.consider(input, result)?;
-                let result = self.yul_path(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-            .with_label(NodeLabel::Variant)
-        };
-        let postfix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_postfix_yul_function_call_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let linear_expression_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(primary_expression_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
-                seq.finish()
-            })
-        };
-        PrecedenceHelper::reduce_precedence_result(
-            RuleKind::YulExpression,
-            linear_expression_parser(input),
-        )
-        .with_kind(RuleKind::YulExpression)
+            .with_label(NodeLabel::ContinueKeyword)
+            .with_kind(RuleKind::YulContinueStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_for_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::ForKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulForKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Initialization, self.yul_block(input))?;
-            seq.elem_labeled(NodeLabel::Condition, self.yul_expression(input))?;
-            seq.elem_labeled(NodeLabel::Iterator, self.yul_block(input))?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+    fn yul_default_case(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::DefaultKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulDefaultKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulDefaultCase)
         })
-        .with_kind(RuleKind::YulForStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_function_call_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.yul_expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::YulExpression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
-                    label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::YulFunctionCallExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
-                _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+    fn yul_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let parse_postfix_yul_function_call_expression = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_postfix_operator(
+                    RuleKind::YulFunctionCallExpression,
+                    1u8,
+                    SequenceHelper::run(|mut seq| {
+                        let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                        let input = delim_guard.ctx();
+                        seq.elem_labeled(
+                            NodeLabel::OpenParen,
+                            self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                                input,
+                                TokenKind::OpenParen,
+                            ),
+                        )?;
+                        seq.elem(
+                            self.yul_arguments(input)
+                                .with_label(NodeLabel::Arguments)
+                                .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
+                                input,
+                                self,
+                                TokenKind::CloseParen,
+                                TokenAcceptanceThreshold(0u8),
+                            ),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::CloseParen,
+                            self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                                input,
+                                TokenKind::CloseParen,
+                            ),
+                        )?;
+                        seq.finish()
+                    }),
+                )
+            };
+            let primary_expression_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.yul_literal(input);
+                    choice.consider(input, result)?;
+                    let result = self.yul_built_in_function(input);
+                    choice.consider(input, result)?;
+                    let result = self.yul_path(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            };
+            let postfix_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_postfix_yul_function_call_expression(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let linear_expression_parser = |input: &mut ParserContext<'_>| {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(primary_expression_parser(input))?;
+                    seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
+                    seq.finish()
+                })
+            };
+            PrecedenceHelper::reduce_precedence_result(
+                RuleKind::YulExpression,
+                linear_expression_parser(input),
+            )
+            .with_kind(RuleKind::YulExpression)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::FunctionKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulFunctionKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Name,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulIdentifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Parameters,
-                self.yul_parameters_declaration(input),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Returns,
-                OptionalHelper::transform(self.yul_returns_declaration(input)),
-            )?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+    fn yul_for_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::ForKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulForKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Initialization, self.yul_block(input))?;
+                seq.elem_labeled(NodeLabel::Condition, self.yul_expression(input))?;
+                seq.elem_labeled(NodeLabel::Iterator, self.yul_block(input))?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulForStatement)
         })
-        .with_kind(RuleKind::YulFunctionDefinition)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_if_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::IfKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulIfKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Condition, self.yul_expression(input))?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+    fn yul_function_call_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            let result = self.yul_expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
+                    label: _,
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::YulExpression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::YulFunctionCallExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
+                _ => ParserResult::no_match(vec![]),
+            }
         })
-        .with_kind(RuleKind::YulIfStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_label(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if !self.version_is_at_least_0_5_0 {
+    fn yul_function_definition(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             SequenceHelper::run(|mut seq| {
                 seq.elem_labeled(
-                    NodeLabel::Label,
+                    NodeLabel::FunctionKeyword,
                     self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
-                        TokenKind::YulIdentifier,
+                        TokenKind::YulFunctionKeyword,
                     ),
                 )?;
                 seq.elem_labeled(
-                    NodeLabel::Colon,
+                    NodeLabel::Name,
                     self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
-                        TokenKind::Colon,
+                        TokenKind::YulIdentifier,
                     ),
                 )?;
+                seq.elem_labeled(
+                    NodeLabel::Parameters,
+                    self.yul_parameters_declaration(input),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Returns,
+                    OptionalHelper::transform(self.yul_returns_declaration(input)),
+                )?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
                 seq.finish()
             })
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::YulLabel)
+            .with_kind(RuleKind::YulFunctionDefinition)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_leave_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_0_6_0 {
-            self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulLeaveKeyword,
-            )
-            .with_label(NodeLabel::LeaveKeyword)
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::YulLeaveStatement)
+    fn yul_if_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::IfKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulIfKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Condition, self.yul_expression(input))?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulIfStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulTrueKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulFalseKeyword,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulDecimalLiteral,
-            );
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulHexLiteral,
-            );
-            choice.consider(input, result)?;
-            let result = self.hex_string_literal(input);
-            choice.consider(input, result)?;
-            let result = self.string_literal(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+    fn yul_label(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if !self.version_is_at_least_0_5_0 {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem_labeled(
+                        NodeLabel::Label,
+                        self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                            input,
+                            TokenKind::YulIdentifier,
+                        ),
+                    )?;
+                    seq.elem_labeled(
+                        NodeLabel::Colon,
+                        self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                            input,
+                            TokenKind::Colon,
+                        ),
+                    )?;
+                    seq.finish()
+                })
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::YulLabel)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulLiteral)
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Yul>(
-            input,
-            self,
-            |input| {
+    fn yul_leave_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            if self.version_is_at_least_0_6_0 {
                 self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulIdentifier,
+                    TokenKind::YulLeaveKeyword,
                 )
-                .with_label(NodeLabel::Item)
-            },
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        ))
-        .with_kind(RuleKind::YulParameters)
+                .with_label(NodeLabel::LeaveKeyword)
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::YulLeaveStatement)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
-    fn yul_parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseParen);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenParen,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
+    fn yul_literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::OpenParen,
-                ),
-            )?;
-            seq.elem(
-                self.yul_parameters(input)
-                    .with_label(NodeLabel::Parameters)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
+                    TokenKind::YulTrueKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulFalseKeyword,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulDecimalLiteral,
+                );
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                    input,
+                    TokenKind::YulHexLiteral,
+                );
+                choice.consider(input, result)?;
+                let result = self.hex_string_literal(input);
+                choice.consider(input, result)?;
+                let result = self.string_literal(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulLiteral)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn yul_parameters(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            OptionalHelper::transform(SeparatedHelper::run::<_, LexicalContextType::Yul>(
+                input,
+                self,
+                |input| {
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulIdentifier,
+                    )
+                    .with_label(NodeLabel::Item)
+                },
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            ))
+            .with_kind(RuleKind::YulParameters)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn yul_parameters_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseParen);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::OpenParen,
+                    ),
+                )?;
+                seq.elem(
+                    self.yul_parameters(input)
+                        .with_label(NodeLabel::Parameters)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Yul>(
+                            input,
+                            self,
+                            TokenKind::CloseParen,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseParen,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
                         input,
-                        self,
                         TokenKind::CloseParen,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseParen,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::CloseParen,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulParametersDeclaration)
         })
-        .with_kind(RuleKind::YulParametersDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_path(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Yul>(
-            input,
-            self,
-            |input| self.yul_path_component(input).with_label(NodeLabel::Item),
-            TokenKind::Period,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::YulPath)
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Yul>(
+                input,
+                self,
+                |input| self.yul_path_component(input).with_label(NodeLabel::Item),
+                TokenKind::Period,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::YulPath)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_path_component(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                input,
-                TokenKind::YulIdentifier,
-            );
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_8_10 {
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
                     input,
-                    TokenKind::YulAddressKeyword,
+                    TokenKind::YulIdentifier,
                 );
                 choice.consider(input, result)?;
-            }
-            choice.finish(input)
+                if self.version_is_at_least_0_8_10 {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulAddressKeyword,
+                    );
+                    choice.consider(input, result)?;
+                }
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulPathComponent)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulPathComponent)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_paths(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Yul>(
-            input,
-            self,
-            |input| self.yul_path(input).with_label(NodeLabel::Item),
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::YulPaths)
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Yul>(
+                input,
+                self,
+                |input| self.yul_path(input).with_label(NodeLabel::Item),
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::YulPaths)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_return_variables(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SeparatedHelper::run::<_, LexicalContextType::Yul>(
-            input,
-            self,
-            |input| {
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulIdentifier,
-                )
-                .with_label(NodeLabel::Item)
-            },
-            TokenKind::Comma,
-            NodeLabel::Separator,
-        )
-        .with_kind(RuleKind::YulReturnVariables)
+        input.nested(|input| {
+            SeparatedHelper::run::<_, LexicalContextType::Yul>(
+                input,
+                self,
+                |input| {
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulIdentifier,
+                    )
+                    .with_label(NodeLabel::Item)
+                },
+                TokenKind::Comma,
+                NodeLabel::Separator,
+            )
+            .with_kind(RuleKind::YulReturnVariables)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_returns_declaration(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::MinusGreaterThan,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::MinusGreaterThan,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Variables, self.yul_return_variables(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::MinusGreaterThan,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::MinusGreaterThan,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Variables, self.yul_return_variables(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulReturnsDeclaration)
         })
-        .with_kind(RuleKind::YulReturnsDeclaration)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.yul_block(input);
-            choice.consider(input, result)?;
-            let result = self.yul_function_definition(input);
-            choice.consider(input, result)?;
-            let result = self.yul_variable_declaration_statement(input);
-            choice.consider(input, result)?;
-            let result = self.yul_assignment_statement(input);
-            choice.consider(input, result)?;
-            let result = self.yul_if_statement(input);
-            choice.consider(input, result)?;
-            let result = self.yul_for_statement(input);
-            choice.consider(input, result)?;
-            let result = self.yul_switch_statement(input);
-            choice.consider(input, result)?;
-            if self.version_is_at_least_0_6_0 {
-                let result = self.yul_leave_statement(input);
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.yul_block(input);
                 choice.consider(input, result)?;
-            }
-            let result = self.yul_break_statement(input);
-            choice.consider(input, result)?;
-            let result = self.yul_continue_statement(input);
-            choice.consider(input, result)?;
-            if !self.version_is_at_least_0_5_0 {
-                let result = self.yul_label(input);
+                let result = self.yul_function_definition(input);
                 choice.consider(input, result)?;
-            }
-            let result = self.yul_expression(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+                let result = self.yul_variable_declaration_statement(input);
+                choice.consider(input, result)?;
+                let result = self.yul_assignment_statement(input);
+                choice.consider(input, result)?;
+                let result = self.yul_if_statement(input);
+                choice.consider(input, result)?;
+                let result = self.yul_for_statement(input);
+                choice.consider(input, result)?;
+                let result = self.yul_switch_statement(input);
+                choice.consider(input, result)?;
+                if self.version_is_at_least_0_6_0 {
+                    let result = self.yul_leave_statement(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.yul_break_statement(input);
+                choice.consider(input, result)?;
+                let result = self.yul_continue_statement(input);
+                choice.consider(input, result)?;
+                if !self.version_is_at_least_0_5_0 {
+                    let result = self.yul_label(input);
+                    choice.consider(input, result)?;
+                }
+                let result = self.yul_expression(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulStatement)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_statements(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ZeroOrMoreHelper::run(input, |input| {
-            self.yul_statement(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            ZeroOrMoreHelper::run(input, |input| {
+                self.yul_statement(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::YulStatements)
         })
-        .with_kind(RuleKind::YulStatements)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_switch_case(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.yul_default_case(input);
-            choice.consider(input, result)?;
-            let result = self.yul_value_case(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.yul_default_case(input);
+                choice.consider(input, result)?;
+                let result = self.yul_value_case(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::YulSwitchCase)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::YulSwitchCase)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_switch_cases(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OneOrMoreHelper::run(input, |input| {
-            self.yul_switch_case(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            OneOrMoreHelper::run(input, |input| {
+                self.yul_switch_case(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::YulSwitchCases)
         })
-        .with_kind(RuleKind::YulSwitchCases)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_switch_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::SwitchKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulSwitchKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
-            seq.elem_labeled(NodeLabel::Cases, self.yul_switch_cases(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::SwitchKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulSwitchKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
+                seq.elem_labeled(NodeLabel::Cases, self.yul_switch_cases(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulSwitchStatement)
         })
-        .with_kind(RuleKind::YulSwitchStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_value_case(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::CaseKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulCaseKeyword,
-                ),
-            )?;
-            seq.elem_labeled(NodeLabel::Value, self.yul_literal(input))?;
-            seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::CaseKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulCaseKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(NodeLabel::Value, self.yul_literal(input))?;
+                seq.elem_labeled(NodeLabel::Body, self.yul_block(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulValueCase)
         })
-        .with_kind(RuleKind::YulValueCase)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_variable_declaration_statement(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(
-                NodeLabel::LetKeyword,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulLetKeyword,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Names,
-                self.parse_token_with_trivia::<LexicalContextType::Yul>(
-                    input,
-                    TokenKind::YulIdentifier,
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Value,
-                OptionalHelper::transform(self.yul_variable_declaration_value(input)),
-            )?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(
+                    NodeLabel::LetKeyword,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulLetKeyword,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Names,
+                    self.parse_token_with_trivia::<LexicalContextType::Yul>(
+                        input,
+                        TokenKind::YulIdentifier,
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Value,
+                    OptionalHelper::transform(self.yul_variable_declaration_value(input)),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulVariableDeclarationStatement)
         })
-        .with_kind(RuleKind::YulVariableDeclarationStatement)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn yul_variable_declaration_value(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem_labeled(NodeLabel::Assignment, self.yul_assignment_operator(input))?;
-            seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
-            seq.finish()
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem_labeled(NodeLabel::Assignment, self.yul_assignment_operator(input))?;
+                seq.elem_labeled(NodeLabel::Expression, self.yul_expression(input))?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::YulVariableDeclarationValue)
         })
-        .with_kind(RuleKind::YulVariableDeclarationValue)
     }
 
     #[allow(unused_assignments, unused_parens)]
@@ -9112,287 +12624,413 @@ impl Language {
     }
 
     pub fn parse(&self, kind: RuleKind, input: &str) -> ParseOutput {
-        match kind {
-            RuleKind::ABICoderPragma => Self::abi_coder_pragma.parse(self, input),
-            RuleKind::AdditiveExpression => Self::additive_expression.parse(self, input),
-            RuleKind::AddressType => Self::address_type.parse(self, input),
-            RuleKind::AndExpression => Self::and_expression.parse(self, input),
-            RuleKind::ArgumentsDeclaration => Self::arguments_declaration.parse(self, input),
-            RuleKind::ArrayExpression => Self::array_expression.parse(self, input),
-            RuleKind::ArrayTypeName => Self::array_type_name.parse(self, input),
-            RuleKind::ArrayValues => Self::array_values.parse(self, input),
-            RuleKind::AssemblyFlags => Self::assembly_flags.parse(self, input),
+        self.parse_with_options(kind, input, &ParseOptions::default())
+    }
+
+    /// Same as [`Language::parse`], but stops early with an error if any of the limits in `options` is hit,
+    /// instead of overflowing the stack or running for too long on malicious input.
+    pub fn parse_with_options(
+        &self,
+        kind: RuleKind,
+        input: &str,
+        options: &ParseOptions,
+    ) -> ParseOutput {
+        let mut output = match kind {
+            RuleKind::ABICoderPragma => Self::abi_coder_pragma.parse(self, input, options),
+            RuleKind::AdditiveExpression => Self::additive_expression.parse(self, input, options),
+            RuleKind::AddressType => Self::address_type.parse(self, input, options),
+            RuleKind::AndExpression => Self::and_expression.parse(self, input, options),
+            RuleKind::ArgumentsDeclaration => {
+                Self::arguments_declaration.parse(self, input, options)
+            }
+            RuleKind::ArrayExpression => Self::array_expression.parse(self, input, options),
+            RuleKind::ArrayTypeName => Self::array_type_name.parse(self, input, options),
+            RuleKind::ArrayValues => Self::array_values.parse(self, input, options),
+            RuleKind::AssemblyFlags => Self::assembly_flags.parse(self, input, options),
             RuleKind::AssemblyFlagsDeclaration => {
-                Self::assembly_flags_declaration.parse(self, input)
-            }
-            RuleKind::AssemblyStatement => Self::assembly_statement.parse(self, input),
-            RuleKind::AssignmentExpression => Self::assignment_expression.parse(self, input),
-            RuleKind::BitwiseAndExpression => Self::bitwise_and_expression.parse(self, input),
-            RuleKind::BitwiseOrExpression => Self::bitwise_or_expression.parse(self, input),
-            RuleKind::BitwiseXorExpression => Self::bitwise_xor_expression.parse(self, input),
-            RuleKind::Block => Self::block.parse(self, input),
-            RuleKind::BreakStatement => Self::break_statement.parse(self, input),
-            RuleKind::CallOptions => Self::call_options.parse(self, input),
-            RuleKind::CallOptionsExpression => Self::call_options_expression.parse(self, input),
-            RuleKind::CatchClause => Self::catch_clause.parse(self, input),
-            RuleKind::CatchClauseError => Self::catch_clause_error.parse(self, input),
-            RuleKind::CatchClauses => Self::catch_clauses.parse(self, input),
-            RuleKind::ComparisonExpression => Self::comparison_expression.parse(self, input),
-            RuleKind::ConditionalExpression => Self::conditional_expression.parse(self, input),
-            RuleKind::ConstantDefinition => Self::constant_definition.parse(self, input),
-            RuleKind::ConstructorAttribute => Self::constructor_attribute.parse(self, input),
-            RuleKind::ConstructorAttributes => Self::constructor_attributes.parse(self, input),
-            RuleKind::ConstructorDefinition => Self::constructor_definition.parse(self, input),
-            RuleKind::ContinueStatement => Self::continue_statement.parse(self, input),
-            RuleKind::ContractDefinition => Self::contract_definition.parse(self, input),
-            RuleKind::ContractMember => Self::contract_member.parse(self, input),
-            RuleKind::ContractMembers => Self::contract_members.parse(self, input),
-            RuleKind::DecimalNumberExpression => Self::decimal_number_expression.parse(self, input),
-            RuleKind::DoWhileStatement => Self::do_while_statement.parse(self, input),
-            RuleKind::ElementaryType => Self::elementary_type.parse(self, input),
-            RuleKind::ElseBranch => Self::else_branch.parse(self, input),
-            RuleKind::EmitStatement => Self::emit_statement.parse(self, input),
-            RuleKind::EnumDefinition => Self::enum_definition.parse(self, input),
-            RuleKind::EnumMembers => Self::enum_members.parse(self, input),
-            RuleKind::EqualityExpression => Self::equality_expression.parse(self, input),
-            RuleKind::ErrorDefinition => Self::error_definition.parse(self, input),
-            RuleKind::ErrorParameter => Self::error_parameter.parse(self, input),
-            RuleKind::ErrorParameters => Self::error_parameters.parse(self, input),
+                Self::assembly_flags_declaration.parse(self, input, options)
+            }
+            RuleKind::AssemblyStatement => Self::assembly_statement.parse(self, input, options),
+            RuleKind::AssignmentExpression => {
+                Self::assignment_expression.parse(self, input, options)
+            }
+            RuleKind::BitwiseAndExpression => {
+                Self::bitwise_and_expression.parse(self, input, options)
+            }
+            RuleKind::BitwiseOrExpression => {
+                Self::bitwise_or_expression.parse(self, input, options)
+            }
+            RuleKind::BitwiseXorExpression => {
+                Self::bitwise_xor_expression.parse(self, input, options)
+            }
+            RuleKind::Block => Self::block.parse(self, input, options),
+            RuleKind::BreakStatement => Self::break_statement.parse(self, input, options),
+            RuleKind::CallOptions => Self::call_options.parse(self, input, options),
+            RuleKind::CallOptionsExpression => {
+                Self::call_options_expression.parse(self, input, options)
+            }
+            RuleKind::CatchClause => Self::catch_clause.parse(self, input, options),
+            RuleKind::CatchClauseError => Self::catch_clause_error.parse(self, input, options),
+            RuleKind::CatchClauses => Self::catch_clauses.parse(self, input, options),
+            RuleKind::ComparisonExpression => {
+                Self::comparison_expression.parse(self, input, options)
+            }
+            RuleKind::ConditionalExpression => {
+                Self::conditional_expression.parse(self, input, options)
+            }
+            RuleKind::ConstantDefinition => Self::constant_definition.parse(self, input, options),
+            RuleKind::ConstructorAttribute => {
+                Self::constructor_attribute.parse(self, input, options)
+            }
+            RuleKind::ConstructorAttributes => {
+                Self::constructor_attributes.parse(self, input, options)
+            }
+            RuleKind::ConstructorDefinition => {
+                Self::constructor_definition.parse(self, input, options)
+            }
+            RuleKind::ContinueStatement => Self::continue_statement.parse(self, input, options),
+            RuleKind::ContractDefinition => Self::contract_definition.parse(self, input, options),
+            RuleKind::ContractMember => Self::contract_member.parse(self, input, options),
+            RuleKind::ContractMembers => Self::contract_members.parse(self, input, options),
+            RuleKind::DecimalNumberExpression => {
+                Self::decimal_number_expression.parse(self, input, options)
+            }
+            RuleKind::DoWhileStatement => Self::do_while_statement.parse(self, input, options),
+            RuleKind::ElementaryType => Self::elementary_type.parse(self, input, options),
+            RuleKind::ElseBranch => Self::else_branch.parse(self, input, options),
+            RuleKind::EmitStatement => Self::emit_statement.parse(self, input, options),
+            RuleKind::EnumDefinition => Self::enum_definition.parse(self, input, options),
+            RuleKind::EnumMembers => Self::enum_members.parse(self, input, options),
+            RuleKind::EqualityExpression => Self::equality_expression.parse(self, input, options),
+            RuleKind::ErrorDefinition => Self::error_definition.parse(self, input, options),
+            RuleKind::ErrorParameter => Self::error_parameter.parse(self, input, options),
+            RuleKind::ErrorParameters => Self::error_parameters.parse(self, input, options),
             RuleKind::ErrorParametersDeclaration => {
-                Self::error_parameters_declaration.parse(self, input)
+                Self::error_parameters_declaration.parse(self, input, options)
             }
-            RuleKind::EventDefinition => Self::event_definition.parse(self, input),
-            RuleKind::EventParameter => Self::event_parameter.parse(self, input),
-            RuleKind::EventParameters => Self::event_parameters.parse(self, input),
+            RuleKind::EventDefinition => Self::event_definition.parse(self, input, options),
+            RuleKind::EventParameter => Self::event_parameter.parse(self, input, options),
+            RuleKind::EventParameters => Self::event_parameters.parse(self, input, options),
             RuleKind::EventParametersDeclaration => {
-                Self::event_parameters_declaration.parse(self, input)
+                Self::event_parameters_declaration.parse(self, input, options)
             }
-            RuleKind::ExperimentalFeature => Self::experimental_feature.parse(self, input),
-            RuleKind::ExperimentalPragma => Self::experimental_pragma.parse(self, input),
+            RuleKind::ExperimentalFeature => Self::experimental_feature.parse(self, input, options),
+            RuleKind::ExperimentalPragma => Self::experimental_pragma.parse(self, input, options),
             RuleKind::ExponentiationExpression => {
-                Self::exponentiation_expression.parse(self, input)
+                Self::exponentiation_expression.parse(self, input, options)
             }
-            RuleKind::Expression => Self::expression.parse(self, input),
-            RuleKind::ExpressionStatement => Self::expression_statement.parse(self, input),
+            RuleKind::Expression => Self::expression.parse(self, input, options),
+            RuleKind::ExpressionStatement => Self::expression_statement.parse(self, input, options),
             RuleKind::FallbackFunctionAttribute => {
-                Self::fallback_function_attribute.parse(self, input)
+                Self::fallback_function_attribute.parse(self, input, options)
             }
             RuleKind::FallbackFunctionAttributes => {
-                Self::fallback_function_attributes.parse(self, input)
+                Self::fallback_function_attributes.parse(self, input, options)
             }
             RuleKind::FallbackFunctionDefinition => {
-                Self::fallback_function_definition.parse(self, input)
+                Self::fallback_function_definition.parse(self, input, options)
+            }
+            RuleKind::ForStatement => Self::for_statement.parse(self, input, options),
+            RuleKind::ForStatementCondition => {
+                Self::for_statement_condition.parse(self, input, options)
             }
-            RuleKind::ForStatement => Self::for_statement.parse(self, input),
-            RuleKind::ForStatementCondition => Self::for_statement_condition.parse(self, input),
             RuleKind::ForStatementInitialization => {
-                Self::for_statement_initialization.parse(self, input)
-            }
-            RuleKind::FunctionAttribute => Self::function_attribute.parse(self, input),
-            RuleKind::FunctionAttributes => Self::function_attributes.parse(self, input),
-            RuleKind::FunctionBody => Self::function_body.parse(self, input),
-            RuleKind::FunctionCallExpression => Self::function_call_expression.parse(self, input),
-            RuleKind::FunctionDefinition => Self::function_definition.parse(self, input),
-            RuleKind::FunctionName => Self::function_name.parse(self, input),
-            RuleKind::FunctionType => Self::function_type.parse(self, input),
-            RuleKind::FunctionTypeAttribute => Self::function_type_attribute.parse(self, input),
-            RuleKind::FunctionTypeAttributes => Self::function_type_attributes.parse(self, input),
-            RuleKind::HexNumberExpression => Self::hex_number_expression.parse(self, input),
-            RuleKind::HexStringLiteral => Self::hex_string_literal.parse(self, input),
-            RuleKind::HexStringLiterals => Self::hex_string_literals.parse(self, input),
-            RuleKind::IdentifierPath => Self::identifier_path.parse(self, input),
-            RuleKind::IfStatement => Self::if_statement.parse(self, input),
-            RuleKind::ImportAlias => Self::import_alias.parse(self, input),
-            RuleKind::ImportClause => Self::import_clause.parse(self, input),
-            RuleKind::ImportDeconstruction => Self::import_deconstruction.parse(self, input),
+                Self::for_statement_initialization.parse(self, input, options)
+            }
+            RuleKind::FunctionAttribute => Self::function_attribute.parse(self, input, options),
+            RuleKind::FunctionAttributes => Self::function_attributes.parse(self, input, options),
+            RuleKind::FunctionBody => Self::function_body.parse(self, input, options),
+            RuleKind::FunctionCallExpression => {
+                Self::function_call_expression.parse(self, input, options)
+            }
+            RuleKind::FunctionDefinition => Self::function_definition.parse(self, input, options),
+            RuleKind::FunctionName => Self::function_name.parse(self, input, options),
+            RuleKind::FunctionType => Self::function_type.parse(self, input, options),
+            RuleKind::FunctionTypeAttribute => {
+                Self::function_type_attribute.parse(self, input, options)
+            }
+            RuleKind::FunctionTypeAttributes => {
+                Self::function_type_attributes.parse(self, input, options)
+            }
+            RuleKind::HexNumberExpression => {
+                Self::hex_number_expression.parse(self, input, options)
+            }
+            RuleKind::HexStringLiteral => Self::hex_string_literal.parse(self, input, options),
+            RuleKind::HexStringLiterals => Self::hex_string_literals.parse(self, input, options),
+            RuleKind::IdentifierPath => Self::identifier_path.parse(self, input, options),
+            RuleKind::IfStatement => Self::if_statement.parse(self, input, options),
+            RuleKind::ImportAlias => Self::import_alias.parse(self, input, options),
+            RuleKind::ImportClause => Self::import_clause.parse(self, input, options),
+            RuleKind::ImportDeconstruction => {
+                Self::import_deconstruction.parse(self, input, options)
+            }
             RuleKind::ImportDeconstructionSymbol => {
-                Self::import_deconstruction_symbol.parse(self, input)
+                Self::import_deconstruction_symbol.parse(self, input, options)
             }
             RuleKind::ImportDeconstructionSymbols => {
-                Self::import_deconstruction_symbols.parse(self, input)
-            }
-            RuleKind::ImportDirective => Self::import_directive.parse(self, input),
-            RuleKind::IndexAccessEnd => Self::index_access_end.parse(self, input),
-            RuleKind::IndexAccessExpression => Self::index_access_expression.parse(self, input),
-            RuleKind::InheritanceSpecifier => Self::inheritance_specifier.parse(self, input),
-            RuleKind::InheritanceType => Self::inheritance_type.parse(self, input),
-            RuleKind::InheritanceTypes => Self::inheritance_types.parse(self, input),
-            RuleKind::InterfaceDefinition => Self::interface_definition.parse(self, input),
-            RuleKind::InterfaceMembers => Self::interface_members.parse(self, input),
-            RuleKind::LibraryDefinition => Self::library_definition.parse(self, input),
-            RuleKind::LibraryMembers => Self::library_members.parse(self, input),
-            RuleKind::MappingKey => Self::mapping_key.parse(self, input),
-            RuleKind::MappingKeyType => Self::mapping_key_type.parse(self, input),
-            RuleKind::MappingType => Self::mapping_type.parse(self, input),
-            RuleKind::MappingValue => Self::mapping_value.parse(self, input),
-            RuleKind::MemberAccess => Self::member_access.parse(self, input),
-            RuleKind::MemberAccessExpression => Self::member_access_expression.parse(self, input),
-            RuleKind::ModifierAttribute => Self::modifier_attribute.parse(self, input),
-            RuleKind::ModifierAttributes => Self::modifier_attributes.parse(self, input),
-            RuleKind::ModifierDefinition => Self::modifier_definition.parse(self, input),
-            RuleKind::ModifierInvocation => Self::modifier_invocation.parse(self, input),
+                Self::import_deconstruction_symbols.parse(self, input, options)
+            }
+            RuleKind::ImportDirective => Self::import_directive.parse(self, input, options),
+            RuleKind::IndexAccessEnd => Self::index_access_end.parse(self, input, options),
+            RuleKind::IndexAccessExpression => {
+                Self::index_access_expression.parse(self, input, options)
+            }
+            RuleKind::InheritanceSpecifier => {
+                Self::inheritance_specifier.parse(self, input, options)
+            }
+            RuleKind::InheritanceType => Self::inheritance_type.parse(self, input, options),
+            RuleKind::InheritanceTypes => Self::inheritance_types.parse(self, input, options),
+            RuleKind::InterfaceDefinition => Self::interface_definition.parse(self, input, options),
+            RuleKind::InterfaceMembers => Self::interface_members.parse(self, input, options),
+            RuleKind::LibraryDefinition => Self::library_definition.parse(self, input, options),
+            RuleKind::LibraryMembers => Self::library_members.parse(self, input, options),
+            RuleKind::MappingKey => Self::mapping_key.parse(self, input, options),
+            RuleKind::MappingKeyType => Self::mapping_key_type.parse(self, input, options),
+            RuleKind::MappingType => Self::mapping_type.parse(self, input, options),
+            RuleKind::MappingValue => Self::mapping_value.parse(self, input, options),
+            RuleKind::MemberAccess => Self::member_access.parse(self, input, options),
+            RuleKind::MemberAccessExpression => {
+                Self::member_access_expression.parse(self, input, options)
+            }
+            RuleKind::ModifierAttribute => Self::modifier_attribute.parse(self, input, options),
+            RuleKind::ModifierAttributes => Self::modifier_attributes.parse(self, input, options),
+            RuleKind::ModifierDefinition => Self::modifier_definition.parse(self, input, options),
+            RuleKind::ModifierInvocation => Self::modifier_invocation.parse(self, input, options),
             RuleKind::MultiplicativeExpression => {
-                Self::multiplicative_expression.parse(self, input)
+                Self::multiplicative_expression.parse(self, input, options)
             }
-            RuleKind::NamedArgument => Self::named_argument.parse(self, input),
-            RuleKind::NamedArgumentGroup => Self::named_argument_group.parse(self, input),
-            RuleKind::NamedArguments => Self::named_arguments.parse(self, input),
+            RuleKind::NamedArgument => Self::named_argument.parse(self, input, options),
+            RuleKind::NamedArgumentGroup => Self::named_argument_group.parse(self, input, options),
+            RuleKind::NamedArguments => Self::named_arguments.parse(self, input, options),
             RuleKind::NamedArgumentsDeclaration => {
-                Self::named_arguments_declaration.parse(self, input)
+                Self::named_arguments_declaration.parse(self, input, options)
             }
-            RuleKind::NamedImport => Self::named_import.parse(self, input),
-            RuleKind::NewExpression => Self::new_expression.parse(self, input),
-            RuleKind::NumberUnit => Self::number_unit.parse(self, input),
-            RuleKind::OrExpression => Self::or_expression.parse(self, input),
-            RuleKind::OverridePaths => Self::override_paths.parse(self, input),
+            RuleKind::NamedImport => Self::named_import.parse(self, input, options),
+            RuleKind::NewExpression => Self::new_expression.parse(self, input, options),
+            RuleKind::NumberUnit => Self::number_unit.parse(self, input, options),
+            RuleKind::OrExpression => Self::or_expression.parse(self, input, options),
+            RuleKind::OverridePaths => Self::override_paths.parse(self, input, options),
             RuleKind::OverridePathsDeclaration => {
-                Self::override_paths_declaration.parse(self, input)
-            }
-            RuleKind::OverrideSpecifier => Self::override_specifier.parse(self, input),
-            RuleKind::Parameter => Self::parameter.parse(self, input),
-            RuleKind::Parameters => Self::parameters.parse(self, input),
-            RuleKind::ParametersDeclaration => Self::parameters_declaration.parse(self, input),
-            RuleKind::PathImport => Self::path_import.parse(self, input),
-            RuleKind::PositionalArguments => Self::positional_arguments.parse(self, input),
+                Self::override_paths_declaration.parse(self, input, options)
+            }
+            RuleKind::OverrideSpecifier => Self::override_specifier.parse(self, input, options),
+            RuleKind::Parameter => Self::parameter.parse(self, input, options),
+            RuleKind::Parameters => Self::parameters.parse(self, input, options),
+            RuleKind::ParametersDeclaration => {
+                Self::parameters_declaration.parse(self, input, options)
+            }
+            RuleKind::PathImport => Self::path_import.parse(self, input, options),
+            RuleKind::PositionalArguments => Self::positional_arguments.parse(self, input, options),
             RuleKind::PositionalArgumentsDeclaration => {
-                Self::positional_arguments_declaration.parse(self, input)
+                Self::positional_arguments_declaration.parse(self, input, options)
             }
-            RuleKind::PostfixExpression => Self::postfix_expression.parse(self, input),
-            RuleKind::Pragma => Self::pragma.parse(self, input),
-            RuleKind::PragmaDirective => Self::pragma_directive.parse(self, input),
-            RuleKind::PrefixExpression => Self::prefix_expression.parse(self, input),
+            RuleKind::PostfixExpression => Self::postfix_expression.parse(self, input, options),
+            RuleKind::Pragma => Self::pragma.parse(self, input, options),
+            RuleKind::PragmaDirective => Self::pragma_directive.parse(self, input, options),
+            RuleKind::PrefixExpression => Self::prefix_expression.parse(self, input, options),
             RuleKind::ReceiveFunctionAttribute => {
-                Self::receive_function_attribute.parse(self, input)
+                Self::receive_function_attribute.parse(self, input, options)
             }
             RuleKind::ReceiveFunctionAttributes => {
-                Self::receive_function_attributes.parse(self, input)
+                Self::receive_function_attributes.parse(self, input, options)
             }
             RuleKind::ReceiveFunctionDefinition => {
-                Self::receive_function_definition.parse(self, input)
-            }
-            RuleKind::ReturnStatement => Self::return_statement.parse(self, input),
-            RuleKind::ReturnsDeclaration => Self::returns_declaration.parse(self, input),
-            RuleKind::RevertStatement => Self::revert_statement.parse(self, input),
-            RuleKind::ShiftExpression => Self::shift_expression.parse(self, input),
-            RuleKind::SourceUnit => Self::source_unit.parse(self, input),
-            RuleKind::SourceUnitMember => Self::source_unit_member.parse(self, input),
-            RuleKind::SourceUnitMembers => Self::source_unit_members.parse(self, input),
-            RuleKind::StateVariableAttribute => Self::state_variable_attribute.parse(self, input),
-            RuleKind::StateVariableAttributes => Self::state_variable_attributes.parse(self, input),
-            RuleKind::StateVariableDefinition => Self::state_variable_definition.parse(self, input),
+                Self::receive_function_definition.parse(self, input, options)
+            }
+            RuleKind::ReturnStatement => Self::return_statement.parse(self, input, options),
+            RuleKind::ReturnsDeclaration => Self::returns_declaration.parse(self, input, options),
+            RuleKind::RevertStatement => Self::revert_statement.parse(self, input, options),
+            RuleKind::ShiftExpression => Self::shift_expression.parse(self, input, options),
+            RuleKind::SourceUnit => Self::source_unit.parse(self, input, options),
+            RuleKind::SourceUnitMember => Self::source_unit_member.parse(self, input, options),
+            RuleKind::SourceUnitMembers => Self::source_unit_members.parse(self, input, options),
+            RuleKind::StateVariableAttribute => {
+                Self::state_variable_attribute.parse(self, input, options)
+            }
+            RuleKind::StateVariableAttributes => {
+                Self::state_variable_attributes.parse(self, input, options)
+            }
+            RuleKind::StateVariableDefinition => {
+                Self::state_variable_definition.parse(self, input, options)
+            }
             RuleKind::StateVariableDefinitionValue => {
-                Self::state_variable_definition_value.parse(self, input)
-            }
-            RuleKind::Statement => Self::statement.parse(self, input),
-            RuleKind::Statements => Self::statements.parse(self, input),
-            RuleKind::StorageLocation => Self::storage_location.parse(self, input),
-            RuleKind::StringExpression => Self::string_expression.parse(self, input),
-            RuleKind::StringLiteral => Self::string_literal.parse(self, input),
-            RuleKind::StringLiterals => Self::string_literals.parse(self, input),
-            RuleKind::StructDefinition => Self::struct_definition.parse(self, input),
-            RuleKind::StructMember => Self::struct_member.parse(self, input),
-            RuleKind::StructMembers => Self::struct_members.parse(self, input),
-            RuleKind::ThrowStatement => Self::throw_statement.parse(self, input),
-            RuleKind::TryStatement => Self::try_statement.parse(self, input),
+                Self::state_variable_definition_value.parse(self, input, options)
+            }
+            RuleKind::Statement => Self::statement.parse(self, input, options),
+            RuleKind::Statements => Self::statements.parse(self, input, options),
+            RuleKind::StorageLocation => Self::storage_location.parse(self, input, options),
+            RuleKind::StringExpression => Self::string_expression.parse(self, input, options),
+            RuleKind::StringLiteral => Self::string_literal.parse(self, input, options),
+            RuleKind::StringLiterals => Self::string_literals.parse(self, input, options),
+            RuleKind::StructDefinition => Self::struct_definition.parse(self, input, options),
+            RuleKind::StructMember => Self::struct_member.parse(self, input, options),
+            RuleKind::StructMembers => Self::struct_members.parse(self, input, options),
+            RuleKind::ThrowStatement => Self::throw_statement.parse(self, input, options),
+            RuleKind::TryStatement => Self::try_statement.parse(self, input, options),
             RuleKind::TupleDeconstructionElement => {
-                Self::tuple_deconstruction_element.parse(self, input)
+                Self::tuple_deconstruction_element.parse(self, input, options)
             }
             RuleKind::TupleDeconstructionElements => {
-                Self::tuple_deconstruction_elements.parse(self, input)
+                Self::tuple_deconstruction_elements.parse(self, input, options)
             }
             RuleKind::TupleDeconstructionStatement => {
-                Self::tuple_deconstruction_statement.parse(self, input)
-            }
-            RuleKind::TupleExpression => Self::tuple_expression.parse(self, input),
-            RuleKind::TupleMember => Self::tuple_member.parse(self, input),
-            RuleKind::TupleValue => Self::tuple_value.parse(self, input),
-            RuleKind::TupleValues => Self::tuple_values.parse(self, input),
-            RuleKind::TypeExpression => Self::type_expression.parse(self, input),
-            RuleKind::TypeName => Self::type_name.parse(self, input),
-            RuleKind::TypedTupleMember => Self::typed_tuple_member.parse(self, input),
-            RuleKind::UncheckedBlock => Self::unchecked_block.parse(self, input),
-            RuleKind::UnicodeStringLiteral => Self::unicode_string_literal.parse(self, input),
-            RuleKind::UnicodeStringLiterals => Self::unicode_string_literals.parse(self, input),
+                Self::tuple_deconstruction_statement.parse(self, input, options)
+            }
+            RuleKind::TupleExpression => Self::tuple_expression.parse(self, input, options),
+            RuleKind::TupleMember => Self::tuple_member.parse(self, input, options),
+            RuleKind::TupleValue => Self::tuple_value.parse(self, input, options),
+            RuleKind::TupleValues => Self::tuple_values.parse(self, input, options),
+            RuleKind::TypeExpression => Self::type_expression.parse(self, input, options),
+            RuleKind::TypeName => Self::type_name.parse(self, input, options),
+            RuleKind::TypedTupleMember => Self::typed_tuple_member.parse(self, input, options),
+            RuleKind::UncheckedBlock => Self::unchecked_block.parse(self, input, options),
+            RuleKind::UnicodeStringLiteral => {
+                Self::unicode_string_literal.parse(self, input, options)
+            }
+            RuleKind::UnicodeStringLiterals => {
+                Self::unicode_string_literals.parse(self, input, options)
+            }
             RuleKind::UnnamedFunctionAttribute => {
-                Self::unnamed_function_attribute.parse(self, input)
+                Self::unnamed_function_attribute.parse(self, input, options)
             }
             RuleKind::UnnamedFunctionAttributes => {
-                Self::unnamed_function_attributes.parse(self, input)
+                Self::unnamed_function_attributes.parse(self, input, options)
             }
             RuleKind::UnnamedFunctionDefinition => {
-                Self::unnamed_function_definition.parse(self, input)
+                Self::unnamed_function_definition.parse(self, input, options)
             }
-            RuleKind::UntypedTupleMember => Self::untyped_tuple_member.parse(self, input),
+            RuleKind::UntypedTupleMember => Self::untyped_tuple_member.parse(self, input, options),
             RuleKind::UserDefinedValueTypeDefinition => {
-                Self::user_defined_value_type_definition.parse(self, input)
+                Self::user_defined_value_type_definition.parse(self, input, options)
             }
-            RuleKind::UsingAlias => Self::using_alias.parse(self, input),
-            RuleKind::UsingClause => Self::using_clause.parse(self, input),
-            RuleKind::UsingDeconstruction => Self::using_deconstruction.parse(self, input),
+            RuleKind::UsingAlias => Self::using_alias.parse(self, input, options),
+            RuleKind::UsingClause => Self::using_clause.parse(self, input, options),
+            RuleKind::UsingDeconstruction => Self::using_deconstruction.parse(self, input, options),
             RuleKind::UsingDeconstructionSymbol => {
-                Self::using_deconstruction_symbol.parse(self, input)
+                Self::using_deconstruction_symbol.parse(self, input, options)
             }
             RuleKind::UsingDeconstructionSymbols => {
-                Self::using_deconstruction_symbols.parse(self, input)
+                Self::using_deconstruction_symbols.parse(self, input, options)
             }
-            RuleKind::UsingDirective => Self::using_directive.parse(self, input),
-            RuleKind::UsingOperator => Self::using_operator.parse(self, input),
-            RuleKind::UsingTarget => Self::using_target.parse(self, input),
+            RuleKind::UsingDirective => Self::using_directive.parse(self, input, options),
+            RuleKind::UsingOperator => Self::using_operator.parse(self, input, options),
+            RuleKind::UsingTarget => Self::using_target.parse(self, input, options),
             RuleKind::VariableDeclarationStatement => {
-                Self::variable_declaration_statement.parse(self, input)
+                Self::variable_declaration_statement.parse(self, input, options)
+            }
+            RuleKind::VariableDeclarationType => {
+                Self::variable_declaration_type.parse(self, input, options)
             }
-            RuleKind::VariableDeclarationType => Self::variable_declaration_type.parse(self, input),
             RuleKind::VariableDeclarationValue => {
-                Self::variable_declaration_value.parse(self, input)
-            }
-            RuleKind::VersionComparator => Self::version_comparator.parse(self, input),
-            RuleKind::VersionExpression => Self::version_expression.parse(self, input),
-            RuleKind::VersionExpressionSet => Self::version_expression_set.parse(self, input),
-            RuleKind::VersionExpressionSets => Self::version_expression_sets.parse(self, input),
-            RuleKind::VersionPragma => Self::version_pragma.parse(self, input),
-            RuleKind::VersionRange => Self::version_range.parse(self, input),
-            RuleKind::VersionSpecifiers => Self::version_specifiers.parse(self, input),
-            RuleKind::WhileStatement => Self::while_statement.parse(self, input),
-            RuleKind::YulArguments => Self::yul_arguments.parse(self, input),
-            RuleKind::YulAssignmentOperator => Self::yul_assignment_operator.parse(self, input),
-            RuleKind::YulAssignmentStatement => Self::yul_assignment_statement.parse(self, input),
-            RuleKind::YulBlock => Self::yul_block.parse(self, input),
-            RuleKind::YulBreakStatement => Self::yul_break_statement.parse(self, input),
-            RuleKind::YulBuiltInFunction => Self::yul_built_in_function.parse(self, input),
-            RuleKind::YulColonAndEqual => Self::yul_colon_and_equal.parse(self, input),
-            RuleKind::YulContinueStatement => Self::yul_continue_statement.parse(self, input),
-            RuleKind::YulDefaultCase => Self::yul_default_case.parse(self, input),
-            RuleKind::YulExpression => Self::yul_expression.parse(self, input),
-            RuleKind::YulForStatement => Self::yul_for_statement.parse(self, input),
+                Self::variable_declaration_value.parse(self, input, options)
+            }
+            RuleKind::VersionComparator => Self::version_comparator.parse(self, input, options),
+            RuleKind::VersionExpression => Self::version_expression.parse(self, input, options),
+            RuleKind::VersionExpressionSet => {
+                Self::version_expression_set.parse(self, input, options)
+            }
+            RuleKind::VersionExpressionSets => {
+                Self::version_expression_sets.parse(self, input, options)
+            }
+            RuleKind::VersionPragma => Self::version_pragma.parse(self, input, options),
+            RuleKind::VersionRange => Self::version_range.parse(self, input, options),
+            RuleKind::VersionSpecifiers => Self::version_specifiers.parse(self, input, options),
+            RuleKind::WhileStatement => Self::while_statement.parse(self, input, options),
+            RuleKind::YulArguments => Self::yul_arguments.parse(self, input, options),
+            RuleKind::YulAssignmentOperator => {
+                Self::yul_assignment_operator.parse(self, input, options)
+            }
+            RuleKind::YulAssignmentStatement => {
+                Self::yul_assignment_statement.parse(self, input, options)
+            }
+            RuleKind::YulBlock => Self::yul_block.parse(self, input, options),
+            RuleKind::YulBreakStatement => Self::yul_break_statement.parse(self, input, options),
+            RuleKind::YulBuiltInFunction => Self::yul_built_in_function.parse(self, input, options),
+            RuleKind::YulColonAndEqual => Self::yul_colon_and_equal.parse(self, input, options),
+            RuleKind::YulContinueStatement => {
+                Self::yul_continue_statement.parse(self, input, options)
+            }
+            RuleKind::YulDefaultCase => Self::yul_default_case.parse(self, input, options),
+            RuleKind::YulExpression => Self::yul_expression.parse(self, input, options),
+            RuleKind::YulForStatement => Self::yul_for_statement.parse(self, input, options),
             RuleKind::YulFunctionCallExpression => {
-                Self::yul_function_call_expression.parse(self, input)
-            }
-            RuleKind::YulFunctionDefinition => Self::yul_function_definition.parse(self, input),
-            RuleKind::YulIfStatement => Self::yul_if_statement.parse(self, input),
-            RuleKind::YulLabel => Self::yul_label.parse(self, input),
-            RuleKind::YulLeaveStatement => Self::yul_leave_statement.parse(self, input),
-            RuleKind::YulLiteral => Self::yul_literal.parse(self, input),
-            RuleKind::YulParameters => Self::yul_parameters.parse(self, input),
+                Self::yul_function_call_expression.parse(self, input, options)
+            }
+            RuleKind::YulFunctionDefinition => {
+                Self::yul_function_definition.parse(self, input, options)
+            }
+            RuleKind::YulIfStatement => Self::yul_if_statement.parse(self, input, options),
+            RuleKind::YulLabel => Self::yul_label.parse(self, input, options),
+            RuleKind::YulLeaveStatement => Self::yul_leave_statement.parse(self, input, options),
+            RuleKind::YulLiteral => Self::yul_literal.parse(self, input, options),
+            RuleKind::YulParameters => Self::yul_parameters.parse(self, input, options),
             RuleKind::YulParametersDeclaration => {
-                Self::yul_parameters_declaration.parse(self, input)
-            }
-            RuleKind::YulPath => Self::yul_path.parse(self, input),
-            RuleKind::YulPathComponent => Self::yul_path_component.parse(self, input),
-            RuleKind::YulPaths => Self::yul_paths.parse(self, input),
-            RuleKind::YulReturnVariables => Self::yul_return_variables.parse(self, input),
-            RuleKind::YulReturnsDeclaration => Self::yul_returns_declaration.parse(self, input),
-            RuleKind::YulStatement => Self::yul_statement.parse(self, input),
-            RuleKind::YulStatements => Self::yul_statements.parse(self, input),
-            RuleKind::YulSwitchCase => Self::yul_switch_case.parse(self, input),
-            RuleKind::YulSwitchCases => Self::yul_switch_cases.parse(self, input),
-            RuleKind::YulSwitchStatement => Self::yul_switch_statement.parse(self, input),
-            RuleKind::YulValueCase => Self::yul_value_case.parse(self, input),
+                Self::yul_parameters_declaration.parse(self, input, options)
+            }
+            RuleKind::YulPath => Self::yul_path.parse(self, input, options),
+            RuleKind::YulPathComponent => Self::yul_path_component.parse(self, input, options),
+            RuleKind::YulPaths => Self::yul_paths.parse(self, input, options),
+            RuleKind::YulReturnVariables => Self::yul_return_variables.parse(self, input, options),
+            RuleKind::YulReturnsDeclaration => {
+                Self::yul_returns_declaration.parse(self, input, options)
+            }
+            RuleKind::YulStatement => Self::yul_statement.parse(self, input, options),
+            RuleKind::YulStatements => Self::yul_statements.parse(self, input, options),
+            RuleKind::YulSwitchCase => Self::yul_switch_case.parse(self, input, options),
+            RuleKind::YulSwitchCases => Self::yul_switch_cases.parse(self, input, options),
+            RuleKind::YulSwitchStatement => Self::yul_switch_statement.parse(self, input, options),
+            RuleKind::YulValueCase => Self::yul_value_case.parse(self, input, options),
             RuleKind::YulVariableDeclarationStatement => {
-                Self::yul_variable_declaration_statement.parse(self, input)
+                Self::yul_variable_declaration_statement.parse(self, input, options)
             }
             RuleKind::YulVariableDeclarationValue => {
-                Self::yul_variable_declaration_value.parse(self, input)
+                Self::yul_variable_declaration_value.parse(self, input, options)
             }
+        };
+
+        compatibility::add_version_notes(self, &mut output);
+        output
+    }
+
+    /// Parses the `input` after applying the `edit` to the source of the `previous` parse output,
+    /// re-parsing only the innermost delimited group (e.g. a block) containing the edit, and sharing the rest of the tree.
+    ///
+    /// The result is identical to calling [`Language::parse`] with the same `kind` and `input`.
+    /// If the `previous` output is not valid, or was not produced by parsing the same `kind`, the entire input is parsed again.
+    pub fn reparse(
+        &self,
+        kind: RuleKind,
+        previous: &ParseOutput,
+        edit: &TextEdit,
+        input: &str,
+    ) -> ParseOutput {
+        const DELIMITERS: &[(TokenKind, TokenKind)] = &[
+            (TokenKind::OpenBrace, TokenKind::CloseBrace),
+            (TokenKind::OpenBracket, TokenKind::CloseBracket),
+            (TokenKind::OpenParen, TokenKind::CloseParen),
+            (TokenKind::OpenBrace, TokenKind::CloseBrace),
+            (TokenKind::OpenParen, TokenKind::CloseParen),
+        ];
+
+        reparse(kind, previous, edit, input, DELIMITERS, |kind, input| {
+            self.parse(kind, input)
+        })
+    }
+
+    /// Scans the `input` into tokens of the given lexical context, including trivia, without parsing it.
+    pub fn tokenize<'a>(&'a self, input: &'a str, context: LexicalContext) -> Tokenizer<'a> {
+        Tokenizer::new(self, input, context)
+    }
+
+    pub(crate) fn next_token_in_context(
+        &self,
+        context: LexicalContext,
+        input: &mut ParserContext<'_>,
+    ) -> Option<ScannedToken> {
+        match context {
+            LexicalContext::Default => self.next_token::<LexicalContextType::Default>(input),
+            LexicalContext::Pragma => self.next_token::<LexicalContextType::Pragma>(input),
+            LexicalContext::Yul => self.next_token::<LexicalContextType::Yul>(input),
         }
     }
 }
@@ -9425,6 +13063,10 @@ impl Lexer for Language {
         &self,
         input: &mut ParserContext<'_>,
     ) -> Option<ScannedToken> {
+        if !input.scan_token() {
+            return None;
+        }
+
         let save = input.position();
         let mut furthest_position = input.position();
         let mut longest_token = None;
@@ -2,11 +2,10 @@
 
 #![allow(clippy::too_many_lines)]
 
-use std::rc::Rc;
-
 use napi::Either;
 use napi_derive::napi;
 
+use crate::cst::Rc;
 use crate::napi_interface::cst::{RuleNode, TokenNode};
 use crate::napi_interface::{RuleKind, RustLabeledNode, RustNode, RustRuleNode, TokenKind};
 
@@ -3199,6 +3198,12 @@ impl Selector {
                 } if matches!(token.kind, TokenKind::SKIPPED) => {
                     return Error::SkippedToken(self.index).into();
                 }
+                RustLabeledNode {
+                    label: _,
+                    node: RustNode::Token(token),
+                } if matches!(token.kind, TokenKind::MISSING) => {
+                    return Error::MissingToken(self.index).into();
+                }
                 labeled if filter(labeled) => {
                     self.index += 1;
                     return Ok(Some(labeled.node.clone().into_js_either_node()));
@@ -3240,6 +3245,10 @@ enum Error {
     // Can happen if the user decided to use an incorrect/incomplete CST node.
     #[error("Unexpected SKIPPED token at index '{0}'. Creating AST types from incorrect/incomplete CST nodes is not supported yet.")]
     SkippedToken(usize),
+
+    // Can happen if the user decided to use an incorrect/incomplete CST node.
+    #[error("Unexpected MISSING token at index '{0}'. Creating AST types from incorrect/incomplete CST nodes is not supported yet.")]
+    MissingToken(usize),
 }
 
 impl<T> From<Error> for Result<T> {
@@ -50,4 +50,9 @@ impl Diagnostic {
     pub fn code(&self) -> String {
         self.0.code().to_string()
     }
+
+    #[napi]
+    pub fn note(&self) -> Option<String> {
+        self.0.note()
+    }
 }
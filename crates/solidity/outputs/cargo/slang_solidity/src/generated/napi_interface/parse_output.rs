@@ -4,10 +4,10 @@ use napi::Either;
 use napi_derive::napi;
 
 use crate::napi_interface::cst::{RuleNode, TokenNode};
-use crate::napi_interface::{cursor, parse_error, RustParseOutput};
+use crate::napi_interface::{cursor, parse_error, text_index, RustParseOutput};
 
 #[napi(namespace = "parse_output")]
-pub struct ParseOutput(RustParseOutput);
+pub struct ParseOutput(pub(crate) RustParseOutput);
 
 impl From<RustParseOutput> for ParseOutput {
     fn from(value: RustParseOutput) -> Self {
@@ -37,4 +37,30 @@ impl ParseOutput {
     pub fn create_tree_cursor(&self) -> cursor::Cursor {
         self.0.create_tree_cursor().into()
     }
+
+    /// Creates a cursor that points to the deepest node (i.e. a token) containing the given offset,
+    /// or `null` if the offset is outside of the parse tree.
+    #[napi(ts_return_type = "cursor.Cursor | null", catch_unwind)]
+    pub fn node_at_offset(
+        &self,
+        #[napi(ts_arg_type = "text_index.TextIndex")] offset: text_index::TextIndex,
+    ) -> Option<cursor::Cursor> {
+        self.0.node_at_offset(offset.into()).map(Into::into)
+    }
+
+    /// Creates a cursor that points to the deepest node fully containing the given range,
+    /// or `null` if the range is outside of the parse tree.
+    #[napi(ts_return_type = "cursor.Cursor | null", catch_unwind)]
+    pub fn covering_node(
+        &self,
+        #[napi(ts_arg_type = "text_index.TextRange")] range: text_index::TextRange,
+    ) -> Option<cursor::Cursor> {
+        self.0.covering_node(&range.into()).map(Into::into)
+    }
+
+    /// Creates a line index of the parsed source, to convert text offsets to (line, column) positions and back.
+    #[napi(ts_return_type = "text_index.LineIndex", catch_unwind)]
+    pub fn create_line_index(&self) -> text_index::LineIndex {
+        self.0.create_line_index().into()
+    }
 }
@@ -1,11 +1,10 @@
 // This file is generated automatically by infrastructure scripts. Please don't edit by hand.
 
-use std::rc::Rc;
-
-use crate::cst::{self, LabeledNode};
+use crate::cst::{self, LabeledNode, Rc};
 use crate::kinds::TokenKind;
 use crate::lexer::Lexer;
-use crate::parse_error::ParseError;
+use crate::parse_error::{self, ParseError, ParseErrorKind};
+use crate::parse_options::ParseOptions;
 use crate::parse_output::ParseOutput;
 use crate::parser_support::context::ParserContext;
 use crate::parser_support::parser_result::{IncompleteMatch, Match, ParserResult, SkippedUntil};
@@ -15,7 +14,7 @@ pub trait ParserFunction<L>
 where
     Self: Fn(&L, &mut ParserContext<'_>) -> ParserResult,
 {
-    fn parse(&self, language: &L, input: &str) -> ParseOutput;
+    fn parse(&self, language: &L, input: &str, options: &ParseOptions) -> ParseOutput;
 }
 
 impl<L, F> ParserFunction<L> for F
@@ -23,10 +22,14 @@ where
     L: Lexer,
     F: Fn(&L, &mut ParserContext<'_>) -> ParserResult,
 {
-    fn parse(&self, language: &L, input: &str) -> ParseOutput {
-        let mut stream = ParserContext::new(input);
+    fn parse(&self, language: &L, input: &str, options: &ParseOptions) -> ParseOutput {
+        let mut stream = ParserContext::with_options(input, options.clone());
         let mut result = self(language, &mut stream);
 
+        if let Some((kind, position)) = stream.limit_exceeded() {
+            return limit_exceeded(kind, position, input);
+        }
+
         // For a succesful/recovered parse, collect any remaining trivia as part of the parse result
         if let ParserResult::Match(r#match) = &mut result {
             let [topmost] = r#match.nodes.as_mut_slice() else {
@@ -56,13 +59,18 @@ where
         match result {
             ParserResult::PrattOperatorMatch(..) => unreachable!("PrattOperatorMatch is internal"),
 
-            ParserResult::NoMatch(no_match) => ParseOutput {
-                parse_tree: cst::Node::token(TokenKind::SKIPPED, input.to_string()),
-                errors: vec![ParseError::new(
+            ParserResult::NoMatch(no_match) => {
+                let parse_tree = cst::Node::token(TokenKind::SKIPPED, input.to_string());
+                let errors = vec![ParseError::new(
                     TextIndex::ZERO..input.into(),
                     no_match.expected_tokens,
-                )],
-            },
+                )];
+
+                ParseOutput {
+                    errors: parse_error::contextualize(errors, &parse_tree, input),
+                    parse_tree,
+                }
+            }
             some_match => {
                 let (nodes, expected_tokens) = match some_match {
                     ParserResult::PrattOperatorMatch(..) | ParserResult::NoMatch(..) => {
@@ -111,25 +119,40 @@ where
                     let mut errors = stream.into_errors();
                     errors.push(ParseError::new(start..input.into(), expected_tokens));
 
+                    let parse_tree = cst::Node::rule(topmost_rule.kind, new_children);
+
                     ParseOutput {
-                        parse_tree: cst::Node::rule(topmost_rule.kind, new_children),
-                        errors,
+                        errors: parse_error::contextualize(errors, &parse_tree, input),
+                        parse_tree,
                     }
                 } else {
                     let parse_tree = cst::Node::Rule(topmost_rule);
                     let errors = stream.into_errors();
 
-                    // Sanity check: Make sure that succesful parse is equivalent to not having any SKIPPED nodes
+                    // Sanity check: Make sure that succesful parse is equivalent to not having any SKIPPED or MISSING nodes
                     debug_assert_eq!(
                         errors.is_empty(),
                         parse_tree
                             .cursor_with_offset(TextIndex::ZERO)
-                            .all(|node| node.as_token_with_kind(TokenKind::SKIPPED).is_none())
+                            .all(|node| !node
+                                .is_token_with_kinds(&[TokenKind::SKIPPED, TokenKind::MISSING]))
                     );
 
-                    ParseOutput { parse_tree, errors }
+                    ParseOutput {
+                        errors: parse_error::contextualize(errors, &parse_tree, input),
+                        parse_tree,
+                    }
                 }
             }
         }
     }
 }
+
+/// The tree of a parse stopped by a limit is incomplete, so the entire input is skipped instead, and the error
+/// starts where the limit was hit.
+fn limit_exceeded(kind: ParseErrorKind, position: TextIndex, input: &str) -> ParseOutput {
+    ParseOutput {
+        parse_tree: cst::Node::token(TokenKind::SKIPPED, input.to_string()),
+        errors: vec![ParseError::limit_exceeded(kind, position..input.into())],
+    }
+}
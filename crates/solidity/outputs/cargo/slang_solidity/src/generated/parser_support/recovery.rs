@@ -78,6 +78,29 @@ impl ParserResult {
 
         let leading_trivia = opt_parse(input, |input| lexer.leading_trivia(input));
 
+        // Instead of skipping the rest of the line (or beyond), assume that only the expected token is missing,
+        // if what follows it can still be parsed:
+        if matches!(result_kind, ParseResultKind::Match)
+            && next_token_follows_missing::<_, LexCtx>(
+                input,
+                lexer,
+                expected,
+                &nodes,
+                &leading_trivia,
+            )
+        {
+            input.set_position(before_recovery);
+
+            expected_tokens.push(expected);
+            input.emit(ParseError::new(
+                before_recovery..before_recovery,
+                expected_tokens.clone(),
+            ));
+            input.insert_missing(expected);
+
+            return ParserResult::r#match(nodes, expected_tokens);
+        }
+
         if let Some((found, skipped_range)) =
             skip_until_with_nested_delims::<_, LexCtx>(input, lexer, expected)
         {
@@ -88,10 +111,7 @@ impl ParserResult {
 
             let skipped = input.content(skipped_range.utf8());
 
-            input.emit(ParseError {
-                text_range: skipped_range,
-                tokens_that_would_have_allowed_more_progress: expected_tokens.clone(),
-            });
+            input.emit(ParseError::new(skipped_range, expected_tokens.clone()));
 
             ParserResult::SkippedUntil(SkippedUntil {
                 nodes,
@@ -114,6 +134,54 @@ impl ParserResult {
     }
 }
 
+/// Whether the next token can follow a missing token, because it closes a delimited group of an outer parse
+/// (e.g. `}` after a statement that is missing its `;`), or because it starts a new line after a match that is
+/// missing its terminator. Groups (e.g. blocks) often span multiple lines, so their closing delimiters are only
+/// inserted in the former case.
+fn next_token_follows_missing<L: Lexer, LexCtx: IsLexicalContext>(
+    input: &mut ParserContext<'_>,
+    lexer: &L,
+    expected: TokenKind,
+    nodes: &[cst::LabeledNode],
+    leading_trivia: &[cst::LabeledNode],
+) -> bool {
+    let Some(next) = lexer.peek_token::<LexCtx>(input) else {
+        // Nothing follows at the end of input:
+        return false;
+    };
+
+    if input.closing_delimiters().contains(&next.unambiguous()) {
+        return true;
+    }
+
+    let is_terminator = !L::delimiters::<LexCtx>()
+        .iter()
+        .any(|(_, close)| *close == expected);
+
+    is_terminator
+        && (ends_with_line_break(nodes)
+            || leading_trivia.iter().any(
+                |node| matches!(&node.node, cst::Node::Token(token) if token.text.contains('\n')),
+            ))
+}
+
+/// Whether the trivia after the last significant token of the nodes contains a line break.
+fn ends_with_line_break(nodes: &[cst::LabeledNode]) -> bool {
+    for node in nodes.iter().rev() {
+        match &node.node {
+            cst::Node::Token(token) if token.kind.is_trivia() => {
+                if token.text.contains('\n') {
+                    return true;
+                }
+            }
+            cst::Node::Token(_) => return false,
+            cst::Node::Rule(rule) => return ends_with_line_break(&rule.children),
+        }
+    }
+
+    false
+}
+
 /// Skips tokens until a given token is found or until we hit a closing delimiter that's expected by an outer parse.
 /// Respects nested delimiters, i.e. the `expected` token is only accepted if it's not nested inside.
 /// Does not consume the `expected` token.
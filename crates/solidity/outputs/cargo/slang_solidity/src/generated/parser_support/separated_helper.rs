@@ -59,11 +59,7 @@ impl SeparatedHelper {
                                 TokenKind::SKIPPED,
                                 input.content(skipped_range.utf8()),
                             )));
-                            input.emit(ParseError {
-                                text_range: skipped_range,
-                                tokens_that_would_have_allowed_more_progress: incomplete
-                                    .expected_tokens,
-                            });
+                            input.emit(ParseError::new(skipped_range, incomplete.expected_tokens));
 
                             match lexer.parse_token_with_trivia::<LexCtx>(input, separator) {
                                 ParserResult::Match(r#match) => {
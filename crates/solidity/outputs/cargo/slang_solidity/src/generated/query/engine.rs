@@ -1,14 +1,15 @@
 // This file is generated automatically by infrastructure scripts. Please don't edit by hand.
 
 use std::collections::HashMap;
-use std::rc::Rc;
 
 // This crate is copied to another crate, so all imports should be relative
-use super::super::cst;
+use super::super::cst::{self, Rc};
 use super::super::cursor::Cursor;
+use super::super::text_index::TextIndex;
 use super::model::{
-    AlternativesMatcher, BindingMatcher, Kind, Matcher, NodeMatcher, NodeSelector,
-    OneOrMoreMatcher, OptionalMatcher, Query, SequenceMatcher,
+    AlternativesMatcher, BindingMatcher, Kind, Matcher, NegationMatcher, NodeMatcher, NodeSelector,
+    OneOrMoreMatcher, OptionalMatcher, Predicate, PredicateOperator, PredicateValue, Query,
+    SequenceMatcher,
 };
 
 impl Cursor {
@@ -66,6 +67,7 @@ impl Matcher {
             Self::Sequence(matcher) => matcher.children[0].can_match(cursor),
             Self::OneOrMore(matcher) => matcher.child.can_match(cursor),
             Self::Optional(_) => true,
+            Self::Negation(_) => true,
             Self::Ellipsis => true,
         }
     }
@@ -80,11 +82,75 @@ impl Matcher {
             }
             Self::Optional(matcher) => Box::new(OptionalCombinator::new(matcher.clone(), cursor)),
             Self::OneOrMore(matcher) => Box::new(OneOrMoreCombinator::new(matcher.clone(), cursor)),
+            Self::Negation(matcher) => Box::new(NegationCombinator::new(matcher.clone(), cursor)),
             Self::Ellipsis => Box::new(EllipsisCombinator::new(cursor)),
         }
     }
 }
 
+impl Predicate {
+    /// Whether the predicate holds for every node captured by its binding.
+    /// It trivially holds if the binding (or the one it is compared to) didn't capture any nodes
+    /// (e.g. inside an optional pattern).
+    fn holds(&self, bindings: &HashMap<String, Vec<Cursor>>) -> bool {
+        let texts = |binding: &str| -> Vec<String> {
+            bindings
+                .get(binding)
+                .map(|cursors| cursors.iter().map(node_text).collect())
+                .unwrap_or_default()
+        };
+
+        let others = match &self.operator {
+            PredicateOperator::Eq(PredicateValue::Binding(other)) => {
+                let others = texts(other);
+                if others.is_empty() {
+                    return true;
+                }
+                others
+            }
+            _ => vec![],
+        };
+
+        texts(&self.binding).iter().all(|text| {
+            let matches = match &self.operator {
+                PredicateOperator::Eq(PredicateValue::Binding(_)) => {
+                    others.iter().all(|other| text == other)
+                }
+                PredicateOperator::Eq(PredicateValue::Text(expected)) => text == expected,
+                PredicateOperator::Match(regex) => regex.is_match(text),
+                PredicateOperator::AnyOf(values) => values.contains(text),
+            };
+
+            matches != self.negated
+        })
+    }
+}
+
+/// The text of the node, excluding any leading or trailing trivia.
+fn node_text(cursor: &Cursor) -> String {
+    let node = cursor.node();
+    let Some(rule) = node.as_rule() else {
+        return node.unparse();
+    };
+
+    let tokens: Vec<_> = rule
+        .clone()
+        .cursor_with_offset(TextIndex::ZERO)
+        .filter_map(|node| node.into_token())
+        .collect();
+
+    let start = tokens.iter().position(|token| !token.kind.is_trivia());
+    let end = tokens.iter().rposition(|token| !token.kind.is_trivia());
+
+    match (start, end) {
+        (Some(start), Some(end)) => tokens[start..=end]
+            .iter()
+            .map(|token| token.text.as_str())
+            .collect(),
+        _ => String::new(),
+    }
+}
+
 pub struct QueryResult {
     pub query_number: usize,
     pub bindings: HashMap<String, Vec<Cursor>>,
@@ -110,7 +176,7 @@ impl QueryResultIterator {
     fn advance_to_next_possible_matching_query(&mut self) {
         while !self.cursor.is_completed() {
             while self.query_number < self.queries.len() {
-                let matcher = &self.queries[self.query_number].0;
+                let matcher = &self.queries[self.query_number].matcher;
                 if matcher.can_match(&self.cursor) {
                     self.combinator = Some(matcher.create_combinator(self.cursor.clone()));
                     return;
@@ -129,13 +195,21 @@ impl Iterator for QueryResultIterator {
     fn next(&mut self) -> Option<Self::Item> {
         while !self.cursor.is_completed() {
             if let Some(combinator) = self.combinator.as_mut() {
-                if combinator.next().is_some() {
+                let predicates = &self.queries[self.query_number].predicates;
+                while combinator.next().is_some() {
                     let mut bindings = HashMap::new();
                     combinator.accumulate_bindings(&mut bindings);
-                    return Some(QueryResult {
-                        query_number: self.query_number,
-                        bindings,
-                    });
+
+                    // Predicates are only checked once the whole query matched, so that they can compare bindings:
+                    if predicates
+                        .iter()
+                        .all(|predicate| predicate.holds(&bindings))
+                    {
+                        return Some(QueryResult {
+                            query_number: self.query_number,
+                            bindings,
+                        });
+                    }
                 }
                 self.query_number += 1;
             }
@@ -449,6 +523,42 @@ impl Combinator for OneOrMoreCombinator {
     }
 }
 
+struct NegationCombinator {
+    matcher: Rc<NegationMatcher>,
+    cursor: Cursor,
+    is_initialised: bool,
+}
+
+impl NegationCombinator {
+    fn new(matcher: Rc<NegationMatcher>, cursor: Cursor) -> Self {
+        Self {
+            matcher,
+            cursor,
+            is_initialised: false,
+        }
+    }
+}
+
+impl Combinator for NegationCombinator {
+    fn next(&mut self) -> Option<Cursor> {
+        if self.is_initialised {
+            return None;
+        }
+        self.is_initialised = true;
+
+        // Matches once without consuming any nodes, if the child can't match from here:
+        let mut child = self.matcher.child.create_combinator(self.cursor.clone());
+        if child.next().is_some() {
+            None
+        } else {
+            Some(self.cursor.clone())
+        }
+    }
+
+    // Nothing is captured by a pattern that didn't match:
+    fn accumulate_bindings(&self, _bindings: &mut HashMap<String, Vec<Cursor>>) {}
+}
+
 struct EllipsisCombinator {
     cursor: Cursor,
     has_returned_initial_empty_value: bool,
@@ -0,0 +1,467 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! Rewriting a CST by replacing, inserting and removing nodes, producing a new tree along with the matching text edits.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::cst::{LabeledNode, Node, Rc, RuleNode};
+use crate::cursor::Cursor;
+use crate::kinds::NodeLabel;
+use crate::text_index::{TextEdit, TextIndex};
+
+/// Collects a set of edits to a CST, that can then be applied all at once using [`Rewriter::finish`].
+///
+/// Edits are described using cursors pointing to nodes of the original tree, and must not overlap
+/// (for example, it is not possible to edit a node that is inside another replaced node).
+/// The new tree shares all unchanged subtrees with the original one.
+///
+/// Trivia is part of the nodes it is attached to: inserted nodes should include their own trivia (e.g. a leading space),
+/// and removing a node also removes its trivia.
+pub struct Rewriter {
+    root: Node,
+    edits: Vec<Edit>,
+}
+
+/// The result of applying a [`Rewriter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewriteOutput {
+    tree: Node,
+    text_edits: Vec<TextEdit>,
+}
+
+impl RewriteOutput {
+    /// Returns the new tree.
+    pub fn tree(&self) -> Node {
+        self.tree.clone()
+    }
+
+    /// Returns the text edits that transform the original source into the new tree's source.
+    ///
+    /// The edits are sorted by their ranges in the original source, and do not overlap,
+    /// so they can be applied to the original buffer in reverse order.
+    pub fn text_edits(&self) -> &[TextEdit] {
+        &self.text_edits
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RewriteError {
+    /// The cursor was not created from the tree being rewritten.
+    ForeignCursor,
+    /// The edit overlaps with a previous edit.
+    OverlappingEdit,
+    /// The cursor points to a rule node, but a token was expected.
+    ExpectedToken,
+    /// The cursor does not point to a list rule.
+    ExpectedList,
+    /// The list does not have an item with the given index.
+    ItemOutOfBounds { index: usize, count: usize },
+}
+
+impl Error for RewriteError {}
+impl fmt::Display for RewriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForeignCursor => write!(
+                f,
+                "The cursor was not created from the tree being rewritten."
+            ),
+            Self::OverlappingEdit => write!(f, "The edit overlaps with a previous edit."),
+            Self::ExpectedToken => write!(f, "Expected the cursor to point to a token."),
+            Self::ExpectedList => write!(f, "Expected the cursor to point to a list."),
+            Self::ItemOutOfBounds { index, count } => {
+                write!(
+                    f,
+                    "Item index {index} is out of bounds for a list of {count} items."
+                )
+            }
+        }
+    }
+}
+
+struct Edit {
+    kind: EditKind,
+    text_edit: TextEdit,
+}
+
+enum EditKind {
+    /// Replaces the node at the given path.
+    Replace { path: Vec<usize>, node: Node },
+    /// Inserts children in the list at the given path, before its child at `position`.
+    Insert {
+        list: Vec<usize>,
+        position: usize,
+        children: Vec<LabeledNode>,
+    },
+    /// Removes the child at the given path.
+    Remove { path: Vec<usize> },
+}
+
+impl Edit {
+    /// The paths of the existing nodes that are replaced or removed by this edit.
+    fn affected(&self) -> Option<&[usize]> {
+        match &self.kind {
+            EditKind::Replace { path, .. } | EditKind::Remove { path } => Some(path),
+            EditKind::Insert { .. } => None,
+        }
+    }
+
+    /// The path of the node whose children are modified in place by this edit.
+    fn parent(&self) -> &[usize] {
+        match &self.kind {
+            EditKind::Replace { path, .. } | EditKind::Remove { path } => {
+                &path[..path.len().saturating_sub(1)]
+            }
+            EditKind::Insert { list, .. } => list,
+        }
+    }
+
+    fn overlaps(&self, other: &Edit) -> bool {
+        let is_within = |inner: &[usize], outer: &[usize]| inner.starts_with(outer);
+
+        match (self.affected(), other.affected()) {
+            (Some(this), Some(other)) => is_within(this, other) || is_within(other, this),
+            (Some(this), None) => is_within(other.parent(), this),
+            (None, Some(other)) => is_within(self.parent(), other),
+            (None, None) => false,
+        }
+    }
+}
+
+impl Rewriter {
+    /// Creates a rewriter for the tree with the given root.
+    pub fn new(root: Node) -> Self {
+        Self {
+            root,
+            edits: vec![],
+        }
+    }
+
+    /// Replaces the node pointed to by the cursor with a new one.
+    pub fn replace_node(&mut self, cursor: &Cursor, node: Node) -> Result<(), RewriteError> {
+        let path = self.path_of(cursor)?;
+
+        let text_edit = TextEdit {
+            range: cursor.text_range(),
+            new_text: node.clone().unparse(),
+        };
+
+        self.add(Edit {
+            kind: EditKind::Replace { path, node },
+            text_edit,
+        })
+    }
+
+    /// Replaces the text of the token pointed to by the cursor, keeping its kind.
+    pub fn replace_token_text(
+        &mut self,
+        cursor: &Cursor,
+        text: impl Into<String>,
+    ) -> Result<(), RewriteError> {
+        let Node::Token(token) = cursor.node() else {
+            return Err(RewriteError::ExpectedToken);
+        };
+
+        self.replace_node(cursor, Node::token(token.kind, text.into()))
+    }
+
+    /// Inserts a new item in the list pointed to by the cursor, so that it ends up at the given `index`.
+    ///
+    /// For separated lists, the `separator` (e.g. a comma) is inserted along with the item:
+    /// after it if the item is inserted before another one, or before it if the item is appended to the list.
+    pub fn insert_list_item(
+        &mut self,
+        list: &Cursor,
+        index: usize,
+        item: Node,
+        separator: Option<Node>,
+    ) -> Result<(), RewriteError> {
+        let path = self.path_of(list)?;
+        let rule = list_rule(list)?;
+        let children = &rule.children;
+        let items = item_positions(children);
+
+        if index > items.len() {
+            return Err(RewriteError::ItemOutOfBounds {
+                index,
+                count: items.len(),
+            });
+        }
+
+        let item = LabeledNode {
+            label: Some(NodeLabel::Item),
+            node: item,
+        };
+        let separator = separator
+            .filter(|_| !items.is_empty())
+            .map(|separator| LabeledNode {
+                label: Some(NodeLabel::Separator),
+                node: separator,
+            });
+
+        let (position, new_children) = match items.get(index) {
+            Some(&position) => (position, [Some(item), separator]),
+            None => (children.len(), [separator, Some(item)]),
+        };
+        let new_children = new_children.into_iter().flatten().collect::<Vec<_>>();
+
+        let offset = child_offset(list, children, position);
+        let text_edit = TextEdit {
+            range: offset..offset,
+            new_text: new_children
+                .iter()
+                .map(|child| child.node.clone().unparse())
+                .collect(),
+        };
+
+        self.add(Edit {
+            kind: EditKind::Insert {
+                list: path,
+                position,
+                children: new_children,
+            },
+            text_edit,
+        })
+    }
+
+    /// Removes the item with the given `index` from the list pointed to by the cursor.
+    ///
+    /// For separated lists, the separator following the item is removed as well,
+    /// or the one preceding it if it is the last item. When removing several items of the same list,
+    /// separators are chosen so that exactly one separator remains between each pair of remaining items.
+    pub fn remove_list_item(&mut self, list: &Cursor, index: usize) -> Result<(), RewriteError> {
+        let path = self.path_of(list)?;
+        let rule = list_rule(list)?;
+        let children = &rule.children;
+        let items = item_positions(children);
+
+        let Some(&position) = items.get(index) else {
+            return Err(RewriteError::ItemOutOfBounds {
+                index,
+                count: items.len(),
+            });
+        };
+
+        let is_removed_child = |edit: &Edit, position: usize| match &edit.kind {
+            EditKind::Remove { path: target } => {
+                target.split_last() == Some((&position, path.as_slice()))
+            }
+            _ => false,
+        };
+
+        // Separators removed by previous calls are chosen again, along with the ones of this item:
+        let is_removed_separator = |edit: &Edit| {
+            (0..children.len()).any(|position| {
+                children[position].label == Some(NodeLabel::Separator)
+                    && is_removed_child(edit, position)
+            })
+        };
+
+        let removed_items = items
+            .iter()
+            .copied()
+            .filter(|&item| {
+                item == position || self.edits.iter().any(|edit| is_removed_child(edit, item))
+            })
+            .collect::<Vec<_>>();
+
+        let edits = std::iter::once(position)
+            .chain(removed_separators(children, &items, &removed_items))
+            .map(|position| Self::remove_child(list, &path, children, position))
+            .collect::<Vec<_>>();
+
+        if edits.iter().any(|edit| {
+            self.edits
+                .iter()
+                .filter(|other| !is_removed_separator(other))
+                .any(|other| edit.overlaps(other))
+        }) {
+            return Err(RewriteError::OverlappingEdit);
+        }
+
+        self.edits.retain(|edit| !is_removed_separator(edit));
+        self.edits.extend(edits);
+        Ok(())
+    }
+
+    fn remove_child(
+        list: &Cursor,
+        path: &[usize],
+        children: &[LabeledNode],
+        position: usize,
+    ) -> Edit {
+        let start = child_offset(list, children, position);
+        let end = start + children[position].text_len();
+
+        let mut path = path.to_vec();
+        path.push(position);
+
+        Edit {
+            kind: EditKind::Remove { path },
+            text_edit: TextEdit {
+                range: start..end,
+                new_text: String::new(),
+            },
+        }
+    }
+
+    /// Applies all edits, returning the new tree along with the matching text edits.
+    pub fn finish(self) -> RewriteOutput {
+        let tree = self.rebuild(&self.root, &mut vec![]);
+
+        let mut text_edits = self
+            .edits
+            .into_iter()
+            .map(|edit| edit.text_edit)
+            .collect::<Vec<_>>();
+
+        // Stable sort, so that insertions at the same offset keep their order:
+        text_edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+        RewriteOutput { tree, text_edits }
+    }
+
+    fn add(&mut self, edit: Edit) -> Result<(), RewriteError> {
+        if self.edits.iter().any(|other| edit.overlaps(other)) {
+            return Err(RewriteError::OverlappingEdit);
+        }
+
+        self.edits.push(edit);
+        Ok(())
+    }
+
+    fn path_of(&self, cursor: &Cursor) -> Result<Vec<usize>, RewriteError> {
+        let (root, path) = cursor.root_and_path();
+
+        let is_same_root = match (&root, &self.root) {
+            (Node::Rule(cursor_root), Node::Rule(root)) => Rc::ptr_eq(cursor_root, root),
+            (Node::Token(cursor_root), Node::Token(root)) => Rc::ptr_eq(cursor_root, root),
+            _ => false,
+        };
+
+        if is_same_root {
+            Ok(path)
+        } else {
+            Err(RewriteError::ForeignCursor)
+        }
+    }
+
+    fn rebuild(&self, node: &Node, path: &mut Vec<usize>) -> Node {
+        for edit in &self.edits {
+            if let EditKind::Replace {
+                path: target,
+                node: replacement,
+            } = &edit.kind
+            {
+                if target == path {
+                    return replacement.clone();
+                }
+            }
+        }
+
+        let Node::Rule(rule) = node else {
+            return node.clone();
+        };
+
+        // Share the entire subtree if there are no edits inside it:
+        if !self
+            .edits
+            .iter()
+            .any(|edit| edit.parent().starts_with(path))
+        {
+            return node.clone();
+        }
+
+        let mut children = vec![];
+        for (child_number, child) in rule.children.iter().enumerate() {
+            children.extend(self.insertions_at(path, child_number));
+
+            path.push(child_number);
+
+            let is_removed = self.edits.iter().any(
+                |edit| matches!(&edit.kind, EditKind::Remove { path: target } if target == path),
+            );
+
+            if !is_removed {
+                children.push(LabeledNode {
+                    label: child.label,
+                    node: self.rebuild(&child.node, path),
+                });
+            }
+
+            path.pop();
+        }
+        children.extend(self.insertions_at(path, rule.children.len()));
+
+        Node::rule(rule.kind, children)
+    }
+
+    fn insertions_at<'a>(
+        &'a self,
+        path: &'a [usize],
+        position: usize,
+    ) -> impl Iterator<Item = LabeledNode> + 'a {
+        self.edits
+            .iter()
+            .filter_map(move |edit| match &edit.kind {
+                EditKind::Insert {
+                    list,
+                    position: target,
+                    children,
+                } if list == path && *target == position => Some(children.iter().cloned()),
+                _ => None,
+            })
+            .flatten()
+    }
+}
+
+fn list_rule(list: &Cursor) -> Result<Rc<RuleNode>, RewriteError> {
+    match list.node() {
+        Node::Rule(rule) if rule.kind.is_list() => Ok(rule),
+        _ => Err(RewriteError::ExpectedList),
+    }
+}
+
+/// Returns the positions of the separators to remove along with the given items, so that exactly one separator
+/// (the first one) remains between each pair of remaining items, and none before the first or after the last one.
+fn removed_separators(
+    children: &[LabeledNode],
+    items: &[usize],
+    removed_items: &[usize],
+) -> Vec<usize> {
+    let mut kept = vec![];
+    let mut previous_item = None;
+
+    for &item in items.iter().filter(|item| !removed_items.contains(item)) {
+        if let Some(previous_item) = previous_item {
+            kept.extend(
+                (previous_item..item)
+                    .find(|&position| children[position].label == Some(NodeLabel::Separator)),
+            );
+        }
+        previous_item = Some(item);
+    }
+
+    (0..children.len())
+        .filter(|&position| children[position].label == Some(NodeLabel::Separator))
+        .filter(|position| !kept.contains(position))
+        .collect()
+}
+
+fn item_positions(children: &[LabeledNode]) -> Vec<usize> {
+    children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| child.label == Some(NodeLabel::Item))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+fn child_offset(list: &Cursor, children: &[LabeledNode], position: usize) -> TextIndex {
+    list.text_offset()
+        + children[..position]
+            .iter()
+            .map(|child| child.text_len())
+            .sum()
+}
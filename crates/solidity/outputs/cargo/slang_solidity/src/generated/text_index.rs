@@ -3,9 +3,9 @@
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Range, Sub, SubAssign};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Copy, Clone, PartialEq, Eq, Debug, Serialize)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct TextIndex {
     pub utf8: usize,
     pub utf16: usize,
@@ -115,6 +115,13 @@ impl SubAssign for TextIndex {
 
 pub type TextRange = Range<TextIndex>;
 
+/// Replaces the text in `range` of a previous source with `new_text`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
 pub trait TextRangeExtensions {
     fn utf8(&self) -> Range<usize>;
     fn utf16(&self) -> Range<usize>;
@@ -134,3 +141,184 @@ impl TextRangeExtensions for TextRange {
         self.start.char..self.end.char
     }
 }
+
+/// The unit used to measure a column in a [`LineIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextUnit {
+    Utf8,
+    Utf16,
+    Char,
+}
+
+impl TextIndex {
+    fn in_unit(self, unit: TextUnit) -> usize {
+        match unit {
+            TextUnit::Utf8 => self.utf8,
+            TextUnit::Utf16 => self.utf16,
+            TextUnit::Char => self.char,
+        }
+    }
+
+    fn of_char(c: char) -> Self {
+        Self {
+            utf8: c.len_utf8(),
+            utf16: c.len_utf16(),
+            char: 1,
+        }
+    }
+
+    /// The length of `count` ASCII characters, which is the same in all units.
+    fn ascii(count: usize) -> Self {
+        Self {
+            utf8: count,
+            utf16: count,
+            char: count,
+        }
+    }
+}
+
+/// A zero-based line number, along with the column of a position within that line, in all units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct LineColumn {
+    pub line: usize,
+    pub column: TextIndex,
+}
+
+/// The characters that a [`LineIndex`] recognizes as line terminators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineTerminators {
+    /// `\n`, `\r\n`, `\r`, and the Unicode line separators (`U+0085`, `U+2028` and `U+2029`).
+    #[default]
+    Unicode,
+    /// Only `\n`, `\r\n` and `\r`, which are the terminators of the Language Server Protocol.
+    Lsp,
+}
+
+/// Converts offsets in a source text to (line, column) positions, and back.
+///
+/// Lines are terminated by `\n`, `\r\n`, `\r`, or the Unicode line separators (`U+0085`, `U+2028` and `U+2029`),
+/// unless created with [`LineTerminators::Lsp`]. The terminator is considered part of the line it ends.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex {
+    lines: Vec<Line>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Line {
+    start: TextIndex,
+    /// Excludes the line terminator.
+    end: TextIndex,
+    /// Non-ASCII characters, whose length differ between units, along with their columns.
+    wide_chars: Vec<(TextIndex, char)>,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        Self::with_terminators(source, LineTerminators::Unicode)
+    }
+
+    pub fn with_terminators(source: &str, terminators: LineTerminators) -> Self {
+        let mut lines = vec![];
+        let mut line = Line {
+            start: TextIndex::ZERO,
+            end: TextIndex::ZERO,
+            wide_chars: vec![],
+        };
+
+        let mut offset = TextIndex::ZERO;
+        let mut chars = source.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let c_len = TextIndex::of_char(c);
+
+            let terminator_len = match c {
+                '\r' if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                    c_len + TextIndex::ascii(1)
+                }
+                '\n' | '\r' => c_len,
+                '\u{0085}' | '\u{2028}' | '\u{2029}' if terminators == LineTerminators::Unicode => {
+                    c_len
+                }
+                _ => {
+                    if !c.is_ascii() {
+                        line.wide_chars.push((offset - line.start, c));
+                    }
+
+                    offset += c_len;
+                    continue;
+                }
+            };
+
+            line.end = offset;
+            offset += terminator_len;
+
+            let next_line = Line {
+                start: offset,
+                end: offset,
+                wide_chars: vec![],
+            };
+            lines.push(std::mem::replace(&mut line, next_line));
+        }
+
+        line.end = offset;
+        lines.push(line);
+
+        Self { lines }
+    }
+
+    /// Returns the number of lines, which is always at least one.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the position of the given offset.
+    ///
+    /// Offsets within a line terminator are reported on the line it ends,
+    /// and offsets past the end of the source are reported on the last line.
+    pub fn line_column(&self, offset: TextIndex) -> LineColumn {
+        let line = self
+            .lines
+            .partition_point(|line| line.start <= offset)
+            .saturating_sub(1);
+
+        LineColumn {
+            line,
+            column: offset - self.lines[line].start,
+        }
+    }
+
+    /// Returns the positions of the start and end of the given range.
+    pub fn line_column_range(&self, range: &TextRange) -> Range<LineColumn> {
+        self.line_column(range.start)..self.line_column(range.end)
+    }
+
+    /// Returns the offset of a `column` (measured in the given `unit`) within a zero-based `line`.
+    ///
+    /// Returns `None` if the line does not exist, if the column is past the end of the line
+    /// (excluding its terminator), or if it points to the middle of a character.
+    pub fn offset(&self, line: usize, column: usize, unit: TextUnit) -> Option<TextIndex> {
+        let line = self.lines.get(line)?;
+
+        let mut current = TextIndex::ZERO;
+        for (wide_column, c) in &line.wide_chars {
+            // All characters between the current column and the next wide character are ASCII:
+            let remaining = column - current.in_unit(unit);
+            if remaining <= wide_column.in_unit(unit) - current.in_unit(unit) {
+                return Some(line.start + current + TextIndex::ascii(remaining));
+            }
+
+            current = *wide_column + TextIndex::of_char(*c);
+            if column < current.in_unit(unit) {
+                return None;
+            }
+        }
+
+        let remaining = column - current.in_unit(unit);
+        if remaining > (line.end - line.start).in_unit(unit) - current.in_unit(unit) {
+            return None;
+        }
+
+        Some(line.start + current + TextIndex::ascii(remaining))
+    }
+}
@@ -0,0 +1,5221 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! A visitor over the CST, with hooks for entering and leaving every kind of node.
+//!
+//! Implement the hooks of interest on a [`Visitor`], and pass it to [`walk`] to visit all nodes in a DFS pre-order traversal.
+//! All hooks have a default implementation that does nothing.
+
+#![allow(clippy::too_many_lines)]
+
+use crate::cst::{Node, Rc, RuleNode, TokenNode};
+use crate::cursor::Cursor;
+use crate::kinds::{RuleKind, TokenKind};
+use crate::parse_output::ParseOutput;
+
+/// Returned by the `enter_*` hooks of a [`Visitor`], to control whether the children of a rule node are visited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkControl {
+    /// Visit the children of the node.
+    Continue,
+    /// Skip the children of the node. Its `leave_*` hooks are still called.
+    SkipChildren,
+}
+
+/// Hooks called by [`walk`] for every node in the tree.
+///
+/// For rule nodes, the generic [`Visitor::enter_rule`] hook is called first, followed by the hook specific to
+/// the node's [`RuleKind`]. If either of them returns [`WalkControl::SkipChildren`], the children are skipped.
+/// When leaving the node, the kind-specific hook is called first, followed by [`Visitor::leave_rule`].
+///
+/// For token nodes, [`Visitor::visit_token`] is called first, followed by the hook specific to the node's [`TokenKind`].
+///
+/// Every hook receives a [`Cursor`] pointing to the node, which can be used to inspect its position and ancestors.
+#[allow(unused_variables)]
+pub trait Visitor {
+    /// Called when entering any rule node.
+    fn enter_rule(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving any rule node, after all of its children were visited.
+    fn leave_rule(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when visiting any token node.
+    fn visit_token(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ABICoderPragma`] node.
+    fn enter_abi_coder_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ABICoderPragma`] node.
+    fn leave_abi_coder_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AdditiveExpression`] node.
+    fn enter_additive_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AdditiveExpression`] node.
+    fn leave_additive_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AddressType`] node.
+    fn enter_address_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AddressType`] node.
+    fn leave_address_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AndExpression`] node.
+    fn enter_and_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AndExpression`] node.
+    fn leave_and_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ArgumentsDeclaration`] node.
+    fn enter_arguments_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ArgumentsDeclaration`] node.
+    fn leave_arguments_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ArrayExpression`] node.
+    fn enter_array_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ArrayExpression`] node.
+    fn leave_array_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ArrayTypeName`] node.
+    fn enter_array_type_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ArrayTypeName`] node.
+    fn leave_array_type_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ArrayValues`] node.
+    fn enter_array_values(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ArrayValues`] node.
+    fn leave_array_values(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AssemblyFlags`] node.
+    fn enter_assembly_flags(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AssemblyFlags`] node.
+    fn leave_assembly_flags(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AssemblyFlagsDeclaration`] node.
+    fn enter_assembly_flags_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AssemblyFlagsDeclaration`] node.
+    fn leave_assembly_flags_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AssemblyStatement`] node.
+    fn enter_assembly_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AssemblyStatement`] node.
+    fn leave_assembly_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AssignmentExpression`] node.
+    fn enter_assignment_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AssignmentExpression`] node.
+    fn leave_assignment_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::BitwiseAndExpression`] node.
+    fn enter_bitwise_and_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::BitwiseAndExpression`] node.
+    fn leave_bitwise_and_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::BitwiseOrExpression`] node.
+    fn enter_bitwise_or_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::BitwiseOrExpression`] node.
+    fn leave_bitwise_or_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::BitwiseXorExpression`] node.
+    fn enter_bitwise_xor_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::BitwiseXorExpression`] node.
+    fn leave_bitwise_xor_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Block`] node.
+    fn enter_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Block`] node.
+    fn leave_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::BreakStatement`] node.
+    fn enter_break_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::BreakStatement`] node.
+    fn leave_break_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::CallOptions`] node.
+    fn enter_call_options(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::CallOptions`] node.
+    fn leave_call_options(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::CallOptionsExpression`] node.
+    fn enter_call_options_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::CallOptionsExpression`] node.
+    fn leave_call_options_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::CatchClause`] node.
+    fn enter_catch_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::CatchClause`] node.
+    fn leave_catch_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::CatchClauseError`] node.
+    fn enter_catch_clause_error(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::CatchClauseError`] node.
+    fn leave_catch_clause_error(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::CatchClauses`] node.
+    fn enter_catch_clauses(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::CatchClauses`] node.
+    fn leave_catch_clauses(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ComparisonExpression`] node.
+    fn enter_comparison_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ComparisonExpression`] node.
+    fn leave_comparison_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ConditionalExpression`] node.
+    fn enter_conditional_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ConditionalExpression`] node.
+    fn leave_conditional_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ConstantDefinition`] node.
+    fn enter_constant_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ConstantDefinition`] node.
+    fn leave_constant_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ConstructorAttribute`] node.
+    fn enter_constructor_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ConstructorAttribute`] node.
+    fn leave_constructor_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ConstructorAttributes`] node.
+    fn enter_constructor_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ConstructorAttributes`] node.
+    fn leave_constructor_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ConstructorDefinition`] node.
+    fn enter_constructor_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ConstructorDefinition`] node.
+    fn leave_constructor_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ContinueStatement`] node.
+    fn enter_continue_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ContinueStatement`] node.
+    fn leave_continue_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ContractDefinition`] node.
+    fn enter_contract_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ContractDefinition`] node.
+    fn leave_contract_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ContractMember`] node.
+    fn enter_contract_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ContractMember`] node.
+    fn leave_contract_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ContractMembers`] node.
+    fn enter_contract_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ContractMembers`] node.
+    fn leave_contract_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::DecimalNumberExpression`] node.
+    fn enter_decimal_number_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::DecimalNumberExpression`] node.
+    fn leave_decimal_number_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::DoWhileStatement`] node.
+    fn enter_do_while_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::DoWhileStatement`] node.
+    fn leave_do_while_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ElementaryType`] node.
+    fn enter_elementary_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ElementaryType`] node.
+    fn leave_elementary_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ElseBranch`] node.
+    fn enter_else_branch(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ElseBranch`] node.
+    fn leave_else_branch(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EmitStatement`] node.
+    fn enter_emit_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EmitStatement`] node.
+    fn leave_emit_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EnumDefinition`] node.
+    fn enter_enum_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EnumDefinition`] node.
+    fn leave_enum_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EnumMembers`] node.
+    fn enter_enum_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EnumMembers`] node.
+    fn leave_enum_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EqualityExpression`] node.
+    fn enter_equality_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EqualityExpression`] node.
+    fn leave_equality_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ErrorDefinition`] node.
+    fn enter_error_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ErrorDefinition`] node.
+    fn leave_error_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ErrorParameter`] node.
+    fn enter_error_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ErrorParameter`] node.
+    fn leave_error_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ErrorParameters`] node.
+    fn enter_error_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ErrorParameters`] node.
+    fn leave_error_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ErrorParametersDeclaration`] node.
+    fn enter_error_parameters_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ErrorParametersDeclaration`] node.
+    fn leave_error_parameters_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EventDefinition`] node.
+    fn enter_event_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EventDefinition`] node.
+    fn leave_event_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EventParameter`] node.
+    fn enter_event_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EventParameter`] node.
+    fn leave_event_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EventParameters`] node.
+    fn enter_event_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EventParameters`] node.
+    fn leave_event_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::EventParametersDeclaration`] node.
+    fn enter_event_parameters_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::EventParametersDeclaration`] node.
+    fn leave_event_parameters_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ExperimentalFeature`] node.
+    fn enter_experimental_feature(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ExperimentalFeature`] node.
+    fn leave_experimental_feature(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ExperimentalPragma`] node.
+    fn enter_experimental_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ExperimentalPragma`] node.
+    fn leave_experimental_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ExponentiationExpression`] node.
+    fn enter_exponentiation_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ExponentiationExpression`] node.
+    fn leave_exponentiation_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Expression`] node.
+    fn enter_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Expression`] node.
+    fn leave_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ExpressionStatement`] node.
+    fn enter_expression_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ExpressionStatement`] node.
+    fn leave_expression_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FallbackFunctionAttribute`] node.
+    fn enter_fallback_function_attribute(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FallbackFunctionAttribute`] node.
+    fn leave_fallback_function_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FallbackFunctionAttributes`] node.
+    fn enter_fallback_function_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FallbackFunctionAttributes`] node.
+    fn leave_fallback_function_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FallbackFunctionDefinition`] node.
+    fn enter_fallback_function_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FallbackFunctionDefinition`] node.
+    fn leave_fallback_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ForStatement`] node.
+    fn enter_for_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ForStatement`] node.
+    fn leave_for_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ForStatementCondition`] node.
+    fn enter_for_statement_condition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ForStatementCondition`] node.
+    fn leave_for_statement_condition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ForStatementInitialization`] node.
+    fn enter_for_statement_initialization(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ForStatementInitialization`] node.
+    fn leave_for_statement_initialization(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionAttribute`] node.
+    fn enter_function_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionAttribute`] node.
+    fn leave_function_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionAttributes`] node.
+    fn enter_function_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionAttributes`] node.
+    fn leave_function_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionBody`] node.
+    fn enter_function_body(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionBody`] node.
+    fn leave_function_body(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionCallExpression`] node.
+    fn enter_function_call_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionCallExpression`] node.
+    fn leave_function_call_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionDefinition`] node.
+    fn enter_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionDefinition`] node.
+    fn leave_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionName`] node.
+    fn enter_function_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionName`] node.
+    fn leave_function_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionType`] node.
+    fn enter_function_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionType`] node.
+    fn leave_function_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionTypeAttribute`] node.
+    fn enter_function_type_attribute(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionTypeAttribute`] node.
+    fn leave_function_type_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::FunctionTypeAttributes`] node.
+    fn enter_function_type_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::FunctionTypeAttributes`] node.
+    fn leave_function_type_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::HexNumberExpression`] node.
+    fn enter_hex_number_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::HexNumberExpression`] node.
+    fn leave_hex_number_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::HexStringLiteral`] node.
+    fn enter_hex_string_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::HexStringLiteral`] node.
+    fn leave_hex_string_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::HexStringLiterals`] node.
+    fn enter_hex_string_literals(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::HexStringLiterals`] node.
+    fn leave_hex_string_literals(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::IdentifierPath`] node.
+    fn enter_identifier_path(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::IdentifierPath`] node.
+    fn leave_identifier_path(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::IfStatement`] node.
+    fn enter_if_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::IfStatement`] node.
+    fn leave_if_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportAlias`] node.
+    fn enter_import_alias(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportAlias`] node.
+    fn leave_import_alias(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportClause`] node.
+    fn enter_import_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportClause`] node.
+    fn leave_import_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportDeconstruction`] node.
+    fn enter_import_deconstruction(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportDeconstruction`] node.
+    fn leave_import_deconstruction(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportDeconstructionSymbol`] node.
+    fn enter_import_deconstruction_symbol(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportDeconstructionSymbol`] node.
+    fn leave_import_deconstruction_symbol(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportDeconstructionSymbols`] node.
+    fn enter_import_deconstruction_symbols(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportDeconstructionSymbols`] node.
+    fn leave_import_deconstruction_symbols(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ImportDirective`] node.
+    fn enter_import_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ImportDirective`] node.
+    fn leave_import_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::IndexAccessEnd`] node.
+    fn enter_index_access_end(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::IndexAccessEnd`] node.
+    fn leave_index_access_end(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::IndexAccessExpression`] node.
+    fn enter_index_access_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::IndexAccessExpression`] node.
+    fn leave_index_access_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::InheritanceSpecifier`] node.
+    fn enter_inheritance_specifier(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::InheritanceSpecifier`] node.
+    fn leave_inheritance_specifier(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::InheritanceType`] node.
+    fn enter_inheritance_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::InheritanceType`] node.
+    fn leave_inheritance_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::InheritanceTypes`] node.
+    fn enter_inheritance_types(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::InheritanceTypes`] node.
+    fn leave_inheritance_types(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::InterfaceDefinition`] node.
+    fn enter_interface_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::InterfaceDefinition`] node.
+    fn leave_interface_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::InterfaceMembers`] node.
+    fn enter_interface_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::InterfaceMembers`] node.
+    fn leave_interface_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::LibraryDefinition`] node.
+    fn enter_library_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::LibraryDefinition`] node.
+    fn leave_library_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::LibraryMembers`] node.
+    fn enter_library_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::LibraryMembers`] node.
+    fn leave_library_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MappingKey`] node.
+    fn enter_mapping_key(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MappingKey`] node.
+    fn leave_mapping_key(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MappingKeyType`] node.
+    fn enter_mapping_key_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MappingKeyType`] node.
+    fn leave_mapping_key_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MappingType`] node.
+    fn enter_mapping_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MappingType`] node.
+    fn leave_mapping_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MappingValue`] node.
+    fn enter_mapping_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MappingValue`] node.
+    fn leave_mapping_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MemberAccess`] node.
+    fn enter_member_access(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MemberAccess`] node.
+    fn leave_member_access(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MemberAccessExpression`] node.
+    fn enter_member_access_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MemberAccessExpression`] node.
+    fn leave_member_access_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ModifierAttribute`] node.
+    fn enter_modifier_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ModifierAttribute`] node.
+    fn leave_modifier_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ModifierAttributes`] node.
+    fn enter_modifier_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ModifierAttributes`] node.
+    fn leave_modifier_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ModifierDefinition`] node.
+    fn enter_modifier_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ModifierDefinition`] node.
+    fn leave_modifier_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ModifierInvocation`] node.
+    fn enter_modifier_invocation(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ModifierInvocation`] node.
+    fn leave_modifier_invocation(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MultiplicativeExpression`] node.
+    fn enter_multiplicative_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MultiplicativeExpression`] node.
+    fn leave_multiplicative_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NamedArgument`] node.
+    fn enter_named_argument(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NamedArgument`] node.
+    fn leave_named_argument(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NamedArgumentGroup`] node.
+    fn enter_named_argument_group(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NamedArgumentGroup`] node.
+    fn leave_named_argument_group(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NamedArguments`] node.
+    fn enter_named_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NamedArguments`] node.
+    fn leave_named_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NamedArgumentsDeclaration`] node.
+    fn enter_named_arguments_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NamedArgumentsDeclaration`] node.
+    fn leave_named_arguments_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NamedImport`] node.
+    fn enter_named_import(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NamedImport`] node.
+    fn leave_named_import(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NewExpression`] node.
+    fn enter_new_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NewExpression`] node.
+    fn leave_new_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NumberUnit`] node.
+    fn enter_number_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NumberUnit`] node.
+    fn leave_number_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::OrExpression`] node.
+    fn enter_or_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::OrExpression`] node.
+    fn leave_or_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::OverridePaths`] node.
+    fn enter_override_paths(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::OverridePaths`] node.
+    fn leave_override_paths(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::OverridePathsDeclaration`] node.
+    fn enter_override_paths_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::OverridePathsDeclaration`] node.
+    fn leave_override_paths_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::OverrideSpecifier`] node.
+    fn enter_override_specifier(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::OverrideSpecifier`] node.
+    fn leave_override_specifier(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Parameter`] node.
+    fn enter_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Parameter`] node.
+    fn leave_parameter(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Parameters`] node.
+    fn enter_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Parameters`] node.
+    fn leave_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ParametersDeclaration`] node.
+    fn enter_parameters_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ParametersDeclaration`] node.
+    fn leave_parameters_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PathImport`] node.
+    fn enter_path_import(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PathImport`] node.
+    fn leave_path_import(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PositionalArguments`] node.
+    fn enter_positional_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PositionalArguments`] node.
+    fn leave_positional_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PositionalArgumentsDeclaration`] node.
+    fn enter_positional_arguments_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PositionalArgumentsDeclaration`] node.
+    fn leave_positional_arguments_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PostfixExpression`] node.
+    fn enter_postfix_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PostfixExpression`] node.
+    fn leave_postfix_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Pragma`] node.
+    fn enter_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Pragma`] node.
+    fn leave_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PragmaDirective`] node.
+    fn enter_pragma_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PragmaDirective`] node.
+    fn leave_pragma_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::PrefixExpression`] node.
+    fn enter_prefix_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::PrefixExpression`] node.
+    fn leave_prefix_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ReceiveFunctionAttribute`] node.
+    fn enter_receive_function_attribute(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ReceiveFunctionAttribute`] node.
+    fn leave_receive_function_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ReceiveFunctionAttributes`] node.
+    fn enter_receive_function_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ReceiveFunctionAttributes`] node.
+    fn leave_receive_function_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ReceiveFunctionDefinition`] node.
+    fn enter_receive_function_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ReceiveFunctionDefinition`] node.
+    fn leave_receive_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ReturnStatement`] node.
+    fn enter_return_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ReturnStatement`] node.
+    fn leave_return_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ReturnsDeclaration`] node.
+    fn enter_returns_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ReturnsDeclaration`] node.
+    fn leave_returns_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::RevertStatement`] node.
+    fn enter_revert_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::RevertStatement`] node.
+    fn leave_revert_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ShiftExpression`] node.
+    fn enter_shift_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ShiftExpression`] node.
+    fn leave_shift_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnit`] node.
+    fn enter_source_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnit`] node.
+    fn leave_source_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnitMember`] node.
+    fn enter_source_unit_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnitMember`] node.
+    fn leave_source_unit_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnitMembers`] node.
+    fn enter_source_unit_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnitMembers`] node.
+    fn leave_source_unit_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StateVariableAttribute`] node.
+    fn enter_state_variable_attribute(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StateVariableAttribute`] node.
+    fn leave_state_variable_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StateVariableAttributes`] node.
+    fn enter_state_variable_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StateVariableAttributes`] node.
+    fn leave_state_variable_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StateVariableDefinition`] node.
+    fn enter_state_variable_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StateVariableDefinition`] node.
+    fn leave_state_variable_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StateVariableDefinitionValue`] node.
+    fn enter_state_variable_definition_value(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StateVariableDefinitionValue`] node.
+    fn leave_state_variable_definition_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Statement`] node.
+    fn enter_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Statement`] node.
+    fn leave_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Statements`] node.
+    fn enter_statements(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Statements`] node.
+    fn leave_statements(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StorageLocation`] node.
+    fn enter_storage_location(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StorageLocation`] node.
+    fn leave_storage_location(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StringExpression`] node.
+    fn enter_string_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StringExpression`] node.
+    fn leave_string_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StringLiteral`] node.
+    fn enter_string_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StringLiteral`] node.
+    fn leave_string_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StringLiterals`] node.
+    fn enter_string_literals(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StringLiterals`] node.
+    fn leave_string_literals(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StructDefinition`] node.
+    fn enter_struct_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StructDefinition`] node.
+    fn leave_struct_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StructMember`] node.
+    fn enter_struct_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StructMember`] node.
+    fn leave_struct_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::StructMembers`] node.
+    fn enter_struct_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::StructMembers`] node.
+    fn leave_struct_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::ThrowStatement`] node.
+    fn enter_throw_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::ThrowStatement`] node.
+    fn leave_throw_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TryStatement`] node.
+    fn enter_try_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TryStatement`] node.
+    fn leave_try_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleDeconstructionElement`] node.
+    fn enter_tuple_deconstruction_element(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleDeconstructionElement`] node.
+    fn leave_tuple_deconstruction_element(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleDeconstructionElements`] node.
+    fn enter_tuple_deconstruction_elements(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleDeconstructionElements`] node.
+    fn leave_tuple_deconstruction_elements(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleDeconstructionStatement`] node.
+    fn enter_tuple_deconstruction_statement(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleDeconstructionStatement`] node.
+    fn leave_tuple_deconstruction_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleExpression`] node.
+    fn enter_tuple_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleExpression`] node.
+    fn leave_tuple_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleMember`] node.
+    fn enter_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleMember`] node.
+    fn leave_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleValue`] node.
+    fn enter_tuple_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleValue`] node.
+    fn leave_tuple_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TupleValues`] node.
+    fn enter_tuple_values(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TupleValues`] node.
+    fn leave_tuple_values(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TypeExpression`] node.
+    fn enter_type_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TypeExpression`] node.
+    fn leave_type_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TypeName`] node.
+    fn enter_type_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TypeName`] node.
+    fn leave_type_name(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TypedTupleMember`] node.
+    fn enter_typed_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TypedTupleMember`] node.
+    fn leave_typed_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UncheckedBlock`] node.
+    fn enter_unchecked_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UncheckedBlock`] node.
+    fn leave_unchecked_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UnicodeStringLiteral`] node.
+    fn enter_unicode_string_literal(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UnicodeStringLiteral`] node.
+    fn leave_unicode_string_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UnicodeStringLiterals`] node.
+    fn enter_unicode_string_literals(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UnicodeStringLiterals`] node.
+    fn leave_unicode_string_literals(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UnnamedFunctionAttribute`] node.
+    fn enter_unnamed_function_attribute(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UnnamedFunctionAttribute`] node.
+    fn leave_unnamed_function_attribute(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UnnamedFunctionAttributes`] node.
+    fn enter_unnamed_function_attributes(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UnnamedFunctionAttributes`] node.
+    fn leave_unnamed_function_attributes(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UnnamedFunctionDefinition`] node.
+    fn enter_unnamed_function_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UnnamedFunctionDefinition`] node.
+    fn leave_unnamed_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UntypedTupleMember`] node.
+    fn enter_untyped_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UntypedTupleMember`] node.
+    fn leave_untyped_tuple_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UserDefinedValueTypeDefinition`] node.
+    fn enter_user_defined_value_type_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UserDefinedValueTypeDefinition`] node.
+    fn leave_user_defined_value_type_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingAlias`] node.
+    fn enter_using_alias(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingAlias`] node.
+    fn leave_using_alias(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingClause`] node.
+    fn enter_using_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingClause`] node.
+    fn leave_using_clause(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingDeconstruction`] node.
+    fn enter_using_deconstruction(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingDeconstruction`] node.
+    fn leave_using_deconstruction(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingDeconstructionSymbol`] node.
+    fn enter_using_deconstruction_symbol(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingDeconstructionSymbol`] node.
+    fn leave_using_deconstruction_symbol(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingDeconstructionSymbols`] node.
+    fn enter_using_deconstruction_symbols(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingDeconstructionSymbols`] node.
+    fn leave_using_deconstruction_symbols(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingDirective`] node.
+    fn enter_using_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingDirective`] node.
+    fn leave_using_directive(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingOperator`] node.
+    fn enter_using_operator(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingOperator`] node.
+    fn leave_using_operator(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::UsingTarget`] node.
+    fn enter_using_target(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::UsingTarget`] node.
+    fn leave_using_target(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VariableDeclarationStatement`] node.
+    fn enter_variable_declaration_statement(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VariableDeclarationStatement`] node.
+    fn leave_variable_declaration_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VariableDeclarationType`] node.
+    fn enter_variable_declaration_type(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VariableDeclarationType`] node.
+    fn leave_variable_declaration_type(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VariableDeclarationValue`] node.
+    fn enter_variable_declaration_value(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VariableDeclarationValue`] node.
+    fn leave_variable_declaration_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionComparator`] node.
+    fn enter_version_comparator(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionComparator`] node.
+    fn leave_version_comparator(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionExpression`] node.
+    fn enter_version_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionExpression`] node.
+    fn leave_version_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionExpressionSet`] node.
+    fn enter_version_expression_set(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionExpressionSet`] node.
+    fn leave_version_expression_set(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionExpressionSets`] node.
+    fn enter_version_expression_sets(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionExpressionSets`] node.
+    fn leave_version_expression_sets(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionPragma`] node.
+    fn enter_version_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionPragma`] node.
+    fn leave_version_pragma(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionRange`] node.
+    fn enter_version_range(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionRange`] node.
+    fn leave_version_range(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::VersionSpecifiers`] node.
+    fn enter_version_specifiers(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::VersionSpecifiers`] node.
+    fn leave_version_specifiers(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::WhileStatement`] node.
+    fn enter_while_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::WhileStatement`] node.
+    fn leave_while_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulArguments`] node.
+    fn enter_yul_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulArguments`] node.
+    fn leave_yul_arguments(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulAssignmentOperator`] node.
+    fn enter_yul_assignment_operator(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulAssignmentOperator`] node.
+    fn leave_yul_assignment_operator(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulAssignmentStatement`] node.
+    fn enter_yul_assignment_statement(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulAssignmentStatement`] node.
+    fn leave_yul_assignment_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulBlock`] node.
+    fn enter_yul_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulBlock`] node.
+    fn leave_yul_block(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulBreakStatement`] node.
+    fn enter_yul_break_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulBreakStatement`] node.
+    fn leave_yul_break_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulBuiltInFunction`] node.
+    fn enter_yul_built_in_function(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulBuiltInFunction`] node.
+    fn leave_yul_built_in_function(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulColonAndEqual`] node.
+    fn enter_yul_colon_and_equal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulColonAndEqual`] node.
+    fn leave_yul_colon_and_equal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulContinueStatement`] node.
+    fn enter_yul_continue_statement(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulContinueStatement`] node.
+    fn leave_yul_continue_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulDefaultCase`] node.
+    fn enter_yul_default_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulDefaultCase`] node.
+    fn leave_yul_default_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulExpression`] node.
+    fn enter_yul_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulExpression`] node.
+    fn leave_yul_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulForStatement`] node.
+    fn enter_yul_for_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulForStatement`] node.
+    fn leave_yul_for_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulFunctionCallExpression`] node.
+    fn enter_yul_function_call_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulFunctionCallExpression`] node.
+    fn leave_yul_function_call_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulFunctionDefinition`] node.
+    fn enter_yul_function_definition(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulFunctionDefinition`] node.
+    fn leave_yul_function_definition(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulIfStatement`] node.
+    fn enter_yul_if_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulIfStatement`] node.
+    fn leave_yul_if_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulLabel`] node.
+    fn enter_yul_label(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulLabel`] node.
+    fn leave_yul_label(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulLeaveStatement`] node.
+    fn enter_yul_leave_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulLeaveStatement`] node.
+    fn leave_yul_leave_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulLiteral`] node.
+    fn enter_yul_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulLiteral`] node.
+    fn leave_yul_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulParameters`] node.
+    fn enter_yul_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulParameters`] node.
+    fn leave_yul_parameters(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulParametersDeclaration`] node.
+    fn enter_yul_parameters_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulParametersDeclaration`] node.
+    fn leave_yul_parameters_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulPath`] node.
+    fn enter_yul_path(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulPath`] node.
+    fn leave_yul_path(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulPathComponent`] node.
+    fn enter_yul_path_component(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulPathComponent`] node.
+    fn leave_yul_path_component(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulPaths`] node.
+    fn enter_yul_paths(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulPaths`] node.
+    fn leave_yul_paths(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulReturnVariables`] node.
+    fn enter_yul_return_variables(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulReturnVariables`] node.
+    fn leave_yul_return_variables(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulReturnsDeclaration`] node.
+    fn enter_yul_returns_declaration(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulReturnsDeclaration`] node.
+    fn leave_yul_returns_declaration(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulStatement`] node.
+    fn enter_yul_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulStatement`] node.
+    fn leave_yul_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulStatements`] node.
+    fn enter_yul_statements(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulStatements`] node.
+    fn leave_yul_statements(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulSwitchCase`] node.
+    fn enter_yul_switch_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulSwitchCase`] node.
+    fn leave_yul_switch_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulSwitchCases`] node.
+    fn enter_yul_switch_cases(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulSwitchCases`] node.
+    fn leave_yul_switch_cases(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulSwitchStatement`] node.
+    fn enter_yul_switch_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulSwitchStatement`] node.
+    fn leave_yul_switch_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulValueCase`] node.
+    fn enter_yul_value_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulValueCase`] node.
+    fn leave_yul_value_case(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulVariableDeclarationStatement`] node.
+    fn enter_yul_variable_declaration_statement(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulVariableDeclarationStatement`] node.
+    fn leave_yul_variable_declaration_statement(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::YulVariableDeclarationValue`] node.
+    fn enter_yul_variable_declaration_value(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::YulVariableDeclarationValue`] node.
+    fn leave_yul_variable_declaration_value(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SKIPPED`] node.
+    fn visit_skipped(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MISSING`] node.
+    fn visit_missing(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AbicoderKeyword`] node.
+    fn visit_abicoder_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AbstractKeyword`] node.
+    fn visit_abstract_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AddressKeyword`] node.
+    fn visit_address_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AfterKeyword`] node.
+    fn visit_after_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AliasKeyword`] node.
+    fn visit_alias_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Ampersand`] node.
+    fn visit_ampersand(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AmpersandAmpersand`] node.
+    fn visit_ampersand_ampersand(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AmpersandEqual`] node.
+    fn visit_ampersand_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AnonymousKeyword`] node.
+    fn visit_anonymous_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ApplyKeyword`] node.
+    fn visit_apply_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AsKeyword`] node.
+    fn visit_as_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AssemblyKeyword`] node.
+    fn visit_assembly_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Asterisk`] node.
+    fn visit_asterisk(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AsteriskAsterisk`] node.
+    fn visit_asterisk_asterisk(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AsteriskEqual`] node.
+    fn visit_asterisk_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::AutoKeyword`] node.
+    fn visit_auto_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Bang`] node.
+    fn visit_bang(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BangEqual`] node.
+    fn visit_bang_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Bar`] node.
+    fn visit_bar(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BarBar`] node.
+    fn visit_bar_bar(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BarEqual`] node.
+    fn visit_bar_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BoolKeyword`] node.
+    fn visit_bool_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BreakKeyword`] node.
+    fn visit_break_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ByteKeyword`] node.
+    fn visit_byte_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::BytesKeyword`] node.
+    fn visit_bytes_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CallDataKeyword`] node.
+    fn visit_call_data_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Caret`] node.
+    fn visit_caret(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CaretEqual`] node.
+    fn visit_caret_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CaseKeyword`] node.
+    fn visit_case_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CatchKeyword`] node.
+    fn visit_catch_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CloseBrace`] node.
+    fn visit_close_brace(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CloseBracket`] node.
+    fn visit_close_bracket(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CloseParen`] node.
+    fn visit_close_paren(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Colon`] node.
+    fn visit_colon(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ColonEqual`] node.
+    fn visit_colon_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Comma`] node.
+    fn visit_comma(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ConstantKeyword`] node.
+    fn visit_constant_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ConstructorKeyword`] node.
+    fn visit_constructor_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ContinueKeyword`] node.
+    fn visit_continue_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ContractKeyword`] node.
+    fn visit_contract_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CopyOfKeyword`] node.
+    fn visit_copy_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DaysKeyword`] node.
+    fn visit_days_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DecimalLiteral`] node.
+    fn visit_decimal_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DefaultKeyword`] node.
+    fn visit_default_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DefineKeyword`] node.
+    fn visit_define_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DeleteKeyword`] node.
+    fn visit_delete_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DoKeyword`] node.
+    fn visit_do_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DoubleQuotedHexStringLiteral`] node.
+    fn visit_double_quoted_hex_string_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DoubleQuotedStringLiteral`] node.
+    fn visit_double_quoted_string_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DoubleQuotedUnicodeStringLiteral`] node.
+    fn visit_double_quoted_unicode_string_literal(
+        &mut self,
+        node: &Rc<TokenNode>,
+        cursor: &Cursor,
+    ) {
+    }
+
+    /// Called when visiting a [`TokenKind::DoubleQuotedVersionLiteral`] node.
+    fn visit_double_quoted_version_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ElseKeyword`] node.
+    fn visit_else_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EmitKeyword`] node.
+    fn visit_emit_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EndOfLine`] node.
+    fn visit_end_of_line(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EnumKeyword`] node.
+    fn visit_enum_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Equal`] node.
+    fn visit_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EqualEqual`] node.
+    fn visit_equal_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EqualGreaterThan`] node.
+    fn visit_equal_greater_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ErrorKeyword`] node.
+    fn visit_error_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EtherKeyword`] node.
+    fn visit_ether_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EventKeyword`] node.
+    fn visit_event_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ExperimentalKeyword`] node.
+    fn visit_experimental_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ExternalKeyword`] node.
+    fn visit_external_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FallbackKeyword`] node.
+    fn visit_fallback_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FalseKeyword`] node.
+    fn visit_false_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FinalKeyword`] node.
+    fn visit_final_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FinneyKeyword`] node.
+    fn visit_finney_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FixedKeyword`] node.
+    fn visit_fixed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ForKeyword`] node.
+    fn visit_for_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FromKeyword`] node.
+    fn visit_from_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::FunctionKeyword`] node.
+    fn visit_function_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GlobalKeyword`] node.
+    fn visit_global_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GreaterThan`] node.
+    fn visit_greater_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GreaterThanEqual`] node.
+    fn visit_greater_than_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GreaterThanGreaterThan`] node.
+    fn visit_greater_than_greater_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GreaterThanGreaterThanEqual`] node.
+    fn visit_greater_than_greater_than_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::GreaterThanGreaterThanGreaterThan`] node.
+    fn visit_greater_than_greater_than_greater_than(
+        &mut self,
+        node: &Rc<TokenNode>,
+        cursor: &Cursor,
+    ) {
+    }
+
+    /// Called when visiting a [`TokenKind::GreaterThanGreaterThanGreaterThanEqual`] node.
+    fn visit_greater_than_greater_than_greater_than_equal(
+        &mut self,
+        node: &Rc<TokenNode>,
+        cursor: &Cursor,
+    ) {
+    }
+
+    /// Called when visiting a [`TokenKind::GweiKeyword`] node.
+    fn visit_gwei_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::HexKeyword`] node.
+    fn visit_hex_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::HexLiteral`] node.
+    fn visit_hex_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::HoursKeyword`] node.
+    fn visit_hours_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Identifier`] node.
+    fn visit_identifier(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::IfKeyword`] node.
+    fn visit_if_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ImmutableKeyword`] node.
+    fn visit_immutable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ImplementsKeyword`] node.
+    fn visit_implements_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ImportKeyword`] node.
+    fn visit_import_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::InKeyword`] node.
+    fn visit_in_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::IndexedKeyword`] node.
+    fn visit_indexed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::InlineKeyword`] node.
+    fn visit_inline_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::IntKeyword`] node.
+    fn visit_int_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::InterfaceKeyword`] node.
+    fn visit_interface_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::InternalKeyword`] node.
+    fn visit_internal_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::IsKeyword`] node.
+    fn visit_is_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LessThan`] node.
+    fn visit_less_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LessThanEqual`] node.
+    fn visit_less_than_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LessThanLessThan`] node.
+    fn visit_less_than_less_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LessThanLessThanEqual`] node.
+    fn visit_less_than_less_than_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LetKeyword`] node.
+    fn visit_let_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::LibraryKeyword`] node.
+    fn visit_library_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MacroKeyword`] node.
+    fn visit_macro_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MappingKeyword`] node.
+    fn visit_mapping_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MatchKeyword`] node.
+    fn visit_match_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MemoryKeyword`] node.
+    fn visit_memory_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Minus`] node.
+    fn visit_minus(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MinusEqual`] node.
+    fn visit_minus_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MinusGreaterThan`] node.
+    fn visit_minus_greater_than(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MinusMinus`] node.
+    fn visit_minus_minus(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MinutesKeyword`] node.
+    fn visit_minutes_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ModifierKeyword`] node.
+    fn visit_modifier_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MultiLineComment`] node.
+    fn visit_multi_line_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MultiLineNatSpecComment`] node.
+    fn visit_multi_line_nat_spec_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MutableKeyword`] node.
+    fn visit_mutable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::NewKeyword`] node.
+    fn visit_new_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::NullKeyword`] node.
+    fn visit_null_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OfKeyword`] node.
+    fn visit_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OpenBrace`] node.
+    fn visit_open_brace(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OpenBracket`] node.
+    fn visit_open_bracket(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OpenParen`] node.
+    fn visit_open_paren(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OverrideKeyword`] node.
+    fn visit_override_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PartialKeyword`] node.
+    fn visit_partial_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PayableKeyword`] node.
+    fn visit_payable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Percent`] node.
+    fn visit_percent(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PercentEqual`] node.
+    fn visit_percent_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Period`] node.
+    fn visit_period(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Plus`] node.
+    fn visit_plus(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PlusEqual`] node.
+    fn visit_plus_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PlusPlus`] node.
+    fn visit_plus_plus(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PragmaKeyword`] node.
+    fn visit_pragma_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PrivateKeyword`] node.
+    fn visit_private_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PromiseKeyword`] node.
+    fn visit_promise_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PublicKeyword`] node.
+    fn visit_public_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::PureKeyword`] node.
+    fn visit_pure_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::QuestionMark`] node.
+    fn visit_question_mark(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ReceiveKeyword`] node.
+    fn visit_receive_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ReferenceKeyword`] node.
+    fn visit_reference_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::RelocatableKeyword`] node.
+    fn visit_relocatable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ReturnKeyword`] node.
+    fn visit_return_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ReturnsKeyword`] node.
+    fn visit_returns_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::RevertKeyword`] node.
+    fn visit_revert_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SealedKeyword`] node.
+    fn visit_sealed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SecondsKeyword`] node.
+    fn visit_seconds_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Semicolon`] node.
+    fn visit_semicolon(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleLineComment`] node.
+    fn visit_single_line_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleLineNatSpecComment`] node.
+    fn visit_single_line_nat_spec_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleQuotedHexStringLiteral`] node.
+    fn visit_single_quoted_hex_string_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleQuotedStringLiteral`] node.
+    fn visit_single_quoted_string_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleQuotedUnicodeStringLiteral`] node.
+    fn visit_single_quoted_unicode_string_literal(
+        &mut self,
+        node: &Rc<TokenNode>,
+        cursor: &Cursor,
+    ) {
+    }
+
+    /// Called when visiting a [`TokenKind::SingleQuotedVersionLiteral`] node.
+    fn visit_single_quoted_version_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SizeOfKeyword`] node.
+    fn visit_size_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Slash`] node.
+    fn visit_slash(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SlashEqual`] node.
+    fn visit_slash_equal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SolidityKeyword`] node.
+    fn visit_solidity_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::StaticKeyword`] node.
+    fn visit_static_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::StorageKeyword`] node.
+    fn visit_storage_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::StringKeyword`] node.
+    fn visit_string_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::StructKeyword`] node.
+    fn visit_struct_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SupportsKeyword`] node.
+    fn visit_supports_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SwitchKeyword`] node.
+    fn visit_switch_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SzaboKeyword`] node.
+    fn visit_szabo_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ThrowKeyword`] node.
+    fn visit_throw_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Tilde`] node.
+    fn visit_tilde(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TrueKeyword`] node.
+    fn visit_true_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TryKeyword`] node.
+    fn visit_try_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TypeDefKeyword`] node.
+    fn visit_type_def_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TypeKeyword`] node.
+    fn visit_type_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TypeOfKeyword`] node.
+    fn visit_type_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::UfixedKeyword`] node.
+    fn visit_ufixed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::UintKeyword`] node.
+    fn visit_uint_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::UncheckedKeyword`] node.
+    fn visit_unchecked_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::UsingKeyword`] node.
+    fn visit_using_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::VarKeyword`] node.
+    fn visit_var_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::VersionSpecifier`] node.
+    fn visit_version_specifier(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::ViewKeyword`] node.
+    fn visit_view_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::VirtualKeyword`] node.
+    fn visit_virtual_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::WeeksKeyword`] node.
+    fn visit_weeks_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::WeiKeyword`] node.
+    fn visit_wei_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::WhileKeyword`] node.
+    fn visit_while_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Whitespace`] node.
+    fn visit_whitespace(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YearsKeyword`] node.
+    fn visit_years_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAbstractKeyword`] node.
+    fn visit_yul_abstract_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAddKeyword`] node.
+    fn visit_yul_add_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAddModKeyword`] node.
+    fn visit_yul_add_mod_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAddressKeyword`] node.
+    fn visit_yul_address_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAfterKeyword`] node.
+    fn visit_yul_after_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAliasKeyword`] node.
+    fn visit_yul_alias_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAndKeyword`] node.
+    fn visit_yul_and_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAnonymousKeyword`] node.
+    fn visit_yul_anonymous_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulApplyKeyword`] node.
+    fn visit_yul_apply_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAsKeyword`] node.
+    fn visit_yul_as_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAssemblyKeyword`] node.
+    fn visit_yul_assembly_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulAutoKeyword`] node.
+    fn visit_yul_auto_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBalanceKeyword`] node.
+    fn visit_yul_balance_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBaseFeeKeyword`] node.
+    fn visit_yul_base_fee_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBlobBaseFeeKeyword`] node.
+    fn visit_yul_blob_base_fee_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBlobHashKeyword`] node.
+    fn visit_yul_blob_hash_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBlockHashKeyword`] node.
+    fn visit_yul_block_hash_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBoolKeyword`] node.
+    fn visit_yul_bool_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBreakKeyword`] node.
+    fn visit_yul_break_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulByteKeyword`] node.
+    fn visit_yul_byte_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulBytesKeyword`] node.
+    fn visit_yul_bytes_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallCodeKeyword`] node.
+    fn visit_yul_call_code_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallDataCopyKeyword`] node.
+    fn visit_yul_call_data_copy_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallDataKeyword`] node.
+    fn visit_yul_call_data_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallDataLoadKeyword`] node.
+    fn visit_yul_call_data_load_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallDataSizeKeyword`] node.
+    fn visit_yul_call_data_size_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallKeyword`] node.
+    fn visit_yul_call_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallValueKeyword`] node.
+    fn visit_yul_call_value_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCallerKeyword`] node.
+    fn visit_yul_caller_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCaseKeyword`] node.
+    fn visit_yul_case_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCatchKeyword`] node.
+    fn visit_yul_catch_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulChainIdKeyword`] node.
+    fn visit_yul_chain_id_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCoinBaseKeyword`] node.
+    fn visit_yul_coin_base_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulConstantKeyword`] node.
+    fn visit_yul_constant_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulConstructorKeyword`] node.
+    fn visit_yul_constructor_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulContinueKeyword`] node.
+    fn visit_yul_continue_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulContractKeyword`] node.
+    fn visit_yul_contract_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCopyOfKeyword`] node.
+    fn visit_yul_copy_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCreate2Keyword`] node.
+    fn visit_yul_create_2_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulCreateKeyword`] node.
+    fn visit_yul_create_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDaysKeyword`] node.
+    fn visit_yul_days_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDecimalLiteral`] node.
+    fn visit_yul_decimal_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDefaultKeyword`] node.
+    fn visit_yul_default_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDefineKeyword`] node.
+    fn visit_yul_define_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDelegateCallKeyword`] node.
+    fn visit_yul_delegate_call_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDeleteKeyword`] node.
+    fn visit_yul_delete_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDifficultyKeyword`] node.
+    fn visit_yul_difficulty_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDivKeyword`] node.
+    fn visit_yul_div_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulDoKeyword`] node.
+    fn visit_yul_do_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulElseKeyword`] node.
+    fn visit_yul_else_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulEmitKeyword`] node.
+    fn visit_yul_emit_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulEnumKeyword`] node.
+    fn visit_yul_enum_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulEqKeyword`] node.
+    fn visit_yul_eq_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulEtherKeyword`] node.
+    fn visit_yul_ether_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulEventKeyword`] node.
+    fn visit_yul_event_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulExpKeyword`] node.
+    fn visit_yul_exp_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulExtCodeCopyKeyword`] node.
+    fn visit_yul_ext_code_copy_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulExtCodeHashKeyword`] node.
+    fn visit_yul_ext_code_hash_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulExtCodeSizeKeyword`] node.
+    fn visit_yul_ext_code_size_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulExternalKeyword`] node.
+    fn visit_yul_external_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFallbackKeyword`] node.
+    fn visit_yul_fallback_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFalseKeyword`] node.
+    fn visit_yul_false_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFinalKeyword`] node.
+    fn visit_yul_final_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFinneyKeyword`] node.
+    fn visit_yul_finney_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFixedKeyword`] node.
+    fn visit_yul_fixed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulForKeyword`] node.
+    fn visit_yul_for_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulFunctionKeyword`] node.
+    fn visit_yul_function_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulGasKeyword`] node.
+    fn visit_yul_gas_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulGasLimitKeyword`] node.
+    fn visit_yul_gas_limit_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulGasPriceKeyword`] node.
+    fn visit_yul_gas_price_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulGtKeyword`] node.
+    fn visit_yul_gt_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulGweiKeyword`] node.
+    fn visit_yul_gwei_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulHexKeyword`] node.
+    fn visit_yul_hex_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulHexLiteral`] node.
+    fn visit_yul_hex_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulHoursKeyword`] node.
+    fn visit_yul_hours_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIdentifier`] node.
+    fn visit_yul_identifier(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIfKeyword`] node.
+    fn visit_yul_if_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulImmutableKeyword`] node.
+    fn visit_yul_immutable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulImplementsKeyword`] node.
+    fn visit_yul_implements_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulImportKeyword`] node.
+    fn visit_yul_import_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulInKeyword`] node.
+    fn visit_yul_in_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIndexedKeyword`] node.
+    fn visit_yul_indexed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulInlineKeyword`] node.
+    fn visit_yul_inline_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIntKeyword`] node.
+    fn visit_yul_int_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulInterfaceKeyword`] node.
+    fn visit_yul_interface_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulInternalKeyword`] node.
+    fn visit_yul_internal_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulInvalidKeyword`] node.
+    fn visit_yul_invalid_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIsKeyword`] node.
+    fn visit_yul_is_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulIsZeroKeyword`] node.
+    fn visit_yul_is_zero_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulKeccak256Keyword`] node.
+    fn visit_yul_keccak_256_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLeaveKeyword`] node.
+    fn visit_yul_leave_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLetKeyword`] node.
+    fn visit_yul_let_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLibraryKeyword`] node.
+    fn visit_yul_library_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLog0Keyword`] node.
+    fn visit_yul_log_0_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLog1Keyword`] node.
+    fn visit_yul_log_1_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLog2Keyword`] node.
+    fn visit_yul_log_2_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLog3Keyword`] node.
+    fn visit_yul_log_3_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLog4Keyword`] node.
+    fn visit_yul_log_4_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulLtKeyword`] node.
+    fn visit_yul_lt_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMCopyKeyword`] node.
+    fn visit_yul_m_copy_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMLoadKeyword`] node.
+    fn visit_yul_m_load_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMSizeKeyword`] node.
+    fn visit_yul_m_size_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMStore8Keyword`] node.
+    fn visit_yul_m_store_8_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMStoreKeyword`] node.
+    fn visit_yul_m_store_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMacroKeyword`] node.
+    fn visit_yul_macro_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMappingKeyword`] node.
+    fn visit_yul_mapping_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMatchKeyword`] node.
+    fn visit_yul_match_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMemoryKeyword`] node.
+    fn visit_yul_memory_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMinutesKeyword`] node.
+    fn visit_yul_minutes_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulModKeyword`] node.
+    fn visit_yul_mod_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulModifierKeyword`] node.
+    fn visit_yul_modifier_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMulKeyword`] node.
+    fn visit_yul_mul_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMulModKeyword`] node.
+    fn visit_yul_mul_mod_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulMutableKeyword`] node.
+    fn visit_yul_mutable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulNewKeyword`] node.
+    fn visit_yul_new_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulNotKeyword`] node.
+    fn visit_yul_not_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulNullKeyword`] node.
+    fn visit_yul_null_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulNumberKeyword`] node.
+    fn visit_yul_number_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulOfKeyword`] node.
+    fn visit_yul_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulOrKeyword`] node.
+    fn visit_yul_or_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulOriginKeyword`] node.
+    fn visit_yul_origin_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulOverrideKeyword`] node.
+    fn visit_yul_override_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPartialKeyword`] node.
+    fn visit_yul_partial_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPayableKeyword`] node.
+    fn visit_yul_payable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPopKeyword`] node.
+    fn visit_yul_pop_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPragmaKeyword`] node.
+    fn visit_yul_pragma_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPrevRandaoKeyword`] node.
+    fn visit_yul_prev_randao_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPrivateKeyword`] node.
+    fn visit_yul_private_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPromiseKeyword`] node.
+    fn visit_yul_promise_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPublicKeyword`] node.
+    fn visit_yul_public_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulPureKeyword`] node.
+    fn visit_yul_pure_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReceiveKeyword`] node.
+    fn visit_yul_receive_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReferenceKeyword`] node.
+    fn visit_yul_reference_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulRelocatableKeyword`] node.
+    fn visit_yul_relocatable_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReturnDataCopyKeyword`] node.
+    fn visit_yul_return_data_copy_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReturnDataSizeKeyword`] node.
+    fn visit_yul_return_data_size_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReturnKeyword`] node.
+    fn visit_yul_return_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulReturnsKeyword`] node.
+    fn visit_yul_returns_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulRevertKeyword`] node.
+    fn visit_yul_revert_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSDivKeyword`] node.
+    fn visit_yul_s_div_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSLoadKeyword`] node.
+    fn visit_yul_s_load_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSModKeyword`] node.
+    fn visit_yul_s_mod_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSStoreKeyword`] node.
+    fn visit_yul_s_store_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSarKeyword`] node.
+    fn visit_yul_sar_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSealedKeyword`] node.
+    fn visit_yul_sealed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSecondsKeyword`] node.
+    fn visit_yul_seconds_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSelfBalanceKeyword`] node.
+    fn visit_yul_self_balance_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSelfDestructKeyword`] node.
+    fn visit_yul_self_destruct_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSgtKeyword`] node.
+    fn visit_yul_sgt_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSha3Keyword`] node.
+    fn visit_yul_sha_3_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulShlKeyword`] node.
+    fn visit_yul_shl_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulShrKeyword`] node.
+    fn visit_yul_shr_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSignExtendKeyword`] node.
+    fn visit_yul_sign_extend_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSizeOfKeyword`] node.
+    fn visit_yul_size_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSltKeyword`] node.
+    fn visit_yul_slt_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStaticCallKeyword`] node.
+    fn visit_yul_static_call_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStaticKeyword`] node.
+    fn visit_yul_static_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStopKeyword`] node.
+    fn visit_yul_stop_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStorageKeyword`] node.
+    fn visit_yul_storage_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStringKeyword`] node.
+    fn visit_yul_string_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulStructKeyword`] node.
+    fn visit_yul_struct_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSubKeyword`] node.
+    fn visit_yul_sub_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSuicideKeyword`] node.
+    fn visit_yul_suicide_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSupportsKeyword`] node.
+    fn visit_yul_supports_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSwitchKeyword`] node.
+    fn visit_yul_switch_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulSzaboKeyword`] node.
+    fn visit_yul_szabo_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTLoadKeyword`] node.
+    fn visit_yul_t_load_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTStoreKeyword`] node.
+    fn visit_yul_t_store_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulThrowKeyword`] node.
+    fn visit_yul_throw_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTimestampKeyword`] node.
+    fn visit_yul_timestamp_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTrueKeyword`] node.
+    fn visit_yul_true_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTryKeyword`] node.
+    fn visit_yul_try_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTypeDefKeyword`] node.
+    fn visit_yul_type_def_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTypeKeyword`] node.
+    fn visit_yul_type_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulTypeOfKeyword`] node.
+    fn visit_yul_type_of_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulUfixedKeyword`] node.
+    fn visit_yul_ufixed_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulUintKeyword`] node.
+    fn visit_yul_uint_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulUncheckedKeyword`] node.
+    fn visit_yul_unchecked_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulUsingKeyword`] node.
+    fn visit_yul_using_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulVarKeyword`] node.
+    fn visit_yul_var_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulViewKeyword`] node.
+    fn visit_yul_view_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulVirtualKeyword`] node.
+    fn visit_yul_virtual_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulWeeksKeyword`] node.
+    fn visit_yul_weeks_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulWeiKeyword`] node.
+    fn visit_yul_wei_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulWhileKeyword`] node.
+    fn visit_yul_while_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulXorKeyword`] node.
+    fn visit_yul_xor_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::YulYearsKeyword`] node.
+    fn visit_yul_years_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+}
+
+/// Visits all nodes of the parse tree, calling the matching hooks of the `visitor`.
+pub fn walk(output: &ParseOutput, visitor: &mut impl Visitor) {
+    walk_cursor(&output.create_tree_cursor(), visitor);
+}
+
+/// Visits the node pointed to by the cursor, along with all of its descendants.
+pub fn walk_cursor(cursor: &Cursor, visitor: &mut impl Visitor) {
+    let mut cursor = cursor.clone();
+    let mut depth = 0_usize;
+
+    loop {
+        match cursor.node() {
+            Node::Rule(rule) => {
+                if enter(visitor, &rule, &cursor) == WalkControl::Continue
+                    && cursor.go_to_first_child()
+                {
+                    depth += 1;
+                    continue;
+                }
+            }
+            Node::Token(token) => {
+                visit(visitor, &token, &cursor);
+            }
+        }
+
+        // Leave the current node, and any ancestors that have no more children to visit:
+        loop {
+            if let Node::Rule(rule) = cursor.node() {
+                leave(visitor, &rule, &cursor);
+            }
+
+            if depth == 0 {
+                return;
+            }
+
+            if cursor.go_to_next_sibling() {
+                break;
+            }
+
+            cursor.go_to_parent();
+            depth -= 1;
+        }
+    }
+}
+
+fn enter(visitor: &mut impl Visitor, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+    let generic = visitor.enter_rule(node, cursor);
+
+    let specific = match node.kind {
+        RuleKind::ABICoderPragma => visitor.enter_abi_coder_pragma(node, cursor),
+        RuleKind::AdditiveExpression => visitor.enter_additive_expression(node, cursor),
+        RuleKind::AddressType => visitor.enter_address_type(node, cursor),
+        RuleKind::AndExpression => visitor.enter_and_expression(node, cursor),
+        RuleKind::ArgumentsDeclaration => visitor.enter_arguments_declaration(node, cursor),
+        RuleKind::ArrayExpression => visitor.enter_array_expression(node, cursor),
+        RuleKind::ArrayTypeName => visitor.enter_array_type_name(node, cursor),
+        RuleKind::ArrayValues => visitor.enter_array_values(node, cursor),
+        RuleKind::AssemblyFlags => visitor.enter_assembly_flags(node, cursor),
+        RuleKind::AssemblyFlagsDeclaration => {
+            visitor.enter_assembly_flags_declaration(node, cursor)
+        }
+        RuleKind::AssemblyStatement => visitor.enter_assembly_statement(node, cursor),
+        RuleKind::AssignmentExpression => visitor.enter_assignment_expression(node, cursor),
+        RuleKind::BitwiseAndExpression => visitor.enter_bitwise_and_expression(node, cursor),
+        RuleKind::BitwiseOrExpression => visitor.enter_bitwise_or_expression(node, cursor),
+        RuleKind::BitwiseXorExpression => visitor.enter_bitwise_xor_expression(node, cursor),
+        RuleKind::Block => visitor.enter_block(node, cursor),
+        RuleKind::BreakStatement => visitor.enter_break_statement(node, cursor),
+        RuleKind::CallOptions => visitor.enter_call_options(node, cursor),
+        RuleKind::CallOptionsExpression => visitor.enter_call_options_expression(node, cursor),
+        RuleKind::CatchClause => visitor.enter_catch_clause(node, cursor),
+        RuleKind::CatchClauseError => visitor.enter_catch_clause_error(node, cursor),
+        RuleKind::CatchClauses => visitor.enter_catch_clauses(node, cursor),
+        RuleKind::ComparisonExpression => visitor.enter_comparison_expression(node, cursor),
+        RuleKind::ConditionalExpression => visitor.enter_conditional_expression(node, cursor),
+        RuleKind::ConstantDefinition => visitor.enter_constant_definition(node, cursor),
+        RuleKind::ConstructorAttribute => visitor.enter_constructor_attribute(node, cursor),
+        RuleKind::ConstructorAttributes => visitor.enter_constructor_attributes(node, cursor),
+        RuleKind::ConstructorDefinition => visitor.enter_constructor_definition(node, cursor),
+        RuleKind::ContinueStatement => visitor.enter_continue_statement(node, cursor),
+        RuleKind::ContractDefinition => visitor.enter_contract_definition(node, cursor),
+        RuleKind::ContractMember => visitor.enter_contract_member(node, cursor),
+        RuleKind::ContractMembers => visitor.enter_contract_members(node, cursor),
+        RuleKind::DecimalNumberExpression => visitor.enter_decimal_number_expression(node, cursor),
+        RuleKind::DoWhileStatement => visitor.enter_do_while_statement(node, cursor),
+        RuleKind::ElementaryType => visitor.enter_elementary_type(node, cursor),
+        RuleKind::ElseBranch => visitor.enter_else_branch(node, cursor),
+        RuleKind::EmitStatement => visitor.enter_emit_statement(node, cursor),
+        RuleKind::EnumDefinition => visitor.enter_enum_definition(node, cursor),
+        RuleKind::EnumMembers => visitor.enter_enum_members(node, cursor),
+        RuleKind::EqualityExpression => visitor.enter_equality_expression(node, cursor),
+        RuleKind::ErrorDefinition => visitor.enter_error_definition(node, cursor),
+        RuleKind::ErrorParameter => visitor.enter_error_parameter(node, cursor),
+        RuleKind::ErrorParameters => visitor.enter_error_parameters(node, cursor),
+        RuleKind::ErrorParametersDeclaration => {
+            visitor.enter_error_parameters_declaration(node, cursor)
+        }
+        RuleKind::EventDefinition => visitor.enter_event_definition(node, cursor),
+        RuleKind::EventParameter => visitor.enter_event_parameter(node, cursor),
+        RuleKind::EventParameters => visitor.enter_event_parameters(node, cursor),
+        RuleKind::EventParametersDeclaration => {
+            visitor.enter_event_parameters_declaration(node, cursor)
+        }
+        RuleKind::ExperimentalFeature => visitor.enter_experimental_feature(node, cursor),
+        RuleKind::ExperimentalPragma => visitor.enter_experimental_pragma(node, cursor),
+        RuleKind::ExponentiationExpression => visitor.enter_exponentiation_expression(node, cursor),
+        RuleKind::Expression => visitor.enter_expression(node, cursor),
+        RuleKind::ExpressionStatement => visitor.enter_expression_statement(node, cursor),
+        RuleKind::FallbackFunctionAttribute => {
+            visitor.enter_fallback_function_attribute(node, cursor)
+        }
+        RuleKind::FallbackFunctionAttributes => {
+            visitor.enter_fallback_function_attributes(node, cursor)
+        }
+        RuleKind::FallbackFunctionDefinition => {
+            visitor.enter_fallback_function_definition(node, cursor)
+        }
+        RuleKind::ForStatement => visitor.enter_for_statement(node, cursor),
+        RuleKind::ForStatementCondition => visitor.enter_for_statement_condition(node, cursor),
+        RuleKind::ForStatementInitialization => {
+            visitor.enter_for_statement_initialization(node, cursor)
+        }
+        RuleKind::FunctionAttribute => visitor.enter_function_attribute(node, cursor),
+        RuleKind::FunctionAttributes => visitor.enter_function_attributes(node, cursor),
+        RuleKind::FunctionBody => visitor.enter_function_body(node, cursor),
+        RuleKind::FunctionCallExpression => visitor.enter_function_call_expression(node, cursor),
+        RuleKind::FunctionDefinition => visitor.enter_function_definition(node, cursor),
+        RuleKind::FunctionName => visitor.enter_function_name(node, cursor),
+        RuleKind::FunctionType => visitor.enter_function_type(node, cursor),
+        RuleKind::FunctionTypeAttribute => visitor.enter_function_type_attribute(node, cursor),
+        RuleKind::FunctionTypeAttributes => visitor.enter_function_type_attributes(node, cursor),
+        RuleKind::HexNumberExpression => visitor.enter_hex_number_expression(node, cursor),
+        RuleKind::HexStringLiteral => visitor.enter_hex_string_literal(node, cursor),
+        RuleKind::HexStringLiterals => visitor.enter_hex_string_literals(node, cursor),
+        RuleKind::IdentifierPath => visitor.enter_identifier_path(node, cursor),
+        RuleKind::IfStatement => visitor.enter_if_statement(node, cursor),
+        RuleKind::ImportAlias => visitor.enter_import_alias(node, cursor),
+        RuleKind::ImportClause => visitor.enter_import_clause(node, cursor),
+        RuleKind::ImportDeconstruction => visitor.enter_import_deconstruction(node, cursor),
+        RuleKind::ImportDeconstructionSymbol => {
+            visitor.enter_import_deconstruction_symbol(node, cursor)
+        }
+        RuleKind::ImportDeconstructionSymbols => {
+            visitor.enter_import_deconstruction_symbols(node, cursor)
+        }
+        RuleKind::ImportDirective => visitor.enter_import_directive(node, cursor),
+        RuleKind::IndexAccessEnd => visitor.enter_index_access_end(node, cursor),
+        RuleKind::IndexAccessExpression => visitor.enter_index_access_expression(node, cursor),
+        RuleKind::InheritanceSpecifier => visitor.enter_inheritance_specifier(node, cursor),
+        RuleKind::InheritanceType => visitor.enter_inheritance_type(node, cursor),
+        RuleKind::InheritanceTypes => visitor.enter_inheritance_types(node, cursor),
+        RuleKind::InterfaceDefinition => visitor.enter_interface_definition(node, cursor),
+        RuleKind::InterfaceMembers => visitor.enter_interface_members(node, cursor),
+        RuleKind::LibraryDefinition => visitor.enter_library_definition(node, cursor),
+        RuleKind::LibraryMembers => visitor.enter_library_members(node, cursor),
+        RuleKind::MappingKey => visitor.enter_mapping_key(node, cursor),
+        RuleKind::MappingKeyType => visitor.enter_mapping_key_type(node, cursor),
+        RuleKind::MappingType => visitor.enter_mapping_type(node, cursor),
+        RuleKind::MappingValue => visitor.enter_mapping_value(node, cursor),
+        RuleKind::MemberAccess => visitor.enter_member_access(node, cursor),
+        RuleKind::MemberAccessExpression => visitor.enter_member_access_expression(node, cursor),
+        RuleKind::ModifierAttribute => visitor.enter_modifier_attribute(node, cursor),
+        RuleKind::ModifierAttributes => visitor.enter_modifier_attributes(node, cursor),
+        RuleKind::ModifierDefinition => visitor.enter_modifier_definition(node, cursor),
+        RuleKind::ModifierInvocation => visitor.enter_modifier_invocation(node, cursor),
+        RuleKind::MultiplicativeExpression => visitor.enter_multiplicative_expression(node, cursor),
+        RuleKind::NamedArgument => visitor.enter_named_argument(node, cursor),
+        RuleKind::NamedArgumentGroup => visitor.enter_named_argument_group(node, cursor),
+        RuleKind::NamedArguments => visitor.enter_named_arguments(node, cursor),
+        RuleKind::NamedArgumentsDeclaration => {
+            visitor.enter_named_arguments_declaration(node, cursor)
+        }
+        RuleKind::NamedImport => visitor.enter_named_import(node, cursor),
+        RuleKind::NewExpression => visitor.enter_new_expression(node, cursor),
+        RuleKind::NumberUnit => visitor.enter_number_unit(node, cursor),
+        RuleKind::OrExpression => visitor.enter_or_expression(node, cursor),
+        RuleKind::OverridePaths => visitor.enter_override_paths(node, cursor),
+        RuleKind::OverridePathsDeclaration => {
+            visitor.enter_override_paths_declaration(node, cursor)
+        }
+        RuleKind::OverrideSpecifier => visitor.enter_override_specifier(node, cursor),
+        RuleKind::Parameter => visitor.enter_parameter(node, cursor),
+        RuleKind::Parameters => visitor.enter_parameters(node, cursor),
+        RuleKind::ParametersDeclaration => visitor.enter_parameters_declaration(node, cursor),
+        RuleKind::PathImport => visitor.enter_path_import(node, cursor),
+        RuleKind::PositionalArguments => visitor.enter_positional_arguments(node, cursor),
+        RuleKind::PositionalArgumentsDeclaration => {
+            visitor.enter_positional_arguments_declaration(node, cursor)
+        }
+        RuleKind::PostfixExpression => visitor.enter_postfix_expression(node, cursor),
+        RuleKind::Pragma => visitor.enter_pragma(node, cursor),
+        RuleKind::PragmaDirective => visitor.enter_pragma_directive(node, cursor),
+        RuleKind::PrefixExpression => visitor.enter_prefix_expression(node, cursor),
+        RuleKind::ReceiveFunctionAttribute => {
+            visitor.enter_receive_function_attribute(node, cursor)
+        }
+        RuleKind::ReceiveFunctionAttributes => {
+            visitor.enter_receive_function_attributes(node, cursor)
+        }
+        RuleKind::ReceiveFunctionDefinition => {
+            visitor.enter_receive_function_definition(node, cursor)
+        }
+        RuleKind::ReturnStatement => visitor.enter_return_statement(node, cursor),
+        RuleKind::ReturnsDeclaration => visitor.enter_returns_declaration(node, cursor),
+        RuleKind::RevertStatement => visitor.enter_revert_statement(node, cursor),
+        RuleKind::ShiftExpression => visitor.enter_shift_expression(node, cursor),
+        RuleKind::SourceUnit => visitor.enter_source_unit(node, cursor),
+        RuleKind::SourceUnitMember => visitor.enter_source_unit_member(node, cursor),
+        RuleKind::SourceUnitMembers => visitor.enter_source_unit_members(node, cursor),
+        RuleKind::StateVariableAttribute => visitor.enter_state_variable_attribute(node, cursor),
+        RuleKind::StateVariableAttributes => visitor.enter_state_variable_attributes(node, cursor),
+        RuleKind::StateVariableDefinition => visitor.enter_state_variable_definition(node, cursor),
+        RuleKind::StateVariableDefinitionValue => {
+            visitor.enter_state_variable_definition_value(node, cursor)
+        }
+        RuleKind::Statement => visitor.enter_statement(node, cursor),
+        RuleKind::Statements => visitor.enter_statements(node, cursor),
+        RuleKind::StorageLocation => visitor.enter_storage_location(node, cursor),
+        RuleKind::StringExpression => visitor.enter_string_expression(node, cursor),
+        RuleKind::StringLiteral => visitor.enter_string_literal(node, cursor),
+        RuleKind::StringLiterals => visitor.enter_string_literals(node, cursor),
+        RuleKind::StructDefinition => visitor.enter_struct_definition(node, cursor),
+        RuleKind::StructMember => visitor.enter_struct_member(node, cursor),
+        RuleKind::StructMembers => visitor.enter_struct_members(node, cursor),
+        RuleKind::ThrowStatement => visitor.enter_throw_statement(node, cursor),
+        RuleKind::TryStatement => visitor.enter_try_statement(node, cursor),
+        RuleKind::TupleDeconstructionElement => {
+            visitor.enter_tuple_deconstruction_element(node, cursor)
+        }
+        RuleKind::TupleDeconstructionElements => {
+            visitor.enter_tuple_deconstruction_elements(node, cursor)
+        }
+        RuleKind::TupleDeconstructionStatement => {
+            visitor.enter_tuple_deconstruction_statement(node, cursor)
+        }
+        RuleKind::TupleExpression => visitor.enter_tuple_expression(node, cursor),
+        RuleKind::TupleMember => visitor.enter_tuple_member(node, cursor),
+        RuleKind::TupleValue => visitor.enter_tuple_value(node, cursor),
+        RuleKind::TupleValues => visitor.enter_tuple_values(node, cursor),
+        RuleKind::TypeExpression => visitor.enter_type_expression(node, cursor),
+        RuleKind::TypeName => visitor.enter_type_name(node, cursor),
+        RuleKind::TypedTupleMember => visitor.enter_typed_tuple_member(node, cursor),
+        RuleKind::UncheckedBlock => visitor.enter_unchecked_block(node, cursor),
+        RuleKind::UnicodeStringLiteral => visitor.enter_unicode_string_literal(node, cursor),
+        RuleKind::UnicodeStringLiterals => visitor.enter_unicode_string_literals(node, cursor),
+        RuleKind::UnnamedFunctionAttribute => {
+            visitor.enter_unnamed_function_attribute(node, cursor)
+        }
+        RuleKind::UnnamedFunctionAttributes => {
+            visitor.enter_unnamed_function_attributes(node, cursor)
+        }
+        RuleKind::UnnamedFunctionDefinition => {
+            visitor.enter_unnamed_function_definition(node, cursor)
+        }
+        RuleKind::UntypedTupleMember => visitor.enter_untyped_tuple_member(node, cursor),
+        RuleKind::UserDefinedValueTypeDefinition => {
+            visitor.enter_user_defined_value_type_definition(node, cursor)
+        }
+        RuleKind::UsingAlias => visitor.enter_using_alias(node, cursor),
+        RuleKind::UsingClause => visitor.enter_using_clause(node, cursor),
+        RuleKind::UsingDeconstruction => visitor.enter_using_deconstruction(node, cursor),
+        RuleKind::UsingDeconstructionSymbol => {
+            visitor.enter_using_deconstruction_symbol(node, cursor)
+        }
+        RuleKind::UsingDeconstructionSymbols => {
+            visitor.enter_using_deconstruction_symbols(node, cursor)
+        }
+        RuleKind::UsingDirective => visitor.enter_using_directive(node, cursor),
+        RuleKind::UsingOperator => visitor.enter_using_operator(node, cursor),
+        RuleKind::UsingTarget => visitor.enter_using_target(node, cursor),
+        RuleKind::VariableDeclarationStatement => {
+            visitor.enter_variable_declaration_statement(node, cursor)
+        }
+        RuleKind::VariableDeclarationType => visitor.enter_variable_declaration_type(node, cursor),
+        RuleKind::VariableDeclarationValue => {
+            visitor.enter_variable_declaration_value(node, cursor)
+        }
+        RuleKind::VersionComparator => visitor.enter_version_comparator(node, cursor),
+        RuleKind::VersionExpression => visitor.enter_version_expression(node, cursor),
+        RuleKind::VersionExpressionSet => visitor.enter_version_expression_set(node, cursor),
+        RuleKind::VersionExpressionSets => visitor.enter_version_expression_sets(node, cursor),
+        RuleKind::VersionPragma => visitor.enter_version_pragma(node, cursor),
+        RuleKind::VersionRange => visitor.enter_version_range(node, cursor),
+        RuleKind::VersionSpecifiers => visitor.enter_version_specifiers(node, cursor),
+        RuleKind::WhileStatement => visitor.enter_while_statement(node, cursor),
+        RuleKind::YulArguments => visitor.enter_yul_arguments(node, cursor),
+        RuleKind::YulAssignmentOperator => visitor.enter_yul_assignment_operator(node, cursor),
+        RuleKind::YulAssignmentStatement => visitor.enter_yul_assignment_statement(node, cursor),
+        RuleKind::YulBlock => visitor.enter_yul_block(node, cursor),
+        RuleKind::YulBreakStatement => visitor.enter_yul_break_statement(node, cursor),
+        RuleKind::YulBuiltInFunction => visitor.enter_yul_built_in_function(node, cursor),
+        RuleKind::YulColonAndEqual => visitor.enter_yul_colon_and_equal(node, cursor),
+        RuleKind::YulContinueStatement => visitor.enter_yul_continue_statement(node, cursor),
+        RuleKind::YulDefaultCase => visitor.enter_yul_default_case(node, cursor),
+        RuleKind::YulExpression => visitor.enter_yul_expression(node, cursor),
+        RuleKind::YulForStatement => visitor.enter_yul_for_statement(node, cursor),
+        RuleKind::YulFunctionCallExpression => {
+            visitor.enter_yul_function_call_expression(node, cursor)
+        }
+        RuleKind::YulFunctionDefinition => visitor.enter_yul_function_definition(node, cursor),
+        RuleKind::YulIfStatement => visitor.enter_yul_if_statement(node, cursor),
+        RuleKind::YulLabel => visitor.enter_yul_label(node, cursor),
+        RuleKind::YulLeaveStatement => visitor.enter_yul_leave_statement(node, cursor),
+        RuleKind::YulLiteral => visitor.enter_yul_literal(node, cursor),
+        RuleKind::YulParameters => visitor.enter_yul_parameters(node, cursor),
+        RuleKind::YulParametersDeclaration => {
+            visitor.enter_yul_parameters_declaration(node, cursor)
+        }
+        RuleKind::YulPath => visitor.enter_yul_path(node, cursor),
+        RuleKind::YulPathComponent => visitor.enter_yul_path_component(node, cursor),
+        RuleKind::YulPaths => visitor.enter_yul_paths(node, cursor),
+        RuleKind::YulReturnVariables => visitor.enter_yul_return_variables(node, cursor),
+        RuleKind::YulReturnsDeclaration => visitor.enter_yul_returns_declaration(node, cursor),
+        RuleKind::YulStatement => visitor.enter_yul_statement(node, cursor),
+        RuleKind::YulStatements => visitor.enter_yul_statements(node, cursor),
+        RuleKind::YulSwitchCase => visitor.enter_yul_switch_case(node, cursor),
+        RuleKind::YulSwitchCases => visitor.enter_yul_switch_cases(node, cursor),
+        RuleKind::YulSwitchStatement => visitor.enter_yul_switch_statement(node, cursor),
+        RuleKind::YulValueCase => visitor.enter_yul_value_case(node, cursor),
+        RuleKind::YulVariableDeclarationStatement => {
+            visitor.enter_yul_variable_declaration_statement(node, cursor)
+        }
+        RuleKind::YulVariableDeclarationValue => {
+            visitor.enter_yul_variable_declaration_value(node, cursor)
+        }
+    };
+
+    if generic == WalkControl::SkipChildren || specific == WalkControl::SkipChildren {
+        WalkControl::SkipChildren
+    } else {
+        WalkControl::Continue
+    }
+}
+
+fn leave(visitor: &mut impl Visitor, node: &Rc<RuleNode>, cursor: &Cursor) {
+    match node.kind {
+        RuleKind::ABICoderPragma => {
+            visitor.leave_abi_coder_pragma(node, cursor);
+        }
+        RuleKind::AdditiveExpression => {
+            visitor.leave_additive_expression(node, cursor);
+        }
+        RuleKind::AddressType => {
+            visitor.leave_address_type(node, cursor);
+        }
+        RuleKind::AndExpression => {
+            visitor.leave_and_expression(node, cursor);
+        }
+        RuleKind::ArgumentsDeclaration => {
+            visitor.leave_arguments_declaration(node, cursor);
+        }
+        RuleKind::ArrayExpression => {
+            visitor.leave_array_expression(node, cursor);
+        }
+        RuleKind::ArrayTypeName => {
+            visitor.leave_array_type_name(node, cursor);
+        }
+        RuleKind::ArrayValues => {
+            visitor.leave_array_values(node, cursor);
+        }
+        RuleKind::AssemblyFlags => {
+            visitor.leave_assembly_flags(node, cursor);
+        }
+        RuleKind::AssemblyFlagsDeclaration => {
+            visitor.leave_assembly_flags_declaration(node, cursor);
+        }
+        RuleKind::AssemblyStatement => {
+            visitor.leave_assembly_statement(node, cursor);
+        }
+        RuleKind::AssignmentExpression => {
+            visitor.leave_assignment_expression(node, cursor);
+        }
+        RuleKind::BitwiseAndExpression => {
+            visitor.leave_bitwise_and_expression(node, cursor);
+        }
+        RuleKind::BitwiseOrExpression => {
+            visitor.leave_bitwise_or_expression(node, cursor);
+        }
+        RuleKind::BitwiseXorExpression => {
+            visitor.leave_bitwise_xor_expression(node, cursor);
+        }
+        RuleKind::Block => {
+            visitor.leave_block(node, cursor);
+        }
+        RuleKind::BreakStatement => {
+            visitor.leave_break_statement(node, cursor);
+        }
+        RuleKind::CallOptions => {
+            visitor.leave_call_options(node, cursor);
+        }
+        RuleKind::CallOptionsExpression => {
+            visitor.leave_call_options_expression(node, cursor);
+        }
+        RuleKind::CatchClause => {
+            visitor.leave_catch_clause(node, cursor);
+        }
+        RuleKind::CatchClauseError => {
+            visitor.leave_catch_clause_error(node, cursor);
+        }
+        RuleKind::CatchClauses => {
+            visitor.leave_catch_clauses(node, cursor);
+        }
+        RuleKind::ComparisonExpression => {
+            visitor.leave_comparison_expression(node, cursor);
+        }
+        RuleKind::ConditionalExpression => {
+            visitor.leave_conditional_expression(node, cursor);
+        }
+        RuleKind::ConstantDefinition => {
+            visitor.leave_constant_definition(node, cursor);
+        }
+        RuleKind::ConstructorAttribute => {
+            visitor.leave_constructor_attribute(node, cursor);
+        }
+        RuleKind::ConstructorAttributes => {
+            visitor.leave_constructor_attributes(node, cursor);
+        }
+        RuleKind::ConstructorDefinition => {
+            visitor.leave_constructor_definition(node, cursor);
+        }
+        RuleKind::ContinueStatement => {
+            visitor.leave_continue_statement(node, cursor);
+        }
+        RuleKind::ContractDefinition => {
+            visitor.leave_contract_definition(node, cursor);
+        }
+        RuleKind::ContractMember => {
+            visitor.leave_contract_member(node, cursor);
+        }
+        RuleKind::ContractMembers => {
+            visitor.leave_contract_members(node, cursor);
+        }
+        RuleKind::DecimalNumberExpression => {
+            visitor.leave_decimal_number_expression(node, cursor);
+        }
+        RuleKind::DoWhileStatement => {
+            visitor.leave_do_while_statement(node, cursor);
+        }
+        RuleKind::ElementaryType => {
+            visitor.leave_elementary_type(node, cursor);
+        }
+        RuleKind::ElseBranch => {
+            visitor.leave_else_branch(node, cursor);
+        }
+        RuleKind::EmitStatement => {
+            visitor.leave_emit_statement(node, cursor);
+        }
+        RuleKind::EnumDefinition => {
+            visitor.leave_enum_definition(node, cursor);
+        }
+        RuleKind::EnumMembers => {
+            visitor.leave_enum_members(node, cursor);
+        }
+        RuleKind::EqualityExpression => {
+            visitor.leave_equality_expression(node, cursor);
+        }
+        RuleKind::ErrorDefinition => {
+            visitor.leave_error_definition(node, cursor);
+        }
+        RuleKind::ErrorParameter => {
+            visitor.leave_error_parameter(node, cursor);
+        }
+        RuleKind::ErrorParameters => {
+            visitor.leave_error_parameters(node, cursor);
+        }
+        RuleKind::ErrorParametersDeclaration => {
+            visitor.leave_error_parameters_declaration(node, cursor);
+        }
+        RuleKind::EventDefinition => {
+            visitor.leave_event_definition(node, cursor);
+        }
+        RuleKind::EventParameter => {
+            visitor.leave_event_parameter(node, cursor);
+        }
+        RuleKind::EventParameters => {
+            visitor.leave_event_parameters(node, cursor);
+        }
+        RuleKind::EventParametersDeclaration => {
+            visitor.leave_event_parameters_declaration(node, cursor);
+        }
+        RuleKind::ExperimentalFeature => {
+            visitor.leave_experimental_feature(node, cursor);
+        }
+        RuleKind::ExperimentalPragma => {
+            visitor.leave_experimental_pragma(node, cursor);
+        }
+        RuleKind::ExponentiationExpression => {
+            visitor.leave_exponentiation_expression(node, cursor);
+        }
+        RuleKind::Expression => {
+            visitor.leave_expression(node, cursor);
+        }
+        RuleKind::ExpressionStatement => {
+            visitor.leave_expression_statement(node, cursor);
+        }
+        RuleKind::FallbackFunctionAttribute => {
+            visitor.leave_fallback_function_attribute(node, cursor);
+        }
+        RuleKind::FallbackFunctionAttributes => {
+            visitor.leave_fallback_function_attributes(node, cursor);
+        }
+        RuleKind::FallbackFunctionDefinition => {
+            visitor.leave_fallback_function_definition(node, cursor);
+        }
+        RuleKind::ForStatement => {
+            visitor.leave_for_statement(node, cursor);
+        }
+        RuleKind::ForStatementCondition => {
+            visitor.leave_for_statement_condition(node, cursor);
+        }
+        RuleKind::ForStatementInitialization => {
+            visitor.leave_for_statement_initialization(node, cursor);
+        }
+        RuleKind::FunctionAttribute => {
+            visitor.leave_function_attribute(node, cursor);
+        }
+        RuleKind::FunctionAttributes => {
+            visitor.leave_function_attributes(node, cursor);
+        }
+        RuleKind::FunctionBody => {
+            visitor.leave_function_body(node, cursor);
+        }
+        RuleKind::FunctionCallExpression => {
+            visitor.leave_function_call_expression(node, cursor);
+        }
+        RuleKind::FunctionDefinition => {
+            visitor.leave_function_definition(node, cursor);
+        }
+        RuleKind::FunctionName => {
+            visitor.leave_function_name(node, cursor);
+        }
+        RuleKind::FunctionType => {
+            visitor.leave_function_type(node, cursor);
+        }
+        RuleKind::FunctionTypeAttribute => {
+            visitor.leave_function_type_attribute(node, cursor);
+        }
+        RuleKind::FunctionTypeAttributes => {
+            visitor.leave_function_type_attributes(node, cursor);
+        }
+        RuleKind::HexNumberExpression => {
+            visitor.leave_hex_number_expression(node, cursor);
+        }
+        RuleKind::HexStringLiteral => {
+            visitor.leave_hex_string_literal(node, cursor);
+        }
+        RuleKind::HexStringLiterals => {
+            visitor.leave_hex_string_literals(node, cursor);
+        }
+        RuleKind::IdentifierPath => {
+            visitor.leave_identifier_path(node, cursor);
+        }
+        RuleKind::IfStatement => {
+            visitor.leave_if_statement(node, cursor);
+        }
+        RuleKind::ImportAlias => {
+            visitor.leave_import_alias(node, cursor);
+        }
+        RuleKind::ImportClause => {
+            visitor.leave_import_clause(node, cursor);
+        }
+        RuleKind::ImportDeconstruction => {
+            visitor.leave_import_deconstruction(node, cursor);
+        }
+        RuleKind::ImportDeconstructionSymbol => {
+            visitor.leave_import_deconstruction_symbol(node, cursor);
+        }
+        RuleKind::ImportDeconstructionSymbols => {
+            visitor.leave_import_deconstruction_symbols(node, cursor);
+        }
+        RuleKind::ImportDirective => {
+            visitor.leave_import_directive(node, cursor);
+        }
+        RuleKind::IndexAccessEnd => {
+            visitor.leave_index_access_end(node, cursor);
+        }
+        RuleKind::IndexAccessExpression => {
+            visitor.leave_index_access_expression(node, cursor);
+        }
+        RuleKind::InheritanceSpecifier => {
+            visitor.leave_inheritance_specifier(node, cursor);
+        }
+        RuleKind::InheritanceType => {
+            visitor.leave_inheritance_type(node, cursor);
+        }
+        RuleKind::InheritanceTypes => {
+            visitor.leave_inheritance_types(node, cursor);
+        }
+        RuleKind::InterfaceDefinition => {
+            visitor.leave_interface_definition(node, cursor);
+        }
+        RuleKind::InterfaceMembers => {
+            visitor.leave_interface_members(node, cursor);
+        }
+        RuleKind::LibraryDefinition => {
+            visitor.leave_library_definition(node, cursor);
+        }
+        RuleKind::LibraryMembers => {
+            visitor.leave_library_members(node, cursor);
+        }
+        RuleKind::MappingKey => {
+            visitor.leave_mapping_key(node, cursor);
+        }
+        RuleKind::MappingKeyType => {
+            visitor.leave_mapping_key_type(node, cursor);
+        }
+        RuleKind::MappingType => {
+            visitor.leave_mapping_type(node, cursor);
+        }
+        RuleKind::MappingValue => {
+            visitor.leave_mapping_value(node, cursor);
+        }
+        RuleKind::MemberAccess => {
+            visitor.leave_member_access(node, cursor);
+        }
+        RuleKind::MemberAccessExpression => {
+            visitor.leave_member_access_expression(node, cursor);
+        }
+        RuleKind::ModifierAttribute => {
+            visitor.leave_modifier_attribute(node, cursor);
+        }
+        RuleKind::ModifierAttributes => {
+            visitor.leave_modifier_attributes(node, cursor);
+        }
+        RuleKind::ModifierDefinition => {
+            visitor.leave_modifier_definition(node, cursor);
+        }
+        RuleKind::ModifierInvocation => {
+            visitor.leave_modifier_invocation(node, cursor);
+        }
+        RuleKind::MultiplicativeExpression => {
+            visitor.leave_multiplicative_expression(node, cursor);
+        }
+        RuleKind::NamedArgument => {
+            visitor.leave_named_argument(node, cursor);
+        }
+        RuleKind::NamedArgumentGroup => {
+            visitor.leave_named_argument_group(node, cursor);
+        }
+        RuleKind::NamedArguments => {
+            visitor.leave_named_arguments(node, cursor);
+        }
+        RuleKind::NamedArgumentsDeclaration => {
+            visitor.leave_named_arguments_declaration(node, cursor);
+        }
+        RuleKind::NamedImport => {
+            visitor.leave_named_import(node, cursor);
+        }
+        RuleKind::NewExpression => {
+            visitor.leave_new_expression(node, cursor);
+        }
+        RuleKind::NumberUnit => {
+            visitor.leave_number_unit(node, cursor);
+        }
+        RuleKind::OrExpression => {
+            visitor.leave_or_expression(node, cursor);
+        }
+        RuleKind::OverridePaths => {
+            visitor.leave_override_paths(node, cursor);
+        }
+        RuleKind::OverridePathsDeclaration => {
+            visitor.leave_override_paths_declaration(node, cursor);
+        }
+        RuleKind::OverrideSpecifier => {
+            visitor.leave_override_specifier(node, cursor);
+        }
+        RuleKind::Parameter => {
+            visitor.leave_parameter(node, cursor);
+        }
+        RuleKind::Parameters => {
+            visitor.leave_parameters(node, cursor);
+        }
+        RuleKind::ParametersDeclaration => {
+            visitor.leave_parameters_declaration(node, cursor);
+        }
+        RuleKind::PathImport => {
+            visitor.leave_path_import(node, cursor);
+        }
+        RuleKind::PositionalArguments => {
+            visitor.leave_positional_arguments(node, cursor);
+        }
+        RuleKind::PositionalArgumentsDeclaration => {
+            visitor.leave_positional_arguments_declaration(node, cursor);
+        }
+        RuleKind::PostfixExpression => {
+            visitor.leave_postfix_expression(node, cursor);
+        }
+        RuleKind::Pragma => {
+            visitor.leave_pragma(node, cursor);
+        }
+        RuleKind::PragmaDirective => {
+            visitor.leave_pragma_directive(node, cursor);
+        }
+        RuleKind::PrefixExpression => {
+            visitor.leave_prefix_expression(node, cursor);
+        }
+        RuleKind::ReceiveFunctionAttribute => {
+            visitor.leave_receive_function_attribute(node, cursor);
+        }
+        RuleKind::ReceiveFunctionAttributes => {
+            visitor.leave_receive_function_attributes(node, cursor);
+        }
+        RuleKind::ReceiveFunctionDefinition => {
+            visitor.leave_receive_function_definition(node, cursor);
+        }
+        RuleKind::ReturnStatement => {
+            visitor.leave_return_statement(node, cursor);
+        }
+        RuleKind::ReturnsDeclaration => {
+            visitor.leave_returns_declaration(node, cursor);
+        }
+        RuleKind::RevertStatement => {
+            visitor.leave_revert_statement(node, cursor);
+        }
+        RuleKind::ShiftExpression => {
+            visitor.leave_shift_expression(node, cursor);
+        }
+        RuleKind::SourceUnit => {
+            visitor.leave_source_unit(node, cursor);
+        }
+        RuleKind::SourceUnitMember => {
+            visitor.leave_source_unit_member(node, cursor);
+        }
+        RuleKind::SourceUnitMembers => {
+            visitor.leave_source_unit_members(node, cursor);
+        }
+        RuleKind::StateVariableAttribute => {
+            visitor.leave_state_variable_attribute(node, cursor);
+        }
+        RuleKind::StateVariableAttributes => {
+            visitor.leave_state_variable_attributes(node, cursor);
+        }
+        RuleKind::StateVariableDefinition => {
+            visitor.leave_state_variable_definition(node, cursor);
+        }
+        RuleKind::StateVariableDefinitionValue => {
+            visitor.leave_state_variable_definition_value(node, cursor);
+        }
+        RuleKind::Statement => {
+            visitor.leave_statement(node, cursor);
+        }
+        RuleKind::Statements => {
+            visitor.leave_statements(node, cursor);
+        }
+        RuleKind::StorageLocation => {
+            visitor.leave_storage_location(node, cursor);
+        }
+        RuleKind::StringExpression => {
+            visitor.leave_string_expression(node, cursor);
+        }
+        RuleKind::StringLiteral => {
+            visitor.leave_string_literal(node, cursor);
+        }
+        RuleKind::StringLiterals => {
+            visitor.leave_string_literals(node, cursor);
+        }
+        RuleKind::StructDefinition => {
+            visitor.leave_struct_definition(node, cursor);
+        }
+        RuleKind::StructMember => {
+            visitor.leave_struct_member(node, cursor);
+        }
+        RuleKind::StructMembers => {
+            visitor.leave_struct_members(node, cursor);
+        }
+        RuleKind::ThrowStatement => {
+            visitor.leave_throw_statement(node, cursor);
+        }
+        RuleKind::TryStatement => {
+            visitor.leave_try_statement(node, cursor);
+        }
+        RuleKind::TupleDeconstructionElement => {
+            visitor.leave_tuple_deconstruction_element(node, cursor);
+        }
+        RuleKind::TupleDeconstructionElements => {
+            visitor.leave_tuple_deconstruction_elements(node, cursor);
+        }
+        RuleKind::TupleDeconstructionStatement => {
+            visitor.leave_tuple_deconstruction_statement(node, cursor);
+        }
+        RuleKind::TupleExpression => {
+            visitor.leave_tuple_expression(node, cursor);
+        }
+        RuleKind::TupleMember => {
+            visitor.leave_tuple_member(node, cursor);
+        }
+        RuleKind::TupleValue => {
+            visitor.leave_tuple_value(node, cursor);
+        }
+        RuleKind::TupleValues => {
+            visitor.leave_tuple_values(node, cursor);
+        }
+        RuleKind::TypeExpression => {
+            visitor.leave_type_expression(node, cursor);
+        }
+        RuleKind::TypeName => {
+            visitor.leave_type_name(node, cursor);
+        }
+        RuleKind::TypedTupleMember => {
+            visitor.leave_typed_tuple_member(node, cursor);
+        }
+        RuleKind::UncheckedBlock => {
+            visitor.leave_unchecked_block(node, cursor);
+        }
+        RuleKind::UnicodeStringLiteral => {
+            visitor.leave_unicode_string_literal(node, cursor);
+        }
+        RuleKind::UnicodeStringLiterals => {
+            visitor.leave_unicode_string_literals(node, cursor);
+        }
+        RuleKind::UnnamedFunctionAttribute => {
+            visitor.leave_unnamed_function_attribute(node, cursor);
+        }
+        RuleKind::UnnamedFunctionAttributes => {
+            visitor.leave_unnamed_function_attributes(node, cursor);
+        }
+        RuleKind::UnnamedFunctionDefinition => {
+            visitor.leave_unnamed_function_definition(node, cursor);
+        }
+        RuleKind::UntypedTupleMember => {
+            visitor.leave_untyped_tuple_member(node, cursor);
+        }
+        RuleKind::UserDefinedValueTypeDefinition => {
+            visitor.leave_user_defined_value_type_definition(node, cursor);
+        }
+        RuleKind::UsingAlias => {
+            visitor.leave_using_alias(node, cursor);
+        }
+        RuleKind::UsingClause => {
+            visitor.leave_using_clause(node, cursor);
+        }
+        RuleKind::UsingDeconstruction => {
+            visitor.leave_using_deconstruction(node, cursor);
+        }
+        RuleKind::UsingDeconstructionSymbol => {
+            visitor.leave_using_deconstruction_symbol(node, cursor);
+        }
+        RuleKind::UsingDeconstructionSymbols => {
+            visitor.leave_using_deconstruction_symbols(node, cursor);
+        }
+        RuleKind::UsingDirective => {
+            visitor.leave_using_directive(node, cursor);
+        }
+        RuleKind::UsingOperator => {
+            visitor.leave_using_operator(node, cursor);
+        }
+        RuleKind::UsingTarget => {
+            visitor.leave_using_target(node, cursor);
+        }
+        RuleKind::VariableDeclarationStatement => {
+            visitor.leave_variable_declaration_statement(node, cursor);
+        }
+        RuleKind::VariableDeclarationType => {
+            visitor.leave_variable_declaration_type(node, cursor);
+        }
+        RuleKind::VariableDeclarationValue => {
+            visitor.leave_variable_declaration_value(node, cursor);
+        }
+        RuleKind::VersionComparator => {
+            visitor.leave_version_comparator(node, cursor);
+        }
+        RuleKind::VersionExpression => {
+            visitor.leave_version_expression(node, cursor);
+        }
+        RuleKind::VersionExpressionSet => {
+            visitor.leave_version_expression_set(node, cursor);
+        }
+        RuleKind::VersionExpressionSets => {
+            visitor.leave_version_expression_sets(node, cursor);
+        }
+        RuleKind::VersionPragma => {
+            visitor.leave_version_pragma(node, cursor);
+        }
+        RuleKind::VersionRange => {
+            visitor.leave_version_range(node, cursor);
+        }
+        RuleKind::VersionSpecifiers => {
+            visitor.leave_version_specifiers(node, cursor);
+        }
+        RuleKind::WhileStatement => {
+            visitor.leave_while_statement(node, cursor);
+        }
+        RuleKind::YulArguments => {
+            visitor.leave_yul_arguments(node, cursor);
+        }
+        RuleKind::YulAssignmentOperator => {
+            visitor.leave_yul_assignment_operator(node, cursor);
+        }
+        RuleKind::YulAssignmentStatement => {
+            visitor.leave_yul_assignment_statement(node, cursor);
+        }
+        RuleKind::YulBlock => {
+            visitor.leave_yul_block(node, cursor);
+        }
+        RuleKind::YulBreakStatement => {
+            visitor.leave_yul_break_statement(node, cursor);
+        }
+        RuleKind::YulBuiltInFunction => {
+            visitor.leave_yul_built_in_function(node, cursor);
+        }
+        RuleKind::YulColonAndEqual => {
+            visitor.leave_yul_colon_and_equal(node, cursor);
+        }
+        RuleKind::YulContinueStatement => {
+            visitor.leave_yul_continue_statement(node, cursor);
+        }
+        RuleKind::YulDefaultCase => {
+            visitor.leave_yul_default_case(node, cursor);
+        }
+        RuleKind::YulExpression => {
+            visitor.leave_yul_expression(node, cursor);
+        }
+        RuleKind::YulForStatement => {
+            visitor.leave_yul_for_statement(node, cursor);
+        }
+        RuleKind::YulFunctionCallExpression => {
+            visitor.leave_yul_function_call_expression(node, cursor);
+        }
+        RuleKind::YulFunctionDefinition => {
+            visitor.leave_yul_function_definition(node, cursor);
+        }
+        RuleKind::YulIfStatement => {
+            visitor.leave_yul_if_statement(node, cursor);
+        }
+        RuleKind::YulLabel => {
+            visitor.leave_yul_label(node, cursor);
+        }
+        RuleKind::YulLeaveStatement => {
+            visitor.leave_yul_leave_statement(node, cursor);
+        }
+        RuleKind::YulLiteral => {
+            visitor.leave_yul_literal(node, cursor);
+        }
+        RuleKind::YulParameters => {
+            visitor.leave_yul_parameters(node, cursor);
+        }
+        RuleKind::YulParametersDeclaration => {
+            visitor.leave_yul_parameters_declaration(node, cursor);
+        }
+        RuleKind::YulPath => {
+            visitor.leave_yul_path(node, cursor);
+        }
+        RuleKind::YulPathComponent => {
+            visitor.leave_yul_path_component(node, cursor);
+        }
+        RuleKind::YulPaths => {
+            visitor.leave_yul_paths(node, cursor);
+        }
+        RuleKind::YulReturnVariables => {
+            visitor.leave_yul_return_variables(node, cursor);
+        }
+        RuleKind::YulReturnsDeclaration => {
+            visitor.leave_yul_returns_declaration(node, cursor);
+        }
+        RuleKind::YulStatement => {
+            visitor.leave_yul_statement(node, cursor);
+        }
+        RuleKind::YulStatements => {
+            visitor.leave_yul_statements(node, cursor);
+        }
+        RuleKind::YulSwitchCase => {
+            visitor.leave_yul_switch_case(node, cursor);
+        }
+        RuleKind::YulSwitchCases => {
+            visitor.leave_yul_switch_cases(node, cursor);
+        }
+        RuleKind::YulSwitchStatement => {
+            visitor.leave_yul_switch_statement(node, cursor);
+        }
+        RuleKind::YulValueCase => {
+            visitor.leave_yul_value_case(node, cursor);
+        }
+        RuleKind::YulVariableDeclarationStatement => {
+            visitor.leave_yul_variable_declaration_statement(node, cursor);
+        }
+        RuleKind::YulVariableDeclarationValue => {
+            visitor.leave_yul_variable_declaration_value(node, cursor);
+        }
+    }
+
+    visitor.leave_rule(node, cursor);
+}
+
+fn visit(visitor: &mut impl Visitor, node: &Rc<TokenNode>, cursor: &Cursor) {
+    visitor.visit_token(node, cursor);
+
+    match node.kind {
+        TokenKind::SKIPPED => {
+            visitor.visit_skipped(node, cursor);
+        }
+        TokenKind::MISSING => {
+            visitor.visit_missing(node, cursor);
+        }
+        TokenKind::AbicoderKeyword => {
+            visitor.visit_abicoder_keyword(node, cursor);
+        }
+        TokenKind::AbstractKeyword => {
+            visitor.visit_abstract_keyword(node, cursor);
+        }
+        TokenKind::AddressKeyword => {
+            visitor.visit_address_keyword(node, cursor);
+        }
+        TokenKind::AfterKeyword => {
+            visitor.visit_after_keyword(node, cursor);
+        }
+        TokenKind::AliasKeyword => {
+            visitor.visit_alias_keyword(node, cursor);
+        }
+        TokenKind::Ampersand => {
+            visitor.visit_ampersand(node, cursor);
+        }
+        TokenKind::AmpersandAmpersand => {
+            visitor.visit_ampersand_ampersand(node, cursor);
+        }
+        TokenKind::AmpersandEqual => {
+            visitor.visit_ampersand_equal(node, cursor);
+        }
+        TokenKind::AnonymousKeyword => {
+            visitor.visit_anonymous_keyword(node, cursor);
+        }
+        TokenKind::ApplyKeyword => {
+            visitor.visit_apply_keyword(node, cursor);
+        }
+        TokenKind::AsKeyword => {
+            visitor.visit_as_keyword(node, cursor);
+        }
+        TokenKind::AssemblyKeyword => {
+            visitor.visit_assembly_keyword(node, cursor);
+        }
+        TokenKind::Asterisk => {
+            visitor.visit_asterisk(node, cursor);
+        }
+        TokenKind::AsteriskAsterisk => {
+            visitor.visit_asterisk_asterisk(node, cursor);
+        }
+        TokenKind::AsteriskEqual => {
+            visitor.visit_asterisk_equal(node, cursor);
+        }
+        TokenKind::AutoKeyword => {
+            visitor.visit_auto_keyword(node, cursor);
+        }
+        TokenKind::Bang => {
+            visitor.visit_bang(node, cursor);
+        }
+        TokenKind::BangEqual => {
+            visitor.visit_bang_equal(node, cursor);
+        }
+        TokenKind::Bar => {
+            visitor.visit_bar(node, cursor);
+        }
+        TokenKind::BarBar => {
+            visitor.visit_bar_bar(node, cursor);
+        }
+        TokenKind::BarEqual => {
+            visitor.visit_bar_equal(node, cursor);
+        }
+        TokenKind::BoolKeyword => {
+            visitor.visit_bool_keyword(node, cursor);
+        }
+        TokenKind::BreakKeyword => {
+            visitor.visit_break_keyword(node, cursor);
+        }
+        TokenKind::ByteKeyword => {
+            visitor.visit_byte_keyword(node, cursor);
+        }
+        TokenKind::BytesKeyword => {
+            visitor.visit_bytes_keyword(node, cursor);
+        }
+        TokenKind::CallDataKeyword => {
+            visitor.visit_call_data_keyword(node, cursor);
+        }
+        TokenKind::Caret => {
+            visitor.visit_caret(node, cursor);
+        }
+        TokenKind::CaretEqual => {
+            visitor.visit_caret_equal(node, cursor);
+        }
+        TokenKind::CaseKeyword => {
+            visitor.visit_case_keyword(node, cursor);
+        }
+        TokenKind::CatchKeyword => {
+            visitor.visit_catch_keyword(node, cursor);
+        }
+        TokenKind::CloseBrace => {
+            visitor.visit_close_brace(node, cursor);
+        }
+        TokenKind::CloseBracket => {
+            visitor.visit_close_bracket(node, cursor);
+        }
+        TokenKind::CloseParen => {
+            visitor.visit_close_paren(node, cursor);
+        }
+        TokenKind::Colon => {
+            visitor.visit_colon(node, cursor);
+        }
+        TokenKind::ColonEqual => {
+            visitor.visit_colon_equal(node, cursor);
+        }
+        TokenKind::Comma => {
+            visitor.visit_comma(node, cursor);
+        }
+        TokenKind::ConstantKeyword => {
+            visitor.visit_constant_keyword(node, cursor);
+        }
+        TokenKind::ConstructorKeyword => {
+            visitor.visit_constructor_keyword(node, cursor);
+        }
+        TokenKind::ContinueKeyword => {
+            visitor.visit_continue_keyword(node, cursor);
+        }
+        TokenKind::ContractKeyword => {
+            visitor.visit_contract_keyword(node, cursor);
+        }
+        TokenKind::CopyOfKeyword => {
+            visitor.visit_copy_of_keyword(node, cursor);
+        }
+        TokenKind::DaysKeyword => {
+            visitor.visit_days_keyword(node, cursor);
+        }
+        TokenKind::DecimalLiteral => {
+            visitor.visit_decimal_literal(node, cursor);
+        }
+        TokenKind::DefaultKeyword => {
+            visitor.visit_default_keyword(node, cursor);
+        }
+        TokenKind::DefineKeyword => {
+            visitor.visit_define_keyword(node, cursor);
+        }
+        TokenKind::DeleteKeyword => {
+            visitor.visit_delete_keyword(node, cursor);
+        }
+        TokenKind::DoKeyword => {
+            visitor.visit_do_keyword(node, cursor);
+        }
+        TokenKind::DoubleQuotedHexStringLiteral => {
+            visitor.visit_double_quoted_hex_string_literal(node, cursor);
+        }
+        TokenKind::DoubleQuotedStringLiteral => {
+            visitor.visit_double_quoted_string_literal(node, cursor);
+        }
+        TokenKind::DoubleQuotedUnicodeStringLiteral => {
+            visitor.visit_double_quoted_unicode_string_literal(node, cursor);
+        }
+        TokenKind::DoubleQuotedVersionLiteral => {
+            visitor.visit_double_quoted_version_literal(node, cursor);
+        }
+        TokenKind::ElseKeyword => {
+            visitor.visit_else_keyword(node, cursor);
+        }
+        TokenKind::EmitKeyword => {
+            visitor.visit_emit_keyword(node, cursor);
+        }
+        TokenKind::EndOfLine => {
+            visitor.visit_end_of_line(node, cursor);
+        }
+        TokenKind::EnumKeyword => {
+            visitor.visit_enum_keyword(node, cursor);
+        }
+        TokenKind::Equal => {
+            visitor.visit_equal(node, cursor);
+        }
+        TokenKind::EqualEqual => {
+            visitor.visit_equal_equal(node, cursor);
+        }
+        TokenKind::EqualGreaterThan => {
+            visitor.visit_equal_greater_than(node, cursor);
+        }
+        TokenKind::ErrorKeyword => {
+            visitor.visit_error_keyword(node, cursor);
+        }
+        TokenKind::EtherKeyword => {
+            visitor.visit_ether_keyword(node, cursor);
+        }
+        TokenKind::EventKeyword => {
+            visitor.visit_event_keyword(node, cursor);
+        }
+        TokenKind::ExperimentalKeyword => {
+            visitor.visit_experimental_keyword(node, cursor);
+        }
+        TokenKind::ExternalKeyword => {
+            visitor.visit_external_keyword(node, cursor);
+        }
+        TokenKind::FallbackKeyword => {
+            visitor.visit_fallback_keyword(node, cursor);
+        }
+        TokenKind::FalseKeyword => {
+            visitor.visit_false_keyword(node, cursor);
+        }
+        TokenKind::FinalKeyword => {
+            visitor.visit_final_keyword(node, cursor);
+        }
+        TokenKind::FinneyKeyword => {
+            visitor.visit_finney_keyword(node, cursor);
+        }
+        TokenKind::FixedKeyword => {
+            visitor.visit_fixed_keyword(node, cursor);
+        }
+        TokenKind::ForKeyword => {
+            visitor.visit_for_keyword(node, cursor);
+        }
+        TokenKind::FromKeyword => {
+            visitor.visit_from_keyword(node, cursor);
+        }
+        TokenKind::FunctionKeyword => {
+            visitor.visit_function_keyword(node, cursor);
+        }
+        TokenKind::GlobalKeyword => {
+            visitor.visit_global_keyword(node, cursor);
+        }
+        TokenKind::GreaterThan => {
+            visitor.visit_greater_than(node, cursor);
+        }
+        TokenKind::GreaterThanEqual => {
+            visitor.visit_greater_than_equal(node, cursor);
+        }
+        TokenKind::GreaterThanGreaterThan => {
+            visitor.visit_greater_than_greater_than(node, cursor);
+        }
+        TokenKind::GreaterThanGreaterThanEqual => {
+            visitor.visit_greater_than_greater_than_equal(node, cursor);
+        }
+        TokenKind::GreaterThanGreaterThanGreaterThan => {
+            visitor.visit_greater_than_greater_than_greater_than(node, cursor);
+        }
+        TokenKind::GreaterThanGreaterThanGreaterThanEqual => {
+            visitor.visit_greater_than_greater_than_greater_than_equal(node, cursor);
+        }
+        TokenKind::GweiKeyword => {
+            visitor.visit_gwei_keyword(node, cursor);
+        }
+        TokenKind::HexKeyword => {
+            visitor.visit_hex_keyword(node, cursor);
+        }
+        TokenKind::HexLiteral => {
+            visitor.visit_hex_literal(node, cursor);
+        }
+        TokenKind::HoursKeyword => {
+            visitor.visit_hours_keyword(node, cursor);
+        }
+        TokenKind::Identifier => {
+            visitor.visit_identifier(node, cursor);
+        }
+        TokenKind::IfKeyword => {
+            visitor.visit_if_keyword(node, cursor);
+        }
+        TokenKind::ImmutableKeyword => {
+            visitor.visit_immutable_keyword(node, cursor);
+        }
+        TokenKind::ImplementsKeyword => {
+            visitor.visit_implements_keyword(node, cursor);
+        }
+        TokenKind::ImportKeyword => {
+            visitor.visit_import_keyword(node, cursor);
+        }
+        TokenKind::InKeyword => {
+            visitor.visit_in_keyword(node, cursor);
+        }
+        TokenKind::IndexedKeyword => {
+            visitor.visit_indexed_keyword(node, cursor);
+        }
+        TokenKind::InlineKeyword => {
+            visitor.visit_inline_keyword(node, cursor);
+        }
+        TokenKind::IntKeyword => {
+            visitor.visit_int_keyword(node, cursor);
+        }
+        TokenKind::InterfaceKeyword => {
+            visitor.visit_interface_keyword(node, cursor);
+        }
+        TokenKind::InternalKeyword => {
+            visitor.visit_internal_keyword(node, cursor);
+        }
+        TokenKind::IsKeyword => {
+            visitor.visit_is_keyword(node, cursor);
+        }
+        TokenKind::LessThan => {
+            visitor.visit_less_than(node, cursor);
+        }
+        TokenKind::LessThanEqual => {
+            visitor.visit_less_than_equal(node, cursor);
+        }
+        TokenKind::LessThanLessThan => {
+            visitor.visit_less_than_less_than(node, cursor);
+        }
+        TokenKind::LessThanLessThanEqual => {
+            visitor.visit_less_than_less_than_equal(node, cursor);
+        }
+        TokenKind::LetKeyword => {
+            visitor.visit_let_keyword(node, cursor);
+        }
+        TokenKind::LibraryKeyword => {
+            visitor.visit_library_keyword(node, cursor);
+        }
+        TokenKind::MacroKeyword => {
+            visitor.visit_macro_keyword(node, cursor);
+        }
+        TokenKind::MappingKeyword => {
+            visitor.visit_mapping_keyword(node, cursor);
+        }
+        TokenKind::MatchKeyword => {
+            visitor.visit_match_keyword(node, cursor);
+        }
+        TokenKind::MemoryKeyword => {
+            visitor.visit_memory_keyword(node, cursor);
+        }
+        TokenKind::Minus => {
+            visitor.visit_minus(node, cursor);
+        }
+        TokenKind::MinusEqual => {
+            visitor.visit_minus_equal(node, cursor);
+        }
+        TokenKind::MinusGreaterThan => {
+            visitor.visit_minus_greater_than(node, cursor);
+        }
+        TokenKind::MinusMinus => {
+            visitor.visit_minus_minus(node, cursor);
+        }
+        TokenKind::MinutesKeyword => {
+            visitor.visit_minutes_keyword(node, cursor);
+        }
+        TokenKind::ModifierKeyword => {
+            visitor.visit_modifier_keyword(node, cursor);
+        }
+        TokenKind::MultiLineComment => {
+            visitor.visit_multi_line_comment(node, cursor);
+        }
+        TokenKind::MultiLineNatSpecComment => {
+            visitor.visit_multi_line_nat_spec_comment(node, cursor);
+        }
+        TokenKind::MutableKeyword => {
+            visitor.visit_mutable_keyword(node, cursor);
+        }
+        TokenKind::NewKeyword => {
+            visitor.visit_new_keyword(node, cursor);
+        }
+        TokenKind::NullKeyword => {
+            visitor.visit_null_keyword(node, cursor);
+        }
+        TokenKind::OfKeyword => {
+            visitor.visit_of_keyword(node, cursor);
+        }
+        TokenKind::OpenBrace => {
+            visitor.visit_open_brace(node, cursor);
+        }
+        TokenKind::OpenBracket => {
+            visitor.visit_open_bracket(node, cursor);
+        }
+        TokenKind::OpenParen => {
+            visitor.visit_open_paren(node, cursor);
+        }
+        TokenKind::OverrideKeyword => {
+            visitor.visit_override_keyword(node, cursor);
+        }
+        TokenKind::PartialKeyword => {
+            visitor.visit_partial_keyword(node, cursor);
+        }
+        TokenKind::PayableKeyword => {
+            visitor.visit_payable_keyword(node, cursor);
+        }
+        TokenKind::Percent => {
+            visitor.visit_percent(node, cursor);
+        }
+        TokenKind::PercentEqual => {
+            visitor.visit_percent_equal(node, cursor);
+        }
+        TokenKind::Period => {
+            visitor.visit_period(node, cursor);
+        }
+        TokenKind::Plus => {
+            visitor.visit_plus(node, cursor);
+        }
+        TokenKind::PlusEqual => {
+            visitor.visit_plus_equal(node, cursor);
+        }
+        TokenKind::PlusPlus => {
+            visitor.visit_plus_plus(node, cursor);
+        }
+        TokenKind::PragmaKeyword => {
+            visitor.visit_pragma_keyword(node, cursor);
+        }
+        TokenKind::PrivateKeyword => {
+            visitor.visit_private_keyword(node, cursor);
+        }
+        TokenKind::PromiseKeyword => {
+            visitor.visit_promise_keyword(node, cursor);
+        }
+        TokenKind::PublicKeyword => {
+            visitor.visit_public_keyword(node, cursor);
+        }
+        TokenKind::PureKeyword => {
+            visitor.visit_pure_keyword(node, cursor);
+        }
+        TokenKind::QuestionMark => {
+            visitor.visit_question_mark(node, cursor);
+        }
+        TokenKind::ReceiveKeyword => {
+            visitor.visit_receive_keyword(node, cursor);
+        }
+        TokenKind::ReferenceKeyword => {
+            visitor.visit_reference_keyword(node, cursor);
+        }
+        TokenKind::RelocatableKeyword => {
+            visitor.visit_relocatable_keyword(node, cursor);
+        }
+        TokenKind::ReturnKeyword => {
+            visitor.visit_return_keyword(node, cursor);
+        }
+        TokenKind::ReturnsKeyword => {
+            visitor.visit_returns_keyword(node, cursor);
+        }
+        TokenKind::RevertKeyword => {
+            visitor.visit_revert_keyword(node, cursor);
+        }
+        TokenKind::SealedKeyword => {
+            visitor.visit_sealed_keyword(node, cursor);
+        }
+        TokenKind::SecondsKeyword => {
+            visitor.visit_seconds_keyword(node, cursor);
+        }
+        TokenKind::Semicolon => {
+            visitor.visit_semicolon(node, cursor);
+        }
+        TokenKind::SingleLineComment => {
+            visitor.visit_single_line_comment(node, cursor);
+        }
+        TokenKind::SingleLineNatSpecComment => {
+            visitor.visit_single_line_nat_spec_comment(node, cursor);
+        }
+        TokenKind::SingleQuotedHexStringLiteral => {
+            visitor.visit_single_quoted_hex_string_literal(node, cursor);
+        }
+        TokenKind::SingleQuotedStringLiteral => {
+            visitor.visit_single_quoted_string_literal(node, cursor);
+        }
+        TokenKind::SingleQuotedUnicodeStringLiteral => {
+            visitor.visit_single_quoted_unicode_string_literal(node, cursor);
+        }
+        TokenKind::SingleQuotedVersionLiteral => {
+            visitor.visit_single_quoted_version_literal(node, cursor);
+        }
+        TokenKind::SizeOfKeyword => {
+            visitor.visit_size_of_keyword(node, cursor);
+        }
+        TokenKind::Slash => {
+            visitor.visit_slash(node, cursor);
+        }
+        TokenKind::SlashEqual => {
+            visitor.visit_slash_equal(node, cursor);
+        }
+        TokenKind::SolidityKeyword => {
+            visitor.visit_solidity_keyword(node, cursor);
+        }
+        TokenKind::StaticKeyword => {
+            visitor.visit_static_keyword(node, cursor);
+        }
+        TokenKind::StorageKeyword => {
+            visitor.visit_storage_keyword(node, cursor);
+        }
+        TokenKind::StringKeyword => {
+            visitor.visit_string_keyword(node, cursor);
+        }
+        TokenKind::StructKeyword => {
+            visitor.visit_struct_keyword(node, cursor);
+        }
+        TokenKind::SupportsKeyword => {
+            visitor.visit_supports_keyword(node, cursor);
+        }
+        TokenKind::SwitchKeyword => {
+            visitor.visit_switch_keyword(node, cursor);
+        }
+        TokenKind::SzaboKeyword => {
+            visitor.visit_szabo_keyword(node, cursor);
+        }
+        TokenKind::ThrowKeyword => {
+            visitor.visit_throw_keyword(node, cursor);
+        }
+        TokenKind::Tilde => {
+            visitor.visit_tilde(node, cursor);
+        }
+        TokenKind::TrueKeyword => {
+            visitor.visit_true_keyword(node, cursor);
+        }
+        TokenKind::TryKeyword => {
+            visitor.visit_try_keyword(node, cursor);
+        }
+        TokenKind::TypeDefKeyword => {
+            visitor.visit_type_def_keyword(node, cursor);
+        }
+        TokenKind::TypeKeyword => {
+            visitor.visit_type_keyword(node, cursor);
+        }
+        TokenKind::TypeOfKeyword => {
+            visitor.visit_type_of_keyword(node, cursor);
+        }
+        TokenKind::UfixedKeyword => {
+            visitor.visit_ufixed_keyword(node, cursor);
+        }
+        TokenKind::UintKeyword => {
+            visitor.visit_uint_keyword(node, cursor);
+        }
+        TokenKind::UncheckedKeyword => {
+            visitor.visit_unchecked_keyword(node, cursor);
+        }
+        TokenKind::UsingKeyword => {
+            visitor.visit_using_keyword(node, cursor);
+        }
+        TokenKind::VarKeyword => {
+            visitor.visit_var_keyword(node, cursor);
+        }
+        TokenKind::VersionSpecifier => {
+            visitor.visit_version_specifier(node, cursor);
+        }
+        TokenKind::ViewKeyword => {
+            visitor.visit_view_keyword(node, cursor);
+        }
+        TokenKind::VirtualKeyword => {
+            visitor.visit_virtual_keyword(node, cursor);
+        }
+        TokenKind::WeeksKeyword => {
+            visitor.visit_weeks_keyword(node, cursor);
+        }
+        TokenKind::WeiKeyword => {
+            visitor.visit_wei_keyword(node, cursor);
+        }
+        TokenKind::WhileKeyword => {
+            visitor.visit_while_keyword(node, cursor);
+        }
+        TokenKind::Whitespace => {
+            visitor.visit_whitespace(node, cursor);
+        }
+        TokenKind::YearsKeyword => {
+            visitor.visit_years_keyword(node, cursor);
+        }
+        TokenKind::YulAbstractKeyword => {
+            visitor.visit_yul_abstract_keyword(node, cursor);
+        }
+        TokenKind::YulAddKeyword => {
+            visitor.visit_yul_add_keyword(node, cursor);
+        }
+        TokenKind::YulAddModKeyword => {
+            visitor.visit_yul_add_mod_keyword(node, cursor);
+        }
+        TokenKind::YulAddressKeyword => {
+            visitor.visit_yul_address_keyword(node, cursor);
+        }
+        TokenKind::YulAfterKeyword => {
+            visitor.visit_yul_after_keyword(node, cursor);
+        }
+        TokenKind::YulAliasKeyword => {
+            visitor.visit_yul_alias_keyword(node, cursor);
+        }
+        TokenKind::YulAndKeyword => {
+            visitor.visit_yul_and_keyword(node, cursor);
+        }
+        TokenKind::YulAnonymousKeyword => {
+            visitor.visit_yul_anonymous_keyword(node, cursor);
+        }
+        TokenKind::YulApplyKeyword => {
+            visitor.visit_yul_apply_keyword(node, cursor);
+        }
+        TokenKind::YulAsKeyword => {
+            visitor.visit_yul_as_keyword(node, cursor);
+        }
+        TokenKind::YulAssemblyKeyword => {
+            visitor.visit_yul_assembly_keyword(node, cursor);
+        }
+        TokenKind::YulAutoKeyword => {
+            visitor.visit_yul_auto_keyword(node, cursor);
+        }
+        TokenKind::YulBalanceKeyword => {
+            visitor.visit_yul_balance_keyword(node, cursor);
+        }
+        TokenKind::YulBaseFeeKeyword => {
+            visitor.visit_yul_base_fee_keyword(node, cursor);
+        }
+        TokenKind::YulBlobBaseFeeKeyword => {
+            visitor.visit_yul_blob_base_fee_keyword(node, cursor);
+        }
+        TokenKind::YulBlobHashKeyword => {
+            visitor.visit_yul_blob_hash_keyword(node, cursor);
+        }
+        TokenKind::YulBlockHashKeyword => {
+            visitor.visit_yul_block_hash_keyword(node, cursor);
+        }
+        TokenKind::YulBoolKeyword => {
+            visitor.visit_yul_bool_keyword(node, cursor);
+        }
+        TokenKind::YulBreakKeyword => {
+            visitor.visit_yul_break_keyword(node, cursor);
+        }
+        TokenKind::YulByteKeyword => {
+            visitor.visit_yul_byte_keyword(node, cursor);
+        }
+        TokenKind::YulBytesKeyword => {
+            visitor.visit_yul_bytes_keyword(node, cursor);
+        }
+        TokenKind::YulCallCodeKeyword => {
+            visitor.visit_yul_call_code_keyword(node, cursor);
+        }
+        TokenKind::YulCallDataCopyKeyword => {
+            visitor.visit_yul_call_data_copy_keyword(node, cursor);
+        }
+        TokenKind::YulCallDataKeyword => {
+            visitor.visit_yul_call_data_keyword(node, cursor);
+        }
+        TokenKind::YulCallDataLoadKeyword => {
+            visitor.visit_yul_call_data_load_keyword(node, cursor);
+        }
+        TokenKind::YulCallDataSizeKeyword => {
+            visitor.visit_yul_call_data_size_keyword(node, cursor);
+        }
+        TokenKind::YulCallKeyword => {
+            visitor.visit_yul_call_keyword(node, cursor);
+        }
+        TokenKind::YulCallValueKeyword => {
+            visitor.visit_yul_call_value_keyword(node, cursor);
+        }
+        TokenKind::YulCallerKeyword => {
+            visitor.visit_yul_caller_keyword(node, cursor);
+        }
+        TokenKind::YulCaseKeyword => {
+            visitor.visit_yul_case_keyword(node, cursor);
+        }
+        TokenKind::YulCatchKeyword => {
+            visitor.visit_yul_catch_keyword(node, cursor);
+        }
+        TokenKind::YulChainIdKeyword => {
+            visitor.visit_yul_chain_id_keyword(node, cursor);
+        }
+        TokenKind::YulCoinBaseKeyword => {
+            visitor.visit_yul_coin_base_keyword(node, cursor);
+        }
+        TokenKind::YulConstantKeyword => {
+            visitor.visit_yul_constant_keyword(node, cursor);
+        }
+        TokenKind::YulConstructorKeyword => {
+            visitor.visit_yul_constructor_keyword(node, cursor);
+        }
+        TokenKind::YulContinueKeyword => {
+            visitor.visit_yul_continue_keyword(node, cursor);
+        }
+        TokenKind::YulContractKeyword => {
+            visitor.visit_yul_contract_keyword(node, cursor);
+        }
+        TokenKind::YulCopyOfKeyword => {
+            visitor.visit_yul_copy_of_keyword(node, cursor);
+        }
+        TokenKind::YulCreate2Keyword => {
+            visitor.visit_yul_create_2_keyword(node, cursor);
+        }
+        TokenKind::YulCreateKeyword => {
+            visitor.visit_yul_create_keyword(node, cursor);
+        }
+        TokenKind::YulDaysKeyword => {
+            visitor.visit_yul_days_keyword(node, cursor);
+        }
+        TokenKind::YulDecimalLiteral => {
+            visitor.visit_yul_decimal_literal(node, cursor);
+        }
+        TokenKind::YulDefaultKeyword => {
+            visitor.visit_yul_default_keyword(node, cursor);
+        }
+        TokenKind::YulDefineKeyword => {
+            visitor.visit_yul_define_keyword(node, cursor);
+        }
+        TokenKind::YulDelegateCallKeyword => {
+            visitor.visit_yul_delegate_call_keyword(node, cursor);
+        }
+        TokenKind::YulDeleteKeyword => {
+            visitor.visit_yul_delete_keyword(node, cursor);
+        }
+        TokenKind::YulDifficultyKeyword => {
+            visitor.visit_yul_difficulty_keyword(node, cursor);
+        }
+        TokenKind::YulDivKeyword => {
+            visitor.visit_yul_div_keyword(node, cursor);
+        }
+        TokenKind::YulDoKeyword => {
+            visitor.visit_yul_do_keyword(node, cursor);
+        }
+        TokenKind::YulElseKeyword => {
+            visitor.visit_yul_else_keyword(node, cursor);
+        }
+        TokenKind::YulEmitKeyword => {
+            visitor.visit_yul_emit_keyword(node, cursor);
+        }
+        TokenKind::YulEnumKeyword => {
+            visitor.visit_yul_enum_keyword(node, cursor);
+        }
+        TokenKind::YulEqKeyword => {
+            visitor.visit_yul_eq_keyword(node, cursor);
+        }
+        TokenKind::YulEtherKeyword => {
+            visitor.visit_yul_ether_keyword(node, cursor);
+        }
+        TokenKind::YulEventKeyword => {
+            visitor.visit_yul_event_keyword(node, cursor);
+        }
+        TokenKind::YulExpKeyword => {
+            visitor.visit_yul_exp_keyword(node, cursor);
+        }
+        TokenKind::YulExtCodeCopyKeyword => {
+            visitor.visit_yul_ext_code_copy_keyword(node, cursor);
+        }
+        TokenKind::YulExtCodeHashKeyword => {
+            visitor.visit_yul_ext_code_hash_keyword(node, cursor);
+        }
+        TokenKind::YulExtCodeSizeKeyword => {
+            visitor.visit_yul_ext_code_size_keyword(node, cursor);
+        }
+        TokenKind::YulExternalKeyword => {
+            visitor.visit_yul_external_keyword(node, cursor);
+        }
+        TokenKind::YulFallbackKeyword => {
+            visitor.visit_yul_fallback_keyword(node, cursor);
+        }
+        TokenKind::YulFalseKeyword => {
+            visitor.visit_yul_false_keyword(node, cursor);
+        }
+        TokenKind::YulFinalKeyword => {
+            visitor.visit_yul_final_keyword(node, cursor);
+        }
+        TokenKind::YulFinneyKeyword => {
+            visitor.visit_yul_finney_keyword(node, cursor);
+        }
+        TokenKind::YulFixedKeyword => {
+            visitor.visit_yul_fixed_keyword(node, cursor);
+        }
+        TokenKind::YulForKeyword => {
+            visitor.visit_yul_for_keyword(node, cursor);
+        }
+        TokenKind::YulFunctionKeyword => {
+            visitor.visit_yul_function_keyword(node, cursor);
+        }
+        TokenKind::YulGasKeyword => {
+            visitor.visit_yul_gas_keyword(node, cursor);
+        }
+        TokenKind::YulGasLimitKeyword => {
+            visitor.visit_yul_gas_limit_keyword(node, cursor);
+        }
+        TokenKind::YulGasPriceKeyword => {
+            visitor.visit_yul_gas_price_keyword(node, cursor);
+        }
+        TokenKind::YulGtKeyword => {
+            visitor.visit_yul_gt_keyword(node, cursor);
+        }
+        TokenKind::YulGweiKeyword => {
+            visitor.visit_yul_gwei_keyword(node, cursor);
+        }
+        TokenKind::YulHexKeyword => {
+            visitor.visit_yul_hex_keyword(node, cursor);
+        }
+        TokenKind::YulHexLiteral => {
+            visitor.visit_yul_hex_literal(node, cursor);
+        }
+        TokenKind::YulHoursKeyword => {
+            visitor.visit_yul_hours_keyword(node, cursor);
+        }
+        TokenKind::YulIdentifier => {
+            visitor.visit_yul_identifier(node, cursor);
+        }
+        TokenKind::YulIfKeyword => {
+            visitor.visit_yul_if_keyword(node, cursor);
+        }
+        TokenKind::YulImmutableKeyword => {
+            visitor.visit_yul_immutable_keyword(node, cursor);
+        }
+        TokenKind::YulImplementsKeyword => {
+            visitor.visit_yul_implements_keyword(node, cursor);
+        }
+        TokenKind::YulImportKeyword => {
+            visitor.visit_yul_import_keyword(node, cursor);
+        }
+        TokenKind::YulInKeyword => {
+            visitor.visit_yul_in_keyword(node, cursor);
+        }
+        TokenKind::YulIndexedKeyword => {
+            visitor.visit_yul_indexed_keyword(node, cursor);
+        }
+        TokenKind::YulInlineKeyword => {
+            visitor.visit_yul_inline_keyword(node, cursor);
+        }
+        TokenKind::YulIntKeyword => {
+            visitor.visit_yul_int_keyword(node, cursor);
+        }
+        TokenKind::YulInterfaceKeyword => {
+            visitor.visit_yul_interface_keyword(node, cursor);
+        }
+        TokenKind::YulInternalKeyword => {
+            visitor.visit_yul_internal_keyword(node, cursor);
+        }
+        TokenKind::YulInvalidKeyword => {
+            visitor.visit_yul_invalid_keyword(node, cursor);
+        }
+        TokenKind::YulIsKeyword => {
+            visitor.visit_yul_is_keyword(node, cursor);
+        }
+        TokenKind::YulIsZeroKeyword => {
+            visitor.visit_yul_is_zero_keyword(node, cursor);
+        }
+        TokenKind::YulKeccak256Keyword => {
+            visitor.visit_yul_keccak_256_keyword(node, cursor);
+        }
+        TokenKind::YulLeaveKeyword => {
+            visitor.visit_yul_leave_keyword(node, cursor);
+        }
+        TokenKind::YulLetKeyword => {
+            visitor.visit_yul_let_keyword(node, cursor);
+        }
+        TokenKind::YulLibraryKeyword => {
+            visitor.visit_yul_library_keyword(node, cursor);
+        }
+        TokenKind::YulLog0Keyword => {
+            visitor.visit_yul_log_0_keyword(node, cursor);
+        }
+        TokenKind::YulLog1Keyword => {
+            visitor.visit_yul_log_1_keyword(node, cursor);
+        }
+        TokenKind::YulLog2Keyword => {
+            visitor.visit_yul_log_2_keyword(node, cursor);
+        }
+        TokenKind::YulLog3Keyword => {
+            visitor.visit_yul_log_3_keyword(node, cursor);
+        }
+        TokenKind::YulLog4Keyword => {
+            visitor.visit_yul_log_4_keyword(node, cursor);
+        }
+        TokenKind::YulLtKeyword => {
+            visitor.visit_yul_lt_keyword(node, cursor);
+        }
+        TokenKind::YulMCopyKeyword => {
+            visitor.visit_yul_m_copy_keyword(node, cursor);
+        }
+        TokenKind::YulMLoadKeyword => {
+            visitor.visit_yul_m_load_keyword(node, cursor);
+        }
+        TokenKind::YulMSizeKeyword => {
+            visitor.visit_yul_m_size_keyword(node, cursor);
+        }
+        TokenKind::YulMStore8Keyword => {
+            visitor.visit_yul_m_store_8_keyword(node, cursor);
+        }
+        TokenKind::YulMStoreKeyword => {
+            visitor.visit_yul_m_store_keyword(node, cursor);
+        }
+        TokenKind::YulMacroKeyword => {
+            visitor.visit_yul_macro_keyword(node, cursor);
+        }
+        TokenKind::YulMappingKeyword => {
+            visitor.visit_yul_mapping_keyword(node, cursor);
+        }
+        TokenKind::YulMatchKeyword => {
+            visitor.visit_yul_match_keyword(node, cursor);
+        }
+        TokenKind::YulMemoryKeyword => {
+            visitor.visit_yul_memory_keyword(node, cursor);
+        }
+        TokenKind::YulMinutesKeyword => {
+            visitor.visit_yul_minutes_keyword(node, cursor);
+        }
+        TokenKind::YulModKeyword => {
+            visitor.visit_yul_mod_keyword(node, cursor);
+        }
+        TokenKind::YulModifierKeyword => {
+            visitor.visit_yul_modifier_keyword(node, cursor);
+        }
+        TokenKind::YulMulKeyword => {
+            visitor.visit_yul_mul_keyword(node, cursor);
+        }
+        TokenKind::YulMulModKeyword => {
+            visitor.visit_yul_mul_mod_keyword(node, cursor);
+        }
+        TokenKind::YulMutableKeyword => {
+            visitor.visit_yul_mutable_keyword(node, cursor);
+        }
+        TokenKind::YulNewKeyword => {
+            visitor.visit_yul_new_keyword(node, cursor);
+        }
+        TokenKind::YulNotKeyword => {
+            visitor.visit_yul_not_keyword(node, cursor);
+        }
+        TokenKind::YulNullKeyword => {
+            visitor.visit_yul_null_keyword(node, cursor);
+        }
+        TokenKind::YulNumberKeyword => {
+            visitor.visit_yul_number_keyword(node, cursor);
+        }
+        TokenKind::YulOfKeyword => {
+            visitor.visit_yul_of_keyword(node, cursor);
+        }
+        TokenKind::YulOrKeyword => {
+            visitor.visit_yul_or_keyword(node, cursor);
+        }
+        TokenKind::YulOriginKeyword => {
+            visitor.visit_yul_origin_keyword(node, cursor);
+        }
+        TokenKind::YulOverrideKeyword => {
+            visitor.visit_yul_override_keyword(node, cursor);
+        }
+        TokenKind::YulPartialKeyword => {
+            visitor.visit_yul_partial_keyword(node, cursor);
+        }
+        TokenKind::YulPayableKeyword => {
+            visitor.visit_yul_payable_keyword(node, cursor);
+        }
+        TokenKind::YulPopKeyword => {
+            visitor.visit_yul_pop_keyword(node, cursor);
+        }
+        TokenKind::YulPragmaKeyword => {
+            visitor.visit_yul_pragma_keyword(node, cursor);
+        }
+        TokenKind::YulPrevRandaoKeyword => {
+            visitor.visit_yul_prev_randao_keyword(node, cursor);
+        }
+        TokenKind::YulPrivateKeyword => {
+            visitor.visit_yul_private_keyword(node, cursor);
+        }
+        TokenKind::YulPromiseKeyword => {
+            visitor.visit_yul_promise_keyword(node, cursor);
+        }
+        TokenKind::YulPublicKeyword => {
+            visitor.visit_yul_public_keyword(node, cursor);
+        }
+        TokenKind::YulPureKeyword => {
+            visitor.visit_yul_pure_keyword(node, cursor);
+        }
+        TokenKind::YulReceiveKeyword => {
+            visitor.visit_yul_receive_keyword(node, cursor);
+        }
+        TokenKind::YulReferenceKeyword => {
+            visitor.visit_yul_reference_keyword(node, cursor);
+        }
+        TokenKind::YulRelocatableKeyword => {
+            visitor.visit_yul_relocatable_keyword(node, cursor);
+        }
+        TokenKind::YulReturnDataCopyKeyword => {
+            visitor.visit_yul_return_data_copy_keyword(node, cursor);
+        }
+        TokenKind::YulReturnDataSizeKeyword => {
+            visitor.visit_yul_return_data_size_keyword(node, cursor);
+        }
+        TokenKind::YulReturnKeyword => {
+            visitor.visit_yul_return_keyword(node, cursor);
+        }
+        TokenKind::YulReturnsKeyword => {
+            visitor.visit_yul_returns_keyword(node, cursor);
+        }
+        TokenKind::YulRevertKeyword => {
+            visitor.visit_yul_revert_keyword(node, cursor);
+        }
+        TokenKind::YulSDivKeyword => {
+            visitor.visit_yul_s_div_keyword(node, cursor);
+        }
+        TokenKind::YulSLoadKeyword => {
+            visitor.visit_yul_s_load_keyword(node, cursor);
+        }
+        TokenKind::YulSModKeyword => {
+            visitor.visit_yul_s_mod_keyword(node, cursor);
+        }
+        TokenKind::YulSStoreKeyword => {
+            visitor.visit_yul_s_store_keyword(node, cursor);
+        }
+        TokenKind::YulSarKeyword => {
+            visitor.visit_yul_sar_keyword(node, cursor);
+        }
+        TokenKind::YulSealedKeyword => {
+            visitor.visit_yul_sealed_keyword(node, cursor);
+        }
+        TokenKind::YulSecondsKeyword => {
+            visitor.visit_yul_seconds_keyword(node, cursor);
+        }
+        TokenKind::YulSelfBalanceKeyword => {
+            visitor.visit_yul_self_balance_keyword(node, cursor);
+        }
+        TokenKind::YulSelfDestructKeyword => {
+            visitor.visit_yul_self_destruct_keyword(node, cursor);
+        }
+        TokenKind::YulSgtKeyword => {
+            visitor.visit_yul_sgt_keyword(node, cursor);
+        }
+        TokenKind::YulSha3Keyword => {
+            visitor.visit_yul_sha_3_keyword(node, cursor);
+        }
+        TokenKind::YulShlKeyword => {
+            visitor.visit_yul_shl_keyword(node, cursor);
+        }
+        TokenKind::YulShrKeyword => {
+            visitor.visit_yul_shr_keyword(node, cursor);
+        }
+        TokenKind::YulSignExtendKeyword => {
+            visitor.visit_yul_sign_extend_keyword(node, cursor);
+        }
+        TokenKind::YulSizeOfKeyword => {
+            visitor.visit_yul_size_of_keyword(node, cursor);
+        }
+        TokenKind::YulSltKeyword => {
+            visitor.visit_yul_slt_keyword(node, cursor);
+        }
+        TokenKind::YulStaticCallKeyword => {
+            visitor.visit_yul_static_call_keyword(node, cursor);
+        }
+        TokenKind::YulStaticKeyword => {
+            visitor.visit_yul_static_keyword(node, cursor);
+        }
+        TokenKind::YulStopKeyword => {
+            visitor.visit_yul_stop_keyword(node, cursor);
+        }
+        TokenKind::YulStorageKeyword => {
+            visitor.visit_yul_storage_keyword(node, cursor);
+        }
+        TokenKind::YulStringKeyword => {
+            visitor.visit_yul_string_keyword(node, cursor);
+        }
+        TokenKind::YulStructKeyword => {
+            visitor.visit_yul_struct_keyword(node, cursor);
+        }
+        TokenKind::YulSubKeyword => {
+            visitor.visit_yul_sub_keyword(node, cursor);
+        }
+        TokenKind::YulSuicideKeyword => {
+            visitor.visit_yul_suicide_keyword(node, cursor);
+        }
+        TokenKind::YulSupportsKeyword => {
+            visitor.visit_yul_supports_keyword(node, cursor);
+        }
+        TokenKind::YulSwitchKeyword => {
+            visitor.visit_yul_switch_keyword(node, cursor);
+        }
+        TokenKind::YulSzaboKeyword => {
+            visitor.visit_yul_szabo_keyword(node, cursor);
+        }
+        TokenKind::YulTLoadKeyword => {
+            visitor.visit_yul_t_load_keyword(node, cursor);
+        }
+        TokenKind::YulTStoreKeyword => {
+            visitor.visit_yul_t_store_keyword(node, cursor);
+        }
+        TokenKind::YulThrowKeyword => {
+            visitor.visit_yul_throw_keyword(node, cursor);
+        }
+        TokenKind::YulTimestampKeyword => {
+            visitor.visit_yul_timestamp_keyword(node, cursor);
+        }
+        TokenKind::YulTrueKeyword => {
+            visitor.visit_yul_true_keyword(node, cursor);
+        }
+        TokenKind::YulTryKeyword => {
+            visitor.visit_yul_try_keyword(node, cursor);
+        }
+        TokenKind::YulTypeDefKeyword => {
+            visitor.visit_yul_type_def_keyword(node, cursor);
+        }
+        TokenKind::YulTypeKeyword => {
+            visitor.visit_yul_type_keyword(node, cursor);
+        }
+        TokenKind::YulTypeOfKeyword => {
+            visitor.visit_yul_type_of_keyword(node, cursor);
+        }
+        TokenKind::YulUfixedKeyword => {
+            visitor.visit_yul_ufixed_keyword(node, cursor);
+        }
+        TokenKind::YulUintKeyword => {
+            visitor.visit_yul_uint_keyword(node, cursor);
+        }
+        TokenKind::YulUncheckedKeyword => {
+            visitor.visit_yul_unchecked_keyword(node, cursor);
+        }
+        TokenKind::YulUsingKeyword => {
+            visitor.visit_yul_using_keyword(node, cursor);
+        }
+        TokenKind::YulVarKeyword => {
+            visitor.visit_yul_var_keyword(node, cursor);
+        }
+        TokenKind::YulViewKeyword => {
+            visitor.visit_yul_view_keyword(node, cursor);
+        }
+        TokenKind::YulVirtualKeyword => {
+            visitor.visit_yul_virtual_keyword(node, cursor);
+        }
+        TokenKind::YulWeeksKeyword => {
+            visitor.visit_yul_weeks_keyword(node, cursor);
+        }
+        TokenKind::YulWeiKeyword => {
+            visitor.visit_yul_wei_keyword(node, cursor);
+        }
+        TokenKind::YulWhileKeyword => {
+            visitor.visit_yul_while_keyword(node, cursor);
+        }
+        TokenKind::YulXorKeyword => {
+            visitor.visit_yul_xor_keyword(node, cursor);
+        }
+        TokenKind::YulYearsKeyword => {
+            visitor.visit_yul_years_keyword(node, cursor);
+        }
+    }
+}
@@ -1,5 +1,13 @@
+pub mod formatter;
 mod generated;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+pub mod natspec;
+pub mod outline;
+pub mod semantic_tokens;
+pub mod solc_ast;
 mod user_defined;
+pub mod version_selection;
 
 pub use generated::*;
 
@@ -0,0 +1,67 @@
+use semver::Version;
+use serde_json::{json, Value};
+
+use crate::kinds::RuleKind;
+use crate::language::Language;
+use crate::parse_output::ParseOutput;
+use crate::text_index::{LineIndex, LineTerminators, TextIndex, TextRange, TextUnit};
+use crate::version_selection;
+
+/// An open document, parsed with the language version selected for it.
+pub(super) struct Document {
+    pub text: String,
+    pub output: ParseOutput,
+    pub line_index: LineIndex,
+}
+
+impl Document {
+    /// Parses the text with the configured version if any, or the latest version allowed by its
+    /// `pragma solidity` directives, falling back to the latest supported version.
+    pub fn new(text: String, configured_version: Option<&Version>) -> Self {
+        let language = version_selection::select_version(&text, configured_version).language();
+        let output = language.parse(RuleKind::SourceUnit, &text);
+        let line_index = LineIndex::with_terminators(&text, LineTerminators::Lsp);
+
+        Self {
+            text,
+            output,
+            line_index,
+        }
+    }
+
+    /// Converts an offset to an LSP `Position`, with UTF-16 columns.
+    pub fn position(&self, offset: TextIndex) -> Value {
+        let position = self.line_index.line_column(offset);
+
+        json!({
+            "line": position.line,
+            "character": position.column.utf16,
+        })
+    }
+
+    pub fn range(&self, range: &TextRange) -> Value {
+        json!({
+            "start": self.position(range.start),
+            "end": self.position(range.end),
+        })
+    }
+
+    /// Converts an LSP `Position` to an offset, or `None` if it is outside of the document.
+    pub fn offset(&self, position: &Value) -> Option<TextIndex> {
+        let line = position.get("line")?.as_u64()?;
+        let character = position.get("character")?.as_u64()?;
+
+        self.line_index.offset(
+            usize::try_from(line).ok()?,
+            usize::try_from(character).ok()?,
+            TextUnit::Utf16,
+        )
+    }
+}
+
+pub(super) fn latest_version() -> Version {
+    Language::SUPPORTED_VERSIONS
+        .last()
+        .expect("There is at least one supported version.")
+        .clone()
+}
@@ -0,0 +1,118 @@
+use serde_json::{json, Value};
+
+use super::document::Document;
+use crate::outline::{self, FoldingRangeKind, Symbol, SymbolKind};
+use crate::text_index::TextIndex;
+
+/// Values of the LSP `SymbolKind` enumeration.
+mod symbol_kind {
+    pub const NAMESPACE: u8 = 3;
+    pub const CLASS: u8 = 5;
+    pub const METHOD: u8 = 6;
+    pub const FIELD: u8 = 8;
+    pub const CONSTRUCTOR: u8 = 9;
+    pub const ENUM: u8 = 10;
+    pub const INTERFACE: u8 = 11;
+    pub const FUNCTION: u8 = 12;
+    pub const CONSTANT: u8 = 14;
+    pub const ENUM_MEMBER: u8 = 22;
+    pub const STRUCT: u8 = 23;
+    pub const EVENT: u8 = 24;
+    pub const TYPE_PARAMETER: u8 = 26;
+}
+
+/// Returns the hierarchy of declarations in the document, as LSP `DocumentSymbol`s.
+pub(super) fn document_symbols(document: &Document) -> Value {
+    let outline = outline::outline(&document.output);
+
+    symbols(document, &outline.symbols)
+}
+
+fn symbols(document: &Document, symbols: &[Symbol]) -> Value {
+    symbols
+        .iter()
+        .map(|symbol| {
+            json!({
+                "name": symbol.name,
+                "kind": lsp_symbol_kind(symbol.kind),
+                "range": document.range(&symbol.range),
+                "selectionRange": document.range(&symbol.name_range),
+                "children": self::symbols(document, &symbol.children),
+            })
+        })
+        .collect()
+}
+
+fn lsp_symbol_kind(kind: SymbolKind) -> u8 {
+    match kind {
+        SymbolKind::Contract => symbol_kind::CLASS,
+        SymbolKind::Interface => symbol_kind::INTERFACE,
+        SymbolKind::Library => symbol_kind::NAMESPACE,
+        SymbolKind::Function | SymbolKind::SpecialFunction => symbol_kind::FUNCTION,
+        SymbolKind::Constructor => symbol_kind::CONSTRUCTOR,
+        SymbolKind::Modifier => symbol_kind::METHOD,
+        // LSP has no kind for errors, which are declared and used like events:
+        SymbolKind::Event | SymbolKind::Error => symbol_kind::EVENT,
+        SymbolKind::Struct => symbol_kind::STRUCT,
+        SymbolKind::StructMember | SymbolKind::StateVariable => symbol_kind::FIELD,
+        SymbolKind::Enum => symbol_kind::ENUM,
+        SymbolKind::EnumMember => symbol_kind::ENUM_MEMBER,
+        SymbolKind::Constant => symbol_kind::CONSTANT,
+        SymbolKind::UserDefinedValueType => symbol_kind::TYPE_PARAMETER,
+    }
+}
+
+/// Returns the folding ranges of the document, as LSP `FoldingRange`s.
+pub(super) fn folding_ranges(document: &Document) -> Value {
+    let outline = outline::outline(&document.output);
+
+    outline
+        .folding_ranges
+        .iter()
+        .map(|folding_range| {
+            let start = document
+                .line_index
+                .line_column(folding_range.range.start)
+                .line;
+            let end = document
+                .line_index
+                .line_column(folding_range.range.end)
+                .line;
+
+            match folding_range.kind {
+                // The closing brace is kept visible when folded:
+                FoldingRangeKind::Block => json!({ "startLine": start, "endLine": end - 1 }),
+                FoldingRangeKind::Comment => {
+                    json!({ "startLine": start, "endLine": end, "kind": "comment" })
+                }
+                FoldingRangeKind::Imports => {
+                    json!({ "startLine": start, "endLine": end, "kind": "imports" })
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the nested ranges of the nodes enclosing each position, as LSP `SelectionRange`s.
+pub(super) fn selection_ranges(document: &Document, positions: &[Value]) -> Value {
+    positions
+        .iter()
+        .map(|position| {
+            let offset = document.offset(position).unwrap_or(TextIndex::ZERO);
+
+            let mut ranges = outline::selection_ranges(&document.output, offset);
+            if ranges.is_empty() {
+                ranges.push(offset..offset);
+            }
+
+            // Built from the outermost range, so that each range can be nested in the next one:
+            ranges.iter().rev().fold(Value::Null, |parent, range| {
+                let mut selection = json!({ "range": document.range(range) });
+                if !parent.is_null() {
+                    selection["parent"] = parent;
+                }
+                selection
+            })
+        })
+        .collect()
+}
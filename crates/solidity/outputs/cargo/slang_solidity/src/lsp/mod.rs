@@ -0,0 +1,51 @@
+//! A Language Server Protocol server for Solidity: <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/>
+//!
+//! The server keeps all open documents parsed, and provides syntax diagnostics, document symbols, folding ranges,
+//! selection ranges, semantic tokens, and formatting. Documents are synchronized in full on every change.
+//!
+//! Each document is parsed with the version set by the client (either as `initializationOptions.version`, or as
+//! the `slang.version` setting), or otherwise the latest supported version allowed by its `pragma solidity` directives.
+
+mod document;
+mod features;
+mod server;
+mod transport;
+
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+
+/// The JSON-RPC error code of messages that are not valid JSON.
+const PARSE_ERROR: i64 = -32700;
+
+/// Runs the server, reading client messages from `input` and writing server messages to `output`,
+/// until the client sends an `exit` notification, or `input` ends.
+///
+/// Returns whether the client requested a `shutdown` before exiting, which the protocol uses as the exit status.
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> io::Result<bool> {
+    let mut server = server::Server::default();
+
+    while let Some(content) = transport::read_message(&mut input)? {
+        match serde_json::from_slice::<Value>(&content) {
+            Ok(message) => server.handle(&message),
+            Err(error) => transport::write_message(
+                &mut output,
+                &json!({
+                    "jsonrpc": "2.0",
+                    "id": null,
+                    "error": { "code": PARSE_ERROR, "message": error.to_string() },
+                }),
+            )?,
+        }
+
+        for message in server.take_outgoing() {
+            transport::write_message(&mut output, &message)?;
+        }
+
+        if server.exited() {
+            break;
+        }
+    }
+
+    Ok(server.shutdown_requested())
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use semver::Version;
+use serde_json::{json, Value};
+
+use super::document::{latest_version, Document};
+use super::features;
+use crate::diagnostic::Diagnostic;
+use crate::formatter::{self, FormatterOptions};
+use crate::language::Language;
+use crate::semantic_tokens::{self, SemanticTokenModifier, SemanticTokenType};
+use crate::text_index::{TextIndex, TextUnit};
+
+/// JSON-RPC and LSP error codes.
+mod error_code {
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+}
+
+/// Values of the LSP `MessageType` enumeration.
+const MESSAGE_TYPE_WARNING: u8 = 2;
+
+/// Values of the LSP `TextDocumentSyncKind` enumeration.
+const TEXT_DOCUMENT_SYNC_FULL: u8 = 1;
+
+struct RequestError {
+    code: i64,
+    message: String,
+}
+
+impl RequestError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+type RequestResult = Result<Value, RequestError>;
+
+#[derive(Default)]
+pub(super) struct Server {
+    documents: HashMap<String, Document>,
+    /// The version set by the client, overriding the `pragma solidity` directives of documents.
+    configured_version: Option<Version>,
+    shutdown_requested: bool,
+    exited: bool,
+    /// Messages (responses and notifications) waiting to be sent to the client.
+    outgoing: Vec<Value>,
+}
+
+impl Server {
+    pub fn shutdown_requested(&self) -> bool {
+        self.shutdown_requested
+    }
+
+    pub fn exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Returns the messages produced since the last call, in the order they should be sent.
+    pub fn take_outgoing(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.outgoing)
+    }
+
+    pub fn handle(&mut self, message: &Value) {
+        let method = message.get("method").and_then(Value::as_str);
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        match (message.get("id"), method) {
+            (Some(id), Some(method)) => {
+                let result = if self.shutdown_requested {
+                    Err(RequestError::new(
+                        error_code::INVALID_REQUEST,
+                        "The server is shutting down.",
+                    ))
+                } else {
+                    self.handle_request(method, &params)
+                };
+
+                self.respond(id, result);
+            }
+            (None, Some(method)) => self.handle_notification(method, &params),
+            (Some(id), None) => self.respond(
+                id,
+                Err(RequestError::new(
+                    error_code::INVALID_REQUEST,
+                    "The message has no method.",
+                )),
+            ),
+            // Responses to server requests are not expected, since the server never sends any:
+            (None, None) => {}
+        }
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> RequestResult {
+        match method {
+            "initialize" => Ok(self.initialize(params)),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            }
+            "textDocument/documentSymbol" => {
+                let document = self.document(params)?;
+                Ok(features::document_symbols(document))
+            }
+            "textDocument/foldingRange" => {
+                let document = self.document(params)?;
+                Ok(features::folding_ranges(document))
+            }
+            "textDocument/selectionRange" => {
+                let document = self.document(params)?;
+                let positions = params
+                    .get("positions")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| {
+                        RequestError::new(error_code::INVALID_PARAMS, "Missing positions.")
+                    })?;
+
+                Ok(features::selection_ranges(document, positions))
+            }
+            "textDocument/semanticTokens/full" => {
+                let document = self.document(params)?;
+                let tokens = semantic_tokens::classify(&document.output);
+                let data = semantic_tokens::encode(&tokens, &document.line_index, TextUnit::Utf16);
+
+                Ok(json!({ "data": data }))
+            }
+            "textDocument/formatting" => {
+                let document = self.document(params)?;
+                Ok(format(document, params))
+            }
+            _ => Err(RequestError::new(
+                error_code::METHOD_NOT_FOUND,
+                format!("Unsupported method: {method}"),
+            )),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) {
+        match method {
+            "exit" => self.exited = true,
+            "textDocument/didOpen" => {
+                let document = &params["textDocument"];
+                if let (Some(uri), Some(text)) = (
+                    document.get("uri").and_then(Value::as_str),
+                    document.get("text").and_then(Value::as_str),
+                ) {
+                    self.update(uri.to_owned(), text.to_owned());
+                }
+            }
+            "textDocument/didChange" => {
+                // With full synchronization, the last change holds the whole text of the document:
+                let uri = params["textDocument"].get("uri").and_then(Value::as_str);
+                let text = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str);
+
+                if let (Some(uri), Some(text)) = (uri, text) {
+                    self.update(uri.to_owned(), text.to_owned());
+                }
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = params["textDocument"].get("uri").and_then(Value::as_str) {
+                    self.documents.remove(uri);
+                    self.notify(
+                        "textDocument/publishDiagnostics",
+                        &json!({ "uri": uri, "diagnostics": [] }),
+                    );
+                }
+            }
+            "workspace/didChangeConfiguration" => {
+                let version = &params["settings"]["slang"]["version"];
+                if !version.is_null() {
+                    self.configure_version(version);
+                }
+            }
+            // Other notifications (e.g. `initialized` or `$/cancelRequest`) can be safely ignored:
+            _ => {}
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        let version = &params["initializationOptions"]["version"];
+        if !version.is_null() {
+            self.configure_version(version);
+        }
+
+        json!({
+            "capabilities": {
+                "positionEncoding": "utf-16",
+                "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                "documentSymbolProvider": true,
+                "foldingRangeProvider": true,
+                "selectionRangeProvider": true,
+                "documentFormattingProvider": true,
+                "semanticTokensProvider": {
+                    "legend": {
+                        "tokenTypes": SemanticTokenType::legend(),
+                        "tokenModifiers": SemanticTokenModifier::legend(),
+                    },
+                    "full": true,
+                },
+            },
+            "serverInfo": {
+                "name": "slang",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        })
+    }
+
+    /// Sets the version used to parse all documents, and reparses the open ones.
+    /// Invalid or unsupported versions are reported to the user, and otherwise ignored.
+    fn configure_version(&mut self, version: &Value) {
+        let parsed = version
+            .as_str()
+            .and_then(|version| Version::parse(version).ok())
+            .filter(|version| Language::SUPPORTED_VERSIONS.contains(version));
+
+        let Some(parsed) = parsed else {
+            self.notify(
+                "window/showMessage",
+                &json!({
+                    "type": MESSAGE_TYPE_WARNING,
+                    "message": format!(
+                        "Unsupported Solidity version {version}. Supported versions are {} to {}.",
+                        Language::SUPPORTED_VERSIONS[0],
+                        latest_version(),
+                    ),
+                }),
+            );
+            return;
+        };
+
+        self.configured_version = Some(parsed);
+
+        let documents = std::mem::take(&mut self.documents);
+        for (uri, document) in documents {
+            self.update(uri, document.text);
+        }
+    }
+
+    fn update(&mut self, uri: String, text: String) {
+        let document = Document::new(text, self.configured_version.as_ref());
+
+        let diagnostics = document
+            .output
+            .errors()
+            .iter()
+            .map(|error| {
+                let message = match error.note() {
+                    Some(note) => format!("{}\nNote: {note}", error.message()),
+                    None => error.message(),
+                };
+
+                json!({
+                    "range": document.range(&error.range()),
+                    "severity": error.severity() as u8,
+                    "code": error.code().to_string(),
+                    "source": "slang",
+                    "message": message,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.notify(
+            "textDocument/publishDiagnostics",
+            &json!({ "uri": uri, "diagnostics": diagnostics }),
+        );
+
+        self.documents.insert(uri, document);
+    }
+
+    fn document(&self, params: &Value) -> Result<&Document, RequestError> {
+        let uri = params["textDocument"]
+            .get("uri")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        self.documents.get(uri).ok_or_else(|| {
+            RequestError::new(
+                error_code::INVALID_PARAMS,
+                format!("Unknown document: {uri}"),
+            )
+        })
+    }
+
+    fn respond(&mut self, id: &Value, result: RequestResult) {
+        let message = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": error.code, "message": error.message },
+            }),
+        };
+
+        self.outgoing.push(message);
+    }
+
+    fn notify(&mut self, method: &str, params: &Value) {
+        self.outgoing
+            .push(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+}
+
+/// Formats the whole document, or returns `null` if it has syntax errors.
+fn format(document: &Document, params: &Value) -> Value {
+    let mut options = FormatterOptions::default();
+    if let Some(tab_size) = params["options"].get("tabSize").and_then(Value::as_u64) {
+        options.indent_width = usize::try_from(tab_size).unwrap_or(options.indent_width);
+    }
+
+    let Ok(formatted) = formatter::format(&document.output, &options) else {
+        return Value::Null;
+    };
+
+    let end = TextIndex::from(document.text.as_str());
+
+    json!([{
+        "range": document.range(&(TextIndex::ZERO..end)),
+        "newText": formatted,
+    }])
+}
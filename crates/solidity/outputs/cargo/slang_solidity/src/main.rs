@@ -5,15 +5,20 @@ use std::process::ExitCode;
 use anyhow::{Context, Result};
 use clap::{Parser as ClapParser, Subcommand};
 use semver::Version;
+use slang_solidity::formatter::{self, FormatterOptions};
 use slang_solidity::kinds::RuleKind;
 use slang_solidity::language::Language;
+use slang_solidity::version_selection;
 
 // Below are dependencies used by the API `lib.rs`, but not the CLI "main.rs".
 // However, we need to add a fake usage to suppress Cargo warnings about unused dependencies.
 // This is a known issue, and we should remove this hack once there is a better solution from Cargo.
 // https://github.com/rust-lang/cargo/issues/1982
 mod supress_api_dependencies {
-    use {ariadne as _, nom as _, serde as _, strum as _, strum_macros as _, thiserror as _};
+    use {
+        ariadne as _, nom as _, regex as _, serde as _, strum as _, strum_macros as _,
+        thiserror as _,
+    };
 }
 
 #[derive(ClapParser, Debug)]
@@ -31,14 +36,41 @@ enum Commands {
         /// File path to the Solidity (*.sol) source file to parse
         file_path: String,
 
-        /// The Solidity language version to use for parsing
+        /// The Solidity language version to use for parsing. Defaults to the latest version allowed by the
+        /// `pragma solidity` directives of the file
         #[arg(short, long)]
-        version: Version,
+        version: Option<Version>,
 
         /// Print the concrete syntax tree as JSON
         #[clap(long)]
         json: bool,
     },
+
+    /// Formats a Solidity (*.sol) source file, and outputs the result, or any syntax errors
+    Format {
+        /// File path to the Solidity (*.sol) source file to format
+        file_path: String,
+
+        /// The Solidity language version to use for parsing. Defaults to the latest version allowed by the
+        /// `pragma solidity` directives of the file
+        #[arg(short, long)]
+        version: Option<Version>,
+
+        /// Overwrite the source file with the formatted result, instead of printing it
+        #[clap(long)]
+        write: bool,
+
+        /// The maximum width of a line
+        #[arg(long, default_value_t = FormatterOptions::default().line_width)]
+        line_width: usize,
+
+        /// Sort consecutive import directives by their path
+        #[clap(long)]
+        sort_imports: bool,
+    },
+
+    /// Runs a Language Server Protocol server, communicating with the client over stdin and stdout
+    Lsp,
 }
 
 fn main() -> Result<ExitCode> {
@@ -47,17 +79,36 @@ fn main() -> Result<ExitCode> {
             file_path,
             version,
             json,
-        } => execute_parse_command(&file_path, version, json),
+        } => execute_parse_command(&file_path, version.as_ref(), json),
+        Commands::Format {
+            file_path,
+            version,
+            write,
+            line_width,
+            sort_imports,
+        } => {
+            let options = FormatterOptions {
+                line_width,
+                sort_imports,
+                ..FormatterOptions::default()
+            };
+            execute_format_command(&file_path, version.as_ref(), write, &options)
+        }
+        Commands::Lsp => execute_lsp_command(),
     }
 }
 
-fn execute_parse_command(file_path_string: &str, version: Version, json: bool) -> Result<ExitCode> {
+fn execute_parse_command(
+    file_path_string: &str,
+    version: Option<&Version>,
+    json: bool,
+) -> Result<ExitCode> {
     let file_path = PathBuf::from(&file_path_string)
         .canonicalize()
         .with_context(|| format!("Failed to find file path: {file_path_string:?}"))?;
 
     let input = fs::read_to_string(file_path)?;
-    let language = Language::new(version)?;
+    let language = select_language(file_path_string, &input, version)?;
     let output = language.parse(RuleKind::SourceUnit, &input);
 
     let errors = output.errors();
@@ -81,6 +132,75 @@ fn execute_parse_command(file_path_string: &str, version: Version, json: bool) -
     }
 }
 
+fn execute_format_command(
+    file_path_string: &str,
+    version: Option<&Version>,
+    write: bool,
+    options: &FormatterOptions,
+) -> Result<ExitCode> {
+    let file_path = PathBuf::from(&file_path_string)
+        .canonicalize()
+        .with_context(|| format!("Failed to find file path: {file_path_string:?}"))?;
+
+    let input = fs::read_to_string(&file_path)?;
+    let language = select_language(file_path_string, &input, version)?;
+    let output = language.parse(RuleKind::SourceUnit, &input);
+
+    let errors = output.errors();
+    if !errors.is_empty() {
+        for error in errors {
+            const COLOR: bool = true;
+            let report = slang_solidity::diagnostic::render(error, file_path_string, &input, COLOR);
+            eprintln!("{report}");
+        }
+
+        eprintln!("Couldn't format the Solidity source file.");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let formatted = formatter::format(&output, options)?;
+
+    if write {
+        fs::write(file_path, formatted)?;
+    } else {
+        print!("{formatted}");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Selects the language version from the `pragma solidity` directives of the input, unless one was requested,
+/// and reports any problems with them. Requesting an unsupported version is an error.
+fn select_language(
+    file_path_string: &str,
+    input: &str,
+    version: Option<&Version>,
+) -> Result<Language> {
+    let requested = version
+        .map(|version| Language::new(version.clone()))
+        .transpose()?;
+
+    let selection = version_selection::select_version(input, version);
+
+    for error in &selection.errors {
+        const COLOR: bool = true;
+        let report = slang_solidity::diagnostic::render(error, file_path_string, input, COLOR);
+        eprintln!("{report}");
+    }
+
+    Ok(requested.unwrap_or_else(|| selection.language()))
+}
+
+fn execute_lsp_command() -> Result<ExitCode> {
+    let shutdown_requested = slang_solidity::lsp::run(std::io::stdin().lock(), std::io::stdout())?;
+
+    if shutdown_requested {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
 #[test]
 fn verify_clap_cli() {
     // Catches problems earlier in the development cycle:
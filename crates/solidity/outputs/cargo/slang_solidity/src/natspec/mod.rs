@@ -0,0 +1,278 @@
+//! Structured parsing of `NatSpec` documentation comments (`///` and `/** */`).
+//!
+//! `NatSpec` comments are collected from the leading trivia of each documented declaration (contracts, interfaces,
+//! libraries, functions, modifiers, events and errors), and parsed into a list of [`NatSpecTag`]s.
+//! See <https://docs.soliditylang.org/en/latest/natspec-format.html> for the format itself.
+
+mod parser;
+
+use crate::cursor::Cursor;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::parse_output::ParseOutput;
+use crate::text_index::TextRange;
+use crate::trivia::Comment;
+
+/// The declarations that `NatSpec` comments can be attached to.
+const DOCUMENTED_KINDS: &[RuleKind] = &[
+    RuleKind::ContractDefinition,
+    RuleKind::InterfaceDefinition,
+    RuleKind::LibraryDefinition,
+    RuleKind::FunctionDefinition,
+    RuleKind::ConstructorDefinition,
+    RuleKind::FallbackFunctionDefinition,
+    RuleKind::ReceiveFunctionDefinition,
+    RuleKind::UnnamedFunctionDefinition,
+    RuleKind::ModifierDefinition,
+    RuleKind::EventDefinition,
+    RuleKind::ErrorDefinition,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NatSpecTagKind {
+    /// `@title`
+    Title,
+    /// `@author`
+    Author,
+    /// `@notice`, or untagged text at the start of a comment.
+    Notice,
+    /// `@dev`
+    Dev,
+    /// `@param <name>`
+    Param,
+    /// `@return`
+    Return,
+    /// `@inheritdoc <contract>`
+    InheritDoc,
+    /// `@custom:<name>`, holding the name after the `custom:` prefix.
+    Custom(String),
+    /// Any other tag, holding its name without the `@` prefix. Reported as a [`NatSpecError::UnknownTag`].
+    Unknown(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NatSpecTag {
+    pub kind: NatSpecTagKind,
+    /// Range of the tag in the source, from the `@` (or the first word of an implicit `@notice`) to the end of its content.
+    pub text_range: TextRange,
+    /// The name following `@param` and `@inheritdoc` tags, along with its range in the source.
+    pub name: Option<(String, TextRange)>,
+    /// The text of the tag, with comment delimiters removed, and lines joined with `\n`.
+    pub content: String,
+}
+
+/// The `NatSpec` documentation of a single declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NatSpec {
+    pub tags: Vec<NatSpecTag>,
+    /// The `NatSpec` comments the tags were parsed from.
+    pub comments: Vec<Comment>,
+}
+
+impl NatSpec {
+    /// Returns all tags of the given kind, in source order.
+    pub fn tags_of_kind<'a>(
+        &'a self,
+        kind: &'a NatSpecTagKind,
+    ) -> impl Iterator<Item = &'a NatSpecTag> + 'a {
+        self.tags.iter().filter(move |tag| &tag.kind == kind)
+    }
+
+    /// Returns the `@param` tag documenting the parameter with the given name, if any.
+    pub fn param(&self, name: &str) -> Option<&NatSpecTag> {
+        self.tags_of_kind(&NatSpecTagKind::Param)
+            .find(|tag| matches!(&tag.name, Some((tag_name, _)) if tag_name == name))
+    }
+}
+
+/// A declaration, along with the `NatSpec` documentation attached to it.
+#[derive(Clone, Debug)]
+pub struct DocumentedItem {
+    pub kind: RuleKind,
+    /// The name of the declaration, if it has one (e.g. constructors and `receive` functions don't).
+    pub name: Option<String>,
+    /// A cursor pointing to the declaration.
+    pub cursor: Cursor,
+    pub natspec: NatSpec,
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum NatSpecError {
+    #[error("Unknown NatSpec tag '@{tag}'.")]
+    UnknownTag { tag: String, text_range: TextRange },
+
+    #[error("NatSpec tag '@{tag}' is missing a name.")]
+    MissingName { tag: String, text_range: TextRange },
+
+    #[error("Documented parameter '{name}' not found in the list of parameters.")]
+    UnknownParameter { name: String, text_range: TextRange },
+}
+
+impl Diagnostic for NatSpecError {
+    fn range(&self) -> TextRange {
+        match self {
+            Self::UnknownTag { text_range, .. }
+            | Self::MissingName { text_range, .. }
+            | Self::UnknownParameter { text_range, .. } => text_range.clone(),
+        }
+    }
+
+    fn code(&self) -> Box<dyn std::fmt::Display> {
+        Box::new(match self {
+            Self::UnknownTag { .. } => "NatSpecUnknownTag",
+            Self::MissingName { .. } => "NatSpecMissingName",
+            Self::UnknownParameter { .. } => "NatSpecUnknownParameter",
+        })
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self) -> String {
+        ToString::to_string(&self)
+    }
+}
+
+/// The result of [`extract`]: all documented declarations in a parse tree, and any problems found in their `NatSpec`.
+#[derive(Clone, Debug, Default)]
+pub struct NatSpecOutput {
+    items: Vec<DocumentedItem>,
+    diagnostics: Vec<NatSpecError>,
+}
+
+impl NatSpecOutput {
+    pub fn items(&self) -> &Vec<DocumentedItem> {
+        &self.items
+    }
+
+    pub fn diagnostics(&self) -> &Vec<NatSpecError> {
+        &self.diagnostics
+    }
+}
+
+/// Extracts the `NatSpec` documentation of all declarations in the parse tree, in source order.
+/// Declarations without any `NatSpec` comments are skipped.
+pub fn extract(output: &ParseOutput) -> NatSpecOutput {
+    let mut result = NatSpecOutput::default();
+
+    let mut cursor = output.create_tree_cursor();
+    while cursor.go_to_next_rule_with_kinds(DOCUMENTED_KINDS) {
+        let Some((natspec, mut diagnostics)) = natspec_of(&cursor) else {
+            continue;
+        };
+
+        result.diagnostics.append(&mut diagnostics);
+        result.items.push(DocumentedItem {
+            kind: cursor.node().as_rule().expect("matched a rule kind").kind,
+            name: declaration_name(&cursor),
+            cursor: cursor.clone(),
+            natspec,
+        });
+    }
+
+    result
+}
+
+/// Returns the `NatSpec` documentation attached to the declaration the cursor points to,
+/// along with any problems found in it, or `None` if the declaration has no `NatSpec` comments.
+///
+/// The cursor should not be re-rooted (see [`Cursor::spawn`]), as the comments are found in the preceding trivia.
+pub fn natspec_of(cursor: &Cursor) -> Option<(NatSpec, Vec<NatSpecError>)> {
+    let comments: Vec<_> = cursor
+        .attached_trivia()
+        .leading_comments
+        .into_iter()
+        .filter(|comment| {
+            matches!(
+                comment.token.kind,
+                TokenKind::SingleLineNatSpecComment | TokenKind::MultiLineNatSpecComment
+            )
+        })
+        .collect();
+
+    if comments.is_empty() {
+        return None;
+    }
+
+    let (tags, mut diagnostics) = parser::parse_tags(&comments);
+
+    let parameters = parameter_names(cursor);
+    for tag in &tags {
+        if let (NatSpecTagKind::Param, Some((name, text_range))) = (&tag.kind, &tag.name) {
+            if !parameters.contains(name) {
+                diagnostics.push(NatSpecError::UnknownParameter {
+                    name: name.clone(),
+                    text_range: text_range.clone(),
+                });
+            }
+        }
+    }
+
+    Some((NatSpec { tags, comments }, diagnostics))
+}
+
+fn declaration_name(cursor: &Cursor) -> Option<String> {
+    let name = child_with_label(cursor, NodeLabel::Name)?;
+    first_token_text(&name)
+}
+
+/// Collects the names of the declared parameters, if the declaration has any.
+fn parameter_names(cursor: &Cursor) -> Vec<String> {
+    // Error definitions label their parameters declaration as `Members`:
+    let Some(declaration) = child_with_label(cursor, NodeLabel::Parameters)
+        .or_else(|| child_with_label(cursor, NodeLabel::Members))
+    else {
+        return vec![];
+    };
+    let Some(mut item) = child_with_label(&declaration, NodeLabel::Parameters) else {
+        return vec![];
+    };
+
+    let mut names = vec![];
+    if !item.go_to_first_child() {
+        return names;
+    }
+
+    loop {
+        if item.label() == Some(NodeLabel::Item) {
+            names.extend(
+                child_with_label(&item, NodeLabel::Name).and_then(|name| first_token_text(&name)),
+            );
+        }
+
+        if !item.go_to_next_sibling() {
+            return names;
+        }
+    }
+}
+
+fn child_with_label(cursor: &Cursor, label: NodeLabel) -> Option<Cursor> {
+    let mut child = cursor.spawn();
+    if !child.go_to_first_child() {
+        return None;
+    }
+
+    loop {
+        if child.label() == Some(label) {
+            return Some(child);
+        }
+
+        if !child.go_to_next_sibling() {
+            return None;
+        }
+    }
+}
+
+fn first_token_text(cursor: &Cursor) -> Option<String> {
+    if let Some(token) = cursor.node().as_token() {
+        return Some(token.text.clone());
+    }
+
+    let mut token = cursor.spawn();
+    if !token.go_to_next_non_trivia_token() {
+        return None;
+    }
+
+    token.node().as_token().map(|token| token.text.clone())
+}
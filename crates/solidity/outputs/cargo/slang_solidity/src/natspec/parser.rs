@@ -0,0 +1,227 @@
+use crate::kinds::TokenKind;
+use crate::natspec::{NatSpecError, NatSpecTag, NatSpecTagKind};
+use crate::text_index::{TextIndex, TextRange};
+use crate::trivia::Comment;
+
+/// A line of comment content, with the comment delimiters and any leading `*` removed.
+struct Line<'a> {
+    text: &'a str,
+    start: TextIndex,
+}
+
+impl<'a> Line<'a> {
+    fn offset_of(&self, byte_offset: usize) -> TextIndex {
+        self.start + TextIndex::from(&self.text[..byte_offset])
+    }
+
+    /// Splits off the first word of the line, returning it along with the rest of the line.
+    fn split_word(&self) -> Option<(Line<'a>, Line<'a>)> {
+        let leading_whitespace = self.text.len() - self.text.trim_start().len();
+        if leading_whitespace == self.text.len() {
+            return None;
+        }
+
+        let word_end = self.text[leading_whitespace..]
+            .find(char::is_whitespace)
+            .map_or(self.text.len(), |end| leading_whitespace + end);
+
+        let word = Line {
+            text: &self.text[leading_whitespace..word_end],
+            start: self.offset_of(leading_whitespace),
+        };
+        let rest = Line {
+            text: &self.text[word_end..],
+            start: self.offset_of(word_end),
+        };
+
+        Some((word, rest))
+    }
+
+    fn trimmed(&self) -> Line<'a> {
+        let leading_whitespace = self.text.len() - self.text.trim_start().len();
+
+        Line {
+            text: self.text.trim(),
+            start: self.offset_of(leading_whitespace),
+        }
+    }
+
+    fn end(&self) -> TextIndex {
+        self.offset_of(self.text.len())
+    }
+}
+
+/// A tag that is still collecting its content lines.
+struct PendingTag {
+    kind: NatSpecTagKind,
+    start: TextIndex,
+    end: TextIndex,
+    name: Option<(String, TextRange)>,
+    content: Vec<String>,
+}
+
+impl PendingTag {
+    fn add_content(&mut self, line: &Line<'_>) {
+        let line = line.trimmed();
+
+        if line.text.is_empty() {
+            // Blank lines only matter between two content lines:
+            if !self.content.is_empty() {
+                self.content.push(String::new());
+            }
+        } else {
+            self.content.push(line.text.to_owned());
+            self.end = line.end();
+        }
+    }
+
+    fn finish(mut self) -> NatSpecTag {
+        while self.content.last().is_some_and(String::is_empty) {
+            self.content.pop();
+        }
+
+        NatSpecTag {
+            kind: self.kind,
+            text_range: self.start..self.end,
+            name: self.name,
+            content: self.content.join("\n"),
+        }
+    }
+}
+
+/// Parses the tags of a list of consecutive `NatSpec` comments.
+pub(super) fn parse_tags(comments: &[Comment]) -> (Vec<NatSpecTag>, Vec<NatSpecError>) {
+    let mut tags = vec![];
+    let mut errors = vec![];
+    let mut current: Option<PendingTag> = None;
+
+    for line in comments.iter().flat_map(comment_lines) {
+        let Some((word, rest)) = line.split_word() else {
+            if let Some(current) = &mut current {
+                current.add_content(&line);
+            }
+            continue;
+        };
+
+        let Some(tag) = word.text.strip_prefix('@') else {
+            // Untagged content is either a continuation of the previous tag, or an implicit `@notice`:
+            current
+                .get_or_insert_with(|| PendingTag {
+                    kind: NatSpecTagKind::Notice,
+                    start: word.start,
+                    end: word.start,
+                    name: None,
+                    content: vec![],
+                })
+                .add_content(&line);
+            continue;
+        };
+
+        tags.extend(current.take().map(PendingTag::finish));
+
+        let kind = match tag {
+            "title" => NatSpecTagKind::Title,
+            "author" => NatSpecTagKind::Author,
+            "notice" => NatSpecTagKind::Notice,
+            "dev" => NatSpecTagKind::Dev,
+            "param" => NatSpecTagKind::Param,
+            "return" => NatSpecTagKind::Return,
+            "inheritdoc" => NatSpecTagKind::InheritDoc,
+            _ => match tag.strip_prefix("custom:") {
+                Some(name) if is_valid_custom_name(name) => NatSpecTagKind::Custom(name.to_owned()),
+                _ => {
+                    errors.push(NatSpecError::UnknownTag {
+                        tag: tag.to_owned(),
+                        text_range: word.start..word.end(),
+                    });
+                    NatSpecTagKind::Unknown(tag.to_owned())
+                }
+            },
+        };
+
+        let mut pending = PendingTag {
+            kind,
+            start: word.start,
+            end: word.end(),
+            name: None,
+            content: vec![],
+        };
+
+        let mut rest = rest;
+        if matches!(
+            pending.kind,
+            NatSpecTagKind::Param | NatSpecTagKind::InheritDoc
+        ) {
+            match rest.split_word() {
+                Some((name, after_name)) => {
+                    pending.name = Some((name.text.to_owned(), name.start..name.end()));
+                    pending.end = name.end();
+                    rest = after_name;
+                }
+                None => errors.push(NatSpecError::MissingName {
+                    tag: tag.to_owned(),
+                    text_range: word.start..word.end(),
+                }),
+            }
+        }
+
+        pending.add_content(&rest);
+        current = Some(pending);
+    }
+
+    tags.extend(current.take().map(PendingTag::finish));
+
+    (tags, errors)
+}
+
+/// Custom tags are of the form `@custom:name`, where the name consists of lowercase letters and hyphens.
+fn is_valid_custom_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+}
+
+fn comment_lines(comment: &Comment) -> Vec<Line<'_>> {
+    let text = comment.token.text.as_str();
+    let start = comment.text_range.start;
+
+    match comment.token.kind {
+        TokenKind::SingleLineNatSpecComment => {
+            let prefix = "///";
+            vec![Line {
+                text: &text[prefix.len()..],
+                start: start + TextIndex::from(prefix),
+            }]
+        }
+        TokenKind::MultiLineNatSpecComment => {
+            let body = text
+                .strip_prefix("/**")
+                .and_then(|body| body.strip_suffix("*/"))
+                .unwrap_or_default();
+            let mut offset = start + TextIndex::from("/**");
+
+            let mut lines = vec![];
+            for (index, line) in body.split_inclusive('\n').enumerate() {
+                let line_start = offset;
+                offset += TextIndex::from(line);
+
+                let content = line.trim_end_matches(['\n', '\r']);
+
+                // Continuation lines usually start with a `*`, aligned with the opening delimiter:
+                let prefix_len = if index == 0 {
+                    0
+                } else {
+                    let trimmed = content.trim_start();
+                    let whitespace = content.len() - trimmed.len();
+                    whitespace + usize::from(trimmed.starts_with('*'))
+                };
+
+                lines.push(Line {
+                    text: &content[prefix_len..],
+                    start: line_start + TextIndex::from(&content[..prefix_len]),
+                });
+            }
+
+            lines
+        }
+        _ => vec![],
+    }
+}
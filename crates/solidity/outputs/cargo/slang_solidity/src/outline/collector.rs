@@ -0,0 +1,290 @@
+use super::{FoldingRange, FoldingRangeKind, Outline, Symbol, SymbolKind};
+use crate::cst::{LabeledNode, Node, RuleNode, TokenNode};
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::parse_output::ParseOutput;
+use crate::text_index::{LineIndex, LineTerminators, TextIndex, TextRange};
+
+pub(super) struct Collector {
+    line_index: LineIndex,
+    folding_ranges: Vec<FoldingRange>,
+    comment_run: Option<CommentRun>,
+}
+
+/// Consecutive single-line comments, that are only separated by whitespace and single line breaks.
+struct CommentRun {
+    range: TextRange,
+    line_breaks: usize,
+}
+
+impl Collector {
+    pub fn collect(output: &ParseOutput) -> Outline {
+        let mut collector = Self {
+            line_index: LineIndex::with_terminators(&output.tree().unparse(), LineTerminators::Lsp),
+            folding_ranges: vec![],
+            comment_run: None,
+        };
+
+        let symbols = match &output.tree() {
+            Node::Rule(rule) => collector.visit(rule, TextIndex::ZERO),
+            Node::Token(_) => vec![],
+        };
+
+        collector.end_comment_run();
+
+        // Blocks are collected before the comments and blocks within them, so ranges have to be ordered by position:
+        let mut folding_ranges = collector.folding_ranges;
+        folding_ranges.sort_by_key(|folding_range| folding_range.range.start.utf8);
+
+        Outline {
+            symbols,
+            folding_ranges,
+        }
+    }
+
+    fn visit(&mut self, rule: &RuleNode, offset: TextIndex) -> Vec<Symbol> {
+        self.fold_block(rule, offset);
+
+        if rule.kind == RuleKind::SourceUnitMembers {
+            self.fold_imports(rule, offset);
+        }
+
+        let mut symbols = vec![];
+        let mut offset = offset;
+
+        for child in &rule.children {
+            let range = offset..offset + child.text_len();
+
+            match &child.node {
+                Node::Rule(child_rule) => match symbol_kind(child_rule) {
+                    Some(kind) => symbols.push(self.symbol(child_rule, kind, range)),
+                    None => symbols.extend(self.visit(child_rule, offset)),
+                },
+                Node::Token(token) => {
+                    self.visit_token(token, range.clone());
+
+                    if rule.kind == RuleKind::EnumMembers && token.kind == TokenKind::Identifier {
+                        symbols.push(Symbol {
+                            name: token.text.clone(),
+                            kind: SymbolKind::EnumMember,
+                            range: range.clone(),
+                            name_range: range,
+                            children: vec![],
+                        });
+                    }
+                }
+            }
+
+            offset += child.text_len();
+        }
+
+        symbols
+    }
+
+    fn symbol(&mut self, rule: &RuleNode, kind: SymbolKind, full_range: TextRange) -> Symbol {
+        let offset = full_range.start;
+
+        let range = significant_range_of_rule(rule, offset).unwrap_or(full_range);
+
+        // Special functions have no name, and are named after their first keyword instead:
+        let name = find_child(rule, offset, |child| child.label == Some(NodeLabel::Name))
+            .or_else(|| find_child(rule, offset, |child| !child.is_trivia()));
+
+        let (name, name_range) = name
+            .and_then(|(name, name_offset)| {
+                let name_range = significant_range(&name.node, name_offset)?;
+                Some((name.node.clone().unparse().trim().to_owned(), name_range))
+            })
+            .unwrap_or_else(|| (String::new(), range.start..range.start));
+
+        let children = self.visit(rule, offset);
+
+        Symbol {
+            name,
+            kind,
+            range,
+            name_range,
+            children,
+        }
+    }
+
+    fn visit_token(&mut self, token: &TokenNode, range: TextRange) {
+        match token.kind {
+            TokenKind::SingleLineComment | TokenKind::SingleLineNatSpecComment => {
+                match &mut self.comment_run {
+                    Some(run) if run.line_breaks <= 1 => {
+                        run.range.end = range.end;
+                        run.line_breaks = 0;
+                    }
+                    _ => {
+                        self.end_comment_run();
+                        self.comment_run = Some(CommentRun {
+                            range,
+                            line_breaks: 0,
+                        });
+                    }
+                }
+            }
+            TokenKind::MultiLineComment | TokenKind::MultiLineNatSpecComment => {
+                self.end_comment_run();
+                self.fold(range, FoldingRangeKind::Comment);
+            }
+            TokenKind::EndOfLine => {
+                if let Some(run) = &mut self.comment_run {
+                    run.line_breaks += 1;
+                }
+            }
+            TokenKind::Whitespace => {}
+            _ => self.end_comment_run(),
+        }
+    }
+
+    fn end_comment_run(&mut self) {
+        if let Some(run) = self.comment_run.take() {
+            self.fold(run.range, FoldingRangeKind::Comment);
+        }
+    }
+
+    fn fold_block(&mut self, rule: &RuleNode, offset: TextIndex) {
+        let open = find_child(rule, offset, |child| {
+            child.is_token_with_kind(TokenKind::OpenBrace)
+        });
+        let close = find_child(rule, offset, |child| {
+            child.is_token_with_kind(TokenKind::CloseBrace)
+        });
+
+        if let (Some((_, open)), Some((close, close_offset))) = (open, close) {
+            self.fold(
+                open..close_offset + close.text_len(),
+                FoldingRangeKind::Block,
+            );
+        }
+    }
+
+    fn fold_imports(&mut self, members: &RuleNode, offset: TextIndex) {
+        let mut group: Option<TextRange> = None;
+        let mut offset = offset;
+
+        for member in &members.children {
+            let is_import = member.as_rule().is_some_and(|member| {
+                member
+                    .children
+                    .iter()
+                    .any(|variant| variant.is_rule_with_kind(RuleKind::ImportDirective))
+            });
+
+            match significant_range(&member.node, offset) {
+                Some(range) if is_import => {
+                    group = Some(match group {
+                        Some(group) => group.start..range.end,
+                        None => range,
+                    });
+                }
+                _ => {
+                    if let Some(group) = group.take() {
+                        self.fold(group, FoldingRangeKind::Imports);
+                    }
+                }
+            }
+
+            offset += member.text_len();
+        }
+
+        if let Some(group) = group {
+            self.fold(group, FoldingRangeKind::Imports);
+        }
+    }
+
+    /// Only keeps ranges that span multiple lines, since there is nothing to fold otherwise.
+    fn fold(&mut self, range: TextRange, kind: FoldingRangeKind) {
+        let start = self.line_index.line_column(range.start).line;
+        let end = self.line_index.line_column(range.end).line;
+
+        if start < end {
+            self.folding_ranges.push(FoldingRange { range, kind });
+        }
+    }
+}
+
+fn symbol_kind(rule: &RuleNode) -> Option<SymbolKind> {
+    let kind = match rule.kind {
+        RuleKind::ContractDefinition => SymbolKind::Contract,
+        RuleKind::InterfaceDefinition => SymbolKind::Interface,
+        RuleKind::LibraryDefinition => SymbolKind::Library,
+        RuleKind::FunctionDefinition => SymbolKind::Function,
+        RuleKind::ConstructorDefinition => SymbolKind::Constructor,
+        RuleKind::FallbackFunctionDefinition
+        | RuleKind::ReceiveFunctionDefinition
+        | RuleKind::UnnamedFunctionDefinition => SymbolKind::SpecialFunction,
+        RuleKind::ModifierDefinition => SymbolKind::Modifier,
+        RuleKind::EventDefinition => SymbolKind::Event,
+        RuleKind::ErrorDefinition => SymbolKind::Error,
+        RuleKind::StructDefinition => SymbolKind::Struct,
+        RuleKind::StructMember => SymbolKind::StructMember,
+        RuleKind::EnumDefinition => SymbolKind::Enum,
+        RuleKind::StateVariableDefinition if is_constant(rule) => SymbolKind::Constant,
+        RuleKind::StateVariableDefinition => SymbolKind::StateVariable,
+        RuleKind::ConstantDefinition => SymbolKind::Constant,
+        RuleKind::UserDefinedValueTypeDefinition => SymbolKind::UserDefinedValueType,
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+fn is_constant(definition: &RuleNode) -> bool {
+    definition
+        .children
+        .iter()
+        .filter(|child| child.label == Some(NodeLabel::Attributes))
+        .filter_map(|child| child.as_rule())
+        .flat_map(|attributes| &attributes.children)
+        .filter_map(|attribute| attribute.as_rule())
+        .flat_map(|attribute| &attribute.children)
+        .any(|child| child.is_token_with_kind(TokenKind::ConstantKeyword))
+}
+
+/// Returns the first child matching the predicate, along with its offset.
+fn find_child(
+    rule: &RuleNode,
+    offset: TextIndex,
+    predicate: impl Fn(&LabeledNode) -> bool,
+) -> Option<(&LabeledNode, TextIndex)> {
+    let mut offset = offset;
+
+    for child in &rule.children {
+        if predicate(child) {
+            return Some((child, offset));
+        }
+
+        offset += child.text_len();
+    }
+
+    None
+}
+
+/// Returns the range of the node, excluding any leading or trailing trivia.
+pub(super) fn significant_range(node: &Node, offset: TextIndex) -> Option<TextRange> {
+    match node {
+        Node::Token(_) if node.is_trivia() => None,
+        Node::Token(_) => Some(offset..offset + node.text_len()),
+        Node::Rule(rule) => significant_range_of_rule(rule, offset),
+    }
+}
+
+fn significant_range_of_rule(rule: &RuleNode, offset: TextIndex) -> Option<TextRange> {
+    let mut range: Option<TextRange> = None;
+    let mut offset = offset;
+
+    for child in &rule.children {
+        if let Some(child_range) = significant_range(&child.node, offset) {
+            range = Some(match range {
+                Some(range) => range.start..child_range.end,
+                None => child_range,
+            });
+        }
+
+        offset += child.text_len();
+    }
+
+    range
+}
@@ -0,0 +1,122 @@
+//! Computing the outline of a document: the hierarchy of its declarations, the ranges that editors can fold, and the
+//! nested ranges used to expand a selection.
+//!
+//! Like [`semantic_tokens`](crate::semantic_tokens), the outline is purely syntactic: declarations are recognized by
+//! the [`RuleKind`](crate::kinds::RuleKind) of their nodes, and named after their `Name` [`NodeLabel`](crate::kinds::NodeLabel).
+
+mod collector;
+
+use serde::Serialize;
+
+use crate::cst::Node;
+use crate::parse_output::ParseOutput;
+use crate::text_index::{TextIndex, TextRange};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Contract,
+    Interface,
+    Library,
+    Function,
+    Constructor,
+    /// Fallback and receive functions, as well as unnamed functions of older versions.
+    SpecialFunction,
+    Modifier,
+    Event,
+    Error,
+    Struct,
+    StructMember,
+    Enum,
+    EnumMember,
+    StateVariable,
+    /// Constant state variables, and file-level constants.
+    Constant,
+    UserDefinedValueType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Symbol {
+    /// The name of the declaration. Special functions (e.g. constructors) are named after their keyword.
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The range of the whole declaration, excluding any leading or trailing trivia.
+    pub range: TextRange,
+    /// The range of the name, which is always within [`Symbol::range`].
+    pub name_range: TextRange,
+    /// Declarations nested within this one, in source order.
+    pub children: Vec<Symbol>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FoldingRangeKind {
+    /// From an opening brace to its closing brace, inclusive.
+    Block,
+    /// A multi-line comment, or a run of single-line comments on consecutive lines.
+    Comment,
+    /// A group of consecutive import directives.
+    Imports,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FoldingRange {
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Outline {
+    /// Top-level declarations, in source order.
+    pub symbols: Vec<Symbol>,
+    /// Ranges that span multiple lines, ordered by their start. Like in the Language Server Protocol, lines are only
+    /// terminated by `\n`, `\r\n` or `\r`.
+    pub folding_ranges: Vec<FoldingRange>,
+}
+
+/// Computes the declarations and folding ranges of the parse output, in a single pass over its tree.
+pub fn outline(output: &ParseOutput) -> Outline {
+    collector::Collector::collect(output)
+}
+
+/// Returns the ranges of all nodes containing the offset, from the innermost to the outermost, where each range
+/// contains the previous one. Ranges exclude leading and trailing trivia, and nodes with the same range as their
+/// child are only returned once.
+///
+/// Offsets between two tokens are considered inside the first one, so that a cursor placed right after an
+/// identifier selects it.
+pub fn selection_ranges(output: &ParseOutput, offset: TextIndex) -> Vec<TextRange> {
+    let mut ranges: Vec<TextRange> = vec![];
+
+    let mut node = output.tree();
+    let mut node_offset = TextIndex::ZERO;
+
+    loop {
+        if let Some(range) = collector::significant_range(&node, node_offset) {
+            if ranges.last() != Some(&range) {
+                ranges.push(range);
+            }
+        }
+
+        let Node::Rule(rule) = &node else {
+            break;
+        };
+
+        let mut child_offset = node_offset;
+        let Some((child, start)) = rule.children.iter().find_map(|child| {
+            let start = child_offset;
+            child_offset += child.text_len();
+
+            (!child.is_trivia() && start <= offset && offset <= child_offset)
+                .then_some((child, start))
+        }) else {
+            break;
+        };
+
+        node = child.node.clone();
+        node_offset = start;
+    }
+
+    ranges.reverse();
+    ranges
+}
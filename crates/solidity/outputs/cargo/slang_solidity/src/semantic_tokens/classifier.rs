@@ -0,0 +1,337 @@
+use super::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
+use crate::cst::{Node, Rc, RuleNode, TokenNode};
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::text_index::TextIndex;
+
+/// Global variables and functions that are always available, without being declared.
+const BUILT_INS: &[&str] = &[
+    "abi",
+    "addmod",
+    "assert",
+    "block",
+    "blockhash",
+    "ecrecover",
+    "gasleft",
+    "keccak256",
+    "msg",
+    "mulmod",
+    "now",
+    "require",
+    "ripemd160",
+    "selfdestruct",
+    "sha256",
+    "sha3",
+    "suicide",
+    "super",
+    "this",
+    "tx",
+];
+
+const NONE: u32 = 0;
+
+pub(super) struct Classifier<'a> {
+    tokens: Vec<SemanticToken>,
+    /// Rules enclosing the current token, along with their labels in their parents.
+    ancestors: Vec<(&'a RuleNode, Option<NodeLabel>)>,
+    offset: TextIndex,
+}
+
+impl<'a> Classifier<'a> {
+    pub fn classify(root: &'a Node) -> Vec<SemanticToken> {
+        let mut classifier = Self {
+            tokens: vec![],
+            ancestors: vec![],
+            offset: TextIndex::ZERO,
+        };
+
+        classifier.visit(root, None);
+        classifier.tokens
+    }
+
+    fn visit(&mut self, node: &'a Node, label: Option<NodeLabel>) {
+        match node {
+            Node::Rule(rule) => {
+                self.ancestors.push((rule, label));
+                for child in &rule.children {
+                    self.visit(&child.node, child.label);
+                }
+                self.ancestors.pop();
+            }
+            Node::Token(token) => {
+                if let Some((token_type, modifiers)) = self.token(token, label) {
+                    self.push_lines(&token.text, token_type, modifiers);
+                }
+
+                self.offset += node.text_len();
+            }
+        }
+    }
+
+    /// Pushes a token for each line of the text, excluding line terminators.
+    fn push_lines(&mut self, text: &str, token_type: SemanticTokenType, modifiers: u32) {
+        let is_terminator =
+            |c: char| matches!(c, '\n' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}');
+
+        let mut start = self.offset;
+        for line in text.split_inclusive(is_terminator) {
+            let content = line.trim_end_matches(is_terminator);
+            if !content.is_empty() {
+                self.tokens.push(SemanticToken {
+                    range: start..start + content.into(),
+                    token_type,
+                    modifiers,
+                });
+            }
+
+            start += line.into();
+        }
+    }
+
+    fn token(
+        &self,
+        token: &Rc<TokenNode>,
+        label: Option<NodeLabel>,
+    ) -> Option<(SemanticTokenType, u32)> {
+        let parent = self.ancestors.last().map(|(rule, _)| rule.kind);
+
+        let result = match token.kind {
+            TokenKind::SingleLineComment | TokenKind::MultiLineComment => {
+                (SemanticTokenType::Comment, NONE)
+            }
+            TokenKind::SingleLineNatSpecComment | TokenKind::MultiLineNatSpecComment => (
+                SemanticTokenType::Comment,
+                SemanticTokenModifier::Documentation.bit(),
+            ),
+            // Other trivia (whitespace) is not classified, even inside the rules below:
+            _ if token.kind.is_trivia() => return None,
+
+            TokenKind::Identifier | TokenKind::YulIdentifier => self.identifier(token, label),
+
+            _ if token.kind.is_keyword() => match parent {
+                Some(RuleKind::ElementaryType) => (
+                    SemanticTokenType::Type,
+                    SemanticTokenModifier::DefaultLibrary.bit(),
+                ),
+                Some(RuleKind::AddressType) if token.kind == TokenKind::AddressKeyword => (
+                    SemanticTokenType::Type,
+                    SemanticTokenModifier::DefaultLibrary.bit(),
+                ),
+                Some(RuleKind::YulBuiltInFunction) => (
+                    SemanticTokenType::Function,
+                    SemanticTokenModifier::DefaultLibrary.bit(),
+                ),
+                _ => (SemanticTokenType::Keyword, NONE),
+            },
+
+            // The only tokens of these rules are string literals:
+            _ if matches!(
+                parent,
+                Some(
+                    RuleKind::StringLiteral
+                        | RuleKind::HexStringLiteral
+                        | RuleKind::UnicodeStringLiteral
+                )
+            ) =>
+            {
+                (SemanticTokenType::String, NONE)
+            }
+            // Quoted version literals, as opposed to the numbers of version specifiers:
+            _ if parent == Some(RuleKind::VersionExpression)
+                && label == Some(NodeLabel::Variant) =>
+            {
+                (SemanticTokenType::String, NONE)
+            }
+            TokenKind::DecimalLiteral
+            | TokenKind::HexLiteral
+            | TokenKind::YulDecimalLiteral
+            | TokenKind::YulHexLiteral
+            | TokenKind::VersionSpecifier => (SemanticTokenType::Number, NONE),
+
+            _ if label == Some(NodeLabel::Operator) => (SemanticTokenType::Operator, NONE),
+
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    fn identifier(
+        &self,
+        token: &Rc<TokenNode>,
+        label: Option<NodeLabel>,
+    ) -> (SemanticTokenType, u32) {
+        let declaration = SemanticTokenModifier::Declaration.bit();
+        let readonly = SemanticTokenModifier::Readonly.bit();
+
+        let Some(&(parent, _)) = self.ancestors.last() else {
+            return (SemanticTokenType::Variable, NONE);
+        };
+
+        match (parent.kind, label) {
+            (RuleKind::ContractDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Class, declaration)
+            }
+            (RuleKind::InterfaceDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Interface, declaration)
+            }
+            (RuleKind::LibraryDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Namespace, declaration)
+            }
+            (RuleKind::StructDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Struct, declaration)
+            }
+            (RuleKind::StructMember, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Property, declaration)
+            }
+            (RuleKind::EnumDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Enum, declaration)
+            }
+            (RuleKind::EnumMembers, _) => (SemanticTokenType::EnumMember, declaration),
+            (RuleKind::EventDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Event, declaration)
+            }
+            (
+                RuleKind::ErrorDefinition | RuleKind::UserDefinedValueTypeDefinition,
+                Some(NodeLabel::Name),
+            ) => (SemanticTokenType::Type, declaration),
+            (RuleKind::FunctionName, _) => (SemanticTokenType::Function, declaration),
+            (RuleKind::ModifierDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Decorator, declaration)
+            }
+            (RuleKind::StateVariableDefinition, Some(NodeLabel::Name)) => {
+                if is_constant_state_variable(parent) {
+                    (SemanticTokenType::Property, declaration | readonly)
+                } else {
+                    (SemanticTokenType::Property, declaration)
+                }
+            }
+            (RuleKind::ConstantDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Variable, declaration | readonly)
+            }
+            (
+                RuleKind::Parameter
+                | RuleKind::EventParameter
+                | RuleKind::ErrorParameter
+                | RuleKind::MappingKey
+                | RuleKind::MappingValue,
+                Some(NodeLabel::Name),
+            ) => (SemanticTokenType::Parameter, declaration),
+            (
+                RuleKind::VariableDeclarationStatement
+                | RuleKind::TypedTupleMember
+                | RuleKind::UntypedTupleMember,
+                Some(NodeLabel::Name),
+            ) => (SemanticTokenType::Variable, declaration),
+
+            // Imported symbols can be any top-level declaration, but are most often types:
+            (RuleKind::ImportDeconstructionSymbol, _) => (SemanticTokenType::Type, NONE),
+            (RuleKind::ImportAlias, _) => match self.ancestor(1) {
+                Some((RuleKind::ImportDeconstructionSymbol, _)) => {
+                    (SemanticTokenType::Type, declaration)
+                }
+                _ => (SemanticTokenType::Namespace, declaration),
+            },
+
+            (RuleKind::NamedArgument, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Parameter, NONE)
+            }
+            (RuleKind::CatchClauseError, Some(NodeLabel::Name)) => (SemanticTokenType::Type, NONE),
+            (RuleKind::IdentifierPath, _) => self.identifier_path(parent, token),
+
+            (RuleKind::Expression, _) => {
+                let modifiers = if BUILT_INS.contains(&token.text.as_str()) {
+                    SemanticTokenModifier::DefaultLibrary.bit()
+                } else {
+                    NONE
+                };
+
+                if self.is_called(0) {
+                    (SemanticTokenType::Function, modifiers)
+                } else {
+                    (SemanticTokenType::Variable, modifiers)
+                }
+            }
+            // `MemberAccess` < `MemberAccessExpression` < `Expression`:
+            (RuleKind::MemberAccess, _) if self.is_called(2) => (SemanticTokenType::Method, NONE),
+            (RuleKind::MemberAccess, _) => (SemanticTokenType::Property, NONE),
+
+            (RuleKind::YulFunctionDefinition, Some(NodeLabel::Name)) => {
+                (SemanticTokenType::Function, declaration)
+            }
+            (RuleKind::YulParameters, _) => (SemanticTokenType::Parameter, declaration),
+            (RuleKind::YulReturnVariables | RuleKind::YulVariableDeclarationStatement, _) => {
+                (SemanticTokenType::Variable, declaration)
+            }
+            // `YulPathComponent` < `YulPath` < `YulExpression`:
+            (RuleKind::YulPathComponent, _) if self.is_called(2) => {
+                (SemanticTokenType::Function, NONE)
+            }
+
+            _ => (SemanticTokenType::Variable, NONE),
+        }
+    }
+
+    /// Classifies an identifier in a path (e.g. `Foo.Bar`), based on where the path is used.
+    /// All identifiers except the last one are namespaces.
+    fn identifier_path(&self, path: &RuleNode, token: &Rc<TokenNode>) -> (SemanticTokenType, u32) {
+        let is_last = path
+            .children
+            .iter()
+            .rev()
+            .find_map(|child| child.as_token_with_kind(TokenKind::Identifier))
+            .is_some_and(|last| Rc::ptr_eq(last, token));
+
+        if !is_last {
+            return (SemanticTokenType::Namespace, NONE);
+        }
+
+        let token_type = match self.ancestor(1) {
+            Some((RuleKind::ModifierInvocation, _)) => SemanticTokenType::Decorator,
+            Some((RuleKind::EmitStatement, _)) => SemanticTokenType::Event,
+            Some((RuleKind::InheritanceType | RuleKind::OverridePaths, _)) => {
+                SemanticTokenType::Class
+            }
+            Some((RuleKind::UsingClause, _)) => SemanticTokenType::Namespace,
+            Some((RuleKind::UsingDeconstructionSymbol, _)) => SemanticTokenType::Function,
+            _ => SemanticTokenType::Type,
+        };
+
+        (token_type, NONE)
+    }
+
+    /// Returns the kind and label of the n-th ancestor of the current token, where `0` is its parent.
+    fn ancestor(&self, depth: usize) -> Option<(RuleKind, Option<NodeLabel>)> {
+        let index = self.ancestors.len().checked_sub(depth + 1)?;
+        let (rule, label) = self.ancestors[index];
+
+        Some((rule.kind, label))
+    }
+
+    /// Whether the n-th ancestor of the current token is the operand of a function call.
+    fn is_called(&self, depth: usize) -> bool {
+        matches!(self.ancestor(depth), Some((_, Some(NodeLabel::Operand))))
+            && matches!(
+                self.ancestor(depth + 1),
+                Some((
+                    RuleKind::FunctionCallExpression | RuleKind::YulFunctionCallExpression,
+                    _
+                ))
+            )
+    }
+}
+
+fn is_constant_state_variable(definition: &RuleNode) -> bool {
+    definition
+        .children
+        .iter()
+        .filter(|child| child.label == Some(NodeLabel::Attributes))
+        .filter_map(|child| child.as_rule())
+        .flat_map(|attributes| &attributes.children)
+        .filter_map(|attribute| attribute.as_rule())
+        .flat_map(|attribute| &attribute.children)
+        .any(|child| {
+            child
+                .as_token_with_kinds(&[TokenKind::ConstantKeyword, TokenKind::ImmutableKeyword])
+                .is_some()
+        })
+}
@@ -0,0 +1,146 @@
+//! Classifying tokens for semantic highlighting, using the token types and modifiers of the
+//! Language Server Protocol: <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocument_semanticTokens>
+//!
+//! The classification is purely syntactic: identifiers are classified by the [`NodeLabel`](crate::kinds::NodeLabel)
+//! and [`RuleKind`](crate::kinds::RuleKind) of their position in the tree (e.g. the `Name` of a `ContractDefinition`
+//! is a class), without resolving what they refer to.
+
+mod classifier;
+#[cfg(feature = "slang_napi_interfaces")]
+pub mod napi_interface;
+
+use strum::IntoEnumIterator;
+
+use crate::parse_output::ParseOutput;
+use crate::text_index::{LineIndex, TextIndex, TextRange, TextUnit};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::EnumIter, strum_macros::IntoStaticStr,
+)]
+#[strum(serialize_all = "camelCase")]
+pub enum SemanticTokenType {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    Struct,
+    Parameter,
+    Variable,
+    Property,
+    EnumMember,
+    Event,
+    Function,
+    Method,
+    Decorator,
+    Keyword,
+    Comment,
+    String,
+    Number,
+    Operator,
+}
+
+impl SemanticTokenType {
+    /// Returns the LSP names of all token types, ordered by the indices used in the encoded format.
+    pub fn legend() -> Vec<&'static str> {
+        Self::iter().map(Into::into).collect()
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::EnumIter, strum_macros::IntoStaticStr,
+)]
+#[strum(serialize_all = "camelCase")]
+pub enum SemanticTokenModifier {
+    /// The name of a declaration.
+    Declaration,
+    /// Constants and immutable state variables.
+    Readonly,
+    /// `NatSpec` comments.
+    Documentation,
+    /// Built-in types, variables, and functions.
+    DefaultLibrary,
+}
+
+impl SemanticTokenModifier {
+    /// Returns the LSP names of all token modifiers, ordered by their bits in the encoded format.
+    pub fn legend() -> Vec<&'static str> {
+        Self::iter().map(Into::into).collect()
+    }
+
+    /// The bit of this modifier in [`SemanticToken::modifiers`].
+    pub fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SemanticToken {
+    /// The range of the token, which never spans multiple lines.
+    pub range: TextRange,
+    pub token_type: SemanticTokenType,
+    /// A bit set of [`SemanticTokenModifier::bit`] values.
+    pub modifiers: u32,
+}
+
+impl SemanticToken {
+    pub fn has_modifier(&self, modifier: SemanticTokenModifier) -> bool {
+        self.modifiers & modifier.bit() != 0
+    }
+}
+
+/// Classifies the tokens of the parse output, in source order.
+///
+/// Whitespace and punctuation are not classified. Tokens that span multiple lines (e.g. multi-line comments) are
+/// split into one token per line, as not all LSP clients support multi-line tokens.
+pub fn classify(output: &ParseOutput) -> Vec<SemanticToken> {
+    classifier::Classifier::classify(&output.tree())
+}
+
+/// Encodes tokens in the relative format of the LSP `SemanticTokens.data` array: five integers per token,
+/// for the line delta, start column delta, length, token type index, and modifiers bit set.
+///
+/// Columns and lengths are measured in the given `unit`, which is [`TextUnit::Utf16`] by default in LSP.
+pub fn encode(tokens: &[SemanticToken], line_index: &LineIndex, unit: TextUnit) -> Vec<u32> {
+    let mut data = Vec::with_capacity(tokens.len() * 5);
+
+    let mut previous_line = 0;
+    let mut previous_column = 0;
+
+    for token in tokens {
+        let start = line_index.line_column(token.range.start);
+        let column = measure(start.column, unit);
+        let length = measure(token.range.end - token.range.start, unit);
+
+        let delta_column = if start.line == previous_line {
+            column - previous_column
+        } else {
+            column
+        };
+
+        data.extend([
+            to_u32(start.line - previous_line),
+            to_u32(delta_column),
+            to_u32(length),
+            token.token_type as u32,
+            token.modifiers,
+        ]);
+
+        previous_line = start.line;
+        previous_column = column;
+    }
+
+    data
+}
+
+fn measure(index: TextIndex, unit: TextUnit) -> usize {
+    match unit {
+        TextUnit::Utf8 => index.utf8,
+        TextUnit::Utf16 => index.utf16,
+        TextUnit::Char => index.char,
+    }
+}
+
+fn to_u32(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
@@ -0,0 +1,36 @@
+use napi_derive::napi;
+
+use crate::napi_interface::parse_output::ParseOutput;
+use crate::napi_interface::text_index::TextUnit;
+
+#[napi(object, namespace = "semantic_tokens")]
+pub struct SemanticTokensLegend {
+    pub token_types: Vec<String>,
+    pub token_modifiers: Vec<String>,
+}
+
+/// Returns the names of the token types and modifiers used in the encoded format.
+#[napi(namespace = "semantic_tokens", catch_unwind)]
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: super::SemanticTokenType::legend()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        token_modifiers: super::SemanticTokenModifier::legend()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    }
+}
+
+/// Classifies the tokens of the parse output, and encodes them in the relative format of the LSP
+/// `SemanticTokens.data` array, with columns and lengths measured in the given `unit`.
+#[napi(namespace = "semantic_tokens", catch_unwind)]
+pub fn encode(
+    #[napi(ts_arg_type = "parse_output.ParseOutput")] output: &ParseOutput,
+    #[napi(ts_arg_type = "text_index.TextUnit")] unit: TextUnit,
+) -> Vec<u32> {
+    let tokens = super::classify(&output.0);
+    super::encode(&tokens, &output.0.create_line_index(), unit.into())
+}
@@ -0,0 +1,2099 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+use crate::ast;
+use crate::cst::{LabeledNode, Node, Rc, RuleNode, TokenNode};
+use crate::kinds::{RuleKind, TokenKind};
+use crate::solc_ast::{literals, SolcNode, SolcValue, SourceLocation};
+use crate::text_index::TextIndex;
+use crate::trivia::Comment;
+
+/// Used by `solc` for optional locations, like the `nameLocation` of an unnamed declaration.
+const UNKNOWN_LOCATION: &str = "-1:-1:-1";
+
+/// Declarations that can have a `documentation` attribute.
+const DOCUMENTED_KINDS: &[RuleKind] = &[
+    RuleKind::ContractDefinition,
+    RuleKind::InterfaceDefinition,
+    RuleKind::LibraryDefinition,
+    RuleKind::FunctionDefinition,
+    RuleKind::ConstructorDefinition,
+    RuleKind::FallbackFunctionDefinition,
+    RuleKind::ReceiveFunctionDefinition,
+    RuleKind::UnnamedFunctionDefinition,
+    RuleKind::ModifierDefinition,
+    RuleKind::EventDefinition,
+    RuleKind::ErrorDefinition,
+    RuleKind::StateVariableDefinition,
+];
+
+/// Converts the typed AST wrappers into `solc` nodes. Since the wrappers don't carry any positions,
+/// the ranges of all nodes are collected upfront, keyed by the address of their CST node. This requires
+/// a tree without any shared subtrees (see [`unshare`]), so that each address has a single position.
+pub(super) struct Exporter {
+    source_index: usize,
+    source_len: usize,
+    ranges: HashMap<*const (), Range<usize>>,
+    /// Ranges of rules ending with a semicolon, excluding it. `solc` does not include it in most statements.
+    ranges_without_semicolon: HashMap<*const (), Range<usize>>,
+    documentation: HashMap<*const (), Vec<Comment>>,
+    license: Option<String>,
+    next_id: usize,
+}
+
+/// Attributes shared by all function-like definitions, normalized from their different attribute kinds.
+enum FunctionAttribute {
+    Modifier(ast::ModifierInvocation),
+    Override(ast::OverrideSpecifier),
+    Keyword(Rc<TokenNode>),
+}
+
+struct Function {
+    kind: &'static str,
+    name: Option<Rc<TokenNode>>,
+    parameters: ast::ParametersDeclaration,
+    returns: Option<ast::ReturnsDeclaration>,
+    attributes: Vec<FunctionAttribute>,
+    body: Option<ast::Block>,
+    visibility: &'static str,
+}
+
+struct Arguments {
+    names: Vec<String>,
+    name_locations: Vec<SourceLocation>,
+    values: Vec<SolcNode>,
+}
+
+impl Exporter {
+    pub(super) fn new(tree: &Node, source_index: usize) -> Self {
+        let mut exporter = Self {
+            source_index,
+            source_len: tree.text_len().utf8,
+            ranges: HashMap::new(),
+            ranges_without_semicolon: HashMap::new(),
+            documentation: HashMap::new(),
+            license: None,
+            next_id: 0,
+        };
+
+        exporter.collect_ranges(tree, 0);
+
+        let mut cursor = tree.cursor_with_offset(TextIndex::ZERO);
+        while cursor.go_to_next_rule_with_kinds(DOCUMENTED_KINDS) {
+            let comments: Vec<_> = cursor
+                .attached_trivia()
+                .leading_comments
+                .into_iter()
+                .filter(|comment| is_natspec(&comment.token))
+                .collect();
+
+            if let (Node::Rule(rule), false) = (cursor.node(), comments.is_empty()) {
+                exporter.documentation.insert(key(&rule), comments);
+            }
+        }
+
+        exporter
+    }
+
+    fn collect_ranges(&mut self, node: &Node, offset: usize) -> Option<Range<usize>> {
+        match node {
+            Node::Token(token) => {
+                if token.kind.is_trivia() {
+                    self.collect_license(token);
+                    return None;
+                }
+
+                let range = offset..offset + token.text.len();
+                self.ranges.insert(key(token), range.clone());
+                Some(range)
+            }
+            Node::Rule(rule) => {
+                let mut result: Option<Range<usize>> = None;
+                let mut before_semicolon = None;
+                let mut child_offset = offset;
+
+                for child in &rule.children {
+                    if let Some(child_range) = self.collect_ranges(&child.node, child_offset) {
+                        before_semicolon = child
+                            .node
+                            .is_token_with_kind(TokenKind::Semicolon)
+                            .then(|| result.clone())
+                            .flatten();
+
+                        result = Some(match result {
+                            Some(range) => range.start..child_range.end,
+                            None => child_range,
+                        });
+                    }
+
+                    child_offset += child.node.text_len().utf8;
+                }
+
+                if let Some(range) = before_semicolon {
+                    self.ranges_without_semicolon.insert(key(rule), range);
+                }
+                if let Some(range) = &result {
+                    self.ranges.insert(key(rule), range.clone());
+                }
+
+                result
+            }
+        }
+    }
+
+    fn collect_license(&mut self, token: &TokenNode) {
+        const MARKER: &str = "SPDX-License-Identifier:";
+
+        if self.license.is_some() {
+            return;
+        }
+
+        if let Some((_, rest)) = token.text.split_once(MARKER) {
+            let license = rest
+                .trim_start()
+                .split(|c: char| c.is_whitespace() || c == '*')
+                .next()
+                .unwrap_or_default();
+
+            if !license.is_empty() {
+                self.license = Some(license.to_owned());
+            }
+        }
+    }
+
+    //
+    // Helpers:
+    //
+
+    pub(super) fn range<T>(&self, node: &Rc<T>) -> Range<usize> {
+        self.ranges.get(&key(node)).cloned().unwrap_or_default()
+    }
+
+    /// Range of a statement or declaration, excluding its trailing semicolon (if any).
+    pub(super) fn statement_range(&self, node: &Rc<RuleNode>) -> Range<usize> {
+        self.ranges_without_semicolon
+            .get(&key(node))
+            .cloned()
+            .unwrap_or_else(|| self.range(node))
+    }
+
+    pub(super) fn location(&self, range: Range<usize>) -> SourceLocation {
+        SourceLocation {
+            start: range.start,
+            length: range.len(),
+            source_index: self.source_index,
+        }
+    }
+
+    fn token_location(&self, token: &Rc<TokenNode>) -> SourceLocation {
+        self.location(self.range(token))
+    }
+
+    fn optional_location(&self, token: Option<&Rc<TokenNode>>) -> SolcValue {
+        match token {
+            Some(token) => self.token_location(token).into(),
+            None => UNKNOWN_LOCATION.into(),
+        }
+    }
+
+    fn node(&mut self, node_type: &'static str, range: Range<usize>) -> SolcNode {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        SolcNode {
+            id: Some(id),
+            node_type,
+            src: self.location(range),
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    fn documentation(&mut self, cst: &Rc<RuleNode>) -> Option<SolcNode> {
+        let comments = self.documentation.get(&key(cst))?;
+
+        // Similar to `solc`, only the last documentation block is kept:
+        // either a single `/** */` comment, or a sequence of consecutive `///` comments.
+        let block = match comments.last()? {
+            last if last.token.kind == TokenKind::MultiLineNatSpecComment => {
+                &comments[comments.len() - 1..]
+            }
+            _ => {
+                let count = comments
+                    .iter()
+                    .rev()
+                    .take_while(|comment| comment.token.kind == TokenKind::SingleLineNatSpecComment)
+                    .count();
+                &comments[comments.len() - count..]
+            }
+        };
+
+        let text = block
+            .iter()
+            .map(|comment| {
+                let text = comment.token.text.as_str();
+                match comment.token.kind {
+                    TokenKind::MultiLineNatSpecComment => text
+                        .strip_prefix("/**")
+                        .and_then(|text| text.strip_suffix("*/"))
+                        .unwrap_or(text),
+                    _ => text.strip_prefix("///").unwrap_or(text),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let range = block.first()?.text_range.start.utf8..block.last()?.text_range.end.utf8;
+
+        let mut node = self.node("StructuredDocumentation", range);
+        node.set("text", text);
+        Some(node)
+    }
+
+    //
+    // Source Unit:
+    //
+
+    pub(super) fn source_unit(mut self, root: &Rc<RuleNode>, absolute_path: &str) -> SolcNode {
+        let unit = ast::SourceUnit::cast(Rc::clone(root)).expect("checked by the caller");
+
+        let mut node = self.node("SourceUnit", 0..self.source_len);
+        node.set("absolutePath", absolute_path);
+        node.set("license", self.license.clone());
+
+        let mut exported_symbols = BTreeMap::<String, Vec<SolcValue>>::new();
+        let mut nodes = vec![];
+
+        for member in unit.members().items() {
+            let child = self.source_unit_member(&member);
+
+            if let (Some(id), Some(SolcValue::String(name)), true) = (
+                child.id,
+                child.attribute("name"),
+                is_exported(child.node_type),
+            ) {
+                exported_symbols
+                    .entry(name.clone())
+                    .or_default()
+                    .push(id.into());
+            }
+
+            nodes.push(child);
+        }
+
+        node.set(
+            "exportedSymbols",
+            SolcValue::Map(
+                exported_symbols
+                    .into_iter()
+                    .map(|(name, ids)| (name, SolcValue::List(ids)))
+                    .collect(),
+            ),
+        );
+        node.set("nodes", nodes);
+        node
+    }
+
+    fn source_unit_member(&mut self, member: &ast::SourceUnitMember) -> SolcNode {
+        use ast::SourceUnitMemberVariant as Variant;
+
+        match member.variant() {
+            Variant::PragmaDirective(pragma) => self.pragma_directive(&pragma),
+            Variant::ImportDirective(import) => self.import_directive(&import),
+            Variant::ContractDefinition(contract) => self.contract_definition(
+                contract.cst(),
+                "contract",
+                contract.abstract_keyword().is_some(),
+                &contract.name(),
+                contract.inheritence(),
+                contract.members().items(),
+            ),
+            Variant::InterfaceDefinition(interface) => self.contract_definition(
+                interface.cst(),
+                "interface",
+                false,
+                &interface.name(),
+                interface.inheritence(),
+                interface.members().items(),
+            ),
+            Variant::LibraryDefinition(library) => self.contract_definition(
+                library.cst(),
+                "library",
+                false,
+                &library.name(),
+                None,
+                library.members().items(),
+            ),
+            Variant::StructDefinition(definition) => self.struct_definition(&definition),
+            Variant::EnumDefinition(definition) => self.enum_definition(&definition),
+            Variant::FunctionDefinition(definition) => {
+                self.function_definition(&definition, "freeFunction", "internal")
+            }
+            Variant::ConstantDefinition(definition) => self.constant_definition(&definition),
+            Variant::ErrorDefinition(definition) => self.error_definition(&definition),
+            Variant::UserDefinedValueTypeDefinition(definition) => {
+                self.user_defined_value_type_definition(&definition)
+            }
+            Variant::UsingDirective(directive) => self.using_directive(&directive),
+            Variant::EventDefinition(definition) => self.event_definition(&definition),
+        }
+    }
+
+    fn pragma_directive(&mut self, pragma: &ast::PragmaDirective) -> SolcNode {
+        use ast::{ExperimentalFeatureVariant, PragmaVariant};
+
+        let mut node = self.node("PragmaDirective", self.range(pragma.cst()));
+
+        let mut literals = vec![];
+        match pragma.pragma().variant() {
+            PragmaVariant::ABICoderPragma(abicoder) => {
+                literals.push(abicoder.abicoder_keyword().text.clone());
+                literals.push(abicoder.version().text.clone());
+            }
+            PragmaVariant::ExperimentalPragma(experimental) => {
+                literals.push(experimental.experimental_keyword().text.clone());
+                literals.push(match experimental.feature().variant() {
+                    ExperimentalFeatureVariant::StringLiteral(literal) => {
+                        string_literal_token(&literal).text.clone()
+                    }
+                    ExperimentalFeatureVariant::Token(token) => token.text.clone(),
+                });
+            }
+            PragmaVariant::VersionPragma(version) => {
+                literals.push(version.solidity_keyword().text.clone());
+
+                for (index, set) in version.sets().items().iter().enumerate() {
+                    if index > 0 {
+                        literals.push("||".to_owned());
+                    }
+                    for expression in set.items() {
+                        version_literals(&expression, &mut literals);
+                    }
+                }
+            }
+        }
+
+        node.set(
+            "literals",
+            literals
+                .into_iter()
+                .map(SolcValue::String)
+                .collect::<Vec<_>>(),
+        );
+        node
+    }
+
+    fn import_directive(&mut self, import: &ast::ImportDirective) -> SolcNode {
+        use ast::ImportClauseVariant;
+
+        let mut node = self.node("ImportDirective", self.range(import.cst()));
+
+        let (path, alias, symbols) = match import.clause().variant() {
+            ImportClauseVariant::PathImport(clause) => (clause.path(), clause.alias(), vec![]),
+            ImportClauseVariant::NamedImport(clause) => {
+                (clause.path(), Some(clause.alias()), vec![])
+            }
+            ImportClauseVariant::ImportDeconstruction(clause) => {
+                (clause.path(), None, clause.symbols().items())
+            }
+        };
+
+        let file =
+            String::from_utf8_lossy(&literals::decode_string(&string_literal_token(&path).text))
+                .into_owned();
+        let alias = alias.map(|alias| alias.identifier());
+
+        let mut symbol_aliases = vec![];
+        for symbol in symbols {
+            let foreign = self.identifier(&symbol.name());
+            let local = symbol.alias().map(|alias| alias.identifier());
+
+            let mut entry = BTreeMap::new();
+            entry.insert("foreign".to_owned(), foreign.into());
+            entry.insert(
+                "local".to_owned(),
+                local.as_ref().map(|local| local.text.clone()).into(),
+            );
+            entry.insert(
+                "nameLocation".to_owned(),
+                self.optional_location(local.as_ref()),
+            );
+            symbol_aliases.push(SolcValue::Map(entry));
+        }
+
+        node.set("absolutePath", file.clone());
+        node.set("file", file);
+        node.set("nameLocation", self.optional_location(alias.as_ref()));
+        node.set("symbolAliases", symbol_aliases);
+        node.set(
+            "unitAlias",
+            alias.map_or_else(String::new, |alias| alias.text.clone()),
+        );
+        node
+    }
+
+    //
+    // Contracts:
+    //
+
+    fn contract_definition(
+        &mut self,
+        cst: &Rc<RuleNode>,
+        kind: &'static str,
+        is_abstract: bool,
+        name: &Rc<TokenNode>,
+        inheritance: Option<ast::InheritanceSpecifier>,
+        members: Vec<ast::ContractMember>,
+    ) -> SolcNode {
+        let mut node = self.node("ContractDefinition", self.range(cst));
+
+        let documentation = self.documentation(cst);
+
+        let mut base_contracts = vec![];
+        for base in inheritance
+            .map(|inheritance| inheritance.types().items())
+            .unwrap_or_default()
+        {
+            let mut specifier = self.node("InheritanceSpecifier", self.range(base.cst()));
+            specifier.set("baseName", self.identifier_path(&base.type_name()));
+            specifier.set(
+                "arguments",
+                base.arguments()
+                    .map(|arguments| self.arguments(&arguments).values),
+            );
+            base_contracts.push(specifier);
+        }
+
+        let mut nodes = vec![];
+        for member in members {
+            nodes.push(self.contract_member(&member));
+        }
+
+        node.set("abstract", is_abstract);
+        node.set("baseContracts", base_contracts);
+        node.set("canonicalName", name.text.clone());
+        node.set("contractKind", kind);
+        node.set("documentation", documentation);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(name));
+        node.set("nodes", nodes);
+        node
+    }
+
+    #[allow(clippy::too_many_lines)] // Normalizes the attributes of each kind of function
+    fn contract_member(&mut self, member: &ast::ContractMember) -> SolcNode {
+        use ast::ContractMemberVariant as Variant;
+
+        match member.variant() {
+            Variant::UsingDirective(directive) => self.using_directive(&directive),
+            Variant::FunctionDefinition(definition) => {
+                self.function_definition(&definition, "function", "public")
+            }
+            Variant::ConstructorDefinition(definition) => {
+                let function = Function {
+                    kind: "constructor",
+                    name: None,
+                    parameters: definition.parameters(),
+                    returns: None,
+                    attributes: definition
+                        .attributes()
+                        .items()
+                        .into_iter()
+                        .map(|attribute| match attribute.variant() {
+                            ast::ConstructorAttributeVariant::ModifierInvocation(modifier) => {
+                                FunctionAttribute::Modifier(modifier)
+                            }
+                            ast::ConstructorAttributeVariant::Token(token) => {
+                                FunctionAttribute::Keyword(token)
+                            }
+                        })
+                        .collect(),
+                    body: Some(definition.body()),
+                    visibility: "public",
+                };
+                self.function(definition.cst(), function)
+            }
+            Variant::ReceiveFunctionDefinition(definition) => {
+                let function = Function {
+                    kind: "receive",
+                    name: None,
+                    parameters: definition.parameters(),
+                    returns: None,
+                    attributes: definition
+                        .attributes()
+                        .items()
+                        .into_iter()
+                        .map(|attribute| match attribute.variant() {
+                            ast::ReceiveFunctionAttributeVariant::ModifierInvocation(modifier) => {
+                                FunctionAttribute::Modifier(modifier)
+                            }
+                            ast::ReceiveFunctionAttributeVariant::OverrideSpecifier(specifier) => {
+                                FunctionAttribute::Override(specifier)
+                            }
+                            ast::ReceiveFunctionAttributeVariant::Token(token) => {
+                                FunctionAttribute::Keyword(token)
+                            }
+                        })
+                        .collect(),
+                    body: function_body(&definition.body()),
+                    visibility: "external",
+                };
+                self.function(definition.cst(), function)
+            }
+            Variant::FallbackFunctionDefinition(definition) => {
+                let function = Function {
+                    kind: "fallback",
+                    name: None,
+                    parameters: definition.parameters(),
+                    returns: definition.returns(),
+                    attributes: definition
+                        .attributes()
+                        .items()
+                        .into_iter()
+                        .map(|attribute| match attribute.variant() {
+                            ast::FallbackFunctionAttributeVariant::ModifierInvocation(modifier) => {
+                                FunctionAttribute::Modifier(modifier)
+                            }
+                            ast::FallbackFunctionAttributeVariant::OverrideSpecifier(specifier) => {
+                                FunctionAttribute::Override(specifier)
+                            }
+                            ast::FallbackFunctionAttributeVariant::Token(token) => {
+                                FunctionAttribute::Keyword(token)
+                            }
+                        })
+                        .collect(),
+                    body: function_body(&definition.body()),
+                    visibility: "external",
+                };
+                self.function(definition.cst(), function)
+            }
+            Variant::UnnamedFunctionDefinition(definition) => {
+                let function = Function {
+                    kind: "fallback",
+                    name: None,
+                    parameters: definition.parameters(),
+                    returns: None,
+                    attributes: definition
+                        .attributes()
+                        .items()
+                        .into_iter()
+                        .map(|attribute| match attribute.variant() {
+                            ast::UnnamedFunctionAttributeVariant::ModifierInvocation(modifier) => {
+                                FunctionAttribute::Modifier(modifier)
+                            }
+                            ast::UnnamedFunctionAttributeVariant::Token(token) => {
+                                FunctionAttribute::Keyword(token)
+                            }
+                        })
+                        .collect(),
+                    body: function_body(&definition.body()),
+                    visibility: "public",
+                };
+                self.function(definition.cst(), function)
+            }
+            Variant::ModifierDefinition(definition) => self.modifier_definition(&definition),
+            Variant::StructDefinition(definition) => self.struct_definition(&definition),
+            Variant::EnumDefinition(definition) => self.enum_definition(&definition),
+            Variant::EventDefinition(definition) => self.event_definition(&definition),
+            Variant::StateVariableDefinition(definition) => {
+                self.state_variable_definition(&definition)
+            }
+            Variant::ErrorDefinition(definition) => self.error_definition(&definition),
+            Variant::UserDefinedValueTypeDefinition(definition) => {
+                self.user_defined_value_type_definition(&definition)
+            }
+        }
+    }
+
+    fn using_directive(&mut self, directive: &ast::UsingDirective) -> SolcNode {
+        use ast::{UsingClauseVariant, UsingOperatorVariant, UsingTargetVariant};
+
+        let mut node = self.node("UsingForDirective", self.range(directive.cst()));
+
+        match directive.clause().variant() {
+            UsingClauseVariant::IdentifierPath(path) => {
+                node.set("libraryName", self.identifier_path(&path));
+            }
+            UsingClauseVariant::UsingDeconstruction(deconstruction) => {
+                let mut functions = vec![];
+                for symbol in deconstruction.symbols().items() {
+                    let path = self.identifier_path(&symbol.name());
+
+                    let mut entry = BTreeMap::new();
+                    match symbol.alias() {
+                        Some(alias) => {
+                            let UsingOperatorVariant::Token(operator) = alias.operator().variant();
+                            entry.insert("definition".to_owned(), path.into());
+                            entry.insert("operator".to_owned(), operator.text.clone().into());
+                        }
+                        None => {
+                            entry.insert("function".to_owned(), path.into());
+                        }
+                    }
+                    functions.push(SolcValue::Map(entry));
+                }
+                node.set("functionList", functions);
+            }
+        }
+
+        match directive.target().variant() {
+            UsingTargetVariant::TypeName(type_name) => {
+                node.set("typeName", self.type_name(&type_name));
+            }
+            // `using ... for *;`
+            UsingTargetVariant::Token(_) => {}
+        }
+
+        node.set("global", directive.global_keyword().is_some());
+        node
+    }
+
+    fn function_definition(
+        &mut self,
+        definition: &ast::FunctionDefinition,
+        kind: &'static str,
+        visibility: &'static str,
+    ) -> SolcNode {
+        let ast::FunctionNameVariant::Token(name) = definition.name().variant();
+
+        let function = Function {
+            kind,
+            name: Some(name),
+            parameters: definition.parameters(),
+            returns: definition.returns(),
+            attributes: definition
+                .attributes()
+                .items()
+                .into_iter()
+                .map(|attribute| match attribute.variant() {
+                    ast::FunctionAttributeVariant::ModifierInvocation(modifier) => {
+                        FunctionAttribute::Modifier(modifier)
+                    }
+                    ast::FunctionAttributeVariant::OverrideSpecifier(specifier) => {
+                        FunctionAttribute::Override(specifier)
+                    }
+                    ast::FunctionAttributeVariant::Token(token) => {
+                        FunctionAttribute::Keyword(token)
+                    }
+                })
+                .collect(),
+            body: function_body(&definition.body()),
+            visibility,
+        };
+
+        self.function(definition.cst(), function)
+    }
+
+    fn function(&mut self, cst: &Rc<RuleNode>, function: Function) -> SolcNode {
+        let mut node = self.node("FunctionDefinition", self.range(cst));
+
+        let documentation = self.documentation(cst);
+        let parameters = self.parameter_list(&function.parameters);
+        let return_parameters = match &function.returns {
+            Some(returns) => self.parameter_list(&returns.variables()),
+            None => self.empty_parameter_list(self.range(function.parameters.cst()).end),
+        };
+
+        let mut visibility = function.visibility;
+        let mut state_mutability = "nonpayable";
+        let mut is_virtual = false;
+        let mut modifiers = vec![];
+        let mut overrides = None;
+
+        for attribute in function.attributes {
+            match attribute {
+                FunctionAttribute::Modifier(modifier) => {
+                    modifiers.push(self.modifier_invocation(&modifier));
+                }
+                FunctionAttribute::Override(specifier) => {
+                    overrides = Some(self.override_specifier(&specifier));
+                }
+                FunctionAttribute::Keyword(keyword) => match keyword.kind {
+                    TokenKind::PublicKeyword => visibility = "public",
+                    TokenKind::PrivateKeyword => visibility = "private",
+                    TokenKind::InternalKeyword => visibility = "internal",
+                    TokenKind::ExternalKeyword => visibility = "external",
+                    TokenKind::PureKeyword => state_mutability = "pure",
+                    TokenKind::ViewKeyword | TokenKind::ConstantKeyword => {
+                        state_mutability = "view";
+                    }
+                    TokenKind::PayableKeyword => state_mutability = "payable",
+                    TokenKind::VirtualKeyword => is_virtual = true,
+                    _ => {}
+                },
+            }
+        }
+
+        let body = function.body.map(|body| self.block(&body));
+
+        node.set("documentation", documentation);
+        node.set("implemented", body.is_some());
+        node.set("body", body);
+        node.set("kind", function.kind);
+        node.set("modifiers", modifiers);
+        node.set(
+            "name",
+            function
+                .name
+                .as_ref()
+                .map_or_else(String::new, |name| name.text.clone()),
+        );
+        node.set(
+            "nameLocation",
+            self.optional_location(function.name.as_ref()),
+        );
+        node.set("overrides", overrides);
+        node.set("parameters", parameters);
+        node.set("returnParameters", return_parameters);
+        node.set("stateMutability", state_mutability);
+        node.set("virtual", is_virtual);
+        node.set("visibility", visibility);
+        node
+    }
+
+    fn modifier_definition(&mut self, definition: &ast::ModifierDefinition) -> SolcNode {
+        let mut node = self.node("ModifierDefinition", self.range(definition.cst()));
+
+        let name = definition.name();
+        let documentation = self.documentation(definition.cst());
+        let parameters = match definition.parameters() {
+            Some(parameters) => self.parameter_list(&parameters),
+            None => self.empty_parameter_list(self.range(&name).end),
+        };
+
+        let mut is_virtual = false;
+        let mut overrides = None;
+        for attribute in definition.attributes().items() {
+            match attribute.variant() {
+                ast::ModifierAttributeVariant::OverrideSpecifier(specifier) => {
+                    overrides = Some(self.override_specifier(&specifier));
+                }
+                ast::ModifierAttributeVariant::Token(token) => {
+                    is_virtual |= token.kind == TokenKind::VirtualKeyword;
+                }
+            }
+        }
+
+        node.set("documentation", documentation);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node.set("overrides", overrides);
+        node.set("parameters", parameters);
+        node.set(
+            "body",
+            function_body(&definition.body()).map(|body| self.block(&body)),
+        );
+        node.set("virtual", is_virtual);
+        node.set("visibility", "internal");
+        node
+    }
+
+    fn modifier_invocation(&mut self, modifier: &ast::ModifierInvocation) -> SolcNode {
+        let mut node = self.node("ModifierInvocation", self.range(modifier.cst()));
+        node.set("modifierName", self.identifier_path(&modifier.name()));
+        node.set(
+            "arguments",
+            modifier
+                .arguments()
+                .map(|arguments| self.arguments(&arguments).values),
+        );
+        node.set("kind", "modifierInvocation");
+        node
+    }
+
+    fn override_specifier(&mut self, specifier: &ast::OverrideSpecifier) -> SolcNode {
+        let mut node = self.node("OverrideSpecifier", self.range(specifier.cst()));
+
+        let mut overrides = vec![];
+        for path in specifier
+            .overridden()
+            .map(|overridden| overridden.paths().items())
+            .unwrap_or_default()
+        {
+            overrides.push(self.identifier_path(&path));
+        }
+
+        node.set("overrides", overrides);
+        node
+    }
+
+    fn parameter_list(&mut self, declaration: &ast::ParametersDeclaration) -> SolcNode {
+        let mut node = self.node("ParameterList", self.range(declaration.cst()));
+
+        let mut parameters = vec![];
+        for parameter in declaration.parameters().items() {
+            parameters.push(self.variable_declaration(
+                self.range(parameter.cst()),
+                Some(&parameter.type_name()),
+                parameter.name().as_ref(),
+                parameter.storage_location().as_ref(),
+            ));
+        }
+
+        node.set("parameters", parameters);
+        node
+    }
+
+    /// `solc` reports missing parameter lists as empty ones, located where they would have been.
+    fn empty_parameter_list(&mut self, offset: usize) -> SolcNode {
+        let mut node = self.node("ParameterList", offset..offset);
+        node.set("parameters", Vec::<SolcNode>::new());
+        node
+    }
+
+    fn struct_definition(&mut self, definition: &ast::StructDefinition) -> SolcNode {
+        let mut node = self.node("StructDefinition", self.range(definition.cst()));
+
+        let mut members = vec![];
+        for member in definition.members().items() {
+            members.push(self.variable_declaration(
+                self.statement_range(member.cst()),
+                Some(&member.type_name()),
+                Some(&member.name()),
+                None,
+            ));
+        }
+
+        let name = definition.name();
+        node.set("canonicalName", name.text.clone());
+        node.set("members", members);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node.set("visibility", "public");
+        node
+    }
+
+    fn enum_definition(&mut self, definition: &ast::EnumDefinition) -> SolcNode {
+        let mut node = self.node("EnumDefinition", self.range(definition.cst()));
+
+        let mut members = vec![];
+        for member in definition.members().items() {
+            let mut value = self.node("EnumValue", self.range(&member));
+            value.set("name", member.text.clone());
+            value.set("nameLocation", self.token_location(&member));
+            members.push(value);
+        }
+
+        let name = definition.name();
+        node.set("canonicalName", name.text.clone());
+        node.set("members", members);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node
+    }
+
+    fn event_definition(&mut self, definition: &ast::EventDefinition) -> SolcNode {
+        let mut node = self.node("EventDefinition", self.statement_range(definition.cst()));
+
+        let documentation = self.documentation(definition.cst());
+
+        let declaration = definition.parameters();
+        let mut parameters = self.node("ParameterList", self.range(declaration.cst()));
+        let mut items = vec![];
+        for parameter in declaration.parameters().items() {
+            let mut item = self.variable_declaration(
+                self.range(parameter.cst()),
+                Some(&parameter.type_name()),
+                parameter.name().as_ref(),
+                None,
+            );
+            item.set("indexed", parameter.indexed_keyword().is_some());
+            items.push(item);
+        }
+        parameters.set("parameters", items);
+
+        let name = definition.name();
+        node.set("anonymous", definition.anonymous_keyword().is_some());
+        node.set("documentation", documentation);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node.set("parameters", parameters);
+        node
+    }
+
+    fn error_definition(&mut self, definition: &ast::ErrorDefinition) -> SolcNode {
+        let mut node = self.node("ErrorDefinition", self.statement_range(definition.cst()));
+
+        let documentation = self.documentation(definition.cst());
+
+        let declaration = definition.members();
+        let mut parameters = self.node("ParameterList", self.range(declaration.cst()));
+        let mut items = vec![];
+        for parameter in declaration.parameters().items() {
+            items.push(self.variable_declaration(
+                self.range(parameter.cst()),
+                Some(&parameter.type_name()),
+                parameter.name().as_ref(),
+                None,
+            ));
+        }
+        parameters.set("parameters", items);
+
+        let name = definition.name();
+        node.set("documentation", documentation);
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node.set("parameters", parameters);
+        node
+    }
+
+    fn user_defined_value_type_definition(
+        &mut self,
+        definition: &ast::UserDefinedValueTypeDefinition,
+    ) -> SolcNode {
+        let mut node = self.node(
+            "UserDefinedValueTypeDefinition",
+            self.statement_range(definition.cst()),
+        );
+
+        let name = definition.name();
+        node.set("canonicalName", name.text.clone());
+        node.set("name", name.text.clone());
+        node.set("nameLocation", self.token_location(&name));
+        node.set(
+            "underlyingType",
+            self.elementary_type_name(&definition.value_type()),
+        );
+        node
+    }
+
+    fn state_variable_definition(&mut self, definition: &ast::StateVariableDefinition) -> SolcNode {
+        let mut node = self.variable_declaration(
+            self.statement_range(definition.cst()),
+            Some(&definition.type_name()),
+            Some(&definition.name()),
+            None,
+        );
+
+        let mut visibility = "internal";
+        let mut mutability = "mutable";
+        for attribute in definition.attributes().items() {
+            match attribute.variant() {
+                ast::StateVariableAttributeVariant::OverrideSpecifier(specifier) => {
+                    node.set("overrides", self.override_specifier(&specifier));
+                }
+                ast::StateVariableAttributeVariant::Token(token) => match token.kind {
+                    TokenKind::PublicKeyword => visibility = "public",
+                    TokenKind::PrivateKeyword => visibility = "private",
+                    TokenKind::InternalKeyword => visibility = "internal",
+                    TokenKind::ConstantKeyword => mutability = "constant",
+                    TokenKind::ImmutableKeyword => mutability = "immutable",
+                    _ => {}
+                },
+            }
+        }
+
+        if let Some(documentation) = self.documentation(definition.cst()) {
+            node.set("documentation", documentation);
+        }
+
+        node.set("constant", mutability == "constant");
+        node.set("mutability", mutability);
+        node.set("stateVariable", true);
+        node.set(
+            "value",
+            definition
+                .value()
+                .map(|value| self.expression(&value.value())),
+        );
+        node.set("visibility", visibility);
+        node
+    }
+
+    fn constant_definition(&mut self, definition: &ast::ConstantDefinition) -> SolcNode {
+        let mut node = self.variable_declaration(
+            self.statement_range(definition.cst()),
+            Some(&definition.type_name()),
+            Some(&definition.name()),
+            None,
+        );
+
+        node.set("constant", true);
+        node.set("mutability", "constant");
+        node.set("value", self.expression(&definition.value()));
+        node
+    }
+
+    /// Creates a `VariableDeclaration` with the defaults of a local variable or parameter.
+    fn variable_declaration(
+        &mut self,
+        range: Range<usize>,
+        type_name: Option<&ast::TypeName>,
+        name: Option<&Rc<TokenNode>>,
+        storage_location: Option<&ast::StorageLocation>,
+    ) -> SolcNode {
+        let mut node = self.node("VariableDeclaration", range);
+
+        let storage_location = match storage_location.map(|location| location.variant()) {
+            Some(ast::StorageLocationVariant::Token(location)) => location.text.clone(),
+            None => "default".to_owned(),
+        };
+
+        node.set("constant", false);
+        node.set("mutability", "mutable");
+        node.set(
+            "name",
+            name.map_or_else(String::new, |name| name.text.clone()),
+        );
+        node.set("nameLocation", self.optional_location(name));
+        node.set("stateVariable", false);
+        node.set("storageLocation", storage_location);
+        node.set(
+            "typeName",
+            type_name.map(|type_name| self.type_name(type_name)),
+        );
+        node.set("value", SolcValue::Null);
+        node.set("visibility", "internal");
+        node
+    }
+
+    //
+    // Types:
+    //
+
+    fn type_name(&mut self, type_name: &ast::TypeName) -> SolcNode {
+        use ast::{MappingKeyTypeVariant, TypeNameVariant};
+
+        match type_name.variant() {
+            TypeNameVariant::ArrayTypeName(array) => {
+                let mut node = self.node("ArrayTypeName", self.range(array.cst()));
+                node.set("baseType", self.type_name(&array.operand()));
+                node.set("length", array.index().map(|index| self.expression(&index)));
+                node
+            }
+            TypeNameVariant::FunctionType(function) => {
+                let mut node = self.node("FunctionTypeName", self.range(function.cst()));
+
+                let parameters = function.parameters();
+                node.set("parameterTypes", self.parameter_list(&parameters));
+                let return_parameters = match function.returns() {
+                    Some(returns) => self.parameter_list(&returns.variables()),
+                    None => self.empty_parameter_list(self.range(parameters.cst()).end),
+                };
+                node.set("returnParameterTypes", return_parameters);
+
+                let mut visibility = "internal";
+                let mut state_mutability = "nonpayable";
+                for attribute in function.attributes().items() {
+                    let ast::FunctionTypeAttributeVariant::Token(keyword) = attribute.variant();
+                    match keyword.kind {
+                        TokenKind::PublicKeyword => visibility = "public",
+                        TokenKind::PrivateKeyword => visibility = "private",
+                        TokenKind::InternalKeyword => visibility = "internal",
+                        TokenKind::ExternalKeyword => visibility = "external",
+                        TokenKind::PureKeyword => state_mutability = "pure",
+                        TokenKind::ViewKeyword | TokenKind::ConstantKeyword => {
+                            state_mutability = "view";
+                        }
+                        TokenKind::PayableKeyword => state_mutability = "payable",
+                        _ => {}
+                    }
+                }
+
+                node.set("stateMutability", state_mutability);
+                node.set("visibility", visibility);
+                node
+            }
+            TypeNameVariant::MappingType(mapping) => {
+                let mut node = self.node("Mapping", self.range(mapping.cst()));
+
+                let key = mapping.key_type();
+                let key_type = match key.key_type().variant() {
+                    MappingKeyTypeVariant::ElementaryType(elementary) => {
+                        self.elementary_type_name(&elementary)
+                    }
+                    MappingKeyTypeVariant::IdentifierPath(path) => {
+                        self.user_defined_type_name(&path)
+                    }
+                };
+                node.set("keyType", key_type);
+                node.set(
+                    "keyName",
+                    key.name()
+                        .map_or_else(String::new, |name| name.text.clone()),
+                );
+                node.set(
+                    "keyNameLocation",
+                    self.optional_location(key.name().as_ref()),
+                );
+
+                let value = mapping.value_type();
+                node.set("valueType", self.type_name(&value.type_name()));
+                node.set(
+                    "valueName",
+                    value
+                        .name()
+                        .map_or_else(String::new, |name| name.text.clone()),
+                );
+                node.set(
+                    "valueNameLocation",
+                    self.optional_location(value.name().as_ref()),
+                );
+                node
+            }
+            TypeNameVariant::ElementaryType(elementary) => self.elementary_type_name(&elementary),
+            TypeNameVariant::IdentifierPath(path) => self.user_defined_type_name(&path),
+        }
+    }
+
+    fn elementary_type_name(&mut self, elementary: &ast::ElementaryType) -> SolcNode {
+        let mut node = self.node("ElementaryTypeName", self.range(elementary.cst()));
+
+        match elementary.variant() {
+            ast::ElementaryTypeVariant::AddressType(address) => {
+                node.set("name", "address");
+                node.set(
+                    "stateMutability",
+                    if address.payable_keyword().is_some() {
+                        "payable"
+                    } else {
+                        "nonpayable"
+                    },
+                );
+            }
+            ast::ElementaryTypeVariant::Token(token) => {
+                node.set("name", token.text.clone());
+            }
+        }
+
+        node
+    }
+
+    fn user_defined_type_name(&mut self, path: &ast::IdentifierPath) -> SolcNode {
+        let mut node = self.node("UserDefinedTypeName", self.range(path.cst()));
+        node.set("pathNode", self.identifier_path(path));
+        node
+    }
+
+    fn identifier_path(&mut self, path: &ast::IdentifierPath) -> SolcNode {
+        let mut node = self.node("IdentifierPath", self.range(path.cst()));
+
+        let items = path.items();
+        node.set(
+            "name",
+            items
+                .iter()
+                .map(|item| item.text.as_str())
+                .collect::<Vec<_>>()
+                .join("."),
+        );
+        node.set(
+            "nameLocations",
+            items
+                .iter()
+                .map(|item| self.token_location(item))
+                .collect::<Vec<_>>(),
+        );
+        node
+    }
+
+    //
+    // Statements:
+    //
+
+    fn block(&mut self, block: &ast::Block) -> SolcNode {
+        let mut node = self.node("Block", self.range(block.cst()));
+        node.set("statements", self.statements(&block.statements()));
+        node
+    }
+
+    fn statements(&mut self, statements: &ast::Statements) -> Vec<SolcNode> {
+        statements
+            .items()
+            .iter()
+            .map(|statement| self.statement(statement))
+            .collect()
+    }
+
+    fn statement(&mut self, statement: &ast::Statement) -> SolcNode {
+        use ast::StatementVariant as Variant;
+
+        match statement.variant() {
+            Variant::ExpressionStatement(statement) => self.expression_statement(&statement),
+            Variant::VariableDeclarationStatement(statement) => {
+                self.variable_declaration_statement(&statement)
+            }
+            Variant::TupleDeconstructionStatement(statement) => {
+                self.tuple_deconstruction_statement(&statement)
+            }
+            Variant::IfStatement(statement) => {
+                let mut node = self.node("IfStatement", self.range(statement.cst()));
+                node.set("condition", self.expression(&statement.condition()));
+                node.set("trueBody", self.statement(&statement.body()));
+                node.set(
+                    "falseBody",
+                    statement
+                        .else_branch()
+                        .map(|branch| self.statement(&branch.body())),
+                );
+                node
+            }
+            Variant::ForStatement(statement) => self.for_statement(&statement),
+            Variant::WhileStatement(statement) => {
+                let mut node = self.node("WhileStatement", self.range(statement.cst()));
+                node.set("condition", self.expression(&statement.condition()));
+                node.set("body", self.statement(&statement.body()));
+                node
+            }
+            Variant::DoWhileStatement(statement) => {
+                let mut node = self.node("DoWhileStatement", self.statement_range(statement.cst()));
+                node.set("body", self.statement(&statement.body()));
+                node.set("condition", self.expression(&statement.condition()));
+                node
+            }
+            Variant::ContinueStatement(statement) => {
+                self.node("Continue", self.statement_range(statement.cst()))
+            }
+            Variant::BreakStatement(statement) => {
+                self.node("Break", self.statement_range(statement.cst()))
+            }
+            Variant::ReturnStatement(statement) => {
+                let mut node = self.node("Return", self.statement_range(statement.cst()));
+                node.set(
+                    "expression",
+                    statement
+                        .expression()
+                        .map(|expression| self.expression(&expression)),
+                );
+                node
+            }
+            Variant::ThrowStatement(statement) => {
+                self.node("Throw", self.statement_range(statement.cst()))
+            }
+            Variant::EmitStatement(statement) => {
+                let mut node = self.node("EmitStatement", self.statement_range(statement.cst()));
+                node.set(
+                    "eventCall",
+                    self.path_call(&statement.event(), &statement.arguments()),
+                );
+                node
+            }
+            Variant::TryStatement(statement) => self.try_statement(&statement),
+            Variant::RevertStatement(statement) => {
+                let range = self.statement_range(statement.cst());
+                if let Some(error) = statement.error() {
+                    let mut node = self.node("RevertStatement", range);
+                    node.set("errorCall", self.path_call(&error, &statement.arguments()));
+                    node
+                } else {
+                    // Before custom errors, `revert(...)` is a call to a built-in function:
+                    let mut node = self.node("ExpressionStatement", range.clone());
+
+                    let mut call = self.node("FunctionCall", range);
+                    call.set("expression", self.identifier(&statement.revert_keyword()));
+                    self.set_call_arguments(&mut call, &statement.arguments());
+
+                    node.set("expression", call);
+                    node
+                }
+            }
+            Variant::AssemblyStatement(statement) => self.inline_assembly(&statement),
+            Variant::Block(block) => self.block(&block),
+            Variant::UncheckedBlock(block) => {
+                let mut node = self.node("UncheckedBlock", self.range(block.cst()));
+                node.set("statements", self.statements(&block.block().statements()));
+                node
+            }
+        }
+    }
+
+    fn expression_statement(&mut self, statement: &ast::ExpressionStatement) -> SolcNode {
+        let expression = statement.expression();
+
+        // The body of a modifier is parsed as a regular statement:
+        if let ast::ExpressionVariant::Token(token) = expression.variant() {
+            if token.kind == TokenKind::Identifier && token.text == "_" {
+                return self.node(
+                    "PlaceholderStatement",
+                    self.statement_range(statement.cst()),
+                );
+            }
+        }
+
+        let mut node = self.node("ExpressionStatement", self.statement_range(statement.cst()));
+        node.set("expression", self.expression(&expression));
+        node
+    }
+
+    fn variable_declaration_statement(
+        &mut self,
+        statement: &ast::VariableDeclarationStatement,
+    ) -> SolcNode {
+        let mut node = self.node(
+            "VariableDeclarationStatement",
+            self.statement_range(statement.cst()),
+        );
+
+        let variable_type = statement.variable_type();
+        let name = statement.name();
+        let type_name = match variable_type.variant() {
+            ast::VariableDeclarationTypeVariant::TypeName(type_name) => Some(type_name),
+            // `var` declarations (before 0.5.0) don't have a type:
+            ast::VariableDeclarationTypeVariant::Token(_) => None,
+        };
+
+        let declaration = self.variable_declaration(
+            self.range(variable_type.cst()).start..self.range(&name).end,
+            type_name.as_ref(),
+            Some(&name),
+            statement.storage_location().as_ref(),
+        );
+
+        node.set("assignments", vec![declaration.id]);
+        node.set("declarations", vec![declaration]);
+        node.set(
+            "initialValue",
+            statement
+                .value()
+                .map(|value| self.expression(&value.expression())),
+        );
+        node
+    }
+
+    fn tuple_deconstruction_statement(
+        &mut self,
+        statement: &ast::TupleDeconstructionStatement,
+    ) -> SolcNode {
+        use ast::TupleMemberVariant;
+
+        let range = self.statement_range(statement.cst());
+        let members: Vec<_> = statement
+            .elements()
+            .items()
+            .iter()
+            .map(|element| element.member().map(|member| member.variant()))
+            .collect();
+
+        let is_declaration = statement.var_keyword().is_some()
+            || members
+                .iter()
+                .any(|member| matches!(member, Some(TupleMemberVariant::TypedTupleMember(_))));
+
+        if is_declaration {
+            let mut node = self.node("VariableDeclarationStatement", range);
+
+            let mut declarations = vec![];
+            for member in members {
+                declarations.push(match member {
+                    Some(TupleMemberVariant::TypedTupleMember(member)) => {
+                        Some(self.variable_declaration(
+                            self.range(member.cst()),
+                            Some(&member.type_name()),
+                            Some(&member.name()),
+                            member.storage_location().as_ref(),
+                        ))
+                    }
+                    Some(TupleMemberVariant::UntypedTupleMember(member)) => {
+                        Some(self.variable_declaration(
+                            self.range(member.cst()),
+                            None,
+                            Some(&member.name()),
+                            member.storage_location().as_ref(),
+                        ))
+                    }
+                    None => None,
+                });
+            }
+
+            node.set(
+                "assignments",
+                declarations
+                    .iter()
+                    .map(|declaration| declaration.as_ref().and_then(|declaration| declaration.id))
+                    .collect::<Vec<_>>(),
+            );
+            node.set("declarations", declarations);
+            node.set("initialValue", self.expression(&statement.expression()));
+            return node;
+        }
+
+        // Otherwise, it is an assignment to existing variables: `(a, b) = ...;`
+        let mut node = self.node("ExpressionStatement", range.clone());
+        let mut assignment = self.node("Assignment", range);
+
+        let tuple_range =
+            self.range(&statement.open_paren()).start..self.range(&statement.close_paren()).end;
+        let mut tuple = self.node("TupleExpression", tuple_range);
+        let mut components = vec![];
+        for member in members {
+            components.push(match member {
+                Some(TupleMemberVariant::UntypedTupleMember(member)) => {
+                    Some(self.identifier(&member.name()))
+                }
+                Some(TupleMemberVariant::TypedTupleMember(_)) | None => None,
+            });
+        }
+        tuple.set("components", components);
+        tuple.set("isInlineArray", false);
+
+        assignment.set("leftHandSide", tuple);
+        assignment.set("operator", "=");
+        assignment.set("rightHandSide", self.expression(&statement.expression()));
+
+        node.set("expression", assignment);
+        node
+    }
+
+    fn for_statement(&mut self, statement: &ast::ForStatement) -> SolcNode {
+        use ast::{ForStatementConditionVariant, ForStatementInitializationVariant};
+
+        let mut node = self.node("ForStatement", self.range(statement.cst()));
+
+        let initialization = match statement.initialization().variant() {
+            ForStatementInitializationVariant::ExpressionStatement(statement) => {
+                Some(self.expression_statement(&statement))
+            }
+            ForStatementInitializationVariant::VariableDeclarationStatement(statement) => {
+                Some(self.variable_declaration_statement(&statement))
+            }
+            ForStatementInitializationVariant::TupleDeconstructionStatement(statement) => {
+                Some(self.tuple_deconstruction_statement(&statement))
+            }
+            ForStatementInitializationVariant::Token(_) => None,
+        };
+        node.set("initializationExpression", initialization);
+
+        let condition = match statement.condition().variant() {
+            ForStatementConditionVariant::ExpressionStatement(statement) => {
+                Some(self.expression(&statement.expression()))
+            }
+            ForStatementConditionVariant::Token(_) => None,
+        };
+        node.set("condition", condition);
+
+        let loop_expression = statement.iterator().map(|iterator| {
+            let mut expression_statement =
+                self.node("ExpressionStatement", self.range(iterator.cst()));
+            expression_statement.set("expression", self.expression(&iterator));
+            expression_statement
+        });
+        node.set("loopExpression", loop_expression);
+
+        node.set("body", self.statement(&statement.body()));
+        node
+    }
+
+    fn try_statement(&mut self, statement: &ast::TryStatement) -> SolcNode {
+        let mut node = self.node("TryStatement", self.range(statement.cst()));
+
+        let mut external_call = self.expression(&statement.expression());
+        if external_call.node_type == "FunctionCall" {
+            external_call.set("tryCall", true);
+        }
+        node.set("externalCall", external_call);
+
+        let mut clauses = vec![];
+
+        let body = statement.body();
+        let returns = statement.returns();
+        let start = returns
+            .as_ref()
+            .map_or_else(
+                || self.range(body.cst()),
+                |returns| self.range(returns.cst()),
+            )
+            .start;
+        let mut success = self.node("TryCatchClause", start..self.range(body.cst()).end);
+        success.set("errorName", "");
+        success.set(
+            "parameters",
+            returns.map(|returns| self.parameter_list(&returns.variables())),
+        );
+        success.set("block", self.block(&body));
+        clauses.push(success);
+
+        for clause in statement.catch_clauses().items() {
+            let mut catch = self.node("TryCatchClause", self.range(clause.cst()));
+            let error = clause.error();
+            catch.set(
+                "errorName",
+                error
+                    .as_ref()
+                    .and_then(|error| error.name())
+                    .map_or_else(String::new, |name| name.text.clone()),
+            );
+            catch.set(
+                "parameters",
+                error.map(|error| self.parameter_list(&error.parameters())),
+            );
+            catch.set("block", self.block(&clause.body()));
+            clauses.push(catch);
+        }
+
+        node.set("clauses", clauses);
+        node
+    }
+
+    fn inline_assembly(&mut self, statement: &ast::AssemblyStatement) -> SolcNode {
+        let mut node = self.node("InlineAssembly", self.range(statement.cst()));
+
+        if let Some(flags) = statement.flags() {
+            let flags: Vec<_> = flags
+                .flags()
+                .items()
+                .iter()
+                .map(|flag| {
+                    String::from_utf8_lossy(&literals::decode_string(
+                        &string_literal_token(flag).text,
+                    ))
+                    .into_owned()
+                })
+                .collect();
+            node.set("flags", flags);
+        }
+
+        node.set("AST", self.yul_block(&statement.body()));
+        node
+    }
+
+    //
+    // Expressions:
+    //
+
+    #[allow(clippy::too_many_lines)] // Big switch over all kinds of expressions
+    fn expression(&mut self, expression: &ast::Expression) -> SolcNode {
+        use ast::ExpressionVariant as Variant;
+
+        let range = self.range(expression.cst());
+
+        match expression.variant() {
+            Variant::AssignmentExpression(expression) => self.binary_operation(
+                "Assignment",
+                range,
+                ("leftHandSide", "rightHandSide"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::OrExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::AndExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::EqualityExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::ComparisonExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::BitwiseOrExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::BitwiseXorExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::BitwiseAndExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::ShiftExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::AdditiveExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::MultiplicativeExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::ExponentiationExpression(expression) => self.binary_operation(
+                "BinaryOperation",
+                range,
+                ("leftExpression", "rightExpression"),
+                (
+                    expression.left_operand(),
+                    expression.operator(),
+                    expression.right_operand(),
+                ),
+            ),
+            Variant::ConditionalExpression(expression) => {
+                let mut node = self.node("Conditional", range);
+                node.set("condition", self.expression(&expression.operand()));
+                node.set(
+                    "trueExpression",
+                    self.expression(&expression.true_expression()),
+                );
+                node.set(
+                    "falseExpression",
+                    self.expression(&expression.false_expression()),
+                );
+                node
+            }
+            Variant::PostfixExpression(expression) => {
+                let mut node = self.node("UnaryOperation", range);
+                node.set("operator", expression.operator().text.clone());
+                node.set("prefix", false);
+                node.set("subExpression", self.expression(&expression.operand()));
+                node
+            }
+            Variant::PrefixExpression(expression) => {
+                let mut node = self.node("UnaryOperation", range);
+                node.set("operator", expression.operator().text.clone());
+                node.set("prefix", true);
+                node.set("subExpression", self.expression(&expression.operand()));
+                node
+            }
+            Variant::FunctionCallExpression(expression) => {
+                let mut node = self.node("FunctionCall", range);
+                node.set("expression", self.expression(&expression.operand()));
+                self.set_call_arguments(&mut node, &expression.arguments());
+                node
+            }
+            Variant::CallOptionsExpression(expression) => {
+                let mut node = self.node("FunctionCallOptions", range);
+                node.set("expression", self.expression(&expression.operand()));
+
+                let mut names = vec![];
+                let mut options = vec![];
+                for option in expression.options().items() {
+                    names.push(option.name().text.clone());
+                    options.push(self.expression(&option.value()));
+                }
+
+                node.set(
+                    "names",
+                    names.into_iter().map(SolcValue::String).collect::<Vec<_>>(),
+                );
+                node.set("options", options);
+                node
+            }
+            Variant::MemberAccessExpression(expression) => {
+                let ast::MemberAccessVariant::Token(member) = expression.member().variant();
+
+                let mut node = self.node("MemberAccess", range);
+                node.set("expression", self.expression(&expression.operand()));
+                node.set("memberLocation", self.token_location(&member));
+                node.set("memberName", member.text.clone());
+                node
+            }
+            Variant::IndexAccessExpression(expression) => {
+                if let Some(end) = expression.end() {
+                    let mut node = self.node("IndexRangeAccess", range);
+                    node.set("baseExpression", self.expression(&expression.operand()));
+                    node.set(
+                        "startExpression",
+                        expression.start().map(|start| self.expression(&start)),
+                    );
+                    node.set("endExpression", end.end().map(|end| self.expression(&end)));
+                    node
+                } else {
+                    let mut node = self.node("IndexAccess", range);
+                    node.set("baseExpression", self.expression(&expression.operand()));
+                    node.set(
+                        "indexExpression",
+                        expression.start().map(|index| self.expression(&index)),
+                    );
+                    node
+                }
+            }
+            Variant::NewExpression(expression) => {
+                let mut node = self.node("NewExpression", range);
+                node.set("typeName", self.type_name(&expression.type_name()));
+                node
+            }
+            Variant::TupleExpression(expression) => {
+                let mut node = self.node("TupleExpression", range);
+
+                let mut components = vec![];
+                for item in expression.items().items() {
+                    components.push(item.expression().map(|value| self.expression(&value)));
+                }
+
+                node.set("components", components);
+                node.set("isInlineArray", false);
+                node
+            }
+            Variant::TypeExpression(expression) => {
+                // `type(T)` is a call to a built-in function:
+                let mut node = self.node("FunctionCall", range);
+                node.set("expression", self.identifier(&expression.type_keyword()));
+
+                let argument = match expression.type_name().variant() {
+                    ast::TypeNameVariant::IdentifierPath(path) => {
+                        self.path_expression(&path.items())
+                    }
+                    ast::TypeNameVariant::ElementaryType(elementary) => {
+                        let mut argument =
+                            self.node("ElementaryTypeNameExpression", self.range(elementary.cst()));
+                        argument.set("typeName", self.elementary_type_name(&elementary));
+                        argument
+                    }
+                    _ => self.type_name(&expression.type_name()),
+                };
+
+                node.set("arguments", vec![argument]);
+                node.set("names", Vec::<SolcValue>::new());
+                node.set("nameLocations", Vec::<SolcValue>::new());
+                node.set("tryCall", false);
+                node
+            }
+            Variant::ArrayExpression(expression) => {
+                let mut node = self.node("TupleExpression", range);
+
+                let mut components = vec![];
+                for item in expression.items().items() {
+                    components.push(self.expression(&item));
+                }
+
+                node.set("components", components);
+                node.set("isInlineArray", true);
+                node
+            }
+            Variant::HexNumberExpression(expression) => {
+                self.number_literal(range, &expression.literal(), expression.unit())
+            }
+            Variant::DecimalNumberExpression(expression) => {
+                self.number_literal(range, &expression.literal(), expression.unit())
+            }
+            Variant::StringExpression(expression) => self.string_literal(range, &expression),
+            Variant::ElementaryType(elementary) => {
+                let mut node = self.node("ElementaryTypeNameExpression", range);
+                node.set("typeName", self.elementary_type_name(&elementary));
+                node
+            }
+            Variant::Token(token) => match token.kind {
+                TokenKind::TrueKeyword | TokenKind::FalseKeyword => {
+                    let mut node = self.node("Literal", range);
+                    node.set("hexValue", literals::to_hex(token.text.as_bytes()));
+                    node.set("kind", "bool");
+                    node.set("value", token.text.clone());
+                    node
+                }
+                // `payable(...)` is a conversion to `address payable`:
+                TokenKind::PayableKeyword => {
+                    let mut node = self.node("ElementaryTypeNameExpression", range.clone());
+
+                    let mut type_name = self.node("ElementaryTypeName", range);
+                    type_name.set("name", "address");
+                    type_name.set("stateMutability", "payable");
+
+                    node.set("typeName", type_name);
+                    node
+                }
+                _ => self.identifier(&token),
+            },
+        }
+    }
+
+    fn binary_operation(
+        &mut self,
+        node_type: &'static str,
+        range: Range<usize>,
+        (left_name, right_name): (&'static str, &'static str),
+        (left, operator, right): (ast::Expression, Rc<TokenNode>, ast::Expression),
+    ) -> SolcNode {
+        let mut node = self.node(node_type, range);
+        node.set(left_name, self.expression(&left));
+        node.set("operator", operator.text.clone());
+        node.set(right_name, self.expression(&right));
+        node
+    }
+
+    fn number_literal(
+        &mut self,
+        range: Range<usize>,
+        literal: &Rc<TokenNode>,
+        unit: Option<ast::NumberUnit>,
+    ) -> SolcNode {
+        let mut node = self.node("Literal", range);
+        node.set("hexValue", literals::to_hex(literal.text.as_bytes()));
+        node.set("kind", "number");
+        node.set(
+            "subdenomination",
+            unit.map(|unit| {
+                let ast::NumberUnitVariant::Token(unit) = unit.variant();
+                unit.text.clone()
+            }),
+        );
+        node.set("value", literal.text.clone());
+        node
+    }
+
+    fn string_literal(
+        &mut self,
+        range: Range<usize>,
+        expression: &ast::StringExpression,
+    ) -> SolcNode {
+        use ast::{HexStringLiteralVariant, StringExpressionVariant, UnicodeStringLiteralVariant};
+
+        let mut node = self.node("Literal", range);
+
+        let hex_literal = |literal: &ast::HexStringLiteral| {
+            let HexStringLiteralVariant::Token(token) = literal.variant();
+            literals::decode_hex_string(&token.text)
+        };
+
+        let (kind, bytes) = match expression.variant() {
+            StringExpressionVariant::StringLiteral(literal) => (
+                "string",
+                literals::decode_string(&string_literal_token(&literal).text),
+            ),
+            StringExpressionVariant::StringLiterals(list) => (
+                "string",
+                list.items()
+                    .iter()
+                    .flat_map(|literal| {
+                        literals::decode_string(&string_literal_token(literal).text)
+                    })
+                    .collect(),
+            ),
+            StringExpressionVariant::HexStringLiteral(literal) => {
+                ("hexString", hex_literal(&literal))
+            }
+            StringExpressionVariant::HexStringLiterals(list) => (
+                "hexString",
+                list.items().iter().flat_map(hex_literal).collect(),
+            ),
+            StringExpressionVariant::UnicodeStringLiterals(list) => (
+                "unicodeString",
+                list.items()
+                    .iter()
+                    .flat_map(|literal| {
+                        let UnicodeStringLiteralVariant::Token(token) = literal.variant();
+                        literals::decode_string(&token.text)
+                    })
+                    .collect(),
+            ),
+        };
+
+        node.set("hexValue", literals::to_hex(&bytes));
+        node.set("kind", kind);
+        node.set("value", String::from_utf8(bytes).ok());
+        node
+    }
+
+    fn identifier(&mut self, token: &Rc<TokenNode>) -> SolcNode {
+        let mut node = self.node("Identifier", self.range(token));
+        node.set("name", token.text.clone());
+        node
+    }
+
+    /// Converts a path (e.g. `A.B.C`) into an identifier, followed by member accesses.
+    fn path_expression(&mut self, items: &[Rc<TokenNode>]) -> SolcNode {
+        match items {
+            [] => unreachable!("paths have at least one item"),
+            [identifier] => self.identifier(identifier),
+            [prefix @ .., member] => {
+                let range = self.range(&items[0]).start..self.range(member).end;
+                let mut node = self.node("MemberAccess", range);
+                node.set("expression", self.path_expression(prefix));
+                node.set("memberLocation", self.token_location(member));
+                node.set("memberName", member.text.clone());
+                node
+            }
+        }
+    }
+
+    /// Creates a call to the declaration at the given path, used in `emit` and `revert` statements.
+    fn path_call(
+        &mut self,
+        path: &ast::IdentifierPath,
+        arguments: &ast::ArgumentsDeclaration,
+    ) -> SolcNode {
+        let range = self.range(path.cst()).start..self.range(arguments.cst()).end;
+
+        let mut node = self.node("FunctionCall", range);
+        node.set("expression", self.path_expression(&path.items()));
+        self.set_call_arguments(&mut node, arguments);
+        node
+    }
+
+    fn set_call_arguments(&mut self, node: &mut SolcNode, arguments: &ast::ArgumentsDeclaration) {
+        let arguments = self.arguments(arguments);
+
+        node.set("arguments", arguments.values);
+        node.set(
+            "names",
+            arguments
+                .names
+                .into_iter()
+                .map(SolcValue::String)
+                .collect::<Vec<_>>(),
+        );
+        node.set("nameLocations", arguments.name_locations);
+        node.set("tryCall", false);
+    }
+
+    fn arguments(&mut self, arguments: &ast::ArgumentsDeclaration) -> Arguments {
+        let mut result = Arguments {
+            names: vec![],
+            name_locations: vec![],
+            values: vec![],
+        };
+
+        match arguments.variant() {
+            ast::ArgumentsDeclarationVariant::PositionalArgumentsDeclaration(positional) => {
+                for argument in positional.arguments().items() {
+                    result.values.push(self.expression(&argument));
+                }
+            }
+            ast::ArgumentsDeclarationVariant::NamedArgumentsDeclaration(named) => {
+                for argument in named
+                    .arguments()
+                    .map(|group| group.arguments().items())
+                    .unwrap_or_default()
+                {
+                    let name = argument.name();
+                    result.names.push(name.text.clone());
+                    result.name_locations.push(self.token_location(&name));
+                    result.values.push(self.expression(&argument.value()));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn key<T>(node: &Rc<T>) -> *const () {
+    Rc::as_ptr(node).cast()
+}
+
+/// Copies the tree, so that each node has its own allocation. Since nodes are immutable and reference-counted,
+/// a tree can share an identical subtree between different positions, which would otherwise have a single key.
+pub(super) fn unshare(node: &Node) -> Node {
+    match node {
+        Node::Rule(rule) => Node::rule(
+            rule.kind,
+            rule.children
+                .iter()
+                .map(|child| LabeledNode {
+                    label: child.label,
+                    node: unshare(&child.node),
+                })
+                .collect(),
+        ),
+        Node::Token(token) => Node::token(token.kind, token.text.clone()),
+    }
+}
+
+fn is_natspec(token: &TokenNode) -> bool {
+    matches!(
+        token.kind,
+        TokenKind::SingleLineNatSpecComment | TokenKind::MultiLineNatSpecComment
+    )
+}
+
+/// Top-level declarations listed in the `exportedSymbols` of a `SourceUnit`.
+fn is_exported(node_type: &str) -> bool {
+    matches!(
+        node_type,
+        "ContractDefinition"
+            | "StructDefinition"
+            | "EnumDefinition"
+            | "FunctionDefinition"
+            | "VariableDeclaration"
+            | "ErrorDefinition"
+            | "EventDefinition"
+            | "UserDefinedValueTypeDefinition"
+    )
+}
+
+fn function_body(body: &ast::FunctionBody) -> Option<ast::Block> {
+    match body.variant() {
+        ast::FunctionBodyVariant::Block(block) => Some(block),
+        ast::FunctionBodyVariant::Token(_) => None,
+    }
+}
+
+fn string_literal_token(literal: &ast::StringLiteral) -> Rc<TokenNode> {
+    let ast::StringLiteralVariant::Token(token) = literal.variant();
+    token
+}
+
+/// Collects the tokens of a version expression, the way `solc` would scan them.
+fn version_literals(expression: &ast::VersionExpression, literals: &mut Vec<String>) {
+    match expression.variant() {
+        ast::VersionExpressionVariant::VersionRange(range) => {
+            version_literals(&range.left_operand(), literals);
+            literals.push(range.operator().text.clone());
+            version_literals(&range.right_operand(), literals);
+        }
+        ast::VersionExpressionVariant::VersionComparator(comparator) => {
+            literals.push(comparator.operator().text.clone());
+            version_literals(&comparator.operand(), literals);
+        }
+        ast::VersionExpressionVariant::VersionSpecifiers(specifiers) => {
+            // `solc` scans `0.8.0` as the two number literals `0.8` and `.0`:
+            let mut items = specifiers.items().into_iter().map(|item| item.text.clone());
+            if let Some(first) = items.next() {
+                match items.next() {
+                    Some(second) => literals.push(format!("{first}.{second}")),
+                    None => literals.push(first),
+                }
+            }
+            literals.extend(items.map(|item| format!(".{item}")));
+        }
+        ast::VersionExpressionVariant::Token(token) => literals.push(token.text.clone()),
+    }
+}
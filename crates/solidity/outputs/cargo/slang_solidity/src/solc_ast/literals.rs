@@ -0,0 +1,80 @@
+//! Decoding of string literals, as reported in the `value` and `hexValue` attributes of `solc` literals.
+
+use std::fmt::Write;
+
+/// Decodes the contents of a (possibly `unicode` prefixed) string literal, resolving any escape sequences.
+pub(super) fn decode_string(literal: &str) -> Vec<u8> {
+    let contents = strip_quotes(literal.strip_prefix("unicode").unwrap_or(literal));
+
+    let mut bytes = vec![];
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('x') => {
+                let digits: String = chars.by_ref().take(2).collect();
+                bytes.extend(u8::from_str_radix(&digits, 16).ok());
+            }
+            Some('u') => {
+                let digits: String = chars.by_ref().take(4).collect();
+                let c = u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            // Line continuations are removed from the value:
+            Some('\r') => {
+                chars.next_if_eq(&'\n');
+            }
+            Some('\n') => {}
+            Some(other) => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+            }
+            None => {}
+        }
+    }
+
+    bytes
+}
+
+/// Decodes the contents of a `hex` string literal into the bytes it represents.
+pub(super) fn decode_hex_string(literal: &str) -> Vec<u8> {
+    let digits: Vec<u8> = strip_quotes(literal.strip_prefix("hex").unwrap_or(literal))
+        .chars()
+        .filter_map(|c| c.to_digit(16))
+        .filter_map(|digit| u8::try_from(digit).ok())
+        .collect();
+
+    digits
+        .chunks(2)
+        .map(|pair| pair.iter().fold(0, |byte, digit| byte * 16 + digit))
+        .collect()
+}
+
+/// Encodes the bytes as lowercase hexadecimal digits.
+pub(super) fn to_hex(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(result, "{byte:02x}").expect("writing to a string cannot fail");
+    }
+    result
+}
+
+fn strip_quotes(literal: &str) -> &str {
+    literal
+        .get(1..literal.len().saturating_sub(1))
+        .unwrap_or_default()
+}
@@ -0,0 +1,181 @@
+//! Exporting a parse tree in the JSON AST format produced by `solc`:
+//! <https://docs.soliditylang.org/en/latest/using-the-compiler.html#output-description>
+//!
+//! Only the syntactic parts of the AST are produced: node types, names, literals, operators, and source locations.
+//! Attributes that require name resolution or type checking (e.g. `typeDescriptions`, `referencedDeclaration`,
+//! `scope`, `functionSelector`, or `linearizedBaseContracts`) are omitted. Node ids are assigned in pre-order,
+//! so they are unique within a source unit, but will not match the ones assigned by `solc`.
+
+mod exporter;
+mod literals;
+mod yul;
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Serialize, Serializer};
+
+use crate::cst::Node;
+use crate::kinds::RuleKind;
+use crate::parse_output::ParseOutput;
+
+/// A node of the `solc` JSON AST.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SolcNode {
+    /// Unique id of the node. Yul nodes don't have ids, similar to `solc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
+    #[serde(rename = "nodeType")]
+    pub node_type: &'static str,
+    pub src: SourceLocation,
+    /// All other attributes of the node, keyed by their names in the JSON output.
+    #[serde(flatten)]
+    pub attributes: BTreeMap<&'static str, SolcValue>,
+}
+
+impl SolcNode {
+    /// Returns the attribute with the given name, if any.
+    pub fn attribute(&self, name: &str) -> Option<&SolcValue> {
+        self.attributes.get(name)
+    }
+
+    /// Returns the child node stored in the given attribute, if any.
+    pub fn child(&self, name: &str) -> Option<&SolcNode> {
+        match self.attribute(name)? {
+            SolcValue::Node(node) => Some(node),
+            _ => None,
+        }
+    }
+
+    /// Returns the child nodes stored in the given (list) attribute, skipping any `null` entries.
+    pub fn children(&self, name: &str) -> Vec<&SolcNode> {
+        match self.attribute(name) {
+            Some(SolcValue::List(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    SolcValue::Node(node) => Some(node.as_ref()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub(crate) fn set(&mut self, name: &'static str, value: impl Into<SolcValue>) {
+        self.attributes.insert(name, value.into());
+    }
+}
+
+/// The value of an attribute of a [`SolcNode`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum SolcValue {
+    Null,
+    Bool(bool),
+    Number(usize),
+    String(String),
+    Location(SourceLocation),
+    Node(Box<SolcNode>),
+    List(Vec<SolcValue>),
+    Map(BTreeMap<String, SolcValue>),
+}
+
+impl From<bool> for SolcValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<usize> for SolcValue {
+    fn from(value: usize) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for SolcValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for SolcValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<SourceLocation> for SolcValue {
+    fn from(value: SourceLocation) -> Self {
+        Self::Location(value)
+    }
+}
+
+impl From<SolcNode> for SolcValue {
+    fn from(value: SolcNode) -> Self {
+        Self::Node(Box::new(value))
+    }
+}
+
+impl<T: Into<SolcValue>> From<Option<T>> for SolcValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+impl<T: Into<SolcValue>> From<Vec<T>> for SolcValue {
+    fn from(value: Vec<T>) -> Self {
+        Self::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A `solc` source location, serialized as `"start:length:sourceIndex"`, where `start` and `length` are in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub start: usize,
+    pub length: usize,
+    pub source_index: usize,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.start, self.length, self.source_index)
+    }
+}
+
+impl Serialize for SourceLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error("Cannot export a parse tree with {0} syntax error(s).")]
+    SyntaxErrors(usize),
+
+    #[error("Expected a parse tree of a 'SourceUnit', but found '{0}'.")]
+    NotASourceUnit(String),
+}
+
+/// Converts the parse tree of a `SourceUnit` into a `solc` JSON AST.
+///
+/// The `absolute_path` is reported as-is in the `SourceUnit` node, and `source_index` is used in all source locations.
+/// Only parse outputs of a `SourceUnit` without any syntax errors can be exported.
+pub fn export(
+    output: &ParseOutput,
+    absolute_path: &str,
+    source_index: usize,
+) -> Result<SolcNode, ExportError> {
+    if !output.is_valid() {
+        return Err(ExportError::SyntaxErrors(output.errors().len()));
+    }
+
+    let tree = exporter::unshare(&output.tree());
+    let root = match &tree {
+        Node::Rule(rule) if rule.kind == RuleKind::SourceUnit => rule,
+        Node::Rule(rule) => return Err(ExportError::NotASourceUnit(rule.kind.to_string())),
+        Node::Token(token) => return Err(ExportError::NotASourceUnit(token.kind.to_string())),
+    };
+
+    Ok(exporter::Exporter::new(&tree, source_index).source_unit(root, absolute_path))
+}
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::ast;
+use crate::cst::{Rc, TokenNode};
+use crate::kinds::TokenKind;
+use crate::solc_ast::exporter::Exporter;
+use crate::solc_ast::{literals, SolcNode};
+
+/// Yul nodes don't have ids, and repeat their location in a `nativeSrc` attribute.
+impl Exporter {
+    fn yul_node(&self, node_type: &'static str, range: Range<usize>) -> SolcNode {
+        let src = self.location(range);
+
+        let mut node = SolcNode {
+            id: None,
+            node_type,
+            src,
+            attributes: BTreeMap::new(),
+        };
+        node.set("nativeSrc", src);
+        node
+    }
+
+    pub(super) fn yul_block(&self, block: &ast::YulBlock) -> SolcNode {
+        let mut node = self.yul_node("YulBlock", self.range(block.cst()));
+
+        let statements: Vec<_> = block
+            .statements()
+            .items()
+            .iter()
+            .filter_map(|statement| self.yul_statement(statement))
+            .collect();
+
+        node.set("statements", statements);
+        node
+    }
+
+    #[allow(clippy::too_many_lines)] // Big switch over all kinds of statements
+    fn yul_statement(&self, statement: &ast::YulStatement) -> Option<SolcNode> {
+        use ast::YulStatementVariant as Variant;
+
+        let node = match statement.variant() {
+            Variant::YulBlock(block) => self.yul_block(&block),
+            Variant::YulFunctionDefinition(definition) => {
+                let mut node = self.yul_node("YulFunctionDefinition", self.range(definition.cst()));
+
+                let parameters =
+                    self.yul_typed_names(&definition.parameters().parameters().items());
+                let return_variables = self.yul_typed_names(
+                    &definition
+                        .returns()
+                        .map(|returns| returns.variables().items())
+                        .unwrap_or_default(),
+                );
+
+                node.set("body", self.yul_block(&definition.body()));
+                node.set("name", definition.name().text.clone());
+                if !parameters.is_empty() {
+                    node.set("parameters", parameters);
+                }
+                if !return_variables.is_empty() {
+                    node.set("returnVariables", return_variables);
+                }
+                node
+            }
+            Variant::YulVariableDeclarationStatement(statement) => {
+                let mut node = self.yul_node("YulVariableDeclaration", self.range(statement.cst()));
+                node.set(
+                    "value",
+                    statement
+                        .value()
+                        .map(|value| self.yul_expression(&value.expression())),
+                );
+                node.set("variables", self.yul_typed_names(&[statement.names()]));
+                node
+            }
+            Variant::YulAssignmentStatement(statement) => {
+                let mut node = self.yul_node("YulAssignment", self.range(statement.cst()));
+
+                let names: Vec<_> = statement
+                    .names()
+                    .items()
+                    .iter()
+                    .map(|path| self.yul_path(path))
+                    .collect();
+
+                node.set("value", self.yul_expression(&statement.expression()));
+                node.set("variableNames", names);
+                node
+            }
+            Variant::YulIfStatement(statement) => {
+                let mut node = self.yul_node("YulIf", self.range(statement.cst()));
+                node.set("body", self.yul_block(&statement.body()));
+                node.set("condition", self.yul_expression(&statement.condition()));
+                node
+            }
+            Variant::YulForStatement(statement) => {
+                let mut node = self.yul_node("YulForLoop", self.range(statement.cst()));
+                node.set("body", self.yul_block(&statement.body()));
+                node.set("condition", self.yul_expression(&statement.condition()));
+                node.set("post", self.yul_block(&statement.iterator()));
+                node.set("pre", self.yul_block(&statement.initialization()));
+                node
+            }
+            Variant::YulSwitchStatement(statement) => {
+                let mut node = self.yul_node("YulSwitch", self.range(statement.cst()));
+
+                let mut cases = vec![];
+                for case in statement.cases().items() {
+                    cases.push(match case.variant() {
+                        ast::YulSwitchCaseVariant::YulValueCase(case) => {
+                            let mut node = self.yul_node("YulCase", self.range(case.cst()));
+                            node.set("body", self.yul_block(&case.body()));
+                            node.set("value", self.yul_literal(&case.value()));
+                            node
+                        }
+                        ast::YulSwitchCaseVariant::YulDefaultCase(case) => {
+                            let mut node = self.yul_node("YulCase", self.range(case.cst()));
+                            node.set("body", self.yul_block(&case.body()));
+                            node.set("value", "default");
+                            node
+                        }
+                    });
+                }
+
+                node.set("cases", cases);
+                node.set("expression", self.yul_expression(&statement.expression()));
+                node
+            }
+            Variant::YulLeaveStatement(statement) => {
+                self.yul_node("YulLeave", self.range(statement.cst()))
+            }
+            Variant::YulBreakStatement(statement) => {
+                self.yul_node("YulBreak", self.range(statement.cst()))
+            }
+            Variant::YulContinueStatement(statement) => {
+                self.yul_node("YulContinue", self.range(statement.cst()))
+            }
+            // Labels were removed from the language in 0.5.0, and have no `solc` equivalent:
+            Variant::YulLabel(_) => return None,
+            Variant::YulExpression(expression) => {
+                let mut node =
+                    self.yul_node("YulExpressionStatement", self.range(expression.cst()));
+                node.set("expression", self.yul_expression(&expression));
+                node
+            }
+        };
+
+        Some(node)
+    }
+
+    fn yul_typed_names(&self, names: &[Rc<TokenNode>]) -> Vec<SolcNode> {
+        names
+            .iter()
+            .map(|name| {
+                let mut node = self.yul_node("YulTypedName", self.range(name));
+                node.set("name", name.text.clone());
+                node.set("type", "");
+                node
+            })
+            .collect()
+    }
+
+    fn yul_expression(&self, expression: &ast::YulExpression) -> SolcNode {
+        use ast::YulExpressionVariant as Variant;
+
+        match expression.variant() {
+            Variant::YulFunctionCallExpression(call) => {
+                let mut node = self.yul_node("YulFunctionCall", self.range(call.cst()));
+
+                let arguments: Vec<_> = call
+                    .arguments()
+                    .items()
+                    .iter()
+                    .map(|argument| self.yul_expression(argument))
+                    .collect();
+
+                node.set("arguments", arguments);
+                node.set("functionName", self.yul_expression(&call.operand()));
+                node
+            }
+            Variant::YulLiteral(literal) => self.yul_literal(&literal),
+            Variant::YulBuiltInFunction(function) => {
+                let ast::YulBuiltInFunctionVariant::Token(token) = function.variant();
+                self.yul_identifier(self.range(&token), token.text.clone())
+            }
+            Variant::YulPath(path) => self.yul_path(&path),
+        }
+    }
+
+    fn yul_path(&self, path: &ast::YulPath) -> SolcNode {
+        let name = path
+            .items()
+            .iter()
+            .map(|component| {
+                let ast::YulPathComponentVariant::Token(token) = component.variant();
+                token.text.clone()
+            })
+            .collect::<Vec<_>>()
+            .join(".");
+
+        self.yul_identifier(self.range(path.cst()), name)
+    }
+
+    fn yul_identifier(&self, range: Range<usize>, name: String) -> SolcNode {
+        let mut node = self.yul_node("YulIdentifier", range);
+        node.set("name", name);
+        node
+    }
+
+    fn yul_literal(&self, literal: &ast::YulLiteral) -> SolcNode {
+        let mut node = self.yul_node("YulLiteral", self.range(literal.cst()));
+
+        match literal.variant() {
+            ast::YulLiteralVariant::StringLiteral(string) => {
+                let ast::StringLiteralVariant::Token(token) = string.variant();
+                let bytes = literals::decode_string(&token.text);
+
+                node.set("hexValue", literals::to_hex(&bytes));
+                node.set("kind", "string");
+                node.set("value", String::from_utf8(bytes).ok());
+            }
+            ast::YulLiteralVariant::HexStringLiteral(string) => {
+                let ast::HexStringLiteralVariant::Token(token) = string.variant();
+                let bytes = literals::decode_hex_string(&token.text);
+
+                node.set("hexValue", literals::to_hex(&bytes));
+                node.set("kind", "string");
+                node.set("value", String::from_utf8(bytes).ok());
+            }
+            ast::YulLiteralVariant::Token(token) => {
+                let kind = match token.kind {
+                    TokenKind::YulTrueKeyword | TokenKind::YulFalseKeyword => "bool",
+                    _ => "number",
+                };
+
+                node.set("kind", kind);
+                node.set("value", token.text.clone());
+            }
+        }
+
+        node.set("type", "");
+        node
+    }
+}
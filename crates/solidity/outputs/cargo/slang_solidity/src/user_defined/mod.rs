@@ -1 +1,2 @@
+pub mod parse_error;
 pub mod query;
@@ -0,0 +1,187 @@
+use crate::kinds::{RuleKind, TokenKind};
+use crate::parse_error::TokenCategory;
+
+/// Broader categories come first, so that they are used instead of the narrower ones they contain.
+pub(crate) static TOKEN_CATEGORIES: &[TokenCategory] = &[
+    TokenCategory {
+        name: "a statement",
+        required: &[
+            TokenKind::IfKeyword,
+            TokenKind::ReturnKeyword,
+            TokenKind::Identifier,
+        ],
+        contains: is_statement_start,
+    },
+    TokenCategory {
+        name: "an expression",
+        required: &[
+            TokenKind::Identifier,
+            TokenKind::DecimalLiteral,
+            TokenKind::OpenParen,
+        ],
+        contains: is_expression_start,
+    },
+    TokenCategory {
+        name: "a type name",
+        required: &[
+            TokenKind::Identifier,
+            TokenKind::BoolKeyword,
+            TokenKind::MappingKeyword,
+        ],
+        contains: is_type_name_start,
+    },
+    TokenCategory {
+        name: "a Yul expression",
+        required: &[TokenKind::YulIdentifier, TokenKind::YulDecimalLiteral],
+        contains: is_yul_expression_start,
+    },
+    TokenCategory {
+        name: "a string literal",
+        required: &[
+            TokenKind::DoubleQuotedStringLiteral,
+            TokenKind::SingleQuotedStringLiteral,
+        ],
+        contains: is_string_literal,
+    },
+    TokenCategory {
+        name: "a hex string literal",
+        required: &[
+            TokenKind::DoubleQuotedHexStringLiteral,
+            TokenKind::SingleQuotedHexStringLiteral,
+        ],
+        contains: is_hex_string_literal,
+    },
+    TokenCategory {
+        name: "a number unit",
+        required: &[TokenKind::EtherKeyword, TokenKind::WeiKeyword],
+        contains: is_number_unit,
+    },
+    TokenCategory {
+        name: "an operator",
+        required: &[TokenKind::Plus, TokenKind::Asterisk, TokenKind::EqualEqual],
+        contains: is_operator,
+    },
+];
+
+fn is_statement_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::OpenBrace
+            | TokenKind::IfKeyword
+            | TokenKind::ForKeyword
+            | TokenKind::WhileKeyword
+            | TokenKind::DoKeyword
+            | TokenKind::ContinueKeyword
+            | TokenKind::BreakKeyword
+            | TokenKind::ReturnKeyword
+            | TokenKind::EmitKeyword
+            | TokenKind::TryKeyword
+            | TokenKind::RevertKeyword
+            | TokenKind::ThrowKeyword
+            | TokenKind::AssemblyKeyword
+            | TokenKind::UncheckedKeyword
+            | TokenKind::VarKeyword
+    ) || is_expression_start(kind)
+        || is_type_name_start(kind)
+}
+
+fn is_expression_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Identifier
+            | TokenKind::DecimalLiteral
+            | TokenKind::HexLiteral
+            | TokenKind::TrueKeyword
+            | TokenKind::FalseKeyword
+            | TokenKind::NewKeyword
+            | TokenKind::TypeKeyword
+            | TokenKind::PayableKeyword
+            | TokenKind::OpenParen
+            | TokenKind::OpenBracket
+            | TokenKind::Bang
+            | TokenKind::Tilde
+            | TokenKind::Plus
+            | TokenKind::Minus
+            | TokenKind::PlusPlus
+            | TokenKind::MinusMinus
+            | TokenKind::DeleteKeyword
+    ) || is_string_literal(kind)
+        || is_hex_string_literal(kind)
+        || is_elementary_type(kind)
+}
+
+fn is_type_name_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Identifier | TokenKind::MappingKeyword | TokenKind::FunctionKeyword
+    ) || is_elementary_type(kind)
+}
+
+fn is_elementary_type(kind: TokenKind) -> bool {
+    // Address types are rules, which start with their keyword:
+    kind == TokenKind::AddressKeyword || RuleKind::ElementaryType.token_variants().contains(&kind)
+}
+
+fn is_string_literal(kind: TokenKind) -> bool {
+    RuleKind::StringLiteral.token_variants().contains(&kind)
+        || RuleKind::UnicodeStringLiteral
+            .token_variants()
+            .contains(&kind)
+}
+
+fn is_hex_string_literal(kind: TokenKind) -> bool {
+    RuleKind::HexStringLiteral.token_variants().contains(&kind)
+}
+
+fn is_number_unit(kind: TokenKind) -> bool {
+    RuleKind::NumberUnit.token_variants().contains(&kind)
+}
+
+fn is_operator(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Ampersand
+            | TokenKind::AmpersandAmpersand
+            | TokenKind::AmpersandEqual
+            | TokenKind::Asterisk
+            | TokenKind::AsteriskAsterisk
+            | TokenKind::AsteriskEqual
+            | TokenKind::BangEqual
+            | TokenKind::Bar
+            | TokenKind::BarBar
+            | TokenKind::BarEqual
+            | TokenKind::Caret
+            | TokenKind::CaretEqual
+            | TokenKind::Equal
+            | TokenKind::EqualEqual
+            | TokenKind::GreaterThan
+            | TokenKind::GreaterThanEqual
+            | TokenKind::GreaterThanGreaterThan
+            | TokenKind::GreaterThanGreaterThanEqual
+            | TokenKind::GreaterThanGreaterThanGreaterThan
+            | TokenKind::GreaterThanGreaterThanGreaterThanEqual
+            | TokenKind::LessThan
+            | TokenKind::LessThanEqual
+            | TokenKind::LessThanLessThan
+            | TokenKind::LessThanLessThanEqual
+            | TokenKind::Minus
+            | TokenKind::MinusEqual
+            | TokenKind::Percent
+            | TokenKind::PercentEqual
+            | TokenKind::Plus
+            | TokenKind::PlusEqual
+            | TokenKind::QuestionMark
+            | TokenKind::Slash
+            | TokenKind::SlashEqual
+    )
+}
+
+fn is_yul_expression_start(kind: TokenKind) -> bool {
+    kind == TokenKind::YulIdentifier
+        || RuleKind::YulLiteral.token_variants().contains(&kind)
+        || RuleKind::YulBuiltInFunction
+            .token_variants()
+            .contains(&kind)
+        || is_string_literal(kind)
+        || is_hex_string_literal(kind)
+}
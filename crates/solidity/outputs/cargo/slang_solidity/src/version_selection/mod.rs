@@ -0,0 +1,172 @@
+//! Selecting the language version to parse a source file with, from its `pragma solidity` directives.
+//!
+//! The directives are found by scanning the source, before it is parsed, so they can be used to create the
+//! [`Language`] that parses it. Version expressions follow the syntax of `solc`: sets of comparators separated by
+//! `||`, where a version without an operator only matches that exact version, and `a - b` matches the versions
+//! between `a` and `b` (inclusive).
+
+mod pragma;
+
+use semver::Version;
+
+pub use self::pragma::VersionPragma;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::language::Language;
+use crate::text_index::{TextIndex, TextRange};
+
+/// The result of [`select_version`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionSelection {
+    /// The selected version, which is always one of [`Language::SUPPORTED_VERSIONS`].
+    pub version: Version,
+    /// All `pragma solidity` directives found in the source, in order.
+    pub pragmas: Vec<VersionPragma>,
+    pub errors: Vec<VersionSelectionError>,
+}
+
+impl VersionSelection {
+    /// Creates a [`Language`] for the selected version.
+    pub fn language(&self) -> Language {
+        Language::new(self.version.clone()).expect("Selected versions are supported.")
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum VersionSelectionError {
+    #[error("Invalid version expression in 'pragma solidity' directive.")]
+    InvalidExpression { text_range: TextRange },
+
+    #[error("No supported Solidity version satisfies this 'pragma solidity' directive. Using version '{selected}'.")]
+    NoSupportedVersion {
+        selected: Version,
+        text_range: TextRange,
+    },
+
+    #[error("Requested Solidity version '{requested}' does not satisfy this 'pragma solidity' directive.")]
+    ConflictingVersion {
+        requested: Version,
+        text_range: TextRange,
+    },
+
+    #[error(
+        "Requested Solidity version '{requested}' is not supported. Using version '{selected}'."
+    )]
+    UnsupportedVersion {
+        requested: Version,
+        selected: Version,
+    },
+}
+
+impl Diagnostic for VersionSelectionError {
+    fn range(&self) -> TextRange {
+        match self {
+            Self::InvalidExpression { text_range }
+            | Self::NoSupportedVersion { text_range, .. }
+            | Self::ConflictingVersion { text_range, .. } => text_range.clone(),
+            Self::UnsupportedVersion { .. } => TextIndex::ZERO..TextIndex::ZERO,
+        }
+    }
+
+    fn code(&self) -> Box<dyn std::fmt::Display> {
+        Box::new(match self {
+            Self::InvalidExpression { .. } => "VersionInvalidExpression",
+            Self::NoSupportedVersion { .. } => "VersionNotSupported",
+            Self::ConflictingVersion { .. } => "VersionConflict",
+            Self::UnsupportedVersion { .. } => "VersionUnsupported",
+        })
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            Self::InvalidExpression { .. } | Self::NoSupportedVersion { .. } => Severity::Error,
+            // The source can still be parsed with the requested version:
+            Self::ConflictingVersion { .. } | Self::UnsupportedVersion { .. } => Severity::Warning,
+        }
+    }
+
+    fn message(&self) -> String {
+        ToString::to_string(&self)
+    }
+}
+
+/// Selects the version to parse the `input` with.
+///
+/// If a supported version is `requested`, it is always selected, and any directive it doesn't satisfy is reported.
+/// Otherwise, the latest supported version that satisfies all directives is selected, falling back to the latest
+/// supported version if there are no directives, or if no supported version satisfies them.
+pub fn select_version(input: &str, requested: Option<&Version>) -> VersionSelection {
+    let latest = Language::SUPPORTED_VERSIONS
+        .last()
+        .expect("There is at least one supported version.");
+
+    let language = Language::new(latest.clone()).expect("The latest version is supported.");
+    let pragmas = pragma::scan(&language, input);
+
+    let mut errors = pragmas
+        .iter()
+        .filter(|pragma| pragma.requirements.is_empty())
+        .map(|pragma| VersionSelectionError::InvalidExpression {
+            text_range: pragma.text_range.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    // Invalid directives are ignored:
+    let valid = pragmas
+        .iter()
+        .filter(|pragma| !pragma.requirements.is_empty())
+        .collect::<Vec<_>>();
+
+    let requested = match requested {
+        Some(requested) if Language::SUPPORTED_VERSIONS.contains(requested) => Some(requested),
+        Some(requested) => {
+            errors.push(VersionSelectionError::UnsupportedVersion {
+                requested: requested.clone(),
+                selected: best_version(&valid).unwrap_or(latest).clone(),
+            });
+            None
+        }
+        None => None,
+    };
+
+    let version = if let Some(requested) = requested {
+        errors.extend(
+            valid
+                .iter()
+                .filter(|pragma| !pragma.matches(requested))
+                .map(|pragma| VersionSelectionError::ConflictingVersion {
+                    requested: requested.clone(),
+                    text_range: pragma.text_range.clone(),
+                }),
+        );
+
+        requested.clone()
+    } else if let Some(best) = best_version(&valid) {
+        best.clone()
+    } else {
+        // Report the first directive that excludes all versions allowed by the previous ones:
+        let conflicting = (1..=valid.len())
+            .find(|&count| best_version(&valid[..count]).is_none())
+            .expect("Some directive excludes all supported versions.");
+
+        errors.push(VersionSelectionError::NoSupportedVersion {
+            selected: latest.clone(),
+            text_range: valid[conflicting - 1].text_range.clone(),
+        });
+
+        latest.clone()
+    };
+
+    VersionSelection {
+        version,
+        pragmas,
+        errors,
+    }
+}
+
+/// Returns the latest supported version that satisfies all of the directives.
+fn best_version(pragmas: &[&VersionPragma]) -> Option<&'static Version> {
+    Language::SUPPORTED_VERSIONS
+        .iter()
+        .rev()
+        .find(|version| pragmas.iter().all(|pragma| pragma.matches(version)))
+}
@@ -0,0 +1,184 @@
+use semver::{Version, VersionReq};
+
+use crate::kinds::{LexicalContext, TokenKind};
+use crate::language::Language;
+use crate::text_index::{TextIndex, TextRange};
+use crate::tokenizer::{KeywordScan, Token};
+
+/// A `pragma solidity` directive found in the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionPragma {
+    /// Range of the version expression, following the `solidity` keyword.
+    pub text_range: TextRange,
+    /// The alternatives separated by `||`, one of which has to match. Empty if the expression is invalid.
+    pub requirements: Vec<VersionReq>,
+}
+
+impl VersionPragma {
+    pub fn matches(&self, version: &Version) -> bool {
+        self.requirements
+            .iter()
+            .any(|requirement| requirement.matches(version))
+    }
+}
+
+/// Finds all `pragma solidity` directives without parsing the source: other tokens are scanned in the `Default`
+/// lexical context, and the tokens following each `pragma` keyword in the `Pragma` context, up to the `;`.
+pub(super) fn scan(language: &Language, input: &str) -> Vec<VersionPragma> {
+    let mut pragmas = vec![];
+    let mut start = TextIndex::ZERO;
+
+    'outer: loop {
+        for token in significant_tokens(language, input, start, LexicalContext::Default) {
+            if token.kind != TokenKind::PragmaKeyword {
+                continue;
+            }
+
+            start = token.range.end;
+
+            let mut tokens =
+                significant_tokens(language, input, start, LexicalContext::Pragma).peekable();
+
+            let is_version_pragma = tokens.next_if(|token| {
+                token.kind == TokenKind::SolidityKeyword
+                    || token.keyword == KeywordScan::Present(TokenKind::SolidityKeyword)
+            });
+
+            let mut expression = vec![];
+            for token in tokens {
+                start = token.range.end;
+
+                if token.kind == TokenKind::Semicolon {
+                    break;
+                }
+                expression.push(token);
+            }
+
+            if let (Some(_), Some(first), Some(last)) =
+                (is_version_pragma, expression.first(), expression.last())
+            {
+                pragmas.push(VersionPragma {
+                    text_range: first.range.start..last.range.end,
+                    requirements: requirements(input, &expression).unwrap_or_default(),
+                });
+            }
+
+            // Continue scanning after the directive:
+            continue 'outer;
+        }
+
+        return pragmas;
+    }
+}
+
+/// Scans the input from `start`, skipping trivia, and returns tokens with ranges relative to the whole input.
+fn significant_tokens<'a>(
+    language: &'a Language,
+    input: &'a str,
+    start: TextIndex,
+    context: LexicalContext,
+) -> impl Iterator<Item = Token> + 'a {
+    language
+        .tokenize(&input[start.utf8..], context)
+        .filter(|token| !token.kind.is_trivia())
+        .map(move |token| Token {
+            range: start + token.range.start..start + token.range.end,
+            ..token
+        })
+}
+
+/// Converts the tokens of a version expression to one requirement per set of comparators separated by `||`,
+/// or returns `None` if it is not valid.
+fn requirements(input: &str, expression: &[Token]) -> Option<Vec<VersionReq>> {
+    expression
+        .split(|token| token.kind == TokenKind::BarBar)
+        .map(|set| {
+            let comparators = comparators(input, set)?;
+            VersionReq::parse(&comparators.join(", ")).ok()
+        })
+        .collect()
+}
+
+/// Converts a set of version expressions to comparators in the syntax of [`VersionReq`].
+fn comparators(input: &str, set: &[Token]) -> Option<Vec<String>> {
+    let mut comparators = vec![];
+    let mut tokens = set.iter().peekable();
+
+    while tokens.peek().is_some() {
+        let operator = tokens
+            .next_if(|token| comparison_operator(token.kind).is_some())
+            .and_then(|token| comparison_operator(token.kind));
+
+        let version = read_version(input, &mut tokens)?;
+
+        if tokens
+            .next_if(|token| token.kind == TokenKind::Minus)
+            .is_some()
+        {
+            // A hyphen range can't have an operator:
+            if operator.is_some() {
+                return None;
+            }
+
+            let end = read_version(input, &mut tokens)?;
+            comparators.push(format!(">={version}"));
+            comparators.push(format!("<={end}"));
+            continue;
+        }
+
+        match operator {
+            Some(operator) => comparators.push(format!("{operator}{version}")),
+            // Wildcards match any version:
+            None if version.starts_with(['*', 'x', 'X']) => comparators.push("*".to_owned()),
+            // Unlike Cargo, a version without an operator only matches that exact version:
+            None => comparators.push(format!("={version}")),
+        }
+    }
+
+    (!comparators.is_empty()).then_some(comparators)
+}
+
+fn comparison_operator(kind: TokenKind) -> Option<&'static str> {
+    match kind {
+        TokenKind::Caret => Some("^"),
+        TokenKind::Tilde => Some("~"),
+        TokenKind::Equal => Some("="),
+        TokenKind::LessThan => Some("<"),
+        TokenKind::GreaterThan => Some(">"),
+        TokenKind::LessThanEqual => Some("<="),
+        TokenKind::GreaterThanEqual => Some(">="),
+        _ => None,
+    }
+}
+
+/// Reads a version made of specifiers separated by periods (e.g. `0.8.x`), or a quoted version literal.
+fn read_version<'a>(
+    input: &str,
+    tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a Token>>,
+) -> Option<String> {
+    let first = tokens.next()?;
+    let text = &input[first.range.start.utf8..first.range.end.utf8];
+
+    match first.kind {
+        TokenKind::SingleQuotedVersionLiteral | TokenKind::DoubleQuotedVersionLiteral => {
+            Some(text[1..text.len() - 1].to_owned())
+        }
+        TokenKind::VersionSpecifier => {
+            let mut version = text.to_owned();
+
+            while tokens
+                .next_if(|token| token.kind == TokenKind::Period)
+                .is_some()
+            {
+                let specifier =
+                    tokens.next_if(|token| token.kind == TokenKind::VersionSpecifier)?;
+
+                version.push('.');
+                version.push_str(&input[specifier.range.start.utf8..specifier.range.end.utf8]);
+            }
+
+            Some(version)
+        }
+        _ => None,
+    }
+}
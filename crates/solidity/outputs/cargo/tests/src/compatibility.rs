@@ -0,0 +1,165 @@
+use anyhow::Result;
+use infra_utils::cargo::CargoWorkspace;
+use infra_utils::paths::PathExtensions;
+use semver::Version;
+use slang_solidity::compatibility::{check_compatibility, Compatibility, Construct};
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+
+fn check(source: &str) -> Compatibility {
+    check_compatibility(RuleKind::SourceUnit, source)
+}
+
+fn range(compatibility: &Compatibility) -> (String, String) {
+    (
+        compatibility.minimum_version().unwrap().to_string(),
+        compatibility.maximum_version().unwrap().to_string(),
+    )
+}
+
+fn excluding(compatibility: &Compatibility, version: &str) -> Result<Vec<String>> {
+    let version = Version::parse(version)?;
+
+    Ok(compatibility
+        .constraints_excluding(&version)
+        .map(ToString::to_string)
+        .collect())
+}
+
+#[test]
+fn all_versions() {
+    let compatibility = check("contract A {\n  function f() public {}\n}\n");
+
+    assert_eq!(compatibility.versions, Language::SUPPORTED_VERSIONS);
+    assert_eq!(compatibility.constraints, []);
+}
+
+#[test]
+fn minimum_version() -> Result<()> {
+    let source = "contract A {\n  function f() public {\n    unchecked { x += 1; }\n  }\n}\n";
+    let compatibility = check(source);
+
+    assert_eq!(range(&compatibility), ("0.8.0".into(), "0.8.25".into()));
+    assert_eq!(
+        excluding(&compatibility, "0.7.6")?,
+        [
+            "UncheckedBlock in Statement requires >=0.8.0",
+            "UncheckedBlock requires >=0.8.0",
+        ]
+    );
+
+    let constraint = &compatibility.constraints[1];
+    assert_eq!(
+        constraint.construct,
+        Construct::Rule(RuleKind::UncheckedBlock)
+    );
+    assert_eq!(
+        &source[constraint.text_range.start.utf8..constraint.text_range.end.utf8],
+        "unchecked { x += 1; }"
+    );
+
+    let compatibility = check("contract A {\n  constructor() {}\n}\n");
+    assert_eq!(range(&compatibility), ("0.4.22".into(), "0.8.25".into()));
+    assert!(compatibility
+        .constraints
+        .iter()
+        .any(|constraint| constraint.construct == Construct::Token(TokenKind::ConstructorKeyword)));
+
+    Ok(())
+}
+
+#[test]
+fn maximum_version() -> Result<()> {
+    // Not valid in the latest version, so it is parsed again with older ones:
+    let compatibility = check("contract A {\n  uint x = 1 finney;\n}\n");
+
+    assert_eq!(range(&compatibility), ("0.4.11".into(), "0.6.12".into()));
+    assert_eq!(
+        excluding(&compatibility, "0.7.0")?,
+        [
+            "FinneyKeyword in NumberUnit requires <0.7.0",
+            "FinneyKeyword requires <0.7.0",
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn older_minor_versions() {
+    // Not valid in the latest version, and not explained by any construct of the tree:
+    let compatibility = check("contract A {\n  function() payable {}\n}\n");
+
+    assert_eq!(range(&compatibility), ("0.4.11".into(), "0.5.17".into()));
+}
+
+#[test]
+fn reserved_words() -> Result<()> {
+    let compatibility = check("contract A {\n  uint emit;\n}\n");
+
+    assert_eq!(range(&compatibility), ("0.4.11".into(), "0.4.26".into()));
+    assert_eq!(
+        excluding(&compatibility, "0.5.0")?,
+        ["'emit' as Identifier requires <0.5.0"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_in_all_versions() {
+    let compatibility = check("contract {}");
+
+    assert_eq!(compatibility.versions, Vec::<Version>::new());
+    assert_eq!(compatibility.minimum_version(), None);
+}
+
+#[test]
+fn keywords_as_identifiers() {
+    // 'leave' is only a keyword since 0.6.0, and an identifier before it:
+    let source =
+        "contract A {\n  function f() public {\n    assembly {\n      function g() { leave }\n    }\n  }\n}\n";
+    let compatibility = check(source);
+
+    assert_eq!(range(&compatibility), ("0.4.11".into(), "0.8.25".into()));
+}
+
+#[test]
+fn literals() -> Result<()> {
+    // Upper case hex prefixes are only allowed before 0.5.0, which is not a construct of the tree:
+    let compatibility = check("contract A {\n  uint x = 0X1;\n}\n");
+
+    assert_eq!(range(&compatibility), ("0.4.11".into(), "0.4.26".into()));
+    assert_eq!(excluding(&compatibility, "0.5.0")?, Vec::<String>::new());
+
+    Ok(())
+}
+
+#[test]
+fn snapshots() -> Result<()> {
+    let snapshots_dir = CargoWorkspace::locate_source_crate("solidity_testing_snapshots")?;
+
+    for test_dir in snapshots_dir.join("solc_ast").collect_children()? {
+        let source = test_dir.join("input.sol").read_to_string()?;
+
+        // The versions that parse it, one by one:
+        let mut expected = vec![];
+        for version in Language::SUPPORTED_VERSIONS {
+            if Language::new(version.clone())?
+                .parse(RuleKind::SourceUnit, &source)
+                .is_valid()
+            {
+                expected.push(version.clone());
+            }
+        }
+
+        assert_eq!(
+            check(&source).versions,
+            expected,
+            "{}",
+            test_dir.strip_repo_root()?.unwrap_str()
+        );
+    }
+
+    Ok(())
+}
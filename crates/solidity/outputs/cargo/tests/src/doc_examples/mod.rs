@@ -1,4 +1,5 @@
 mod tree_query_language;
 mod using_queries;
+mod using_the_ast;
 mod using_the_cursor;
 mod using_the_parser;
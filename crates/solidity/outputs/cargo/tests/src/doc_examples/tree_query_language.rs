@@ -19,9 +19,12 @@ impl RemoveMkdocSnippetMarkers for &str {
     }
 }
 
+fn language() -> Language {
+    Language::new(Version::new(0, 8, 12)).unwrap()
+}
+
 fn assert_matches(query: &Query, kind: RuleKind, source: &str) -> QueryResultIterator {
-    let language = Language::new(Version::new(0, 8, 12)).unwrap();
-    let cursor = language.parse(kind, source).create_tree_cursor();
+    let cursor = language().parse(kind, source).create_tree_cursor();
 
     let tree = cursor.node();
     assert!(
@@ -284,3 +287,96 @@ fn alternations() {
         "break"
     );
 }
+
+#[test]
+fn predicates() {
+    let query = Query::parse(
+        &r#"
+    // --8<-- [start:predicates-1]
+	[FunctionDefinition
+		...
+		@name [name: FunctionName]
+		...
+	]
+	(#match? @name "^_")
+    // --8<-- [end:predicates-1]
+    "#
+        .remove_mkdoc_snippet_markers(),
+    )
+    .unwrap();
+
+    assert_matches(
+        &query,
+        RuleKind::FunctionDefinition,
+        "function _f() internal {}",
+    );
+    assert!(language()
+        .parse(RuleKind::FunctionDefinition, "function f() internal {}")
+        .create_tree_cursor()
+        .query(vec![query])
+        .next()
+        .is_none());
+
+    let query = Query::parse(
+        &"
+    // --8<-- [start:predicates-2]
+	[Statements
+		...
+		[Statement [VariableDeclarationStatement ... @declared [name: Identifier] ...]]
+		...
+		[Statement [ExpressionStatement
+			[Expression [AssignmentExpression @assigned [left_operand: _] ...]]
+			...
+		]]
+		...
+	]
+	(#eq? @declared @assigned)
+    // --8<-- [end:predicates-2]
+    "
+        .remove_mkdoc_snippet_markers(),
+    )
+    .unwrap();
+
+    let results: Vec<_> = assert_matches(
+        &query,
+        RuleKind::Statements,
+        "uint a; uint b; b = 1; a = 2;",
+    )
+    .collect();
+    assert_eq!(results.len(), 2);
+}
+
+#[test]
+fn negation() {
+    let query = Query::parse(
+        &"
+    // --8<-- [start:negation-1]
+	[FunctionDefinition
+		...
+		[attributes: FunctionAttributes
+			!(... [FunctionAttribute ... [VirtualKeyword]])
+			...
+		]
+		...
+	]
+    // --8<-- [end:negation-1]
+    "
+        .remove_mkdoc_snippet_markers(),
+    )
+    .unwrap();
+
+    assert_matches(
+        &query,
+        RuleKind::FunctionDefinition,
+        "function f() public {}",
+    );
+    assert!(language()
+        .parse(
+            RuleKind::FunctionDefinition,
+            "function f() public virtual {}"
+        )
+        .create_tree_cursor()
+        .query(vec![query])
+        .next()
+        .is_none());
+}
@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use anyhow::Result;
+use infra_utils::paths::PathExtensions;
+
+#[test]
+fn using_the_ast() -> Result<()> {
+    // --8<-- [start:imports]
+    use semver::Version;
+    use slang_solidity::ast::{FunctionDefinition, FunctionNameVariant};
+    use slang_solidity::kinds::RuleKind;
+    use slang_solidity::language::Language;
+    // --8<-- [end:imports]
+
+    let input_path = Path::repo_path("documentation/public/user-guide/inputs/using-the-ast.sol");
+    let input_path = input_path.unwrap_str();
+
+    let source = std::fs::read_to_string(input_path)?;
+    let source = source.trim();
+
+    // --8<-- [start:parse-input]
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    let parse_output = language.parse(RuleKind::FunctionDefinition, source);
+    // --8<-- [end:parse-input]
+
+    // --8<-- [start:create-node]
+    let tree = parse_output.tree().into_rule().unwrap();
+    let function = FunctionDefinition::cast(tree).unwrap();
+
+    let FunctionNameVariant::Token(name) = function.name().variant();
+    assert_eq!(name.text, "add");
+    // --8<-- [end:create-node]
+
+    // --8<-- [start:list-parameters]
+    let parameters = function
+        .parameters()
+        .parameters()
+        .items()
+        .into_iter()
+        .map(|parameter| parameter.name().map(|name| name.text.clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(parameters, [Some("a".to_string()), Some("b".to_string())]);
+    // --8<-- [end:list-parameters]
+
+    // --8<-- [start:list-attributes]
+    let attributes = function
+        .attributes()
+        .items()
+        .into_iter()
+        .map(|attribute| attribute.cst().clone().unparse().trim().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(attributes, ["public", "pure"]);
+    // --8<-- [end:list-attributes]
+
+    Ok(())
+}
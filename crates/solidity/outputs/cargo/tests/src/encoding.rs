@@ -0,0 +1,155 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cst::Node;
+use slang_solidity::encoding::{self, DecodeError, FORMAT_VERSION, MAX_DEPTH};
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+
+const VALID_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+contract Foo {
+    function bar() public { emit Baz(unicode"ünïcödé 😀"); }
+}
+"#;
+
+const INVALID_SOURCE: &str = "contract Foo { uint a = ; } contract";
+
+#[test]
+fn json_round_trip() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    for source in [VALID_SOURCE, INVALID_SOURCE] {
+        let output = language.parse(RuleKind::SourceUnit, source);
+
+        let json = serde_json::to_string(&output)?;
+        assert_eq!(serde_json::from_str::<ParseOutput>(&json)?, output);
+
+        let json = serde_json::to_string(&output.tree())?;
+        assert_eq!(serde_json::from_str::<Node>(&json)?, output.tree());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn json_text_len_is_recomputed() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let tree = language.parse(RuleKind::SourceUnit, VALID_SOURCE).tree();
+
+    let mut json = serde_json::to_value(&tree)?;
+    json["Rule"]["text_len"]["utf8"] = 0.into();
+
+    let deserialized = serde_json::from_value::<Node>(json)?;
+    assert_eq!(deserialized.text_len(), tree.text_len());
+    assert_eq!(deserialized, tree);
+
+    Ok(())
+}
+
+#[test]
+fn binary_round_trip() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    for source in [VALID_SOURCE, INVALID_SOURCE] {
+        let output = language.parse(RuleKind::SourceUnit, source);
+        assert_eq!(output.is_valid(), source == VALID_SOURCE);
+
+        let bytes = output.to_bytes();
+        assert_eq!(ParseOutput::from_bytes(&bytes)?, output);
+
+        let bytes = encoding::encode_tree(&output.tree());
+        assert_eq!(encoding::decode_tree(&bytes)?, output.tree());
+
+        // Much smaller than the JSON representation:
+        assert!(bytes.len() * 5 < serde_json::to_vec(&output.tree())?.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn binary_decode_errors() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let bytes = language
+        .parse(RuleKind::SourceUnit, VALID_SOURCE)
+        .to_bytes();
+
+    assert_eq!(
+        ParseOutput::from_bytes(b"not slang"),
+        Err(DecodeError::InvalidHeader)
+    );
+
+    let mut other_format = bytes.clone();
+    other_format[4] = u8::try_from(FORMAT_VERSION + 1)?;
+    assert_eq!(
+        ParseOutput::from_bytes(&other_format),
+        Err(DecodeError::UnsupportedFormatVersion(FORMAT_VERSION + 1))
+    );
+
+    assert_eq!(
+        ParseOutput::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+
+    let mut other_language = bytes.clone();
+    // The first letter of the crate name, after the magic number, format version, and name length:
+    other_language[6] = b'x';
+    assert_eq!(
+        ParseOutput::from_bytes(&other_language),
+        Err(DecodeError::UnsupportedLanguage(
+            "xlang_solidity".to_owned()
+        ))
+    );
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        ParseOutput::from_bytes(&trailing),
+        Err(DecodeError::InvalidData(bytes.len()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn binary_max_depth() -> Result<()> {
+    // Ends with the empty token: its tag, kind, and length, then the number of errors:
+    let empty = encoding::encode_tree(&Node::token(TokenKind::SKIPPED, String::new()));
+    let (header, token) = empty.split_at(empty.len() - 4);
+    assert_eq!(token, [1, TokenKind::SKIPPED as u8, 0, 0]);
+
+    let nested = |depth: usize| {
+        let mut bytes = header.to_vec();
+        for _ in 0..depth {
+            // A rule of the first kind, with a single unlabeled child:
+            bytes.extend([0, 0, 1, 0]);
+        }
+        bytes.extend(token);
+        bytes
+    };
+
+    let tree = encoding::decode_tree(&nested(MAX_DEPTH))?;
+    let mut depth = 0;
+    let mut node = &tree;
+    while let [child] = node.children() {
+        depth += 1;
+        node = &child.node;
+    }
+    assert_eq!(depth, MAX_DEPTH);
+    assert_eq!(
+        node.as_token_with_kind(TokenKind::SKIPPED).unwrap().text,
+        ""
+    );
+
+    assert_eq!(
+        encoding::decode_tree(&nested(MAX_DEPTH + 1)),
+        Err(DecodeError::MaxDepthExceeded)
+    );
+    assert_eq!(
+        encoding::decode_tree(&nested(1_000_000)),
+        Err(DecodeError::MaxDepthExceeded)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cst::{LabeledNode, Node};
+use slang_solidity::formatter::{self, FormatError, FormatterOptions};
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+
+fn parse(source: &str) -> Result<ParseOutput> {
+    let version = Version::parse("0.8.4")?;
+    let language = Language::new(version)?;
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid(), "{:?}", output.errors());
+
+    Ok(output)
+}
+
+fn format(source: &str, options: &FormatterOptions) -> Result<String> {
+    let formatted = formatter::format(&parse(source)?, options)?;
+
+    // Formatting is idempotent:
+    let reformatted = formatter::format(&parse(&formatted)?, options)?;
+    assert_eq!(formatted, reformatted);
+
+    Ok(formatted)
+}
+
+/// Returns a copy of the tree without any trivia, which the formatter is allowed to change.
+fn strip_trivia(node: &Node) -> Node {
+    match node {
+        Node::Rule(rule) => Node::rule(
+            rule.kind,
+            rule.children
+                .iter()
+                .filter(|child| !child.node.is_trivia())
+                .map(|child| LabeledNode {
+                    label: child.label,
+                    node: strip_trivia(&child.node),
+                })
+                .collect(),
+        ),
+        Node::Token(_) => node.clone(),
+    }
+}
+
+fn significant_tokens(output: &ParseOutput) -> Vec<String> {
+    output
+        .create_tree_cursor()
+        .filter_map(|node| match node {
+            Node::Token(token) if !token.kind.is_trivia() => Some(token.text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn normalizes_whitespace() -> Result<()> {
+    let source = r"pragma solidity   ^0.8.0;
+contract Foo is Bar(1){
+uint[ ] public values ;
+
+
+
+    function add( uint a,uint b ) public pure returns(uint){ return a+b*- -a; }
+    receive( ) external payable{ }
+}";
+
+    assert_eq!(
+        format(source, &FormatterOptions::default())?,
+        r"pragma solidity ^0.8.0;
+contract Foo is Bar(1) {
+    uint[] public values;
+
+    function add(uint a, uint b) public pure returns (uint) {
+        return a + b * - -a;
+    }
+    receive() external payable {}
+}
+"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn preserves_comments() -> Result<()> {
+    let source = r"// SPDX-License-Identifier: MIT
+contract Foo { // after the brace
+    /// @notice Doc comment
+    uint x ;  // trailing
+
+    /* block */ function bar() public { x = 1; // assignment
+    }
+    // end of contract
+}
+// end of file
+";
+
+    assert_eq!(
+        format(source, &FormatterOptions::default())?,
+        r"// SPDX-License-Identifier: MIT
+contract Foo { // after the brace
+    /// @notice Doc comment
+    uint x; // trailing
+
+    /* block */ function bar() public {
+        x = 1; // assignment
+    }
+    // end of contract
+}
+// end of file
+"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn breaks_long_lists() -> Result<()> {
+    let source =
+        "function transfer(address from, address to, uint256 amount, bytes memory data) {}";
+
+    let options = FormatterOptions {
+        line_width: 60,
+        ..FormatterOptions::default()
+    };
+
+    assert_eq!(
+        format(source, &options)?,
+        r"function transfer(
+    address from,
+    address to,
+    uint256 amount,
+    bytes memory data
+) {}
+"
+    );
+
+    let options = FormatterOptions {
+        line_width: 100,
+        ..FormatterOptions::default()
+    };
+
+    assert_eq!(format(source, &options)?, format!("{source}\n"));
+
+    Ok(())
+}
+
+#[test]
+fn sorts_imports() -> Result<()> {
+    let source = r#"pragma solidity ^0.8.0;
+
+import "./z.sol";
+import {A} from "./a.sol";
+
+// Not sorted, as it is commented:
+import "./y.sol";
+import "./b.sol";
+"#;
+
+    // Opt-in, as it is the only change that reorders tokens:
+    assert_eq!(format(source, &FormatterOptions::default())?, source);
+
+    let options = FormatterOptions {
+        sort_imports: true,
+        ..FormatterOptions::default()
+    };
+
+    assert_eq!(
+        format(source, &options)?,
+        r#"pragma solidity ^0.8.0;
+
+import {A} from "./a.sol";
+import "./z.sol";
+
+// Not sorted, as it is commented:
+import "./y.sol";
+import "./b.sol";
+"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn preserves_tokens() -> Result<()> {
+    let source = r"
+contract Foo {
+    mapping(address=>uint) balances;
+    function f(uint[] calldata xs) external returns(uint total, bool) {
+        for(uint i=0;i<xs.length;i++){ total+=xs[i]; }
+        (uint a, , bool b) = g{value: 1}({x: xs[1:], y: type(uint).max});
+        try this.h() returns (uint v) { delete balances[msg.sender]; } catch Error(string memory) {}
+        assembly { let y := add(mload(0x40), 1) switch y case 0 { y := 1 } default {} }
+        unchecked { total = total ** 2 << 1 | ~total; }
+        return (a > 0 ? total : 0, !b);
+    }
+}
+";
+
+    let formatted = format(source, &FormatterOptions::default())?;
+
+    assert_eq!(
+        significant_tokens(&parse(source)?),
+        significant_tokens(&parse(&formatted)?)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn preserves_tree() -> Result<()> {
+    let source = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+import "./z.sol";
+import {A} from "./a.sol";
+/// @title Foo
+contract Foo is A , B(1) {
+    using Math for uint;
+    struct S { uint a; mapping(address=>uint) b; }
+    enum E { X , Y }
+    event Ev(address indexed from , uint value);
+    modifier only( ) { _ ; }
+    function f(uint[] calldata xs) external only returns(uint total) {
+        for(uint i=0;i<xs.length;i++){ total+=xs[i]; } // sum
+        if (total > 10) { revert(); } else if (total == 0) return 0 ; else { emit Ev(msg.sender , total) ; }
+        assembly { let y := add(mload(0x40), 1) }
+    }
+}
+"#;
+
+    let formatted = format(source, &FormatterOptions::default())?;
+    assert_ne!(formatted, source);
+
+    assert_eq!(
+        strip_trivia(&parse(source)?.tree()),
+        strip_trivia(&parse(&formatted)?.tree())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_input() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.4")?)?;
+    let output = language.parse(RuleKind::SourceUnit, "contract Foo { uint x }");
+
+    assert!(matches!(
+        formatter::format(&output, &FormatterOptions::default()),
+        Err(FormatError::SyntaxErrors(1))
+    ));
+
+    Ok(())
+}
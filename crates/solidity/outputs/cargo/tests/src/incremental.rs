@@ -0,0 +1,121 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cst::{Node, NodeRc, RuleNode};
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::text_index::{TextEdit, TextIndex};
+
+const SOURCE: &str = r"
+contract Foo {
+    function first() public {
+        uint a = 1;
+    }
+
+    function second() public {
+        uint b = 2;
+    }
+}
+";
+
+#[test]
+fn edit_inside_block_shares_siblings() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let previous = language.parse(RuleKind::SourceUnit, SOURCE);
+
+    let (output, input) = reparse(&language, &previous, "uint b = 2;", "uint b = 2 + 3;");
+    assert!(output.is_valid());
+
+    let previous_functions = function_definitions(&previous);
+    let new_functions = function_definitions(&output);
+
+    // The first function was not touched, and should be shared:
+    assert!(NodeRc::ptr_eq(&previous_functions[0], &new_functions[0]));
+    assert!(!NodeRc::ptr_eq(&previous_functions[1], &new_functions[1]));
+
+    // The edited function still shares its unchanged children (i.e. its name and attributes):
+    let name = |function: &NodeRc<RuleNode>| function.children[1].node.clone();
+    assert!(matches!(
+        (name(&previous_functions[1]), name(&new_functions[1])),
+        (Node::Token(previous), Node::Token(new)) if NodeRc::ptr_eq(&previous, &new)
+    ));
+
+    assert_eq!(output.tree().unparse(), input);
+    Ok(())
+}
+
+#[test]
+fn matches_full_parse() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let previous = language.parse(RuleKind::SourceUnit, SOURCE);
+
+    for (old_text, new_text) in [
+        // Edits within a block:
+        ("uint a = 1;", "uint a = 1; a += 1;"),
+        ("uint a = 1;", ""),
+        ("1", "\"\u{1F600}\""),
+        // Edits that cross or touch the delimiters:
+        ("{\n        uint b", "{ uint b"),
+        ("first() public {", "first() public "),
+        ("}\n}", "}"),
+        // Edits outside of any block:
+        ("function second()", "function renamed()"),
+        ("contract Foo", "interface Foo"),
+        // Edits that introduce errors:
+        ("uint a = 1;", "uint a = ;"),
+        ("2;", "2; }"),
+        ("2;", "2; {"),
+    ] {
+        let (output, input) = reparse(&language, &previous, old_text, new_text);
+
+        assert_eq!(output, language.parse(RuleKind::SourceUnit, &input));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn previous_with_errors() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    let source = SOURCE.replace("uint a = 1;", "uint a = ;");
+    let previous = language.parse(RuleKind::SourceUnit, &source);
+    assert!(!previous.is_valid());
+
+    let (output, input) = reparse(&language, &previous, "uint b = 2;", "uint b = 3;");
+
+    assert_eq!(output, language.parse(RuleKind::SourceUnit, &input));
+    Ok(())
+}
+
+fn reparse(
+    language: &Language,
+    previous: &ParseOutput,
+    old_text: &str,
+    new_text: &str,
+) -> (ParseOutput, String) {
+    let source = previous.tree().unparse();
+
+    let start = source.find(old_text).expect("text not found in source");
+    let end = start + old_text.len();
+
+    let edit = TextEdit {
+        range: TextIndex::from(&source[..start])..TextIndex::from(&source[..end]),
+        new_text: new_text.to_owned(),
+    };
+
+    let input = format!("{}{new_text}{}", &source[..start], &source[end..]);
+    let output = language.reparse(RuleKind::SourceUnit, previous, &edit, &input);
+
+    (output, input)
+}
+
+fn function_definitions(output: &ParseOutput) -> Vec<NodeRc<RuleNode>> {
+    output
+        .create_tree_cursor()
+        .filter_map(|node| {
+            node.as_rule_with_kind(RuleKind::FunctionDefinition)
+                .cloned()
+        })
+        .collect()
+}
@@ -1,5 +1,22 @@
 #![cfg(test)]
 
+mod compatibility;
 mod cst_output;
 mod doc_examples;
+mod encoding;
+mod formatter;
+mod incremental;
+mod lsp;
+mod natspec;
+mod node_at_offset;
+mod outline;
+mod parse_error;
+mod parse_options;
+mod rewriter;
+mod semantic_tokens;
+mod solc_ast;
+mod syntax_tree;
+mod tokenizer;
 mod trivia;
+mod version_selection;
+mod visitor;
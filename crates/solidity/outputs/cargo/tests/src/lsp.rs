@@ -0,0 +1,377 @@
+use std::io::{BufRead, Read};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use slang_solidity::lsp;
+
+const URI: &str = "file:///test.sol";
+
+const SOURCE: &str = r"pragma solidity ^0.8.0;
+
+/*
+ * A counter.
+ */
+contract Counter {
+    uint256 public constant MAX = 10;
+    enum State { Active, Paused }
+
+    constructor() {}
+
+    function increment(uint256 amount) public {
+        count += amount;
+    }
+}
+";
+
+/// Scripts a client session: sends all messages in order, and returns all server messages.
+fn session(messages: &[Value]) -> Result<(bool, Vec<Value>)> {
+    let mut input = vec![];
+    for message in messages {
+        let content = message.to_string();
+        input.extend(format!("Content-Length: {}\r\n\r\n{content}", content.len()).bytes());
+    }
+
+    let mut output = vec![];
+    let shutdown_requested = lsp::run(input.as_slice(), &mut output)?;
+
+    Ok((shutdown_requested, read_messages(&output)?))
+}
+
+fn read_messages(mut output: &[u8]) -> Result<Vec<Value>> {
+    let mut messages = vec![];
+
+    while !output.is_empty() {
+        let mut header = String::new();
+        output.read_line(&mut header)?;
+
+        let length: usize = header
+            .trim()
+            .strip_prefix("Content-Length: ")
+            .context("Missing Content-Length header")?
+            .parse()?;
+
+        let mut separator = String::new();
+        output.read_line(&mut separator)?;
+        assert_eq!(separator, "\r\n");
+
+        let mut content = vec![0; length];
+        output.read_exact(&mut content)?;
+        messages.push(serde_json::from_slice(&content)?);
+    }
+
+    Ok(messages)
+}
+
+fn request(id: u64, method: &str, params: &Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+fn notification(method: &str, params: &Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn did_open(text: &str) -> Value {
+    notification(
+        "textDocument/didOpen",
+        &json!({ "textDocument": { "uri": URI, "languageId": "solidity", "version": 1, "text": text } }),
+    )
+}
+
+fn response(messages: &[Value], id: u64) -> &Value {
+    messages
+        .iter()
+        .find(|message| message["id"] == id)
+        .unwrap_or_else(|| panic!("No response to request {id}"))
+}
+
+fn notifications<'a>(messages: &'a [Value], method: &str) -> Vec<&'a Value> {
+    messages
+        .iter()
+        .filter(|message| message["method"] == method)
+        .collect()
+}
+
+/// Runs a single request against a document with the given text.
+fn query(text: &str, method: &str, params: Value) -> Result<Value> {
+    let mut params = params;
+    params["textDocument"] = json!({ "uri": URI });
+
+    let (_, messages) = session(&[did_open(text), request(1, method, &params)])?;
+    Ok(response(&messages, 1).clone())
+}
+
+#[test]
+fn lifecycle() -> Result<()> {
+    let (shutdown_requested, messages) = session(&[
+        request(1, "initialize", &json!({ "capabilities": {} })),
+        notification("initialized", &json!({})),
+        request(2, "unknown/method", &json!({})),
+        request(3, "shutdown", &Value::Null),
+        request(4, "textDocument/documentSymbol", &json!({})),
+        notification("exit", &Value::Null),
+        // Ignored after exiting:
+        request(5, "shutdown", &Value::Null),
+    ])?;
+
+    assert!(shutdown_requested);
+    assert_eq!(messages.len(), 4);
+
+    let capabilities = &response(&messages, 1)["result"]["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"], 1);
+    assert_eq!(capabilities["documentSymbolProvider"], true);
+    assert_eq!(capabilities["foldingRangeProvider"], true);
+    assert_eq!(capabilities["selectionRangeProvider"], true);
+    assert_eq!(capabilities["documentFormattingProvider"], true);
+    assert_eq!(
+        capabilities["semanticTokensProvider"]["legend"]["tokenModifiers"],
+        json!(["declaration", "readonly", "documentation", "defaultLibrary"])
+    );
+
+    assert_eq!(response(&messages, 2)["error"]["code"], -32601);
+    assert_eq!(response(&messages, 3)["result"], Value::Null);
+    assert_eq!(response(&messages, 4)["error"]["code"], -32600);
+
+    // Exiting without a shutdown request is reported as a failure:
+    let (shutdown_requested, _) = session(&[notification("exit", &Value::Null)])?;
+    assert!(!shutdown_requested);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_messages() -> Result<()> {
+    let mut output = vec![];
+    lsp::run("Content-Length: 3\r\n\r\n{{{".as_bytes(), &mut output)?;
+
+    let messages = read_messages(&output)?;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["id"], Value::Null);
+    assert_eq!(messages[0]["error"]["code"], -32700);
+
+    let (_, messages) = session(&[request(
+        1,
+        "textDocument/foldingRange",
+        &json!({ "textDocument": { "uri": "file:///unknown.sol" } }),
+    )])?;
+    assert_eq!(response(&messages, 1)["error"]["code"], -32602);
+
+    Ok(())
+}
+
+#[test]
+fn diagnostics() -> Result<()> {
+    let (_, messages) = session(&[
+        did_open("contract Foo {\n  uint x\n}"),
+        notification(
+            "textDocument/didChange",
+            &json!({
+                "textDocument": { "uri": URI, "version": 2 },
+                "contentChanges": [{ "text": "contract Foo {}" }],
+            }),
+        ),
+        notification(
+            "textDocument/didClose",
+            &json!({ "textDocument": { "uri": URI } }),
+        ),
+    ])?;
+
+    let published = notifications(&messages, "textDocument/publishDiagnostics");
+    assert_eq!(published.len(), 3);
+
+    let diagnostics = published[0]["params"]["diagnostics"]
+        .as_array()
+        .context("Expected diagnostics")?;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["source"], "slang");
+    // The missing semicolon is reported at the closing brace:
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 2);
+
+    assert_eq!(published[1]["params"]["diagnostics"], json!([]));
+    assert_eq!(published[2]["params"]["diagnostics"], json!([]));
+
+    Ok(())
+}
+
+#[test]
+fn version_selection() -> Result<()> {
+    // `error` definitions are only supported since 0.8.4:
+    let source = "pragma solidity >=0.8.0 <0.8.4;\nerror Failed();";
+    let errors_of = |messages: &[Value]| {
+        notifications(messages, "textDocument/publishDiagnostics")
+            .last()
+            .map(|published| published["params"]["diagnostics"].as_array().unwrap().len())
+    };
+
+    let (_, messages) = session(&[did_open(source)])?;
+    assert_eq!(errors_of(&messages), Some(1));
+
+    let (_, messages) = session(&[
+        did_open(source),
+        notification(
+            "workspace/didChangeConfiguration",
+            &json!({ "settings": { "slang": { "version": "0.8.22" } } }),
+        ),
+    ])?;
+    assert_eq!(errors_of(&messages), Some(0));
+
+    let (_, messages) = session(&[
+        request(
+            1,
+            "initialize",
+            &json!({ "initializationOptions": { "version": "0.0.1" } }),
+        ),
+        did_open(source),
+    ])?;
+    assert_eq!(notifications(&messages, "window/showMessage").len(), 1);
+    assert_eq!(errors_of(&messages), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn document_symbols() -> Result<()> {
+    let response = query(SOURCE, "textDocument/documentSymbol", json!({}))?;
+    let symbols = response["result"].as_array().context("Expected symbols")?;
+
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0]["name"], "Counter");
+    assert_eq!(symbols[0]["kind"], 5);
+    assert_eq!(
+        symbols[0]["range"],
+        json!({ "start": { "line": 5, "character": 0 }, "end": { "line": 14, "character": 1 } })
+    );
+    assert_eq!(
+        symbols[0]["selectionRange"],
+        json!({ "start": { "line": 5, "character": 9 }, "end": { "line": 5, "character": 16 } })
+    );
+
+    let children = symbols[0]["children"]
+        .as_array()
+        .context("Expected children")?
+        .iter()
+        .map(|child| {
+            (
+                child["name"].as_str().unwrap(),
+                child["kind"].as_u64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        children,
+        [
+            ("MAX", 14),
+            ("State", 10),
+            ("constructor", 9),
+            ("increment", 12)
+        ]
+    );
+
+    let members = symbols[0]["children"][1]["children"]
+        .as_array()
+        .context("Expected members")?
+        .iter()
+        .map(|member| member["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(members, ["Active", "Paused"]);
+
+    Ok(())
+}
+
+#[test]
+fn folding_ranges() -> Result<()> {
+    let response = query(SOURCE, "textDocument/foldingRange", json!({}))?;
+
+    assert_eq!(
+        response["result"],
+        json!([
+            { "startLine": 2, "endLine": 4, "kind": "comment" },
+            { "startLine": 5, "endLine": 13 },
+            { "startLine": 11, "endLine": 12 },
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn selection_ranges() -> Result<()> {
+    // Inside `amount` in `count += amount;`:
+    let response = query(
+        SOURCE,
+        "textDocument/selectionRange",
+        json!({ "positions": [{ "line": 12, "character": 20 }] }),
+    )?;
+
+    let mut texts = vec![];
+    let mut selection = &response["result"][0];
+    while !selection.is_null() {
+        texts.push(text_of(SOURCE, &selection["range"]));
+        selection = &selection["parent"];
+    }
+
+    assert_eq!(texts[0], "amount");
+    assert_eq!(texts[1], "count += amount");
+    assert_eq!(texts[2], "count += amount;");
+    assert_eq!(texts.last().unwrap(), &SOURCE.trim_end());
+
+    Ok(())
+}
+
+#[test]
+fn semantic_tokens() -> Result<()> {
+    let response = query(
+        "contract Foo {}",
+        "textDocument/semanticTokens/full",
+        json!({}),
+    )?;
+
+    // `contract` (keyword) and `Foo` (class declaration):
+    assert_eq!(
+        response["result"]["data"],
+        json!([0, 0, 8, 14, 0, 0, 9, 3, 2, 1])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn formatting() -> Result<()> {
+    let response = query(
+        "contract Foo{uint x;}",
+        "textDocument/formatting",
+        json!({ "options": { "tabSize": 2, "insertSpaces": true } }),
+    )?;
+
+    assert_eq!(
+        response["result"],
+        json!([{
+            "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 21 } },
+            "newText": "contract Foo {\n  uint x;\n}\n",
+        }])
+    );
+
+    // Documents with syntax errors are not formatted:
+    let response = query("contract Foo {", "textDocument/formatting", json!({}))?;
+    assert_eq!(response["result"], Value::Null);
+
+    Ok(())
+}
+
+fn text_of<'a>(source: &'a str, range: &Value) -> &'a str {
+    let offset = |position: &Value| {
+        let line = usize::try_from(position["line"].as_u64().unwrap()).unwrap();
+        let character = usize::try_from(position["character"].as_u64().unwrap()).unwrap();
+
+        source
+            .lines()
+            .take(line)
+            .map(|line| line.len() + 1)
+            .sum::<usize>()
+            + character
+    };
+
+    &source[offset(&range["start"])..offset(&range["end"])]
+}
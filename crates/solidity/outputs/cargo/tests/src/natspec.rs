@@ -0,0 +1,229 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::diagnostic::Diagnostic;
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::natspec::{self, NatSpecError, NatSpecOutput, NatSpecTagKind};
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::text_index::TextRangeExtensions;
+
+const SOURCE: &str = r"
+/// @title A token
+/// @author Someone
+contract Token {
+    /**
+     * @notice Transfers tokens.
+     *
+     * @dev Reverts if the balance is too low.
+     * @param to The recipient.
+     * @param amount The amount
+     *   to transfer.
+     * @return Whether it succeeded.
+     */
+    function transfer(address to, uint amount) public returns (bool) {}
+
+    /// Emitted on every transfer.
+    /// @param from The sender.
+    event Transfer(address indexed from);
+
+    /// @custom:security-contact security@example.com
+    /// @param caller The caller.
+    error Unauthorized(address caller);
+
+    /// @inheritdoc Base
+    modifier onlyOwner() { _; }
+
+    // Not NatSpec:
+    function undocumented() public {}
+}
+";
+
+fn parse(source: &str) -> Result<ParseOutput> {
+    let version = Version::parse("0.8.4")?;
+    let language = Language::new(version)?;
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid(), "{:?}", output.errors());
+
+    Ok(output)
+}
+
+fn item_summary(output: &NatSpecOutput) -> Vec<(RuleKind, Option<&str>)> {
+    output
+        .items()
+        .iter()
+        .map(|item| (item.kind, item.name.as_deref()))
+        .collect()
+}
+
+#[test]
+fn attaches_to_declarations() -> Result<()> {
+    let output = natspec::extract(&parse(SOURCE)?);
+
+    assert_eq!(
+        item_summary(&output),
+        [
+            (RuleKind::ContractDefinition, Some("Token")),
+            (RuleKind::FunctionDefinition, Some("transfer")),
+            (RuleKind::EventDefinition, Some("Transfer")),
+            (RuleKind::ErrorDefinition, Some("Unauthorized")),
+            (RuleKind::ModifierDefinition, Some("onlyOwner")),
+        ]
+    );
+    assert!(
+        output.diagnostics().is_empty(),
+        "{:?}",
+        output.diagnostics()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parses_tags() -> Result<()> {
+    let output = natspec::extract(&parse(SOURCE)?);
+    let tags = |index: usize| {
+        output.items()[index]
+            .natspec
+            .tags
+            .iter()
+            .map(|tag| (tag.kind.clone(), tag.content.as_str()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        tags(0),
+        [
+            (NatSpecTagKind::Title, "A token"),
+            (NatSpecTagKind::Author, "Someone"),
+        ]
+    );
+    assert_eq!(
+        tags(1),
+        [
+            (NatSpecTagKind::Notice, "Transfers tokens."),
+            (NatSpecTagKind::Dev, "Reverts if the balance is too low."),
+            (NatSpecTagKind::Param, "The recipient."),
+            (NatSpecTagKind::Param, "The amount\nto transfer."),
+            (NatSpecTagKind::Return, "Whether it succeeded."),
+        ]
+    );
+    assert_eq!(
+        tags(2),
+        [
+            (NatSpecTagKind::Notice, "Emitted on every transfer."),
+            (NatSpecTagKind::Param, "The sender."),
+        ]
+    );
+    assert_eq!(
+        tags(3),
+        [
+            (
+                NatSpecTagKind::Custom("security-contact".to_owned()),
+                "security@example.com"
+            ),
+            (NatSpecTagKind::Param, "The caller."),
+        ]
+    );
+    assert_eq!(tags(4), [(NatSpecTagKind::InheritDoc, "")]);
+
+    let transfer = &output.items()[1].natspec;
+    let amount = transfer.param("amount").expect("amount is documented");
+    assert_eq!(
+        amount.name.as_ref().map(|(name, _)| name.as_str()),
+        Some("amount")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn text_ranges() -> Result<()> {
+    let output = natspec::extract(&parse(SOURCE)?);
+    let text_of = |range: &std::ops::Range<_>| &SOURCE[range.utf8()];
+
+    let transfer = &output.items()[1].natspec;
+
+    let amount = transfer.param("amount").expect("amount is documented");
+    assert_eq!(
+        text_of(&amount.text_range),
+        "@param amount The amount\n     *   to transfer."
+    );
+    assert_eq!(text_of(&amount.name.as_ref().unwrap().1), "amount");
+
+    let notice = &transfer.tags[0];
+    assert_eq!(text_of(&notice.text_range), "@notice Transfers tokens.");
+
+    let inheritdoc = &output.items()[4].natspec.tags[0];
+    assert_eq!(text_of(&inheritdoc.name.as_ref().unwrap().1), "Base");
+
+    Ok(())
+}
+
+#[test]
+fn reports_unknown_tags_and_parameters() -> Result<()> {
+    let source = r"
+contract Foo {
+    /// @param amount The amount.
+    /// @param reciever Misspelled.
+    /// @returns Not a valid tag.
+    /// @custom:Invalid Not a valid custom tag.
+    function bar(uint amount, address receiver) public {}
+}
+";
+
+    let output = natspec::extract(&parse(source)?);
+
+    let actual = output
+        .diagnostics()
+        .iter()
+        .map(|error| {
+            (
+                error.code().to_string(),
+                error.message(),
+                &source[error.range().utf8()],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        actual,
+        [
+            (
+                "NatSpecUnknownTag".to_owned(),
+                "Unknown NatSpec tag '@returns'.".to_owned(),
+                "@returns"
+            ),
+            (
+                "NatSpecUnknownTag".to_owned(),
+                "Unknown NatSpec tag '@custom:Invalid'.".to_owned(),
+                "@custom:Invalid"
+            ),
+            (
+                "NatSpecUnknownParameter".to_owned(),
+                "Documented parameter 'reciever' not found in the list of parameters.".to_owned(),
+                "reciever"
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reports_missing_names() -> Result<()> {
+    let source = "contract Foo {\n    /// @param\n    event Bar(uint baz);\n}";
+
+    let output = natspec::extract(&parse(source)?);
+
+    assert_eq!(
+        output.diagnostics(),
+        &[NatSpecError::MissingName {
+            tag: "param".to_owned(),
+            text_range: output.diagnostics()[0].range(),
+        }]
+    );
+    assert_eq!(&source[output.diagnostics()[0].range().utf8()], "@param");
+
+    Ok(())
+}
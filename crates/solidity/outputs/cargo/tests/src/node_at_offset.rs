@@ -0,0 +1,121 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cursor::Cursor;
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::text_index::TextIndex;
+
+const SOURCE: &str = "contract Foo { function bar() {} }";
+
+#[test]
+fn node_at_offset() -> Result<()> {
+    let output = parse()?;
+
+    let cursor = output.node_at_offset(offset_of("bar")).unwrap();
+    assert!(cursor.node().is_token_with_kind(TokenKind::Identifier));
+    assert_eq!(cursor.text_range().start, offset_of("bar"));
+    assert_eq!(
+        cursor.ancestors().map(|rule| rule.kind).collect::<Vec<_>>(),
+        [
+            RuleKind::FunctionName,
+            RuleKind::FunctionDefinition,
+            RuleKind::ContractMember,
+            RuleKind::ContractMembers,
+            RuleKind::ContractDefinition,
+            RuleKind::SourceUnitMember,
+            RuleKind::SourceUnitMembers,
+            RuleKind::SourceUnit,
+        ]
+    );
+
+    // Between two tokens, the one starting at the offset is preferred:
+    let cursor = output.node_at_offset(offset_of("()")).unwrap();
+    assert!(cursor.node().is_token_with_kind(TokenKind::OpenParen));
+
+    // Except at the end of the tree:
+    let cursor = output.node_at_offset(TextIndex::from(SOURCE)).unwrap();
+    assert!(cursor.node().is_token_with_kind(TokenKind::CloseBrace));
+
+    // Past the end of the tree:
+    let past_end = TextIndex::from(SOURCE) + TextIndex::from(" ");
+    assert!(output.node_at_offset(past_end).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn go_to_offset_from_any_position() -> Result<()> {
+    let output = parse()?;
+
+    let mut cursor = output.node_at_offset(offset_of("bar")).unwrap();
+    assert!(cursor.go_to_offset(offset_of("Foo")));
+    assert_eq!(token_text(&cursor), "Foo");
+
+    // Matches a full traversal of the tree:
+    for offset in 0..SOURCE.len() {
+        let offset = TextIndex::from(&SOURCE[..offset]);
+        assert!(cursor.go_to_offset(offset));
+
+        let expected = output
+            .create_tree_cursor()
+            .filter_map(|node| node.into_token())
+            .scan(TextIndex::ZERO, |start, token| {
+                let end = *start + TextIndex::from(&token.text);
+                let range = *start..end;
+                *start = end;
+                Some((range, token))
+            })
+            .find(|(range, _)| range.start <= offset && offset < range.end)
+            .unwrap();
+
+        assert_eq!(cursor.text_range(), expected.0);
+        assert_eq!(token_text(&cursor), expected.1.text);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn covering_node() -> Result<()> {
+    let output = parse()?;
+
+    let start = offset_of("function");
+    let end = offset_of("{}");
+    let cursor = output.covering_node(&(start..end)).unwrap();
+    assert!(cursor
+        .node()
+        .is_rule_with_kind(RuleKind::FunctionDefinition));
+
+    // A range within a single token:
+    let start = offset_of("bar") + TextIndex::from("b");
+    let cursor = output.covering_node(&(start..start)).unwrap();
+    assert_eq!(token_text(&cursor), "bar");
+
+    // A range spanning the contract name and one of its members:
+    let start = offset_of("Foo");
+    let end = offset_of("bar");
+    let cursor = output.covering_node(&(start..end)).unwrap();
+    assert!(cursor
+        .node()
+        .is_rule_with_kind(RuleKind::ContractDefinition));
+
+    Ok(())
+}
+
+fn parse() -> Result<ParseOutput> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    assert!(output.is_valid());
+
+    Ok(output)
+}
+
+fn offset_of(text: &str) -> TextIndex {
+    TextIndex::from(&SOURCE[..SOURCE.find(text).unwrap()])
+}
+
+fn token_text(cursor: &Cursor) -> String {
+    cursor.node().into_token().unwrap().text.clone()
+}
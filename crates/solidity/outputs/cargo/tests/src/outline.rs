@@ -0,0 +1,203 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::outline::{self, FoldingRangeKind, Symbol, SymbolKind};
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::text_index::{TextIndex, TextRange};
+
+const SOURCE: &str = r#"import "./a.sol";
+import "./b.sol";
+import "./c.sol";
+
+// First line.
+// Second line.
+
+// Separate comment.
+library Math {
+    uint256 constant LIMIT = 100;
+}
+
+interface IToken {
+    error Unauthorized(address caller);
+}
+
+contract Token is IToken {
+    struct Entry {
+        address owner;
+        uint256 amount;
+    }
+
+    enum State { Active, Paused }
+
+    /**
+     * The balances.
+     */
+    mapping(address => uint256) balances;
+
+    event Transfer(address to);
+
+    modifier onlyOwner() { _; }
+
+    constructor() {}
+
+    receive() external payable {}
+
+    function transfer(address to) public onlyOwner {
+        balances[to] += 1;
+    }
+}
+"#;
+
+fn parse(source: &str) -> Result<ParseOutput> {
+    let version = Version::parse("0.8.4")?;
+    let language = Language::new(version)?;
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid(), "{:?}", output.errors());
+
+    Ok(output)
+}
+
+fn text(range: &TextRange) -> &str {
+    &SOURCE[range.start.utf8..range.end.utf8]
+}
+
+fn flatten(symbols: &[Symbol], depth: usize) -> Vec<(usize, String, SymbolKind)> {
+    symbols
+        .iter()
+        .flat_map(|symbol| {
+            let mut result = vec![(depth, symbol.name.clone(), symbol.kind)];
+            result.extend(flatten(&symbol.children, depth + 1));
+            result
+        })
+        .collect()
+}
+
+#[test]
+fn symbols() -> Result<()> {
+    let outline = outline::outline(&parse(SOURCE)?);
+
+    let actual = flatten(&outline.symbols, 0);
+    let expected = [
+        (0, "Math", SymbolKind::Library),
+        (1, "LIMIT", SymbolKind::Constant),
+        (0, "IToken", SymbolKind::Interface),
+        (1, "Unauthorized", SymbolKind::Error),
+        (0, "Token", SymbolKind::Contract),
+        (1, "Entry", SymbolKind::Struct),
+        (2, "owner", SymbolKind::StructMember),
+        (2, "amount", SymbolKind::StructMember),
+        (1, "State", SymbolKind::Enum),
+        (2, "Active", SymbolKind::EnumMember),
+        (2, "Paused", SymbolKind::EnumMember),
+        (1, "balances", SymbolKind::StateVariable),
+        (1, "Transfer", SymbolKind::Event),
+        (1, "onlyOwner", SymbolKind::Modifier),
+        (1, "constructor", SymbolKind::Constructor),
+        (1, "receive", SymbolKind::SpecialFunction),
+        (1, "transfer", SymbolKind::Function),
+    ]
+    .map(|(depth, name, kind)| (depth, name.to_owned(), kind));
+
+    assert_eq!(actual, expected);
+
+    // Ranges exclude leading trivia (e.g. the comments before the library):
+    let math = &outline.symbols[0];
+    assert!(text(&math.range).starts_with("library Math {"));
+    assert!(text(&math.range).ends_with('}'));
+    assert_eq!(text(&math.name_range), "Math");
+
+    let balances = &outline.symbols[2].children[2];
+    assert!(text(&balances.range).starts_with("mapping"));
+    assert_eq!(text(&balances.name_range), "balances");
+
+    Ok(())
+}
+
+#[test]
+fn folding_ranges() -> Result<()> {
+    let outline = outline::outline(&parse(SOURCE)?);
+
+    let actual = outline
+        .folding_ranges
+        .iter()
+        .map(|folding_range| {
+            let text = text(&folding_range.range);
+            (folding_range.kind, text.lines().next().unwrap_or_default())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        actual,
+        [
+            (FoldingRangeKind::Imports, r#"import "./a.sol";"#),
+            (FoldingRangeKind::Comment, "// First line."),
+            (FoldingRangeKind::Block, "{"),
+            (FoldingRangeKind::Block, "{"),
+            (FoldingRangeKind::Block, "{"),
+            (FoldingRangeKind::Block, "{"),
+            (FoldingRangeKind::Comment, "/**"),
+            (FoldingRangeKind::Block, "{"),
+        ]
+    );
+
+    // Comment runs are split by empty lines:
+    let comments = outline
+        .folding_ranges
+        .iter()
+        .filter(|folding_range| folding_range.kind == FoldingRangeKind::Comment)
+        .map(|folding_range| text(&folding_range.range))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        comments,
+        [
+            "// First line.\n// Second line.",
+            "/**\n     * The balances.\n     */"
+        ]
+    );
+
+    // Single-line blocks (e.g. of the modifier) are not folded, and consecutive imports are folded together:
+    let imports = &outline.folding_ranges[0];
+    assert!(text(&imports.range).ends_with(r#"import "./c.sol";"#));
+
+    Ok(())
+}
+
+#[test]
+fn unicode_line_separators() -> Result<()> {
+    // Editors following the Language Server Protocol do not break lines at Unicode separators:
+    let outline = outline::outline(&parse("contract A { /* \u{2028} */ }\r\n")?);
+    assert_eq!(outline.folding_ranges, []);
+
+    let outline = outline::outline(&parse("contract A { /* \r */ }\n")?);
+    assert_eq!(outline.folding_ranges.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn selection_ranges() -> Result<()> {
+    let output = parse(SOURCE)?;
+
+    // Inside `to` in `balances[to] += 1;`:
+    let offset = SOURCE.find("to] +=").unwrap() + 1;
+    let offset = TextIndex::from(&SOURCE[..offset]);
+
+    let ranges = outline::selection_ranges(&output, offset);
+    let texts = ranges.iter().map(text).collect::<Vec<_>>();
+
+    assert_eq!(texts[0], "to");
+    assert_eq!(texts[1], "balances[to]");
+    assert_eq!(texts[2], "balances[to] += 1");
+    assert_eq!(texts[3], "balances[to] += 1;");
+    assert_eq!(texts.last().unwrap(), &SOURCE.trim_end());
+
+    // Each range contains the previous one:
+    for pair in ranges.windows(2) {
+        assert!(pair[1].start.utf8 <= pair[0].start.utf8 && pair[0].end.utf8 <= pair[1].end.utf8);
+        assert_ne!(pair[0], pair[1]);
+    }
+
+    Ok(())
+}
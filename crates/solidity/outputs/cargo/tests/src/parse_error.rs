@@ -0,0 +1,314 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::diagnostic::{self, Diagnostic};
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::outline;
+use slang_solidity::parse_error::{Expected, ParseError, ParseErrorKind};
+
+fn parse(kind: RuleKind, source: &str) -> Result<Vec<ParseError>> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    Ok(language.parse(kind, source).errors().clone())
+}
+
+fn summary(error: &ParseError) -> (&'static str, Option<RuleKind>, String) {
+    (error.kind().code(), error.rule_kind(), error.to_string())
+}
+
+#[test]
+fn missing_tokens() -> Result<()> {
+    let errors = parse(RuleKind::SourceUnit, "contract A { uint x }")?;
+
+    assert_eq!(
+        errors.iter().map(summary).collect::<Vec<_>>(),
+        [(
+            "P0002",
+            Some(RuleKind::StateVariableDefinition),
+            "Expected Equal or Semicolon while parsing StateVariableDefinition.".to_owned()
+        )]
+    );
+
+    assert_eq!(errors[0].kind(), ParseErrorKind::MissingTokens);
+    assert!(errors[0].text_range().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn unexpected_end_of_input() -> Result<()> {
+    // The function is complete, so its enclosing contract is the one that was being parsed:
+    let source = "contract A {\n  function f() public {\n    uint x = ;\n  }\n";
+    let errors = parse(RuleKind::SourceUnit, source)?;
+
+    let summaries = errors.iter().map(summary).collect::<Vec<_>>();
+    assert_eq!(summaries.len(), 2);
+
+    assert_eq!(
+        summaries[0],
+        (
+            "P0002",
+            Some(RuleKind::VariableDeclarationStatement),
+            "Expected an expression while parsing VariableDeclarationStatement.".to_owned()
+        )
+    );
+
+    assert_eq!(summaries[1].0, "P0003");
+    assert_eq!(summaries[1].1, Some(RuleKind::ContractDefinition));
+    assert_eq!(errors[1].kind(), ParseErrorKind::UnexpectedEndOfInput);
+
+    Ok(())
+}
+
+#[test]
+fn unexpected_tokens() -> Result<()> {
+    let errors = parse(RuleKind::Expression, "1 2")?;
+
+    assert_eq!(
+        errors.iter().map(summary).collect::<Vec<_>>(),
+        [(
+            "P0001",
+            Some(RuleKind::Expression),
+            "Expected an operator while parsing Expression.".to_owned()
+        )]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn expected_end_of_input() -> Result<()> {
+    let errors = parse(RuleKind::Statement, "a = 1; b")?;
+
+    assert_eq!(
+        errors.iter().map(summary).collect::<Vec<_>>(),
+        [("P0004", None, "Expected end of file.".to_owned())]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn expected_categories() -> Result<()> {
+    let errors = parse(
+        RuleKind::SourceUnit,
+        "contract A { function f() { x = (1 + ); } }",
+    )?;
+    assert_eq!(errors.len(), 1);
+
+    let error = &errors[0];
+    assert_eq!(error.expected(), [Expected::Category("an expression")]);
+
+    // The underlying tokens are still available:
+    let tokens = error.expected_tokens();
+    assert!(tokens.contains(&TokenKind::Identifier));
+    assert!(tokens.contains(&TokenKind::DecimalLiteral));
+    assert!(tokens.windows(2).all(|pair| pair[0] < pair[1]));
+
+    Ok(())
+}
+
+#[test]
+fn missing_token_insertion() -> Result<()> {
+    let source = "contract A {\n  uint x = 1\n  uint y = 2\n  function f() public {}\n}\n";
+
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, source);
+
+    // Each missing semicolon is reported separately, right after its statement:
+    let errors = output.errors();
+    assert_eq!(
+        errors.iter().map(summary).collect::<Vec<_>>(),
+        [
+            (
+                "P0002",
+                Some(RuleKind::StateVariableDefinition),
+                "Expected an operator or Semicolon while parsing StateVariableDefinition."
+                    .to_owned()
+            ),
+            (
+                "P0002",
+                Some(RuleKind::StateVariableDefinition),
+                "Expected an operator or Semicolon while parsing StateVariableDefinition."
+                    .to_owned()
+            ),
+        ]
+    );
+
+    // The tree has zero-width nodes in their place, and nothing was skipped:
+    let missing = output
+        .create_tree_cursor()
+        .filter_map(|node| node.as_token_with_kind(TokenKind::MISSING).cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(missing.len(), 2);
+    assert!(missing.iter().all(|token| token.text.is_empty()));
+
+    assert!(output
+        .create_tree_cursor()
+        .all(|node| !node.is_token_with_kind(TokenKind::SKIPPED)));
+    assert_eq!(output.tree().unparse(), source);
+
+    // Following members are still parsed:
+    let outline = outline::outline(&output);
+    let members = outline.symbols[0]
+        .children
+        .iter()
+        .map(|symbol| symbol.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(members, ["x", "y", "f"]);
+
+    Ok(())
+}
+
+#[test]
+fn missing_token_before_closing_delimiter() -> Result<()> {
+    let errors = parse(
+        RuleKind::SourceUnit,
+        "contract A { function f() { x = 1 } }",
+    )?;
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind(), ParseErrorKind::MissingTokens);
+    assert_eq!(errors[0].rule_kind(), Some(RuleKind::ExpressionStatement));
+
+    Ok(())
+}
+
+fn notes(version: &str, source: &str) -> Result<Vec<Option<String>>> {
+    let language = Language::new(Version::parse(version)?)?;
+    let output = language.parse(RuleKind::SourceUnit, source);
+
+    Ok(output
+        .errors()
+        .iter()
+        .map(|error| error.note().map(ToOwned::to_owned))
+        .collect())
+}
+
+#[test]
+fn notes_for_constructs_of_other_versions() -> Result<()> {
+    assert_eq!(
+        notes("0.8.3", "contract A {\n  error Failed();\n}\n")?,
+        [Some(
+            "ErrorDefinition (starting with 'error') requires Solidity >=0.8.4.".to_owned()
+        )]
+    );
+
+    assert_eq!(
+        notes(
+            "0.4.20",
+            "contract A {\n  function f() public {\n    emit E();\n  }\n}\n"
+        )?,
+        [Some(
+            "EmitStatement (starting with 'emit') requires Solidity >=0.4.21.".to_owned()
+        )]
+    );
+
+    // Skipped input is checked as well:
+    assert_eq!(
+        notes(
+            "0.7.6",
+            "contract A {\n  function f() public {\n    unchecked { x += 1; }\n  }\n}\n"
+        )?,
+        [Some(
+            "UncheckedBlock (starting with 'unchecked') requires Solidity >=0.8.0.".to_owned()
+        )]
+    );
+
+    assert_eq!(
+        notes("0.8.0", "contract A {\n  uint x = 1 finney;\n}\n")?,
+        [Some(
+            "'finney' is only a keyword in Solidity <0.7.0.".to_owned()
+        )]
+    );
+
+    // The same source in a version that supports it:
+    assert_eq!(notes("0.8.4", "contract A {\n  error Failed();\n}\n")?, []);
+
+    Ok(())
+}
+
+#[test]
+fn notes_for_reserved_words() -> Result<()> {
+    assert_eq!(
+        notes("0.5.0", "contract A {\n  uint calldata;\n}\n")?,
+        [Some(
+            "'calldata' is reserved since Solidity 0.5.0.".to_owned()
+        )]
+    );
+
+    assert_eq!(
+        notes(
+            "0.5.0",
+            "contract A {\n  function f() public {\n    uint calldata = 1;\n  }\n}\n"
+        )?,
+        [Some(
+            "'calldata' is reserved since Solidity 0.5.0.".to_owned()
+        )]
+    );
+
+    // Errors that are not caused by other versions have no notes:
+    assert_eq!(notes("0.5.0", "contract A {\n  uint x = ;\n}\n")?, [None]);
+
+    Ok(())
+}
+
+#[test]
+fn notes_in_diagnostics() -> Result<()> {
+    let source = "contract A {\n  error Failed();\n}\n";
+    let language = Language::new(Version::parse("0.8.3")?)?;
+    let output = language.parse(RuleKind::SourceUnit, source);
+
+    let error = &output.errors()[0];
+    assert_eq!(
+        Diagnostic::note(error).as_deref(),
+        Some("ErrorDefinition (starting with 'error') requires Solidity >=0.8.4.")
+    );
+
+    let rendered = diagnostic::render(error, "test.sol", source, false);
+    assert!(rendered
+        .contains("│ Note: ErrorDefinition (starting with 'error') requires Solidity >=0.8.4.\n"));
+
+    Ok(())
+}
+
+#[test]
+fn categories_match_the_grammar() -> Result<()> {
+    let cases = [
+        (
+            "contract C { function f() public { if (true) } }",
+            "a statement",
+        ),
+        (
+            "contract C { function f() public { x = ; } }",
+            "an expression",
+        ),
+        ("contract C { mapping(uint => ) x; }", "a type name"),
+        (
+            "contract C { function f() public { assembly { let x := } } }",
+            "a Yul expression",
+        ),
+    ];
+
+    // Any keyword or Yul built-in that a category misses in some version is left over as a separate token:
+    for version in Language::SUPPORTED_VERSIONS {
+        let language = Language::new(version.clone())?;
+
+        for (source, category) in cases {
+            let output = language.parse(RuleKind::SourceUnit, source);
+            let expected = output
+                .errors()
+                .iter()
+                .map(ParseError::expected)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                expected,
+                [[Expected::Category(category)]],
+                "{version}: {source}"
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::parse_error::ParseErrorKind;
+use slang_solidity::parse_options::ParseOptions;
+use slang_solidity::parse_output::ParseOutput;
+
+const SOURCE: &str = "contract A {\n  function f() public {\n    x = 1;\n  }\n}\n";
+
+fn parse(kind: RuleKind, source: &str, options: &ParseOptions) -> Result<ParseOutput> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    Ok(language.parse_with_options(kind, source, options))
+}
+
+fn assert_stopped(output: &ParseOutput, source: &str, kind: ParseErrorKind) {
+    let errors = output.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind(), kind);
+    assert!(kind.is_limit_exceeded());
+
+    let tree = output.tree();
+    assert!(tree.is_token_with_kind(TokenKind::SKIPPED));
+    assert_eq!(tree.unparse(), source);
+}
+
+#[test]
+fn within_limits() -> Result<()> {
+    let options = ParseOptions {
+        max_depth: Some(1_000),
+        max_input_size: Some(SOURCE.len()),
+        token_budget: Some(10_000),
+        cancellation_flag: Some(Arc::new(AtomicBool::new(false))),
+    };
+
+    let output = parse(RuleKind::SourceUnit, SOURCE, &options)?;
+    let expected = parse(RuleKind::SourceUnit, SOURCE, &ParseOptions::default())?;
+
+    assert!(output.is_valid());
+    assert_eq!(output, expected);
+
+    Ok(())
+}
+
+#[test]
+fn nesting_too_deep() -> Result<()> {
+    let source = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+    let options = ParseOptions {
+        max_depth: Some(100),
+        ..ParseOptions::default()
+    };
+
+    let output = parse(RuleKind::Expression, &source, &options)?;
+    assert_stopped(&output, &source, ParseErrorKind::NestingTooDeep);
+
+    let error = &output.errors()[0];
+    assert_eq!(error.kind().code(), "P0005");
+    assert_eq!(
+        error.to_string(),
+        "Parsing stopped: the input is nested too deeply."
+    );
+
+    // The error starts where the limit was hit:
+    let start = error.text_range().start.utf8;
+    assert!(0 < start && start < 100_000);
+
+    Ok(())
+}
+
+#[test]
+fn input_too_large() -> Result<()> {
+    let options = ParseOptions {
+        max_input_size: Some(SOURCE.len() - 1),
+        ..ParseOptions::default()
+    };
+
+    let output = parse(RuleKind::SourceUnit, SOURCE, &options)?;
+    assert_stopped(&output, SOURCE, ParseErrorKind::InputTooLarge);
+    assert_eq!(output.errors()[0].text_range().start.utf8, 0);
+
+    Ok(())
+}
+
+#[test]
+fn token_budget_exceeded() -> Result<()> {
+    let options = ParseOptions {
+        token_budget: Some(5),
+        ..ParseOptions::default()
+    };
+
+    let output = parse(RuleKind::SourceUnit, SOURCE, &options)?;
+    assert_stopped(&output, SOURCE, ParseErrorKind::TokenBudgetExceeded);
+
+    Ok(())
+}
+
+#[test]
+fn cancelled() -> Result<()> {
+    let options = ParseOptions {
+        cancellation_flag: Some(Arc::new(AtomicBool::new(true))),
+        ..ParseOptions::default()
+    };
+
+    let output = parse(RuleKind::SourceUnit, SOURCE, &options)?;
+    assert_stopped(&output, SOURCE, ParseErrorKind::Cancelled);
+    assert_eq!(output.errors()[0].to_string(), "Parsing was cancelled.");
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cst::{Node, NodeRc, RuleNode};
+use slang_solidity::cursor::Cursor;
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::rewriter::{RewriteError, RewriteOutput, Rewriter};
+use slang_solidity::text_index::TextIndex;
+
+const SOURCE: &str = r"
+contract Foo {
+    function bar(uint a, uint b, uint c) public {}
+}
+
+contract Unchanged {
+    uint x;
+}
+";
+
+#[test]
+fn replace_token_text() -> Result<()> {
+    let (language, output) = parse()?;
+    let mut rewriter = Rewriter::new(output.tree());
+
+    let name = find_token(&output, TokenKind::Identifier, "Foo");
+    rewriter.replace_token_text(&name, "Baz")?;
+
+    let result = rewriter.finish();
+    assert_rewritten(&language, &result, &SOURCE.replace("Foo", "Baz"));
+
+    // Untouched subtrees are shared with the original tree:
+    let contracts = |tree: Node| {
+        tree.cursor_with_offset(TextIndex::ZERO)
+            .filter_map(|node| node.into_rule())
+            .filter(|rule| rule.kind == RuleKind::ContractDefinition)
+            .collect::<Vec<NodeRc<RuleNode>>>()
+    };
+    let (old, new) = (contracts(output.tree()), contracts(result.tree()));
+    assert!(!NodeRc::ptr_eq(&old[0], &new[0]));
+    assert!(NodeRc::ptr_eq(&old[1], &new[1]));
+
+    Ok(())
+}
+
+#[test]
+fn separated_list_items() -> Result<()> {
+    let (language, output) = parse()?;
+    let parameters = find_rule(&output, RuleKind::Parameters);
+    let comma = || Node::token(TokenKind::Comma, ",".to_string());
+
+    // Removing the last item also removes the separator before it:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 2)?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace("(uint a, uint b, uint c)", "(uint a, uint b)"),
+    );
+
+    // Otherwise, the separator after it:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 0)?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace("(uint a, uint b, uint c)", "( uint b, uint c)"),
+    );
+
+    // Inserting both at the start and the end of the list:
+    let mut rewriter = Rewriter::new(output.tree());
+    let first = language.parse(RuleKind::Parameter, "bool x").tree();
+    let last = language.parse(RuleKind::Parameter, " bool y").tree();
+    rewriter.insert_list_item(&parameters, 0, first, Some(comma()))?;
+    rewriter.insert_list_item(&parameters, 3, last, Some(comma()))?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace(
+            "(uint a, uint b, uint c)",
+            "(bool x,uint a, uint b, uint c, bool y)",
+        ),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn removing_several_list_items() -> Result<()> {
+    let (language, output) = parse()?;
+    let parameters = find_rule(&output, RuleKind::Parameters);
+
+    // Both items would otherwise remove the separator between them:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 1)?;
+    rewriter.remove_list_item(&parameters, 2)?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace("(uint a, uint b, uint c)", "(uint a)"),
+    );
+
+    // Items that are not adjacent keep the separator between the remaining ones:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 2)?;
+    rewriter.remove_list_item(&parameters, 0)?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace("(uint a, uint b, uint c)", "( uint b)"),
+    );
+
+    // Emptying the list, in any order:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 1)?;
+    rewriter.remove_list_item(&parameters, 2)?;
+    rewriter.remove_list_item(&parameters, 0)?;
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace("(uint a, uint b, uint c)", "()"),
+    );
+
+    // Removing the same item twice still fails:
+    let mut rewriter = Rewriter::new(output.tree());
+    rewriter.remove_list_item(&parameters, 1)?;
+    assert_eq!(
+        rewriter.remove_list_item(&parameters, 1),
+        Err(RewriteError::OverlappingEdit)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn repeated_list_items() -> Result<()> {
+    let (language, output) = parse()?;
+    let members = find_rule(&output, RuleKind::ContractMembers);
+
+    let mut rewriter = Rewriter::new(output.tree());
+    let member = language
+        .parse(RuleKind::ContractMember, "    uint y;\n")
+        .tree();
+    rewriter.insert_list_item(&members, 1, member, None)?;
+    rewriter.remove_list_item(&members, 0)?;
+
+    assert_rewritten(
+        &language,
+        &rewriter.finish(),
+        &SOURCE.replace(
+            "    function bar(uint a, uint b, uint c) public {}\n",
+            "    uint y;\n",
+        ),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_edits() -> Result<()> {
+    let (language, output) = parse()?;
+    let mut rewriter = Rewriter::new(output.tree());
+
+    let parameters = find_rule(&output, RuleKind::Parameters);
+    rewriter.remove_list_item(&parameters, 0)?;
+
+    // Overlapping with the removed item:
+    let name = find_token(&output, TokenKind::Identifier, "a");
+    assert_eq!(
+        rewriter.replace_token_text(&name, "c"),
+        Err(RewriteError::OverlappingEdit)
+    );
+
+    // Not a token:
+    assert_eq!(
+        rewriter.replace_token_text(&parameters, "c"),
+        Err(RewriteError::ExpectedToken)
+    );
+
+    // Not a list:
+    let function = find_rule(&output, RuleKind::FunctionDefinition);
+    assert_eq!(
+        rewriter.remove_list_item(&function, 0),
+        Err(RewriteError::ExpectedList)
+    );
+    assert_eq!(
+        rewriter.remove_list_item(&name, 0),
+        Err(RewriteError::ExpectedList)
+    );
+
+    // Out of bounds:
+    assert_eq!(
+        rewriter.remove_list_item(&parameters, 3),
+        Err(RewriteError::ItemOutOfBounds { index: 3, count: 3 })
+    );
+
+    // From another tree:
+    let other = language.parse(RuleKind::SourceUnit, SOURCE);
+    let name = find_token(&other, TokenKind::Identifier, "Foo");
+    assert_eq!(
+        rewriter.replace_token_text(&name, "Baz"),
+        Err(RewriteError::ForeignCursor)
+    );
+
+    Ok(())
+}
+
+fn parse() -> Result<(Language, ParseOutput)> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    assert!(output.is_valid());
+
+    Ok((language, output))
+}
+
+fn find_rule(output: &ParseOutput, kind: RuleKind) -> Cursor {
+    let mut cursor = output.create_tree_cursor();
+    assert!(cursor.go_to_next_rule_with_kind(kind));
+    cursor
+}
+
+fn find_token(output: &ParseOutput, kind: TokenKind, text: &str) -> Cursor {
+    let mut cursor = output.create_tree_cursor();
+    while cursor.go_to_next_token_with_kind(kind) {
+        if cursor.node().into_token().unwrap().text == text {
+            return cursor;
+        }
+    }
+
+    panic!("Token '{text}' not found.");
+}
+
+fn assert_rewritten(language: &Language, result: &RewriteOutput, expected: &str) {
+    assert_eq!(result.tree().unparse(), expected);
+
+    // Applying the text edits to the original source results in the same text:
+    let mut source = SOURCE.to_string();
+    for edit in result.text_edits().iter().rev() {
+        source.replace_range(edit.range.start.utf8..edit.range.end.utf8, &edit.new_text);
+    }
+    assert_eq!(source, expected);
+
+    // Which is still valid, and matches a full parse:
+    let reparsed = language.parse(RuleKind::SourceUnit, expected);
+    assert!(reparsed.is_valid());
+    assert_eq!(reparsed.tree(), result.tree());
+}
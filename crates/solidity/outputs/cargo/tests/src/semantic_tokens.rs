@@ -0,0 +1,226 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::semantic_tokens::{
+    self, SemanticToken, SemanticTokenModifier, SemanticTokenType,
+};
+use slang_solidity::text_index::TextUnit;
+
+const SOURCE: &str = r"/// @title Counter
+contract Counter is Base {
+    uint256 public constant MAX = 10;
+    event Incremented(uint256 value);
+
+    function increment(uint256 amount) public onlyOwner {
+        count += amount;
+        emit Incremented(count);
+        require(msg.sender != address(0));
+        this.reset();
+    }
+}
+";
+
+fn parse(source: &str) -> Result<ParseOutput> {
+    let version = Version::parse("0.8.4")?;
+    let language = Language::new(version)?;
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid(), "{:?}", output.errors());
+
+    Ok(output)
+}
+
+fn find<'a>(tokens: &'a [SemanticToken], source: &str, text: &str) -> Vec<&'a SemanticToken> {
+    tokens
+        .iter()
+        .filter(|token| &source[token.range.start.utf8..token.range.end.utf8] == text)
+        .collect()
+}
+
+fn classification(tokens: &[SemanticToken], source: &str, text: &str) -> SemanticTokenType {
+    let found = find(tokens, source, text);
+    assert_eq!(found.len(), 1, "Expected a single '{text}' token");
+
+    found[0].token_type
+}
+
+#[test]
+fn declarations_and_references() -> Result<()> {
+    let tokens = semantic_tokens::classify(&parse(SOURCE)?);
+
+    for (text, expected) in [
+        ("Counter", SemanticTokenType::Class),
+        ("Base", SemanticTokenType::Class),
+        ("MAX", SemanticTokenType::Property),
+        ("value", SemanticTokenType::Parameter),
+        ("increment", SemanticTokenType::Function),
+        ("amount", SemanticTokenType::Parameter),
+        ("onlyOwner", SemanticTokenType::Decorator),
+        ("require", SemanticTokenType::Function),
+        ("msg", SemanticTokenType::Variable),
+        ("sender", SemanticTokenType::Property),
+        ("reset", SemanticTokenType::Method),
+        ("uint256", SemanticTokenType::Type),
+        ("contract", SemanticTokenType::Keyword),
+        ("10", SemanticTokenType::Number),
+        ("+=", SemanticTokenType::Operator),
+        ("/// @title Counter", SemanticTokenType::Comment),
+    ] {
+        let actual = find(&tokens, SOURCE, text)
+            .first()
+            .map(|token| token.token_type);
+        assert_eq!(actual, Some(expected), "{text}");
+    }
+
+    // Events are both declared and referenced:
+    let events = find(&tokens, SOURCE, "Incremented");
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|token| token.token_type == SemanticTokenType::Event));
+    assert!(events[0].has_modifier(SemanticTokenModifier::Declaration));
+    assert!(!events[1].has_modifier(SemanticTokenModifier::Declaration));
+
+    let max = find(&tokens, SOURCE, "MAX")[0];
+    assert!(max.has_modifier(SemanticTokenModifier::Readonly));
+
+    let msg = find(&tokens, SOURCE, "msg")[0];
+    assert!(msg.has_modifier(SemanticTokenModifier::DefaultLibrary));
+
+    let doc = find(&tokens, SOURCE, "/// @title Counter")[0];
+    assert!(doc.has_modifier(SemanticTokenModifier::Documentation));
+
+    // Punctuation is not classified:
+    assert!(find(&tokens, SOURCE, "{").is_empty());
+    assert!(find(&tokens, SOURCE, ";").is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> Result<()> {
+    let source = r"contract Foo {
+    function bar() public {
+        assembly {
+            function double(a) -> b { b := add(a, a) }
+            let x := double(1)
+        }
+    }
+}";
+
+    let tokens = semantic_tokens::classify(&parse(source)?);
+
+    let doubles = find(&tokens, source, "double");
+    assert_eq!(doubles.len(), 2);
+    assert!(doubles
+        .iter()
+        .all(|token| token.token_type == SemanticTokenType::Function));
+    assert!(doubles[0].has_modifier(SemanticTokenModifier::Declaration));
+
+    assert_eq!(
+        classification(&tokens, source, "add"),
+        SemanticTokenType::Function
+    );
+    assert_eq!(
+        classification(&tokens, source, "x"),
+        SemanticTokenType::Variable
+    );
+    assert_eq!(
+        find(&tokens, source, "a")[0].token_type,
+        SemanticTokenType::Parameter
+    );
+
+    Ok(())
+}
+
+#[test]
+fn literals() -> Result<()> {
+    let source = r#"pragma solidity "0.8.4" ^0.8.1;
+import "./Foo.sol";
+contract Foo {
+    bytes constant A = hex"00ff";
+    string constant B = unicode"😀";
+    string constant C = 'x' "y";
+    function bar() public {
+        assembly { let c := "abc" }
+    }
+}"#;
+
+    let tokens = semantic_tokens::classify(&parse(source)?);
+
+    for (text, expected) in [
+        ("solidity", SemanticTokenType::Keyword),
+        (r#""0.8.4""#, SemanticTokenType::String),
+        ("0", SemanticTokenType::Number),
+        (r#""./Foo.sol""#, SemanticTokenType::String),
+        (r#"hex"00ff""#, SemanticTokenType::String),
+        (r#"unicode"😀""#, SemanticTokenType::String),
+        ("'x'", SemanticTokenType::String),
+        (r#""y""#, SemanticTokenType::String),
+        (r#""abc""#, SemanticTokenType::String),
+    ] {
+        assert_eq!(classification(&tokens, source, text), expected, "{text}");
+    }
+
+    // Whitespace is not classified, even inside literal rules:
+    assert!(tokens.iter().all(
+        |token| !source[token.range.start.utf8..token.range.end.utf8]
+            .trim()
+            .is_empty()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn multi_line_tokens() -> Result<()> {
+    let source = "/* first\n   second */\ncontract Foo {}";
+    let tokens = semantic_tokens::classify(&parse(source)?);
+
+    let comments = tokens
+        .iter()
+        .filter(|token| token.token_type == SemanticTokenType::Comment)
+        .map(|token| &source[token.range.start.utf8..token.range.end.utf8])
+        .collect::<Vec<_>>();
+
+    assert_eq!(comments, ["/* first", "   second */"]);
+
+    Ok(())
+}
+
+#[test]
+fn delta_encoding() -> Result<()> {
+    let source = "contract Foo {\n  uint x;\n}";
+    let output = parse(source)?;
+
+    let tokens = semantic_tokens::classify(&output);
+    let data = semantic_tokens::encode(&tokens, &output.create_line_index(), TextUnit::Utf16);
+
+    let class = SemanticTokenType::Class as u32;
+    let keyword = SemanticTokenType::Keyword as u32;
+    let property = SemanticTokenType::Property as u32;
+    let r#type = SemanticTokenType::Type as u32;
+    let declaration = SemanticTokenModifier::Declaration.bit();
+    let default_library = SemanticTokenModifier::DefaultLibrary.bit();
+
+    assert_eq!(
+        data,
+        [
+            [0, 0, 8, keyword, 0],              // contract
+            [0, 9, 3, class, declaration],      // Foo
+            [1, 2, 4, r#type, default_library], // uint
+            [0, 5, 1, property, declaration],   // x
+        ]
+        .concat()
+    );
+
+    assert_eq!(SemanticTokenType::legend()[class as usize], "class");
+    assert_eq!(
+        SemanticTokenModifier::legend(),
+        ["declaration", "readonly", "documentation", "defaultLibrary"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,430 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use infra_utils::cargo::CargoWorkspace;
+use infra_utils::paths::PathExtensions;
+use semver::Version;
+use serde_json::Value;
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::parse_output::ParseOutput;
+use slang_solidity::solc_ast::{self, ExportError, SolcNode, SolcValue};
+
+const SOURCE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./Base.sol" as Base;
+
+/// @title A counter
+contract Counter is Base.Owned(1) {
+    uint256 public count = 0x10;
+
+    event Incremented(address indexed by, uint256 value);
+
+    /// @notice Increments the counter.
+    function increment(uint256 amount) external virtual returns (bool) {
+        count += amount;
+        emit Incremented(msg.sender, count);
+        assembly {
+            let x := add(1, 2)
+        }
+        return true;
+    }
+}
+"#;
+
+fn parse(source: &str) -> Result<ParseOutput> {
+    let version = Version::parse("0.8.4")?;
+    let language = Language::new(version)?;
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid(), "{:?}", output.errors());
+
+    Ok(output)
+}
+
+fn string<'a>(node: &'a SolcNode, name: &str) -> &'a str {
+    match node.attribute(name) {
+        Some(SolcValue::String(value)) => value,
+        other => panic!("Expected '{name}' to be a string, found: {other:?}"),
+    }
+}
+
+fn source_text<'a>(source: &'a str, node: &SolcNode) -> &'a str {
+    &source[node.src.start..node.src.start + node.src.length]
+}
+
+/// Collects `(parent_id, id)` pairs of all nodes with ids, where the parent is the closest ancestor with an id.
+fn collect_ids(node: &SolcNode, parent: Option<usize>, ids: &mut Vec<(Option<usize>, usize)>) {
+    let parent = match node.id {
+        Some(id) => {
+            ids.push((parent, id));
+            Some(id)
+        }
+        None => parent,
+    };
+
+    for value in node.attributes.values() {
+        collect_value_ids(value, parent, ids);
+    }
+}
+
+fn collect_value_ids(
+    value: &SolcValue,
+    parent: Option<usize>,
+    ids: &mut Vec<(Option<usize>, usize)>,
+) {
+    match value {
+        SolcValue::Node(node) => collect_ids(node, parent, ids),
+        SolcValue::List(items) => items
+            .iter()
+            .for_each(|item| collect_value_ids(item, parent, ids)),
+        SolcValue::Map(entries) => entries
+            .values()
+            .for_each(|entry| collect_value_ids(entry, parent, ids)),
+        _ => {}
+    }
+}
+
+#[test]
+fn source_unit() -> Result<()> {
+    let unit = solc_ast::export(&parse(SOURCE)?, "Counter.sol", 3)?;
+
+    assert_eq!(unit.node_type, "SourceUnit");
+    assert_eq!(unit.src.to_string(), format!("0:{}:3", SOURCE.len()));
+    assert_eq!(string(&unit, "absolutePath"), "Counter.sol");
+    assert_eq!(string(&unit, "license"), "MIT");
+
+    let nodes = unit.children("nodes");
+    assert_eq!(
+        nodes.iter().map(|node| node.node_type).collect::<Vec<_>>(),
+        ["PragmaDirective", "ImportDirective", "ContractDefinition"]
+    );
+
+    assert_eq!(
+        nodes[0].attribute("literals"),
+        Some(&SolcValue::List(
+            ["solidity", "^", "0.8", ".0"]
+                .into_iter()
+                .map(SolcValue::from)
+                .collect()
+        ))
+    );
+    assert_eq!(string(nodes[1], "file"), "./Base.sol");
+    assert_eq!(string(nodes[1], "unitAlias"), "Base");
+
+    let contract = nodes[2];
+    let mut exported = std::collections::BTreeMap::new();
+    exported.insert(
+        "Counter".to_owned(),
+        SolcValue::List(vec![contract.id.unwrap().into()]),
+    );
+    assert_eq!(
+        unit.attribute("exportedSymbols"),
+        Some(&SolcValue::Map(exported))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn declarations() -> Result<()> {
+    let unit = solc_ast::export(&parse(SOURCE)?, "Counter.sol", 0)?;
+    let contract = unit.children("nodes")[2];
+
+    assert_eq!(string(contract, "name"), "Counter");
+    assert_eq!(string(contract, "contractKind"), "contract");
+    assert_eq!(
+        string(contract.child("documentation").unwrap(), "text"),
+        " @title A counter"
+    );
+
+    let base = contract.children("baseContracts")[0];
+    assert_eq!(
+        string(base.child("baseName").unwrap(), "name"),
+        "Base.Owned"
+    );
+    assert_eq!(source_text(SOURCE, base), "Base.Owned(1)");
+
+    let members = contract.children("nodes");
+    assert_eq!(
+        members
+            .iter()
+            .map(|node| node.node_type)
+            .collect::<Vec<_>>(),
+        [
+            "VariableDeclaration",
+            "EventDefinition",
+            "FunctionDefinition"
+        ]
+    );
+
+    let count = members[0];
+    assert_eq!(source_text(SOURCE, count), "uint256 public count = 0x10");
+    assert_eq!(string(count, "visibility"), "public");
+    assert_eq!(
+        count.attribute("stateVariable"),
+        Some(&SolcValue::Bool(true))
+    );
+    assert_eq!(string(count.child("typeName").unwrap(), "name"), "uint256");
+
+    let event = members[1];
+    let parameters = event.child("parameters").unwrap().children("parameters");
+    assert_eq!(
+        parameters[0].attribute("indexed"),
+        Some(&SolcValue::Bool(true))
+    );
+    assert_eq!(
+        parameters[1].attribute("indexed"),
+        Some(&SolcValue::Bool(false))
+    );
+
+    let function = members[2];
+    assert_eq!(string(function, "name"), "increment");
+    assert_eq!(string(function, "kind"), "function");
+    assert_eq!(string(function, "visibility"), "external");
+    assert_eq!(string(function, "stateMutability"), "nonpayable");
+    assert_eq!(function.attribute("virtual"), Some(&SolcValue::Bool(true)));
+    assert_eq!(
+        string(function.child("documentation").unwrap(), "text"),
+        " @notice Increments the counter."
+    );
+    assert_eq!(
+        source_text(SOURCE, function.child("returnParameters").unwrap()),
+        "(bool)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn statements_and_expressions() -> Result<()> {
+    let unit = solc_ast::export(&parse(SOURCE)?, "Counter.sol", 0)?;
+    let function = unit.children("nodes")[2].children("nodes")[2];
+    let statements = function.child("body").unwrap().children("statements");
+
+    assert_eq!(
+        statements
+            .iter()
+            .map(|node| (node.node_type, source_text(SOURCE, node)))
+            .collect::<Vec<_>>(),
+        [
+            ("ExpressionStatement", "count += amount"),
+            ("EmitStatement", "emit Incremented(msg.sender, count)"),
+            (
+                "InlineAssembly",
+                "assembly {\n            let x := add(1, 2)\n        }"
+            ),
+            ("Return", "return true"),
+        ]
+    );
+
+    let assignment = statements[0].child("expression").unwrap();
+    assert_eq!(assignment.node_type, "Assignment");
+    assert_eq!(string(assignment, "operator"), "+=");
+
+    let call = statements[1].child("eventCall").unwrap();
+    let sender = call.children("arguments")[0];
+    assert_eq!(sender.node_type, "MemberAccess");
+    assert_eq!(string(sender, "memberName"), "sender");
+    assert_eq!(string(sender.child("expression").unwrap(), "name"), "msg");
+
+    let yul = statements[2].child("AST").unwrap().children("statements")[0];
+    assert_eq!(yul.node_type, "YulVariableDeclaration");
+    assert_eq!(yul.id, None);
+    assert_eq!(yul.attribute("nativeSrc"), Some(&yul.src.into()));
+    assert_eq!(
+        string(
+            yul.child("value").unwrap().child("functionName").unwrap(),
+            "name"
+        ),
+        "add"
+    );
+
+    let literal = statements[3].child("expression").unwrap();
+    assert_eq!(string(literal, "kind"), "bool");
+    assert_eq!(string(literal, "value"), "true");
+
+    Ok(())
+}
+
+#[test]
+fn literals() -> Result<()> {
+    let source = r#"contract Foo {
+    function bar() public {
+        bar("a\x62c", hex"00ff", unicode"é", 1 ether);
+    }
+}"#;
+
+    let unit = solc_ast::export(&parse(source)?, "Foo.sol", 0)?;
+    let function = unit.children("nodes")[0].children("nodes")[0];
+    let statement = function.child("body").unwrap().children("statements")[0];
+    let arguments = statement.child("expression").unwrap().children("arguments");
+
+    let summary = arguments
+        .iter()
+        .map(|literal| {
+            (
+                string(literal, "kind"),
+                literal.attribute("value").cloned(),
+                string(literal, "hexValue"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        summary,
+        [
+            ("string", Some("abc".into()), "616263"),
+            ("hexString", Some(SolcValue::Null), "00ff"),
+            ("unicodeString", Some("é".into()), "c3a9"),
+            ("number", Some("1".into()), "31"),
+        ]
+    );
+    assert_eq!(string(arguments[3], "subdenomination"), "ether");
+
+    Ok(())
+}
+
+#[test]
+fn unique_ids() -> Result<()> {
+    let unit = solc_ast::export(&parse(SOURCE)?, "Counter.sol", 0)?;
+
+    let mut ids = vec![];
+    collect_ids(&unit, None, &mut ids);
+
+    let unique: HashSet<_> = ids.iter().map(|(_, id)| id).collect();
+    assert_eq!(unique.len(), ids.len());
+
+    // Ids are assigned in pre-order, so parents always have lower ids than their children:
+    for (parent, id) in ids {
+        assert!(
+            parent.map_or(true, |parent| parent < id),
+            "{parent:?} -> {id}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn serializes_to_json() -> Result<()> {
+    let source = "contract Foo {}";
+    let unit = solc_ast::export(&parse(source)?, "Foo.sol", 0)?;
+
+    let json = serde_json::to_value(&unit)?;
+    let contract = &json["nodes"][0];
+
+    assert_eq!(json["nodeType"], "SourceUnit");
+    assert_eq!(json["src"], "0:15:0");
+    assert_eq!(json["license"], serde_json::Value::Null);
+    assert_eq!(contract["nodeType"], "ContractDefinition");
+    assert_eq!(contract["name"], "Foo");
+    assert_eq!(contract["nameLocation"], "9:3:0");
+    assert_eq!(contract["baseContracts"], serde_json::json!([]));
+    assert_eq!(
+        json["exportedSymbols"]["Foo"],
+        serde_json::json!([contract["id"]])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_input() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.4")?)?;
+
+    let output = language.parse(RuleKind::SourceUnit, "contract Foo { uint x }");
+    assert!(matches!(
+        solc_ast::export(&output, "Foo.sol", 0),
+        Err(ExportError::SyntaxErrors(1))
+    ));
+
+    let output = language.parse(RuleKind::Expression, "1 + 2");
+    assert!(matches!(
+        solc_ast::export(&output, "Foo.sol", 0),
+        Err(ExportError::NotASourceUnit(kind)) if kind == "Expression"
+    ));
+
+    Ok(())
+}
+
+/// Attributes that are expected to differ from `solc`: node ids are assigned in a different order.
+const IGNORED_ATTRIBUTES: &[&str] = &["id", "exportedSymbols"];
+
+/// Compares the attributes exported by Slang against the ones recorded from `solc` (if it has them),
+/// collecting the paths of any differences.
+fn compare(actual: &Value, expected: &Value, path: &str, mismatches: &mut Vec<String>) {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            for (key, value) in actual {
+                if let (false, Some(expected)) = (
+                    IGNORED_ATTRIBUTES.contains(&key.as_str()),
+                    expected.get(key),
+                ) {
+                    compare(value, expected, &format!("{path}.{key}"), mismatches);
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) if actual.len() == expected.len() => {
+            for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+                compare(actual, expected, &format!("{path}[{index}]"), mismatches);
+            }
+        }
+        _ if actual == expected => {}
+        _ => mismatches.push(format!("{path}: expected {expected}, found {actual}")),
+    }
+}
+
+#[test]
+fn solc_snapshots() -> Result<()> {
+    let snapshots_dir =
+        CargoWorkspace::locate_source_crate("solidity_testing_snapshots")?.join("solc_ast");
+
+    for test_dir in snapshots_dir.collect_children()? {
+        let source = test_dir.join("input.sol").read_to_string()?;
+
+        let latest = Language::SUPPORTED_VERSIONS.last().unwrap();
+        let output = Language::new(latest.clone())?.parse(RuleKind::SourceUnit, &source);
+        assert!(
+            output.is_valid(),
+            "{}: {:?}",
+            test_dir.unwrap_name(),
+            output.errors()
+        );
+        solc_ast::export(&output, "input.sol", 0)?;
+
+        // Recorded by `infra run --bin solidity_testing_solc solc-ast`, one file per `solc` version:
+        let generated_dir = test_dir.join("generated");
+        let expected_paths = if generated_dir.exists() {
+            generated_dir.collect_children()?
+        } else {
+            vec![]
+        };
+
+        assert!(
+            !expected_paths.is_empty(),
+            "{}: No ASTs were recorded from solc. Run 'infra run --bin solidity_testing_solc solc-ast'.",
+            test_dir.strip_repo_root()?.unwrap_str()
+        );
+
+        for expected_path in expected_paths {
+            let version = Version::parse(expected_path.file_stem().unwrap().to_str().unwrap())?;
+            let output = Language::new(version)?.parse(RuleKind::SourceUnit, &source);
+
+            let actual = serde_json::to_value(solc_ast::export(&output, "input.sol", 0)?)?;
+            let expected: Value = serde_json::from_str(&expected_path.read_to_string()?)?;
+
+            let mut mismatches = vec![];
+            compare(&actual, &expected, "", &mut mismatches);
+            assert!(
+                mismatches.is_empty(),
+                "{}:\n{}",
+                expected_path.strip_repo_root()?.unwrap_str(),
+                mismatches.join("\n")
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::kinds::{NodeLabel, RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::syntax_tree::SyntaxNode;
+
+const SOURCE: &str = "contract Foo { uint a; uint a; }";
+
+#[test]
+fn parents_and_offsets() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    assert!(output.is_valid());
+
+    let root = output.syntax_tree();
+    assert!(root.parent().is_none());
+
+    for node in descendants(&root) {
+        let parent = node.parent().expect("descendants have a parent");
+
+        // Every node can be found again through its parent:
+        let sibling = parent.nth_child(node.child_number()).unwrap();
+        assert_eq!(sibling, node);
+        assert_eq!(sibling.label(), node.label());
+
+        // And its text range is the absolute range in the source:
+        let text = node.node().clone().unparse();
+        assert_eq!(
+            &SOURCE[node.text_range().start.utf8..node.text_range().end.utf8],
+            text
+        );
+    }
+
+    let name = descendants(&root)
+        .find(|node| node.node().is_token_with_kind(TokenKind::Identifier))
+        .unwrap();
+    assert_eq!(name.label(), Some(NodeLabel::Name));
+    assert_eq!(name.text_offset().utf8, "contract ".len());
+    assert_eq!(
+        name.ancestors()
+            .filter_map(|node| node.node().as_rule().map(|rule| rule.kind))
+            .collect::<Vec<_>>(),
+        [
+            RuleKind::ContractDefinition,
+            RuleKind::SourceUnitMember,
+            RuleKind::SourceUnitMembers,
+            RuleKind::SourceUnit,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stable_identities() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+
+    let members = descendants(&output.syntax_tree())
+        .filter(|node| node.node().is_rule_with_kind(RuleKind::ContractMember))
+        .collect::<Vec<_>>();
+
+    // Both members are structurally equal, but have different identities:
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].node(), members[1].node());
+    assert_ne!(members[0].id(), members[1].id());
+
+    // Navigating to the same node again results in the same identity:
+    assert_eq!(members[0].next_sibling().unwrap().id(), members[1].id());
+    assert_eq!(members[1].previous_sibling().unwrap().id(), members[0].id());
+
+    let mut cursor = output.create_tree_cursor();
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ContractMember));
+    assert_eq!(cursor.syntax_node(), members[0]);
+    assert_eq!(cursor.syntax_node().parent(), members[0].parent());
+
+    // Which can be used to key side tables:
+    let side_table = members
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.id(), index))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(side_table[&cursor.syntax_node().id()], 0);
+
+    // Identities outlive their tree, without being confused with the nodes of another tree:
+    let id = members[0].id();
+    assert!(id.is_alive());
+    drop((members, cursor, output));
+    assert!(!id.is_alive());
+
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    let member = descendants(&output.syntax_tree())
+        .find(|node| node.node().is_rule_with_kind(RuleKind::ContractMember))
+        .unwrap();
+    assert_ne!(member.id(), id);
+    assert!(!side_table.contains_key(&member.id()));
+
+    Ok(())
+}
+
+fn descendants(node: &SyntaxNode) -> Box<dyn Iterator<Item = SyntaxNode>> {
+    let children = node.children().collect::<Vec<_>>();
+
+    Box::new(
+        children
+            .into_iter()
+            .flat_map(|child| std::iter::once(child.clone()).chain(descendants(&child))),
+    )
+}
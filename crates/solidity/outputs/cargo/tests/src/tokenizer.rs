@@ -0,0 +1,162 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::kinds::{LexicalContext, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::tokenizer::KeywordScan;
+
+fn tokenize(
+    version: &str,
+    input: &str,
+    context: LexicalContext,
+) -> Result<Vec<(TokenKind, String, KeywordScan)>> {
+    let language = Language::new(Version::parse(version)?)?;
+
+    let mut end = 0;
+    let tokens = language
+        .tokenize(input, context)
+        .map(|token| {
+            // Tokens are contiguous, and cover the entire input:
+            assert_eq!(token.range.start.utf8, end);
+            end = token.range.end.utf8;
+
+            let text = input[token.range.start.utf8..token.range.end.utf8].to_owned();
+            (token.kind, text, token.keyword)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(end, input.len());
+
+    Ok(tokens)
+}
+
+#[test]
+fn default_context() -> Result<()> {
+    let tokens = tokenize(
+        "0.8.4",
+        "contract Foo { // comment\n}",
+        LexicalContext::Default,
+    )?;
+
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|(kind, text, _)| (*kind, text.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            (TokenKind::ContractKeyword, "contract"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Identifier, "Foo"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::OpenBrace, "{"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::SingleLineComment, "// comment"),
+            (TokenKind::EndOfLine, "\n"),
+            (TokenKind::CloseBrace, "}"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn keyword_scans() -> Result<()> {
+    let tokens = tokenize("0.8.4", "contract from Foo", LexicalContext::Default)?;
+
+    assert_eq!(
+        tokens[0],
+        (
+            TokenKind::ContractKeyword,
+            "contract".to_owned(),
+            KeywordScan::Reserved(TokenKind::ContractKeyword)
+        )
+    );
+    assert_eq!(
+        tokens[2],
+        (
+            TokenKind::Identifier,
+            "from".to_owned(),
+            KeywordScan::Present(TokenKind::FromKeyword)
+        )
+    );
+    assert_eq!(
+        tokens[4],
+        (TokenKind::Identifier, "Foo".to_owned(), KeywordScan::Absent)
+    );
+
+    // Reserved keywords depend on the language version:
+    let tokens = tokenize("0.4.11", "emit", LexicalContext::Default)?;
+    assert_eq!(tokens[0].0, TokenKind::Identifier);
+
+    let tokens = tokenize("0.5.0", "emit", LexicalContext::Default)?;
+    assert_eq!(tokens[0].0, TokenKind::EmitKeyword);
+
+    Ok(())
+}
+
+#[test]
+fn pragma_and_yul_contexts() -> Result<()> {
+    let tokens = tokenize("0.8.4", "solidity ^0.8.0", LexicalContext::Pragma)?;
+    assert_eq!(
+        tokens[0],
+        (
+            TokenKind::Identifier,
+            "solidity".to_owned(),
+            KeywordScan::Present(TokenKind::SolidityKeyword)
+        )
+    );
+    assert_eq!(
+        tokens[1..]
+            .iter()
+            .map(|(kind, ..)| *kind)
+            .collect::<Vec<_>>(),
+        [
+            TokenKind::Whitespace,
+            TokenKind::Caret,
+            TokenKind::VersionSpecifier,
+            TokenKind::Period,
+            TokenKind::VersionSpecifier,
+            TokenKind::Period,
+            TokenKind::VersionSpecifier,
+        ]
+    );
+
+    let tokens = tokenize("0.8.4", "let x := add(1, 2)", LexicalContext::Yul)?;
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|(kind, ..)| !kind.is_trivia())
+            .map(|(kind, ..)| *kind)
+            .collect::<Vec<_>>(),
+        [
+            TokenKind::YulLetKeyword,
+            TokenKind::YulIdentifier,
+            TokenKind::ColonEqual,
+            TokenKind::YulAddKeyword,
+            TokenKind::OpenParen,
+            TokenKind::YulDecimalLiteral,
+            TokenKind::Comma,
+            TokenKind::YulDecimalLiteral,
+            TokenKind::CloseParen,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unrecognized_characters() -> Result<()> {
+    let tokens = tokenize("0.8.4", "a # b", LexicalContext::Default)?;
+
+    assert_eq!(
+        tokens.iter().map(|(kind, ..)| *kind).collect::<Vec<_>>(),
+        [
+            TokenKind::Identifier,
+            TokenKind::Whitespace,
+            TokenKind::SKIPPED,
+            TokenKind::Whitespace,
+            TokenKind::Identifier,
+        ]
+    );
+
+    Ok(())
+}
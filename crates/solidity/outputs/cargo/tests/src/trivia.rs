@@ -3,6 +3,7 @@ use semver::Version;
 use slang_solidity::cst::Node;
 use slang_solidity::kinds::{RuleKind, TokenKind};
 use slang_solidity::language::Language;
+use slang_solidity::trivia::{AttachedTrivia, Comment};
 
 #[test]
 fn end_of_line() -> Result<()> {
@@ -45,3 +46,118 @@ fn compare_end_of_lines(input: &str, expected: &[&str]) -> Result<()> {
 
     Ok(())
 }
+
+const SOURCE: &str = r"// header comment
+
+/// Doc for Foo
+contract Foo {
+    // leading of a
+    uint a; // trailing of a
+
+
+    /* block */ uint b;
+
+    // dangling
+}
+";
+
+#[test]
+fn attached_comments() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    assert!(output.is_valid());
+
+    let mut cursor = output.create_tree_cursor();
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ContractDefinition));
+    assert_trivia(
+        &cursor.attached_trivia(),
+        &["// header comment"],
+        &["/// Doc for Foo"],
+        &[],
+        1,
+    );
+
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ContractMember));
+    assert_trivia(
+        &cursor.attached_trivia(),
+        &[],
+        &["// leading of a"],
+        &["// trailing of a"],
+        0,
+    );
+
+    // Nested nodes starting at the same token share the same leading trivia:
+    let mut type_name = cursor.clone();
+    assert!(type_name.go_to_next_rule_with_kind(RuleKind::TypeName));
+    assert_eq!(
+        type_name.attached_trivia().leading_comments,
+        cursor.attached_trivia().leading_comments
+    );
+
+    // But not the trailing trivia, since they end at different tokens:
+    assert!(type_name.attached_trivia().trailing_comments.is_empty());
+
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ContractMember));
+    assert_trivia(&cursor.attached_trivia(), &[], &["/* block */"], &[], 2);
+
+    // Comments at the end of a block are attached to its closing delimiter:
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::CloseBrace));
+    assert_trivia(&cursor.attached_trivia(), &[], &["// dangling"], &[], 1);
+
+    Ok(())
+}
+
+#[test]
+fn comments_separated_from_node() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let source = "// first\n// second\n\ncontract Foo {}\n";
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid());
+
+    let mut cursor = output.create_tree_cursor();
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ContractDefinition));
+    assert_trivia(
+        &cursor.attached_trivia(),
+        &["// first", "// second"],
+        &[],
+        &[],
+        1,
+    );
+
+    // Tokens without any comments:
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Identifier));
+    assert_eq!(cursor.attached_trivia(), AttachedTrivia::default());
+
+    Ok(())
+}
+
+fn assert_trivia(
+    trivia: &AttachedTrivia,
+    detached: &[&str],
+    leading: &[&str],
+    trailing: &[&str],
+    blank_lines_before: usize,
+) {
+    let texts = |comments: &[Comment]| {
+        comments
+            .iter()
+            .map(|comment| comment.token.text.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(texts(&trivia.detached_comments), detached);
+    assert_eq!(texts(&trivia.leading_comments), leading);
+    assert_eq!(texts(&trivia.trailing_comments), trailing);
+    assert_eq!(trivia.blank_lines_before, blank_lines_before);
+
+    for comment in trivia
+        .leading_comments
+        .iter()
+        .chain(&trivia.trailing_comments)
+    {
+        assert_eq!(
+            &SOURCE[comment.text_range.start.utf8..comment.text_range.end.utf8],
+            comment.token.text
+        );
+    }
+}
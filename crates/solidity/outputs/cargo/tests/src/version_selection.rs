@@ -0,0 +1,132 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::diagnostic::Diagnostic;
+use slang_solidity::kinds::RuleKind;
+use slang_solidity::language::Language;
+use slang_solidity::version_selection::{select_version, VersionSelectionError};
+
+fn selected(source: &str) -> String {
+    let selection = select_version(source, None);
+    assert_eq!(selection.errors, []);
+
+    selection.version.to_string()
+}
+
+fn latest() -> Version {
+    Language::SUPPORTED_VERSIONS.last().unwrap().clone()
+}
+
+#[test]
+fn comparators() {
+    assert_eq!(selected("pragma solidity 0.7.6;"), "0.7.6");
+    assert_eq!(selected("pragma solidity =0.6.2;"), "0.6.2");
+    assert_eq!(selected("pragma solidity ^0.5.0;"), "0.5.17");
+    assert_eq!(selected("pragma solidity ~0.6.1;"), "0.6.12");
+    assert_eq!(selected("pragma solidity >=0.4.22 <0.6.0;"), "0.5.17");
+    assert_eq!(selected("pragma solidity >0.4.0 <=0.4.24;"), "0.4.24");
+    assert_eq!(selected("pragma solidity \"0.4.24\";"), "0.4.24");
+    assert_eq!(selected("pragma solidity 0.6;"), "0.6.12");
+    assert_eq!(selected("pragma solidity 0.6.x;"), "0.6.12");
+    assert_eq!(selected("pragma solidity *;"), latest().to_string());
+}
+
+#[test]
+fn alternatives_and_ranges() {
+    assert_eq!(selected("pragma solidity ^0.4.11 || ^0.5.0;"), "0.5.17");
+    assert_eq!(selected("pragma solidity 0.4.24 || 0.6.2;"), "0.6.2");
+    assert_eq!(selected("pragma solidity 0.5.0 - 0.6.2;"), "0.6.2");
+    assert_eq!(selected("pragma solidity 0.5.0 - 0.6;"), "0.6.12");
+}
+
+#[test]
+fn all_directives_apply() {
+    let source = r#"
+        // pragma solidity 0.4.11;
+        pragma abicoder v2;
+        pragma solidity >=0.6.0;
+        string constant s = "pragma solidity 0.4.11;";
+        pragma solidity <0.8.0;
+        /* pragma solidity 0.4.11; */
+    "#;
+
+    assert_eq!(selected(source), "0.7.6");
+
+    let selection = select_version(source, None);
+    let ranges = selection
+        .pragmas
+        .iter()
+        .map(|pragma| &source[pragma.text_range.start.utf8..pragma.text_range.end.utf8])
+        .collect::<Vec<_>>();
+    assert_eq!(ranges, [">=0.6.0", "<0.8.0"]);
+
+    assert_eq!(selected("contract A {}"), latest().to_string());
+}
+
+#[test]
+fn no_supported_version() {
+    let source = "pragma solidity >=0.6.0;\npragma solidity <0.5.0;\n";
+    let selection = select_version(source, None);
+
+    assert_eq!(selection.version, latest());
+    assert_eq!(
+        selection.errors,
+        [VersionSelectionError::NoSupportedVersion {
+            selected: latest(),
+            text_range: selection.pragmas[1].text_range.clone(),
+        }]
+    );
+}
+
+#[test]
+fn requested_version() -> Result<()> {
+    // `error` definitions are only supported since 0.8.4:
+    let source = "pragma solidity >=0.8.0 <0.8.4;\nerror Failed();";
+
+    let selection = select_version(source, None);
+    assert_eq!(selection.version, Version::parse("0.8.3")?);
+    assert!(!selection
+        .language()
+        .parse(RuleKind::SourceUnit, source)
+        .is_valid());
+
+    let requested = Version::parse("0.8.22")?;
+    let selection = select_version(source, Some(&requested));
+    assert_eq!(selection.version, requested);
+    assert!(selection
+        .language()
+        .parse(RuleKind::SourceUnit, source)
+        .is_valid());
+
+    assert_eq!(selection.errors.len(), 1);
+    let error = &selection.errors[0];
+    assert_eq!(error.code().to_string(), "VersionConflict");
+    assert_eq!(
+        error.message(),
+        "Requested Solidity version '0.8.22' does not satisfy this 'pragma solidity' directive."
+    );
+
+    // Unsupported versions are ignored:
+    let selection = select_version(source, Some(&Version::parse("0.0.1")?));
+    assert_eq!(selection.version, Version::parse("0.8.3")?);
+    assert_eq!(selection.errors.len(), 1);
+    assert_eq!(selection.errors[0].code().to_string(), "VersionUnsupported");
+
+    Ok(())
+}
+
+#[test]
+fn invalid_expression() -> Result<()> {
+    let source = "pragma solidity ^;\npragma solidity ^0.6.0;\n";
+    let selection = select_version(source, None);
+
+    // Invalid directives are ignored:
+    assert_eq!(selection.version, Version::parse("0.6.12")?);
+    assert_eq!(
+        selection.errors,
+        [VersionSelectionError::InvalidExpression {
+            text_range: selection.pragmas[0].text_range.clone(),
+        }]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use anyhow::Result;
+use semver::Version;
+use slang_solidity::cst::{NodeRc, RuleNode, TokenNode};
+use slang_solidity::cursor::Cursor;
+use slang_solidity::kinds::{RuleKind, TokenKind};
+use slang_solidity::language::Language;
+use slang_solidity::visitor::{walk, walk_cursor, Visitor, WalkControl};
+
+const SOURCE: &str = r"
+contract Foo {
+    function first() public {}
+    modifier second() { _; }
+    function third() public {}
+}
+
+contract Bar {
+    function fourth() public {}
+}
+";
+
+#[derive(Default)]
+struct Collector {
+    skip_contracts: Vec<&'static str>,
+    current_contract: Option<String>,
+    functions: Vec<String>,
+    events: Vec<String>,
+}
+
+impl Visitor for Collector {
+    fn enter_contract_definition(
+        &mut self,
+        node: &NodeRc<RuleNode>,
+        _cursor: &Cursor,
+    ) -> WalkControl {
+        let name = node
+            .children
+            .iter()
+            .find_map(|child| child.as_token_with_kind(TokenKind::Identifier))
+            .unwrap()
+            .text
+            .clone();
+
+        self.events.push(format!("enter {name}"));
+
+        if self.skip_contracts.contains(&name.as_str()) {
+            return WalkControl::SkipChildren;
+        }
+
+        self.current_contract = Some(name);
+        WalkControl::Continue
+    }
+
+    fn leave_contract_definition(&mut self, _node: &NodeRc<RuleNode>, _cursor: &Cursor) {
+        self.events.push("leave".to_string());
+        self.current_contract = None;
+    }
+
+    fn visit_identifier(&mut self, node: &NodeRc<TokenNode>, cursor: &Cursor) {
+        if cursor
+            .ancestors()
+            .next()
+            .is_some_and(|parent| parent.kind == RuleKind::FunctionName)
+        {
+            let contract = self.current_contract.as_ref().unwrap();
+            self.functions.push(format!("{contract}.{}", node.text));
+        }
+    }
+}
+
+#[test]
+fn enter_and_leave_hooks() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+    assert!(output.is_valid());
+
+    let mut collector = Collector::default();
+    walk(&output, &mut collector);
+
+    assert_eq!(
+        collector.functions,
+        ["Foo.first", "Foo.third", "Bar.fourth"]
+    );
+    assert_eq!(
+        collector.events,
+        ["enter Foo", "leave", "enter Bar", "leave"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn skip_children() -> Result<()> {
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+
+    let mut collector = Collector {
+        skip_contracts: vec!["Foo"],
+        ..Collector::default()
+    };
+    walk(&output, &mut collector);
+
+    assert_eq!(collector.functions, ["Bar.fourth"]);
+    assert_eq!(
+        collector.events,
+        ["enter Foo", "leave", "enter Bar", "leave"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generic_hooks_match_cursor() -> Result<()> {
+    #[derive(Default)]
+    struct Nodes {
+        entered: Vec<(RuleKind, usize)>,
+        left: usize,
+        tokens: String,
+    }
+
+    impl Visitor for Nodes {
+        fn enter_rule(&mut self, node: &NodeRc<RuleNode>, cursor: &Cursor) -> WalkControl {
+            self.entered.push((node.kind, cursor.text_offset().utf8));
+            WalkControl::Continue
+        }
+
+        fn leave_rule(&mut self, _node: &NodeRc<RuleNode>, _cursor: &Cursor) {
+            self.left += 1;
+        }
+
+        fn visit_token(&mut self, node: &NodeRc<TokenNode>, _cursor: &Cursor) {
+            self.tokens.push_str(&node.text);
+        }
+    }
+
+    let language = Language::new(Version::parse("0.8.0")?)?;
+    let output = language.parse(RuleKind::SourceUnit, SOURCE);
+
+    let mut nodes = Nodes::default();
+    walk(&output, &mut nodes);
+
+    let mut cursor = output.create_tree_cursor();
+    let mut expected = vec![];
+    while !cursor.is_completed() {
+        if let Some(rule) = cursor.node().as_rule() {
+            expected.push((rule.kind, cursor.text_offset().utf8));
+        }
+        cursor.go_to_next();
+    }
+
+    assert_eq!(nodes.entered, expected);
+    assert_eq!(nodes.left, expected.len());
+    assert_eq!(nodes.tokens, SOURCE);
+
+    // Walking a subtree only visits its nodes:
+    let mut cursor = output.create_tree_cursor();
+    assert!(cursor.go_to_next_rule_with_kind(RuleKind::ModifierDefinition));
+
+    let mut nodes = Nodes::default();
+    walk_cursor(&cursor, &mut nodes);
+    assert_eq!(nodes.entered[0].0, RuleKind::ModifierDefinition);
+    assert_eq!(nodes.tokens, "    modifier second() { _; }\n");
+
+    Ok(())
+}
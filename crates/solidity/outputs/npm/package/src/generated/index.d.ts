@@ -360,6 +360,7 @@ export namespace kinds {
   }
   export enum TokenKind {
     SKIPPED = "SKIPPED",
+    MISSING = "MISSING",
     AbicoderKeyword = "AbicoderKeyword",
     AbstractKeyword = "AbstractKeyword",
     AddressKeyword = "AddressKeyword",
@@ -792,14 +793,25 @@ export namespace cursor {
     goToFirstChild(): boolean;
     goToLastChild(): boolean;
     goToNthChild(childNumber: number): boolean;
+    goToOffset(offset: text_index.TextIndex): boolean;
     goToNextSibling(): boolean;
     goToPreviousSibling(): boolean;
     goToNextToken(): boolean;
     goToNextTokenWithKind(kind: kinds.TokenKind): boolean;
     goToNextTokenWithKinds(kinds: Array<kinds.TokenKind>): boolean;
+    goToNextNonTriviaToken(): boolean;
     goToNextRule(): boolean;
     goToNextRuleWithKind(kind: kinds.RuleKind): boolean;
     goToNextRuleWithKinds(kinds: Array<kinds.RuleKind>): boolean;
+    goToPreviousToken(): boolean;
+    goToPreviousTokenWithKind(kind: kinds.TokenKind): boolean;
+    goToPreviousTokenWithKinds(kinds: Array<kinds.TokenKind>): boolean;
+    goToPreviousNonTriviaToken(): boolean;
+    goToPreviousRule(): boolean;
+    goToPreviousRuleWithKind(kind: kinds.RuleKind): boolean;
+    goToPreviousRuleWithKinds(kinds: Array<kinds.RuleKind>): boolean;
+    goToAncestorWithKind(kind: kinds.RuleKind): boolean;
+    goToAncestorWithKinds(kinds: Array<kinds.RuleKind>): boolean;
     query(queries: Array<query.Query>): query.QueryResultIterator;
   }
 }
@@ -820,11 +832,15 @@ export namespace diagnostic {
     textRange(): text_index.TextRange;
     message(): string;
     code(): string;
+    note(): string | undefined;
   }
 }
 export namespace parse_error {
   export class ParseError {
     get textRange(): text_index.TextRange;
+    get code(): string;
+    get ruleKind(): kinds.RuleKind | undefined;
+    get note(): string | undefined;
     toDiagnostic(): diagnostic.Diagnostic;
   }
 }
@@ -835,6 +851,18 @@ export namespace parse_output {
     get isValid(): boolean;
     /** Creates a cursor that starts at the root of the parse tree. */
     createTreeCursor(): cursor.Cursor;
+    /**
+     * Creates a cursor that points to the deepest node (i.e. a token) containing the given offset,
+     * or `null` if the offset is outside of the parse tree.
+     */
+    nodeAtOffset(offset: text_index.TextIndex): cursor.Cursor | null;
+    /**
+     * Creates a cursor that points to the deepest node fully containing the given range,
+     * or `null` if the range is outside of the parse tree.
+     */
+    coveringNode(range: text_index.TextRange): cursor.Cursor | null;
+    /** Creates a line index of the parsed source, to convert text offsets to (line, column) positions and back. */
+    createLineIndex(): text_index.LineIndex;
   }
 }
 export namespace query {
@@ -859,6 +887,36 @@ export namespace text_index {
     start: TextIndex;
     end: TextIndex;
   }
+  export enum TextUnit {
+    Utf8 = "Utf8",
+    Utf16 = "Utf16",
+    Char = "Char",
+  }
+  export interface LineColumn {
+    line: number;
+    column: TextIndex;
+  }
+  export class LineIndex {
+    get lineCount(): number;
+    /** Returns the position of the given offset. */
+    lineColumn(offset: TextIndex): LineColumn;
+    /** Returns the offset of a `column` (measured in the given `unit`) within a zero-based `line`. */
+    offset(line: number, column: number, unit: TextUnit): TextIndex | null;
+  }
+}
+
+export namespace semantic_tokens {
+  export interface SemanticTokensLegend {
+    tokenTypes: Array<string>;
+    tokenModifiers: Array<string>;
+  }
+  /** Returns the names of the token types and modifiers used in the encoded format. */
+  export function legend(): SemanticTokensLegend;
+  /**
+   * Classifies the tokens of the parse output, and encodes them in the relative format of the LSP
+   * `SemanticTokens.data` array, with columns and lengths measured in the given `unit`.
+   */
+  export function encode(output: parse_output.ParseOutput, unit: text_index.TextUnit): Array<number>;
 }
 
 export namespace cst {
@@ -6,4 +6,5 @@ export * as language from "./language";
 export * as parse_error from "./parse_error";
 export * as parse_output from "./parse_output";
 export * as query from "./query";
+export * as semantic_tokens from "./semantic_tokens";
 export * as text_index from "./text_index";
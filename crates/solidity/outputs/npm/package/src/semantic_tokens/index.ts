@@ -0,0 +1,7 @@
+import * as generated from "../generated";
+
+export type SemanticTokensLegend = generated.semantic_tokens.SemanticTokensLegend;
+
+export const legend = generated.semantic_tokens.legend;
+
+export const encode = generated.semantic_tokens.encode;
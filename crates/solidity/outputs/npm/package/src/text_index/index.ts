@@ -3,3 +3,11 @@ import * as generated from "../generated";
 export type TextIndex = generated.text_index.TextIndex;
 
 export type TextRange = generated.text_index.TextRange;
+
+export const TextUnit = generated.text_index.TextUnit;
+export type TextUnit = generated.text_index.TextUnit;
+
+export type LineColumn = generated.text_index.LineColumn;
+
+export const LineIndex = generated.text_index.LineIndex;
+export type LineIndex = generated.text_index.LineIndex;
@@ -0,0 +1,57 @@
+import { Language } from "@nomicfoundation/slang/language";
+import { RuleKind } from "@nomicfoundation/slang/kinds";
+import { encode, legend } from "@nomicfoundation/slang/semantic_tokens";
+import { TextUnit } from "@nomicfoundation/slang/text_index";
+
+test("legend", () => {
+  const { tokenTypes, tokenModifiers } = legend();
+
+  expect(tokenTypes).toEqual([
+    "namespace",
+    "type",
+    "class",
+    "enum",
+    "interface",
+    "struct",
+    "parameter",
+    "variable",
+    "property",
+    "enumMember",
+    "event",
+    "function",
+    "method",
+    "decorator",
+    "keyword",
+    "comment",
+    "string",
+    "number",
+    "operator",
+  ]);
+
+  expect(tokenModifiers).toEqual(["declaration", "readonly", "documentation", "defaultLibrary"]);
+});
+
+test("encode", () => {
+  const source = `contract Foo {\n  string x = unicode"\u{1F600}";\n}`;
+  const language = new Language("0.8.0");
+
+  const output = language.parse(RuleKind.SourceUnit, source);
+  expect(output.isValid).toBeTruthy();
+
+  const { tokenTypes, tokenModifiers } = legend();
+  const type = (name: string) => tokenTypes.indexOf(name);
+  const modifier = (name: string) => 1 << tokenModifiers.indexOf(name);
+
+  // Each token is encoded as [deltaLine, deltaStartColumn, length, tokenType, tokenModifiers]:
+  expect(encode(output, TextUnit.Utf16)).toEqual([
+    ...[0, 0, 8, type("keyword"), 0], // contract
+    ...[0, 9, 3, type("class"), modifier("declaration")], // Foo
+    ...[1, 2, 6, type("type"), modifier("defaultLibrary")], // string
+    ...[0, 7, 1, type("property"), modifier("declaration")], // x
+    ...[0, 4, 11, type("string"), 0], // unicode"😀"
+  ]);
+
+  // Columns and lengths are measured in the requested unit:
+  expect(encode(output, TextUnit.Utf8).slice(-5)).toEqual([0, 4, 13, type("string"), 0]);
+  expect(encode(output, TextUnit.Char).slice(-5)).toEqual([0, 4, 10, type("string"), 0]);
+});
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./Base.sol" as Base;
+import {Owned, Token as T} from "./Token.sol";
+
+type Price is uint128;
+
+error Unauthorized(address caller);
+
+uint256 constant LIMIT = 100;
+
+function double(uint256 value) pure returns (uint256) {
+    return value * 2;
+}
+
+/// @title A counter
+contract Counter is Base.Owned(1), T {
+    using SafeMath for uint256;
+
+    enum State {
+        Active,
+        Paused
+    }
+
+    struct Entry {
+        address owner;
+        mapping(address => uint256) balances;
+    }
+
+    uint256 public count = 0x10;
+    State private state;
+
+    event Incremented(address indexed by, uint256 value);
+
+    modifier onlyOwner() {
+        _;
+    }
+
+    constructor(uint256 initial) payable {
+        count = initial;
+    }
+
+    /// @notice Increments the counter.
+    function increment(uint256 amount) external virtual onlyOwner returns (bool) {
+        count += amount;
+        return true;
+    }
+
+    receive() external payable {}
+
+    fallback() external {}
+}
+
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+library Math {
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a > b ? a : b;
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Expressions {
+    function run(uint256[] memory values, address target) public returns (uint256 result) {
+        result = values[0] + values.length * 2 ** 3 - (1 << 4) % 5;
+        result += uint256(uint160(target)) & 0xff;
+        bool flag = !(result > 1 && result <= 10) || result != 0;
+        result = flag ? result++ : --result;
+        (uint256 a, , uint256 c) = (1, 2, 3);
+        uint256[] memory copy = new uint256[](values.length);
+        copy[1:2];
+        delete copy[0];
+        bytes memory data = abi.encodeWithSignature("f(uint256)", a + c);
+        (bool success, ) = target.call{value: 1 ether, gas: 10000}(data);
+        string memory text = unicode"héllo";
+        bytes memory raw = hex"00ff";
+        result = type(uint256).max - 1e18 - 2 days;
+        payable(target).transfer(success ? raw.length : bytes(text).length);
+    }
+}
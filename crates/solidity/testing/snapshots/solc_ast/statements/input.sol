@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Statements {
+    event Done(uint256 value);
+    error Failed(uint256 code);
+
+    function run(uint256 n, IExternal other) public {
+        uint256 total;
+
+        for (uint256 i = 0; i < n; i++) {
+            if (i % 2 == 0) {
+                continue;
+            } else if (i > 100) {
+                break;
+            } else {
+                total += i;
+            }
+        }
+
+        while (total > 10) total /= 2;
+
+        do {
+            total--;
+        } while (total > 5);
+
+        unchecked {
+            total = total - 1;
+        }
+
+        try other.call(total) returns (uint256 result) {
+            emit Done(result);
+        } catch Error(string memory reason) {
+            revert(reason);
+        } catch (bytes memory) {
+            revert Failed(1);
+        }
+
+        require(total > 0, "empty");
+        return;
+    }
+}
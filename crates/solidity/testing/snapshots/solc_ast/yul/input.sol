@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Yul {
+    function run(uint256 input) public pure returns (uint256 output) {
+        assembly ("memory-safe") {
+            let x := add(input, 1)
+            function double(value) -> result {
+                result := mul(value, 2)
+                if iszero(value) {
+                    leave
+                }
+            }
+            function pair() -> first, second {
+                first := 1
+                second := 2
+            }
+            switch x
+            case 0 {
+                output := 0
+            }
+            default {
+                output := double(x)
+            }
+            for { let i := 0 } lt(i, 10) { i := add(i, 1) } {
+                if eq(i, 5) {
+                    break
+                }
+                output := add(output, i)
+            }
+            let a
+            let b
+            a, b := pair()
+            mstore(0x40, "abc")
+        }
+    }
+}
@@ -100,6 +100,7 @@ impl Dissector {
                 },
             )]
             .into(),
+            settings: None,
         };
 
         let errors = match binary.run(&input) {
@@ -249,6 +249,7 @@ impl TestCase {
                 },
             )]
             .into(),
+            settings: None,
         };
 
         let output = match binary.run(&input) {
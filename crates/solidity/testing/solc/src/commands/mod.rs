@@ -1,11 +1,13 @@
 mod dissect;
 mod keyword_versioning;
+mod solc_ast;
 
 use anyhow::Result;
 use clap::Subcommand;
 
 use crate::commands::dissect::DissectCommand;
 use crate::commands::keyword_versioning::KeywordVersioningCommand;
+use crate::commands::solc_ast::SolcAstCommand;
 
 #[derive(Debug, Subcommand)]
 pub enum AppCommand {
@@ -14,6 +16,9 @@ pub enum AppCommand {
 
     /// Compiles a Solidity file with all versions of `solc`, listing which versions succeeded/failed.
     Dissect(DissectCommand),
+
+    /// Records the JSON AST produced by `solc` for each of the `solc_ast` snapshots.
+    SolcAst(SolcAstCommand),
 }
 
 impl AppCommand {
@@ -21,6 +26,7 @@ impl AppCommand {
         match self {
             AppCommand::KeywordVersioning(command) => command.execute(),
             AppCommand::Dissect(command) => command.execute(),
+            AppCommand::SolcAst(command) => command.execute(),
         }
     }
 }
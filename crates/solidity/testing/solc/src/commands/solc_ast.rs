@@ -0,0 +1,95 @@
+//! Records the JSON AST produced by `solc` for each of the `solc_ast` snapshots, which is then compared against
+//! the one exported by `slang_solidity::solc_ast` in the cargo tests.
+//! It stops `solc` right after parsing, so imports don't need to be resolved, and the AST only contains the
+//! syntactic attributes that Slang can produce.
+
+use anyhow::{ensure, Context, Result};
+use clap::Parser;
+use infra_utils::cargo::CargoWorkspace;
+use infra_utils::codegen::Codegen;
+use infra_utils::paths::PathExtensions;
+use semver::Version;
+use solidity_language::SolidityDefinition;
+
+use crate::utils::{Binary, CliInput, InputSource, LanguageSelector, Settings, Severity};
+
+/// Records the JSON AST of each `solc_ast` snapshot.
+#[derive(Debug, Parser)]
+pub struct SolcAstCommand {
+    /// The version of `solc` to use. Defaults to the latest version supported by Slang.
+    #[arg(long)]
+    version: Option<Version>,
+}
+
+impl SolcAstCommand {
+    pub fn execute(self) -> Result<()> {
+        let language = SolidityDefinition::create();
+        let version = match self.version {
+            Some(version) => version,
+            None => language
+                .versions
+                .last()
+                .expect("Language has no versions.")
+                .clone(),
+        };
+
+        let binary = Binary::fetch(&version)?;
+
+        let snapshots_dir =
+            CargoWorkspace::locate_source_crate("solidity_testing_snapshots")?.join("solc_ast");
+
+        let mut codegen = Codegen::write_only()?;
+
+        for test_dir in snapshots_dir.collect_children()? {
+            let source = test_dir.join("input.sol").read_to_string()?;
+            let ast = export_ast(&binary, &source).with_context(|| {
+                format!("Failed to export the AST of '{}'.", test_dir.unwrap_str())
+            })?;
+
+            codegen.write_file(
+                test_dir.join("generated").join(format!("{version}.json")),
+                serde_json::to_string(&ast)?,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn export_ast(binary: &Binary, source: &str) -> Result<serde_json::Value> {
+    let input = CliInput {
+        language: LanguageSelector::Solidity,
+        sources: [(
+            "input.sol".into(),
+            InputSource {
+                content: source.to_owned(),
+            },
+        )]
+        .into(),
+        settings: Some(Settings {
+            stop_after: Some("parsing".into()),
+            output_selection: [("*".into(), [(String::new(), vec!["ast".into()])].into())].into(),
+        }),
+    };
+
+    let output = binary.run(&input)?;
+
+    let errors: Vec<_> = output
+        .errors
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|error| error.severity == Severity::Error)
+        .collect();
+
+    ensure!(
+        errors.is_empty(),
+        "solc {version} failed: {errors:#?}",
+        version = binary.version
+    );
+
+    output
+        .sources
+        .and_then(|mut sources| sources.remove("input.sol"))
+        .and_then(|source| source.ast)
+        .context("solc did not produce an AST.")
+}
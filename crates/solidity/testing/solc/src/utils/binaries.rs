@@ -39,23 +39,11 @@ impl Binary {
             .iter()
             .par_bridge()
             .map(|version| {
-                let local_path = binaries_dir.join(version.to_string());
-                if !local_path.exists() {
-                    let release = releases.get(version).unwrap_or_else(|| {
-                        panic!("Expected release '{version}' to exist at: {mirror_url}")
-                    });
-
-                    let remote_url = mirror_url.join(release)?;
-                    download_file(remote_url, &local_path)?;
-                    make_file_executable(&local_path)?;
-                }
+                let binary = Self::fetch_release(version, &binaries_dir, &mirror_url, &releases)?;
 
                 progress_bar.inc(1);
 
-                Ok(Self {
-                    version: version.to_owned(),
-                    local_path,
-                })
+                Ok(binary)
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -67,6 +55,37 @@ impl Binary {
         Ok(binaries)
     }
 
+    pub fn fetch(version: &Version) -> Result<Self> {
+        let binaries_dir = get_binaries_dir()?;
+        let mirror_url = get_mirror_url()?;
+        let releases = fetch_releases(&mirror_url, &binaries_dir)?;
+
+        Self::fetch_release(version, &binaries_dir, &mirror_url, &releases)
+    }
+
+    fn fetch_release(
+        version: &Version,
+        binaries_dir: &Path,
+        mirror_url: &Url,
+        releases: &HashMap<Version, String>,
+    ) -> Result<Self> {
+        let local_path = binaries_dir.join(version.to_string());
+        if !local_path.exists() {
+            let release = releases.get(version).unwrap_or_else(|| {
+                panic!("Expected release '{version}' to exist at: {mirror_url}")
+            });
+
+            let remote_url = mirror_url.join(release)?;
+            download_file(remote_url, &local_path)?;
+            make_file_executable(&local_path)?;
+        }
+
+        Ok(Self {
+            version: version.to_owned(),
+            local_path,
+        })
+    }
+
     pub fn run(&self, input: &CliInput) -> Result<CliOutput> {
         let input = serde_json::to_string(input)?;
 
@@ -11,6 +11,8 @@ use strum_macros::Display;
 pub struct CliInput {
     pub language: LanguageSelector,
     pub sources: HashMap<String, InputSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Settings>,
 }
 
 #[derive(Debug, Serialize)]
@@ -23,9 +25,25 @@ pub struct InputSource {
     pub content: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct Settings {
+    /// Only `"parsing"` is supported by `solc`, skipping analysis (and import resolution) altogether.
+    #[serde(rename = "stopAfter", skip_serializing_if = "Option::is_none")]
+    pub stop_after: Option<String>,
+    /// Maps file names, then contract names (or `""` for the whole file), to the requested outputs.
+    #[serde(rename = "outputSelection")]
+    pub output_selection: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CliOutput {
     pub errors: Option<Vec<Error>>,
+    pub sources: Option<HashMap<String, OutputSource>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OutputSource {
+    pub ast: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
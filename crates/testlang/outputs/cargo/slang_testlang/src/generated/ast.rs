@@ -0,0 +1,526 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! Strongly-typed wrappers over the CST nodes, lazily selecting their children on access.
+//!
+//! Every wrapper holds the underlying [`RuleNode`], and can be created from one with the matching
+//! [`RuleKind`] using the `cast` function. Accessing a required child of a node that was recovered
+//! from a syntax error (i.e. the child is missing) will panic.
+
+#![allow(clippy::too_many_lines)]
+
+use crate::cst::{Node, Rc, RuleNode, TokenNode};
+use crate::kinds::{NodeLabel, RuleKind};
+
+//
+// Sequences:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnit(Rc<RuleNode>);
+
+impl SourceUnit {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnit`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnit).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn members(&self) -> SourceUnitMembers {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(SourceUnitMembers::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tree(Rc<RuleNode>);
+
+impl Tree {
+    /// Wraps the given CST node, if it has the [`RuleKind::Tree`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Tree).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn keyword(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Keyword),
+            &self.0,
+            NodeLabel::Keyword,
+        )
+    }
+
+    pub fn name(&self) -> Option<Rc<TokenNode>> {
+        select_token(&self.0, NodeLabel::Name)
+    }
+
+    pub fn node(&self) -> TreeNode {
+        required(
+            select_rule(&self.0, NodeLabel::Node).and_then(TreeNode::cast),
+            &self.0,
+            NodeLabel::Node,
+        )
+    }
+
+    pub fn semicolon(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Semicolon),
+            &self.0,
+            NodeLabel::Semicolon,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode(Rc<RuleNode>);
+
+impl TreeNode {
+    /// Wraps the given CST node, if it has the [`RuleKind::TreeNode`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TreeNode).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn open_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::OpenBracket),
+            &self.0,
+            NodeLabel::OpenBracket,
+        )
+    }
+
+    pub fn members(&self) -> TreeNodeChildren {
+        required(
+            select_rule(&self.0, NodeLabel::Members).and_then(TreeNodeChildren::cast),
+            &self.0,
+            NodeLabel::Members,
+        )
+    }
+
+    pub fn close_bracket(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::CloseBracket),
+            &self.0,
+            NodeLabel::CloseBracket,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdditionExpression(Rc<RuleNode>);
+
+impl AdditionExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::AdditionExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::AdditionExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn left_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::LeftOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::LeftOperand,
+        )
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn right_operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::RightOperand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::RightOperand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegationExpression(Rc<RuleNode>);
+
+impl NegationExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::NegationExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::NegationExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operator(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Operator),
+            &self.0,
+            NodeLabel::Operator,
+        )
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberAccessExpression(Rc<RuleNode>);
+
+impl MemberAccessExpression {
+    /// Wraps the given CST node, if it has the [`RuleKind::MemberAccessExpression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::MemberAccessExpression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn operand(&self) -> Expression {
+        required(
+            select_rule(&self.0, NodeLabel::Operand).and_then(Expression::cast),
+            &self.0,
+            NodeLabel::Operand,
+        )
+    }
+
+    pub fn period(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Period),
+            &self.0,
+            NodeLabel::Period,
+        )
+    }
+
+    pub fn member(&self) -> Rc<TokenNode> {
+        required(
+            select_token(&self.0, NodeLabel::Member),
+            &self.0,
+            NodeLabel::Member,
+        )
+    }
+}
+
+//
+// Choices:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnitMember(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SourceUnitMemberVariant {
+    Tree(Tree),
+    Expression(Expression),
+    SeparatedIdentifiers(SeparatedIdentifiers),
+    Literal(Literal),
+}
+
+impl SourceUnitMember {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnitMember`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnitMember).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> SourceUnitMemberVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::Tree => SourceUnitMemberVariant::Tree(Tree(Rc::clone(rule))),
+                RuleKind::Expression => {
+                    SourceUnitMemberVariant::Expression(Expression(Rc::clone(rule)))
+                }
+                RuleKind::SeparatedIdentifiers => SourceUnitMemberVariant::SeparatedIdentifiers(
+                    SeparatedIdentifiers(Rc::clone(rule)),
+                ),
+                RuleKind::Literal => SourceUnitMemberVariant::Literal(Literal(Rc::clone(rule))),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNodeChild(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TreeNodeChildVariant {
+    TreeNode(TreeNode),
+    Token(Rc<TokenNode>),
+}
+
+impl TreeNodeChild {
+    /// Wraps the given CST node, if it has the [`RuleKind::TreeNodeChild`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TreeNodeChild).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> TreeNodeChildVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::TreeNode => TreeNodeChildVariant::TreeNode(TreeNode(Rc::clone(rule))),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => TreeNodeChildVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expression(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionVariant {
+    AdditionExpression(AdditionExpression),
+    NegationExpression(NegationExpression),
+    MemberAccessExpression(MemberAccessExpression),
+    Token(Rc<TokenNode>),
+}
+
+impl Expression {
+    /// Wraps the given CST node, if it has the [`RuleKind::Expression`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Expression).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> ExpressionVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Rule(rule) => match rule.kind {
+                RuleKind::AdditionExpression => {
+                    ExpressionVariant::AdditionExpression(AdditionExpression(Rc::clone(rule)))
+                }
+                RuleKind::NegationExpression => {
+                    ExpressionVariant::NegationExpression(NegationExpression(Rc::clone(rule)))
+                }
+                RuleKind::MemberAccessExpression => ExpressionVariant::MemberAccessExpression(
+                    MemberAccessExpression(Rc::clone(rule)),
+                ),
+                _ => unexpected_variant(&self.0, variant),
+            },
+            Node::Token(token) => ExpressionVariant::Token(Rc::clone(token)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Literal(Rc<RuleNode>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiteralVariant {
+    Token(Rc<TokenNode>),
+}
+
+impl Literal {
+    /// Wraps the given CST node, if it has the [`RuleKind::Literal`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::Literal).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn variant(&self) -> LiteralVariant {
+        let variant = required(
+            select(&self.0, NodeLabel::Variant),
+            &self.0,
+            NodeLabel::Variant,
+        );
+
+        match variant {
+            Node::Token(token) => LiteralVariant::Token(Rc::clone(token)),
+            Node::Rule(_) => unexpected_variant(&self.0, variant),
+        }
+    }
+}
+
+//
+// Repeated:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceUnitMembers(Rc<RuleNode>);
+
+impl SourceUnitMembers {
+    /// Wraps the given CST node, if it has the [`RuleKind::SourceUnitMembers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SourceUnitMembers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<SourceUnitMember> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(SourceUnitMember::cast)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNodeChildren(Rc<RuleNode>);
+
+impl TreeNodeChildren {
+    /// Wraps the given CST node, if it has the [`RuleKind::TreeNodeChildren`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::TreeNodeChildren).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<TreeNodeChild> {
+        select_all_rules(&self.0, NodeLabel::Item)
+            .filter_map(TreeNodeChild::cast)
+            .collect()
+    }
+}
+
+//
+// Separated:
+//
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeparatedIdentifiers(Rc<RuleNode>);
+
+impl SeparatedIdentifiers {
+    /// Wraps the given CST node, if it has the [`RuleKind::SeparatedIdentifiers`] kind.
+    pub fn cast(cst: Rc<RuleNode>) -> Option<Self> {
+        (cst.kind == RuleKind::SeparatedIdentifiers).then_some(Self(cst))
+    }
+
+    /// Returns the underlying CST node.
+    pub fn cst(&self) -> &Rc<RuleNode> {
+        &self.0
+    }
+
+    pub fn items(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Item).collect()
+    }
+
+    pub fn separators(&self) -> Vec<Rc<TokenNode>> {
+        select_all_tokens(&self.0, NodeLabel::Separator).collect()
+    }
+}
+
+//
+// Common:
+//
+
+fn select(node: &RuleNode, label: NodeLabel) -> Option<&Node> {
+    node.children
+        .iter()
+        .find(|child| child.label == Some(label))
+        .map(|child| &child.node)
+}
+
+fn select_rule(node: &RuleNode, label: NodeLabel) -> Option<Rc<RuleNode>> {
+    select(node, label).and_then(Node::as_rule).cloned()
+}
+
+fn select_token(node: &RuleNode, label: NodeLabel) -> Option<Rc<TokenNode>> {
+    select(node, label).and_then(Node::as_token).cloned()
+}
+
+fn select_all_rules(node: &RuleNode, label: NodeLabel) -> impl Iterator<Item = Rc<RuleNode>> + '_ {
+    node.children
+        .iter()
+        .filter(move |child| child.label == Some(label))
+        .filter_map(|child| child.node.as_rule().cloned())
+}
+
+fn select_all_tokens(
+    node: &RuleNode,
+    label: NodeLabel,
+) -> impl Iterator<Item = Rc<TokenNode>> + '_ {
+    node.children
+        .iter()
+        .filter(move |child| child.label == Some(label))
+        .filter_map(|child| child.node.as_token().cloned())
+}
+
+fn required<T>(child: Option<T>, parent: &RuleNode, label: NodeLabel) -> T {
+    // Can happen if the user decided to use an incorrect/incomplete CST node.
+    child.unwrap_or_else(|| panic!("Missing child '{label}' in a '{}' node. Creating AST types from incorrect/incomplete CST nodes is not supported yet.", parent.kind))
+}
+
+#[allow(dead_code)]
+fn unexpected_variant(parent: &RuleNode, variant: &Node) -> ! {
+    // Should not theoretically happen, unless AST error recovery was changed.
+    match variant {
+        Node::Rule(rule) => panic!(
+            "Unexpected variant '{}' in a '{}' node.",
+            rule.kind, parent.kind
+        ),
+        Node::Token(token) => panic!(
+            "Unexpected variant '{}' in a '{}' node.",
+            token.kind, parent.kind
+        ),
+    }
+}
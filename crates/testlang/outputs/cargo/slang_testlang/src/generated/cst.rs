@@ -1,14 +1,22 @@
 // This file is generated automatically by infrastructure scripts. Please don't edit by hand.
 
-use std::rc::Rc;
-
-use serde::Serialize;
+/// The reference-counted pointer used to share nodes in the tree.
+///
+/// This is [`std::sync::Arc`] when the `sync` feature is enabled, so that trees can be sent and shared across threads.
+/// Otherwise, it is the cheaper [`std::rc::Rc`].
+#[cfg(not(feature = "sync"))]
+pub use std::rc::Rc as NodeRc;
+#[cfg(feature = "sync")]
+pub use std::sync::Arc as NodeRc;
+
+use serde::{Deserialize, Serialize};
+pub(crate) use NodeRc as Rc;
 
 use crate::cursor::Cursor;
 use crate::kinds::{NodeLabel, RuleKind, TokenKind};
 use crate::text_index::TextIndex;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LabeledNode {
     pub label: Option<NodeLabel>,
     pub node: Node,
@@ -29,21 +37,43 @@ impl std::ops::Deref for LabeledNode {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "DeserializedRuleNode")]
 pub struct RuleNode {
     pub kind: RuleKind,
     pub text_len: TextIndex,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<LabeledNode>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+/// The fields of a serialized [`RuleNode`], without its `text_len`, which is recomputed from its children
+/// instead of being trusted.
+#[derive(Deserialize)]
+struct DeserializedRuleNode {
+    kind: RuleKind,
+    #[serde(default)]
+    children: Vec<LabeledNode>,
+}
+
+impl From<DeserializedRuleNode> for RuleNode {
+    fn from(DeserializedRuleNode { kind, children }: DeserializedRuleNode) -> Self {
+        let text_len = children.iter().map(|node| node.text_len()).sum();
+
+        Self {
+            kind,
+            text_len,
+            children,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenNode {
     pub kind: TokenKind,
     pub text: String,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Node {
     Rule(Rc<RuleNode>),
     Token(Rc<TokenNode>),
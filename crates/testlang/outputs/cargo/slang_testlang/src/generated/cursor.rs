@@ -2,11 +2,11 @@
 
 //! A cursor that can traverse a CST in a DFS pre-order fashion.
 
-use std::rc::Rc;
-
-use crate::cst::{LabeledNode, Node, RuleNode};
+use crate::cst::{LabeledNode, Node, Rc, RuleNode};
 use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::syntax_tree::SyntaxNode;
 use crate::text_index::{TextIndex, TextRange};
+use crate::trivia::{self, AttachedTrivia};
 
 /// A node in the ancestor path of a [`Cursor`].
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -50,6 +50,20 @@ impl Cursor {
         }
     }
 
+    fn ancestor_syntax_node(ancestor: &PathAncestor) -> SyntaxNode {
+        match &ancestor.parent {
+            Some(parent) => SyntaxNode::new_child(
+                Self::ancestor_syntax_node(parent),
+                ancestor.child_number,
+                ancestor.text_offset,
+            ),
+            None => SyntaxNode::new_root_with_offset(
+                Node::Rule(ancestor.rule_node.clone()),
+                ancestor.text_offset,
+            ),
+        }
+    }
+
     fn set_from_ancestor_node(&mut self, ancestor: &Rc<PathAncestor>) {
         self.parent = ancestor.parent.clone();
         self.node = Node::Rule(ancestor.rule_node.clone());
@@ -146,6 +160,50 @@ impl Cursor {
         start..end
     }
 
+    /// Returns the [`SyntaxNode`] of the currently pointed to node, linked to all of its ancestors.
+    ///
+    /// Note that the root of the syntax tree is the root of this cursor, which is not necessarily the root of the CST.
+    pub fn syntax_node(&self) -> SyntaxNode {
+        match &self.parent {
+            Some(parent) => SyntaxNode::new_child(
+                Self::ancestor_syntax_node(parent),
+                self.child_number,
+                self.text_offset,
+            ),
+            None => SyntaxNode::new_root_with_offset(self.node.clone(), self.text_offset),
+        }
+    }
+
+    /// Returns the comments and blank lines attached to the currently pointed to node.
+    ///
+    /// Trivia before or after the node is looked up through its ancestors, so the cursor should be created from the root of the tree.
+    /// See [`AttachedTrivia`] for the ownership rules.
+    pub fn attached_trivia(&self) -> AttachedTrivia {
+        trivia::attached_trivia(self)
+    }
+
+    /// Returns the root of this cursor, along with the child numbers leading from it to the current node.
+    pub(crate) fn root_and_path(&self) -> (Node, Vec<usize>) {
+        let mut path = vec![];
+
+        let root = if let Some(parent) = &self.parent {
+            path.push(self.child_number);
+
+            let mut ancestor = parent;
+            while let Some(grandparent) = &ancestor.parent {
+                path.push(ancestor.child_number);
+                ancestor = grandparent;
+            }
+
+            Node::Rule(ancestor.rule_node.clone())
+        } else {
+            self.node.clone()
+        };
+
+        path.reverse();
+        (root, path)
+    }
+
     /// Returns the depth of the current node in the CST, i.e. the number of ancestors.
     pub fn depth(&self) -> usize {
         let mut depth = 0;
@@ -404,6 +462,13 @@ impl Cursor {
         self.go_to_next_matching(|node| node.is_rule_with_kinds(kinds))
     }
 
+    /// Attempts to go to the next non-trivia token, according to the DFS pre-order traversal.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_next_non_trivia_token(&mut self) -> bool {
+        self.go_to_next_matching(|node| node.is_token() && !node.is_trivia())
+    }
+
     fn go_to_next_matching(&mut self, pred: impl Fn(&Node) -> bool) -> bool {
         while self.go_to_next() {
             if pred(&self.node) {
@@ -413,6 +478,192 @@ impl Cursor {
 
         false
     }
+
+    /// Attempts to go to the previous token, according to the DFS pre-order traversal.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_token(&mut self) -> bool {
+        self.go_to_previous_matching(|node| node.is_token())
+    }
+
+    /// Attempts to go to the previous token with the given kind, according to the DFS pre-order traversal.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_token_with_kind(&mut self, kind: TokenKind) -> bool {
+        self.go_to_previous_matching(|node| node.is_token_with_kind(kind))
+    }
+
+    /// Attempts to go to the previous token with any of the given kinds, according to the DFS pre-order traversal.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_token_with_kinds(&mut self, kinds: &[TokenKind]) -> bool {
+        self.go_to_previous_matching(|node| node.is_token_with_kinds(kinds))
+    }
+
+    /// Attempts to go to the previous non-trivia token, according to the DFS pre-order traversal.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_non_trivia_token(&mut self) -> bool {
+        self.go_to_previous_matching(|node| node.is_token() && !node.is_trivia())
+    }
+
+    /// Attempts to go to the previous rule, according to the DFS pre-order traversal.
+    /// Unlike [`Cursor::go_to_previous`], this includes the ancestors of the current node.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_rule(&mut self) -> bool {
+        self.go_to_previous_matching(|node| node.is_rule())
+    }
+
+    /// Attempts to go to the previous rule with the given kind, according to the DFS pre-order traversal.
+    /// Unlike [`Cursor::go_to_previous`], this includes the ancestors of the current node.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_rule_with_kind(&mut self, kind: RuleKind) -> bool {
+        self.go_to_previous_matching(|node| node.is_rule_with_kind(kind))
+    }
+
+    /// Attempts to go to the previous rule with any of the given kinds, according to the DFS pre-order traversal.
+    /// Unlike [`Cursor::go_to_previous`], this includes the ancestors of the current node.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_previous_rule_with_kinds(&mut self, kinds: &[RuleKind]) -> bool {
+        self.go_to_previous_matching(|node| node.is_rule_with_kinds(kinds))
+    }
+
+    fn go_to_previous_matching(&mut self, pred: impl Fn(&Node) -> bool) -> bool {
+        if self.is_completed {
+            return false;
+        }
+
+        loop {
+            // In pre-order, the previous node is the last descendant of the previous sibling, or otherwise the parent:
+            if self.go_to_previous_sibling() {
+                while self.go_to_last_child() {}
+            } else if !self.go_to_parent() {
+                // Like the other navigation methods, the cursor is finished once it is at the root:
+                self.is_completed = true;
+                return false;
+            }
+
+            if pred(&self.node) {
+                return true;
+            }
+        }
+    }
+
+    /// Attempts to go to the closest ancestor with the given kind.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_ancestor_with_kind(&mut self, kind: RuleKind) -> bool {
+        self.go_to_ancestor_matching(|node| node.is_rule_with_kind(kind))
+    }
+
+    /// Attempts to go to the closest ancestor with any of the given kinds.
+    ///
+    /// Returns `false` if the cursor is finished and at the root.
+    pub fn go_to_ancestor_with_kinds(&mut self, kinds: &[RuleKind]) -> bool {
+        self.go_to_ancestor_matching(|node| node.is_rule_with_kinds(kinds))
+    }
+
+    fn go_to_ancestor_matching(&mut self, pred: impl Fn(&Node) -> bool) -> bool {
+        if self.is_completed {
+            return false;
+        }
+
+        while self.go_to_parent() {
+            if pred(&self.node) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Attempts to go to the deepest node (i.e. a token) that contains the given offset.
+    /// If the offset is between two tokens, the one starting at it is preferred, unless it is at the end of the tree.
+    ///
+    /// The cursor first goes up to the closest ancestor containing the offset, so it can be used from any position.
+    /// Then it goes down using the length of each child, without visiting any other descendants.
+    ///
+    /// Returns `false` if the cursor is finished, or the offset is outside of the tree.
+    pub fn go_to_offset(&mut self, offset: TextIndex) -> bool {
+        if self.is_completed {
+            return false;
+        }
+
+        self.go_up_while(|range| !(range.start <= offset && offset < range.end));
+
+        let range = self.text_range();
+        if !(range.start <= offset && offset <= range.end) {
+            return false;
+        }
+
+        // At the end of the tree, go down to the last non-empty child:
+        let at_end = offset == range.end;
+
+        self.go_down_while(|child| {
+            (child.start <= offset && offset < child.end)
+                || (at_end && child.start < child.end && child.end == offset)
+        });
+
+        true
+    }
+
+    /// Attempts to go to the deepest node that fully contains the given range.
+    ///
+    /// Like [`Cursor::go_to_offset`], the cursor first goes up to the closest ancestor containing the range.
+    ///
+    /// Returns `false` if the cursor is finished, or the range is outside of the tree.
+    pub(crate) fn go_to_covering_node(&mut self, range: &TextRange) -> bool {
+        if self.is_completed {
+            return false;
+        }
+
+        let covers = |node: &TextRange| node.start <= range.start && range.end <= node.end;
+
+        self.go_up_while(|node| !covers(node));
+
+        if !covers(&self.text_range()) {
+            return false;
+        }
+
+        // Skip any empty children, as they are not more specific than their parent:
+        self.go_down_while(|child| child.start < child.end && covers(child));
+
+        true
+    }
+
+    /// Goes up to the closest ancestor (or the root) whose range doesn't satisfy the predicate.
+    fn go_up_while(&mut self, pred: impl Fn(&TextRange) -> bool) {
+        while pred(&self.text_range()) && self.parent.is_some() {
+            self.go_to_parent();
+        }
+    }
+
+    /// Goes down to the first child whose range satisfies the predicate, until none does.
+    fn go_down_while(&mut self, pred: impl Fn(&TextRange) -> bool) {
+        while let Some(new_parent) = self.as_ancestor_node() {
+            let mut child_offset = self.text_offset;
+            let selected = new_parent.rule_node.children.iter().enumerate().find_map(
+                |(child_number, child)| {
+                    let range = child_offset..child_offset + child.text_len();
+                    child_offset = range.end;
+
+                    pred(&range).then_some((child_number, range.start))
+                },
+            );
+
+            let Some((child_number, text_offset)) = selected else {
+                return;
+            };
+
+            self.node = new_parent.rule_node.children[child_number].node.clone();
+            self.child_number = child_number;
+            self.text_offset = text_offset;
+            self.parent = Some(new_parent);
+        }
+    }
 }
 
 /// A [`Cursor`] that also keeps track of the labels of the nodes it visits.
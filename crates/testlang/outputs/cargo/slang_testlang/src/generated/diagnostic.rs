@@ -18,6 +18,11 @@ pub trait Diagnostic: Error {
     fn code(&self) -> Box<dyn Display>;
     fn severity(&self) -> Severity;
     fn message(&self) -> String;
+
+    /// Additional information that can help resolve the diagnostic, if any.
+    fn note(&self) -> Option<String> {
+        None
+    }
 }
 
 #[cfg(feature = "__private_ariadne")]
@@ -36,22 +41,31 @@ pub fn render<D: Diagnostic>(error: &D, source_id: &str, source: &str, with_colo
     let color = if with_color { Color::Red } else { Color::Unset };
 
     let message = error.message();
+    let note = error.note();
 
     if source.is_empty() {
-        return format!("{kind}: {message}\n   ─[{source_id}:0:0]");
+        let note = note
+            .map(|note| format!("\n   Note: {note}"))
+            .unwrap_or_default();
+        return format!("{kind}: {message}\n   ─[{source_id}:0:0]{note}");
     }
 
     let range = error.range().char();
 
-    let report = Report::build(kind, source_id, range.start)
+    let mut report = Report::build(kind, source_id, range.start)
         .with_config(Config::default().with_color(with_color))
         .with_message(message)
         .with_label(
             Label::new((source_id, range))
                 .with_color(color)
                 .with_message("Error occurred here."),
-        )
-        .finish();
+        );
+
+    if let Some(note) = note {
+        report.set_note(note);
+    }
+
+    let report = report.finish();
 
     let mut result = vec![];
     report
@@ -0,0 +1,413 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! A compact binary encoding of parse outputs, that can be used to cache trees or send them between processes.
+//!
+//! The encoding starts with a header (a magic number, the format version, and the name and version of this crate,
+//! which identify the language), followed by the source text, the tree, and the errors. Kinds and labels are stored
+//! as integers, and token text is stored as a length into the source text, so it is only valid for the same language
+//! and version of this crate. All integers are encoded as variable-length (LEB128) numbers.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::cst::{LabeledNode, Node};
+use crate::kinds::{NodeLabel, RuleKind, TokenKind};
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::parse_output::ParseOutput;
+use crate::text_index::TextIndex;
+
+const MAGIC: &[u8; 4] = b"SLNG";
+
+/// The version of the encoding format. It is incremented whenever the format changes.
+pub const FORMAT_VERSION: u32 = 5;
+
+/// The maximum number of rules nested inside each other that can be decoded, which bounds the native stack
+/// needed to drop or traverse the decoded tree.
+pub const MAX_DEPTH: usize = 4096;
+
+const RULE_TAG: u8 = 0;
+const TOKEN_TAG: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input does not start with the expected magic number.
+    InvalidHeader,
+    /// The input was encoded with a different format version.
+    UnsupportedFormatVersion(u32),
+    /// The input was encoded by the crate of a different language, with different kinds.
+    UnsupportedLanguage(String),
+    /// The input was encoded by a different version of this crate, with possibly different kinds.
+    UnsupportedCrateVersion(String),
+    /// The input ended unexpectedly.
+    UnexpectedEnd,
+    /// The input contains rules nested deeper than [`MAX_DEPTH`].
+    MaxDepthExceeded,
+    /// The input contains invalid data at the given byte offset.
+    InvalidData(usize),
+}
+
+impl Error for DecodeError {}
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Input is not an encoded parse output."),
+            Self::UnsupportedFormatVersion(version) => {
+                write!(
+                    f,
+                    "Unsupported format version '{version}'. Expected '{FORMAT_VERSION}'."
+                )
+            }
+            Self::UnsupportedLanguage(name) => write!(
+                f,
+                "Input was encoded by crate '{name}'. Expected '{}'.",
+                env!("CARGO_PKG_NAME")
+            ),
+            Self::UnsupportedCrateVersion(version) => write!(
+                f,
+                "Input was encoded by version '{version}'. Expected '{}'.",
+                env!("CARGO_PKG_VERSION")
+            ),
+            Self::UnexpectedEnd => write!(f, "Unexpected end of input."),
+            Self::MaxDepthExceeded => write!(f, "Input is nested deeper than {MAX_DEPTH} rules."),
+            Self::InvalidData(offset) => write!(f, "Invalid data at byte offset {offset}."),
+        }
+    }
+}
+
+/// Encodes the parse output, including its errors.
+pub fn encode(output: &ParseOutput) -> Vec<u8> {
+    encode_parts(&output.parse_tree, &output.errors)
+}
+
+/// Decodes a parse output that was previously encoded using [`encode`].
+pub fn decode(bytes: &[u8]) -> Result<ParseOutput, DecodeError> {
+    let (parse_tree, errors) = decode_parts(bytes)?;
+
+    Ok(ParseOutput { parse_tree, errors })
+}
+
+/// Encodes a single tree, without any errors.
+pub fn encode_tree(node: &Node) -> Vec<u8> {
+    encode_parts(node, &[])
+}
+
+/// Decodes a tree that was previously encoded using either [`encode_tree`] or [`encode`], ignoring any errors.
+pub fn decode_tree(bytes: &[u8]) -> Result<Node, DecodeError> {
+    let (tree, _) = decode_parts(bytes)?;
+
+    Ok(tree)
+}
+
+fn encode_parts(tree: &Node, errors: &[ParseError]) -> Vec<u8> {
+    let mut encoder = Encoder { bytes: vec![] };
+
+    encoder.bytes.extend_from_slice(MAGIC);
+    encoder.number(FORMAT_VERSION as usize);
+    encoder.string(env!("CARGO_PKG_NAME"));
+    encoder.string(env!("CARGO_PKG_VERSION"));
+
+    encoder.string(&tree.clone().unparse());
+    encoder.node(tree);
+
+    encoder.number(errors.len());
+    for error in errors {
+        encoder.number(error.text_range.start.utf8);
+        encoder.number(error.text_range.end.utf8);
+        encoder.number(error.kind as usize);
+        encoder.number(error.rule_kind.map_or(0, |kind| kind as usize + 1));
+
+        let expected = &error.tokens_that_would_have_allowed_more_progress;
+        encoder.number(expected.len());
+        for kind in expected {
+            encoder.number(*kind as usize);
+        }
+
+        match &error.note {
+            Some(note) => {
+                encoder.number(1);
+                encoder.string(note);
+            }
+            None => encoder.number(0),
+        }
+    }
+
+    encoder.bytes
+}
+
+fn decode_parts(bytes: &[u8]) -> Result<(Node, Vec<ParseError>), DecodeError> {
+    if !bytes.starts_with(MAGIC) {
+        return Err(DecodeError::InvalidHeader);
+    }
+
+    let mut decoder = Decoder {
+        bytes,
+        position: MAGIC.len(),
+        source: "",
+        source_offset: 0,
+    };
+
+    let format_version = decoder.number()?;
+    if format_version != FORMAT_VERSION as usize {
+        return Err(DecodeError::UnsupportedFormatVersion(
+            u32::try_from(format_version).unwrap_or(u32::MAX),
+        ));
+    }
+
+    let crate_name = decoder.string()?;
+    if crate_name != env!("CARGO_PKG_NAME") {
+        return Err(DecodeError::UnsupportedLanguage(crate_name.to_owned()));
+    }
+
+    let crate_version = decoder.string()?;
+    if crate_version != env!("CARGO_PKG_VERSION") {
+        return Err(DecodeError::UnsupportedCrateVersion(
+            crate_version.to_owned(),
+        ));
+    }
+
+    decoder.source = decoder.string()?;
+
+    let tree = decoder.node()?;
+    if decoder.source_offset != decoder.source.len() {
+        return Err(decoder.invalid());
+    }
+
+    let error_count = decoder.number()?;
+    let mut errors = vec![];
+    for _ in 0..error_count {
+        let start = decoder.text_index()?;
+        let end = decoder.text_index()?;
+        let kind = decoder.kind(ParseErrorKind::from_repr)?;
+        let rule_kind = match decoder.number()? {
+            0 => None,
+            kind => Some(RuleKind::from_repr(kind - 1).ok_or_else(|| decoder.invalid())?),
+        };
+
+        let expected_count = decoder.number()?;
+        let mut expected = vec![];
+        for _ in 0..expected_count {
+            expected.push(decoder.kind(TokenKind::from_repr)?);
+        }
+
+        let note = match decoder.number()? {
+            0 => None,
+            1 => Some(decoder.string()?.to_owned()),
+            _ => return Err(decoder.invalid()),
+        };
+
+        errors.push(ParseError {
+            text_range: start..end,
+            tokens_that_would_have_allowed_more_progress: expected,
+            kind,
+            rule_kind,
+            note,
+        });
+    }
+
+    if decoder.position != bytes.len() {
+        return Err(decoder.invalid());
+    }
+
+    Ok((tree, errors))
+}
+
+struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    fn number(&mut self, mut value: usize) {
+        loop {
+            let byte = u8::try_from(value & 0x7F).expect("masked to 7 bits");
+            value >>= 7;
+
+            if value == 0 {
+                self.bytes.push(byte);
+                return;
+            }
+
+            self.bytes.push(byte | 0x80);
+        }
+    }
+
+    fn string(&mut self, value: &str) {
+        self.number(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node {
+            Node::Rule(rule) => {
+                self.bytes.push(RULE_TAG);
+                self.number(rule.kind as usize);
+                self.number(rule.children.len());
+
+                for child in &rule.children {
+                    // Zero is reserved for unlabeled children:
+                    self.number(child.label.map_or(0, |label| label as usize + 1));
+                    self.node(&child.node);
+                }
+            }
+            Node::Token(token) => {
+                // The text itself is stored once in the source, so only its length is needed:
+                self.bytes.push(TOKEN_TAG);
+                self.number(token.kind as usize);
+                self.number(token.text.len());
+            }
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    source: &'a str,
+    source_offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn invalid(&self) -> DecodeError {
+        DecodeError::InvalidData(self.position)
+    }
+
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self
+            .bytes
+            .get(self.position)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn number(&mut self) -> Result<usize, DecodeError> {
+        let mut value: usize = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.byte()?;
+            let bits = usize::from(byte & 0x7F);
+
+            if shift >= usize::BITS || (bits << shift) >> shift != bits {
+                return Err(self.invalid());
+            }
+
+            value |= bits << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, DecodeError> {
+        let len = self.number()?;
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(DecodeError::UnexpectedEnd)?;
+
+        let value =
+            std::str::from_utf8(&self.bytes[self.position..end]).map_err(|_| self.invalid())?;
+
+        self.position = end;
+        Ok(value)
+    }
+
+    fn kind<T>(&mut self, from_repr: impl Fn(usize) -> Option<T>) -> Result<T, DecodeError> {
+        let value = self.number()?;
+        from_repr(value).ok_or_else(|| self.invalid())
+    }
+
+    fn text_index(&mut self) -> Result<TextIndex, DecodeError> {
+        let utf8 = self.number()?;
+
+        match self.source.get(..utf8) {
+            Some(prefix) => Ok(TextIndex::from(prefix)),
+            None => Err(self.invalid()),
+        }
+    }
+
+    fn label(&mut self) -> Result<Option<NodeLabel>, DecodeError> {
+        // Zero is reserved for unlabeled children:
+        match self.number()? {
+            0 => Ok(None),
+            label => NodeLabel::from_repr(label - 1)
+                .map(Some)
+                .ok_or_else(|| self.invalid()),
+        }
+    }
+
+    /// Decodes a node iteratively, so that deeply nested input cannot overflow the native stack.
+    fn node(&mut self) -> Result<Node, DecodeError> {
+        // Rules that are still missing some of their children, innermost last:
+        let mut parents: Vec<PendingRule> = vec![];
+
+        loop {
+            let mut node = match self.byte()? {
+                RULE_TAG => {
+                    if parents.len() == MAX_DEPTH {
+                        return Err(DecodeError::MaxDepthExceeded);
+                    }
+
+                    let kind = self.kind(RuleKind::from_repr)?;
+                    let child_count = self.number()?;
+
+                    parents.push(PendingRule {
+                        kind,
+                        child_count,
+                        children: Vec::with_capacity(child_count.min(self.bytes.len())),
+                        next_label: None,
+                    });
+
+                    None
+                }
+                TOKEN_TAG => {
+                    let kind = self.kind(TokenKind::from_repr)?;
+
+                    let len = self.number()?;
+                    let text = self
+                        .source_offset
+                        .checked_add(len)
+                        .and_then(|end| self.source.get(self.source_offset..end))
+                        .ok_or_else(|| self.invalid())?;
+
+                    self.source_offset += len;
+                    Some(Node::token(kind, text.to_owned()))
+                }
+                _ => return Err(self.invalid()),
+            };
+
+            // Attach the decoded node to its parent, completing any parents that have all of their children:
+            loop {
+                let Some(parent) = parents.last_mut() else {
+                    return Ok(node.expect("The root is only left once it is complete."));
+                };
+
+                if let Some(node) = node.take() {
+                    parent.children.push(LabeledNode {
+                        label: parent.next_label.take(),
+                        node,
+                    });
+                }
+
+                if parent.children.len() < parent.child_count {
+                    parent.next_label = self.label()?;
+                    break;
+                }
+
+                let parent = parents.pop().expect("Checked above.");
+                node = Some(Node::rule(parent.kind, parent.children));
+            }
+        }
+    }
+}
+
+struct PendingRule {
+    kind: RuleKind,
+    child_count: usize,
+    children: Vec<LabeledNode>,
+    next_label: Option<NodeLabel>,
+}
@@ -10,10 +10,12 @@ use napi_derive::napi;
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
@@ -33,6 +35,30 @@ pub enum RuleKind {
     TreeNodeChildren,
 }
 
+impl RuleKind {
+    /// Whether nodes of this kind are lists, whose children are labeled as items (and separators, if any).
+    pub fn is_list(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::SourceUnitMembers => true,
+            Self::TreeNodeChildren => true,
+            Self::SeparatedIdentifiers => true,
+            _ => false,
+        }
+    }
+
+    /// The kinds of the tokens that are variants of this rule, if it is a choice (e.g. the units of a number).
+    #[allow(clippy::too_many_lines)]
+    pub fn token_variants(&self) -> &'static [TokenKind] {
+        match self {
+            Self::TreeNodeChild => &[TokenKind::DelimitedIdentifier],
+            Self::Expression => &[TokenKind::StringLiteral, TokenKind::Identifier],
+            Self::Literal => &[TokenKind::StringLiteral],
+            _ => &[],
+        }
+    }
+}
+
 #[derive(
     Debug,
     Eq,
@@ -40,10 +66,12 @@ pub enum RuleKind {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[strum(serialize_all = "snake_case")]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
@@ -78,15 +106,19 @@ pub enum NodeLabel {
     Ord,
     PartialEq,
     PartialOrd,
+    serde::Deserialize,
     serde::Serialize,
     strum_macros::AsRefStr,
     strum_macros::Display,
     strum_macros::EnumString,
+    strum_macros::FromRepr,
 )]
 #[cfg_attr(feature = "slang_napi_interfaces", /* derives `Clone` and `Copy` */ napi(string_enum, namespace = "kinds"))]
 #[cfg_attr(not(feature = "slang_napi_interfaces"), derive(Clone, Copy))]
 pub enum TokenKind {
     SKIPPED,
+    /// A zero-width token inserted by error recovery, in place of an expected token that was missing.
+    MISSING,
     Bang,
     CloseBracket,
     DelimitedIdentifier,
@@ -114,11 +146,59 @@ impl TokenKind {
             _ => false,
         }
     }
+
+    /// Whether this token is a keyword of the language, even if it is only reserved in some versions.
+    #[allow(clippy::too_many_lines)]
+    pub fn is_keyword(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::TreeKeyword => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token opens a delimited group (e.g. `(`), which is incomplete until it is closed.
+    pub fn is_open_delimiter(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::OpenBracket => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token closes a delimited group (e.g. `)`).
+    pub fn is_close_delimiter(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::CloseBracket => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this token terminates a construct (e.g. `;`).
+    pub fn is_terminator(&self) -> bool {
+        #[allow(clippy::match_like_matches_macro)]
+        match self {
+            Self::Semicolon => true,
+            _ => false,
+        }
+    }
 }
 
-/// The lexical context of the scanner.
-#[derive(strum_macros::FromRepr, Clone, Copy)]
-pub(crate) enum LexicalContext {
+/// The lexical context of the scanner, which determines the set of tokens that can be recognized.
+#[derive(
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum_macros::AsRefStr,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::FromRepr,
+    Clone,
+    Copy,
+)]
+pub enum LexicalContext {
     Default,
     Tree,
 }
@@ -7,6 +7,7 @@
     clippy::unused_self,
     clippy::struct_excessive_bools,
     clippy::similar_names,
+    clippy::match_single_binding,
     unused_imports
 )]
 
@@ -14,6 +15,7 @@
 use napi_derive::napi;
 use semver::Version;
 
+use crate::compatibility::{self, KeywordPattern, VersionRange, VersionedKeyword};
 use crate::cst;
 use crate::kinds::{
     IsLexicalContext, LexicalContext, LexicalContextType, NodeLabel, RuleKind, TokenKind,
@@ -21,11 +23,15 @@ use crate::kinds::{
 use crate::lexer::{KeywordScan, Lexer, ScannedToken};
 #[cfg(feature = "slang_napi_interfaces")]
 use crate::napi_interface::parse_output::ParseOutput as NAPIParseOutput;
+use crate::parse_options::ParseOptions;
 use crate::parse_output::ParseOutput;
 use crate::parser_support::{
-    ChoiceHelper, OneOrMoreHelper, OptionalHelper, ParserContext, ParserFunction, ParserResult,
-    PrecedenceHelper, SeparatedHelper, SequenceHelper, TokenAcceptanceThreshold, ZeroOrMoreHelper,
+    reparse, ChoiceHelper, OneOrMoreHelper, OptionalHelper, ParserContext, ParserFunction,
+    ParserResult, PrecedenceHelper, SeparatedHelper, SequenceHelper, TokenAcceptanceThreshold,
+    ZeroOrMoreHelper,
 };
+use crate::text_index::TextEdit;
+use crate::tokenizer::Tokenizer;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "slang_napi_interfaces", napi(namespace = "language"))]
@@ -74,353 +80,444 @@ impl Language {
         &self.version
     }
 
+    /********************************************
+     *         Version Compatibility
+     ********************************************/
+
+    pub(crate) const NAME: &'static str = "Testlang";
+
+    /// The versions in which constructs are added to or removed from the language, in ascending order.
+    pub(crate) const BREAKING_VERSIONS: &'static [Version] = &[Version::new(1, 0, 0)];
+
+    /// Returns the versions rules of this kind are enabled in, or `None` if they are enabled in all versions.
+    pub(crate) fn rule_kind_versions(kind: RuleKind) -> Option<&'static [VersionRange]> {
+        match kind {
+            RuleKind::SeparatedIdentifiers => Some(&[VersionRange {
+                from: Some((1, 0, 0)),
+                till: None,
+            }]),
+            _ => None,
+        }
+    }
+
+    /// Returns the versions tokens of this kind are scanned in, or `None` if they are scanned in all versions.
+    pub(crate) fn token_kind_versions(kind: TokenKind) -> Option<&'static [VersionRange]> {
+        match kind {
+            _ => None,
+        }
+    }
+
+    /// Returns the versions an optional field of a rule is enabled in, or `None` if it is enabled in all versions.
+    pub(crate) fn field_versions(
+        kind: RuleKind,
+        label: NodeLabel,
+    ) -> Option<&'static [VersionRange]> {
+        match (kind, label) {
+            _ => None,
+        }
+    }
+
+    /// Returns the versions a variant of a rule is enabled in, or `None` if it is enabled in all versions.
+    pub(crate) fn variant_versions(
+        kind: RuleKind,
+        variant: &str,
+    ) -> Option<&'static [VersionRange]> {
+        match (kind, variant) {
+            _ => None,
+        }
+    }
+
+    /// Returns the keywords that can be used as identifiers of this kind in some versions,
+    /// and the versions in which the words matching them can be.
+    pub(crate) fn unreserved_keywords(
+        kind: TokenKind,
+    ) -> &'static [(KeywordPattern, &'static [VersionRange])] {
+        match kind {
+            _ => &[],
+        }
+    }
+
+    pub(crate) const VERSIONED_KEYWORDS: &'static [VersionedKeyword] = &[];
+
     /********************************************
      *         Parser Functions
      ********************************************/
 
     #[allow(unused_assignments, unused_parens)]
     fn addition_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::AdditionExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::AdditionExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let parse_left_addition_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_binary_operator(
-                RuleKind::AdditionExpression,
-                1u8,
-                1u8 + 1,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Plus)
+        input.nested(|input| {
+            let parse_left_addition_expression = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_binary_operator(
+                    RuleKind::AdditionExpression,
+                    1u8,
+                    1u8 + 1,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Plus,
+                    )
                     .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_prefix_negation_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_prefix_operator(
-                RuleKind::NegationExpression,
-                3u8,
-                self.parse_token_with_trivia::<LexicalContextType::Default>(input, TokenKind::Bang)
+                )
+            };
+            let parse_prefix_negation_expression = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_prefix_operator(
+                    RuleKind::NegationExpression,
+                    3u8,
+                    self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Bang,
+                    )
                     .with_label(NodeLabel::Operator),
-            )
-        };
-        let parse_postfix_member_access_expression = |input: &mut ParserContext<'_>| {
-            PrecedenceHelper::to_postfix_operator(
-                RuleKind::MemberAccessExpression,
-                5u8,
+                )
+            };
+            let parse_postfix_member_access_expression = |input: &mut ParserContext<'_>| {
+                PrecedenceHelper::to_postfix_operator(
+                    RuleKind::MemberAccessExpression,
+                    5u8,
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(
+                            NodeLabel::Period,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Period,
+                            ),
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::Member,
+                            self.parse_token_with_trivia::<LexicalContextType::Default>(
+                                input,
+                                TokenKind::Identifier,
+                            ),
+                        )?;
+                        seq.finish()
+                    }),
+                )
+            };
+            let prefix_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_prefix_negation_expression(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let primary_expression_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::StringLiteral,
+                    );
+                    choice.consider(input, result)?;
+                    let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
+                        input,
+                        TokenKind::Identifier,
+                    );
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+                .with_label(NodeLabel::Variant)
+            };
+            let postfix_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_postfix_member_access_expression(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let binary_operand_parser = |input: &mut ParserContext<'_>| {
                 SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::Period,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Period,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Member,
-                        self.parse_token_with_trivia::<LexicalContextType::Default>(
-                            input,
-                            TokenKind::Identifier,
-                        ),
-                    )?;
+                    seq.elem(ZeroOrMoreHelper::run(input, prefix_operator_parser))?;
+                    seq.elem(primary_expression_parser(input))?;
+                    seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
+                    seq.finish()
+                })
+            };
+            let binary_operator_parser = |input: &mut ParserContext<'_>| {
+                ChoiceHelper::run(input, |mut choice, input| {
+                    let result = parse_left_addition_expression(input);
+                    choice.consider(input, result)?;
+                    choice.finish(input)
+                })
+            };
+            let linear_expression_parser = |input: &mut ParserContext<'_>| {
+                SequenceHelper::run(|mut seq| {
+                    seq.elem(binary_operand_parser(input))?;
+                    seq.elem(ZeroOrMoreHelper::run(input, |input| {
+                        SequenceHelper::run(|mut seq| {
+                            seq.elem(binary_operator_parser(input))?;
+                            seq.elem(binary_operand_parser(input))?;
+                            seq.finish()
+                        })
+                    }))?;
                     seq.finish()
-                }),
+                })
+            };
+            PrecedenceHelper::reduce_precedence_result(
+                RuleKind::Expression,
+                linear_expression_parser(input),
             )
-        };
-        let prefix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_prefix_negation_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let primary_expression_parser = |input: &mut ParserContext<'_>| {
+            .with_kind(RuleKind::Expression)
+        })
+    }
+
+    #[allow(unused_assignments, unused_parens)]
+    fn literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
+        input.nested(|input| {
             ChoiceHelper::run(input, |mut choice, input| {
                 let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
                     input,
                     TokenKind::StringLiteral,
                 );
                 choice.consider(input, result)?;
-                let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                    input,
-                    TokenKind::Identifier,
-                );
-                choice.consider(input, result)?;
                 choice.finish(input)
             })
             .with_label(NodeLabel::Variant)
-        };
-        let postfix_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_postfix_member_access_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let binary_operand_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(ZeroOrMoreHelper::run(input, prefix_operator_parser))?;
-                seq.elem(primary_expression_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, postfix_operator_parser))?;
-                seq.finish()
-            })
-        };
-        let binary_operator_parser = |input: &mut ParserContext<'_>| {
-            ChoiceHelper::run(input, |mut choice, input| {
-                let result = parse_left_addition_expression(input);
-                choice.consider(input, result)?;
-                choice.finish(input)
-            })
-        };
-        let linear_expression_parser = |input: &mut ParserContext<'_>| {
-            SequenceHelper::run(|mut seq| {
-                seq.elem(binary_operand_parser(input))?;
-                seq.elem(ZeroOrMoreHelper::run(input, |input| {
-                    SequenceHelper::run(|mut seq| {
-                        seq.elem(binary_operator_parser(input))?;
-                        seq.elem(binary_operand_parser(input))?;
-                        seq.finish()
-                    })
-                }))?;
-                seq.finish()
-            })
-        };
-        PrecedenceHelper::reduce_precedence_result(
-            RuleKind::Expression,
-            linear_expression_parser(input),
-        )
-        .with_kind(RuleKind::Expression)
-    }
-
-    #[allow(unused_assignments, unused_parens)]
-    fn literal(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.parse_token_with_trivia::<LexicalContextType::Default>(
-                input,
-                TokenKind::StringLiteral,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+            .with_kind(RuleKind::Literal)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::Literal)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn member_access_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::MemberAccessExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::MemberAccessExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn negation_expression(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        let result = self.expression(input);
-        let ParserResult::Match(r#match) = &result else {
-            return result;
-        };
-        match &r#match.nodes[..] {
-            [cst::LabeledNode {
-                label: _,
-                node: cst::Node::Rule(node),
-            }] if node.kind == RuleKind::Expression => match &node.children[..] {
-                [inner @ cst::LabeledNode {
+        input.nested(|input| {
+            let result = self.expression(input);
+            let ParserResult::Match(r#match) = &result else {
+                return result;
+            };
+            match &r#match.nodes[..] {
+                [cst::LabeledNode {
                     label: _,
-                    node: cst::Node::Rule(rule),
-                }] if rule.kind == RuleKind::NegationExpression => {
-                    ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
-                }
+                    node: cst::Node::Rule(node),
+                }] if node.kind == RuleKind::Expression => match &node.children[..] {
+                    [inner @ cst::LabeledNode {
+                        label: _,
+                        node: cst::Node::Rule(rule),
+                    }] if rule.kind == RuleKind::NegationExpression => {
+                        ParserResult::r#match(vec![inner.clone()], r#match.expected_tokens.clone())
+                    }
+                    _ => ParserResult::no_match(vec![]),
+                },
                 _ => ParserResult::no_match(vec![]),
-            },
-            _ => ParserResult::no_match(vec![]),
-        }
+            }
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn separated_identifiers(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        if self.version_is_at_least_1_0_0 {
-            SeparatedHelper::run::<_, LexicalContextType::Default>(
-                input,
-                self,
-                |input| {
-                    self.parse_token_with_trivia::<LexicalContextType::Default>(
-                        input,
-                        TokenKind::Identifier,
-                    )
-                    .with_label(NodeLabel::Item)
-                },
-                TokenKind::Period,
-                NodeLabel::Separator,
-            )
-        } else {
-            ParserResult::disabled()
-        }
-        .with_kind(RuleKind::SeparatedIdentifiers)
+        input.nested(|input| {
+            if self.version_is_at_least_1_0_0 {
+                SeparatedHelper::run::<_, LexicalContextType::Default>(
+                    input,
+                    self,
+                    |input| {
+                        self.parse_token_with_trivia::<LexicalContextType::Default>(
+                            input,
+                            TokenKind::Identifier,
+                        )
+                        .with_label(NodeLabel::Item)
+                    },
+                    TokenKind::Period,
+                    NodeLabel::Separator,
+                )
+            } else {
+                ParserResult::disabled()
+            }
+            .with_kind(RuleKind::SeparatedIdentifiers)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        self.source_unit_members(input)
-            .with_label(NodeLabel::Members)
-            .with_kind(RuleKind::SourceUnit)
+        input.nested(|input| {
+            self.source_unit_members(input)
+                .with_label(NodeLabel::Members)
+                .with_kind(RuleKind::SourceUnit)
+        })
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit_member(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.tree(input);
-            choice.consider(input, result)?;
-            let result = self.expression(input);
-            choice.consider(input, result)?;
-            let result = self.separated_identifiers(input);
-            choice.consider(input, result)?;
-            let result = self.literal(input);
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.tree(input);
+                choice.consider(input, result)?;
+                let result = self.expression(input);
+                choice.consider(input, result)?;
+                let result = self.separated_identifiers(input);
+                choice.consider(input, result)?;
+                let result = self.literal(input);
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::SourceUnitMember)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::SourceUnitMember)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn source_unit_members(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OneOrMoreHelper::run(input, |input| {
-            self.source_unit_member(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            OneOrMoreHelper::run(input, |input| {
+                self.source_unit_member(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::SourceUnitMembers)
         })
-        .with_kind(RuleKind::SourceUnitMembers)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn tree(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            seq.elem(
-                SequenceHelper::run(|mut seq| {
-                    seq.elem_labeled(
-                        NodeLabel::Keyword,
-                        self.parse_token_with_trivia::<LexicalContextType::Tree>(
-                            input,
-                            TokenKind::TreeKeyword,
-                        ),
-                    )?;
-                    seq.elem_labeled(
-                        NodeLabel::Name,
-                        OptionalHelper::transform(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                seq.elem(
+                    SequenceHelper::run(|mut seq| {
+                        seq.elem_labeled(
+                            NodeLabel::Keyword,
                             self.parse_token_with_trivia::<LexicalContextType::Tree>(
                                 input,
-                                TokenKind::Identifier,
+                                TokenKind::TreeKeyword,
                             ),
-                        ),
-                    )?;
-                    seq.elem_labeled(NodeLabel::Node, self.tree_node(input))?;
-                    seq.finish()
-                })
-                .recover_until_with_nested_delims::<_, LexicalContextType::Tree>(
-                    input,
-                    self,
-                    TokenKind::Semicolon,
-                    TokenAcceptanceThreshold(1u8),
-                ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::Semicolon,
-                self.parse_token_with_trivia::<LexicalContextType::Tree>(
-                    input,
-                    TokenKind::Semicolon,
-                ),
-            )?;
-            seq.finish()
+                        )?;
+                        seq.elem_labeled(
+                            NodeLabel::Name,
+                            OptionalHelper::transform(
+                                self.parse_token_with_trivia::<LexicalContextType::Tree>(
+                                    input,
+                                    TokenKind::Identifier,
+                                ),
+                            ),
+                        )?;
+                        seq.elem_labeled(NodeLabel::Node, self.tree_node(input))?;
+                        seq.finish()
+                    })
+                    .recover_until_with_nested_delims::<_, LexicalContextType::Tree>(
+                        input,
+                        self,
+                        TokenKind::Semicolon,
+                        TokenAcceptanceThreshold(1u8),
+                    ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::Semicolon,
+                    self.parse_token_with_trivia::<LexicalContextType::Tree>(
+                        input,
+                        TokenKind::Semicolon,
+                    ),
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::Tree)
         })
-        .with_kind(RuleKind::Tree)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn tree_node(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        SequenceHelper::run(|mut seq| {
-            let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
-            let input = delim_guard.ctx();
-            seq.elem_labeled(
-                NodeLabel::OpenBracket,
-                self.parse_token_with_trivia::<LexicalContextType::Tree>(
-                    input,
-                    TokenKind::OpenBracket,
-                ),
-            )?;
-            seq.elem(
-                self.tree_node_children(input)
-                    .with_label(NodeLabel::Members)
-                    .recover_until_with_nested_delims::<_, LexicalContextType::Tree>(
+        input.nested(|input| {
+            SequenceHelper::run(|mut seq| {
+                let mut delim_guard = input.open_delim(TokenKind::CloseBracket);
+                let input = delim_guard.ctx();
+                seq.elem_labeled(
+                    NodeLabel::OpenBracket,
+                    self.parse_token_with_trivia::<LexicalContextType::Tree>(
+                        input,
+                        TokenKind::OpenBracket,
+                    ),
+                )?;
+                seq.elem(
+                    self.tree_node_children(input)
+                        .with_label(NodeLabel::Members)
+                        .recover_until_with_nested_delims::<_, LexicalContextType::Tree>(
+                            input,
+                            self,
+                            TokenKind::CloseBracket,
+                            TokenAcceptanceThreshold(0u8),
+                        ),
+                )?;
+                seq.elem_labeled(
+                    NodeLabel::CloseBracket,
+                    self.parse_token_with_trivia::<LexicalContextType::Tree>(
                         input,
-                        self,
                         TokenKind::CloseBracket,
-                        TokenAcceptanceThreshold(0u8),
                     ),
-            )?;
-            seq.elem_labeled(
-                NodeLabel::CloseBracket,
-                self.parse_token_with_trivia::<LexicalContextType::Tree>(
-                    input,
-                    TokenKind::CloseBracket,
-                ),
-            )?;
-            seq.finish()
+                )?;
+                seq.finish()
+            })
+            .with_kind(RuleKind::TreeNode)
         })
-        .with_kind(RuleKind::TreeNode)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn tree_node_child(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        ChoiceHelper::run(input, |mut choice, input| {
-            let result = self.tree_node(input);
-            choice.consider(input, result)?;
-            let result = self.parse_token_with_trivia::<LexicalContextType::Tree>(
-                input,
-                TokenKind::DelimitedIdentifier,
-            );
-            choice.consider(input, result)?;
-            choice.finish(input)
+        input.nested(|input| {
+            ChoiceHelper::run(input, |mut choice, input| {
+                let result = self.tree_node(input);
+                choice.consider(input, result)?;
+                let result = self.parse_token_with_trivia::<LexicalContextType::Tree>(
+                    input,
+                    TokenKind::DelimitedIdentifier,
+                );
+                choice.consider(input, result)?;
+                choice.finish(input)
+            })
+            .with_label(NodeLabel::Variant)
+            .with_kind(RuleKind::TreeNodeChild)
         })
-        .with_label(NodeLabel::Variant)
-        .with_kind(RuleKind::TreeNodeChild)
     }
 
     #[allow(unused_assignments, unused_parens)]
     fn tree_node_children(&self, input: &mut ParserContext<'_>) -> ParserResult {
-        OneOrMoreHelper::run(input, |input| {
-            self.tree_node_child(input).with_label(NodeLabel::Item)
+        input.nested(|input| {
+            OneOrMoreHelper::run(input, |input| {
+                self.tree_node_child(input).with_label(NodeLabel::Item)
+            })
+            .with_kind(RuleKind::TreeNodeChildren)
         })
-        .with_kind(RuleKind::TreeNodeChildren)
     }
 
     #[allow(unused_assignments, unused_parens)]
@@ -647,20 +744,74 @@ impl Language {
     // Keyword scanners
 
     pub fn parse(&self, kind: RuleKind, input: &str) -> ParseOutput {
-        match kind {
-            RuleKind::AdditionExpression => Self::addition_expression.parse(self, input),
-            RuleKind::Expression => Self::expression.parse(self, input),
-            RuleKind::Literal => Self::literal.parse(self, input),
-            RuleKind::MemberAccessExpression => Self::member_access_expression.parse(self, input),
-            RuleKind::NegationExpression => Self::negation_expression.parse(self, input),
-            RuleKind::SeparatedIdentifiers => Self::separated_identifiers.parse(self, input),
-            RuleKind::SourceUnit => Self::source_unit.parse(self, input),
-            RuleKind::SourceUnitMember => Self::source_unit_member.parse(self, input),
-            RuleKind::SourceUnitMembers => Self::source_unit_members.parse(self, input),
-            RuleKind::Tree => Self::tree.parse(self, input),
-            RuleKind::TreeNode => Self::tree_node.parse(self, input),
-            RuleKind::TreeNodeChild => Self::tree_node_child.parse(self, input),
-            RuleKind::TreeNodeChildren => Self::tree_node_children.parse(self, input),
+        self.parse_with_options(kind, input, &ParseOptions::default())
+    }
+
+    /// Same as [`Language::parse`], but stops early with an error if any of the limits in `options` is hit,
+    /// instead of overflowing the stack or running for too long on malicious input.
+    pub fn parse_with_options(
+        &self,
+        kind: RuleKind,
+        input: &str,
+        options: &ParseOptions,
+    ) -> ParseOutput {
+        let mut output = match kind {
+            RuleKind::AdditionExpression => Self::addition_expression.parse(self, input, options),
+            RuleKind::Expression => Self::expression.parse(self, input, options),
+            RuleKind::Literal => Self::literal.parse(self, input, options),
+            RuleKind::MemberAccessExpression => {
+                Self::member_access_expression.parse(self, input, options)
+            }
+            RuleKind::NegationExpression => Self::negation_expression.parse(self, input, options),
+            RuleKind::SeparatedIdentifiers => {
+                Self::separated_identifiers.parse(self, input, options)
+            }
+            RuleKind::SourceUnit => Self::source_unit.parse(self, input, options),
+            RuleKind::SourceUnitMember => Self::source_unit_member.parse(self, input, options),
+            RuleKind::SourceUnitMembers => Self::source_unit_members.parse(self, input, options),
+            RuleKind::Tree => Self::tree.parse(self, input, options),
+            RuleKind::TreeNode => Self::tree_node.parse(self, input, options),
+            RuleKind::TreeNodeChild => Self::tree_node_child.parse(self, input, options),
+            RuleKind::TreeNodeChildren => Self::tree_node_children.parse(self, input, options),
+        };
+
+        compatibility::add_version_notes(self, &mut output);
+        output
+    }
+
+    /// Parses the `input` after applying the `edit` to the source of the `previous` parse output,
+    /// re-parsing only the innermost delimited group (e.g. a block) containing the edit, and sharing the rest of the tree.
+    ///
+    /// The result is identical to calling [`Language::parse`] with the same `kind` and `input`.
+    /// If the `previous` output is not valid, or was not produced by parsing the same `kind`, the entire input is parsed again.
+    pub fn reparse(
+        &self,
+        kind: RuleKind,
+        previous: &ParseOutput,
+        edit: &TextEdit,
+        input: &str,
+    ) -> ParseOutput {
+        const DELIMITERS: &[(TokenKind, TokenKind)] =
+            &[(TokenKind::OpenBracket, TokenKind::CloseBracket)];
+
+        reparse(kind, previous, edit, input, DELIMITERS, |kind, input| {
+            self.parse(kind, input)
+        })
+    }
+
+    /// Scans the `input` into tokens of the given lexical context, including trivia, without parsing it.
+    pub fn tokenize<'a>(&'a self, input: &'a str, context: LexicalContext) -> Tokenizer<'a> {
+        Tokenizer::new(self, input, context)
+    }
+
+    pub(crate) fn next_token_in_context(
+        &self,
+        context: LexicalContext,
+        input: &mut ParserContext<'_>,
+    ) -> Option<ScannedToken> {
+        match context {
+            LexicalContext::Default => self.next_token::<LexicalContextType::Default>(input),
+            LexicalContext::Tree => self.next_token::<LexicalContextType::Tree>(input),
         }
     }
 }
@@ -685,6 +836,10 @@ impl Lexer for Language {
         &self,
         input: &mut ParserContext<'_>,
     ) -> Option<ScannedToken> {
+        if !input.scan_token() {
+            return None;
+        }
+
         let save = input.position();
         let mut furthest_position = input.position();
         let mut longest_token = None;
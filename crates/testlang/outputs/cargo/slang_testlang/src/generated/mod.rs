@@ -3,16 +3,25 @@
 #[macro_use]
 pub mod parser_support;
 
+pub mod ast;
+pub mod compatibility;
 pub mod cst;
 pub mod cursor;
 pub mod diagnostic;
+pub mod encoding;
 pub mod kinds;
 pub mod language;
 pub(crate) mod lexer;
 pub mod parse_error;
+pub mod parse_options;
 pub mod parse_output;
 pub mod query;
+pub mod rewriter;
+pub mod syntax_tree;
 pub mod text_index;
+pub mod tokenizer;
+pub mod trivia;
+pub mod visitor;
 
 #[cfg(feature = "slang_napi_interfaces")]
 pub mod napi_interface;
@@ -119,6 +119,14 @@ impl Cursor {
         self.0.go_to_nth_child(child_number as usize)
     }
 
+    #[napi(catch_unwind)]
+    pub fn go_to_offset(
+        &mut self,
+        #[napi(ts_arg_type = "text_index.TextIndex")] offset: TextIndex,
+    ) -> bool {
+        self.0.go_to_offset(offset.into())
+    }
+
     #[napi(catch_unwind)]
     pub fn go_to_next_sibling(&mut self) -> bool {
         self.0.go_to_next_sibling()
@@ -150,6 +158,11 @@ impl Cursor {
         self.0.go_to_next_token_with_kinds(&kinds)
     }
 
+    #[napi(catch_unwind)]
+    pub fn go_to_next_non_trivia_token(&mut self) -> bool {
+        self.0.go_to_next_non_trivia_token()
+    }
+
     #[napi(catch_unwind)]
     pub fn go_to_next_rule(&mut self) -> bool {
         self.0.go_to_next_rule()
@@ -170,4 +183,67 @@ impl Cursor {
     ) -> bool {
         self.0.go_to_next_rule_with_kinds(&kinds)
     }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_token(&mut self) -> bool {
+        self.0.go_to_previous_token()
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_token_with_kind(
+        &mut self,
+        #[napi(ts_arg_type = "kinds.TokenKind")] kind: TokenKind,
+    ) -> bool {
+        self.0.go_to_previous_token_with_kind(kind)
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_token_with_kinds(
+        &mut self,
+        #[napi(ts_arg_type = "Array<kinds.TokenKind>")] kinds: Vec<TokenKind>,
+    ) -> bool {
+        self.0.go_to_previous_token_with_kinds(&kinds)
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_non_trivia_token(&mut self) -> bool {
+        self.0.go_to_previous_non_trivia_token()
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_rule(&mut self) -> bool {
+        self.0.go_to_previous_rule()
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_rule_with_kind(
+        &mut self,
+        #[napi(ts_arg_type = "kinds.RuleKind")] kind: RuleKind,
+    ) -> bool {
+        self.0.go_to_previous_rule_with_kind(kind)
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_previous_rule_with_kinds(
+        &mut self,
+        #[napi(ts_arg_type = "Array<kinds.RuleKind>")] kinds: Vec<RuleKind>,
+    ) -> bool {
+        self.0.go_to_previous_rule_with_kinds(&kinds)
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_ancestor_with_kind(
+        &mut self,
+        #[napi(ts_arg_type = "kinds.RuleKind")] kind: RuleKind,
+    ) -> bool {
+        self.0.go_to_ancestor_with_kind(kind)
+    }
+
+    #[napi(catch_unwind)]
+    pub fn go_to_ancestor_with_kinds(
+        &mut self,
+        #[napi(ts_arg_type = "Array<kinds.RuleKind>")] kinds: Vec<RuleKind>,
+    ) -> bool {
+        self.0.go_to_ancestor_with_kinds(&kinds)
+    }
 }
@@ -4,7 +4,9 @@ use std::mem;
 use std::ops::Range;
 
 use crate::kinds::TokenKind;
-use crate::parse_error::ParseError;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::parse_options::ParseOptions;
+use crate::parser_support::parser_result::ParserResult;
 use crate::text_index::TextIndex;
 
 pub struct ParserContext<'s> {
@@ -13,6 +15,11 @@ pub struct ParserContext<'s> {
     undo_position: Option<TextIndex>,
     errors: Vec<ParseError>,
     closing_delimiters: Vec<TokenKind>,
+    missing_token: Option<(TextIndex, TokenKind)>,
+    options: ParseOptions,
+    depth: usize,
+    scanned_tokens: usize,
+    limit_exceeded: Option<(ParseErrorKind, TextIndex)>,
 }
 
 #[derive(Copy, Clone)]
@@ -23,12 +30,27 @@ pub struct Marker {
 
 impl<'s> ParserContext<'s> {
     pub fn new(source: &'s str) -> Self {
+        Self::with_options(source, ParseOptions::default())
+    }
+
+    pub fn with_options(source: &'s str, options: ParseOptions) -> Self {
+        // Nothing is parsed at all if the input is too large:
+        let limit_exceeded = options
+            .max_input_size
+            .is_some_and(|max| source.len() > max)
+            .then_some((ParseErrorKind::InputTooLarge, TextIndex::ZERO));
+
         Self {
             source,
             position: TextIndex::ZERO,
             undo_position: None,
             errors: vec![],
             closing_delimiters: vec![],
+            missing_token: None,
+            options,
+            depth: 0,
+            scanned_tokens: 0,
+            limit_exceeded,
         }
     }
 
@@ -44,6 +66,7 @@ impl<'s> ParserContext<'s> {
 
         self.position = marker.position;
         self.errors.truncate(marker.err_len);
+        self.missing_token = None;
     }
 
     pub fn emit(&mut self, error: ParseError) {
@@ -75,6 +98,68 @@ impl<'s> ParserContext<'s> {
         &self.closing_delimiters
     }
 
+    /// Marks the token as missing at the current position, so that the next attempt to parse it here
+    /// produces a [`TokenKind::MISSING`] node instead.
+    pub fn insert_missing(&mut self, kind: TokenKind) {
+        self.missing_token = Some((self.position, kind));
+    }
+
+    /// Whether the token was marked as missing at the current position. Only returns `true` once.
+    pub fn take_missing(&mut self, kind: TokenKind) -> bool {
+        if self.missing_token == Some((self.position, kind)) {
+            self.missing_token = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Runs the parser of a nested rule, unless that exceeds the maximum depth, or a limit was already hit.
+    /// From then on, nothing matches, so that the parser unwinds as quickly as possible.
+    pub fn nested(&mut self, parse: impl FnOnce(&mut Self) -> ParserResult) -> ParserResult {
+        if self.limit_exceeded.is_some() {
+            return ParserResult::disabled();
+        }
+
+        if self.options.max_depth.is_some_and(|max| self.depth >= max) {
+            self.limit_exceeded = Some((ParseErrorKind::NestingTooDeep, self.position));
+            return ParserResult::disabled();
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    /// Counts a token about to be scanned against the budget, and checks whether parsing was cancelled.
+    /// Returns `false` once a limit was hit, in which case the lexer stops as if the input ended here.
+    pub fn scan_token(&mut self) -> bool {
+        if self.limit_exceeded.is_some() {
+            return false;
+        }
+
+        self.scanned_tokens += 1;
+
+        if self.options.is_cancelled() {
+            self.limit_exceeded = Some((ParseErrorKind::Cancelled, self.position));
+        } else if self
+            .options
+            .token_budget
+            .is_some_and(|budget| self.scanned_tokens > budget)
+        {
+            self.limit_exceeded = Some((ParseErrorKind::TokenBudgetExceeded, self.position));
+        }
+
+        self.limit_exceeded.is_none()
+    }
+
+    /// The limit that stopped parsing, if any, and where it was hit.
+    pub fn limit_exceeded(&self) -> Option<(ParseErrorKind, TextIndex)> {
+        self.limit_exceeded
+    }
+
     pub fn position(&self) -> TextIndex {
         self.position
     }
@@ -2,6 +2,7 @@
 
 mod choice_helper;
 mod context;
+mod incremental;
 mod optional_helper;
 mod parser_function;
 mod parser_result;
@@ -20,6 +21,8 @@ pub(crate) use choice_helper::ChoiceHelper;
 #[allow(unused_imports)]
 pub(crate) use context::ParserContext;
 #[allow(unused_imports)]
+pub(crate) use incremental::reparse;
+#[allow(unused_imports)]
 pub(crate) use optional_helper::OptionalHelper;
 #[allow(unused_imports)]
 pub(crate) use parser_function::ParserFunction;
@@ -1,23 +1,32 @@
 // This file is generated automatically by infrastructure scripts. Please don't edit by hand.
 
 use std::fmt;
-use std::rc::Rc;
+
+use regex::Regex;
 
 // This crate is copied to another crate, so all imports should be relative
+use super::super::cst::Rc;
 use super::super::kinds::{NodeLabel, RuleKind, TokenKind};
 
 #[derive(Clone)]
-pub struct Query(pub(super) Matcher);
+pub struct Query {
+    pub(super) matcher: Matcher,
+    pub(super) predicates: Vec<Predicate>,
+}
 
 impl Query {
     pub fn parse(text: &str) -> Result<Self, String> {
-        Matcher::parse(text).map(Self)
+        super::parser::parse_query(text)
     }
 }
 
 impl fmt::Display for Query {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        self.matcher.fmt(f)?;
+        for predicate in &self.predicates {
+            write!(f, " {predicate}")?;
+        }
+        Ok(())
     }
 }
 
@@ -29,12 +38,43 @@ pub(super) enum Matcher {
     Alternatives(Rc<AlternativesMatcher>),
     Sequence(Rc<SequenceMatcher>),
     OneOrMore(Rc<OneOrMoreMatcher>),
+    Negation(Rc<NegationMatcher>),
     Ellipsis,
 }
 
 impl Matcher {
-    fn parse(text: &str) -> Result<Self, String> {
-        super::parser::parse_query(text)
+    /// Calls `f` with the name of every binding in this matcher, including nested ones,
+    /// and whether it is inside a negation, where it never captures any nodes.
+    pub(super) fn visit_binding_names(&self, f: &mut impl FnMut(&str, bool)) {
+        self.visit_binding_names_in(false, f);
+    }
+
+    fn visit_binding_names_in(&self, negated: bool, f: &mut impl FnMut(&str, bool)) {
+        match self {
+            Self::Binding(binding) => {
+                f(&binding.name, negated);
+                binding.child.visit_binding_names_in(negated, f);
+            }
+            Self::Node(node) => {
+                if let Some(child) = &node.child {
+                    child.visit_binding_names_in(negated, f);
+                }
+            }
+            Self::Optional(optional) => optional.child.visit_binding_names_in(negated, f),
+            Self::Alternatives(alternatives) => {
+                for child in &alternatives.children {
+                    child.visit_binding_names_in(negated, f);
+                }
+            }
+            Self::Sequence(sequence) => {
+                for child in &sequence.children {
+                    child.visit_binding_names_in(negated, f);
+                }
+            }
+            Self::OneOrMore(one_or_more) => one_or_more.child.visit_binding_names_in(negated, f),
+            Self::Negation(negation) => negation.child.visit_binding_names_in(true, f),
+            Self::Ellipsis => {}
+        }
     }
 }
 
@@ -83,6 +123,13 @@ impl fmt::Display for Matcher {
             Self::OneOrMore(one_or_more) => {
                 write!(f, "({})+", one_or_more.child)
             }
+            Self::Negation(negation) => {
+                if let Self::Node(_) = negation.child {
+                    write!(f, "!{}", negation.child)
+                } else {
+                    write!(f, "!({})", negation.child)
+                }
+            }
             Self::Ellipsis => write!(f, "..."),
         }
     }
@@ -113,25 +160,25 @@ pub(super) enum NodeSelector {
     LabelAndText { label: NodeLabel, text: String },
 }
 
+fn escape_string(string: &str) -> String {
+    string
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            '\u{08}' => "\\b".to_string(),
+            '\u{0c}' => "\\f".to_string(),
+            _ if c.is_ascii_graphic() => c.to_string(),
+            _ => format!("\\u{{{:x}}}", c as u32),
+        })
+        .collect::<String>()
+}
+
 impl fmt::Display for NodeSelector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fn escape_string(string: &str) -> String {
-            string
-                .chars()
-                .map(|c| match c {
-                    '"' => "\\\"".to_string(),
-                    '\\' => "\\\\".to_string(),
-                    '\n' => "\\n".to_string(),
-                    '\r' => "\\r".to_string(),
-                    '\t' => "\\t".to_string(),
-                    '\u{08}' => "\\b".to_string(),
-                    '\u{0c}' => "\\f".to_string(),
-                    _ if c.is_ascii_graphic() => c.to_string(),
-                    _ => format!("\\u{{{:x}}}", c as u32),
-                })
-                .collect::<String>()
-        }
-
         match self {
             Self::Anonymous => write!(f, "_"),
             Self::Kind { kind } => kind.fmt(f),
@@ -172,3 +219,59 @@ pub(super) struct OptionalMatcher {
 pub(super) struct OneOrMoreMatcher {
     pub child: Matcher,
 }
+
+pub(super) struct NegationMatcher {
+    pub child: Matcher,
+}
+
+/// A condition on the text of captured nodes, checked after the rest of the query has matched,
+/// e.g. `(#eq? @name "foo")`. Negated predicates are written with a `not-` prefix (`#not-eq?`).
+#[derive(Clone)]
+pub(super) struct Predicate {
+    pub operator: PredicateOperator,
+    pub negated: bool,
+    pub binding: String,
+}
+
+#[derive(Clone)]
+pub(super) enum PredicateOperator {
+    Eq(PredicateValue),
+    Match(Regex),
+    AnyOf(Vec<String>),
+}
+
+#[derive(Clone)]
+pub(super) enum PredicateValue {
+    Binding(String),
+    Text(String),
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if self.negated { "not-" } else { "" };
+        let binding = &self.binding;
+
+        match &self.operator {
+            PredicateOperator::Eq(PredicateValue::Binding(other)) => {
+                write!(f, "(#{prefix}eq? @{binding} @{other})")
+            }
+            PredicateOperator::Eq(PredicateValue::Text(text)) => {
+                write!(f, "(#{prefix}eq? @{binding} \"{}\")", escape_string(text))
+            }
+            PredicateOperator::Match(regex) => {
+                write!(
+                    f,
+                    "(#{prefix}match? @{binding} \"{}\")",
+                    escape_string(regex.as_str())
+                )
+            }
+            PredicateOperator::AnyOf(values) => {
+                write!(f, "(#{prefix}any-of? @{binding}")?;
+                for value in values {
+                    write!(f, " \"{}\"", escape_string(value))?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+use std::collections::VecDeque;
+
+use crate::kinds::{LexicalContext, TokenKind};
+use crate::language::Language;
+pub use crate::lexer::KeywordScan;
+use crate::lexer::{Lexer, ScannedToken};
+use crate::parser_support::{ParserContext, ParserResult};
+use crate::text_index::TextRange;
+
+/// A token scanned by [`Language::tokenize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    /// The kind of the token. Identifiers that match a reserved keyword have the kind of that keyword,
+    /// while identifiers that match a contextual (non-reserved) keyword keep the identifier kind.
+    pub kind: TokenKind,
+    pub range: TextRange,
+    /// Whether an identifier also matches a keyword, and if so, whether that keyword is reserved.
+    /// [`KeywordScan::Absent`] for all other tokens.
+    pub keyword: KeywordScan,
+}
+
+/// An iterator over all tokens (including trivia) of an input, scanned in a single lexical context.
+///
+/// Characters that are not recognized in that context are returned as [`TokenKind::SKIPPED`] tokens.
+pub struct Tokenizer<'a> {
+    language: &'a Language,
+    context: LexicalContext,
+    input: ParserContext<'a>,
+    pending_trivia: VecDeque<Token>,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn new(language: &'a Language, input: &'a str, context: LexicalContext) -> Self {
+        Self {
+            language,
+            context,
+            input: ParserContext::new(input),
+            pending_trivia: VecDeque::new(),
+        }
+    }
+
+    /// Scans a run of trivia at the current position, if any, into `pending_trivia`.
+    fn scan_trivia(&mut self) {
+        let start = self.input.position();
+
+        let ParserResult::Match(r#match) = self.language.leading_trivia(&mut self.input) else {
+            self.input.set_position(start);
+            return;
+        };
+
+        let mut position = start;
+        for child in r#match.nodes {
+            if let Some(token) = child.as_token() {
+                let end = position + child.text_len();
+
+                self.pending_trivia.push_back(Token {
+                    kind: token.kind,
+                    range: position..end,
+                    keyword: KeywordScan::Absent,
+                });
+
+                position = end;
+            }
+        }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        if self.pending_trivia.is_empty() {
+            self.scan_trivia();
+        }
+
+        if let Some(trivia) = self.pending_trivia.pop_front() {
+            return Some(trivia);
+        }
+
+        let start = self.input.position();
+        let scanned = self
+            .language
+            .next_token_in_context(self.context, &mut self.input)?;
+
+        let keyword = match scanned {
+            ScannedToken::Single(_) => KeywordScan::Absent,
+            ScannedToken::IdentifierOrKeyword { kw, .. } => kw,
+        };
+
+        Some(Token {
+            kind: scanned.unambiguous(),
+            range: start..self.input.position(),
+            keyword,
+        })
+    }
+}
@@ -0,0 +1,373 @@
+// This file is generated automatically by infrastructure scripts. Please don't edit by hand.
+
+//! A visitor over the CST, with hooks for entering and leaving every kind of node.
+//!
+//! Implement the hooks of interest on a [`Visitor`], and pass it to [`walk`] to visit all nodes in a DFS pre-order traversal.
+//! All hooks have a default implementation that does nothing.
+
+#![allow(clippy::too_many_lines)]
+
+use crate::cst::{Node, Rc, RuleNode, TokenNode};
+use crate::cursor::Cursor;
+use crate::kinds::{RuleKind, TokenKind};
+use crate::parse_output::ParseOutput;
+
+/// Returned by the `enter_*` hooks of a [`Visitor`], to control whether the children of a rule node are visited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkControl {
+    /// Visit the children of the node.
+    Continue,
+    /// Skip the children of the node. Its `leave_*` hooks are still called.
+    SkipChildren,
+}
+
+/// Hooks called by [`walk`] for every node in the tree.
+///
+/// For rule nodes, the generic [`Visitor::enter_rule`] hook is called first, followed by the hook specific to
+/// the node's [`RuleKind`]. If either of them returns [`WalkControl::SkipChildren`], the children are skipped.
+/// When leaving the node, the kind-specific hook is called first, followed by [`Visitor::leave_rule`].
+///
+/// For token nodes, [`Visitor::visit_token`] is called first, followed by the hook specific to the node's [`TokenKind`].
+///
+/// Every hook receives a [`Cursor`] pointing to the node, which can be used to inspect its position and ancestors.
+#[allow(unused_variables)]
+pub trait Visitor {
+    /// Called when entering any rule node.
+    fn enter_rule(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving any rule node, after all of its children were visited.
+    fn leave_rule(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when visiting any token node.
+    fn visit_token(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::AdditionExpression`] node.
+    fn enter_addition_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::AdditionExpression`] node.
+    fn leave_addition_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Expression`] node.
+    fn enter_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Expression`] node.
+    fn leave_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Literal`] node.
+    fn enter_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Literal`] node.
+    fn leave_literal(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::MemberAccessExpression`] node.
+    fn enter_member_access_expression(
+        &mut self,
+        node: &Rc<RuleNode>,
+        cursor: &Cursor,
+    ) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::MemberAccessExpression`] node.
+    fn leave_member_access_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::NegationExpression`] node.
+    fn enter_negation_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::NegationExpression`] node.
+    fn leave_negation_expression(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SeparatedIdentifiers`] node.
+    fn enter_separated_identifiers(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SeparatedIdentifiers`] node.
+    fn leave_separated_identifiers(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnit`] node.
+    fn enter_source_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnit`] node.
+    fn leave_source_unit(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnitMember`] node.
+    fn enter_source_unit_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnitMember`] node.
+    fn leave_source_unit_member(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::SourceUnitMembers`] node.
+    fn enter_source_unit_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::SourceUnitMembers`] node.
+    fn leave_source_unit_members(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::Tree`] node.
+    fn enter_tree(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::Tree`] node.
+    fn leave_tree(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TreeNode`] node.
+    fn enter_tree_node(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TreeNode`] node.
+    fn leave_tree_node(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TreeNodeChild`] node.
+    fn enter_tree_node_child(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TreeNodeChild`] node.
+    fn leave_tree_node_child(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when entering a [`RuleKind::TreeNodeChildren`] node.
+    fn enter_tree_node_children(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+        WalkControl::Continue
+    }
+
+    /// Called when leaving a [`RuleKind::TreeNodeChildren`] node.
+    fn leave_tree_node_children(&mut self, node: &Rc<RuleNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SKIPPED`] node.
+    fn visit_skipped(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MISSING`] node.
+    fn visit_missing(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Bang`] node.
+    fn visit_bang(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::CloseBracket`] node.
+    fn visit_close_bracket(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::DelimitedIdentifier`] node.
+    fn visit_delimited_identifier(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::EndOfLine`] node.
+    fn visit_end_of_line(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Identifier`] node.
+    fn visit_identifier(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::MultiLineComment`] node.
+    fn visit_multi_line_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::OpenBracket`] node.
+    fn visit_open_bracket(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Period`] node.
+    fn visit_period(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Plus`] node.
+    fn visit_plus(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Semicolon`] node.
+    fn visit_semicolon(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::SingleLineComment`] node.
+    fn visit_single_line_comment(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::StringLiteral`] node.
+    fn visit_string_literal(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::TreeKeyword`] node.
+    fn visit_tree_keyword(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+
+    /// Called when visiting a [`TokenKind::Whitespace`] node.
+    fn visit_whitespace(&mut self, node: &Rc<TokenNode>, cursor: &Cursor) {}
+}
+
+/// Visits all nodes of the parse tree, calling the matching hooks of the `visitor`.
+pub fn walk(output: &ParseOutput, visitor: &mut impl Visitor) {
+    walk_cursor(&output.create_tree_cursor(), visitor);
+}
+
+/// Visits the node pointed to by the cursor, along with all of its descendants.
+pub fn walk_cursor(cursor: &Cursor, visitor: &mut impl Visitor) {
+    let mut cursor = cursor.clone();
+    let mut depth = 0_usize;
+
+    loop {
+        match cursor.node() {
+            Node::Rule(rule) => {
+                if enter(visitor, &rule, &cursor) == WalkControl::Continue
+                    && cursor.go_to_first_child()
+                {
+                    depth += 1;
+                    continue;
+                }
+            }
+            Node::Token(token) => {
+                visit(visitor, &token, &cursor);
+            }
+        }
+
+        // Leave the current node, and any ancestors that have no more children to visit:
+        loop {
+            if let Node::Rule(rule) = cursor.node() {
+                leave(visitor, &rule, &cursor);
+            }
+
+            if depth == 0 {
+                return;
+            }
+
+            if cursor.go_to_next_sibling() {
+                break;
+            }
+
+            cursor.go_to_parent();
+            depth -= 1;
+        }
+    }
+}
+
+fn enter(visitor: &mut impl Visitor, node: &Rc<RuleNode>, cursor: &Cursor) -> WalkControl {
+    let generic = visitor.enter_rule(node, cursor);
+
+    let specific = match node.kind {
+        RuleKind::AdditionExpression => visitor.enter_addition_expression(node, cursor),
+        RuleKind::Expression => visitor.enter_expression(node, cursor),
+        RuleKind::Literal => visitor.enter_literal(node, cursor),
+        RuleKind::MemberAccessExpression => visitor.enter_member_access_expression(node, cursor),
+        RuleKind::NegationExpression => visitor.enter_negation_expression(node, cursor),
+        RuleKind::SeparatedIdentifiers => visitor.enter_separated_identifiers(node, cursor),
+        RuleKind::SourceUnit => visitor.enter_source_unit(node, cursor),
+        RuleKind::SourceUnitMember => visitor.enter_source_unit_member(node, cursor),
+        RuleKind::SourceUnitMembers => visitor.enter_source_unit_members(node, cursor),
+        RuleKind::Tree => visitor.enter_tree(node, cursor),
+        RuleKind::TreeNode => visitor.enter_tree_node(node, cursor),
+        RuleKind::TreeNodeChild => visitor.enter_tree_node_child(node, cursor),
+        RuleKind::TreeNodeChildren => visitor.enter_tree_node_children(node, cursor),
+    };
+
+    if generic == WalkControl::SkipChildren || specific == WalkControl::SkipChildren {
+        WalkControl::SkipChildren
+    } else {
+        WalkControl::Continue
+    }
+}
+
+fn leave(visitor: &mut impl Visitor, node: &Rc<RuleNode>, cursor: &Cursor) {
+    match node.kind {
+        RuleKind::AdditionExpression => {
+            visitor.leave_addition_expression(node, cursor);
+        }
+        RuleKind::Expression => {
+            visitor.leave_expression(node, cursor);
+        }
+        RuleKind::Literal => {
+            visitor.leave_literal(node, cursor);
+        }
+        RuleKind::MemberAccessExpression => {
+            visitor.leave_member_access_expression(node, cursor);
+        }
+        RuleKind::NegationExpression => {
+            visitor.leave_negation_expression(node, cursor);
+        }
+        RuleKind::SeparatedIdentifiers => {
+            visitor.leave_separated_identifiers(node, cursor);
+        }
+        RuleKind::SourceUnit => {
+            visitor.leave_source_unit(node, cursor);
+        }
+        RuleKind::SourceUnitMember => {
+            visitor.leave_source_unit_member(node, cursor);
+        }
+        RuleKind::SourceUnitMembers => {
+            visitor.leave_source_unit_members(node, cursor);
+        }
+        RuleKind::Tree => {
+            visitor.leave_tree(node, cursor);
+        }
+        RuleKind::TreeNode => {
+            visitor.leave_tree_node(node, cursor);
+        }
+        RuleKind::TreeNodeChild => {
+            visitor.leave_tree_node_child(node, cursor);
+        }
+        RuleKind::TreeNodeChildren => {
+            visitor.leave_tree_node_children(node, cursor);
+        }
+    }
+
+    visitor.leave_rule(node, cursor);
+}
+
+fn visit(visitor: &mut impl Visitor, node: &Rc<TokenNode>, cursor: &Cursor) {
+    visitor.visit_token(node, cursor);
+
+    match node.kind {
+        TokenKind::SKIPPED => {
+            visitor.visit_skipped(node, cursor);
+        }
+        TokenKind::MISSING => {
+            visitor.visit_missing(node, cursor);
+        }
+        TokenKind::Bang => {
+            visitor.visit_bang(node, cursor);
+        }
+        TokenKind::CloseBracket => {
+            visitor.visit_close_bracket(node, cursor);
+        }
+        TokenKind::DelimitedIdentifier => {
+            visitor.visit_delimited_identifier(node, cursor);
+        }
+        TokenKind::EndOfLine => {
+            visitor.visit_end_of_line(node, cursor);
+        }
+        TokenKind::Identifier => {
+            visitor.visit_identifier(node, cursor);
+        }
+        TokenKind::MultiLineComment => {
+            visitor.visit_multi_line_comment(node, cursor);
+        }
+        TokenKind::OpenBracket => {
+            visitor.visit_open_bracket(node, cursor);
+        }
+        TokenKind::Period => {
+            visitor.visit_period(node, cursor);
+        }
+        TokenKind::Plus => {
+            visitor.visit_plus(node, cursor);
+        }
+        TokenKind::Semicolon => {
+            visitor.visit_semicolon(node, cursor);
+        }
+        TokenKind::SingleLineComment => {
+            visitor.visit_single_line_comment(node, cursor);
+        }
+        TokenKind::StringLiteral => {
+            visitor.visit_string_literal(node, cursor);
+        }
+        TokenKind::TreeKeyword => {
+            visitor.visit_tree_keyword(node, cursor);
+        }
+        TokenKind::Whitespace => {
+            visitor.visit_whitespace(node, cursor);
+        }
+    }
+}
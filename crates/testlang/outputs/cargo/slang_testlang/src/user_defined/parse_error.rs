@@ -0,0 +1,3 @@
+use crate::parse_error::TokenCategory;
+
+pub(crate) static TOKEN_CATEGORIES: &[TokenCategory] = &[];
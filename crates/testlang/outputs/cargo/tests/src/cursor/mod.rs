@@ -0,0 +1,126 @@
+use semver::Version;
+use slang_testlang::cursor::Cursor;
+use slang_testlang::kinds::{RuleKind, TokenKind};
+use slang_testlang::language::Language;
+
+fn create_cursor(source: &str) -> Cursor {
+    let language = Language::new(Version::parse("1.0.0").unwrap()).unwrap();
+
+    let output = language.parse(RuleKind::SourceUnit, source);
+    assert!(output.is_valid());
+
+    output.create_tree_cursor()
+}
+
+fn text(cursor: &Cursor) -> String {
+    cursor.node().unparse()
+}
+
+#[test]
+fn previous_tokens() {
+    let mut cursor = create_cursor("tree [A [B C] D];");
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Semicolon));
+
+    let mut tokens = vec![];
+    while cursor.go_to_previous_token() {
+        tokens.push(text(&cursor));
+    }
+
+    assert_eq!(
+        tokens,
+        ["]", "D", " ", "]", "C", " ", "B", "[", " ", "A", "[", " ", "tree"]
+    );
+    assert!(cursor.is_completed());
+}
+
+#[test]
+fn previous_non_trivia_tokens() {
+    let mut cursor = create_cursor("tree [A [B C] D];");
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Semicolon));
+
+    let mut tokens = vec![];
+    while cursor.go_to_previous_non_trivia_token() {
+        tokens.push(text(&cursor));
+    }
+    assert_eq!(tokens, ["]", "D", "]", "C", "B", "[", "A", "[", "tree"]);
+
+    cursor.reset();
+
+    let mut tokens = vec![];
+    while cursor.go_to_next_non_trivia_token() {
+        tokens.push(text(&cursor));
+    }
+    assert_eq!(
+        tokens,
+        ["tree", "[", "A", "[", "B", "C", "]", "D", "]", ";"]
+    );
+}
+
+#[test]
+fn previous_tokens_with_kinds() {
+    let mut cursor = create_cursor("tree [A [B C] D];");
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Semicolon));
+
+    assert!(cursor.go_to_previous_token_with_kind(TokenKind::OpenBracket));
+    assert_eq!(cursor.text_offset().utf8, "tree [A ".len());
+
+    assert!(cursor.go_to_previous_token_with_kinds(&[
+        TokenKind::TreeKeyword,
+        TokenKind::DelimitedIdentifier
+    ]));
+    assert_eq!(text(&cursor), "A");
+
+    assert!(!cursor.go_to_previous_token_with_kind(TokenKind::Semicolon));
+}
+
+#[test]
+fn previous_rules_include_ancestors() {
+    let mut cursor = create_cursor("tree [A [B C] D];");
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Semicolon));
+
+    let mut rules = vec![];
+    while cursor.go_to_previous_rule_with_kinds(&[RuleKind::Tree, RuleKind::TreeNode]) {
+        rules.push(text(&cursor));
+    }
+
+    assert_eq!(rules, [" [B C]", " [A [B C] D]", "tree [A [B C] D];"]);
+
+    cursor.reset();
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::Semicolon));
+    assert!(cursor.go_to_previous_rule());
+    assert_eq!(text(&cursor), " D");
+    assert!(cursor.node().is_rule_with_kind(RuleKind::TreeNodeChild));
+}
+
+#[test]
+fn previous_from_the_root() {
+    let mut cursor = create_cursor("tree [A];");
+
+    assert!(!cursor.go_to_previous_rule_with_kind(RuleKind::Tree));
+    assert!(cursor.is_completed());
+    assert_eq!(text(&cursor), "tree [A];");
+
+    // Finished cursors don't move anymore, in either direction:
+    assert!(!cursor.go_to_previous_token());
+    assert!(!cursor.go_to_next_token());
+
+    cursor.reset();
+    while cursor.go_to_previous_rule() {}
+    assert!(cursor.is_completed());
+}
+
+#[test]
+fn ancestors_with_kinds() {
+    let mut cursor = create_cursor("tree [A [B C] D];");
+    assert!(cursor.go_to_next_token_with_kind(TokenKind::CloseBracket));
+    assert_eq!(cursor.text_offset().utf8, "tree [A [B C".len());
+
+    assert!(cursor.go_to_ancestor_with_kind(RuleKind::TreeNode));
+    assert_eq!(text(&cursor), " [B C]");
+
+    assert!(cursor.go_to_ancestor_with_kinds(&[RuleKind::TreeNode, RuleKind::Tree]));
+    assert_eq!(text(&cursor), " [A [B C] D]");
+
+    assert!(!cursor.go_to_ancestor_with_kind(RuleKind::TreeNode));
+    assert!(cursor.is_completed());
+}
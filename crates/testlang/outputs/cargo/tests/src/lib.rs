@@ -1,5 +1,8 @@
 #![cfg(test)]
 
+mod cursor;
 mod errors;
+mod line_index;
 mod query;
+mod sync;
 mod versions;
@@ -0,0 +1,95 @@
+use semver::Version;
+use slang_testlang::kinds::RuleKind;
+use slang_testlang::language::Language;
+use slang_testlang::text_index::{LineColumn, LineIndex, LineTerminators, TextIndex, TextUnit};
+
+#[test]
+fn line_terminators() {
+    for terminator in ["\n", "\r\n", "\r", "\u{85}", "\u{2028}", "\u{2029}"] {
+        let source = format!("a{terminator}b{terminator}");
+        let index = LineIndex::new(&source);
+
+        assert_eq!(index.line_count(), 3);
+
+        let b = TextIndex::from(&source[..source.find('b').unwrap()]);
+        assert_eq!(
+            index.line_column(b),
+            LineColumn {
+                line: 1,
+                column: TextIndex::ZERO,
+            }
+        );
+        assert_eq!(index.offset(1, 0, TextUnit::Utf8), Some(b));
+
+        // The terminator is part of the line it ends:
+        assert_eq!(index.line_column(b - TextIndex::from(terminator)).line, 0);
+        assert_eq!(index.offset(0, 2, TextUnit::Char), None);
+    }
+
+    // A line feed followed by a carriage return are two terminators:
+    assert_eq!(LineIndex::new("a\n\rb").line_count(), 3);
+}
+
+#[test]
+fn lsp_line_terminators() {
+    for terminator in ["\n", "\r\n", "\r"] {
+        let source = format!("a{terminator}b");
+        let index = LineIndex::with_terminators(&source, LineTerminators::Lsp);
+
+        assert_eq!(index.line_count(), 2);
+    }
+
+    let source = "a\u{85}b\u{2028}c\u{2029}d";
+    let index = LineIndex::with_terminators(source, LineTerminators::Lsp);
+
+    assert_eq!(index.line_count(), 1);
+    assert_eq!(
+        index.offset(0, 6, TextUnit::Char),
+        Some(TextIndex::from(&source[..source.find('d').unwrap()]))
+    );
+}
+
+#[test]
+fn columns_in_all_units() {
+    let source = "x\n\u{e9}\u{1F600}y";
+    let index = LineIndex::new(source);
+
+    let y = TextIndex::from(&source[..source.find('y').unwrap()]);
+    assert_eq!(
+        index.line_column(y),
+        LineColumn {
+            line: 1,
+            column: TextIndex {
+                utf8: 6,
+                utf16: 3,
+                char: 2,
+            },
+        }
+    );
+
+    assert_eq!(index.offset(1, 6, TextUnit::Utf8), Some(y));
+    assert_eq!(index.offset(1, 3, TextUnit::Utf16), Some(y));
+    assert_eq!(index.offset(1, 2, TextUnit::Char), Some(y));
+
+    // In the middle of a character:
+    assert_eq!(index.offset(1, 1, TextUnit::Utf8), None);
+    assert_eq!(index.offset(1, 2, TextUnit::Utf16), None);
+
+    // Past the end of the line:
+    assert_eq!(index.offset(1, 4, TextUnit::Char), None);
+    assert_eq!(index.offset(2, 0, TextUnit::Char), None);
+}
+
+#[test]
+fn from_parse_output() {
+    let language = Language::new(Version::parse("1.0.0").unwrap()).unwrap();
+
+    let output = language.parse(RuleKind::SourceUnit, "tree [A];\ntree [B;\n");
+    assert_eq!(output.errors().len(), 1);
+
+    let index = output.create_line_index();
+    let range = index.line_column_range(output.errors()[0].text_range());
+
+    assert_eq!((range.start.line, range.start.column.char), (1, 5));
+    assert_eq!((range.end.line, range.end.column.char), (2, 0));
+}
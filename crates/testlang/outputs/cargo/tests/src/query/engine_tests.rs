@@ -261,3 +261,133 @@ fn test_nested() {
         },
     );
 }
+
+fn repeated_identifiers_tree() -> LabeledNode {
+    cst_tree!(
+        TreeNode [
+            DelimitedIdentifier "A",
+            DelimitedIdentifier "B",
+            DelimitedIdentifier "A",
+        ]
+    )
+}
+
+#[test]
+fn test_eq_predicate() {
+    run_query_test(
+        &common_test_tree(),
+        r#"[TreeNode ... @x [DelimitedIdentifier] ... @y [DelimitedIdentifier] ...] (#eq? @x "A")"#,
+        query_results! {
+            {x: ["A"], y: ["B"]}
+            {x: ["A"], y: ["C"]}
+        },
+    );
+
+    run_query_test(
+        &repeated_identifiers_tree(),
+        "[TreeNode ... @x [DelimitedIdentifier] ... @y [DelimitedIdentifier] ...] (#eq? @x @y)",
+        query_results! {
+            {x: ["A"], y: ["A"]}
+        },
+    );
+}
+
+#[test]
+fn test_not_eq_predicate() {
+    run_query_test(
+        &repeated_identifiers_tree(),
+        "[TreeNode ... @x [DelimitedIdentifier] ... @y [DelimitedIdentifier] ...] (#not-eq? @x @y)",
+        query_results! {
+            {x: ["A"], y: ["B"]}
+            {x: ["B"], y: ["A"]}
+        },
+    );
+}
+
+#[test]
+fn test_match_predicate() {
+    run_query_test(
+        &common_test_tree(),
+        r#"[_ ... @x [DelimitedIdentifier] ...] (#match? @x "^[B-D]$")"#,
+        query_results! {
+            {x: ["B"]}
+            {x: ["C"]}
+            {x: ["D"]}
+        },
+    );
+
+    run_query_test(
+        &common_test_tree(),
+        r#"[TreeNode ... @x [DelimitedIdentifier] ...] (#not-match? @x "A|C")"#,
+        query_results! {
+            {x: ["B"]}
+        },
+    );
+}
+
+#[test]
+fn test_any_of_predicate() {
+    run_query_test(
+        &common_test_tree(),
+        r#"[_ ... @x [DelimitedIdentifier] ...] (#any-of? @x "A" "E")"#,
+        query_results! {
+            {x: ["A"]}
+            {x: ["E"]}
+        },
+    );
+
+    run_query_test(
+        &common_test_tree(),
+        r#"[_ ... @x [DelimitedIdentifier] ...] (#not-any-of? @x "A" "E")"#,
+        query_results! {
+            {x: ["B"]}
+            {x: ["C"]}
+            {x: ["D"]}
+        },
+    );
+}
+
+#[test]
+fn test_predicates_on_quantified_bindings() {
+    // A predicate has to hold for every captured node, and holds if there are none:
+    run_query_test(
+        &common_test_tree(),
+        r#"[TreeNode ... (@x [DelimitedIdentifier])* [_] ] (#not-eq? @x "A")"#,
+        query_results! {
+            {x: ["B", "C"]}
+            {x: ["C"]}
+            {}
+        },
+    );
+}
+
+#[test]
+fn test_predicates_on_empty_bindings() {
+    // Comparing to a binding that didn't capture any nodes holds, even when negated:
+    run_query_test(
+        &common_test_tree(),
+        r#"[TreeNode ... @x [DelimitedIdentifier] (@y ["A"])? [TreeNodeChild]] (#not-eq? @x @y) (#eq? @y @x)"#,
+        query_results! {
+            {x: ["C"]}
+        },
+    );
+}
+
+#[test]
+fn test_negation() {
+    run_query_test(
+        &common_test_tree(),
+        "[TreeNode ... @x [DelimitedIdentifier] ![DelimitedIdentifier] ...]",
+        query_results! {
+            {x: ["C"]}
+        },
+    );
+
+    run_query_test(
+        &common_test_tree(),
+        r#"@parent [_ !(... ["E"]) ...]"#,
+        query_results! {
+            {parent: ["ABCDE"]}
+        },
+    );
+}
@@ -61,3 +61,43 @@ fn test_parsing_error() {
         ),
     }
 }
+
+#[test]
+fn test_negation() {
+    run_parser_test(
+        r#"[TreeNode ![TreeNodeChild] !( ... [node: _] ) ...]"#,
+        r#"[TreeNode ![TreeNodeChild] !(... [node]) ...]"#,
+    );
+}
+
+#[test]
+fn test_predicates() {
+    run_parser_test(
+        r#"[TreeNode @a [_] @b [_]] (#eq? @a @b) ( #not-match?  @a "^_" ) (#any-of? @b "x" "y")"#,
+        r#"[TreeNode @a [_] @b [_]] (#eq? @a @b) (#not-match? @a "^_") (#any-of? @b "x" "y")"#,
+    );
+}
+
+#[test]
+fn test_predicate_errors() {
+    let error = |input: &str| Query::parse(input).err().unwrap();
+
+    assert_eq!(
+        error(r#"@a [_] (#eq? @b "x")"#),
+        "Predicate '#eq?' refers to an unknown binding '@b'."
+    );
+    assert_eq!(
+        error(r#"[TreeNode !(@b [_]) ...] (#eq? @b "x")"#),
+        "Predicate '#eq?' refers to a binding '@b' that is only inside a negation."
+    );
+    assert_eq!(
+        error(r#"@a [_] (#contains? @a "x")"#),
+        "Unknown predicate '#contains?'."
+    );
+    assert_eq!(
+        error(r#"@a [_] (#any-of? @a)"#),
+        "Invalid arguments to predicate '#any-of?'."
+    );
+    assert!(error(r#"@a [_] (#match? @a "(")"#)
+        .starts_with("Invalid regular expression in predicate '#match?':"));
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use std::thread;
+
+use semver::Version;
+use slang_testlang::cst::Node;
+use slang_testlang::cursor::Cursor;
+use slang_testlang::kinds::RuleKind;
+use slang_testlang::language::Language;
+use slang_testlang::parse_output::ParseOutput;
+use slang_testlang::query::{Query, QueryResult};
+
+#[test]
+fn types_are_thread_safe() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Language>();
+    assert_send_sync::<ParseOutput>();
+    assert_send_sync::<Node>();
+    assert_send_sync::<Cursor>();
+    assert_send_sync::<Query>();
+    assert_send_sync::<QueryResult>();
+}
+
+#[test]
+fn share_tree_across_threads() {
+    let language = Language::new(Version::parse("1.0.0").unwrap()).unwrap();
+
+    let output = Arc::new(language.parse(RuleKind::SourceUnit, "tree [A [B C] D];"));
+    assert!(output.is_valid());
+
+    let workers = ["A", "B", "C", "D"].map(|name| {
+        let output = Arc::clone(&output);
+        let query = Query::parse(&format!(r#"@x ["{name}"]"#)).unwrap();
+
+        thread::spawn(move || {
+            output
+                .create_tree_cursor()
+                .query(vec![query])
+                .flat_map(|QueryResult { bindings, .. }| bindings["x"].clone())
+                .map(|cursor| cursor.node().unparse())
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let results = workers.map(|worker| worker.join().unwrap());
+
+    assert_eq!(
+        results,
+        [["A"], ["B"], ["C"], ["D"]].map(|names| names.map(String::from).to_vec())
+    );
+}
@@ -115,3 +115,27 @@ test("access the node using its name", () => {
 
   expect(names).toEqual(["[", "[", "]", "]", "[", "]"]);
 });
+
+test("go backwards using the cursor", () => {
+  const source = "tree [A [B C] D];";
+  const language = new Language("1.0.0");
+  const parseTree = language.parse(RuleKind.SourceUnit, source);
+
+  const cursor = parseTree.createTreeCursor();
+  expect(cursor.goToNextTokenWithKind(TokenKind.CloseBracket)).toBe(true);
+
+  expect(cursor.goToPreviousNonTriviaToken()).toBe(true);
+  expectToken(cursor.node(), TokenKind.DelimitedIdentifier, "C");
+
+  expect(cursor.goToAncestorWithKind(RuleKind.TreeNode)).toBe(true);
+  expect(cursor.node().unparse()).toEqual(" [B C]");
+
+  expect(cursor.goToPreviousTokenWithKind(TokenKind.DelimitedIdentifier)).toBe(true);
+  expectToken(cursor.node(), TokenKind.DelimitedIdentifier, "A");
+
+  expect(cursor.goToPreviousRuleWithKind(RuleKind.Tree)).toBe(true);
+  expectRule(cursor.node(), RuleKind.Tree);
+
+  expect(cursor.goToPreviousRuleWithKind(RuleKind.Tree)).toBe(false);
+  expect(cursor.isCompleted).toBe(true);
+});
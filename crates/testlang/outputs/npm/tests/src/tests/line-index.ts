@@ -0,0 +1,28 @@
+import { Language } from "@slang-private/slang-testlang/language";
+import { RuleKind } from "@slang-private/slang-testlang/kinds";
+import { LineColumn, TextIndex, TextUnit } from "@slang-private/slang-testlang/text_index";
+
+test("convert offsets to line and column", () => {
+  const source = `tree [A];\r\ntree ["\u{1F600}" B];\n`;
+  const language = new Language("1.0.0");
+
+  const output = language.parse(RuleKind.SourceUnit, source);
+  expect(output.isValid).toBeTruthy();
+
+  const lineIndex = output.createLineIndex();
+  expect(lineIndex.lineCount).toEqual(3);
+
+  // The position of 'B' on the second line:
+  const offset = { utf8: 24, utf16: 22, char: 21 } satisfies TextIndex;
+  expect(lineIndex.lineColumn(offset)).toEqual({
+    line: 1,
+    column: { utf8: 13, utf16: 11, char: 10 },
+  } satisfies LineColumn);
+
+  expect(lineIndex.offset(1, 13, TextUnit.Utf8)).toEqual(offset);
+  expect(lineIndex.offset(1, 11, TextUnit.Utf16)).toEqual(offset);
+  expect(lineIndex.offset(1, 10, TextUnit.Char)).toEqual(offset);
+
+  // In the middle of the emoji:
+  expect(lineIndex.offset(1, 8, TextUnit.Utf16)).toBeNull();
+});